@@ -1,5 +1,97 @@
-const COMMANDS: &[&str] = &["event", "breadcrumb"];
+const COMMANDS: &[&str] = &[
+    "event",
+    "breadcrumb",
+    "breadcrumbs",
+    "capture_message",
+    "set_user",
+    "set_tag",
+    "remove_tag",
+    "attach_file",
+    "set_telemetry_enabled",
+    "capture_feedback",
+    "push_scope",
+    "pop_scope",
+    "flush",
+    "configure_scope",
+    "sentry_js_ready",
+    "set_fingerprint",
+    "set_level",
+    "heartbeat",
+    "set_extra",
+    "remove_extra",
+    "capture_minidump",
+    "trace_header",
+    "shutdown_sentry",
+    "reinit_sentry",
+    "session_status",
+    "set_context",
+    "remove_context",
+    "transaction",
+    "clear_breadcrumbs",
+    "reset_scope",
+];
+
+/// The commands left out of the default permission set, as they let the
+/// webview read files or silence the crash reporting.
+const OPT_IN_COMMANDS: &[&str] = &[
+    "attach_file",
+    "capture_minidump",
+    "shutdown_sentry",
+    "reinit_sentry",
+];
+
+/// The placeholders replaced by the plugin, which must each appear exactly
+/// once in the bundled script.
+const SCRIPT_PLACEHOLDERS: &[&str] = &[
+    "__DEBUG__",
+    "__PLUGIN_NAME__",
+    "__COMMAND_BREADCRUMBS__",
+    "__HEARTBEAT_INTERVAL__",
+    "__CAPTURE_CONSOLE__",
+    "__TRACES_SAMPLE_RATE__",
+    "__INIT_OPTIONS__",
+];
 
 fn main() {
+    // A bundle rebuilt without the placeholders would ship a broken bridge.
+    println!("cargo:rerun-if-changed=dist/inject.min.js");
+    let script =
+        std::fs::read_to_string("dist/inject.min.js").expect("cannot read dist/inject.min.js");
+    for placeholder in SCRIPT_PLACEHOLDERS {
+        let count = script.matches(placeholder).count();
+        assert!(
+            count == 1,
+            "dist/inject.min.js must contain {} exactly once, found {} times",
+            placeholder,
+            count
+        );
+    }
+
+    // Exposed to the build script of the application as
+    // `DEP_TAURI_PLUGIN_SENTRY_COMMANDS` and `DEP_TAURI_PLUGIN_SENTRY_DEFAULT_PERMISSIONS`,
+    // to generate the permissions of the plugin registered under a custom name.
+    println!("cargo:rerun-if-changed=permissions/default.toml");
+    let default =
+        std::fs::read_to_string("permissions/default.toml").expect("cannot read the permissions");
+    let mut default_permissions = Vec::new();
+    for command in COMMANDS {
+        let permission = format!("allow-{}", command.replace('_', "-"));
+        let opt_in = OPT_IN_COMMANDS.contains(command);
+        assert!(
+            default.contains(&format!("\"{}\"", permission)) != opt_in,
+            "permissions/default.toml must {} {}",
+            if opt_in { "not contain" } else { "contain" },
+            permission
+        );
+        if !opt_in {
+            default_permissions.push(permission);
+        }
+    }
+    println!("cargo:commands={}", COMMANDS.join(","));
+    println!(
+        "cargo:default_permissions={}",
+        default_permissions.join(",")
+    );
+
     tauri_plugin::Builder::new(COMMANDS).build();
 }
@@ -1,13 +1,66 @@
 import { BrowserOptions, ErrorEvent } from "@sentry/browser";
-import { Breadcrumb, Event } from "@sentry/types";
+import { Breadcrumb, Event, TransactionEvent } from "@sentry/types";
+/**
+ * Sets the name the Sentry plugin has been registered with in the Rust process.
+ */
+export declare function setPluginName(name: string): void;
+/**
+ * Sends the batched breadcrumbs to the Rust process in a single call.
+ */
+export declare function flushBreadcrumbs(): Promise<void>;
 /**
  * A simple `beforeSend` that sends the envelope to the Rust process via Tauri invoke.
  */
 export declare function sendEventToRust(event: Event): Promise<ErrorEvent | null>;
+/**
+ * A simple `beforeSendTransaction` that sends the transaction to the Rust process via Tauri invoke.
+ */
+export declare function sendTransactionToRust(event: TransactionEvent): Promise<TransactionEvent | null>;
 /**
  * A simple `beforeBreadcrumb` hook that sends the breadcrumb to the Rust process via Tauri invoke.
  */
 export declare function sendBreadcrumbToRust(breadcrumb: Breadcrumb): Breadcrumb | null;
+/**
+ * Records a breadcrumb for every Tauri command invoked by the webview.
+ *
+ * The commands of this plugin are not recorded, as they are invoked to report
+ * the breadcrumbs themselves.
+ */
+export declare function recordCommandBreadcrumbs(): void;
+/**
+ * Reports the errors of the webview to the Rust process, even if they are not
+ * seen by the Sentry browser SDK: `console.error` calls are recorded as
+ * breadcrumbs, uncaught errors and unhandled promise rejections are captured
+ * as events.
+ */
+export declare function captureConsole(): void;
+/**
+ * Reports the unhandled promise rejections to the Rust process, with the
+ * stacktrace of their reason.
+ *
+ * The `onunhandledrejection` handler of the `GlobalHandlers` integration of
+ * the Sentry browser SDK must be disabled, or the rejections are reported twice.
+ */
+export declare function captureRejections(): void;
+/**
+ * Reports to the Rust process that the Sentry browser SDK has been initialized.
+ */
+export declare function reportReady(): Promise<void>;
+/**
+ * Sends a heartbeat to the Rust process at the given interval, so that it can
+ * detect a crashed or unresponsive webview.
+ */
+export declare function startHeartbeat(intervalMs: number): void;
+/**
+ * Returns the `sentry-trace` header of the active span, to be passed to a
+ * Rust command continuing the trace with `continue_transaction`.
+ */
+export declare function traceHeader(): string | undefined;
+/**
+ * Runs the callback in the trace bound to the scope of the Rust process, if
+ * any, so that the events captured by the webview are linked to it.
+ */
+export declare function continueRustTrace<T>(callback: () => T): Promise<T>;
 /**
  * Default options for the Sentry browser SDK to pass events and breadcrumbs to
  * the Rust SDK.
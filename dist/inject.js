@@ -1,8 +1,42 @@
 import * as Sentry from "@sentry/browser";
-import { defaultOptions } from "./";
+import { captureConsole, captureRejections, defaultOptions, recordCommandBreadcrumbs, reportReady, setPluginName, startHeartbeat, } from "./";
 window.Sentry = Sentry;
+// We replace this with true or false before injecting this code into the browser
+const consoleCapture = __CAPTURE_CONSOLE__;
+// We replace this with the traces sample rate, null if tracing is disabled
+const tracesSampleRate = __TRACES_SAMPLE_RATE__;
+// We replace this with the name the plugin has been registered with
+setPluginName(__PLUGIN_NAME__);
 Sentry.init({
     ...defaultOptions,
+    // We replace this with the release, distribution, environment and tags of the Rust client
+    ...__INIT_OPTIONS__,
     // We replace this with true or false before injecting this code into the browser
     debug: __DEBUG__,
+    ...(tracesSampleRate === null ? {} : { tracesSampleRate }),
+    integrations: (integrations) => {
+        // The uncaught errors are reported by `captureConsole` instead, and the
+        // unhandled rejections by `captureRejections` in any case, with their stack
+        const enabled = integrations.filter((integration) => integration.name !== "GlobalHandlers");
+        if (!consoleCapture) {
+            enabled.push(Sentry.globalHandlersIntegration({ onunhandledrejection: false }));
+        }
+        return tracesSampleRate === null
+            ? enabled
+            : [...enabled, Sentry.browserTracingIntegration()];
+    },
 });
+reportReady();
+captureRejections();
+if (consoleCapture) {
+    captureConsole();
+}
+// We replace this with true or false before injecting this code into the browser
+if (__COMMAND_BREADCRUMBS__) {
+    recordCommandBreadcrumbs();
+}
+// We replace this with the heartbeat interval in milliseconds, 0 if disabled
+const heartbeatInterval = __HEARTBEAT_INTERVAL__;
+if (heartbeatInterval > 0) {
+    startHeartbeat(heartbeatInterval);
+}
@@ -1 +1 @@
-!function(){"use strict";const e=Object.prototype.toString;function t(t){switch(e.call(t)){case"[object Error]":case"[object Exception]":case"[object DOMException]":return!0;default:return d(t,Error)}}function n(t,n){return e.call(t)===`[object ${n}]`}function r(e){return n(e,"ErrorEvent")}function o(e){return n(e,"DOMError")}function s(e){return n(e,"String")}function i(e){return"object"==typeof e&&null!==e&&"__sentry_template_string__"in e&&"__sentry_template_values__"in e}function a(e){return null===e||i(e)||"object"!=typeof e&&"function"!=typeof e}function c(e){return n(e,"Object")}function u(e){return"undefined"!=typeof Event&&d(e,Event)}function l(e){return Boolean(e&&e.then&&"function"==typeof e.then)}function d(e,t){try{return e instanceof t}catch(e){return!1}}function p(e){return!("object"!=typeof e||null===e||!e.__isVue&&!e._isVue)}function h(e,t=0){return"string"!=typeof e||0===t||e.length<=t?e:`${e.slice(0,t)}...`}function f(e,t){let n=e;const r=n.length;if(r<=150)return n;t>r&&(t=r);let o=Math.max(t-60,0);o<5&&(o=0);let s=Math.min(o+140,r);return s>r-5&&(s=r),s===r&&(o=Math.max(s-140,0)),n=n.slice(o,s),o>0&&(n=`'{snip} ${n}`),s<r&&(n+=" {snip}"),n}function m(e,t){if(!Array.isArray(e))return"";const n=[];for(let t=0;t<e.length;t++){const r=e[t];try{p(r)?n.push("[VueViewModel]"):n.push(String(r))}catch(e){n.push("[value cannot be serialized]")}}return n.join(t)}function g(e,t,r=!1){return!!s(e)&&(n(t,"RegExp")?t.test(e):!!s(t)&&(r?e===t:e.includes(t)))}function _(e,t=[],n=!1){return t.some((t=>g(e,t,n)))}function y(e,t,n=250,r,o,s,i){if(!(s.exception&&s.exception.values&&i&&d(i.originalException,Error)))return;const a=s.exception.values.length>0?s.exception.values[s.exception.values.length-1]:void 0;var c,u;a&&(s.exception.values=(c=v(e,t,o,i.originalException,r,s.exception.values,a,0),u=n,c.map((e=>(e.value&&(e.value=h(e.value,u)),e)))))}function v(e,t,n,r,o,s,i,a){if(s.length>=n+1)return s;let c=[...s];if(d(r[o],Error)){b(i,a);const s=e(t,r[o]),u=c.length;S(s,o,u,a),c=v(e,t,n,r[o],o,[s,...c],s,u)}return Array.isArray(r.errors)&&r.errors.forEach(((r,s)=>{if(d(r,Error)){b(i,a);const u=e(t,r),l=c.length;S(u,`errors[${s}]`,l,a),c=v(e,t,n,r,o,[u,...c],u,l)}})),c}function b(e,t){e.mechanism=e.mechanism||{type:"generic",handled:!0},e.mechanism={...e.mechanism,..."AggregateError"===e.type&&{is_exception_group:!0},exception_id:t}}function S(e,t,n,r){e.mechanism=e.mechanism||{type:"generic",handled:!0},e.mechanism={...e.mechanism,type:"chained",source:t,exception_id:n,parent_id:r}}const w="8.30.0",k=globalThis;function x(e,t,n){const r=n||k,o=r.__SENTRY__=r.__SENTRY__||{},s=o[w]=o[w]||{};return s[e]||(s[e]=t())}const E=k,C=80;function T(e,t={}){if(!e)return"<unknown>";try{let n=e;const r=5,o=[];let s=0,i=0;const a=" > ",c=a.length;let u;const l=Array.isArray(t)?t:t.keyAttrs,d=!Array.isArray(t)&&t.maxStringLength||C;for(;n&&s++<r&&(u=I(n,l),!("html"===u||s>1&&i+o.length*c+u.length>=d));)o.push(u),i+=u.length,n=n.parentNode;return o.reverse().join(a)}catch(e){return"<unknown>"}}function I(e,t){const n=e,r=[];if(!n||!n.tagName)return"";if(E.HTMLElement&&n instanceof HTMLElement&&n.dataset){if(n.dataset.sentryComponent)return n.dataset.sentryComponent;if(n.dataset.sentryElement)return n.dataset.sentryElement}r.push(n.tagName.toLowerCase());const o=t&&t.length?t.filter((e=>n.getAttribute(e))).map((e=>[e,n.getAttribute(e)])):null;if(o&&o.length)o.forEach((e=>{r.push(`[${e[0]}="${e[1]}"]`)}));else{n.id&&r.push(`#${n.id}`);const e=n.className;if(e&&s(e)){const t=e.split(/\s+/);for(const e of t)r.push(`.${e}`)}}const i=["aria-label","type","name","title","alt"];for(const e of i){const t=n.getAttribute(e);t&&r.push(`[${e}="${t}"]`)}return r.join("")}function M(){try{return E.document.location.href}catch(e){return""}}function R(e){if(!E.HTMLElement)return null;let t=e;for(let e=0;e<5;e++){if(!t)return null;if(t instanceof HTMLElement){if(t.dataset.sentryComponent)return t.dataset.sentryComponent;if(t.dataset.sentryElement)return t.dataset.sentryElement}t=t.parentNode}return null}const A="undefined"==typeof __SENTRY_DEBUG__||__SENTRY_DEBUG__,N=["debug","info","warn","error","log","assert","trace"],O={};function D(e){if(!("console"in k))return e();const t=k.console,n={},r=Object.keys(O);r.forEach((e=>{const r=O[e];n[e]=t[e],t[e]=r}));try{return e()}finally{r.forEach((e=>{t[e]=n[e]}))}}const L=x("logger",(function(){let e=!1;const t={enable:()=>{e=!0},disable:()=>{e=!1},isEnabled:()=>e};return A?N.forEach((n=>{t[n]=(...t)=>{e&&D((()=>{k.console[n](`Sentry Logger [${n}]:`,...t)}))}})):N.forEach((e=>{t[e]=()=>{}})),t})),P=/^(?:(\w+):)\/\/(?:(\w+)(?::(\w+)?)?@)([\w.-]+)(?::(\d+))?\/(.+)/;function F(e,t=!1){const{host:n,path:r,pass:o,port:s,projectId:i,protocol:a,publicKey:c}=e;return`${a}://${c}${t&&o?`:${o}`:""}@${n}${s?`:${s}`:""}/${r?`${r}/`:r}${i}`}function $(e){const t=P.exec(e);if(!t)return void D((()=>{console.error(`Invalid Sentry Dsn: ${e}`)}));const[n,r,o="",s="",i="",a=""]=t.slice(1);let c="",u=a;const l=u.split("/");if(l.length>1&&(c=l.slice(0,-1).join("/"),u=l.pop()),u){const e=u.match(/^\d+/);e&&(u=e[0])}return B({host:s,pass:o,path:c,projectId:u,port:i,protocol:n,publicKey:r})}function B(e){return{protocol:e.protocol,publicKey:e.publicKey||"",pass:e.pass||"",host:e.host,port:e.port||"",path:e.path||"",projectId:e.projectId}}function U(e){const t="string"==typeof e?$(e):B(e);if(t&&function(e){if(!A)return!0;const{port:t,projectId:n,protocol:r}=e;return!(["protocol","publicKey","host","projectId"].find((t=>!e[t]&&(L.error(`Invalid Sentry Dsn: ${t} missing`),!0)))||(n.match(/^\d+$/)?function(e){return"http"===e||"https"===e}(r)?t&&isNaN(parseInt(t,10))&&(L.error(`Invalid Sentry Dsn: Invalid port ${t}`),1):(L.error(`Invalid Sentry Dsn: Invalid protocol ${r}`),1):(L.error(`Invalid Sentry Dsn: Invalid projectId ${n}`),1)))}(t))return t}class j extends Error{constructor(e,t="warn"){super(e),this.message=e,this.name=new.target.prototype.constructor.name,Object.setPrototypeOf(this,new.target.prototype),this.logLevel=t}}function H(e,t,n){if(!(t in e))return;const r=e[t],o=n(r);"function"==typeof o&&q(o,r),e[t]=o}function z(e,t,n){try{Object.defineProperty(e,t,{value:n,writable:!0,configurable:!0})}catch(n){A&&L.log(`Failed to add non-enumerable property "${t}" to object`,e)}}function q(e,t){try{const n=t.prototype||{};e.prototype=t.prototype=n,z(e,"__sentry_original__",t)}catch(e){}}function W(e){return e.__sentry_original__}function Y(e){if(t(e))return{message:e.message,name:e.name,stack:e.stack,...G(e)};if(u(e)){const t={type:e.type,target:V(e.target),currentTarget:V(e.currentTarget),...G(e)};return"undefined"!=typeof CustomEvent&&d(e,CustomEvent)&&(t.detail=e.detail),t}return e}function V(e){try{return t=e,"undefined"!=typeof Element&&d(t,Element)?T(e):Object.prototype.toString.call(e)}catch(e){return"<unknown>"}var t}function G(e){if("object"==typeof e&&null!==e){const t={};for(const n in e)Object.prototype.hasOwnProperty.call(e,n)&&(t[n]=e[n]);return t}return{}}function X(e){return J(e,new Map)}function J(e,t){if(function(e){if(!c(e))return!1;try{const t=Object.getPrototypeOf(e).constructor.name;return!t||"Object"===t}catch(e){return!0}}(e)){const n=t.get(e);if(void 0!==n)return n;const r={};t.set(e,r);for(const n of Object.keys(e))void 0!==e[n]&&(r[n]=J(e[n],t));return r}if(Array.isArray(e)){const n=t.get(e);if(void 0!==n)return n;const r=[];return t.set(e,r),e.forEach((e=>{r.push(J(e,t))})),r}return e}const K=50,Q="?",Z=/\(error: (.*)\)/,ee=/captureMessage|captureException/;function te(...e){const t=e.sort(((e,t)=>e[0]-t[0])).map((e=>e[1]));return(e,n=0,r=0)=>{const o=[],s=e.split("\n");for(let e=n;e<s.length;e++){const n=s[e];if(n.length>1024)continue;const i=Z.test(n)?n.replace(Z,"$1"):n;if(!i.match(/\S*Error: /)){for(const e of t){const t=e(i);if(t){o.push(t);break}}if(o.length>=K+r)break}}return function(e){if(!e.length)return[];const t=Array.from(e);/sentryWrapped/.test(ne(t).function||"")&&t.pop();t.reverse(),ee.test(ne(t).function||"")&&(t.pop(),ee.test(ne(t).function||"")&&t.pop());return t.slice(0,K).map((e=>({...e,filename:e.filename||ne(t).filename,function:e.function||Q})))}(o.slice(r))}}function ne(e){return e[e.length-1]||{}}const re="<anonymous>";function oe(e){try{return e&&"function"==typeof e&&e.name||re}catch(e){return re}}function se(e){const t=e.exception;if(t){const e=[];try{return t.values.forEach((t=>{t.stacktrace.frames&&e.push(...t.stacktrace.frames)})),e}catch(e){return}}}const ie={},ae={};function ce(e,t){ie[e]=ie[e]||[],ie[e].push(t)}function ue(e,t){ae[e]||(t(),ae[e]=!0)}function le(e,t){const n=e&&ie[e];if(n)for(const r of n)try{r(t)}catch(t){A&&L.error(`Error while triggering instrumentation handler.\nType: ${e}\nName: ${oe(r)}\nError:`,t)}}function de(e){const t="console";ce(t,e),ue(t,pe)}function pe(){"console"in k&&N.forEach((function(e){e in k.console&&H(k.console,e,(function(t){return O[e]=t,function(...t){le("console",{args:t,level:e});const n=O[e];n&&n.apply(k.console,t)}}))}))}const he=k;function fe(){if(!("fetch"in he))return!1;try{return new Headers,new Request("http://www.example.com"),new Response,!0}catch(e){return!1}}function me(e){return e&&/^function\s+\w+\(\)\s+\{\s+\[native code\]\s+\}$/.test(e.toString())}function ge(){if("string"==typeof EdgeRuntime)return!0;if(!fe())return!1;if(me(he.fetch))return!0;let e=!1;const t=he.document;if(t&&"function"==typeof t.createElement)try{const n=t.createElement("iframe");n.hidden=!0,t.head.appendChild(n),n.contentWindow&&n.contentWindow.fetch&&(e=me(n.contentWindow.fetch)),t.head.removeChild(n)}catch(e){A&&L.warn("Could not create sandbox iframe for pure fetch check, bailing to window.fetch: ",e)}return e}const _e=1e3;function ye(){return Date.now()/_e}const ve=function(){const{performance:e}=k;if(!e||!e.now)return ye;const t=Date.now()-e.now(),n=null==e.timeOrigin?t:e.timeOrigin;return()=>(n+e.now())/_e}(),be=(()=>{const{performance:e}=k;if(!e||!e.now)return;const t=36e5,n=e.now(),r=Date.now(),o=e.timeOrigin?Math.abs(e.timeOrigin+n-r):t,s=o<t,i=e.timing&&e.timing.navigationStart,a="number"==typeof i?Math.abs(i+n-r):t;return s||a<t?o<=a?e.timeOrigin:i:r})();function Se(e,t){const n="fetch";ce(n,e),ue(n,(()=>we(void 0,t)))}function we(e,n=!1){n&&!ge()||H(k,"fetch",(function(n){return function(...r){const{method:o,url:s}=function(e){if(0===e.length)return{method:"GET",url:""};if(2===e.length){const[t,n]=e;return{url:Ee(t),method:xe(n,"method")?String(n.method).toUpperCase():"GET"}}const t=e[0];return{url:Ee(t),method:xe(t,"method")?String(t.method).toUpperCase():"GET"}}(r),i={args:r,fetchData:{method:o,url:s},startTimestamp:1e3*ve()};e||le("fetch",{...i});const a=(new Error).stack;return n.apply(k,r).then((async t=>(e?e(t):le("fetch",{...i,endTimestamp:1e3*ve(),response:t}),t)),(e=>{throw le("fetch",{...i,endTimestamp:1e3*ve(),error:e}),t(e)&&void 0===e.stack&&(e.stack=a,z(e,"framesToPop",1)),e}))}}))}async function ke(e){let t;try{t=e.clone()}catch(e){return}await async function(e,t){if(e&&e.body&&e.body.getReader){const n=e.body.getReader();async function r({done:e}){if(e)return Promise.resolve();try{const e=await Promise.race([n.read(),new Promise((e=>{setTimeout((()=>{e({done:!0})}),5e3)}))]);await r(e)}catch(e){}}return n.read().then(r).then(t).catch((()=>{}))}}(t,(()=>{le("fetch-body-resolved",{endTimestamp:1e3*ve(),response:e})}))}function xe(e,t){return!!e&&"object"==typeof e&&!!e[t]}function Ee(e){return"string"==typeof e?e:e?xe(e,"url")?e.url:e.toString?e.toString():"":""}let Ce=null;function Te(e){const t="error";ce(t,e),ue(t,Ie)}function Ie(){Ce=k.onerror,k.onerror=function(e,t,n,r,o){return le("error",{column:r,error:o,line:n,msg:e,url:t}),!(!Ce||Ce.__SENTRY_LOADER__)&&Ce.apply(this,arguments)},k.onerror.__SENTRY_INSTRUMENTED__=!0}let Me=null;function Re(e){const t="unhandledrejection";ce(t,e),ue(t,Ae)}function Ae(){Me=k.onunhandledrejection,k.onunhandledrejection=function(e){return le("unhandledrejection",e),!(Me&&!Me.__SENTRY_LOADER__)||Me.apply(this,arguments)},k.onunhandledrejection.__SENTRY_INSTRUMENTED__=!0}function Ne(){return!("undefined"!=typeof __SENTRY_BROWSER_BUNDLE__&&__SENTRY_BROWSER_BUNDLE__)&&"[object process]"===Object.prototype.toString.call("undefined"!=typeof process?process:0)}function Oe(){return"undefined"!=typeof window&&(!Ne()||void 0!==k.process&&"renderer"===k.process.type)}function De(){const e=k,t=e.crypto||e.msCrypto;let n=()=>16*Math.random();try{if(t&&t.randomUUID)return t.randomUUID().replace(/-/g,"");t&&t.getRandomValues&&(n=()=>{const e=new Uint8Array(1);return t.getRandomValues(e),e[0]})}catch(e){}return([1e7]+1e3+4e3+8e3+1e11).replace(/[018]/g,(e=>(e^(15&n())>>e/4).toString(16)))}function Le(e){return e.exception&&e.exception.values?e.exception.values[0]:void 0}function Pe(e){const{message:t,event_id:n}=e;if(t)return t;const r=Le(e);return r?r.type&&r.value?`${r.type}: ${r.value}`:r.type||r.value||n||"<unknown>":n||"<unknown>"}function Fe(e,t,n){const r=e.exception=e.exception||{},o=r.values=r.values||[],s=o[0]=o[0]||{};s.value||(s.value=t||""),s.type||(s.type=n||"Error")}function $e(e,t){const n=Le(e);if(!n)return;const r=n.mechanism;if(n.mechanism={type:"generic",handled:!0,...r,...t},t&&"data"in t){const e={...r&&r.data,...t.data};n.mechanism.data=e}}function Be(e){if(e&&e.__sentry_captured__)return!0;try{z(e,"__sentry_captured__",!0)}catch(e){}return!1}function Ue(e){return Array.isArray(e)?e:[e]}function je(e,t=100,n=1/0){try{return ze("",e,t,n)}catch(e){return{ERROR:`**non-serializable** (${e})`}}}function He(e,t=3,n=102400){const r=je(e,t);return o=r,function(e){return~-encodeURI(e).split(/%..|./).length}(JSON.stringify(o))>n?He(e,t-1,n):r;var o}function ze(e,t,n=1/0,r=1/0,o=function(){const e="function"==typeof WeakSet,t=e?new WeakSet:[];return[function(n){if(e)return!!t.has(n)||(t.add(n),!1);for(let e=0;e<t.length;e++)if(t[e]===n)return!0;return t.push(n),!1},function(n){if(e)t.delete(n);else for(let e=0;e<t.length;e++)if(t[e]===n){t.splice(e,1);break}}]}()){const[s,i]=o;if(null==t||["number","boolean","string"].includes(typeof t)&&!Number.isNaN(t))return t;const a=function(e,t){try{if("domain"===e&&t&&"object"==typeof t&&t._events)return"[Domain]";if("domainEmitter"===e)return"[DomainEmitter]";if("undefined"!=typeof global&&t===global)return"[Global]";if("undefined"!=typeof window&&t===window)return"[Window]";if("undefined"!=typeof document&&t===document)return"[Document]";if(p(t))return"[VueViewModel]";if(c(n=t)&&"nativeEvent"in n&&"preventDefault"in n&&"stopPropagation"in n)return"[SyntheticEvent]";if("number"==typeof t&&t!=t)return"[NaN]";if("function"==typeof t)return`[Function: ${oe(t)}]`;if("symbol"==typeof t)return`[${String(t)}]`;if("bigint"==typeof t)return`[BigInt: ${String(t)}]`;const r=function(e){const t=Object.getPrototypeOf(e);return t?t.constructor.name:"null prototype"}(t);return/^HTML(\w*)Element$/.test(r)?`[HTMLElement: ${r}]`:`[object ${r}]`}catch(e){return`**non-serializable** (${e})`}var n}(e,t);if(!a.startsWith("[object "))return a;if(t.__sentry_skip_normalization__)return t;const u="number"==typeof t.__sentry_override_normalization_depth__?t.__sentry_override_normalization_depth__:n;if(0===u)return a.replace("object ","");if(s(t))return"[Circular ~]";const l=t;if(l&&"function"==typeof l.toJSON)try{return ze("",l.toJSON(),u-1,r,o)}catch(e){}const d=Array.isArray(t)?[]:{};let h=0;const f=Y(t);for(const e in f){if(!Object.prototype.hasOwnProperty.call(f,e))continue;if(h>=r){d[e]="[MaxProperties ~]";break}const t=f[e];d[e]=ze(e,t,u-1,r,o),h++}return i(t),d}const qe=/^(\S+:\\|\/?)([\s\S]*?)((?:\.{1,2}|[^/\\]+?|)(\.[^./\\]*|))(?:[/\\]*)$/;function We(...e){let t="",n=!1;for(let r=e.length-1;r>=-1&&!n;r--){const o=r>=0?e[r]:"/";o&&(t=`${o}/${t}`,n="/"===o.charAt(0))}return t=function(e,t){let n=0;for(let t=e.length-1;t>=0;t--){const r=e[t];"."===r?e.splice(t,1):".."===r?(e.splice(t,1),n++):n&&(e.splice(t,1),n--)}if(t)for(;n--;n)e.unshift("..");return e}(t.split("/").filter((e=>!!e)),!n).join("/"),(n?"/":"")+t||"."}function Ye(e){let t=0;for(;t<e.length&&""===e[t];t++);let n=e.length-1;for(;n>=0&&""===e[n];n--);return t>n?[]:e.slice(t,n-t+1)}function Ve(e,t){let n=function(e){const t=e.length>1024?`<truncated>${e.slice(-1024)}`:e,n=qe.exec(t);return n?n.slice(1):[]}(e)[2]||"";return t&&n.slice(-1*t.length)===t&&(n=n.slice(0,n.length-t.length)),n}var Ge;function Xe(e){return new Ke((t=>{t(e)}))}function Je(e){return new Ke(((t,n)=>{n(e)}))}!function(e){e[e.PENDING=0]="PENDING";e[e.RESOLVED=1]="RESOLVED";e[e.REJECTED=2]="REJECTED"}(Ge||(Ge={}));class Ke{constructor(e){Ke.prototype.__init.call(this),Ke.prototype.__init2.call(this),Ke.prototype.__init3.call(this),Ke.prototype.__init4.call(this),this._state=Ge.PENDING,this._handlers=[];try{e(this._resolve,this._reject)}catch(e){this._reject(e)}}then(e,t){return new Ke(((n,r)=>{this._handlers.push([!1,t=>{if(e)try{n(e(t))}catch(e){r(e)}else n(t)},e=>{if(t)try{n(t(e))}catch(e){r(e)}else r(e)}]),this._executeHandlers()}))}catch(e){return this.then((e=>e),e)}finally(e){return new Ke(((t,n)=>{let r,o;return this.then((t=>{o=!1,r=t,e&&e()}),(t=>{o=!0,r=t,e&&e()})).then((()=>{o?n(r):t(r)}))}))}__init(){this._resolve=e=>{this._setResult(Ge.RESOLVED,e)}}__init2(){this._reject=e=>{this._setResult(Ge.REJECTED,e)}}__init3(){this._setResult=(e,t)=>{this._state===Ge.PENDING&&(l(t)?t.then(this._resolve,this._reject):(this._state=e,this._value=t,this._executeHandlers()))}}__init4(){this._executeHandlers=()=>{if(this._state===Ge.PENDING)return;const e=this._handlers.slice();this._handlers=[],e.forEach((e=>{e[0]||(this._state===Ge.RESOLVED&&e[1](this._value),this._state===Ge.REJECTED&&e[2](this._value),e[0]=!0)}))}}}function Qe(e){const t=[];function n(e){return t.splice(t.indexOf(e),1)[0]||Promise.resolve(void 0)}return{$:t,add:function(r){if(!(void 0===e||t.length<e))return Je(new j("Not adding Promise because buffer limit was reached."));const o=r();return-1===t.indexOf(o)&&t.push(o),o.then((()=>n(o))).then(null,(()=>n(o).then(null,(()=>{})))),o},drain:function(e){return new Ke(((n,r)=>{let o=t.length;if(!o)return n(!0);const s=setTimeout((()=>{e&&e>0&&n(!1)}),e);t.forEach((e=>{Xe(e).then((()=>{--o||(clearTimeout(s),n(!0))}),r)}))}))}}}function Ze(e){if(!e)return{};const t=e.match(/^(([^:/?#]+):)?(\/\/([^/?#]*))?([^?#]*)(\?([^#]*))?(#(.*))?$/);if(!t)return{};const n=t[6]||"",r=t[8]||"";return{host:t[4],path:t[5],protocol:t[2],search:n,hash:r,relative:t[5]+n+r}}const et=["fatal","error","warning","log","info","debug"];function tt(e){return"warn"===e?"warning":et.includes(e)?e:"log"}const nt="baggage",rt="sentry-",ot=/^sentry-/,st=8192;function it(e){const t=function(e){if(!e||!s(e)&&!Array.isArray(e))return;if(Array.isArray(e))return e.reduce(((e,t)=>{const n=ct(t);return Object.entries(n).forEach((([t,n])=>{e[t]=n})),e}),{});return ct(e)}(e);if(!t)return;const n=Object.entries(t).reduce(((e,[t,n])=>{if(t.match(ot)){e[t.slice(rt.length)]=n}return e}),{});return Object.keys(n).length>0?n:void 0}function at(e){if(!e)return;return function(e){if(0===Object.keys(e).length)return;return Object.entries(e).reduce(((e,[t,n],r)=>{const o=`${encodeURIComponent(t)}=${encodeURIComponent(n)}`,s=0===r?o:`${e},${o}`;return s.length>st?(A&&L.warn(`Not adding key: ${t} with val: ${n} to baggage header due to exceeding baggage size limits.`),e):s}),"")}(Object.entries(e).reduce(((e,[t,n])=>(n&&(e[`${rt}${t}`]=n),e)),{}))}function ct(e){return e.split(",").map((e=>e.split("=").map((e=>decodeURIComponent(e.trim()))))).reduce(((e,[t,n])=>(t&&n&&(e[t]=n),e)),{})}const ut=new RegExp("^[ \\t]*([0-9a-f]{32})?-?([0-9a-f]{16})?-?([01])?[ \\t]*$");function lt(e,t){const n=function(e){if(!e)return;const t=e.match(ut);if(!t)return;let n;return"1"===t[3]?n=!0:"0"===t[3]&&(n=!1),{traceId:t[1],parentSampled:n,parentSpanId:t[2]}}(e),r=it(t),{traceId:o,parentSpanId:s,parentSampled:i}=n||{};return n?{traceId:o||De(),parentSpanId:s||De().substring(16),spanId:De().substring(16),sampled:i,dsc:r||{}}:{traceId:o||De(),spanId:De().substring(16)}}function dt(e=De(),t=De().substring(16),n){let r="";return void 0!==n&&(r=n?"-1":"-0"),`${e}-${t}${r}`}function pt(e,t=[]){return[e,t]}function ht(e,t){const[n,r]=e;return[n,[...r,t]]}function ft(e,t){const n=e[1];for(const e of n){if(t(e,e[0].type))return!0}return!1}function mt(e,t){return ft(e,((e,n)=>t.includes(n)))}function gt(e){return k.__SENTRY__&&k.__SENTRY__.encodePolyfill?k.__SENTRY__.encodePolyfill(e):(new TextEncoder).encode(e)}function _t(e){const[t,n]=e;let r=JSON.stringify(t);function o(e){"string"==typeof r?r="string"==typeof e?r+e:[gt(r),e]:r.push("string"==typeof e?gt(e):e)}for(const e of n){const[t,n]=e;if(o(`\n${JSON.stringify(t)}\n`),"string"==typeof n||n instanceof Uint8Array)o(n);else{let e;try{e=JSON.stringify(n)}catch(t){e=JSON.stringify(je(n))}o(e)}}return"string"==typeof r?r:function(e){const t=e.reduce(((e,t)=>e+t.length),0),n=new Uint8Array(t);let r=0;for(const t of e)n.set(t,r),r+=t.length;return n}(r)}function yt(e){let t="string"==typeof e?gt(e):e;function n(e){const n=t.subarray(0,e);return t=t.subarray(e+1),n}function r(){let e=t.indexOf(10);return e<0&&(e=t.length),JSON.parse((r=n(e),k.__SENTRY__&&k.__SENTRY__.decodePolyfill?k.__SENTRY__.decodePolyfill(r):(new TextDecoder).decode(r)));var r}const o=r(),s=[];for(;t.length;){const e=r(),t="number"==typeof e.length?e.length:void 0;s.push([e,t?n(t):r()])}return[o,s]}function vt(e){return[{type:"span"},e]}function bt(e){const t="string"==typeof e.data?gt(e.data):e.data;return[X({type:"attachment",length:t.length,filename:e.filename,content_type:e.contentType,attachment_type:e.attachmentType}),t]}const St={session:"session",sessions:"session",attachment:"attachment",transaction:"transaction",event:"error",client_report:"internal",user_report:"default",profile:"profile",profile_chunk:"profile",replay_event:"replay",replay_recording:"replay",check_in:"monitor",feedback:"feedback",span:"span",statsd:"metric_bucket"};function wt(e){return St[e]}function kt(e){if(!e||!e.sdk)return;const{name:t,version:n}=e.sdk;return{name:t,version:n}}function xt(e,t,n,r){const o=e.sdkProcessingMetadata&&e.sdkProcessingMetadata.dynamicSamplingContext;return{event_id:e.event_id,sent_at:(new Date).toISOString(),...t&&{sdk:t},...!!n&&r&&{dsn:F(r)},...o&&{trace:X({...o})}}}const Et=6e4;function Ct(e,t=Date.now()){const n=parseInt(`${e}`,10);if(!isNaN(n))return 1e3*n;const r=Date.parse(`${e}`);return isNaN(r)?Et:r-t}function Tt(e,t,n=Date.now()){return function(e,t){return e[t]||e.all||0}(e,t)>n}function It(e,{statusCode:t,headers:n},r=Date.now()){const o={...e},s=n&&n["x-sentry-rate-limits"],i=n&&n["retry-after"];if(s)for(const e of s.trim().split(",")){const[t,n,,,s]=e.split(":",5),i=parseInt(t,10),a=1e3*(isNaN(i)?60:i);if(n)for(const e of n.split(";"))"metric_bucket"===e&&s&&!s.split(";").includes("custom")||(o[e]=r+a);else o.all=r+a}else i?o.all=r+Ct(i,r):429===t&&(o.all=r+6e4);return o}function Mt(e){let t,n=e[0],r=1;for(;r<e.length;){const o=e[r],s=e[r+1];if(r+=2,("optionalAccess"===o||"optionalCall"===o)&&null==n)return;"access"===o||"optionalAccess"===o?(t=n,n=s(n)):"call"!==o&&"optionalCall"!==o||(n=s(((...e)=>n.call(t,...e))),t=void 0)}return n}function Rt(){return{traceId:De(),spanId:De().substring(16)}}const At=k;const Nt="undefined"==typeof __SENTRY_DEBUG__||__SENTRY_DEBUG__;function Ot(){return Dt(k),k}function Dt(e){const t=e.__SENTRY__=e.__SENTRY__||{};return t.version=t.version||w,t[w]=t[w]||{}}function Lt(e){const t=ve(),n={sid:De(),init:!0,timestamp:t,started:t,duration:0,status:"ok",errors:0,ignoreDuration:!1,toJSON:()=>function(e){return X({sid:`${e.sid}`,init:e.init,started:new Date(1e3*e.started).toISOString(),timestamp:new Date(1e3*e.timestamp).toISOString(),status:e.status,errors:e.errors,did:"number"==typeof e.did||"string"==typeof e.did?`${e.did}`:void 0,duration:e.duration,abnormal_mechanism:e.abnormal_mechanism,attrs:{release:e.release,environment:e.environment,ip_address:e.ipAddress,user_agent:e.userAgent}})}(n)};return e&&Pt(n,e),n}function Pt(e,t={}){if(t.user&&(!e.ipAddress&&t.user.ip_address&&(e.ipAddress=t.user.ip_address),e.did||t.did||(e.did=t.user.id||t.user.email||t.user.username)),e.timestamp=t.timestamp||ve(),t.abnormal_mechanism&&(e.abnormal_mechanism=t.abnormal_mechanism),t.ignoreDuration&&(e.ignoreDuration=t.ignoreDuration),t.sid&&(e.sid=32===t.sid.length?t.sid:De()),void 0!==t.init&&(e.init=t.init),!e.did&&t.did&&(e.did=`${t.did}`),"number"==typeof t.started&&(e.started=t.started),e.ignoreDuration)e.duration=void 0;else if("number"==typeof t.duration)e.duration=t.duration;else{const t=e.timestamp-e.started;e.duration=t>=0?t:0}t.release&&(e.release=t.release),t.environment&&(e.environment=t.environment),!e.ipAddress&&t.ipAddress&&(e.ipAddress=t.ipAddress),!e.userAgent&&t.userAgent&&(e.userAgent=t.userAgent),"number"==typeof t.errors&&(e.errors=t.errors),t.status&&(e.status=t.status)}const Ft="_sentrySpan";function $t(e,t){t?z(e,Ft,t):delete e[Ft]}function Bt(e){return e[Ft]}class Ut{constructor(){this._notifyingListeners=!1,this._scopeListeners=[],this._eventProcessors=[],this._breadcrumbs=[],this._attachments=[],this._user={},this._tags={},this._extra={},this._contexts={},this._sdkProcessingMetadata={},this._propagationContext=Rt()}clone(){const e=new Ut;return e._breadcrumbs=[...this._breadcrumbs],e._tags={...this._tags},e._extra={...this._extra},e._contexts={...this._contexts},e._user=this._user,e._level=this._level,e._session=this._session,e._transactionName=this._transactionName,e._fingerprint=this._fingerprint,e._eventProcessors=[...this._eventProcessors],e._requestSession=this._requestSession,e._attachments=[...this._attachments],e._sdkProcessingMetadata={...this._sdkProcessingMetadata},e._propagationContext={...this._propagationContext},e._client=this._client,e._lastEventId=this._lastEventId,$t(e,Bt(this)),e}setClient(e){this._client=e}setLastEventId(e){this._lastEventId=e}getClient(){return this._client}lastEventId(){return this._lastEventId}addScopeListener(e){this._scopeListeners.push(e)}addEventProcessor(e){return this._eventProcessors.push(e),this}setUser(e){return this._user=e||{email:void 0,id:void 0,ip_address:void 0,username:void 0},this._session&&Pt(this._session,{user:e}),this._notifyScopeListeners(),this}getUser(){return this._user}getRequestSession(){return this._requestSession}setRequestSession(e){return this._requestSession=e,this}setTags(e){return this._tags={...this._tags,...e},this._notifyScopeListeners(),this}setTag(e,t){return this._tags={...this._tags,[e]:t},this._notifyScopeListeners(),this}setExtras(e){return this._extra={...this._extra,...e},this._notifyScopeListeners(),this}setExtra(e,t){return this._extra={...this._extra,[e]:t},this._notifyScopeListeners(),this}setFingerprint(e){return this._fingerprint=e,this._notifyScopeListeners(),this}setLevel(e){return this._level=e,this._notifyScopeListeners(),this}setTransactionName(e){return this._transactionName=e,this._notifyScopeListeners(),this}setContext(e,t){return null===t?delete this._contexts[e]:this._contexts[e]=t,this._notifyScopeListeners(),this}setSession(e){return e?this._session=e:delete this._session,this._notifyScopeListeners(),this}getSession(){return this._session}update(e){if(!e)return this;const t="function"==typeof e?e(this):e,[n,r]=t instanceof jt?[t.getScopeData(),t.getRequestSession()]:c(t)?[e,e.requestSession]:[],{tags:o,extra:s,user:i,contexts:a,level:u,fingerprint:l=[],propagationContext:d}=n||{};return this._tags={...this._tags,...o},this._extra={...this._extra,...s},this._contexts={...this._contexts,...a},i&&Object.keys(i).length&&(this._user=i),u&&(this._level=u),l.length&&(this._fingerprint=l),d&&(this._propagationContext=d),r&&(this._requestSession=r),this}clear(){return this._breadcrumbs=[],this._tags={},this._extra={},this._user={},this._contexts={},this._level=void 0,this._transactionName=void 0,this._fingerprint=void 0,this._requestSession=void 0,this._session=void 0,$t(this,void 0),this._attachments=[],this._propagationContext=Rt(),this._notifyScopeListeners(),this}addBreadcrumb(e,t){const n="number"==typeof t?t:100;if(n<=0)return this;const r={timestamp:ye(),...e},o=this._breadcrumbs;return o.push(r),this._breadcrumbs=o.length>n?o.slice(-n):o,this._notifyScopeListeners(),this}getLastBreadcrumb(){return this._breadcrumbs[this._breadcrumbs.length-1]}clearBreadcrumbs(){return this._breadcrumbs=[],this._notifyScopeListeners(),this}addAttachment(e){return this._attachments.push(e),this}clearAttachments(){return this._attachments=[],this}getScopeData(){return{breadcrumbs:this._breadcrumbs,attachments:this._attachments,contexts:this._contexts,tags:this._tags,extra:this._extra,user:this._user,level:this._level,fingerprint:this._fingerprint||[],eventProcessors:this._eventProcessors,propagationContext:this._propagationContext,sdkProcessingMetadata:this._sdkProcessingMetadata,transactionName:this._transactionName,span:Bt(this)}}setSDKProcessingMetadata(e){return this._sdkProcessingMetadata={...this._sdkProcessingMetadata,...e},this}setPropagationContext(e){return this._propagationContext=e,this}getPropagationContext(){return this._propagationContext}captureException(e,t){const n=t&&t.event_id?t.event_id:De();if(!this._client)return L.warn("No client configured on scope - will not capture exception!"),n;const r=new Error("Sentry syntheticException");return this._client.captureException(e,{originalException:e,syntheticException:r,...t,event_id:n},this),n}captureMessage(e,t,n){const r=n&&n.event_id?n.event_id:De();if(!this._client)return L.warn("No client configured on scope - will not capture message!"),r;const o=new Error(e);return this._client.captureMessage(e,t,{originalException:e,syntheticException:o,...n,event_id:r},this),r}captureEvent(e,t){const n=t&&t.event_id?t.event_id:De();return this._client?(this._client.captureEvent(e,{...t,event_id:n},this),n):(L.warn("No client configured on scope - will not capture event!"),n)}_notifyScopeListeners(){this._notifyingListeners||(this._notifyingListeners=!0,this._scopeListeners.forEach((e=>{e(this)})),this._notifyingListeners=!1)}}const jt=Ut;class Ht{constructor(e,t){let n,r;n=e||new jt,r=t||new jt,this._stack=[{scope:n}],this._isolationScope=r}withScope(e){const t=this._pushScope();let n;try{n=e(t)}catch(e){throw this._popScope(),e}return l(n)?n.then((e=>(this._popScope(),e)),(e=>{throw this._popScope(),e})):(this._popScope(),n)}getClient(){return this.getStackTop().client}getScope(){return this.getStackTop().scope}getIsolationScope(){return this._isolationScope}getStackTop(){return this._stack[this._stack.length-1]}_pushScope(){const e=this.getScope().clone();return this._stack.push({client:this.getClient(),scope:e}),e}_popScope(){return!(this._stack.length<=1)&&!!this._stack.pop()}}function zt(){const e=Dt(Ot());return e.stack=e.stack||new Ht(x("defaultCurrentScope",(()=>new jt)),x("defaultIsolationScope",(()=>new jt)))}function qt(e){return zt().withScope(e)}function Wt(e,t){const n=zt();return n.withScope((()=>(n.getStackTop().scope=e,t(e))))}function Yt(e){return zt().withScope((()=>e(zt().getIsolationScope())))}function Vt(e){const t=Dt(e);return t.acs?t.acs:{withIsolationScope:Yt,withScope:qt,withSetScope:Wt,withSetIsolationScope:(e,t)=>Yt(t),getCurrentScope:()=>zt().getScope(),getIsolationScope:()=>zt().getIsolationScope()}}function Gt(){return Vt(Ot()).getCurrentScope()}function Xt(){return Vt(Ot()).getIsolationScope()}function Jt(){return x("globalScope",(()=>new jt))}function Kt(...e){const t=Vt(Ot());if(2===e.length){const[n,r]=e;return n?t.withSetScope(n,r):t.withScope(r)}return t.withScope(e[0])}function Qt(){return Gt().getClient()}const Zt="_sentryMetrics";function en(e){const t=e[Zt];if(!t)return;const n={};for(const[,[e,r]]of t){(n[e]||(n[e]=[])).push(X(r))}return n}const tn="sentry.source",nn="sentry.sample_rate",rn="sentry.op",on="sentry.origin",sn="sentry.idle_span_finish_reason",an="sentry.measurement_unit",cn="sentry.measurement_value",un="sentry.exclusive_time",ln=0,dn=1,pn=2;function hn(e){if(e<400&&e>=100)return{code:dn};if(e>=400&&e<500)switch(e){case 401:return{code:pn,message:"unauthenticated"};case 403:return{code:pn,message:"permission_denied"};case 404:return{code:pn,message:"not_found"};case 409:return{code:pn,message:"already_exists"};case 413:return{code:pn,message:"failed_precondition"};case 429:return{code:pn,message:"resource_exhausted"};case 499:return{code:pn,message:"cancelled"};default:return{code:pn,message:"invalid_argument"}}if(e>=500&&e<600)switch(e){case 501:return{code:pn,message:"unimplemented"};case 503:return{code:pn,message:"unavailable"};case 504:return{code:pn,message:"deadline_exceeded"};default:return{code:pn,message:"internal_error"}}return{code:pn,message:"unknown_error"}}function fn(e,t){e.setAttribute("http.response.status_code",t);const n=hn(t);"unknown_error"!==n.message&&e.setStatus(n)}const mn=1;function gn(e){const{spanId:t,traceId:n}=e.spanContext(),{data:r,op:o,parent_span_id:s,status:i,origin:a}=Sn(e);return X({parent_span_id:s,span_id:t,trace_id:n,data:r,op:o,status:i,origin:a})}function _n(e){const{spanId:t,traceId:n}=e.spanContext(),{parent_span_id:r}=Sn(e);return X({parent_span_id:r,span_id:t,trace_id:n})}function yn(e){const{traceId:t,spanId:n}=e.spanContext();return dt(t,n,wn(e))}function vn(e){return"number"==typeof e?bn(e):Array.isArray(e)?e[0]+e[1]/1e9:e instanceof Date?bn(e.getTime()):ve()}function bn(e){return e>9999999999?e/1e3:e}function Sn(e){if(function(e){return"function"==typeof e.getSpanJSON}(e))return e.getSpanJSON();try{const{spanId:t,traceId:n}=e.spanContext();if(function(e){const t=e;return!!(t.attributes&&t.startTime&&t.name&&t.endTime&&t.status)}(e)){const{attributes:r,startTime:o,name:s,endTime:i,parentSpanId:a,status:c}=e;return X({span_id:t,trace_id:n,data:r,description:s,parent_span_id:a,start_timestamp:vn(o),timestamp:vn(i)||void 0,status:kn(c),op:r[rn],origin:r[on],_metrics_summary:en(e)})}return{span_id:t,trace_id:n}}catch(e){return{}}}function wn(e){const{traceFlags:t}=e.spanContext();return t===mn}function kn(e){if(e&&e.code!==ln)return e.code===dn?"ok":e.message||"unknown_error"}const xn="_sentryChildSpans",En="_sentryRootSpan";function Cn(e,t){const n=e[En]||e;z(t,En,n),e[xn]?e[xn].add(t):z(e,xn,new Set([t]))}function Tn(e){const t=new Set;return function e(n){if(!t.has(n)&&wn(n)){t.add(n);const r=n[xn]?Array.from(n[xn]):[];for(const t of r)e(t)}}(e),Array.from(t)}function In(e){return e[En]||e}function Mn(){const e=Vt(Ot());return e.getActiveSpan?e.getActiveSpan():Bt(Gt())}function Rn(e,t,n,r,o,s){const i=Mn();i&&function(e,t,n,r,o,s,i){const a=e[Zt]||(e[Zt]=new Map),c=`${t}:${n}@${o}`,u=a.get(i);if(u){const[,e]=u;a.set(i,[c,{min:Math.min(e.min,r),max:Math.max(e.max,r),count:e.count+=1,sum:e.sum+=r,tags:e.tags}])}else a.set(i,[c,{min:r,max:r,count:1,sum:r,tags:s}])}(i,e,t,n,r,o,s)}let An=!1;function Nn(){An||(An=!0,Te(On),Re(On))}function On(){const e=Mn(),t=e&&In(e);if(t){const e="internal_error";Nt&&L.log(`[Tracing] Root span: ${e} -> Global error occured`),t.setStatus({code:pn,message:e})}}On.tag="sentry_tracingErrorCallback";const Dn="_sentryScope",Ln="_sentryIsolationScope";function Pn(e){return{scope:e[Dn],isolationScope:e[Ln]}}function Fn(e){if("boolean"==typeof __SENTRY_TRACING__&&!__SENTRY_TRACING__)return!1;const t=Qt(),n=e||t&&t.getOptions();return!!n&&(n.enableTracing||"tracesSampleRate"in n||"tracesSampler"in n)}class $n{constructor(e={}){this._traceId=e.traceId||De(),this._spanId=e.spanId||De().substring(16)}spanContext(){return{spanId:this._spanId,traceId:this._traceId,traceFlags:0}}end(e){}setAttribute(e,t){return this}setAttributes(e){return this}setStatus(e){return this}updateName(e){return this}isRecording(){return!1}addEvent(e,t,n){return this}addLink(e){return this}addLinks(e){return this}recordException(e,t){}}function Bn(e,t,n=()=>{}){let r;try{r=e()}catch(e){throw t(e),n(),e}return function(e,t,n){if(l(e))return e.then((e=>(n(),e)),(e=>{throw t(e),n(),e}));return n(),e}(r,t,n)}const Un="production",jn="_frozenDsc";function Hn(e,t){z(e,jn,t)}function zn(e,t){const n=t.getOptions(),{publicKey:r}=t.getDsn()||{},o=X({environment:n.environment||Un,release:n.release,public_key:r,trace_id:e});return t.emit("createDsc",o),o}function qn(e){const t=Qt();if(!t)return{};const n=zn(Sn(e).trace_id||"",t),r=In(e),o=r[jn];if(o)return o;const s=r.spanContext().traceState,i=s&&s.get("sentry.dsc"),a=i&&it(i);if(a)return a;const c=Sn(r),u=c.data||{},l=u[nn];null!=l&&(n.sample_rate=`${l}`);const d=u[tn],p=c.description;return"url"!==d&&p&&(n.transaction=p),n.sampled=String(wn(r)),t.emit("createDsc",n,r),n}function Wn(e){if("boolean"==typeof e)return Number(e);const t="string"==typeof e?parseFloat(e):e;if(!("number"!=typeof t||isNaN(t)||t<0||t>1))return t;Nt&&L.warn(`[Tracing] Given sample rate is invalid. Sample rate must be a boolean or a number between 0 and 1. Got ${JSON.stringify(e)} of type ${JSON.stringify(typeof e)}.`)}function Yn(e,t,n,r=Mn()){const o=r&&In(r);o&&o.addEvent(e,{[cn]:t,[an]:n})}function Vn(e){if(!e||0===e.length)return;const t={};return e.forEach((e=>{const n=e.attributes||{},r=n[an],o=n[cn];"string"==typeof r&&"number"==typeof o&&(t[e.name]={value:o,unit:r})})),t}class Gn{constructor(e={}){this._traceId=e.traceId||De(),this._spanId=e.spanId||De().substring(16),this._startTime=e.startTimestamp||ve(),this._attributes={},this.setAttributes({[on]:"manual",[rn]:e.op,...e.attributes}),this._name=e.name,e.parentSpanId&&(this._parentSpanId=e.parentSpanId),"sampled"in e&&(this._sampled=e.sampled),e.endTimestamp&&(this._endTime=e.endTimestamp),this._events=[],this._isStandaloneSpan=e.isStandalone,this._endTime&&this._onSpanEnded()}addLink(e){return this}addLinks(e){return this}recordException(e,t){}spanContext(){const{_spanId:e,_traceId:t,_sampled:n}=this;return{spanId:e,traceId:t,traceFlags:n?mn:0}}setAttribute(e,t){return void 0===t?delete this._attributes[e]:this._attributes[e]=t,this}setAttributes(e){return Object.keys(e).forEach((t=>this.setAttribute(t,e[t]))),this}updateStartTime(e){this._startTime=vn(e)}setStatus(e){return this._status=e,this}updateName(e){return this._name=e,this}end(e){this._endTime||(this._endTime=vn(e),function(e){if(!Nt)return;const{description:t="< unknown name >",op:n="< unknown op >"}=Sn(e),{spanId:r}=e.spanContext(),o=`[Tracing] Finishing "${n}" ${In(e)===e?"root ":""}span "${t}" with ID ${r}`;L.log(o)}(this),this._onSpanEnded())}getSpanJSON(){return X({data:this._attributes,description:this._name,op:this._attributes[rn],parent_span_id:this._parentSpanId,span_id:this._spanId,start_timestamp:this._startTime,status:kn(this._status),timestamp:this._endTime,trace_id:this._traceId,origin:this._attributes[on],_metrics_summary:en(this),profile_id:this._attributes["sentry.profile_id"],exclusive_time:this._attributes[un],measurements:Vn(this._events),is_segment:this._isStandaloneSpan&&In(this)===this||void 0,segment_id:this._isStandaloneSpan?In(this).spanContext().spanId:void 0})}isRecording(){return!this._endTime&&!!this._sampled}addEvent(e,t,n){Nt&&L.log("[Tracing] Adding an event to span:",e);const r=Xn(t)?t:n||ve(),o=Xn(t)?{}:t||{},s={name:e,time:vn(r),attributes:o};return this._events.push(s),this}isStandaloneSpan(){return!!this._isStandaloneSpan}_onSpanEnded(){const e=Qt();e&&e.emit("spanEnd",this);if(!(this._isStandaloneSpan||this===In(this)))return;if(this._isStandaloneSpan)return void(this._sampled?function(e){const t=Qt();if(!t)return;const n=e[1];if(!n||0===n.length)return void t.recordDroppedEvent("before_send","span");const r=t.getTransport();r&&r.send(e).then(null,(e=>{Nt&&L.error("Error while sending span:",e)}))}(function(e,t){const n=qn(e[0]),r=t&&t.getDsn(),o=t&&t.getOptions().tunnel,s={sent_at:(new Date).toISOString(),...function(e){return!!e.trace_id&&!!e.public_key}(n)&&{trace:n},...!!o&&r&&{dsn:F(r)}},i=t&&t.getOptions().beforeSendSpan,a=i?e=>i(Sn(e)):e=>Sn(e),c=[];for(const t of e){const e=a(t);e&&c.push(vt(e))}return pt(s,c)}([this],e)):(Nt&&L.log("[Tracing] Discarding standalone span because its trace was not chosen to be sampled."),e&&e.recordDroppedEvent("sample_rate","span")));const t=this._convertSpanToTransaction();if(t){(Pn(this).scope||Gt()).captureEvent(t)}}_convertSpanToTransaction(){if(!Jn(Sn(this)))return;this._name||(Nt&&L.warn("Transaction has no name, falling back to `<unlabeled transaction>`."),this._name="<unlabeled transaction>");const{scope:e,isolationScope:t}=Pn(this),n=(e||Gt()).getClient()||Qt();if(!0!==this._sampled)return Nt&&L.log("[Tracing] Discarding transaction because its trace was not chosen to be sampled."),void(n&&n.recordDroppedEvent("sample_rate","transaction"));const r=Tn(this).filter((e=>e!==this&&!function(e){return e instanceof Gn&&e.isStandaloneSpan()}(e))).map((e=>Sn(e))).filter(Jn),o=this._attributes[tn],s={contexts:{trace:gn(this)},spans:r.length>1e3?r.sort(((e,t)=>e.start_timestamp-t.start_timestamp)).slice(0,1e3):r,start_timestamp:this._startTime,timestamp:this._endTime,transaction:this._name,type:"transaction",sdkProcessingMetadata:{capturedSpanScope:e,capturedSpanIsolationScope:t,...X({dynamicSamplingContext:qn(this)})},_metrics_summary:en(this),...o&&{transaction_info:{source:o}}},i=Vn(this._events);return i&&Object.keys(i).length&&(Nt&&L.log("[Measurements] Adding measurements to transaction event",JSON.stringify(i,void 0,2)),s.measurements=i),s}}function Xn(e){return e&&"number"==typeof e||e instanceof Date||Array.isArray(e)}function Jn(e){return!!(e.start_timestamp&&e.timestamp&&e.span_id&&e.trace_id)}const Kn="__SENTRY_SUPPRESS_TRACING__";function Qn(e,t){const n=rr();if(n.startSpanManual)return n.startSpanManual(e,t);const r=nr(e),{forceTransaction:o,parentSpan:s}=e;return Kt(e.scope,(()=>ir(s)((()=>{const n=Gt(),s=sr(n),i=e.onlyIfParent&&!s?new $n:tr({parentSpan:s,spanArguments:r,forceTransaction:o,scope:n});function a(){i.end()}return $t(n,i),Bn((()=>t(i,a)),(()=>{const{status:e}=Sn(i);!i.isRecording()||e&&"ok"!==e||i.setStatus({code:pn,message:"internal_error"})}))}))))}function Zn(e){const t=rr();if(t.startInactiveSpan)return t.startInactiveSpan(e);const n=nr(e),{forceTransaction:r,parentSpan:o}=e;return(e.scope?t=>Kt(e.scope,t):void 0!==o?e=>er(o,e):e=>e())((()=>{const t=Gt(),o=sr(t);return e.onlyIfParent&&!o?new $n:tr({parentSpan:o,spanArguments:n,forceTransaction:r,scope:t})}))}function er(e,t){const n=rr();return n.withActiveSpan?n.withActiveSpan(e,t):Kt((n=>($t(n,e||void 0),t(n))))}function tr({parentSpan:e,spanArguments:t,forceTransaction:n,scope:r}){if(!Fn())return new $n;const o=Xt();let s;if(e&&!n)s=function(e,t,n){const{spanId:r,traceId:o}=e.spanContext(),s=!t.getScopeData().sdkProcessingMetadata[Kn]&&wn(e),i=s?new Gn({...n,parentSpanId:r,traceId:o,sampled:s}):new $n({traceId:o});Cn(e,i);const a=Qt();a&&(a.emit("spanStart",i),n.endTimestamp&&a.emit("spanEnd",i));return i}(e,r,t),Cn(e,s);else if(e){const n=qn(e),{traceId:o,spanId:i}=e.spanContext(),a=wn(e);s=or({traceId:o,parentSpanId:i,...t},r,a),Hn(s,n)}else{const{traceId:e,dsc:n,parentSpanId:i,sampled:a}={...o.getPropagationContext(),...r.getPropagationContext()};s=or({traceId:e,parentSpanId:i,...t},r,a),n&&Hn(s,n)}return function(e){if(!Nt)return;const{description:t="< unknown name >",op:n="< unknown op >",parent_span_id:r}=Sn(e),{spanId:o}=e.spanContext(),s=wn(e),i=In(e),a=i===e,c=`[Tracing] Starting ${s?"sampled":"unsampled"} ${a?"root ":""}span`,u=[`op: ${n}`,`name: ${t}`,`ID: ${o}`];if(r&&u.push(`parent ID: ${r}`),!a){const{op:e,description:t}=Sn(i);u.push(`root ID: ${i.spanContext().spanId}`),e&&u.push(`root op: ${e}`),t&&u.push(`root description: ${t}`)}L.log(`${c}\n  ${u.join("\n  ")}`)}(s),function(e,t,n){e&&(z(e,Ln,n),z(e,Dn,t))}(s,r,o),s}function nr(e){const t={isStandalone:(e.experimental||{}).standalone,...e};if(e.startTime){const n={...t};return n.startTimestamp=vn(e.startTime),delete n.startTime,n}return t}function rr(){return Vt(Ot())}function or(e,t,n){const r=Qt(),o=r&&r.getOptions()||{},{name:s="",attributes:i}=e,[a,c]=t.getScopeData().sdkProcessingMetadata[Kn]?[!1]:function(e,t){if(!Fn(e))return[!1];let n;n="function"==typeof e.tracesSampler?e.tracesSampler(t):void 0!==t.parentSampled?t.parentSampled:void 0!==e.tracesSampleRate?e.tracesSampleRate:1;const r=Wn(n);return void 0===r?(Nt&&L.warn("[Tracing] Discarding transaction because of invalid sample rate."),[!1]):r?Math.random()<r?[!0,r]:(Nt&&L.log(`[Tracing] Discarding transaction because it's not included in the random sample (sampling rate = ${Number(n)})`),[!1,r]):(Nt&&L.log("[Tracing] Discarding transaction because "+("function"==typeof e.tracesSampler?"tracesSampler returned 0 or false":"a negative sampling decision was inherited or tracesSampleRate is set to 0")),[!1,r])}(o,{name:s,parentSampled:n,attributes:i,transactionContext:{name:s,parentSampled:n}}),u=new Gn({...e,attributes:{[tn]:"custom",...e.attributes},sampled:a});return void 0!==c&&u.setAttribute(nn,c),r&&r.emit("spanStart",u),u}function sr(e){const t=Bt(e);if(!t)return;const n=Qt();return(n?n.getOptions():{}).parentSpanIsAlwaysRootSpan?In(t):t}function ir(e){return void 0!==e?t=>er(e,t):e=>e()}const ar={idleTimeout:1e3,finalTimeout:3e4,childSpanTimeout:15e3},cr="heartbeatFailed",ur="idleTimeout",lr="finalTimeout",dr="externalFinish";function pr(e,t={}){const n=new Map;let r,o=!1,s=dr,i=!t.disableAutoFinish;const a=[],{idleTimeout:c=ar.idleTimeout,finalTimeout:u=ar.finalTimeout,childSpanTimeout:l=ar.childSpanTimeout,beforeSpanEnd:d}=t,p=Qt();if(!p||!Fn())return new $n;const h=Gt(),f=Mn(),m=function(e){const t=Zn(e);return $t(Gt(),t),Nt&&L.log("[Tracing] Started span is an idle span"),t}(e);function g(){r&&(clearTimeout(r),r=void 0)}function _(e){g(),r=setTimeout((()=>{!o&&0===n.size&&i&&(s=ur,m.end(e))}),c)}function y(e){r=setTimeout((()=>{!o&&i&&(s=cr,m.end(e))}),l)}function v(e){o=!0,n.clear(),a.forEach((e=>e())),$t(h,f);const t=Sn(m),{start_timestamp:r}=t;if(!r)return;(t.data||{})[sn]||m.setAttribute(sn,s),L.log(`[Tracing] Idle span "${t.op}" finished`);const i=Tn(m).filter((e=>e!==m));let l=0;i.forEach((t=>{t.isRecording()&&(t.setStatus({code:pn,message:"cancelled"}),t.end(e),Nt&&L.log("[Tracing] Cancelling span since span ended early",JSON.stringify(t,void 0,2)));const n=Sn(t),{timestamp:r=0,start_timestamp:o=0}=n,s=o<=e,i=r-o<=(u+c)/1e3;if(Nt){const e=JSON.stringify(t,void 0,2);s?i||L.log("[Tracing] Discarding span since it finished after idle span final timeout",e):L.log("[Tracing] Discarding span since it happened after idle span was finished",e)}i&&s||(!function(e,t){e[xn]&&e[xn].delete(t)}(m,t),l++)})),l>0&&m.setAttribute("sentry.idle_span_discarded_spans",l)}return m.end=new Proxy(m.end,{apply(e,t,n){d&&d(m);const[r,...o]=n,s=vn(r||ve()),i=Tn(m).filter((e=>e!==m));if(!i.length)return v(s),Reflect.apply(e,t,[s,...o]);const a=i.map((e=>Sn(e).timestamp)).filter((e=>!!e)),c=a.length?Math.max(...a):void 0,l=Sn(m).start_timestamp,p=Math.min(l?l+u/1e3:1/0,Math.max(l||-1/0,Math.min(s,c||1/0)));return v(p),Reflect.apply(e,t,[p,...o])}}),a.push(p.on("spanStart",(e=>{if(o||e===m||Sn(e).timestamp)return;var t;Tn(m).includes(e)&&(t=e.spanContext().spanId,g(),n.set(t,!0),y(ve()+l/1e3))}))),a.push(p.on("spanEnd",(e=>{var t;o||(t=e.spanContext().spanId,n.has(t)&&n.delete(t),0===n.size&&_(ve()+c/1e3))}))),a.push(p.on("idleSpanEnableAutoFinish",(e=>{e===m&&(i=!0,_(),n.size&&y())}))),t.disableAutoFinish||_(),setTimeout((()=>{o||(m.setStatus({code:pn,message:"deadline_exceeded"}),s=lr,m.end())}),u),m}function hr(e,t,n,r=0){return new Ke(((o,s)=>{const i=e[r];if(null===t||"function"!=typeof i)o(t);else{const a=i({...t},n);Nt&&i.id&&null===a&&L.log(`Event processor "${i.id}" dropped event`),l(a)?a.then((t=>hr(e,t,n,r+1).then(o))).then(null,s):hr(e,a,n,r+1).then(o).then(null,s)}}))}function fr(e,t){const{fingerprint:n,span:r,breadcrumbs:o,sdkProcessingMetadata:s}=t;!function(e,t){const{extra:n,tags:r,user:o,contexts:s,level:i,transactionName:a}=t,c=X(n);c&&Object.keys(c).length&&(e.extra={...c,...e.extra});const u=X(r);u&&Object.keys(u).length&&(e.tags={...u,...e.tags});const l=X(o);l&&Object.keys(l).length&&(e.user={...l,...e.user});const d=X(s);d&&Object.keys(d).length&&(e.contexts={...d,...e.contexts});i&&(e.level=i);a&&"transaction"!==e.type&&(e.transaction=a)}(e,t),r&&function(e,t){e.contexts={trace:_n(t),...e.contexts},e.sdkProcessingMetadata={dynamicSamplingContext:qn(t),...e.sdkProcessingMetadata};const n=In(t),r=Sn(n).description;r&&!e.transaction&&"transaction"===e.type&&(e.transaction=r)}(e,r),function(e,t){e.fingerprint=e.fingerprint?Ue(e.fingerprint):[],t&&(e.fingerprint=e.fingerprint.concat(t));e.fingerprint&&!e.fingerprint.length&&delete e.fingerprint}(e,n),function(e,t){const n=[...e.breadcrumbs||[],...t];e.breadcrumbs=n.length?n:void 0}(e,o),function(e,t){e.sdkProcessingMetadata={...e.sdkProcessingMetadata,...t}}(e,s)}function mr(e,t){const{extra:n,tags:r,user:o,contexts:s,level:i,sdkProcessingMetadata:a,breadcrumbs:c,fingerprint:u,eventProcessors:l,attachments:d,propagationContext:p,transactionName:h,span:f}=t;gr(e,"extra",n),gr(e,"tags",r),gr(e,"user",o),gr(e,"contexts",s),gr(e,"sdkProcessingMetadata",a),i&&(e.level=i),h&&(e.transactionName=h),f&&(e.span=f),c.length&&(e.breadcrumbs=[...e.breadcrumbs,...c]),u.length&&(e.fingerprint=[...e.fingerprint,...u]),l.length&&(e.eventProcessors=[...e.eventProcessors,...l]),d.length&&(e.attachments=[...e.attachments,...d]),e.propagationContext={...e.propagationContext,...p}}function gr(e,t,n){if(n&&Object.keys(n).length){e[t]={...e[t]};for(const r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[t][r]=n[r])}}function _r(e,t,n,r,o,s){const{normalizeDepth:i=3,normalizeMaxBreadth:a=1e3}=e,c={...t,event_id:t.event_id||n.event_id||De(),timestamp:t.timestamp||ye()},u=n.integrations||e.integrations.map((e=>e.name));!function(e,t){const{environment:n,release:r,dist:o,maxValueLength:s=250}=t;"environment"in e||(e.environment="environment"in t?n:Un);void 0===e.release&&void 0!==r&&(e.release=r);void 0===e.dist&&void 0!==o&&(e.dist=o);e.message&&(e.message=h(e.message,s));const i=e.exception&&e.exception.values&&e.exception.values[0];i&&i.value&&(i.value=h(i.value,s));const a=e.request;a&&a.url&&(a.url=h(a.url,s))}(c,e),function(e,t){t.length>0&&(e.sdk=e.sdk||{},e.sdk.integrations=[...e.sdk.integrations||[],...t])}(c,u),o&&o.emit("applyFrameMetadata",t),void 0===t.type&&function(e,t){const n=k._sentryDebugIds;if(!n)return;let r;const o=yr.get(t);o?r=o:(r=new Map,yr.set(t,r));const s=Object.entries(n).reduce(((e,[n,o])=>{let s;const i=r.get(n);i?s=i:(s=t(n),r.set(n,s));for(let t=s.length-1;t>=0;t--){const n=s[t];if(n.filename){e[n.filename]=o;break}}return e}),{});try{e.exception.values.forEach((e=>{e.stacktrace.frames.forEach((e=>{e.filename&&(e.debug_id=s[e.filename])}))}))}catch(e){}}(c,e.stackParser);const l=function(e,t){if(!t)return e;const n=e?e.clone():new jt;return n.update(t),n}(r,n.captureContext);n.mechanism&&$e(c,n.mechanism);const d=o?o.getEventProcessors():[],p=Jt().getScopeData();if(s){mr(p,s.getScopeData())}if(l){mr(p,l.getScopeData())}const f=[...n.attachments||[],...p.attachments];f.length&&(n.attachments=f),fr(c,p);return hr([...d,...p.eventProcessors],c,n).then((e=>(e&&function(e){const t={};try{e.exception.values.forEach((e=>{e.stacktrace.frames.forEach((e=>{e.debug_id&&(e.abs_path?t[e.abs_path]=e.debug_id:e.filename&&(t[e.filename]=e.debug_id),delete e.debug_id)}))}))}catch(e){}if(0===Object.keys(t).length)return;e.debug_meta=e.debug_meta||{},e.debug_meta.images=e.debug_meta.images||[];const n=e.debug_meta.images;Object.entries(t).forEach((([e,t])=>{n.push({type:"sourcemap",code_file:e,debug_id:t})}))}(e),"number"==typeof i&&i>0?function(e,t,n){if(!e)return null;const r={...e,...e.breadcrumbs&&{breadcrumbs:e.breadcrumbs.map((e=>({...e,...e.data&&{data:je(e.data,t,n)}})))},...e.user&&{user:je(e.user,t,n)},...e.contexts&&{contexts:je(e.contexts,t,n)},...e.extra&&{extra:je(e.extra,t,n)}};e.contexts&&e.contexts.trace&&r.contexts&&(r.contexts.trace=e.contexts.trace,e.contexts.trace.data&&(r.contexts.trace.data=je(e.contexts.trace.data,t,n)));e.spans&&(r.spans=e.spans.map((e=>({...e,...e.data&&{data:je(e.data,t,n)}}))));return r}(e,i,a):e)))}const yr=new WeakMap;function vr(e){if(e)return function(e){return e instanceof jt||"function"==typeof e}(e)||function(e){return Object.keys(e).some((e=>br.includes(e)))}(e)?{captureContext:e}:e}const br=["user","level","extra","contexts","tags","fingerprint","requestSession","propagationContext"];function Sr(e,t){return Gt().captureException(e,vr(t))}function wr(e,t){const n="string"==typeof t?t:void 0,r="string"!=typeof t?{captureContext:t}:void 0;return Gt().captureMessage(e,n,r)}function kr(e,t){return Gt().captureEvent(e,t)}function xr(e,t){Xt().setContext(e,t)}function Er(e){Xt().setExtras(e)}function Cr(e,t){Xt().setExtra(e,t)}function Tr(e){Xt().setTags(e)}function Ir(e,t){Xt().setTag(e,t)}function Mr(e){Xt().setUser(e)}function Rr(){return Xt().lastEventId()}function Ar(e){Xt().addEventProcessor(e)}function Nr(e){const t=Qt(),n=Xt(),r=Gt(),{release:o,environment:s=Un}=t&&t.getOptions()||{},{userAgent:i}=k.navigator||{},a=Lt({release:o,environment:s,user:r.getUser()||n.getUser(),...i&&{userAgent:i},...e}),c=n.getSession();return c&&"ok"===c.status&&Pt(c,{status:"exited"}),Or(),n.setSession(a),r.setSession(a),a}function Or(){const e=Xt(),t=Gt(),n=t.getSession()||e.getSession();n&&function(e,t){let n={};t?n={status:t}:"ok"===e.status&&(n={status:"exited"}),Pt(e,n)}(n),Dr(),e.setSession(),t.setSession()}function Dr(){const e=Xt(),t=Gt(),n=Qt(),r=t.getSession()||e.getSession();r&&n&&n.captureSession(r)}function Lr(e=!1){e?Or():Dr()}const Pr="7";function Fr(e){const t=e.protocol?`${e.protocol}:`:"",n=e.port?`:${e.port}`:"";return`${t}//${e.host}${n}${e.path?`/${e.path}`:""}/api/`}function $r(e,t){return n={sentry_key:e.publicKey,sentry_version:Pr,...t&&{sentry_client:`${t.name}/${t.version}`}},Object.keys(n).map((e=>`${encodeURIComponent(e)}=${encodeURIComponent(n[e])}`)).join("&");var n}function Br(e,t,n){return t||`${function(e){return`${Fr(e)}${e.projectId}/envelope/`}(e)}?${$r(e,n)}`}const Ur=[];function jr(e){const t=e.defaultIntegrations||[],n=e.integrations;let r;t.forEach((e=>{e.isDefaultInstance=!0})),r=Array.isArray(n)?[...t,...n]:"function"==typeof n?Ue(n(t)):t;const o=function(e){const t={};return e.forEach((e=>{const{name:n}=e,r=t[n];r&&!r.isDefaultInstance&&e.isDefaultInstance||(t[n]=e)})),Object.values(t)}(r),s=o.findIndex((e=>"Debug"===e.name));if(s>-1){const[e]=o.splice(s,1);o.push(e)}return o}function Hr(e,t){for(const n of t)n&&n.afterAllSetup&&n.afterAllSetup(e)}function zr(e,t,n){if(n[t.name])Nt&&L.log(`Integration skipped because it was already installed: ${t.name}`);else{if(n[t.name]=t,-1===Ur.indexOf(t.name)&&"function"==typeof t.setupOnce&&(t.setupOnce(),Ur.push(t.name)),t.setup&&"function"==typeof t.setup&&t.setup(e),"function"==typeof t.preprocessEvent){const n=t.preprocessEvent.bind(t);e.on("preprocessEvent",((t,r)=>n(t,r,e)))}if("function"==typeof t.processEvent){const n=t.processEvent.bind(t),r=Object.assign(((t,r)=>n(t,r,e)),{id:t.name});e.addEventProcessor(r)}Nt&&L.log(`Integration installed: ${t.name}`)}}const qr="Not capturing exception because it's already been captured.";class Wr{constructor(e){if(this._options=e,this._integrations={},this._numProcessing=0,this._outcomes={},this._hooks={},this._eventProcessors=[],e.dsn?this._dsn=U(e.dsn):Nt&&L.warn("No DSN provided, client will not send events."),this._dsn){const t=Br(this._dsn,e.tunnel,e._metadata?e._metadata.sdk:void 0);this._transport=e.transport({tunnel:this._options.tunnel,recordDroppedEvent:this.recordDroppedEvent.bind(this),...e.transportOptions,url:t})}}captureException(e,t,n){const r=De();if(Be(e))return Nt&&L.log(qr),r;const o={event_id:r,...t};return this._process(this.eventFromException(e,o).then((e=>this._captureEvent(e,o,n)))),o.event_id}captureMessage(e,t,n,r){const o={event_id:De(),...n},s=i(e)?e:String(e),c=a(e)?this.eventFromMessage(s,t,o):this.eventFromException(e,o);return this._process(c.then((e=>this._captureEvent(e,o,r)))),o.event_id}captureEvent(e,t,n){const r=De();if(t&&t.originalException&&Be(t.originalException))return Nt&&L.log(qr),r;const o={event_id:r,...t},s=(e.sdkProcessingMetadata||{}).capturedSpanScope;return this._process(this._captureEvent(e,o,s||n)),o.event_id}captureSession(e){"string"!=typeof e.release?Nt&&L.warn("Discarded session because of missing or non-string release"):(this.sendSession(e),Pt(e,{init:!1}))}getDsn(){return this._dsn}getOptions(){return this._options}getSdkMetadata(){return this._options._metadata}getTransport(){return this._transport}flush(e){const t=this._transport;return t?(this.emit("flush"),this._isClientDoneProcessing(e).then((n=>t.flush(e).then((e=>n&&e))))):Xe(!0)}close(e){return this.flush(e).then((e=>(this.getOptions().enabled=!1,this.emit("close"),e)))}getEventProcessors(){return this._eventProcessors}addEventProcessor(e){this._eventProcessors.push(e)}init(){(this._isEnabled()||this._options.integrations.some((({name:e})=>e.startsWith("Spotlight"))))&&this._setupIntegrations()}getIntegrationByName(e){return this._integrations[e]}addIntegration(e){const t=this._integrations[e.name];zr(this,e,this._integrations),t||Hr(this,[e])}sendEvent(e,t={}){this.emit("beforeSendEvent",e,t);let n=function(e,t,n,r){const o=kt(n),s=e.type&&"replay_event"!==e.type?e.type:"event";!function(e,t){t&&(e.sdk=e.sdk||{},e.sdk.name=e.sdk.name||t.name,e.sdk.version=e.sdk.version||t.version,e.sdk.integrations=[...e.sdk.integrations||[],...t.integrations||[]],e.sdk.packages=[...e.sdk.packages||[],...t.packages||[]])}(e,n&&n.sdk);const i=xt(e,o,r,t);return delete e.sdkProcessingMetadata,pt(i,[[{type:s},e]])}(e,this._dsn,this._options._metadata,this._options.tunnel);for(const e of t.attachments||[])n=ht(n,bt(e));const r=this.sendEnvelope(n);r&&r.then((t=>this.emit("afterSendEvent",e,t)),null)}sendSession(e){const t=function(e,t,n,r){const o=kt(n);return pt({sent_at:(new Date).toISOString(),...o&&{sdk:o},...!!r&&t&&{dsn:F(t)}},["aggregates"in e?[{type:"sessions"},e]:[{type:"session"},e.toJSON()]])}(e,this._dsn,this._options._metadata,this._options.tunnel);this.sendEnvelope(t)}recordDroppedEvent(e,t,n){if(this._options.sendClientReports){const r="number"==typeof n?n:1,o=`${e}:${t}`;Nt&&L.log(`Recording outcome: "${o}"${r>1?` (${r} times)`:""}`),this._outcomes[o]=(this._outcomes[o]||0)+r}}on(e,t){const n=this._hooks[e]=this._hooks[e]||[];return n.push(t),()=>{const e=n.indexOf(t);e>-1&&n.splice(e,1)}}emit(e,...t){const n=this._hooks[e];n&&n.forEach((e=>e(...t)))}sendEnvelope(e){return this.emit("beforeEnvelope",e),this._isEnabled()&&this._transport?this._transport.send(e).then(null,(e=>(Nt&&L.error("Error while sending event:",e),e))):(Nt&&L.error("Transport disabled"),Xe({}))}_setupIntegrations(){const{integrations:e}=this._options;this._integrations=function(e,t){const n={};return t.forEach((t=>{t&&zr(e,t,n)})),n}(this,e),Hr(this,e)}_updateSessionFromEvent(e,t){let n=!1,r=!1;const o=t.exception&&t.exception.values;if(o){r=!0;for(const e of o){const t=e.mechanism;if(t&&!1===t.handled){n=!0;break}}}const s="ok"===e.status;(s&&0===e.errors||s&&n)&&(Pt(e,{...n&&{status:"crashed"},errors:e.errors||Number(r||n)}),this.captureSession(e))}_isClientDoneProcessing(e){return new Ke((t=>{let n=0;const r=setInterval((()=>{0==this._numProcessing?(clearInterval(r),t(!0)):(n+=1,e&&n>=e&&(clearInterval(r),t(!1)))}),1)}))}_isEnabled(){return!1!==this.getOptions().enabled&&void 0!==this._transport}_prepareEvent(e,t,n,r=Xt()){const o=this.getOptions(),s=Object.keys(this._integrations);return!t.integrations&&s.length>0&&(t.integrations=s),this.emit("preprocessEvent",e,t),e.type||r.setLastEventId(e.event_id||t.event_id),_r(o,e,t,n,this,r).then((e=>{if(null===e)return e;const t={...r.getPropagationContext(),...n?n.getPropagationContext():void 0};if(!(e.contexts&&e.contexts.trace)&&t){const{traceId:n,spanId:r,parentSpanId:o,dsc:s}=t;e.contexts={trace:X({trace_id:n,span_id:r,parent_span_id:o}),...e.contexts};const i=s||zn(n,this);e.sdkProcessingMetadata={dynamicSamplingContext:i,...e.sdkProcessingMetadata}}return e}))}_captureEvent(e,t={},n){return this._processEvent(e,t,n).then((e=>e.event_id),(e=>{if(Nt){const t=e;"log"===t.logLevel?L.log(t.message):L.warn(t)}}))}_processEvent(e,t,n){const r=this.getOptions(),{sampleRate:o}=r,s=Vr(e),i=Yr(e),a=e.type||"error",u=`before send for type \`${a}\``,d=void 0===o?void 0:Wn(o);if(i&&"number"==typeof d&&Math.random()>d)return this.recordDroppedEvent("sample_rate","error",e),Je(new j(`Discarding event because it's not included in the random sample (sampling rate = ${o})`,"log"));const p="replay_event"===a?"replay":a,h=(e.sdkProcessingMetadata||{}).capturedSpanIsolationScope;return this._prepareEvent(e,t,n,h).then((n=>{if(null===n)throw this.recordDroppedEvent("event_processor",p,e),new j("An event processor returned `null`, will not send event.","log");if(t.data&&!0===t.data.__sentry__)return n;const o=function(e,t,n,r){const{beforeSend:o,beforeSendTransaction:s,beforeSendSpan:i}=t;if(Yr(n)&&o)return o(n,r);if(Vr(n)){if(n.spans&&i){const t=[];for(const r of n.spans){const n=i(r);n?t.push(n):e.recordDroppedEvent("before_send","span")}n.spans=t}if(s){if(n.spans){const e=n.spans.length;n.sdkProcessingMetadata={...n.sdkProcessingMetadata,spanCountBeforeProcessing:e}}return s(n,r)}}return n}(this,r,n,t);return function(e,t){const n=`${t} must return \`null\` or a valid event.`;if(l(e))return e.then((e=>{if(!c(e)&&null!==e)throw new j(n);return e}),(e=>{throw new j(`${t} rejected with ${e}`)}));if(!c(e)&&null!==e)throw new j(n);return e}(o,u)})).then((r=>{if(null===r){if(this.recordDroppedEvent("before_send",p,e),s){const t=1+(e.spans||[]).length;this.recordDroppedEvent("before_send","span",t)}throw new j(`${u} returned \`null\`, will not send event.`,"log")}const o=n&&n.getSession();if(!s&&o&&this._updateSessionFromEvent(o,r),s){const e=(r.sdkProcessingMetadata&&r.sdkProcessingMetadata.spanCountBeforeProcessing||0)-(r.spans?r.spans.length:0);e>0&&this.recordDroppedEvent("before_send","span",e)}const i=r.transaction_info;if(s&&i&&r.transaction!==e.transaction){const e="custom";r.transaction_info={...i,source:e}}return this.sendEvent(r,t),r})).then(null,(e=>{if(e instanceof j)throw e;throw this.captureException(e,{data:{__sentry__:!0},originalException:e}),new j(`Event processing pipeline threw an error, original event will not be sent. Details have been sent as a new event.\nReason: ${e}`)}))}_process(e){this._numProcessing++,e.then((e=>(this._numProcessing--,e)),(e=>(this._numProcessing--,e)))}_clearOutcomes(){const e=this._outcomes;return this._outcomes={},Object.entries(e).map((([e,t])=>{const[n,r]=e.split(":");return{reason:n,category:r,quantity:t}}))}_flushOutcomes(){Nt&&L.log("Flushing outcomes...");const e=this._clearOutcomes();if(0===e.length)return void(Nt&&L.log("No outcomes to send"));if(!this._dsn)return void(Nt&&L.log("No dsn provided, will not send outcomes"));Nt&&L.log("Sending outcomes:",e);const t=(n=e,pt((r=this._options.tunnel&&F(this._dsn))?{dsn:r}:{},[[{type:"client_report"},{timestamp:o||ye(),discarded_events:n}]]));var n,r,o;this.sendEnvelope(t)}}function Yr(e){return void 0===e.type}function Vr(e){return"transaction"===e.type}function Gr(e){Gt().setClient(e)}const Xr=64;function Jr(e,t,n=Qe(e.bufferSize||Xr)){let r={};return{send:function(o){const s=[];if(ft(o,((t,n)=>{const o=wt(n);if(Tt(r,o)){const r=Kr(t,n);e.recordDroppedEvent("ratelimit_backoff",o,r)}else s.push(t)})),0===s.length)return Xe({});const i=pt(o[0],s),a=t=>{ft(i,((n,r)=>{const o=Kr(n,r);e.recordDroppedEvent(t,wt(r),o)}))};return n.add((()=>t({body:_t(i)}).then((e=>(void 0!==e.statusCode&&(e.statusCode<200||e.statusCode>=300)&&Nt&&L.warn(`Sentry responded with status code ${e.statusCode} to sent event.`),r=It(r,e),e)),(e=>{throw a("network_error"),e})))).then((e=>e),(e=>{if(e instanceof j)return Nt&&L.error("Skipped sending event because buffer is full."),a("queue_overflow"),Xe({});throw e}))},flush:e=>n.drain(e)}}function Kr(e,t){if("event"===t||"transaction"===t)return Array.isArray(e)?e[1]:void 0}const Qr=100,Zr=5e3,eo=36e5;function to(e){function t(...e){Nt&&L.info("[Offline]:",...e)}return n=>{const r=e(n);if(!n.createStore)throw new Error("No `createStore` function was provided");const o=n.createStore(n);let s,i=Zr;function a(e){s&&clearTimeout(s),s=setTimeout((async()=>{s=void 0;const e=await o.shift();e&&(t("Attempting to send previously queued event"),e[0].sent_at=(new Date).toISOString(),u(e,!0).catch((e=>{t("Failed to retry sending",e)})))}),e),"number"!=typeof s&&s.unref&&s.unref()}function c(){s||(a(i),i=Math.min(2*i,eo))}async function u(e,s=!1){if(!s&&mt(e,["replay_event","replay_recording"]))return await o.push(e),a(Qr),{};try{const t=await r.send(e);let n=Qr;if(t)if(t.headers&&t.headers["retry-after"])n=Ct(t.headers["retry-after"]);else if(t.headers&&t.headers["x-sentry-rate-limits"])n=6e4;else if((t.statusCode||0)>=400)return t;return a(n),i=Zr,t}catch(r){if(await function(e,t,r){return!mt(e,["client_report"])&&(!n.shouldStore||n.shouldStore(e,t,r))}(e,r,i))return s?await o.unshift(e):await o.push(e),c(),t("Error sending. Event queued.",r),{};throw r}}return n.flushAtStartup&&c(),{send:u,flush:e=>r.flush(e)}}}function no(e,t){let n;return ft(e,((e,r)=>(t.includes(r)&&(n=Array.isArray(e)?e[1]:void 0),!!n))),n}function ro(e,t){const n=t&&t.getDsn(),r=t&&t.getOptions().tunnel;return function(e,t){return!!t&&e.includes(t.host)}(e,n)||function(e,t){if(!t)return!1;return oo(e)===oo(t)}(e,r)}function oo(e){return"/"===e[e.length-1]?e.slice(0,-1):e}const so=100;function io(e,t){const n=Qt(),r=Xt();if(!n)return;const{beforeBreadcrumb:o=null,maxBreadcrumbs:s=so}=n.getOptions();if(s<=0)return;const i={timestamp:ye(),...e},a=o?D((()=>o(i,t))):i;null!==a&&(n.emit&&n.emit("beforeAddBreadcrumb",a,t),r.addBreadcrumb(a,s))}let ao;const co=new WeakMap,uo=()=>({name:"FunctionToString",setupOnce(){ao=Function.prototype.toString;try{Function.prototype.toString=function(...e){const t=W(this),n=co.has(Qt())&&void 0!==t?t:this;return ao.apply(n,e)}}catch(e){}},setup(e){co.set(e,!0)}}),lo=[/^Script error\.?$/,/^Javascript error: Script error\.? on line 0$/,/^ResizeObserver loop completed with undelivered notifications.$/,/^Cannot redefine property: googletag$/,"undefined is not an object (evaluating 'a.L')",'can\'t redefine non-configurable property "solana"',"vv().getRestrictions is not a function. (In 'vv().getRestrictions(1,a)', 'vv().getRestrictions' is undefined)","Can't find variable: _AutofillCallbackHandler"],po=(e={})=>({name:"InboundFilters",processEvent(t,n,r){const o=r.getOptions(),s=function(e={},t={}){return{allowUrls:[...e.allowUrls||[],...t.allowUrls||[]],denyUrls:[...e.denyUrls||[],...t.denyUrls||[]],ignoreErrors:[...e.ignoreErrors||[],...t.ignoreErrors||[],...e.disableErrorDefaults?[]:lo],ignoreTransactions:[...e.ignoreTransactions||[],...t.ignoreTransactions||[]],ignoreInternal:void 0===e.ignoreInternal||e.ignoreInternal}}(e,o);return function(e,t){if(t.ignoreInternal&&function(e){try{return"SentryError"===e.exception.values[0].type}catch(e){}return!1}(e))return Nt&&L.warn(`Event dropped due to being internal Sentry Error.\nEvent: ${Pe(e)}`),!0;if(function(e,t){if(e.type||!t||!t.length)return!1;return function(e){const t=[];e.message&&t.push(e.message);let n;try{n=e.exception.values[e.exception.values.length-1]}catch(e){}n&&n.value&&(t.push(n.value),n.type&&t.push(`${n.type}: ${n.value}`));return t}(e).some((e=>_(e,t)))}(e,t.ignoreErrors))return Nt&&L.warn(`Event dropped due to being matched by \`ignoreErrors\` option.\nEvent: ${Pe(e)}`),!0;if(function(e){if(e.type)return!1;if(!e.exception||!e.exception.values||0===e.exception.values.length)return!1;return!e.message&&!e.exception.values.some((e=>e.stacktrace||e.type&&"Error"!==e.type||e.value))}(e))return Nt&&L.warn(`Event dropped due to not having an error message, error type or stacktrace.\nEvent: ${Pe(e)}`),!0;if(function(e,t){if("transaction"!==e.type||!t||!t.length)return!1;const n=e.transaction;return!!n&&_(n,t)}(e,t.ignoreTransactions))return Nt&&L.warn(`Event dropped due to being matched by \`ignoreTransactions\` option.\nEvent: ${Pe(e)}`),!0;if(function(e,t){if(!t||!t.length)return!1;const n=ho(e);return!!n&&_(n,t)}(e,t.denyUrls))return Nt&&L.warn(`Event dropped due to being matched by \`denyUrls\` option.\nEvent: ${Pe(e)}.\nUrl: ${ho(e)}`),!0;if(!function(e,t){if(!t||!t.length)return!0;const n=ho(e);return!n||_(n,t)}(e,t.allowUrls))return Nt&&L.warn(`Event dropped due to not being matched by \`allowUrls\` option.\nEvent: ${Pe(e)}.\nUrl: ${ho(e)}`),!0;return!1}(t,s)?null:t}});function ho(e){try{let t;try{t=e.exception.values[0].stacktrace.frames}catch(e){}return t?function(e=[]){for(let t=e.length-1;t>=0;t--){const n=e[t];if(n&&"<anonymous>"!==n.filename&&"[native code]"!==n.filename)return n.filename||null}return null}(t):null}catch(t){return Nt&&L.error(`Cannot extract url for event ${Pe(e)}`),null}}const fo=new Map,mo=new Set;function go(e,t){return function(e){if(k._sentryModuleMetadata)for(const t of Object.keys(k._sentryModuleMetadata)){const n=k._sentryModuleMetadata[t];if(mo.has(t))continue;mo.add(t);const r=e(t);for(const e of r.reverse())if(e.filename){fo.set(e.filename,n);break}}}(e),fo.get(t)}function _o(e,t){try{t.exception.values.forEach((t=>{if(t.stacktrace)for(const n of t.stacktrace.frames||[]){if(!n.filename||n.module_metadata)continue;const t=go(e,n.filename);t&&(n.module_metadata=t)}}))}catch(e){}}function yo(e){try{e.exception.values.forEach((e=>{if(e.stacktrace)for(const t of e.stacktrace.frames||[])delete t.module_metadata}))}catch(e){}}const vo=()=>({name:"ModuleMetadata",setup(e){e.on("beforeEnvelope",(e=>{ft(e,((e,t)=>{if("event"===t){const t=Array.isArray(e)?e[1]:void 0;t&&(yo(t),e[1]=t)}}))})),e.on("applyFrameMetadata",(t=>{if(t.type)return;_o(e.getOptions().stackParser,t)}))}}),bo=(e={})=>{const t=e.levels||N;return{name:"CaptureConsole",setup(e){"console"in k&&de((({args:n,level:r})=>{Qt()===e&&t.includes(r)&&function(e,t){const n={level:tt(t),extra:{arguments:e}};Kt((r=>{if(r.addEventProcessor((e=>(e.logger="console",$e(e,{handled:!1,type:"console"}),e))),"assert"===t){if(!e[0]){const t=`Assertion failed: ${m(e.slice(1)," ")||"console.assert"}`;r.setExtra("arguments",e.slice(1)),wr(t,n)}return}const o=e.find((e=>e instanceof Error));if(o)return void Sr(o,n);wr(m(e," "),n)}))}(n,r)}))}}};const So=(e={})=>{const t={debugger:!1,stringify:!1,...e};return{name:"Debug",setup(e){e.on("beforeSendEvent",((e,n)=>{t.debugger,D((()=>{t.stringify?(console.log(JSON.stringify(e,null,2)),n&&Object.keys(n).length&&console.log(JSON.stringify(n,null,2))):(console.log(e),n&&Object.keys(n).length&&console.log(n))}))}))}}},wo=()=>{let e;return{name:"Dedupe",processEvent(t){if(t.type)return t;try{if(function(e,t){if(!t)return!1;if(function(e,t){const n=e.message,r=t.message;if(!n&&!r)return!1;if(n&&!r||!n&&r)return!1;if(n!==r)return!1;if(!xo(e,t))return!1;if(!ko(e,t))return!1;return!0}(e,t))return!0;if(function(e,t){const n=Eo(t),r=Eo(e);if(!n||!r)return!1;if(n.type!==r.type||n.value!==r.value)return!1;if(!xo(e,t))return!1;if(!ko(e,t))return!1;return!0}(e,t))return!0;return!1}(t,e))return Nt&&L.warn("Event dropped due to being a duplicate of previously captured event."),null}catch(e){}return e=t}}};function ko(e,t){let n=se(e),r=se(t);if(!n&&!r)return!0;if(n&&!r||!n&&r)return!1;if(r.length!==n.length)return!1;for(let e=0;e<r.length;e++){const t=r[e],o=n[e];if(t.filename!==o.filename||t.lineno!==o.lineno||t.colno!==o.colno||t.function!==o.function)return!1}return!0}function xo(e,t){let n=e.fingerprint,r=t.fingerprint;if(!n&&!r)return!0;if(n&&!r||!n&&r)return!1;try{return!(n.join("")!==r.join(""))}catch(e){return!1}}function Eo(e){return e.exception&&e.exception.values&&e.exception.values[0]}const Co=(e={})=>{const{depth:n=3,captureErrorCause:r=!0}=e;return{name:"ExtraErrorData",processEvent(e,o,s){const{maxValueLength:i=250}=s.getOptions();return function(e,n={},r,o,s){if(!n.originalException||!t(n.originalException))return e;const i=n.originalException.name||n.originalException.constructor.name,a=function(e,n,r){try{const o=["name","message","stack","line","column","fileName","lineNumber","columnNumber","toJSON"],s={};for(const n of Object.keys(e)){if(-1!==o.indexOf(n))continue;const i=e[n];s[n]=t(i)||"string"==typeof i?h(`${i}`,r):i}if(n&&void 0!==e.cause&&(s.cause=t(e.cause)?e.cause.toString():e.cause),"function"==typeof e.toJSON){const n=e.toJSON();for(const e of Object.keys(n)){const r=n[e];s[e]=t(r)?r.toString():r}}return s}catch(e){Nt&&L.error("Unable to extract extra data from the Error object:",e)}return null}(n.originalException,o,s);if(a){const t={...e.contexts},n=je(a,r);return c(n)&&(z(n,"__sentry_skip_normalization__",!0),t[i]=n),{...e,contexts:t}}return e}(e,o,n,r,i)}}};const To=(e={})=>{const t=e.root,n=e.prefix||"app:///",r="window"in k&&void 0!==k.window,o=e.iteratee||function({isBrowser:e,root:t,prefix:n}){return r=>{if(!r.filename)return r;const o=/^[a-zA-Z]:\\/.test(r.filename)||r.filename.includes("\\")&&!r.filename.includes("/"),s=/^\//.test(r.filename);if(e){if(t){const e=r.filename;0===e.indexOf(t)&&(r.filename=e.replace(t,n))}}else if(o||s){const e=o?r.filename.replace(/^[a-zA-Z]:/,"").replace(/\\/g,"/"):r.filename,s=t?function(e,t){e=We(e).slice(1),t=We(t).slice(1);const n=Ye(e.split("/")),r=Ye(t.split("/")),o=Math.min(n.length,r.length);let s=o;for(let e=0;e<o;e++)if(n[e]!==r[e]){s=e;break}let i=[];for(let e=s;e<n.length;e++)i.push("..");return i=i.concat(r.slice(s)),i.join("/")}(t,e):Ve(e);r.filename=`${n}${s}`}return r}}({isBrowser:r,root:t,prefix:n});return{name:"RewriteFrames",processEvent(e){let t=e;return e.exception&&Array.isArray(e.exception.values)&&(t=function(e){try{return{...e,exception:{...e.exception,values:e.exception.values.map((e=>{return{...e,...e.stacktrace&&{stacktrace:(t=e.stacktrace,{...t,frames:t&&t.frames&&t.frames.map((e=>o(e)))})}};var t}))}}}catch(t){return e}}(t)),t}}};const Io=()=>{const e=1e3*ve();return{name:"SessionTiming",processEvent(t){const n=1e3*ve();return{...t,extra:{...t.extra,"session:start":e,"session:duration":n-e,"session:end":n}}}}};function Mo(e){return{...e,path:"path"in e&&Array.isArray(e.path)?e.path.join("."):void 0,keys:"keys"in e?JSON.stringify(e.keys):void 0,unionErrors:"unionErrors"in e?JSON.stringify(e.unionErrors):void 0}}function Ro(e){const t=new Set;for(const n of e.issues)n.path&&n.path[0]&&t.add(n.path[0]);return`Failed to validate keys: ${h(Array.from(t).join(", "),100)}`}function Ao(e,n,r){return n.exception&&n.exception.values&&r&&r.originalException&&(t(o=r.originalException)&&"ZodError"===o.name&&Array.isArray(o.errors))&&0!==r.originalException.issues.length?{...n,exception:{...n.exception,values:[{...n.exception.values[0],value:Ro(r.originalException)},...n.exception.values.slice(1)]},extra:{...n.extra,"zoderror.issues":r.originalException.errors.slice(0,e).map(Mo)}}:n;var o}const No=(e={})=>{const t=e.limit||10;return{name:"ZodErrors",processEvent:(e,n)=>Ao(t,e,n)}},Oo=e=>({name:"ThirdPartyErrorsFilter",setup(e){e.on("beforeEnvelope",(e=>{ft(e,((e,t)=>{if("event"===t){const t=Array.isArray(e)?e[1]:void 0;t&&(yo(t),e[1]=t)}}))})),e.on("applyFrameMetadata",(t=>{if(t.type)return;_o(e.getOptions().stackParser,t)}))},processEvent(t){const n=function(e){const t=se(e);if(!t)return;return t.filter((e=>!!e.filename)).map((e=>e.module_metadata?Object.keys(e.module_metadata).filter((e=>e.startsWith(Do))).map((e=>e.slice(Do.length))):[]))}(t);if(n){const r=n["drop-error-if-contains-third-party-frames"===e.behaviour||"apply-tag-if-contains-third-party-frames"===e.behaviour?"some":"every"]((t=>!t.some((t=>e.filterKeys.includes(t)))));if(r){if("drop-error-if-contains-third-party-frames"===e.behaviour||"drop-error-if-exclusively-contains-third-party-frames"===e.behaviour)return null;t.tags={...t.tags,third_party_code:!0}}}return t}});const Do="_sentryBundlerPluginAppKey:",Lo="c",Po="g",Fo="s",$o="d";function Bo(e,t){const n=x("globalMetricsAggregators",(()=>new WeakMap)),r=n.get(e);if(r)return r;const o=new t(e);return e.on("flush",(()=>o.flush())),e.on("close",(()=>o.close())),n.set(e,o),o}function Uo(e,t,n,r,o={}){const s=o.client||Qt();if(!s)return;const i=Mn(),a=i?In(i):void 0,c=a&&Sn(a).description,{unit:u,tags:l,timestamp:d}=o,{release:p,environment:h}=s.getOptions(),f={};p&&(f.release=p),h&&(f.environment=h),c&&(f.transaction=c),Nt&&L.log(`Adding value of ${r} to ${t} metric ${n}`);Bo(s,e).add(t,n,r,u,{...f,...l},d)}function jo(e,t,n,r){Uo(e,$o,t,zo(n),r)}const Ho={increment:function(e,t,n=1,r){Uo(e,"c",t,zo(n),r)},distribution:jo,set:function(e,t,n,r){Uo(e,"s",t,n,r)},gauge:function(e,t,n,r){Uo(e,"g",t,zo(n),r)},timing:function(e,t,n,r="second",o){if("function"==typeof n){const r=ve();return Qn({op:"metrics.timing",name:t,startTime:r,onlyIfParent:!0},(s=>Bn((()=>n()),(()=>{}),(()=>{const n=ve();jo(e,t,n-r,{...o,unit:"second"}),s.end(n)}))))}jo(e,t,n,{...o,unit:r})},getMetricsAggregatorForClient:Bo};function zo(e){return"string"==typeof e?parseInt(e):e}function qo(e){return e.replace(/[^\w\-./]+/gi,"")}const Wo=[["\n","\\n"],["\r","\\r"],["\t","\\t"],["\\","\\\\"],["|","\\u{7c}"],[",","\\u{2c}"]];function Yo(e){return[...e].reduce(((e,t)=>e+function(e){for(const[t,n]of Wo)if(e===t)return n;return e}(t)),"")}function Vo(e,t){L.log(`Flushing aggregated metrics, number of metrics: ${t.length}`);const n=function(e,t,n,r){const o={sent_at:(new Date).toISOString()};n&&n.sdk&&(o.sdk={name:n.sdk.name,version:n.sdk.version});r&&t&&(o.dsn=F(t));const s=function(e){const t=function(e){let t="";for(const n of e){const e=Object.entries(n.tags),r=e.length>0?`|#${e.map((([e,t])=>`${e}:${t}`)).join(",")}`:"";t+=`${n.name}@${n.unit}:${n.metric}|${n.metricType}${r}|T${n.timestamp}\n`}return t}(e);return[{type:"statsd",length:t.length},t]}(e);return pt(o,[s])}(t,e.getDsn(),e.getSdkMetadata(),e.getOptions().tunnel);e.sendEnvelope(n)}const Go={[Lo]:class{constructor(e){this._value=e}get weight(){return 1}add(e){this._value+=e}toString(){return`${this._value}`}},[Po]:class{constructor(e){this._last=e,this._min=e,this._max=e,this._sum=e,this._count=1}get weight(){return 5}add(e){this._last=e,e<this._min&&(this._min=e),e>this._max&&(this._max=e),this._sum+=e,this._count++}toString(){return`${this._last}:${this._min}:${this._max}:${this._sum}:${this._count}`}},[$o]:class{constructor(e){this._value=[e]}get weight(){return this._value.length}add(e){this._value.push(e)}toString(){return this._value.join(":")}},[Fo]:class{constructor(e){this.first=e,this._value=new Set([e])}get weight(){return this._value.size}add(e){this._value.add(e)}toString(){return Array.from(this._value).map((e=>"string"==typeof e?function(e){let t=0;for(let n=0;n<e.length;n++)t=(t<<5)-t+e.charCodeAt(n),t&=t;return t>>>0}(e):e)).join(":")}}};class Xo{constructor(e){this._client=e,this._buckets=new Map,this._interval=setInterval((()=>this.flush()),5e3)}add(e,t,n,r="none",o={},s=ve()){const i=Math.floor(s),a=t.replace(/[^\w\-.]+/gi,"_");const c=function(e){const t={};for(const n in e)Object.prototype.hasOwnProperty.call(e,n)&&(t[qo(n)]=Yo(String(e[n])));return t}(o),u=function(e){return e.replace(/[^\w]+/gi,"_")}(r),l=function(e,t,n,r){const o=Object.entries(X(r)).sort(((e,t)=>e[0].localeCompare(t[0])));return`${e}${t}${n}${o}`}(e,a,u,c);let d=this._buckets.get(l);const p=d&&"s"===e?d.metric.weight:0;d?(d.metric.add(n),d.timestamp<i&&(d.timestamp=i)):(d={metric:new Go[e](n),timestamp:i,metricType:e,name:a,unit:u,tags:c},this._buckets.set(l,d));Rn(e,a,"string"==typeof n?d.metric.weight-p:n,u,o,l)}flush(){if(0===this._buckets.size)return;const e=Array.from(this._buckets.values());Vo(this._client,e),this._buckets.clear()}close(){clearInterval(this._interval),this.flush()}}function Jo(e,t,n,r,o="auto.http.browser"){if(!e.fetchData)return;const s=Fn()&&t(e.fetchData.url);if(e.endTimestamp&&s){const t=e.fetchData.__span;if(!t)return;const n=r[t];return void(n&&(!function(e,t){if(t.response){fn(e,t.response.status);const n=t.response&&t.response.headers&&t.response.headers.get("content-length");if(n){const t=parseInt(n);t>0&&e.setAttribute("http.response_content_length",t)}}else t.error&&e.setStatus({code:pn,message:"internal_error"});e.end()}(n,e),delete r[t]))}const i=Gt(),a=Qt(),{method:c,url:u}=e.fetchData,l=function(e){try{return new URL(e).href}catch(e){return}}(u),p=l?Ze(l).host:void 0,h=!!Mn(),f=s&&h?Zn({name:`${c} ${u}`,attributes:{url:u,type:"fetch","http.method":c,"http.url":l,"server.address":p,[on]:o,[rn]:"http.client"}}):new $n;if(e.fetchData.__span=f.spanContext().spanId,r[f.spanContext().spanId]=f,n(e.fetchData.url)&&a){const t=e.args[0];e.args[1]=e.args[1]||{};const n=e.args[1];n.headers=function(e,t,n,r,o){const s=Xt(),{traceId:i,spanId:a,sampled:c,dsc:u}={...s.getPropagationContext(),...n.getPropagationContext()},l=o?yn(o):dt(i,a,c),p=at(u||(o?qn(o):zn(i,t))),h=r.headers||("undefined"!=typeof Request&&d(e,Request)?e.headers:void 0);if(h){if("undefined"!=typeof Headers&&d(h,Headers)){const e=new Headers(h);return e.append("sentry-trace",l),p&&e.append(nt,p),e}if(Array.isArray(h)){const e=[...h,["sentry-trace",l]];return p&&e.push([nt,p]),e}{const e="baggage"in h?h.baggage:void 0,t=[];return Array.isArray(e)?t.push(...e):e&&t.push(e),p&&t.push(p),{...h,"sentry-trace":l,baggage:t.length>0?t.join(","):void 0}}}return{"sentry-trace":l,baggage:p}}(t,a,i,n,Fn()&&h?f:void 0)}return f}function Ko(e,t={},n=Gt()){const{message:r,name:o,email:s,url:i,source:a,associatedEventId:c,tags:u}=e,l={contexts:{feedback:X({contact_email:s,name:o,message:r,url:i,source:a,associated_event_id:c})},type:"feedback",level:"info",tags:u},d=n&&n.getClient()||Qt();d&&d.emit("beforeSendFeedback",l,t);return n.captureEvent(l,t)}const Qo=function(){return{bindClient(e){Gt().setClient(e)},withScope:Kt,getClient:()=>Qt(),getScope:Gt,getIsolationScope:Xt,captureException:(e,t)=>Gt().captureException(e,t),captureMessage:(e,t,n)=>Gt().captureMessage(e,t,n),captureEvent:kr,addBreadcrumb:io,setUser:Mr,setTags:Tr,setTag:Ir,setExtra:Cr,setExtras:Er,setContext:xr,getIntegration(e){const t=Qt();return t&&t.getIntegrationByName(e.id)||null},startSession:Nr,endSession:Or,captureSession(e){if(e)return Or();!function(){const e=Gt(),t=Qt(),n=e.getSession();t&&n&&t.captureSession(n)}()}}};const Zo=k;let es=0;function ts(){return es>0}function ns(e,t={},n){if("function"!=typeof e)return e;try{const t=e.__sentry_wrapped__;if(t)return t;if(W(e))return e}catch(t){return e}const r=function(){const r=Array.prototype.slice.call(arguments);try{n&&"function"==typeof n&&n.apply(this,arguments);const o=r.map((e=>ns(e,t)));return e.apply(this,o)}catch(e){throw es++,setTimeout((()=>{es--})),Kt((n=>{n.addEventProcessor((e=>(t.mechanism&&(Fe(e,void 0,void 0),$e(e,t.mechanism)),e.extra={...e.extra,arguments:r},e))),Sr(e)})),e}};try{for(const t in e)Object.prototype.hasOwnProperty.call(e,t)&&(r[t]=e[t])}catch(e){}q(r,e),z(e,"__sentry_wrapped__",r);try{Object.getOwnPropertyDescriptor(r,"name").configurable&&Object.defineProperty(r,"name",{get:()=>e.name})}catch(e){}return r}const rs="undefined"==typeof __SENTRY_DEBUG__||__SENTRY_DEBUG__;function os(e,t){const n=as(e,t),r={type:t&&t.name,value:us(t)};return n.length&&(r.stacktrace={frames:n}),void 0===r.type&&""===r.value&&(r.value="Unrecoverable error caught"),r}function ss(e,t,n,r){const o=Qt(),s=o&&o.getOptions().normalizeDepth,i=function(e){for(const t in e)if(Object.prototype.hasOwnProperty.call(e,t)){const n=e[t];if(n instanceof Error)return n}return}(t),a={__serialized__:He(t,s)};if(i)return{exception:{values:[os(e,i)]},extra:a};const c={exception:{values:[{type:u(t)?t.constructor.name:r?"UnhandledRejection":"Error",value:fs(t,{isUnhandledRejection:r})}]},extra:a};if(n){const t=as(e,n);t.length&&(c.exception.values[0].stacktrace={frames:t})}return c}function is(e,t){return{exception:{values:[os(e,t)]}}}function as(e,t){const n=t.stacktrace||t.stack||"",r=function(e){if(e&&cs.test(e.message))return 1;return 0}(t),o=function(e){if("number"==typeof e.framesToPop)return e.framesToPop;return 0}(t);try{return e(n,r,o)}catch(e){}return[]}const cs=/Minified React error #\d+;/i;function us(e){const t=e&&e.message;return t?t.error&&"string"==typeof t.error.message?t.error.message:t:"No error message"}function ls(e,t,n,r){const o=ps(e,t,n&&n.syntheticException||void 0,r);return $e(o),o.level="error",n&&n.event_id&&(o.event_id=n.event_id),Xe(o)}function ds(e,t,n="info",r,o){const s=hs(e,t,r&&r.syntheticException||void 0,o);return s.level=n,r&&r.event_id&&(s.event_id=r.event_id),Xe(s)}function ps(e,s,i,a,l){let d;if(r(s)&&s.error){return is(e,s.error)}if(o(s)||n(s,"DOMException")){const t=s;if("stack"in s)d=is(e,s);else{const n=t.name||(o(t)?"DOMError":"DOMException"),r=t.message?`${n}: ${t.message}`:n;d=hs(e,r,i,a),Fe(d,r)}return"code"in t&&(d.tags={...d.tags,"DOMException.code":`${t.code}`}),d}if(t(s))return is(e,s);if(c(s)||u(s)){return d=ss(e,s,i,l),$e(d,{synthetic:!0}),d}return d=hs(e,s,i,a),Fe(d,`${s}`,void 0),$e(d,{synthetic:!0}),d}function hs(e,t,n,r){const o={};if(r&&n){const r=as(e,n);r.length&&(o.exception={values:[{value:t,stacktrace:{frames:r}}]})}if(i(t)){const{__sentry_template_string__:e,__sentry_template_values__:n}=t;return o.logentry={message:e,params:n},o}return o.message=t,o}function fs(e,{isUnhandledRejection:t}){const n=function(e,t=40){const n=Object.keys(Y(e));n.sort();const r=n[0];if(!r)return"[object has no keys]";if(r.length>=t)return h(r,t);for(let e=n.length;e>0;e--){const r=n.slice(0,e).join(", ");if(!(r.length>t))return e===n.length?r:h(r,t)}return""}(e),o=t?"promise rejection":"exception";if(r(e))return`Event \`ErrorEvent\` captured as ${o} with message \`${e.message}\``;if(u(e)){return`Event \`${function(e){try{const t=Object.getPrototypeOf(e);return t?t.constructor.name:void 0}catch(e){}}(e)}\` (type=${e.type}) captured as ${o}`}return`Object captured as ${o} with keys: ${n}`}function ms(e,{metadata:t,tunnel:n,dsn:r}){const o={event_id:e.event_id,sent_at:(new Date).toISOString(),...t&&t.sdk&&{sdk:{name:t.sdk.name,version:t.sdk.version}},...!!n&&!!r&&{dsn:F(r)}},s=function(e){return[{type:"user_report"},e]}(e);return pt(o,[s])}class gs extends Wr{constructor(e){const t={parentSpanIsAlwaysRootSpan:!0,...e};!function(e,t,n=[t],r="npm"){const o=e._metadata||{};o.sdk||(o.sdk={name:`sentry.javascript.${t}`,packages:n.map((e=>({name:`${r}:@sentry/${e}`,version:w}))),version:w}),e._metadata=o}(t,"browser",["browser"],Zo.SENTRY_SDK_SOURCE||"npm"),super(t),t.sendClientReports&&Zo.document&&Zo.document.addEventListener("visibilitychange",(()=>{"hidden"===Zo.document.visibilityState&&this._flushOutcomes()}))}eventFromException(e,t){return ls(this._options.stackParser,e,t,this._options.attachStacktrace)}eventFromMessage(e,t="info",n){return ds(this._options.stackParser,e,t,n,this._options.attachStacktrace)}captureUserFeedback(e){if(!this._isEnabled())return void(rs&&L.warn("SDK not enabled, will not capture user feedback."));const t=ms(e,{metadata:this.getSdkMetadata(),dsn:this.getDsn(),tunnel:this.getOptions().tunnel});this.sendEnvelope(t)}_prepareEvent(e,t,n){return e.platform=e.platform||"javascript",super._prepareEvent(e,t,n)}}const _s="undefined"==typeof __SENTRY_DEBUG__||__SENTRY_DEBUG__,ys=(e,t,n,r)=>{let o,s;return i=>{t.value>=0&&(i||r)&&(s=t.value-(o||0),(s||void 0===o)&&(o=t.value,t.delta=s,t.rating=((e,t)=>e>t[1]?"poor":e>t[0]?"needs-improvement":"good")(t.value,n),e(t)))}},vs=k,bs=()=>vs.performance&&performance.getEntriesByType&&performance.getEntriesByType("navigation")[0],Ss=()=>{const e=bs();return e&&e.activationStart||0},ws=(e,t)=>{const n=bs();let r="navigate";n&&(vs.document&&vs.document.prerendering||Ss()>0?r="prerender":vs.document&&vs.document.wasDiscarded?r="restore":n.type&&(r=n.type.replace(/_/g,"-")));return{name:e,value:void 0===t?-1:t,rating:"good",delta:0,entries:[],id:`v3-${Date.now()}-${Math.floor(8999999999999*Math.random())+1e12}`,navigationType:r}},ks=(e,t,n)=>{try{if(PerformanceObserver.supportedEntryTypes.includes(e)){const r=new PerformanceObserver((e=>{Promise.resolve().then((()=>{t(e.getEntries())}))}));return r.observe(Object.assign({type:e,buffered:!0},n||{})),r}}catch(e){}},xs=e=>{const t=t=>{("pagehide"===t.type||vs.document&&"hidden"===vs.document.visibilityState)&&e(t)};vs.document&&(addEventListener("visibilitychange",t,!0),addEventListener("pagehide",t,!0))},Es=e=>{let t=!1;return n=>{t||(e(n),t=!0)}};let Cs=-1;const Ts=e=>{"hidden"===vs.document.visibilityState&&Cs>-1&&(Cs="visibilitychange"===e.type?e.timeStamp:0,removeEventListener("visibilitychange",Ts,!0),removeEventListener("prerenderingchange",Ts,!0))},Is=()=>(vs.document&&Cs<0&&(Cs="hidden"!==vs.document.visibilityState||vs.document.prerendering?1/0:0,addEventListener("visibilitychange",Ts,!0),addEventListener("prerenderingchange",Ts,!0)),{get firstHiddenTime(){return Cs}}),Ms=e=>{vs.document&&vs.document.prerendering?addEventListener("prerenderingchange",(()=>e()),!0):e()},Rs=[1800,3e3],As=[.1,.25],Ns=(e,t={})=>{((e,t={})=>{Ms((()=>{const n=Is(),r=ws("FCP");let o;const s=ks("paint",(e=>{e.forEach((e=>{"first-contentful-paint"===e.name&&(s.disconnect(),e.startTime<n.firstHiddenTime&&(r.value=Math.max(e.startTime-Ss(),0),r.entries.push(e),o(!0)))}))}));s&&(o=ys(e,r,Rs,t.reportAllChanges))}))})(Es((()=>{const n=ws("CLS",0);let r,o=0,s=[];const i=e=>{e.forEach((e=>{if(!e.hadRecentInput){const t=s[0],n=s[s.length-1];o&&t&&n&&e.startTime-n.startTime<1e3&&e.startTime-t.startTime<5e3?(o+=e.value,s.push(e)):(o=e.value,s=[e])}})),o>n.value&&(n.value=o,n.entries=s,r())},a=ks("layout-shift",i);a&&(r=ys(e,n,As,t.reportAllChanges),xs((()=>{i(a.takeRecords()),r(!0)})),setTimeout(r,0))})))},Os=[100,300],Ds=(e,t={})=>{Ms((()=>{const n=Is(),r=ws("FID");let o;const s=e=>{e.startTime<n.firstHiddenTime&&(r.value=e.processingStart-e.startTime,r.entries.push(e),o(!0))},i=e=>{e.forEach(s)},a=ks("first-input",i);o=ys(e,r,Os,t.reportAllChanges),a&&xs(Es((()=>{i(a.takeRecords()),a.disconnect()})))}))};let Ls=0,Ps=1/0,Fs=0;const $s=e=>{e.forEach((e=>{e.interactionId&&(Ps=Math.min(Ps,e.interactionId),Fs=Math.max(Fs,e.interactionId),Ls=Fs?(Fs-Ps)/7+1:0)}))};let Bs;const Us=()=>{"interactionCount"in performance||Bs||(Bs=ks("event",$s,{type:"event",buffered:!0,durationThreshold:0}))},js=[200,500],Hs=()=>(Bs?Ls:performance.interactionCount||0)-0,zs=[],qs={},Ws=e=>{const t=zs[zs.length-1],n=qs[e.interactionId];if(n||zs.length<10||t&&e.duration>t.latency){if(n)n.entries.push(e),n.latency=Math.max(n.latency,e.duration);else{const t={id:e.interactionId,latency:e.duration,entries:[e]};qs[t.id]=t,zs.push(t)}zs.sort(((e,t)=>t.latency-e.latency)),zs.splice(10).forEach((e=>{delete qs[e.id]}))}},Ys=(e,t={})=>{Ms((()=>{Us();const n=ws("INP");let r;const o=e=>{e.forEach((e=>{if(e.interactionId&&Ws(e),"first-input"===e.entryType){!zs.some((t=>t.entries.some((t=>e.duration===t.duration&&e.startTime===t.startTime))))&&Ws(e)}}));const t=(()=>{const e=Math.min(zs.length-1,Math.floor(Hs()/50));return zs[e]})();t&&t.latency!==n.value&&(n.value=t.latency,n.entries=t.entries,r())},s=ks("event",o,{durationThreshold:null!=t.durationThreshold?t.durationThreshold:40});r=ys(e,n,js,t.reportAllChanges),s&&("PerformanceEventTiming"in vs&&"interactionId"in PerformanceEventTiming.prototype&&s.observe({type:"first-input",buffered:!0}),xs((()=>{o(s.takeRecords()),n.value<0&&Hs()>0&&(n.value=0,n.entries=[]),r(!0)})))}))},Vs=[2500,4e3],Gs={},Xs=(e,t={})=>{Ms((()=>{const n=Is(),r=ws("LCP");let o;const s=e=>{const t=e[e.length-1];t&&t.startTime<n.firstHiddenTime&&(r.value=Math.max(t.startTime-Ss(),0),r.entries=[t],o())},i=ks("largest-contentful-paint",s);if(i){o=ys(e,r,Vs,t.reportAllChanges);const n=Es((()=>{Gs[r.id]||(s(i.takeRecords()),i.disconnect(),Gs[r.id]=!0,o(!0))}));["keydown","click"].forEach((e=>{vs.document&&addEventListener(e,(()=>setTimeout(n,0)),!0)})),xs(n)}}))},Js=[800,1800],Ks=e=>{vs.document&&vs.document.prerendering?Ms((()=>Ks(e))):vs.document&&"complete"!==vs.document.readyState?addEventListener("load",(()=>Ks(e)),!0):setTimeout(e,0)},Qs=(e,t={})=>{const n=ws("TTFB"),r=ys(e,n,Js,t.reportAllChanges);Ks((()=>{const e=bs();if(e){const t=e.responseStart;if(t<=0||t>performance.now())return;n.value=Math.max(t-Ss(),0),n.entries=[e],r(!0)}}))},Zs={},ei={};let ti,ni,ri,oi,si;function ii(e,t=!1){return _i("cls",e,pi,ti,t)}function ai(e,t=!1){return _i("lcp",e,fi,ri,t)}function ci(e){return _i("fid",e,hi,ni)}function ui(e){return _i("inp",e,gi,si)}function li(e,t){return yi(e,t),ei[e]||(!function(e){const t={};"event"===e&&(t.durationThreshold=0);ks(e,(t=>{di(e,{entries:t})}),t)}(e),ei[e]=!0),vi(e,t)}function di(e,t){const n=Zs[e];if(n&&n.length)for(const r of n)try{r(t)}catch(t){_s&&L.error(`Error while triggering instrumentation handler.\nType: ${e}\nName: ${oe(r)}\nError:`,t)}}function pi(){return Ns((e=>{di("cls",{metric:e}),ti=e}),{reportAllChanges:!0})}function hi(){return Ds((e=>{di("fid",{metric:e}),ni=e}))}function fi(){return Xs((e=>{di("lcp",{metric:e}),ri=e}),{reportAllChanges:!0})}function mi(){return Qs((e=>{di("ttfb",{metric:e}),oi=e}))}function gi(){return Ys((e=>{di("inp",{metric:e}),si=e}))}function _i(e,t,n,r,o=!1){let s;return yi(e,t),ei[e]||(s=n(),ei[e]=!0),r&&t({metric:r}),vi(e,t,o?s:void 0)}function yi(e,t){Zs[e]=Zs[e]||[],Zs[e].push(t)}function vi(e,t,n){return()=>{n&&n();const r=Zs[e];if(!r)return;const o=r.indexOf(t);-1!==o&&r.splice(o,1)}}function bi(e){return"number"==typeof e&&isFinite(e)}function Si(e,t,n,{...r}){const o=Sn(e).start_timestamp;return o&&o>t&&"function"==typeof e.updateStartTime&&e.updateStartTime(t),er(e,(()=>{const e=Zn({startTime:t,...r});return e&&e.end(n),e}))}function wi(e){const t=Qt();if(!t)return;const{name:n,transaction:r,attributes:o,startTime:s}=e,{release:i,environment:a}=t.getOptions(),c=t.getIntegrationByName("Replay"),u=c&&c.getReplayId(),l=Gt(),d=l.getUser(),p=void 0!==d?d.email||d.id||d.ip_address:void 0;let h;try{h=l.getScopeData().contexts.profile.profile_id}catch(e){}return Zn({name:n,attributes:{release:i,environment:a,user:p||void 0,profile_id:h||void 0,replay_id:u||void 0,transaction:r,"user_agent.original":vs.navigator&&vs.navigator.userAgent,...o},startTime:s,experimental:{standalone:!0}})}function ki(){return vs&&vs.addEventListener&&vs.performance}function xi(e){return e/1e3}function Ei(){let e,t,n=0;if(!function(){try{return Mt([PerformanceObserver,"access",e=>e.supportedEntryTypes,"optionalAccess",e=>e.includes,"call",e=>e("layout-shift")])}catch(e){return!1}}())return;let r=!1;function o(){r||(r=!0,t&&function(e,t,n){_s&&L.log(`Sending CLS span (${e})`);const r=xi((be||0)+(Mt([t,"optionalAccess",e=>e.startTime])||0)),o=Gt().getScopeData().transactionName,s=t?T(Mt([t,"access",e=>e.sources,"access",e=>e[0],"optionalAccess",e=>e.node])):"Layout shift",i=X({[on]:"auto.http.browser.cls",[rn]:"ui.webvital.cls",[un]:Mt([t,"optionalAccess",e=>e.duration])||0,"sentry.pageload.span_id":n}),a=wi({name:s,transaction:o,attributes:i,startTime:r});Mt([a,"optionalAccess",e=>e.addEvent,"call",t=>t("cls",{[an]:"",[cn]:e})]),Mt([a,"optionalAccess",e=>e.end,"call",e=>e(r)])}(n,e,t),s())}const s=ii((({metric:t})=>{const r=t.entries[t.entries.length-1];r&&(n=t.value,e=r)}),!0);xs((()=>{o()})),setTimeout((()=>{const e=Mt([Qt(),"optionalAccess",e=>e.on,"call",t=>t("startNavigationSpan",(()=>{o(),e&&e()}))]),n=Mn(),r=n&&In(n),s=r&&Sn(r);s&&"pageload"===s.op&&(t=r.spanContext().spanId)}),0)}const Ci=2147483647;let Ti,Ii,Mi=0,Ri={};function Ai({recordClsStandaloneSpans:e}){const t=ki();if(t&&be){t.mark&&vs.performance.mark("sentry-tracing-init");const n=ci((({metric:e})=>{const t=e.entries[e.entries.length-1];if(!t)return;const n=xi(be),r=xi(t.startTime);_s&&L.log("[Measurements] Adding FID"),Ri.fid={value:e.value,unit:"millisecond"},Ri["mark.fid"]={value:n+r,unit:"second"}})),r=ai((({metric:e})=>{const t=e.entries[e.entries.length-1];t&&(_s&&L.log("[Measurements] Adding LCP"),Ri.lcp={value:e.value,unit:"millisecond"},Ti=t)}),!0),o=_i("ttfb",(({metric:e})=>{e.entries[e.entries.length-1]&&(_s&&L.log("[Measurements] Adding TTFB"),Ri.ttfb={value:e.value,unit:"millisecond"})}),mi,oi),s=e?Ei():ii((({metric:e})=>{const t=e.entries[e.entries.length-1];t&&(_s&&L.log(`[Measurements] Adding CLS ${e.value}`),Ri.cls={value:e.value,unit:""},Ii=t)}),!0);return()=>{n(),r(),o(),s&&s()}}return()=>{}}function Ni(e,t){const n=ki();if(!n||!vs.performance.getEntries||!be)return;_s&&L.log("[Tracing] Adding & adjusting spans using Performance API");const r=xi(be),o=n.getEntries(),{op:s,start_timestamp:i}=Sn(e);if(o.slice(Mi).forEach((t=>{const n=xi(t.startTime),o=xi(Math.max(0,t.duration));if(!("navigation"===s&&i&&r+n<i))switch(t.entryType){case"navigation":!function(e,t,n){["unloadEvent","redirect","domContentLoadedEvent","loadEvent","connect"].forEach((r=>{Oi(e,t,r,n)})),Oi(e,t,"secureConnection",n,"TLS/SSL","connectEnd"),Oi(e,t,"fetch",n,"cache","domainLookupStart"),Oi(e,t,"domainLookup",n,"DNS"),function(e,t,n){const r=n+xi(t.requestStart),o=n+xi(t.responseEnd),s=n+xi(t.responseStart);t.responseEnd&&(Si(e,r,o,{op:"browser",name:"request",attributes:{[on]:"auto.ui.browser.metrics"}}),Si(e,s,o,{op:"browser",name:"response",attributes:{[on]:"auto.ui.browser.metrics"}}))}(e,t,n)}(e,t,r);break;case"mark":case"paint":case"measure":{!function(e,t,n,r,o){const s=bs(),i=xi(s?s.requestStart:0),a=o+Math.max(n,i),c=o+n,u=c+r,l={[on]:"auto.resource.browser.metrics"};a!==c&&(l["sentry.browser.measure_happened_before_request"]=!0,l["sentry.browser.measure_start_time"]=a);Si(e,a,u,{name:t.name,op:t.entryType,attributes:l})}(e,t,n,o,r);const s=Is(),i=t.startTime<s.firstHiddenTime;"first-paint"===t.name&&i&&(_s&&L.log("[Measurements] Adding FP"),Ri.fp={value:t.startTime,unit:"millisecond"}),"first-contentful-paint"===t.name&&i&&(_s&&L.log("[Measurements] Adding FCP"),Ri.fcp={value:t.startTime,unit:"millisecond"});break}case"resource":!function(e,t,n,r,o,s){if("xmlhttprequest"===t.initiatorType||"fetch"===t.initiatorType)return;const i=Ze(n),a={[on]:"auto.resource.browser.metrics"};Di(a,t,"transferSize","http.response_transfer_size"),Di(a,t,"encodedBodySize","http.response_content_length"),Di(a,t,"decodedBodySize","http.decoded_response_content_length"),"renderBlockingStatus"in t&&(a["resource.render_blocking_status"]=t.renderBlockingStatus);i.protocol&&(a["url.scheme"]=i.protocol.split(":").pop());i.host&&(a["server.address"]=i.host);a["url.same_origin"]=n.includes(vs.location.origin);const c=s+r,u=c+o;Si(e,c,u,{name:n.replace(vs.location.origin,""),op:t.initiatorType?`resource.${t.initiatorType}`:"resource.other",attributes:a})}(e,t,t.name,n,o,r)}})),Mi=Math.max(o.length-1,0),function(e){const t=vs.navigator;if(!t)return;const n=t.connection;n&&(n.effectiveType&&e.setAttribute("effectiveConnectionType",n.effectiveType),n.type&&e.setAttribute("connectionType",n.type),bi(n.rtt)&&(Ri["connection.rtt"]={value:n.rtt,unit:"millisecond"}));bi(t.deviceMemory)&&e.setAttribute("deviceMemory",`${t.deviceMemory} GB`);bi(t.hardwareConcurrency)&&e.setAttribute("hardwareConcurrency",String(t.hardwareConcurrency))}(e),"pageload"===s){!function(e){const t=bs();if(!t)return;const{responseStart:n,requestStart:r}=t;r<=n&&(_s&&L.log("[Measurements] Adding TTFB Request Time"),e["ttfb.requestTime"]={value:n-r,unit:"millisecond"})}(Ri);const n=Ri["mark.fid"];n&&Ri.fid&&(Si(e,n.value,n.value+xi(Ri.fid.value),{name:"first input delay",op:"ui.action",attributes:{[on]:"auto.ui.browser.metrics"}}),delete Ri["mark.fid"]),"fcp"in Ri&&t.recordClsOnPageloadSpan||delete Ri.cls,Object.entries(Ri).forEach((([e,t])=>{Yn(e,t.value,t.unit)})),e.setAttribute("performance.timeOrigin",r),function(e){Ti&&(_s&&L.log("[Measurements] Adding LCP Data"),Ti.element&&e.setAttribute("lcp.element",T(Ti.element)),Ti.id&&e.setAttribute("lcp.id",Ti.id),Ti.url&&e.setAttribute("lcp.url",Ti.url.trim().slice(0,200)),e.setAttribute("lcp.size",Ti.size));Ii&&Ii.sources&&(_s&&L.log("[Measurements] Adding CLS Data"),Ii.sources.forEach(((t,n)=>e.setAttribute(`cls.source.${n+1}`,T(t.node)))))}(e)}Ti=void 0,Ii=void 0,Ri={}}function Oi(e,t,n,r,o,s){const i=s?t[s]:t[`${n}End`],a=t[`${n}Start`];a&&i&&Si(e,r+xi(a),r+xi(i),{op:"browser",name:o||n,attributes:{[on]:"auto.ui.browser.metrics"}})}function Di(e,t,n,r){const o=t[n];null!=o&&o<Ci&&(e[r]=o)}const Li=1e3;let Pi,Fi,$i,Bi;function Ui(e){ce("dom",e),ue("dom",ji)}function ji(){if(!vs.document)return;const e=le.bind(null,"dom"),t=Hi(e,!0);vs.document.addEventListener("click",t,!1),vs.document.addEventListener("keypress",t,!1),["EventTarget","Node"].forEach((t=>{const n=vs[t]&&vs[t].prototype;n&&n.hasOwnProperty&&n.hasOwnProperty("addEventListener")&&(H(n,"addEventListener",(function(t){return function(n,r,o){if("click"===n||"keypress"==n)try{const r=this,s=r.__sentry_instrumentation_handlers__=r.__sentry_instrumentation_handlers__||{},i=s[n]=s[n]||{refCount:0};if(!i.handler){const r=Hi(e);i.handler=r,t.call(this,n,r,o)}i.refCount++}catch(e){}return t.call(this,n,r,o)}})),H(n,"removeEventListener",(function(e){return function(t,n,r){if("click"===t||"keypress"==t)try{const n=this,o=n.__sentry_instrumentation_handlers__||{},s=o[t];s&&(s.refCount--,s.refCount<=0&&(e.call(this,t,s.handler,r),s.handler=void 0,delete o[t]),0===Object.keys(o).length&&delete n.__sentry_instrumentation_handlers__)}catch(e){}return e.call(this,t,n,r)}})))}))}function Hi(e,t=!1){return n=>{if(!n||n._sentryCaptured)return;const r=function(e){try{return e.target}catch(e){return null}}(n);if(function(e,t){return"keypress"===e&&(!t||!t.tagName||"INPUT"!==t.tagName&&"TEXTAREA"!==t.tagName&&!t.isContentEditable)}(n.type,r))return;z(n,"_sentryCaptured",!0),r&&!r._sentryId&&z(r,"_sentryId",De());const o="keypress"===n.type?"input":n.type;if(!function(e){if(e.type!==Fi)return!1;try{if(!e.target||e.target._sentryId!==$i)return!1}catch(e){}return!0}(n)){e({event:n,name:o,global:t}),Fi=n.type,$i=r?r._sentryId:void 0}clearTimeout(Pi),Pi=vs.setTimeout((()=>{$i=void 0,Fi=void 0}),Li)}}function zi(e){const t="history";ce(t,e),ue(t,qi)}function qi(){if(!function(){const e=At.chrome,t=e&&e.app&&e.app.runtime,n="history"in At&&!!At.history.pushState&&!!At.history.replaceState;return!t&&n}())return;const e=vs.onpopstate;function t(e){return function(...t){const n=t.length>2?t[2]:void 0;if(n){const e=Bi,t=String(n);Bi=t;le("history",{from:e,to:t})}return e.apply(this,t)}}vs.onpopstate=function(...t){const n=vs.location.href,r=Bi;Bi=n;if(le("history",{from:r,to:n}),e)try{return e.apply(this,t)}catch(e){}},H(vs.history,"pushState",t),H(vs.history,"replaceState",t)}const Wi={};function Yi(e){const t=Wi[e];if(t)return t;let n=vs[e];if(me(n))return Wi[e]=n.bind(vs);const r=vs.document;if(r&&"function"==typeof r.createElement)try{const t=r.createElement("iframe");t.hidden=!0,r.head.appendChild(t);const o=t.contentWindow;o&&o[e]&&(n=o[e]),r.head.removeChild(t)}catch(t){_s&&L.warn(`Could not create sandbox iframe for ${e} check, bailing to window.${e}: `,t)}return n?Wi[e]=n.bind(vs):n}function Vi(e){Wi[e]=void 0}function Gi(...e){return Yi("setTimeout")(...e)}const Xi="__sentry_xhr_v3__";function Ji(e){ce("xhr",e),ue("xhr",Ki)}function Ki(){if(!vs.XMLHttpRequest)return;const e=XMLHttpRequest.prototype;e.open=new Proxy(e.open,{apply(e,t,n){const r=1e3*ve(),o=s(n[0])?n[0].toUpperCase():void 0,i=function(e){if(s(e))return e;try{return e.toString()}catch(e){}return}(n[1]);if(!o||!i)return e.apply(t,n);t[Xi]={method:o,url:i,request_headers:{}},"POST"===o&&i.match(/sentry_key/)&&(t.__sentry_own_request__=!0);const a=()=>{const e=t[Xi];if(e&&4===t.readyState){try{e.status_code=t.status}catch(e){}le("xhr",{endTimestamp:1e3*ve(),startTimestamp:r,xhr:t})}};return"onreadystatechange"in t&&"function"==typeof t.onreadystatechange?t.onreadystatechange=new Proxy(t.onreadystatechange,{apply:(e,t,n)=>(a(),e.apply(t,n))}):t.addEventListener("readystatechange",a),t.setRequestHeader=new Proxy(t.setRequestHeader,{apply(e,t,n){const[r,o]=n,i=t[Xi];return i&&s(r)&&s(o)&&(i.request_headers[r.toLowerCase()]=o),e.apply(t,n)}}),e.apply(t,n)}}),e.send=new Proxy(e.send,{apply(e,t,n){const r=t[Xi];if(!r)return e.apply(t,n);void 0!==n[0]&&(r.body=n[0]);return le("xhr",{startTimestamp:1e3*ve(),xhr:t}),e.apply(t,n)}})}const Qi=[],Zi=new Map;function ea(){if(ki()&&be){const e=ui((({metric:e})=>{if(null==e.value)return;const t=e.entries.find((t=>t.duration===e.value&&ta[t.name]));if(!t)return;const{interactionId:n}=t,r=ta[t.name],o=xi(be+t.startTime),s=xi(e.value),i=Mn(),a=i?In(i):void 0,c=(null!=n?Zi.get(n):void 0)||a,u=c?Sn(c).description:Gt().getScopeData().transactionName,l=wi({name:T(t.target),transaction:u,attributes:X({[on]:"auto.http.browser.inp",[rn]:`ui.interaction.${r}`,[un]:t.duration}),startTime:o});Mt([l,"optionalAccess",e=>e.addEvent,"call",t=>t("inp",{[an]:"millisecond",[cn]:e.value})]),Mt([l,"optionalAccess",e=>e.end,"call",e=>e(o+s)])}));return()=>{e()}}return()=>{}}const ta={click:"click",pointerdown:"click",pointerup:"click",mousedown:"click",mouseup:"click",touchstart:"click",touchend:"click",mouseover:"hover",mouseout:"hover",mouseenter:"hover",mouseleave:"hover",pointerover:"hover",pointerout:"hover",pointerenter:"hover",pointerleave:"hover",dragstart:"drag",dragend:"drag",drag:"drag",dragenter:"drag",dragleave:"drag",dragover:"drag",drop:"drag",keydown:"press",keyup:"press",keypress:"press",input:"press"};function na(e,t=Yi("fetch")){let n=0,r=0;return Jr(e,(function(o){const s=o.body.length;n+=s,r++;const i={body:o.body,method:"POST",referrerPolicy:"origin",headers:e.headers,keepalive:n<=6e4&&r<15,...e.fetchOptions};if(!t)return Vi("fetch"),Je("No fetch implementation available");try{return t(e.url,i).then((e=>(n-=s,r--,{statusCode:e.status,headers:{"x-sentry-rate-limits":e.headers.get("X-Sentry-Rate-Limits"),"retry-after":e.headers.get("Retry-After")}})))}catch(e){return Vi("fetch"),n-=s,r--,Je(e)}}))}function ra(e,t,n,r){const o={filename:e,function:"<anonymous>"===t?Q:t,in_app:!0};return void 0!==n&&(o.lineno=n),void 0!==r&&(o.colno=r),o}const oa=/^\s*at (\S+?)(?::(\d+))(?::(\d+))\s*$/i,sa=/^\s*at (?:(.+?\)(?: \[.+\])?|.*?) ?\((?:address at )?)?(?:async )?((?:<anonymous>|[-a-z]+:|.*bundle|\/)?.*?)(?::(\d+))?(?::(\d+))?\)?\s*$/i,ia=/\((\S*)(?::(\d+))(?::(\d+))\)/,aa=[30,e=>{const t=oa.exec(e);if(t){const[,e,n,r]=t;return ra(e,Q,+n,+r)}const n=sa.exec(e);if(n){if(n[2]&&0===n[2].indexOf("eval")){const e=ia.exec(n[2]);e&&(n[2]=e[1],n[3]=e[2],n[4]=e[3])}const[e,t]=va(n[1]||Q,n[2]);return ra(t,e,n[3]?+n[3]:void 0,n[4]?+n[4]:void 0)}}],ca=/^\s*(.*?)(?:\((.*?)\))?(?:^|@)?((?:[-a-z]+)?:\/.*?|\[native code\]|[^@]*(?:bundle|\d+\.js)|\/[\w\-. /=]+)(?::(\d+))?(?::(\d+))?\s*$/i,ua=/(\S+) line (\d+)(?: > eval line \d+)* > eval/i,la=[50,e=>{const t=ca.exec(e);if(t){if(t[3]&&t[3].indexOf(" > eval")>-1){const e=ua.exec(t[3]);e&&(t[1]=t[1]||"eval",t[3]=e[1],t[4]=e[2],t[5]="")}let e=t[3],n=t[1]||Q;return[n,e]=va(n,e),ra(e,n,t[4]?+t[4]:void 0,t[5]?+t[5]:void 0)}}],da=/^\s*at (?:((?:\[object object\])?.+) )?\(?((?:[-a-z]+):.*?):(\d+)(?::(\d+))?\)?\s*$/i,pa=[40,e=>{const t=da.exec(e);return t?ra(t[2],t[1]||Q,+t[3],t[4]?+t[4]:void 0):void 0}],ha=/ line (\d+).*script (?:in )?(\S+)(?:: in function (\S+))?$/i,fa=[10,e=>{const t=ha.exec(e);return t?ra(t[2],t[3]||Q,+t[1]):void 0}],ma=/ line (\d+), column (\d+)\s*(?:in (?:<anonymous function: ([^>]+)>|([^)]+))\(.*\))? in (.*):\s*$/i,ga=[20,e=>{const t=ma.exec(e);return t?ra(t[5],t[3]||t[4]||Q,+t[1],+t[2]):void 0}],_a=[aa,la],ya=te(..._a),va=(e,t)=>{const n=-1!==e.indexOf("safari-extension"),r=-1!==e.indexOf("safari-web-extension");return n||r?[-1!==e.indexOf("@")?e.split("@")[0]:Q,n?`safari-extension:${t}`:`safari-web-extension:${t}`]:[e,t]},ba=1024,Sa=(e={})=>{const t={console:!0,dom:!0,fetch:!0,history:!0,sentry:!0,xhr:!0,...e};return{name:"Breadcrumbs",setup(e){t.console&&de(function(e){return function(t){if(Qt()!==e)return;const n={category:"console",data:{arguments:t.args,logger:"console"},level:tt(t.level),message:m(t.args," ")};if("assert"===t.level){if(!1!==t.args[0])return;n.message=`Assertion failed: ${m(t.args.slice(1)," ")||"console.assert"}`,n.data.arguments=t.args.slice(1)}io(n,{input:t.args,level:t.level})}}(e)),t.dom&&Ui(function(e,t){return function(n){if(Qt()!==e)return;let r,o,s="object"==typeof t?t.serializeAttribute:void 0,i="object"==typeof t&&"number"==typeof t.maxStringLength?t.maxStringLength:void 0;i&&i>ba&&(rs&&L.warn(`\`dom.maxStringLength\` cannot exceed 1024, but a value of ${i} was configured. Sentry will use 1024 instead.`),i=ba),"string"==typeof s&&(s=[s]);try{const e=n.event,t=function(e){return!!e&&!!e.target}(e)?e.target:e;r=T(t,{keyAttrs:s,maxStringLength:i}),o=R(t)}catch(e){r="<unknown>"}if(0===r.length)return;const a={category:`ui.${n.name}`,message:r};o&&(a.data={"ui.component_name":o}),io(a,{event:n.event,name:n.name,global:n.global})}}(e,t.dom)),t.xhr&&Ji(function(e){return function(t){if(Qt()!==e)return;const{startTimestamp:n,endTimestamp:r}=t,o=t.xhr[Xi];if(!n||!r||!o)return;const{method:s,url:i,status_code:a,body:c}=o;io({category:"xhr",data:{method:s,url:i,status_code:a},type:"http"},{xhr:t.xhr,input:c,startTimestamp:n,endTimestamp:r})}}(e)),t.fetch&&Se(function(e){return function(t){if(Qt()!==e)return;const{startTimestamp:n,endTimestamp:r}=t;if(r&&(!t.fetchData.url.match(/sentry_key/)||"POST"!==t.fetchData.method))if(t.error){io({category:"fetch",data:t.fetchData,level:"error",type:"http"},{data:t.error,input:t.args,startTimestamp:n,endTimestamp:r})}else{const e=t.response;io({category:"fetch",data:{...t.fetchData,status_code:e&&e.status},type:"http"},{input:t.args,response:e,startTimestamp:n,endTimestamp:r})}}}(e)),t.history&&zi(function(e){return function(t){if(Qt()!==e)return;let n=t.from,r=t.to;const o=Ze(Zo.location.href);let s=n?Ze(n):void 0;const i=Ze(r);s&&s.path||(s=o),o.protocol===i.protocol&&o.host===i.host&&(r=i.relative),o.protocol===s.protocol&&o.host===s.host&&(n=s.relative),io({category:"navigation",data:{from:n,to:r}})}}(e)),t.sentry&&e.on("beforeSendEvent",function(e){return function(t){Qt()===e&&io({category:"sentry."+("transaction"===t.type?"transaction":"event"),event_id:t.event_id,level:t.level,message:Pe(t)},{event:t})}}(e))}}};const wa=["EventTarget","Window","Node","ApplicationCache","AudioTrackList","BroadcastChannel","ChannelMergerNode","CryptoOperation","EventSource","FileReader","HTMLUnknownElement","IDBDatabase","IDBRequest","IDBTransaction","KeyOperation","MediaController","MessagePort","ModalWindow","Notification","SVGElementInstance","Screen","SharedWorker","TextTrack","TextTrackCue","TextTrackList","WebSocket","WebSocketWorker","Worker","XMLHttpRequest","XMLHttpRequestEventTarget","XMLHttpRequestUpload"],ka=(e={})=>{const t={XMLHttpRequest:!0,eventTarget:!0,requestAnimationFrame:!0,setInterval:!0,setTimeout:!0,...e};return{name:"BrowserApiErrors",setupOnce(){t.setTimeout&&H(Zo,"setTimeout",xa),t.setInterval&&H(Zo,"setInterval",xa),t.requestAnimationFrame&&H(Zo,"requestAnimationFrame",Ea),t.XMLHttpRequest&&"XMLHttpRequest"in Zo&&H(XMLHttpRequest.prototype,"send",Ca);const e=t.eventTarget;if(e){(Array.isArray(e)?e:wa).forEach(Ta)}}}};function xa(e){return function(...t){const n=t[0];return t[0]=ns(n,{mechanism:{data:{function:oe(e)},handled:!1,type:"instrument"}}),e.apply(this,t)}}function Ea(e){return function(t){return e.apply(this,[ns(t,{mechanism:{data:{function:"requestAnimationFrame",handler:oe(e)},handled:!1,type:"instrument"}})])}}function Ca(e){return function(...t){const n=this;return["onload","onerror","onprogress","onreadystatechange"].forEach((e=>{e in n&&"function"==typeof n[e]&&H(n,e,(function(t){const n={mechanism:{data:{function:e,handler:oe(t)},handled:!1,type:"instrument"}},r=W(t);return r&&(n.mechanism.data.handler=oe(r)),ns(t,n)}))})),e.apply(this,t)}}function Ta(e){const t=Zo,n=t[e]&&t[e].prototype;n&&n.hasOwnProperty&&n.hasOwnProperty("addEventListener")&&(H(n,"addEventListener",(function(t){return function(n,r,o){try{"function"==typeof r.handleEvent&&(r.handleEvent=ns(r.handleEvent,{mechanism:{data:{function:"handleEvent",handler:oe(r),target:e},handled:!1,type:"instrument"}}))}catch(e){}return t.apply(this,[n,ns(r,{mechanism:{data:{function:"addEventListener",handler:oe(r),target:e},handled:!1,type:"instrument"}}),o])}})),H(n,"removeEventListener",(function(e){return function(t,n,r){const o=n;try{const n=o&&o.__sentry_wrapped__;n&&e.call(this,t,n,r)}catch(e){}return e.call(this,t,o,r)}})))}const Ia=(e={})=>{const t={onerror:!0,onunhandledrejection:!0,...e};return{name:"GlobalHandlers",setupOnce(){Error.stackTraceLimit=50},setup(e){t.onerror&&(!function(e){Te((t=>{const{stackParser:n,attachStacktrace:r}=Ra();if(Qt()!==e||ts())return;const{msg:o,url:i,line:a,column:c,error:u}=t,l=function(e,t,n,r){const o=e.exception=e.exception||{},i=o.values=o.values||[],a=i[0]=i[0]||{},c=a.stacktrace=a.stacktrace||{},u=c.frames=c.frames||[],l=isNaN(parseInt(r,10))?void 0:r,d=isNaN(parseInt(n,10))?void 0:n,p=s(t)&&t.length>0?t:M();0===u.length&&u.push({colno:l,filename:p,function:Q,in_app:!0,lineno:d});return e}(ps(n,u||o,void 0,r,!1),i,a,c);l.level="error",kr(l,{originalException:u,mechanism:{handled:!1,type:"onerror"}})}))}(e),Ma("onerror")),t.onunhandledrejection&&(!function(e){Re((t=>{const{stackParser:n,attachStacktrace:r}=Ra();if(Qt()!==e||ts())return;const o=function(e){if(a(e))return e;try{if("reason"in e)return e.reason;if("detail"in e&&"reason"in e.detail)return e.detail.reason}catch(e){}return e}(t),s=a(o)?{exception:{values:[{type:"UnhandledRejection",value:`Non-Error promise rejection captured with value: ${String(o)}`}]}}:ps(n,o,void 0,r,!0);s.level="error",kr(s,{originalException:o,mechanism:{handled:!1,type:"onunhandledrejection"}})}))}(e),Ma("onunhandledrejection"))}}};function Ma(e){rs&&L.log(`Global Handler attached: ${e}`)}function Ra(){const e=Qt();return e&&e.getOptions()||{stackParser:()=>[],attachStacktrace:!1}}const Aa=()=>({name:"HttpContext",preprocessEvent(e){if(!Zo.navigator&&!Zo.location&&!Zo.document)return;const t=e.request&&e.request.url||Zo.location&&Zo.location.href,{referrer:n}=Zo.document||{},{userAgent:r}=Zo.navigator||{},o={...e.request&&e.request.headers,...n&&{Referer:n},...r&&{"User-Agent":r}},s={...e.request,...t&&{url:t},headers:o};e.request=s}}),Na=(e={})=>{const t=e.limit||5,n=e.key||"cause";return{name:"LinkedErrors",preprocessEvent(e,r,o){const s=o.getOptions();y(os,s.stackParser,s.maxValueLength,n,t,e,r)}}};function Oa(e){return[po(),uo(),ka(),Sa(),Ia(),Na(),wo(),Aa()]}function Da(e={}){const t=function(e={}){const t={defaultIntegrations:Oa(),release:"string"==typeof __SENTRY_RELEASE__?__SENTRY_RELEASE__:Zo.SENTRY_RELEASE&&Zo.SENTRY_RELEASE.id?Zo.SENTRY_RELEASE.id:void 0,autoSessionTracking:!0,sendClientReports:!0};return null==e.defaultIntegrations&&delete e.defaultIntegrations,{...t,...e}}(e);if(function(){const e=void 0!==Zo.window&&Zo;if(!e)return!1;const t=e[e.chrome?"chrome":"browser"],n=t&&t.runtime&&t.runtime.id,r=Zo.location&&Zo.location.href||"",o=!!n&&Zo===Zo.top&&["chrome-extension:","moz-extension:","ms-browser-extension:","safari-web-extension:"].some((e=>r.startsWith(`${e}//`))),s=void 0!==e.nw;return!!n&&!o&&!s}())return void D((()=>{console.error("[Sentry] You cannot run Sentry this way in a browser extension, check: https://docs.sentry.io/platforms/javascript/best-practices/browser-extensions/")}));rs&&(fe()||L.warn("No Fetch API detected. The Sentry SDK requires a Fetch API compatible environment to send events. Please add a Fetch API polyfill."));const n={...t,stackParser:(r=t.stackParser||ya,Array.isArray(r)?te(...r):r),integrations:jr(t),transport:t.transport||na};var r;const o=function(e,t){!0===t.debug&&(Nt?L.enable():D((()=>{console.warn("[Sentry] Cannot initialize SDK with `debug` option using a non-debug bundle.")}))),Gt().update(t.initialScope);const n=new e(t);return Gr(n),n.init(),n}(gs,n);return t.autoSessionTracking&&function(){if(void 0===Zo.document)return void(rs&&L.warn("Session tracking in non-browser environment with @sentry/browser is not supported."));Nr({ignoreDuration:!0}),Lr(),zi((({from:e,to:t})=>{void 0!==e&&e!==t&&(Nr({ignoreDuration:!0}),Lr())}))}(),o}const La={replayIntegration:"replay",replayCanvasIntegration:"replay-canvas",feedbackIntegration:"feedback",feedbackModalIntegration:"feedback-modal",feedbackScreenshotIntegration:"feedback-screenshot",captureConsoleIntegration:"captureconsole",contextLinesIntegration:"contextlines",linkedErrorsIntegration:"linkederrors",debugIntegration:"debug",dedupeIntegration:"dedupe",extraErrorDataIntegration:"extraerrordata",httpClientIntegration:"httpclient",reportingObserverIntegration:"reportingobserver",rewriteFramesIntegration:"rewriteframes",sessionTimingIntegration:"sessiontiming",browserProfilingIntegration:"browserprofiling"},Pa=Zo;async function Fa(e,t){const n=La[e],r=Pa.Sentry=Pa.Sentry||{};if(!n)throw new Error(`Cannot lazy load integration: ${e}`);const o=r[e];if("function"==typeof o&&!("_isShim"in o))return o;const s=function(e){const t=Qt(),n=t&&t.getOptions(),r=n&&n.cdnBaseUrl||"https://browser.sentry-cdn.com";return new URL(`/${w}/${e}.min.js`,r).toString()}(n),i=Zo.document.createElement("script");i.src=s,i.crossOrigin="anonymous",i.referrerPolicy="origin",t&&i.setAttribute("nonce",t);const a=new Promise(((e,t)=>{i.addEventListener("load",(()=>e())),i.addEventListener("error",t)}));Zo.document.body.appendChild(i);try{await a}catch(t){throw new Error(`Error when loading integration: ${e}`)}const c=r[e];if("function"!=typeof c)throw new Error(`Could not load integration: ${e}`);return c}const $a=k,Ba=new WeakMap,Ua=(e={})=>{const t=e.types||["crash","deprecation","intervention"];function n(e){if(Ba.has(Qt()))for(const t of e)Kt((e=>{e.setExtra("url",t.url);const n=`ReportingObserver [${t.type}]`;let r="No details available";if(t.body){const n={};for(const e in t.body)n[e]=t.body[e];if(e.setExtra("body",n),"crash"===t.type){const e=t.body;r=[e.crashId||"",e.reason||""].join(" ").trim()||r}else{r=t.body.message||r}}wr(`${n}: ${r}`)}))}return{name:"ReportingObserver",setupOnce(){if(!("ReportingObserver"in he))return;new $a.ReportingObserver(n,{buffered:!0,types:t}).observe()},setup(e){Ba.set(e,!0)}}},ja=(e={})=>{const t={failedRequestStatusCodes:[[500,599]],failedRequestTargets:[/.*/],...e};return{name:"HttpClient",setup(e){!function(e,t){if(!ge())return;Se((n=>{if(Qt()!==e)return;const{response:r,args:o}=n,[s,i]=o;r&&function(e,t,n,r){if(Wa(e,n.status,n.url)){const e=function(e,t){if(!t&&e instanceof Request)return e;if(e instanceof Request&&e.bodyUsed)return e;return new Request(e,t)}(t,r);let o,s,i,a;Va()&&([o,i]=Ha("Cookie",e),[s,a]=Ha("Set-Cookie",n));kr(Ya({url:e.url,method:e.method,status:n.status,requestHeaders:o,responseHeaders:s,requestCookies:i,responseCookies:a}))}}(t,s,r,i)}))}(e,t),function(e,t){if(!("XMLHttpRequest"in k))return;Ji((n=>{if(Qt()!==e)return;const r=n.xhr,o=r[Xi];if(!o)return;const{method:s,request_headers:i}=o;try{!function(e,t,n,r){if(Wa(e,t.status,t.responseURL)){let e,o,s;if(Va()){try{const e=t.getResponseHeader("Set-Cookie")||t.getResponseHeader("set-cookie")||void 0;e&&(o=qa(e))}catch(e){rs&&L.log("Could not extract cookies from response headers")}try{s=function(e){const t=e.getAllResponseHeaders();if(!t)return{};return t.split("\r\n").reduce(((e,t)=>{const[n,r]=t.split(": ");return n&&r&&(e[n]=r),e}),{})}(t)}catch(e){rs&&L.log("Could not extract headers from response")}e=r}kr(Ya({url:t.responseURL,method:n,status:t.status,requestHeaders:e,responseHeaders:s,responseCookies:o}))}}(t,r,s,i)}catch(e){rs&&L.warn("Error while extracting response event form XHR response",e)}}))}(e,t)}}};function Ha(e,t){const n=function(e){const t={};return e.forEach(((e,n)=>{t[n]=e})),t}(t.headers);let r;try{const t=n[e]||n[e.toLowerCase()]||void 0;t&&(r=qa(t))}catch(t){rs&&L.log(`Could not extract cookies from header ${e}`)}return[n,r]}function za(e){if(e){const t=e["Content-Length"]||e["content-length"];if(t)return parseInt(t,10)}}function qa(e){return e.split("; ").reduce(((e,t)=>{const[n,r]=t.split("=");return n&&r&&(e[n]=r),e}),{})}function Wa(e,t,n){return function(e,t){return e.some((e=>"number"==typeof e?e===t:t>=e[0]&&t<=e[1]))}(e.failedRequestStatusCodes,t)&&(r=e.failedRequestTargets,o=n,r.some((e=>"string"==typeof e?o.includes(e):e.test(o))))&&!ro(n,Qt());var r,o}function Ya(e){const t=`HTTP Client Error with status code: ${e.status}`,n={message:t,exception:{values:[{type:"Error",value:t}]},request:{url:e.url,method:e.method,headers:e.requestHeaders,cookies:e.requestCookies},contexts:{response:{status_code:e.status,headers:e.responseHeaders,cookies:e.responseCookies,body_size:za(e.responseHeaders)}}};return $e(n,{type:"http.client",handled:!1}),n}function Va(){const e=Qt();return!!e&&Boolean(e.getOptions().sendDefaultPii)}const Ga=k,Xa=(e={})=>{const t=null!=e.frameContextLines?e.frameContextLines:7;return{name:"ContextLines",processEvent:e=>function(e,t){const n=Ga.document,r=Ga.location&&(o=Ga.location.href,o.split(/[?#]/,1)[0]);var o;if(!n||!r)return e;const s=e.exception&&e.exception.values;if(!s||!s.length)return e;const i=n.documentElement.innerHTML;if(!i)return e;const a=["<!DOCTYPE html>","<html>",...i.split("\n"),"</html>"];return s.forEach((e=>{const n=e.stacktrace;n&&n.frames&&(n.frames=n.frames.map((e=>function(e,t,n,r){if(e.filename!==n||!e.lineno||!t.length)return e;return function(e,t,n=5){if(void 0===t.lineno)return;const r=e.length,o=Math.max(Math.min(r-1,t.lineno-1),0);t.pre_context=e.slice(Math.max(0,o-n),o).map((e=>f(e,0)));const s=Math.min(r-1,o);t.context_line=f(e[s],t.colno||0),t.post_context=e.slice(Math.min(o+1,r),o+1+n).map((e=>f(e,0)))}(t,e,r),e}(e,a,r,t))))})),e}(e,t)}};const Ja=k,Ka="sentryReplaySession",Qa="replay_event",Za="Unable to send Replay",ec=15e4,tc=5e3,nc=2e7,rc=36e5;function oc(e,t){return null!=e?e:t()}function sc(e){let t,n=e[0],r=1;for(;r<e.length;){const o=e[r],s=e[r+1];if(r+=2,("optionalAccess"===o||"optionalCall"===o)&&null==n)return;"access"===o||"optionalAccess"===o?(t=n,n=s(n)):"call"!==o&&"optionalCall"!==o||(n=s(((...e)=>n.call(t,...e))),t=void 0)}return n}var ic;function ac(e){const t=sc([e,"optionalAccess",e=>e.host]);return Boolean(sc([t,"optionalAccess",e=>e.shadowRoot])===e)}function cc(e){return"[object ShadowRoot]"===Object.prototype.toString.call(e)}function uc(e){try{const n=e.rules||e.cssRules;return n?((t=Array.from(n,lc).join("")).includes(" background-clip: text;")&&!t.includes(" -webkit-background-clip: text;")&&(t=t.replace(" background-clip: text;"," -webkit-background-clip: text; background-clip: text;")),t):null}catch(e){return null}var t}function lc(e){let t;if(function(e){return"styleSheet"in e}(e))try{t=uc(e.styleSheet)||function(e){const{cssText:t}=e;if(t.split('"').length<3)return t;const n=["@import",`url(${JSON.stringify(e.href)})`];return""===e.layerName?n.push("layer"):e.layerName&&n.push(`layer(${e.layerName})`),e.supportsText&&n.push(`supports(${e.supportsText})`),e.media.length&&n.push(e.media.mediaText),n.join(" ")+";"}(e)}catch(e){}else if(function(e){return"selectorText"in e}(e)&&e.selectorText.includes(":"))return function(e){const t=/(\[(?:[\w-]+)[^\\])(:(?:[\w-]+)\])/gm;return e.replace(t,"$1\\$2")}(e.cssText);return t||e.cssText}!function(e){e[e.Document=0]="Document",e[e.DocumentType=1]="DocumentType",e[e.Element=2]="Element",e[e.Text=3]="Text",e[e.CDATA=4]="CDATA",e[e.Comment=5]="Comment"}(ic||(ic={}));class dc{constructor(){this.idNodeMap=new Map,this.nodeMetaMap=new WeakMap}getId(e){if(!e)return-1;return oc(sc([this,"access",e=>e.getMeta,"call",t=>t(e),"optionalAccess",e=>e.id]),(()=>-1))}getNode(e){return this.idNodeMap.get(e)||null}getIds(){return Array.from(this.idNodeMap.keys())}getMeta(e){return this.nodeMetaMap.get(e)||null}removeNodeFromMap(e){const t=this.getId(e);this.idNodeMap.delete(t),e.childNodes&&e.childNodes.forEach((e=>this.removeNodeFromMap(e)))}has(e){return this.idNodeMap.has(e)}hasNode(e){return this.nodeMetaMap.has(e)}add(e,t){const n=t.id;this.idNodeMap.set(n,e),this.nodeMetaMap.set(e,t)}replace(e,t){const n=this.getNode(e);if(n){const e=this.nodeMetaMap.get(n);e&&this.nodeMetaMap.set(t,e)}this.idNodeMap.set(e,t)}reset(){this.idNodeMap=new Map,this.nodeMetaMap=new WeakMap}}function pc({maskInputOptions:e,tagName:t,type:n}){return"OPTION"===t&&(t="SELECT"),Boolean(e[t.toLowerCase()]||n&&e[n]||"password"===n||"INPUT"===t&&!n&&e.text)}function hc({isMasked:e,element:t,value:n,maskInputFn:r}){let o=n||"";return e?(r&&(o=r(o,t)),"*".repeat(o.length)):o}function fc(e){return e.toLowerCase()}function mc(e){return e.toUpperCase()}const gc="__rrweb_original__";function _c(e){const t=e.type;return e.hasAttribute("data-rr-is-password")?"password":t?fc(t):null}function yc(e,t,n){return"INPUT"!==t||"radio"!==n&&"checkbox"!==n?e.value:e.getAttribute("value")||""}function vc(e,t){let n;try{n=new URL(e,oc(t,(()=>window.location.href)))}catch(e){return null}return oc(sc([n.pathname.match(/\.([0-9a-z]+)(?:$)/i),"optionalAccess",e=>e[1]]),(()=>null))}const bc={};function Sc(e){const t=bc[e];if(t)return t;const n=window.document;let r=window[e];if(n&&"function"==typeof n.createElement)try{const t=n.createElement("iframe");t.hidden=!0,n.head.appendChild(t);const o=t.contentWindow;o&&o[e]&&(r=o[e]),n.head.removeChild(t)}catch(e){}return bc[e]=r.bind(window)}function wc(...e){return Sc("setTimeout")(...e)}function kc(...e){return Sc("clearTimeout")(...e)}let xc=1;const Ec=new RegExp("[^a-z0-9-_:]"),Cc=-2;function Tc(){return xc++}let Ic,Mc;const Rc=/url\((?:(')([^']*)'|(")(.*?)"|([^)]*))\)/gm,Ac=/^(?:[a-z+]+:)?\/\//i,Nc=/^www\..*/i,Oc=/^(data:)([^,]*),(.*)/i;function Dc(e,t){return(e||"").replace(Rc,((e,n,r,o,s,i)=>{const a=r||s||i,c=n||o||"";if(!a)return e;if(Ac.test(a)||Nc.test(a))return`url(${c}${a}${c})`;if(Oc.test(a))return`url(${c}${a}${c})`;if("/"===a[0])return`url(${c}${function(e){let t="";return t=e.indexOf("//")>-1?e.split("/").slice(0,3).join("/"):e.split("/")[0],t=t.split("?")[0],t}(t)+a}${c})`;const u=t.split("/"),l=a.split("/");u.pop();for(const e of l)"."!==e&&(".."===e?u.pop():u.push(e));return`url(${c}${u.join("/")}${c})`}))}const Lc=/^[^ \t\n\r\u000c]+/,Pc=/^[, \t\n\r\u000c]+/;function Fc(e,t){if(!t||""===t.trim())return t;const n=e.createElement("a");return n.href=t,n.href}function $c(e){return Boolean("svg"===e.tagName||e.ownerSVGElement)}function Bc(){const e=document.createElement("a");return e.href="",e.href}function Uc(e,t,n,r,o,s){return r?"src"===n||"href"===n&&("use"!==t||"#"!==r[0])||"xlink:href"===n&&"#"!==r[0]?Fc(e,r):"background"!==n||"table"!==t&&"td"!==t&&"th"!==t?"srcset"===n?function(e,t){if(""===t.trim())return t;let n=0;function r(e){let r;const o=e.exec(t.substring(n));return o?(r=o[0],n+=r.length,r):""}const o=[];for(;r(Pc),!(n>=t.length);){let s=r(Lc);if(","===s.slice(-1))s=Fc(e,s.substring(0,s.length-1)),o.push(s);else{let r="";s=Fc(e,s);let i=!1;for(;;){const e=t.charAt(n);if(""===e){o.push((s+r).trim());break}if(i)")"===e&&(i=!1);else{if(","===e){n+=1,o.push((s+r).trim());break}"("===e&&(i=!0)}r+=e,n+=1}}}return o.join(", ")}(e,r):"style"===n?Dc(r,Bc()):"object"===t&&"data"===n?Fc(e,r):"function"==typeof s?s(n,r,o):r:Fc(e,r):r}function jc(e,t,n){return("video"===e||"audio"===e)&&"autoplay"===t}function Hc(e,t,n=1/0,r=0){return e?e.nodeType!==e.ELEMENT_NODE||r>n?-1:t(e)?r:Hc(e.parentNode,t,n,r+1):-1}function zc(e,t){return n=>{const r=n;if(null===r)return!1;try{if(e)if("string"==typeof e){if(r.matches(`.${e}`))return!0}else if(function(e,t){for(let n=e.classList.length;n--;){const r=e.classList[n];if(t.test(r))return!0}return!1}(r,e))return!0;return!(!t||!r.matches(t))}catch(e){return!1}}}function qc(e,t,n,r,o,s){try{const i=e.nodeType===e.ELEMENT_NODE?e:e.parentElement;if(null===i)return!1;if("INPUT"===i.tagName){const e=i.getAttribute("autocomplete");if(["current-password","new-password","cc-number","cc-exp","cc-exp-month","cc-exp-year","cc-csc"].includes(e))return!0}let a=-1,c=-1;if(s){if(c=Hc(i,zc(r,o)),c<0)return!0;a=Hc(i,zc(t,n),c>=0?c:1/0)}else{if(a=Hc(i,zc(t,n)),a<0)return!1;c=Hc(i,zc(r,o),a>=0?a:1/0)}return a>=0?!(c>=0)||a<=c:!(c>=0)&&!!s}catch(e){}return!!s}function Wc(e,t){const{doc:n,mirror:r,blockClass:o,blockSelector:s,unblockSelector:i,maskAllText:a,maskAttributeFn:c,maskTextClass:u,unmaskTextClass:l,maskTextSelector:d,unmaskTextSelector:p,inlineStylesheet:h,maskInputOptions:f={},maskTextFn:m,maskInputFn:g,dataURLOptions:_={},inlineImages:y,recordCanvas:v,keepIframeSrcFn:b,newlyAddedElement:S=!1}=t,w=function(e,t){if(!t.hasNode(e))return;const n=t.getId(e);return 1===n?void 0:n}(n,r);switch(e.nodeType){case e.DOCUMENT_NODE:return"CSS1Compat"!==e.compatMode?{type:ic.Document,childNodes:[],compatMode:e.compatMode}:{type:ic.Document,childNodes:[]};case e.DOCUMENT_TYPE_NODE:return{type:ic.DocumentType,name:e.name,publicId:e.publicId,systemId:e.systemId,rootId:w};case e.ELEMENT_NODE:return function(e,t){const{doc:n,blockClass:r,blockSelector:o,unblockSelector:s,inlineStylesheet:i,maskInputOptions:a={},maskAttributeFn:c,maskInputFn:u,dataURLOptions:l={},inlineImages:d,recordCanvas:p,keepIframeSrcFn:h,newlyAddedElement:f=!1,rootId:m,maskAllText:g,maskTextClass:_,unmaskTextClass:y,maskTextSelector:v,unmaskTextSelector:b}=t,S=function(e,t,n,r){try{if(r&&e.matches(r))return!1;if("string"==typeof t){if(e.classList.contains(t))return!0}else for(let n=e.classList.length;n--;){const r=e.classList[n];if(t.test(r))return!0}if(n)return e.matches(n)}catch(e){}return!1}(e,r,o,s),w=function(e){if(e instanceof HTMLFormElement)return"form";const t=fc(e.tagName);return Ec.test(t)?"div":t}(e);let k={};const x=e.attributes.length;for(let t=0;t<x;t++){const r=e.attributes[t];r.name&&!jc(w,r.name,r.value)&&(k[r.name]=Uc(n,w,fc(r.name),r.value,e,c))}if("link"===w&&i){const t=Array.from(n.styleSheets).find((t=>t.href===e.href));let r=null;t&&(r=uc(t)),r&&(delete k.rel,delete k.href,k._cssText=Dc(r,t.href))}if("style"===w&&e.sheet&&!(e.innerText||e.textContent||"").trim().length){const t=uc(e.sheet);t&&(k._cssText=Dc(t,Bc()))}if("input"===w||"textarea"===w||"select"===w||"option"===w){const t=e,n=_c(t),r=yc(t,mc(w),n),o=t.checked;if("submit"!==n&&"button"!==n&&r){const e=qc(t,_,v,y,b,pc({type:n,tagName:mc(w),maskInputOptions:a}));k.value=hc({isMasked:e,element:t,value:r,maskInputFn:u})}o&&(k.checked=o)}"option"===w&&(e.selected&&!a.select?k.selected=!0:delete k.selected);if("canvas"===w&&p)if("2d"===e.__context)(function(e){const t=e.getContext("2d");if(!t)return!0;for(let n=0;n<e.width;n+=50)for(let r=0;r<e.height;r+=50){const o=t.getImageData,s=gc in o?o[gc]:o;if(new Uint32Array(s.call(t,n,r,Math.min(50,e.width-n),Math.min(50,e.height-r)).data.buffer).some((e=>0!==e)))return!1}return!0})(e)||(k.rr_dataURL=e.toDataURL(l.type,l.quality));else if(!("__context"in e)){const t=e.toDataURL(l.type,l.quality),n=document.createElement("canvas");n.width=e.width,n.height=e.height;t!==n.toDataURL(l.type,l.quality)&&(k.rr_dataURL=t)}if("img"===w&&d){Ic||(Ic=n.createElement("canvas"),Mc=Ic.getContext("2d"));const t=e,r=t.crossOrigin;t.crossOrigin="anonymous";const o=()=>{t.removeEventListener("load",o);try{Ic.width=t.naturalWidth,Ic.height=t.naturalHeight,Mc.drawImage(t,0,0),k.rr_dataURL=Ic.toDataURL(l.type,l.quality)}catch(e){console.warn(`Cannot inline img src=${t.currentSrc}! Error: ${e}`)}r?k.crossOrigin=r:t.removeAttribute("crossorigin")};t.complete&&0!==t.naturalWidth?o():t.addEventListener("load",o)}"audio"!==w&&"video"!==w||(k.rr_mediaState=e.paused?"paused":"played",k.rr_mediaCurrentTime=e.currentTime);f||(e.scrollLeft&&(k.rr_scrollLeft=e.scrollLeft),e.scrollTop&&(k.rr_scrollTop=e.scrollTop));if(S){const{width:t,height:n}=e.getBoundingClientRect();k={class:k.class,rr_width:`${t}px`,rr_height:`${n}px`}}"iframe"!==w||h(k.src)||(S||e.contentDocument||(k.rr_src=k.src),delete k.src);let E;try{customElements.get(w)&&(E=!0)}catch(e){}return{type:ic.Element,tagName:w,attributes:k,childNodes:[],isSVG:$c(e)||void 0,needBlock:S,rootId:m,isCustom:E}}(e,{doc:n,blockClass:o,blockSelector:s,unblockSelector:i,inlineStylesheet:h,maskAttributeFn:c,maskInputOptions:f,maskInputFn:g,dataURLOptions:_,inlineImages:y,recordCanvas:v,keepIframeSrcFn:b,newlyAddedElement:S,rootId:w,maskAllText:a,maskTextClass:u,unmaskTextClass:l,maskTextSelector:d,unmaskTextSelector:p});case e.TEXT_NODE:return function(e,t){const{maskAllText:n,maskTextClass:r,unmaskTextClass:o,maskTextSelector:s,unmaskTextSelector:i,maskTextFn:a,maskInputOptions:c,maskInputFn:u,rootId:l}=t,d=e.parentNode&&e.parentNode.tagName;let p=e.textContent;const h="STYLE"===d||void 0,f="SCRIPT"===d||void 0,m="TEXTAREA"===d||void 0;if(h&&p){try{e.nextSibling||e.previousSibling||sc([e,"access",e=>e.parentNode,"access",e=>e.sheet,"optionalAccess",e=>e.cssRules])&&(p=uc(e.parentNode.sheet))}catch(t){console.warn(`Cannot get CSS styles from text's parentNode. Error: ${t}`,e)}p=Dc(p,Bc())}f&&(p="SCRIPT_PLACEHOLDER");const g=qc(e,r,s,o,i,n);h||f||m||!p||!g||(p=a?a(p,e.parentElement):p.replace(/[\S]/g,"*"));m&&p&&(c.textarea||g)&&(p=u?u(p,e.parentNode):p.replace(/[\S]/g,"*"));if("OPTION"===d&&p){p=hc({isMasked:qc(e,r,s,o,i,pc({type:null,tagName:d,maskInputOptions:c})),element:e,value:p,maskInputFn:u})}return{type:ic.Text,textContent:p||"",isStyle:h,rootId:l}}(e,{maskAllText:a,maskTextClass:u,unmaskTextClass:l,maskTextSelector:d,unmaskTextSelector:p,maskTextFn:m,maskInputOptions:f,maskInputFn:g,rootId:w});case e.CDATA_SECTION_NODE:return{type:ic.CDATA,textContent:"",rootId:w};case e.COMMENT_NODE:return{type:ic.Comment,textContent:e.textContent||"",rootId:w};default:return!1}}function Yc(e){return null==e?"":e.toLowerCase()}function Vc(e,t){const{doc:n,mirror:r,blockClass:o,blockSelector:s,unblockSelector:i,maskAllText:a,maskTextClass:c,unmaskTextClass:u,maskTextSelector:l,unmaskTextSelector:d,skipChild:p=!1,inlineStylesheet:h=!0,maskInputOptions:f={},maskAttributeFn:m,maskTextFn:g,maskInputFn:_,slimDOMOptions:y,dataURLOptions:v={},inlineImages:b=!1,recordCanvas:S=!1,onSerialize:w,onIframeLoad:k,iframeLoadTimeout:x=5e3,onStylesheetLoad:E,stylesheetLoadTimeout:C=5e3,keepIframeSrcFn:T=()=>!1,newlyAddedElement:I=!1}=t;let{preserveWhiteSpace:M=!0}=t;const R=Wc(e,{doc:n,mirror:r,blockClass:o,blockSelector:s,maskAllText:a,unblockSelector:i,maskTextClass:c,unmaskTextClass:u,maskTextSelector:l,unmaskTextSelector:d,inlineStylesheet:h,maskInputOptions:f,maskAttributeFn:m,maskTextFn:g,maskInputFn:_,dataURLOptions:v,inlineImages:b,recordCanvas:S,keepIframeSrcFn:T,newlyAddedElement:I});if(!R)return console.warn(e,"not serialized"),null;let A;A=r.hasNode(e)?r.getId(e):!function(e,t){if(t.comment&&e.type===ic.Comment)return!0;if(e.type===ic.Element){if(t.script&&("script"===e.tagName||"link"===e.tagName&&("preload"===e.attributes.rel||"modulepreload"===e.attributes.rel)&&"script"===e.attributes.as||"link"===e.tagName&&"prefetch"===e.attributes.rel&&"string"==typeof e.attributes.href&&"js"===vc(e.attributes.href)))return!0;if(t.headFavicon&&("link"===e.tagName&&"shortcut icon"===e.attributes.rel||"meta"===e.tagName&&(Yc(e.attributes.name).match(/^msapplication-tile(image|color)$/)||"application-name"===Yc(e.attributes.name)||"icon"===Yc(e.attributes.rel)||"apple-touch-icon"===Yc(e.attributes.rel)||"shortcut icon"===Yc(e.attributes.rel))))return!0;if("meta"===e.tagName){if(t.headMetaDescKeywords&&Yc(e.attributes.name).match(/^description|keywords$/))return!0;if(t.headMetaSocial&&(Yc(e.attributes.property).match(/^(og|twitter|fb):/)||Yc(e.attributes.name).match(/^(og|twitter):/)||"pinterest"===Yc(e.attributes.name)))return!0;if(t.headMetaRobots&&("robots"===Yc(e.attributes.name)||"googlebot"===Yc(e.attributes.name)||"bingbot"===Yc(e.attributes.name)))return!0;if(t.headMetaHttpEquiv&&void 0!==e.attributes["http-equiv"])return!0;if(t.headMetaAuthorship&&("author"===Yc(e.attributes.name)||"generator"===Yc(e.attributes.name)||"framework"===Yc(e.attributes.name)||"publisher"===Yc(e.attributes.name)||"progid"===Yc(e.attributes.name)||Yc(e.attributes.property).match(/^article:/)||Yc(e.attributes.property).match(/^product:/)))return!0;if(t.headMetaVerification&&("google-site-verification"===Yc(e.attributes.name)||"yandex-verification"===Yc(e.attributes.name)||"csrf-token"===Yc(e.attributes.name)||"p:domain_verify"===Yc(e.attributes.name)||"verify-v1"===Yc(e.attributes.name)||"verification"===Yc(e.attributes.name)||"shopify-checkout-api-token"===Yc(e.attributes.name)))return!0}}return!1}(R,y)&&(M||R.type!==ic.Text||R.isStyle||R.textContent.replace(/^\s+|\s+$/gm,"").length)?Tc():Cc;const N=Object.assign(R,{id:A});if(r.add(e,N),A===Cc)return null;w&&w(e);let O=!p;if(N.type===ic.Element){O=O&&!N.needBlock,delete N.needBlock;const t=e.shadowRoot;t&&cc(t)&&(N.isShadowHost=!0)}if((N.type===ic.Document||N.type===ic.Element)&&O){y.headWhitespace&&N.type===ic.Element&&"head"===N.tagName&&(M=!1);const t={doc:n,mirror:r,blockClass:o,blockSelector:s,maskAllText:a,unblockSelector:i,maskTextClass:c,unmaskTextClass:u,maskTextSelector:l,unmaskTextSelector:d,skipChild:p,inlineStylesheet:h,maskInputOptions:f,maskAttributeFn:m,maskTextFn:g,maskInputFn:_,slimDOMOptions:y,dataURLOptions:v,inlineImages:b,recordCanvas:S,preserveWhiteSpace:M,onSerialize:w,onIframeLoad:k,iframeLoadTimeout:x,onStylesheetLoad:E,stylesheetLoadTimeout:C,keepIframeSrcFn:T};for(const n of Array.from(e.childNodes)){const e=Vc(n,t);e&&N.childNodes.push(e)}if(function(e){return e.nodeType===e.ELEMENT_NODE}(e)&&e.shadowRoot)for(const n of Array.from(e.shadowRoot.childNodes)){const r=Vc(n,t);r&&(cc(e.shadowRoot)&&(r.isShadow=!0),N.childNodes.push(r))}}return e.parentNode&&ac(e.parentNode)&&cc(e.parentNode)&&(N.isShadow=!0),N.type===ic.Element&&"iframe"===N.tagName&&function(e,t,n){const r=e.contentWindow;if(!r)return;let o,s=!1;try{o=r.document.readyState}catch(e){return}if("complete"!==o){const r=wc((()=>{s||(t(),s=!0)}),n);return void e.addEventListener("load",(()=>{kc(r),s=!0,t()}))}const i="about:blank";if(r.location.href!==i||e.src===i||""===e.src)return wc(t,0),e.addEventListener("load",t);e.addEventListener("load",t)}(e,(()=>{const t=e.contentDocument;if(t&&k){const n=Vc(t,{doc:t,mirror:r,blockClass:o,blockSelector:s,unblockSelector:i,maskAllText:a,maskTextClass:c,unmaskTextClass:u,maskTextSelector:l,unmaskTextSelector:d,skipChild:!1,inlineStylesheet:h,maskInputOptions:f,maskAttributeFn:m,maskTextFn:g,maskInputFn:_,slimDOMOptions:y,dataURLOptions:v,inlineImages:b,recordCanvas:S,preserveWhiteSpace:M,onSerialize:w,onIframeLoad:k,iframeLoadTimeout:x,onStylesheetLoad:E,stylesheetLoadTimeout:C,keepIframeSrcFn:T});n&&k(e,n)}}),x),N.type===ic.Element&&"link"===N.tagName&&"string"==typeof N.attributes.rel&&("stylesheet"===N.attributes.rel||"preload"===N.attributes.rel&&"string"==typeof N.attributes.href&&"css"===vc(N.attributes.href))&&function(e,t,n){let r,o=!1;try{r=e.sheet}catch(e){return}if(r)return;const s=wc((()=>{o||(t(),o=!0)}),n);e.addEventListener("load",(()=>{kc(s),o=!0,t()}))}(e,(()=>{if(E){const t=Vc(e,{doc:n,mirror:r,blockClass:o,blockSelector:s,unblockSelector:i,maskAllText:a,maskTextClass:c,unmaskTextClass:u,maskTextSelector:l,unmaskTextSelector:d,skipChild:!1,inlineStylesheet:h,maskInputOptions:f,maskAttributeFn:m,maskTextFn:g,maskInputFn:_,slimDOMOptions:y,dataURLOptions:v,inlineImages:b,recordCanvas:S,preserveWhiteSpace:M,onSerialize:w,onIframeLoad:k,iframeLoadTimeout:x,onStylesheetLoad:E,stylesheetLoadTimeout:C,keepIframeSrcFn:T});t&&E(e,t)}}),C),N}function Gc(e){let t,n=e[0],r=1;for(;r<e.length;){const o=e[r],s=e[r+1];if(r+=2,("optionalAccess"===o||"optionalCall"===o)&&null==n)return;"access"===o||"optionalAccess"===o?(t=n,n=s(n)):"call"!==o&&"optionalCall"!==o||(n=s(((...e)=>n.call(t,...e))),t=void 0)}return n}function Xc(e,t,n=document){const r={capture:!0,passive:!0};return n.addEventListener(e,t,r),()=>n.removeEventListener(e,t,r)}const Jc="Please stop import mirror directly. Instead of that,\r\nnow you can use replayer.getMirror() to access the mirror instance of a replayer,\r\nor you can use record.mirror to access the mirror instance during recording.";let Kc={map:{},getId:()=>(console.error(Jc),-1),getNode:()=>(console.error(Jc),null),removeNodeFromMap(){console.error(Jc)},has:()=>(console.error(Jc),!1),reset(){console.error(Jc)}};function Qc(e,t,n={}){let r=null,o=0;return function(...s){const i=Date.now();o||!1!==n.leading||(o=i);const a=t-(i-o),c=this;a<=0||a>t?(r&&(!function(...e){yu("clearTimeout")(...e)}(r),r=null),o=i,e.apply(c,s)):r||!1===n.trailing||(r=vu((()=>{o=!1===n.leading?0:Date.now(),r=null,e.apply(c,s)}),a))}}function Zc(e,t,n,r,o=window){const s=o.Object.getOwnPropertyDescriptor(e,t);return o.Object.defineProperty(e,t,r?n:{set(e){vu((()=>{n.set.call(this,e)}),0),s&&s.set&&s.set.call(this,e)}}),()=>Zc(e,t,s||{},!0)}function eu(e,t,n){try{if(!(t in e))return()=>{};const r=e[t],o=n(r);return"function"==typeof o&&(o.prototype=o.prototype||{},Object.defineProperties(o,{__rrweb_original__:{enumerable:!1,value:r}})),e[t]=o,()=>{e[t]=r}}catch(e){return()=>{}}}"undefined"!=typeof window&&window.Proxy&&window.Reflect&&(Kc=new Proxy(Kc,{get:(e,t,n)=>("map"===t&&console.error(Jc),Reflect.get(e,t,n))}));let tu=Date.now;function nu(e){const t=e.document;return{left:t.scrollingElement?t.scrollingElement.scrollLeft:void 0!==e.pageXOffset?e.pageXOffset:Gc([t,"optionalAccess",e=>e.documentElement,"access",e=>e.scrollLeft])||Gc([t,"optionalAccess",e=>e.body,"optionalAccess",e=>e.parentElement,"optionalAccess",e=>e.scrollLeft])||Gc([t,"optionalAccess",e=>e.body,"optionalAccess",e=>e.scrollLeft])||0,top:t.scrollingElement?t.scrollingElement.scrollTop:void 0!==e.pageYOffset?e.pageYOffset:Gc([t,"optionalAccess",e=>e.documentElement,"access",e=>e.scrollTop])||Gc([t,"optionalAccess",e=>e.body,"optionalAccess",e=>e.parentElement,"optionalAccess",e=>e.scrollTop])||Gc([t,"optionalAccess",e=>e.body,"optionalAccess",e=>e.scrollTop])||0}}function ru(){return window.innerHeight||document.documentElement&&document.documentElement.clientHeight||document.body&&document.body.clientHeight}function ou(){return window.innerWidth||document.documentElement&&document.documentElement.clientWidth||document.body&&document.body.clientWidth}function su(e){if(!e)return null;return e.nodeType===e.ELEMENT_NODE?e:e.parentElement}function iu(e,t,n,r,o){if(!e)return!1;const s=su(e);if(!s)return!1;const i=zc(t,n);if(!o){const e=r&&s.matches(r);return i(s)&&!e}const a=Hc(s,i);let c=-1;return!(a<0)&&(r&&(c=Hc(s,zc(null,r))),a>-1&&c<0||a<c)}function au(e,t){return t.getId(e)===Cc}function cu(e,t){if(ac(e))return!1;const n=t.getId(e);return!t.has(n)||(!e.parentNode||e.parentNode.nodeType!==e.DOCUMENT_NODE)&&(!e.parentNode||cu(e.parentNode,t))}function uu(e){return Boolean(e.changedTouches)}function lu(e,t){return Boolean("IFRAME"===e.nodeName&&t.getMeta(e))}function du(e,t){return Boolean("LINK"===e.nodeName&&e.nodeType===e.ELEMENT_NODE&&e.getAttribute&&"stylesheet"===e.getAttribute("rel")&&t.getMeta(e))}function pu(e){return Boolean(Gc([e,"optionalAccess",e=>e.shadowRoot]))}/[1-9][0-9]{12}/.test(Date.now().toString())||(tu=()=>(new Date).getTime());class hu{constructor(){this.id=1,this.styleIDMap=new WeakMap,this.idStyleMap=new Map}getId(e){return t=this.styleIDMap.get(e),n=()=>-1,null!=t?t:n();var t,n}has(e){return this.styleIDMap.has(e)}add(e,t){if(this.has(e))return this.getId(e);let n;return n=void 0===t?this.id++:t,this.styleIDMap.set(e,n),this.idStyleMap.set(n,e),n}getStyle(e){return this.idStyleMap.get(e)||null}reset(){this.styleIDMap=new WeakMap,this.idStyleMap=new Map,this.id=1}generateId(){return this.id++}}function fu(e){let t=null;return Gc([e,"access",e=>e.getRootNode,"optionalCall",e=>e(),"optionalAccess",e=>e.nodeType])===Node.DOCUMENT_FRAGMENT_NODE&&e.getRootNode().host&&(t=e.getRootNode().host),t}function mu(e){const t=e.ownerDocument;if(!t)return!1;const n=function(e){let t,n=e;for(;t=fu(n);)n=t;return n}(e);return t.contains(n)}function gu(e){const t=e.ownerDocument;return!!t&&(t.contains(e)||mu(e))}const _u={};function yu(e){const t=_u[e];if(t)return t;const n=window.document;let r=window[e];if(n&&"function"==typeof n.createElement)try{const t=n.createElement("iframe");t.hidden=!0,n.head.appendChild(t);const o=t.contentWindow;o&&o[e]&&(r=o[e]),n.head.removeChild(t)}catch(e){}return _u[e]=r.bind(window)}function vu(...e){return yu("setTimeout")(...e)}var bu=(e=>(e[e.DomContentLoaded=0]="DomContentLoaded",e[e.Load=1]="Load",e[e.FullSnapshot=2]="FullSnapshot",e[e.IncrementalSnapshot=3]="IncrementalSnapshot",e[e.Meta=4]="Meta",e[e.Custom=5]="Custom",e[e.Plugin=6]="Plugin",e))(bu||{}),Su=(e=>(e[e.Mutation=0]="Mutation",e[e.MouseMove=1]="MouseMove",e[e.MouseInteraction=2]="MouseInteraction",e[e.Scroll=3]="Scroll",e[e.ViewportResize=4]="ViewportResize",e[e.Input=5]="Input",e[e.TouchMove=6]="TouchMove",e[e.MediaInteraction=7]="MediaInteraction",e[e.StyleSheetRule=8]="StyleSheetRule",e[e.CanvasMutation=9]="CanvasMutation",e[e.Font=10]="Font",e[e.Log=11]="Log",e[e.Drag=12]="Drag",e[e.StyleDeclaration=13]="StyleDeclaration",e[e.Selection=14]="Selection",e[e.AdoptedStyleSheet=15]="AdoptedStyleSheet",e[e.CustomElement=16]="CustomElement",e))(Su||{}),wu=(e=>(e[e.MouseUp=0]="MouseUp",e[e.MouseDown=1]="MouseDown",e[e.Click=2]="Click",e[e.ContextMenu=3]="ContextMenu",e[e.DblClick=4]="DblClick",e[e.Focus=5]="Focus",e[e.Blur=6]="Blur",e[e.TouchStart=7]="TouchStart",e[e.TouchMove_Departed=8]="TouchMove_Departed",e[e.TouchEnd=9]="TouchEnd",e[e.TouchCancel=10]="TouchCancel",e))(wu||{}),ku=(e=>(e[e.Mouse=0]="Mouse",e[e.Pen=1]="Pen",e[e.Touch=2]="Touch",e))(ku||{});function xu(e){let t,n=e[0],r=1;for(;r<e.length;){const o=e[r],s=e[r+1];if(r+=2,("optionalAccess"===o||"optionalCall"===o)&&null==n)return;"access"===o||"optionalAccess"===o?(t=n,n=s(n)):"call"!==o&&"optionalCall"!==o||(n=s(((...e)=>n.call(t,...e))),t=void 0)}return n}function Eu(e){return"__ln"in e}class Cu{constructor(){this.length=0,this.head=null,this.tail=null}get(e){if(e>=this.length)throw new Error("Position outside of list range");let t=this.head;for(let n=0;n<e;n++)t=xu([t,"optionalAccess",e=>e.next])||null;return t}addNode(e){const t={value:e,previous:null,next:null};if(e.__ln=t,e.previousSibling&&Eu(e.previousSibling)){const n=e.previousSibling.__ln.next;t.next=n,t.previous=e.previousSibling.__ln,e.previousSibling.__ln.next=t,n&&(n.previous=t)}else if(e.nextSibling&&Eu(e.nextSibling)&&e.nextSibling.__ln.previous){const n=e.nextSibling.__ln.previous;t.previous=n,t.next=e.nextSibling.__ln,e.nextSibling.__ln.previous=t,n&&(n.next=t)}else this.head&&(this.head.previous=t),t.next=this.head,this.head=t;null===t.next&&(this.tail=t),this.length++}removeNode(e){const t=e.__ln;this.head&&(t.previous?(t.previous.next=t.next,t.next?t.next.previous=t.previous:this.tail=t.previous):(this.head=t.next,this.head?this.head.previous=null:this.tail=null),e.__ln&&delete e.__ln,this.length--)}}const Tu=(e,t)=>`${e}@${t}`;class Iu{constructor(){this.frozen=!1,this.locked=!1,this.texts=[],this.attributes=[],this.attributeMap=new WeakMap,this.removes=[],this.mapRemoves=[],this.movedMap={},this.addedSet=new Set,this.movedSet=new Set,this.droppedSet=new Set,this.processMutations=e=>{e.forEach(this.processMutation),this.emit()},this.emit=()=>{if(this.frozen||this.locked)return;const e=[],t=new Set,n=new Cu,r=e=>{let t=e,n=Cc;for(;n===Cc;)t=t&&t.nextSibling,n=t&&this.mirror.getId(t);return n},o=o=>{if(!o.parentNode||!gu(o))return;const s=ac(o.parentNode)?this.mirror.getId(fu(o)):this.mirror.getId(o.parentNode),i=r(o);if(-1===s||-1===i)return n.addNode(o);const a=Vc(o,{doc:this.doc,mirror:this.mirror,blockClass:this.blockClass,blockSelector:this.blockSelector,maskAllText:this.maskAllText,unblockSelector:this.unblockSelector,maskTextClass:this.maskTextClass,unmaskTextClass:this.unmaskTextClass,maskTextSelector:this.maskTextSelector,unmaskTextSelector:this.unmaskTextSelector,skipChild:!0,newlyAddedElement:!0,inlineStylesheet:this.inlineStylesheet,maskInputOptions:this.maskInputOptions,maskAttributeFn:this.maskAttributeFn,maskTextFn:this.maskTextFn,maskInputFn:this.maskInputFn,slimDOMOptions:this.slimDOMOptions,dataURLOptions:this.dataURLOptions,recordCanvas:this.recordCanvas,inlineImages:this.inlineImages,onSerialize:e=>{lu(e,this.mirror)&&!iu(e,this.blockClass,this.blockSelector,this.unblockSelector,!1)&&this.iframeManager.addIframe(e),du(e,this.mirror)&&this.stylesheetManager.trackLinkElement(e),pu(o)&&this.shadowDomManager.addShadowRoot(o.shadowRoot,this.doc)},onIframeLoad:(e,t)=>{iu(e,this.blockClass,this.blockSelector,this.unblockSelector,!1)||(this.iframeManager.attachIframe(e,t),e.contentWindow&&this.canvasManager.addWindow(e.contentWindow),this.shadowDomManager.observeAttachShadow(e))},onStylesheetLoad:(e,t)=>{this.stylesheetManager.attachLinkElement(e,t)}});a&&(e.push({parentId:s,nextId:i,node:a}),t.add(a.id))};for(;this.mapRemoves.length;)this.mirror.removeNodeFromMap(this.mapRemoves.shift());for(const e of this.movedSet)Ru(this.removes,e,this.mirror)&&!this.movedSet.has(e.parentNode)||o(e);for(const e of this.addedSet)Nu(this.droppedSet,e)||Ru(this.removes,e,this.mirror)?Nu(this.movedSet,e)?o(e):this.droppedSet.add(e):o(e);let s=null;for(;n.length;){let e=null;if(s){const t=this.mirror.getId(s.value.parentNode),n=r(s.value);-1!==t&&-1!==n&&(e=s)}if(!e){let t=n.tail;for(;t;){const n=t;if(t=t.previous,n){const t=this.mirror.getId(n.value.parentNode);if(-1===r(n.value))continue;if(-1!==t){e=n;break}{const t=n.value;if(t.parentNode&&t.parentNode.nodeType===Node.DOCUMENT_FRAGMENT_NODE){const r=t.parentNode.host;if(-1!==this.mirror.getId(r)){e=n;break}}}}}}if(!e){for(;n.head;)n.removeNode(n.head.value);break}s=e.previous,n.removeNode(e.value),o(e.value)}const i={texts:this.texts.map((e=>({id:this.mirror.getId(e.node),value:e.value}))).filter((e=>!t.has(e.id))).filter((e=>this.mirror.has(e.id))),attributes:this.attributes.map((e=>{const{attributes:t}=e;if("string"==typeof t.style){const n=JSON.stringify(e.styleDiff),r=JSON.stringify(e._unchangedStyles);n.length<t.style.length&&(n+r).split("var(").length===t.style.split("var(").length&&(t.style=e.styleDiff)}return{id:this.mirror.getId(e.node),attributes:t}})).filter((e=>!t.has(e.id))).filter((e=>this.mirror.has(e.id))),removes:this.removes,adds:e};(i.texts.length||i.attributes.length||i.removes.length||i.adds.length)&&(this.texts=[],this.attributes=[],this.attributeMap=new WeakMap,this.removes=[],this.addedSet=new Set,this.movedSet=new Set,this.droppedSet=new Set,this.movedMap={},this.mutationCb(i))},this.processMutation=e=>{if(!au(e.target,this.mirror))switch(e.type){case"characterData":{const t=e.target.textContent;iu(e.target,this.blockClass,this.blockSelector,this.unblockSelector,!1)||t===e.oldValue||this.texts.push({value:qc(e.target,this.maskTextClass,this.maskTextSelector,this.unmaskTextClass,this.unmaskTextSelector,this.maskAllText)&&t?this.maskTextFn?this.maskTextFn(t,su(e.target)):t.replace(/[\S]/g,"*"):t,node:e.target});break}case"attributes":{const t=e.target;let n=e.attributeName,r=e.target.getAttribute(n);if("value"===n){const n=_c(t),o=t.tagName;r=yc(t,o,n);const s=pc({maskInputOptions:this.maskInputOptions,tagName:o,type:n});r=hc({isMasked:qc(e.target,this.maskTextClass,this.maskTextSelector,this.unmaskTextClass,this.unmaskTextSelector,s),element:t,value:r,maskInputFn:this.maskInputFn})}if(iu(e.target,this.blockClass,this.blockSelector,this.unblockSelector,!1)||r===e.oldValue)return;let o=this.attributeMap.get(e.target);if("IFRAME"===t.tagName&&"src"===n&&!this.keepIframeSrcFn(r)){if(t.contentDocument)return;n="rr_src"}if(o||(o={node:e.target,attributes:{},styleDiff:{},_unchangedStyles:{}},this.attributes.push(o),this.attributeMap.set(e.target,o)),"type"===n&&"INPUT"===t.tagName&&"password"===(e.oldValue||"").toLowerCase()&&t.setAttribute("data-rr-is-password","true"),!jc(t.tagName,n)&&(o.attributes[n]=Uc(this.doc,fc(t.tagName),fc(n),r,t,this.maskAttributeFn),"style"===n)){if(!this.unattachedDoc)try{this.unattachedDoc=document.implementation.createHTMLDocument()}catch(e){this.unattachedDoc=this.doc}const n=this.unattachedDoc.createElement("span");e.oldValue&&n.setAttribute("style",e.oldValue);for(const e of Array.from(t.style)){const r=t.style.getPropertyValue(e),s=t.style.getPropertyPriority(e);r!==n.style.getPropertyValue(e)||s!==n.style.getPropertyPriority(e)?o.styleDiff[e]=""===s?r:[r,s]:o._unchangedStyles[e]=[r,s]}for(const e of Array.from(n.style))""===t.style.getPropertyValue(e)&&(o.styleDiff[e]=!1)}break}case"childList":if(iu(e.target,this.blockClass,this.blockSelector,this.unblockSelector,!0))return;e.addedNodes.forEach((t=>this.genAdds(t,e.target))),e.removedNodes.forEach((t=>{const n=this.mirror.getId(t),r=ac(e.target)?this.mirror.getId(e.target.host):this.mirror.getId(e.target);iu(e.target,this.blockClass,this.blockSelector,this.unblockSelector,!1)||au(t,this.mirror)||!function(e,t){return-1!==t.getId(e)}(t,this.mirror)||(this.addedSet.has(t)?(Mu(this.addedSet,t),this.droppedSet.add(t)):this.addedSet.has(e.target)&&-1===n||cu(e.target,this.mirror)||(this.movedSet.has(t)&&this.movedMap[Tu(n,r)]?Mu(this.movedSet,t):this.removes.push({parentId:r,id:n,isShadow:!(!ac(e.target)||!cc(e.target))||void 0})),this.mapRemoves.push(t))}))}},this.genAdds=(e,t)=>{if(!this.processedNodeManager.inOtherBuffer(e,this)&&!this.addedSet.has(e)&&!this.movedSet.has(e)){if(this.mirror.hasNode(e)){if(au(e,this.mirror))return;this.movedSet.add(e);let n=null;t&&this.mirror.hasNode(t)&&(n=this.mirror.getId(t)),n&&-1!==n&&(this.movedMap[Tu(this.mirror.getId(e),n)]=!0)}else this.addedSet.add(e),this.droppedSet.delete(e);iu(e,this.blockClass,this.blockSelector,this.unblockSelector,!1)||(e.childNodes.forEach((e=>this.genAdds(e))),pu(e)&&e.shadowRoot.childNodes.forEach((t=>{this.processedNodeManager.add(t,this),this.genAdds(t,e)})))}}}init(e){["mutationCb","blockClass","blockSelector","unblockSelector","maskAllText","maskTextClass","unmaskTextClass","maskTextSelector","unmaskTextSelector","inlineStylesheet","maskInputOptions","maskAttributeFn","maskTextFn","maskInputFn","keepIframeSrcFn","recordCanvas","inlineImages","slimDOMOptions","dataURLOptions","doc","mirror","iframeManager","stylesheetManager","shadowDomManager","canvasManager","processedNodeManager"].forEach((t=>{this[t]=e[t]}))}freeze(){this.frozen=!0,this.canvasManager.freeze()}unfreeze(){this.frozen=!1,this.canvasManager.unfreeze(),this.emit()}isFrozen(){return this.frozen}lock(){this.locked=!0,this.canvasManager.lock()}unlock(){this.locked=!1,this.canvasManager.unlock(),this.emit()}reset(){this.shadowDomManager.reset(),this.canvasManager.reset()}}function Mu(e,t){e.delete(t),t.childNodes.forEach((t=>Mu(e,t)))}function Ru(e,t,n){return 0!==e.length&&Au(e,t,n)}function Au(e,t,n){const{parentNode:r}=t;if(!r)return!1;const o=n.getId(r);return!!e.some((e=>e.id===o))||Au(e,r,n)}function Nu(e,t){return 0!==e.size&&Ou(e,t)}function Ou(e,t){const{parentNode:n}=t;return!!n&&(!!e.has(n)||Ou(e,n))}let Du;const Lu=e=>{if(!Du)return e;return(...t)=>{try{return e(...t)}catch(e){if(Du&&!0===Du(e))return()=>{};throw e}}};function Pu(e){let t,n=e[0],r=1;for(;r<e.length;){const o=e[r],s=e[r+1];if(r+=2,("optionalAccess"===o||"optionalCall"===o)&&null==n)return;"access"===o||"optionalAccess"===o?(t=n,n=s(n)):"call"!==o&&"optionalCall"!==o||(n=s(((...e)=>n.call(t,...e))),t=void 0)}return n}const Fu=[];function $u(e){try{if("composedPath"in e){const t=e.composedPath();if(t.length)return t[0]}else if("path"in e&&e.path.length)return e.path[0]}catch(e){}return e&&e.target}function Bu(e,t){const n=new Iu;Fu.push(n),n.init(e);let r=window.MutationObserver||window.__rrMutationObserver;const o=Pu([window,"optionalAccess",e=>e.Zone,"optionalAccess",e=>e.__symbol__,"optionalCall",e=>e("MutationObserver")]);o&&window[o]&&(r=window[o]);const s=new r(Lu((t=>{e.onMutation&&!1===e.onMutation(t)||n.processMutations.bind(n)(t)})));return s.observe(t,{attributes:!0,attributeOldValue:!0,characterData:!0,characterDataOldValue:!0,childList:!0,subtree:!0}),s}function Uu({mouseInteractionCb:e,doc:t,mirror:n,blockClass:r,blockSelector:o,unblockSelector:s,sampling:i}){if(!1===i.mouseInteraction)return()=>{};const a=!0===i.mouseInteraction||void 0===i.mouseInteraction?{}:i.mouseInteraction,c=[];let u=null;return Object.keys(wu).filter((e=>Number.isNaN(Number(e))&&!e.endsWith("_Departed")&&!1!==a[e])).forEach((i=>{let a=fc(i);const l=(t=>i=>{const a=$u(i);if(iu(a,r,o,s,!0))return;let c=null,l=t;if("pointerType"in i){switch(i.pointerType){case"mouse":c=ku.Mouse;break;case"touch":c=ku.Touch;break;case"pen":c=ku.Pen}c===ku.Touch?wu[t]===wu.MouseDown?l="TouchStart":wu[t]===wu.MouseUp&&(l="TouchEnd"):ku.Pen}else uu(i)&&(c=ku.Touch);null!==c?(u=c,(l.startsWith("Touch")&&c===ku.Touch||l.startsWith("Mouse")&&c===ku.Mouse)&&(c=null)):wu[t]===wu.Click&&(c=u,u=null);const d=uu(i)?i.changedTouches[0]:i;if(!d)return;const p=n.getId(a),{clientX:h,clientY:f}=d;Lu(e)({type:wu[l],id:p,x:h,y:f,...null!==c&&{pointerType:c}})})(i);if(window.PointerEvent)switch(wu[i]){case wu.MouseDown:case wu.MouseUp:a=a.replace("mouse","pointer");break;case wu.TouchStart:case wu.TouchEnd:return}c.push(Xc(a,l,t))})),Lu((()=>{c.forEach((e=>e()))}))}function ju({scrollCb:e,doc:t,mirror:n,blockClass:r,blockSelector:o,unblockSelector:s,sampling:i}){return Xc("scroll",Lu(Qc(Lu((i=>{const a=$u(i);if(!a||iu(a,r,o,s,!0))return;const c=n.getId(a);if(a===t&&t.defaultView){const n=nu(t.defaultView);e({id:c,x:n.left,y:n.top})}else e({id:c,x:a.scrollLeft,y:a.scrollTop})})),i.scroll||100)),t)}const Hu=["INPUT","TEXTAREA","SELECT"],zu=new WeakMap;function qu({inputCb:e,doc:t,mirror:n,blockClass:r,blockSelector:o,unblockSelector:s,ignoreClass:i,ignoreSelector:a,maskInputOptions:c,maskInputFn:u,sampling:l,userTriggeredOnInput:d,maskTextClass:p,unmaskTextClass:h,maskTextSelector:f,unmaskTextSelector:m}){function g(e){let n=$u(e);const l=e.isTrusted,g=n&&mc(n.tagName);if("OPTION"===g&&(n=n.parentElement),!n||!g||Hu.indexOf(g)<0||iu(n,r,o,s,!0))return;const y=n;if(y.classList.contains(i)||a&&y.matches(a))return;const v=_c(n);let b=yc(y,g,v),S=!1;const w=pc({maskInputOptions:c,tagName:g,type:v}),k=qc(n,p,f,h,m,w);"radio"!==v&&"checkbox"!==v||(S=n.checked),b=hc({isMasked:k,element:n,value:b,maskInputFn:u}),_(n,d?{text:b,isChecked:S,userTriggered:l}:{text:b,isChecked:S});const x=n.name;"radio"===v&&x&&S&&t.querySelectorAll(`input[type="radio"][name="${x}"]`).forEach((e=>{if(e!==n){const t=hc({isMasked:k,element:e,value:yc(e,g,v),maskInputFn:u});_(e,d?{text:t,isChecked:!S,userTriggered:!1}:{text:t,isChecked:!S})}}))}function _(t,r){const o=zu.get(t);if(!o||o.text!==r.text||o.isChecked!==r.isChecked){zu.set(t,r);const o=n.getId(t);Lu(e)({...r,id:o})}}const y=("last"===l.input?["change"]:["input","change"]).map((e=>Xc(e,Lu(g),t))),v=t.defaultView;if(!v)return()=>{y.forEach((e=>e()))};const b=v.Object.getOwnPropertyDescriptor(v.HTMLInputElement.prototype,"value"),S=[[v.HTMLInputElement.prototype,"value"],[v.HTMLInputElement.prototype,"checked"],[v.HTMLSelectElement.prototype,"value"],[v.HTMLTextAreaElement.prototype,"value"],[v.HTMLSelectElement.prototype,"selectedIndex"],[v.HTMLOptionElement.prototype,"selected"]];return b&&b.set&&y.push(...S.map((e=>Zc(e[0],e[1],{set(){Lu(g)({target:this,isTrusted:!1})}},!1,v)))),Lu((()=>{y.forEach((e=>e()))}))}function Wu(e){return function(e,t){if(Xu("CSSGroupingRule")&&e.parentRule instanceof CSSGroupingRule||Xu("CSSMediaRule")&&e.parentRule instanceof CSSMediaRule||Xu("CSSSupportsRule")&&e.parentRule instanceof CSSSupportsRule||Xu("CSSConditionRule")&&e.parentRule instanceof CSSConditionRule){const n=Array.from(e.parentRule.cssRules).indexOf(e);t.unshift(n)}else if(e.parentStyleSheet){const n=Array.from(e.parentStyleSheet.cssRules).indexOf(e);t.unshift(n)}return t}(e,[])}function Yu(e,t,n){let r,o;return e?(e.ownerNode?r=t.getId(e.ownerNode):o=n.getId(e),{styleId:o,id:r}):{}}function Vu({mirror:e,stylesheetManager:t},n){let r=null;r="#document"===n.nodeName?e.getId(n):e.getId(n.host);const o="#document"===n.nodeName?Pu([n,"access",e=>e.defaultView,"optionalAccess",e=>e.Document]):Pu([n,"access",e=>e.ownerDocument,"optionalAccess",e=>e.defaultView,"optionalAccess",e=>e.ShadowRoot]),s=Pu([o,"optionalAccess",e=>e.prototype])?Object.getOwnPropertyDescriptor(Pu([o,"optionalAccess",e=>e.prototype]),"adoptedStyleSheets"):void 0;return null!==r&&-1!==r&&o&&s?(Object.defineProperty(n,"adoptedStyleSheets",{configurable:s.configurable,enumerable:s.enumerable,get(){return Pu([s,"access",e=>e.get,"optionalAccess",e=>e.call,"call",e=>e(this)])},set(e){const n=Pu([s,"access",e=>e.set,"optionalAccess",e=>e.call,"call",t=>t(this,e)]);if(null!==r&&-1!==r)try{t.adoptStyleSheets(e,r)}catch(e){}return n}}),Lu((()=>{Object.defineProperty(n,"adoptedStyleSheets",{configurable:s.configurable,enumerable:s.enumerable,get:s.get,set:s.set})}))):()=>{}}function Gu(e,t={}){const n=e.doc.defaultView;if(!n)return()=>{};let r;e.recordDOM&&(r=Bu(e,e.doc));const o=function({mousemoveCb:e,sampling:t,doc:n,mirror:r}){if(!1===t.mousemove)return()=>{};const o="number"==typeof t.mousemove?t.mousemove:50,s="number"==typeof t.mousemoveCallback?t.mousemoveCallback:500;let i,a=[];const c=Qc(Lu((t=>{const n=Date.now()-i;e(a.map((e=>(e.timeOffset-=n,e))),t),a=[],i=null})),s),u=Lu(Qc(Lu((e=>{const t=$u(e),{clientX:n,clientY:o}=uu(e)?e.changedTouches[0]:e;i||(i=tu()),a.push({x:n,y:o,id:r.getId(t),timeOffset:tu()-i}),c("undefined"!=typeof DragEvent&&e instanceof DragEvent?Su.Drag:e instanceof MouseEvent?Su.MouseMove:Su.TouchMove)})),o,{trailing:!1})),l=[Xc("mousemove",u,n),Xc("touchmove",u,n),Xc("drag",u,n)];return Lu((()=>{l.forEach((e=>e()))}))}(e),s=Uu(e),i=ju(e),a=function({viewportResizeCb:e},{win:t}){let n=-1,r=-1;return Xc("resize",Lu(Qc(Lu((()=>{const t=ru(),o=ou();n===t&&r===o||(e({width:Number(o),height:Number(t)}),n=t,r=o)})),200)),t)}(e,{win:n}),c=qu(e),u=function({mediaInteractionCb:e,blockClass:t,blockSelector:n,unblockSelector:r,mirror:o,sampling:s,doc:i}){const a=Lu((i=>Qc(Lu((s=>{const a=$u(s);if(!a||iu(a,t,n,r,!0))return;const{currentTime:c,volume:u,muted:l,playbackRate:d}=a;e({type:i,id:o.getId(a),currentTime:c,volume:u,muted:l,playbackRate:d})})),s.media||500))),c=[Xc("play",a(0),i),Xc("pause",a(1),i),Xc("seeked",a(2),i),Xc("volumechange",a(3),i),Xc("ratechange",a(4),i)];return Lu((()=>{c.forEach((e=>e()))}))}(e);let l=()=>{},d=()=>{},p=()=>{},h=()=>{};e.recordDOM&&(l=function({styleSheetRuleCb:e,mirror:t,stylesheetManager:n},{win:r}){if(!r.CSSStyleSheet||!r.CSSStyleSheet.prototype)return()=>{};const o=r.CSSStyleSheet.prototype.insertRule;r.CSSStyleSheet.prototype.insertRule=new Proxy(o,{apply:Lu(((r,o,s)=>{const[i,a]=s,{id:c,styleId:u}=Yu(o,t,n.styleMirror);return(c&&-1!==c||u&&-1!==u)&&e({id:c,styleId:u,adds:[{rule:i,index:a}]}),r.apply(o,s)}))});const s=r.CSSStyleSheet.prototype.deleteRule;let i,a;r.CSSStyleSheet.prototype.deleteRule=new Proxy(s,{apply:Lu(((r,o,s)=>{const[i]=s,{id:a,styleId:c}=Yu(o,t,n.styleMirror);return(a&&-1!==a||c&&-1!==c)&&e({id:a,styleId:c,removes:[{index:i}]}),r.apply(o,s)}))}),r.CSSStyleSheet.prototype.replace&&(i=r.CSSStyleSheet.prototype.replace,r.CSSStyleSheet.prototype.replace=new Proxy(i,{apply:Lu(((r,o,s)=>{const[i]=s,{id:a,styleId:c}=Yu(o,t,n.styleMirror);return(a&&-1!==a||c&&-1!==c)&&e({id:a,styleId:c,replace:i}),r.apply(o,s)}))})),r.CSSStyleSheet.prototype.replaceSync&&(a=r.CSSStyleSheet.prototype.replaceSync,r.CSSStyleSheet.prototype.replaceSync=new Proxy(a,{apply:Lu(((r,o,s)=>{const[i]=s,{id:a,styleId:c}=Yu(o,t,n.styleMirror);return(a&&-1!==a||c&&-1!==c)&&e({id:a,styleId:c,replaceSync:i}),r.apply(o,s)}))}));const c={};Ju("CSSGroupingRule")?c.CSSGroupingRule=r.CSSGroupingRule:(Ju("CSSMediaRule")&&(c.CSSMediaRule=r.CSSMediaRule),Ju("CSSConditionRule")&&(c.CSSConditionRule=r.CSSConditionRule),Ju("CSSSupportsRule")&&(c.CSSSupportsRule=r.CSSSupportsRule));const u={};return Object.entries(c).forEach((([r,o])=>{u[r]={insertRule:o.prototype.insertRule,deleteRule:o.prototype.deleteRule},o.prototype.insertRule=new Proxy(u[r].insertRule,{apply:Lu(((r,o,s)=>{const[i,a]=s,{id:c,styleId:u}=Yu(o.parentStyleSheet,t,n.styleMirror);return(c&&-1!==c||u&&-1!==u)&&e({id:c,styleId:u,adds:[{rule:i,index:[...Wu(o),a||0]}]}),r.apply(o,s)}))}),o.prototype.deleteRule=new Proxy(u[r].deleteRule,{apply:Lu(((r,o,s)=>{const[i]=s,{id:a,styleId:c}=Yu(o.parentStyleSheet,t,n.styleMirror);return(a&&-1!==a||c&&-1!==c)&&e({id:a,styleId:c,removes:[{index:[...Wu(o),i]}]}),r.apply(o,s)}))})})),Lu((()=>{r.CSSStyleSheet.prototype.insertRule=o,r.CSSStyleSheet.prototype.deleteRule=s,i&&(r.CSSStyleSheet.prototype.replace=i),a&&(r.CSSStyleSheet.prototype.replaceSync=a),Object.entries(c).forEach((([e,t])=>{t.prototype.insertRule=u[e].insertRule,t.prototype.deleteRule=u[e].deleteRule}))}))}(e,{win:n}),d=Vu(e,e.doc),p=function({styleDeclarationCb:e,mirror:t,ignoreCSSAttributes:n,stylesheetManager:r},{win:o}){const s=o.CSSStyleDeclaration.prototype.setProperty;o.CSSStyleDeclaration.prototype.setProperty=new Proxy(s,{apply:Lu(((o,i,a)=>{const[c,u,l]=a;if(n.has(c))return s.apply(i,[c,u,l]);const{id:d,styleId:p}=Yu(Pu([i,"access",e=>e.parentRule,"optionalAccess",e=>e.parentStyleSheet]),t,r.styleMirror);return(d&&-1!==d||p&&-1!==p)&&e({id:d,styleId:p,set:{property:c,value:u,priority:l},index:Wu(i.parentRule)}),o.apply(i,a)}))});const i=o.CSSStyleDeclaration.prototype.removeProperty;return o.CSSStyleDeclaration.prototype.removeProperty=new Proxy(i,{apply:Lu(((o,s,a)=>{const[c]=a;if(n.has(c))return i.apply(s,[c]);const{id:u,styleId:l}=Yu(Pu([s,"access",e=>e.parentRule,"optionalAccess",e=>e.parentStyleSheet]),t,r.styleMirror);return(u&&-1!==u||l&&-1!==l)&&e({id:u,styleId:l,remove:{property:c},index:Wu(s.parentRule)}),o.apply(s,a)}))}),Lu((()=>{o.CSSStyleDeclaration.prototype.setProperty=s,o.CSSStyleDeclaration.prototype.removeProperty=i}))}(e,{win:n}),e.collectFonts&&(h=function({fontCb:e,doc:t}){const n=t.defaultView;if(!n)return()=>{};const r=[],o=new WeakMap,s=n.FontFace;n.FontFace=function(e,t,n){const r=new s(e,t,n);return o.set(r,{family:e,buffer:"string"!=typeof t,descriptors:n,fontSource:"string"==typeof t?t:JSON.stringify(Array.from(new Uint8Array(t)))}),r};const i=eu(t.fonts,"add",(function(t){return function(n){return vu(Lu((()=>{const t=o.get(n);t&&(e(t),o.delete(n))})),0),t.apply(this,[n])}}));return r.push((()=>{n.FontFace=s})),r.push(i),Lu((()=>{r.forEach((e=>e()))}))}(e)));const f=function(e){const{doc:t,mirror:n,blockClass:r,blockSelector:o,unblockSelector:s,selectionCb:i}=e;let a=!0;const c=Lu((()=>{const e=t.getSelection();if(!e||a&&Pu([e,"optionalAccess",e=>e.isCollapsed]))return;a=e.isCollapsed||!1;const c=[],u=e.rangeCount||0;for(let t=0;t<u;t++){const i=e.getRangeAt(t),{startContainer:a,startOffset:u,endContainer:l,endOffset:d}=i;iu(a,r,o,s,!0)||iu(l,r,o,s,!0)||c.push({start:n.getId(a),startOffset:u,end:n.getId(l),endOffset:d})}i({ranges:c})}));return c(),Xc("selectionchange",c)}(e),m=function({doc:e,customElementCb:t}){const n=e.defaultView;return n&&n.customElements?eu(n.customElements,"define",(function(e){return function(n,r,o){try{t({define:{name:n}})}catch(e){}return e.apply(this,[n,r,o])}})):()=>{}}(e),g=[];for(const t of e.plugins)g.push(t.observer(t.callback,n,t.options));return Lu((()=>{Fu.forEach((e=>e.reset())),Pu([r,"optionalAccess",e=>e.disconnect,"call",e=>e()]),o(),s(),i(),a(),c(),u(),l(),d(),p(),h(),f(),m(),g.forEach((e=>e()))}))}function Xu(e){return void 0!==window[e]}function Ju(e){return Boolean(void 0!==window[e]&&window[e].prototype&&"insertRule"in window[e].prototype&&"deleteRule"in window[e].prototype)}class Ku{constructor(e){this.generateIdFn=e,this.iframeIdToRemoteIdMap=new WeakMap,this.iframeRemoteIdToIdMap=new WeakMap}getId(e,t,n,r){const o=n||this.getIdToRemoteIdMap(e),s=r||this.getRemoteIdToIdMap(e);let i=o.get(t);return i||(i=this.generateIdFn(),o.set(t,i),s.set(i,t)),i}getIds(e,t){const n=this.getIdToRemoteIdMap(e),r=this.getRemoteIdToIdMap(e);return t.map((t=>this.getId(e,t,n,r)))}getRemoteId(e,t,n){const r=n||this.getRemoteIdToIdMap(e);if("number"!=typeof t)return t;const o=r.get(t);return o||-1}getRemoteIds(e,t){const n=this.getRemoteIdToIdMap(e);return t.map((t=>this.getRemoteId(e,t,n)))}reset(e){if(!e)return this.iframeIdToRemoteIdMap=new WeakMap,void(this.iframeRemoteIdToIdMap=new WeakMap);this.iframeIdToRemoteIdMap.delete(e),this.iframeRemoteIdToIdMap.delete(e)}getIdToRemoteIdMap(e){let t=this.iframeIdToRemoteIdMap.get(e);return t||(t=new Map,this.iframeIdToRemoteIdMap.set(e,t)),t}getRemoteIdToIdMap(e){let t=this.iframeRemoteIdToIdMap.get(e);return t||(t=new Map,this.iframeRemoteIdToIdMap.set(e,t)),t}}function Qu(e){let t,n=e[0],r=1;for(;r<e.length;){const o=e[r],s=e[r+1];if(r+=2,("optionalAccess"===o||"optionalCall"===o)&&null==n)return;"access"===o||"optionalAccess"===o?(t=n,n=s(n)):"call"!==o&&"optionalCall"!==o||(n=s(((...e)=>n.call(t,...e))),t=void 0)}return n}class Zu{constructor(){this.crossOriginIframeMirror=new Ku(Tc),this.crossOriginIframeRootIdMap=new WeakMap}addIframe(){}addLoadListener(){}attachIframe(){}}class el{constructor(e){this.iframes=new WeakMap,this.crossOriginIframeMap=new WeakMap,this.crossOriginIframeMirror=new Ku(Tc),this.crossOriginIframeRootIdMap=new WeakMap,this.mutationCb=e.mutationCb,this.wrappedEmit=e.wrappedEmit,this.stylesheetManager=e.stylesheetManager,this.recordCrossOriginIframes=e.recordCrossOriginIframes,this.crossOriginIframeStyleMirror=new Ku(this.stylesheetManager.styleMirror.generateId.bind(this.stylesheetManager.styleMirror)),this.mirror=e.mirror,this.recordCrossOriginIframes&&window.addEventListener("message",this.handleMessage.bind(this))}addIframe(e){this.iframes.set(e,!0),e.contentWindow&&this.crossOriginIframeMap.set(e.contentWindow,e)}addLoadListener(e){this.loadListener=e}attachIframe(e,t){this.mutationCb({adds:[{parentId:this.mirror.getId(e),nextId:null,node:t}],removes:[],texts:[],attributes:[],isAttachIframe:!0}),Qu([this,"access",e=>e.loadListener,"optionalCall",t=>t(e)]),e.contentDocument&&e.contentDocument.adoptedStyleSheets&&e.contentDocument.adoptedStyleSheets.length>0&&this.stylesheetManager.adoptStyleSheets(e.contentDocument.adoptedStyleSheets,this.mirror.getId(e.contentDocument))}handleMessage(e){const t=e;if("rrweb"!==t.data.type||t.origin!==t.data.origin)return;if(!e.source)return;const n=this.crossOriginIframeMap.get(e.source);if(!n)return;const r=this.transformCrossOriginEvent(n,t.data.event);r&&this.wrappedEmit(r,t.data.isCheckout)}transformCrossOriginEvent(e,t){switch(t.type){case bu.FullSnapshot:{this.crossOriginIframeMirror.reset(e),this.crossOriginIframeStyleMirror.reset(e),this.replaceIdOnNode(t.data.node,e);const n=t.data.node.id;return this.crossOriginIframeRootIdMap.set(e,n),this.patchRootIdOnNode(t.data.node,n),{timestamp:t.timestamp,type:bu.IncrementalSnapshot,data:{source:Su.Mutation,adds:[{parentId:this.mirror.getId(e),nextId:null,node:t.data.node}],removes:[],texts:[],attributes:[],isAttachIframe:!0}}}case bu.Meta:case bu.Load:case bu.DomContentLoaded:return!1;case bu.Plugin:return t;case bu.Custom:return this.replaceIds(t.data.payload,e,["id","parentId","previousId","nextId"]),t;case bu.IncrementalSnapshot:switch(t.data.source){case Su.Mutation:return t.data.adds.forEach((t=>{this.replaceIds(t,e,["parentId","nextId","previousId"]),this.replaceIdOnNode(t.node,e);const n=this.crossOriginIframeRootIdMap.get(e);n&&this.patchRootIdOnNode(t.node,n)})),t.data.removes.forEach((t=>{this.replaceIds(t,e,["parentId","id"])})),t.data.attributes.forEach((t=>{this.replaceIds(t,e,["id"])})),t.data.texts.forEach((t=>{this.replaceIds(t,e,["id"])})),t;case Su.Drag:case Su.TouchMove:case Su.MouseMove:return t.data.positions.forEach((t=>{this.replaceIds(t,e,["id"])})),t;case Su.ViewportResize:return!1;case Su.MediaInteraction:case Su.MouseInteraction:case Su.Scroll:case Su.CanvasMutation:case Su.Input:return this.replaceIds(t.data,e,["id"]),t;case Su.StyleSheetRule:case Su.StyleDeclaration:return this.replaceIds(t.data,e,["id"]),this.replaceStyleIds(t.data,e,["styleId"]),t;case Su.Font:return t;case Su.Selection:return t.data.ranges.forEach((t=>{this.replaceIds(t,e,["start","end"])})),t;case Su.AdoptedStyleSheet:return this.replaceIds(t.data,e,["id"]),this.replaceStyleIds(t.data,e,["styleIds"]),Qu([t,"access",e=>e.data,"access",e=>e.styles,"optionalAccess",e=>e.forEach,"call",t=>t((t=>{this.replaceStyleIds(t,e,["styleId"])}))]),t}}return!1}replace(e,t,n,r){for(const o of r)(Array.isArray(t[o])||"number"==typeof t[o])&&(Array.isArray(t[o])?t[o]=e.getIds(n,t[o]):t[o]=e.getId(n,t[o]));return t}replaceIds(e,t,n){return this.replace(this.crossOriginIframeMirror,e,t,n)}replaceStyleIds(e,t,n){return this.replace(this.crossOriginIframeStyleMirror,e,t,n)}replaceIdOnNode(e,t){this.replaceIds(e,t,["id","rootId"]),"childNodes"in e&&e.childNodes.forEach((e=>{this.replaceIdOnNode(e,t)}))}patchRootIdOnNode(e,t){e.type===ic.Document||e.rootId||(e.rootId=t),"childNodes"in e&&e.childNodes.forEach((e=>{this.patchRootIdOnNode(e,t)}))}}class tl{init(){}addShadowRoot(){}observeAttachShadow(){}reset(){}}class nl{constructor(e){this.shadowDoms=new WeakSet,this.restoreHandlers=[],this.mutationCb=e.mutationCb,this.scrollCb=e.scrollCb,this.bypassOptions=e.bypassOptions,this.mirror=e.mirror,this.init()}init(){this.reset(),this.patchAttachShadow(Element,document)}addShadowRoot(e,t){if(!cc(e))return;if(this.shadowDoms.has(e))return;this.shadowDoms.add(e),this.bypassOptions.canvasManager.addShadowRoot(e);const n=Bu({...this.bypassOptions,doc:t,mutationCb:this.mutationCb,mirror:this.mirror,shadowDomManager:this},e);this.restoreHandlers.push((()=>n.disconnect())),this.restoreHandlers.push(ju({...this.bypassOptions,scrollCb:this.scrollCb,doc:e,mirror:this.mirror})),vu((()=>{e.adoptedStyleSheets&&e.adoptedStyleSheets.length>0&&this.bypassOptions.stylesheetManager.adoptStyleSheets(e.adoptedStyleSheets,this.mirror.getId(e.host)),this.restoreHandlers.push(Vu({mirror:this.mirror,stylesheetManager:this.bypassOptions.stylesheetManager},e))}),0)}observeAttachShadow(e){e.contentWindow&&e.contentDocument&&this.patchAttachShadow(e.contentWindow.Element,e.contentDocument)}patchAttachShadow(e,t){const n=this;this.restoreHandlers.push(eu(e.prototype,"attachShadow",(function(e){return function(r){const o=e.call(this,r);return this.shadowRoot&&gu(this)&&n.addShadowRoot(this.shadowRoot,t),o}})))}reset(){this.restoreHandlers.forEach((e=>{try{e()}catch(e){}})),this.restoreHandlers=[],this.shadowDoms=new WeakSet,this.bypassOptions.canvasManager.resetShadowRoots()}}class rl{reset(){}freeze(){}unfreeze(){}lock(){}unlock(){}snapshot(){}addWindow(){}addShadowRoot(){}resetShadowRoots(){}}class ol{constructor(e){this.trackedLinkElements=new WeakSet,this.styleMirror=new hu,this.mutationCb=e.mutationCb,this.adoptedStyleSheetCb=e.adoptedStyleSheetCb}attachLinkElement(e,t){"_cssText"in t.attributes&&this.mutationCb({adds:[],removes:[],texts:[],attributes:[{id:t.id,attributes:t.attributes}]}),this.trackLinkElement(e)}trackLinkElement(e){this.trackedLinkElements.has(e)||(this.trackedLinkElements.add(e),this.trackStylesheetInLinkElement(e))}adoptStyleSheets(e,t){if(0===e.length)return;const n={id:t,styleIds:[]},r=[];for(const t of e){let e;this.styleMirror.has(t)?e=this.styleMirror.getId(t):(e=this.styleMirror.add(t),r.push({styleId:e,rules:Array.from(t.rules||CSSRule,((e,t)=>({rule:lc(e),index:t})))})),n.styleIds.push(e)}r.length>0&&(n.styles=r),this.adoptedStyleSheetCb(n)}reset(){this.styleMirror.reset(),this.trackedLinkElements=new WeakSet}trackStylesheetInLinkElement(e){}}class sl{constructor(){this.nodeMap=new WeakMap,this.loop=!0,this.periodicallyClear()}periodicallyClear(){!function(...e){yu("requestAnimationFrame")(...e)}((()=>{this.clear(),this.loop&&this.periodicallyClear()}))}inOtherBuffer(e,t){const n=this.nodeMap.get(e);return n&&Array.from(n).some((e=>e!==t))}add(e,t){this.nodeMap.set(e,(this.nodeMap.get(e)||new Set).add(t))}clear(){this.nodeMap=new WeakMap}destroy(){this.loop=!1}}let il,al;try{if(2!==Array.from([1],(e=>2*e))[0]){const e=document.createElement("iframe");document.body.appendChild(e),Array.from=Mt([e,"access",e=>e.contentWindow,"optionalAccess",e=>e.Array,"access",e=>e.from])||Array.from,document.body.removeChild(e)}}catch(e){console.debug("Unable to override Array.from",e)}const cl=new dc;function ul(e={}){const{emit:t,checkoutEveryNms:n,checkoutEveryNth:r,blockClass:o="rr-block",blockSelector:s=null,unblockSelector:i=null,ignoreClass:a="rr-ignore",ignoreSelector:c=null,maskAllText:u=!1,maskTextClass:l="rr-mask",unmaskTextClass:d=null,maskTextSelector:p=null,unmaskTextSelector:h=null,inlineStylesheet:f=!0,maskAllInputs:m,maskInputOptions:g,slimDOMOptions:_,maskAttributeFn:y,maskInputFn:v,maskTextFn:b,maxCanvasSize:S=null,packFn:w,sampling:k={},dataURLOptions:x={},mousemoveWait:E,recordDOM:C=!0,recordCanvas:T=!1,recordCrossOriginIframes:I=!1,recordAfter:M=("DOMContentLoaded"===e.recordAfter?e.recordAfter:"load"),userTriggeredOnInput:R=!1,collectFonts:A=!1,inlineImages:N=!1,plugins:O,keepIframeSrcFn:D=()=>!1,ignoreCSSAttributes:L=new Set([]),errorHandler:P,onMutation:F,getCanvasManager:$}=e;Du=P;const B=!I||window.parent===window;let U=!1;if(!B)try{window.parent.document&&(U=!1)}catch(e){U=!0}if(B&&!t)throw new Error("emit function is required");void 0!==E&&void 0===k.mousemove&&(k.mousemove=E),cl.reset();const j=!0===m?{color:!0,date:!0,"datetime-local":!0,email:!0,month:!0,number:!0,range:!0,search:!0,tel:!0,text:!0,time:!0,url:!0,week:!0,textarea:!0,select:!0,radio:!0,checkbox:!0}:void 0!==g?g:{},H=!0===_||"all"===_?{script:!0,comment:!0,headFavicon:!0,headWhitespace:!0,headMetaSocial:!0,headMetaRobots:!0,headMetaHttpEquiv:!0,headMetaVerification:!0,headMetaAuthorship:"all"===_,headMetaDescKeywords:"all"===_}:_||{};let z;!function(e=window){"NodeList"in e&&!e.NodeList.prototype.forEach&&(e.NodeList.prototype.forEach=Array.prototype.forEach),"DOMTokenList"in e&&!e.DOMTokenList.prototype.forEach&&(e.DOMTokenList.prototype.forEach=Array.prototype.forEach),Node.prototype.contains||(Node.prototype.contains=(...e)=>{let t=e[0];if(!(0 in e))throw new TypeError("1 argument is required");do{if(this===t)return!0}while(t=t&&t.parentNode);return!1})}();let q=0;const W=e=>{for(const t of O||[])t.eventProcessor&&(e=t.eventProcessor(e));return w&&!U&&(e=w(e)),e};il=(e,o)=>{const s=e;if(s.timestamp=tu(),!Mt([Fu,"access",e=>e[0],"optionalAccess",e=>e.isFrozen,"call",e=>e()])||s.type===bu.FullSnapshot||s.type===bu.IncrementalSnapshot&&s.data.source===Su.Mutation||Fu.forEach((e=>e.unfreeze())),B)Mt([t,"optionalCall",e=>e(W(s),o)]);else if(U){const e={type:"rrweb",event:W(s),origin:window.location.origin,isCheckout:o};window.parent.postMessage(e,"*")}if(s.type===bu.FullSnapshot)z=s,q=0;else if(s.type===bu.IncrementalSnapshot){if(s.data.source===Su.Mutation&&s.data.isAttachIframe)return;q++;const e=r&&q>=r,t=n&&z&&s.timestamp-z.timestamp>n;(e||t)&&ee(!0)}};const Y=e=>{il({type:bu.IncrementalSnapshot,data:{source:Su.Mutation,...e}})},V=e=>il({type:bu.IncrementalSnapshot,data:{source:Su.Scroll,...e}}),G=e=>il({type:bu.IncrementalSnapshot,data:{source:Su.CanvasMutation,...e}}),X=new ol({mutationCb:Y,adoptedStyleSheetCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.AdoptedStyleSheet,...e}})}),J="boolean"==typeof __RRWEB_EXCLUDE_IFRAME__&&__RRWEB_EXCLUDE_IFRAME__?new Zu:new el({mirror:cl,mutationCb:Y,stylesheetManager:X,recordCrossOriginIframes:I,wrappedEmit:il});for(const e of O||[])e.getMirror&&e.getMirror({nodeMirror:cl,crossOriginIframeMirror:J.crossOriginIframeMirror,crossOriginIframeStyleMirror:J.crossOriginIframeStyleMirror});const K=new sl,Q=function(e,t){try{return e?e(t):new rl}catch(e){return console.warn("Unable to initialize CanvasManager"),new rl}}($,{mirror:cl,win:window,mutationCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.CanvasMutation,...e}}),recordCanvas:T,blockClass:o,blockSelector:s,unblockSelector:i,maxCanvasSize:S,sampling:k.canvas,dataURLOptions:x,errorHandler:P}),Z="boolean"==typeof __RRWEB_EXCLUDE_SHADOW_DOM__&&__RRWEB_EXCLUDE_SHADOW_DOM__?new tl:new nl({mutationCb:Y,scrollCb:V,bypassOptions:{onMutation:F,blockClass:o,blockSelector:s,unblockSelector:i,maskAllText:u,maskTextClass:l,unmaskTextClass:d,maskTextSelector:p,unmaskTextSelector:h,inlineStylesheet:f,maskInputOptions:j,dataURLOptions:x,maskAttributeFn:y,maskTextFn:b,maskInputFn:v,recordCanvas:T,inlineImages:N,sampling:k,slimDOMOptions:H,iframeManager:J,stylesheetManager:X,canvasManager:Q,keepIframeSrcFn:D,processedNodeManager:K},mirror:cl}),ee=(e=!1)=>{if(!C)return;il({type:bu.Meta,data:{href:window.location.href,width:ou(),height:ru()}},e),X.reset(),Z.init(),Fu.forEach((e=>e.lock()));const t=function(e,t){const{mirror:n=new dc,blockClass:r="rr-block",blockSelector:o=null,unblockSelector:s=null,maskAllText:i=!1,maskTextClass:a="rr-mask",unmaskTextClass:c=null,maskTextSelector:u=null,unmaskTextSelector:l=null,inlineStylesheet:d=!0,inlineImages:p=!1,recordCanvas:h=!1,maskAllInputs:f=!1,maskAttributeFn:m,maskTextFn:g,maskInputFn:_,slimDOM:y=!1,dataURLOptions:v,preserveWhiteSpace:b,onSerialize:S,onIframeLoad:w,iframeLoadTimeout:k,onStylesheetLoad:x,stylesheetLoadTimeout:E,keepIframeSrcFn:C=()=>!1}=t||{};return Vc(e,{doc:e,mirror:n,blockClass:r,blockSelector:o,unblockSelector:s,maskAllText:i,maskTextClass:a,unmaskTextClass:c,maskTextSelector:u,unmaskTextSelector:l,skipChild:!1,inlineStylesheet:d,maskInputOptions:!0===f?{color:!0,date:!0,"datetime-local":!0,email:!0,month:!0,number:!0,range:!0,search:!0,tel:!0,text:!0,time:!0,url:!0,week:!0,textarea:!0,select:!0}:!1===f?{}:f,maskAttributeFn:m,maskTextFn:g,maskInputFn:_,slimDOMOptions:!0===y||"all"===y?{script:!0,comment:!0,headFavicon:!0,headWhitespace:!0,headMetaDescKeywords:"all"===y,headMetaSocial:!0,headMetaRobots:!0,headMetaHttpEquiv:!0,headMetaAuthorship:!0,headMetaVerification:!0}:!1===y?{}:y,dataURLOptions:v,inlineImages:p,recordCanvas:h,preserveWhiteSpace:b,onSerialize:S,onIframeLoad:w,iframeLoadTimeout:k,onStylesheetLoad:x,stylesheetLoadTimeout:E,keepIframeSrcFn:C,newlyAddedElement:!1})}(document,{mirror:cl,blockClass:o,blockSelector:s,unblockSelector:i,maskAllText:u,maskTextClass:l,unmaskTextClass:d,maskTextSelector:p,unmaskTextSelector:h,inlineStylesheet:f,maskAllInputs:j,maskAttributeFn:y,maskInputFn:v,maskTextFn:b,slimDOM:H,dataURLOptions:x,recordCanvas:T,inlineImages:N,onSerialize:e=>{lu(e,cl)&&J.addIframe(e),du(e,cl)&&X.trackLinkElement(e),pu(e)&&Z.addShadowRoot(e.shadowRoot,document)},onIframeLoad:(e,t)=>{J.attachIframe(e,t),e.contentWindow&&Q.addWindow(e.contentWindow),Z.observeAttachShadow(e)},onStylesheetLoad:(e,t)=>{X.attachLinkElement(e,t)},keepIframeSrcFn:D});if(!t)return console.warn("Failed to snapshot the document");il({type:bu.FullSnapshot,data:{node:t,initialOffset:nu(window)}}),Fu.forEach((e=>e.unlock())),document.adoptedStyleSheets&&document.adoptedStyleSheets.length>0&&X.adoptStyleSheets(document.adoptedStyleSheets,cl.getId(document))};al=ee;try{const e=[],t=e=>Lu(Gu)({onMutation:F,mutationCb:Y,mousemoveCb:(e,t)=>il({type:bu.IncrementalSnapshot,data:{source:t,positions:e}}),mouseInteractionCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.MouseInteraction,...e}}),scrollCb:V,viewportResizeCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.ViewportResize,...e}}),inputCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.Input,...e}}),mediaInteractionCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.MediaInteraction,...e}}),styleSheetRuleCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.StyleSheetRule,...e}}),styleDeclarationCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.StyleDeclaration,...e}}),canvasMutationCb:G,fontCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.Font,...e}}),selectionCb:e=>{il({type:bu.IncrementalSnapshot,data:{source:Su.Selection,...e}})},customElementCb:e=>{il({type:bu.IncrementalSnapshot,data:{source:Su.CustomElement,...e}})},blockClass:o,ignoreClass:a,ignoreSelector:c,maskAllText:u,maskTextClass:l,unmaskTextClass:d,maskTextSelector:p,unmaskTextSelector:h,maskInputOptions:j,inlineStylesheet:f,sampling:k,recordDOM:C,recordCanvas:T,inlineImages:N,userTriggeredOnInput:R,collectFonts:A,doc:e,maskAttributeFn:y,maskInputFn:v,maskTextFn:b,keepIframeSrcFn:D,blockSelector:s,unblockSelector:i,slimDOMOptions:H,dataURLOptions:x,mirror:cl,iframeManager:J,stylesheetManager:X,shadowDomManager:Z,processedNodeManager:K,canvasManager:Q,ignoreCSSAttributes:L,plugins:Mt([O,"optionalAccess",e=>e.filter,"call",e=>e((e=>e.observer)),"optionalAccess",e=>e.map,"call",e=>e((e=>({observer:e.observer,options:e.options,callback:t=>il({type:bu.Plugin,data:{plugin:e.name,payload:t}})})))])||[]},{});J.addLoadListener((n=>{try{e.push(t(n.contentDocument))}catch(e){console.warn(e)}}));const n=()=>{ee(),e.push(t(document))};return"interactive"===document.readyState||"complete"===document.readyState?n():(e.push(Xc("DOMContentLoaded",(()=>{il({type:bu.DomContentLoaded,data:{}}),"DOMContentLoaded"===M&&n()}))),e.push(Xc("load",(()=>{il({type:bu.Load,data:{}}),"load"===M&&n()}),window))),()=>{e.forEach((e=>e())),K.destroy(),al=void 0,Du=void 0}}catch(e){console.warn(e)}}ul.mirror=cl,ul.takeFullSnapshot=function(e){if(!al)throw new Error("please take full snapshot after start recording");al(e)};const ll="undefined"==typeof __SENTRY_DEBUG__||__SENTRY_DEBUG__,dl=["info","warn","error","log"],pl="[Replay] ";function hl(e,t="info"){io({category:"console",data:{logger:"replay"},level:t,message:`${pl}${e}`},{level:t})}const fl=function(){let e=!1,t=!1;const n={exception:()=>{},infoTick:()=>{},setConfig:n=>{e=n.captureExceptions,t=n.traceInternals}};return ll?(dl.forEach((e=>{n[e]=(...n)=>{L[e](pl,...n),t&&hl(n[0])}})),n.exception=(r,...o)=>{n.error&&n.error(...o),L.error(pl,r),e?Sr(r):t&&hl(r)},n.infoTick=(...e)=>{L.info(pl,...e),t&&setTimeout((()=>hl(e[0])),0)}):dl.forEach((e=>{n[e]=()=>{}})),n}(),ml=3;function gl(e){return e>9999999999?e:1e3*e}function _l(e){return e>9999999999?e/1e3:e}function yl(e,t){"sentry.transaction"!==t.category&&(["ui.click","ui.input"].includes(t.category)?e.triggerUserActivity():e.checkAndHandleExpiredSession(),e.addUpdate((()=>(e.throttledAddEvent({type:bu.Custom,timestamp:1e3*(t.timestamp||0),data:{tag:"breadcrumb",payload:je(t,10,1e3)}}),"console"===t.category))))}const vl="button,a";function bl(e){return e.closest(vl)||e}function Sl(e){const t=wl(e);return t&&t instanceof Element?bl(t):t}function wl(e){return function(e){return"object"==typeof e&&!!e&&"target"in e}(e)?e.target:e}let kl;function xl(e){return kl||(kl=[],H(Ja,"open",(function(e){return function(...t){if(kl)try{kl.forEach((e=>e()))}catch(e){}return e.apply(Ja,t)}}))),kl.push(e),()=>{const t=kl?kl.indexOf(e):-1;t>-1&&kl.splice(t,1)}}const El=new Set([Su.Mutation,Su.StyleSheetRule,Su.StyleDeclaration,Su.AdoptedStyleSheet,Su.CanvasMutation,Su.Selection,Su.MediaInteraction]);class Cl{constructor(e,t,n=yl){this._lastMutation=0,this._lastScroll=0,this._clicks=[],this._timeout=t.timeout/1e3,this._threshold=t.threshold/1e3,this._scollTimeout=t.scrollTimeout/1e3,this._replay=e,this._ignoreSelector=t.ignoreSelector,this._addBreadcrumbEvent=n}addListeners(){const e=xl((()=>{this._lastMutation=Il()}));this._teardown=()=>{e(),this._clicks=[],this._lastMutation=0,this._lastScroll=0}}removeListeners(){this._teardown&&this._teardown(),this._checkClickTimeout&&clearTimeout(this._checkClickTimeout)}handleClick(e,t){if(function(e,t){if(!Tl.includes(e.tagName))return!0;if("INPUT"===e.tagName&&!["submit","button"].includes(e.getAttribute("type")||""))return!0;if("A"===e.tagName&&(e.hasAttribute("download")||e.hasAttribute("target")&&"_self"!==e.getAttribute("target")))return!0;if(t&&e.matches(t))return!0;return!1}(t,this._ignoreSelector)||!function(e){return!(!e.data||"number"!=typeof e.data.nodeId||!e.timestamp)}(e))return;const n={timestamp:_l(e.timestamp),clickBreadcrumb:e,clickCount:0,node:t};this._clicks.some((e=>e.node===n.node&&Math.abs(e.timestamp-n.timestamp)<1))||(this._clicks.push(n),1===this._clicks.length&&this._scheduleCheckClicks())}registerMutation(e=Date.now()){this._lastMutation=_l(e)}registerScroll(e=Date.now()){this._lastScroll=_l(e)}registerClick(e){const t=bl(e);this._handleMultiClick(t)}_handleMultiClick(e){this._getClicks(e).forEach((e=>{e.clickCount++}))}_getClicks(e){return this._clicks.filter((t=>t.node===e))}_checkClicks(){const e=[],t=Il();this._clicks.forEach((n=>{!n.mutationAfter&&this._lastMutation&&(n.mutationAfter=n.timestamp<=this._lastMutation?this._lastMutation-n.timestamp:void 0),!n.scrollAfter&&this._lastScroll&&(n.scrollAfter=n.timestamp<=this._lastScroll?this._lastScroll-n.timestamp:void 0),n.timestamp+this._timeout<=t&&e.push(n)}));for(const t of e){const e=this._clicks.indexOf(t);e>-1&&(this._generateBreadcrumbs(t),this._clicks.splice(e,1))}this._clicks.length&&this._scheduleCheckClicks()}_generateBreadcrumbs(e){const t=this._replay,n=e.scrollAfter&&e.scrollAfter<=this._scollTimeout,r=e.mutationAfter&&e.mutationAfter<=this._threshold,o=!n&&!r,{clickCount:s,clickBreadcrumb:i}=e;if(o){const n=1e3*Math.min(e.mutationAfter||this._timeout,this._timeout),r=n<1e3*this._timeout?"mutation":"timeout",o={type:"default",message:i.message,timestamp:i.timestamp,category:"ui.slowClickDetected",data:{...i.data,url:Ja.location.href,route:t.getCurrentRoute(),timeAfterClickMs:n,endReason:r,clickCount:s||1}};this._addBreadcrumbEvent(t,o)}else if(s>1){const e={type:"default",message:i.message,timestamp:i.timestamp,category:"ui.multiClick",data:{...i.data,url:Ja.location.href,route:t.getCurrentRoute(),clickCount:s,metric:!0}};this._addBreadcrumbEvent(t,e)}}_scheduleCheckClicks(){this._checkClickTimeout&&clearTimeout(this._checkClickTimeout),this._checkClickTimeout=Gi((()=>this._checkClicks()),1e3)}}const Tl=["A","BUTTON","INPUT"];function Il(){return Date.now()/1e3}function Ml(e,t){try{if(!function(e){return e.type===ml}(t))return;const{source:n}=t.data;if(El.has(n)&&e.registerMutation(t.timestamp),n===Su.Scroll&&e.registerScroll(t.timestamp),function(e){return e.data.source===Su.MouseInteraction}(t)){const{type:n,id:r}=t.data,o=ul.mirror.getNode(r);o instanceof HTMLElement&&n===wu.Click&&e.registerClick(o)}}catch(e){}}function Rl(e){return{timestamp:Date.now()/1e3,type:"default",...e}}var Al;!function(e){e[e.Document=0]="Document",e[e.DocumentType=1]="DocumentType",e[e.Element=2]="Element",e[e.Text=3]="Text",e[e.CDATA=4]="CDATA",e[e.Comment=5]="Comment"}(Al||(Al={}));const Nl=new Set(["id","class","aria-label","role","name","alt","title","data-test-id","data-testid","disabled","aria-disabled","data-sentry-component"]);function Ol(e){const t={};!e["data-sentry-component"]&&e["data-sentry-element"]&&(e["data-sentry-component"]=e["data-sentry-element"]);for(const n in e)if(Nl.has(n)){let r=n;"data-testid"!==n&&"data-test-id"!==n||(r="testId"),t[r]=e[n]}return t}const Dl=e=>t=>{if(!e.isEnabled())return;const n=function(e){const{target:t,message:n}=function(e){const t="click"===e.name;let n,r=null;try{r=t?Sl(e.event):wl(e.event),n=T(r,{maxStringLength:200})||"<unknown>"}catch(e){n="<unknown>"}return{target:r,message:n}}(e);return Rl({category:`ui.${e.name}`,...Ll(t,n)})}(t);if(!n)return;const r="click"===t.name,o=r?t.event:void 0;var s,i,a;!(r&&e.clickDetector&&o&&o.target)||o.altKey||o.metaKey||o.ctrlKey||o.shiftKey||(s=e.clickDetector,i=n,a=Sl(t.event),s.handleClick(i,a)),yl(e,n)};function Ll(e,t){const n=ul.mirror.getId(e),r=n&&ul.mirror.getNode(n),o=r&&ul.mirror.getMeta(r),s=o&&function(e){return e.type===Al.Element}(o)?o:null;return{message:t,data:s?{nodeId:n,node:{id:n,tagName:s.tagName,textContent:Array.from(s.childNodes).map((e=>e.type===Al.Text&&e.textContent)).filter(Boolean).map((e=>e.trim())).join(""),attributes:Ol(s.attributes)}}:{}}}function Pl(e,t){if(!e.isEnabled())return;e.updateUserActivity();const n=function(e){const{metaKey:t,shiftKey:n,ctrlKey:r,altKey:o,key:s,target:i}=e;if(!i||function(e){return"INPUT"===e.tagName||"TEXTAREA"===e.tagName||e.isContentEditable}(i)||!s)return null;const a=t||r||o,c=1===s.length;if(!a&&c)return null;const u=T(i,{maxStringLength:200})||"<unknown>",l=Ll(i,u);return Rl({category:"ui.keyDown",message:u,data:{...l.data,metaKey:t,shiftKey:n,ctrlKey:r,altKey:o,key:s}})}(t);n&&yl(e,n)}const Fl={resource:function(e){const{entryType:t,initiatorType:n,name:r,responseEnd:o,startTime:s,decodedBodySize:i,encodedBodySize:a,responseStatus:c,transferSize:u}=e;if(["fetch","xmlhttprequest"].includes(n))return null;return{type:`${t}.${n}`,start:Ul(s),end:Ul(o),name:r,data:{size:u,statusCode:c,decodedBodySize:i,encodedBodySize:a}}},paint:function(e){const{duration:t,entryType:n,name:r,startTime:o}=e,s=Ul(o);return{type:n,name:r,start:s,end:s+t,data:void 0}},navigation:function(e){const{entryType:t,name:n,decodedBodySize:r,duration:o,domComplete:s,encodedBodySize:i,domContentLoadedEventStart:a,domContentLoadedEventEnd:c,domInteractive:u,loadEventStart:l,loadEventEnd:d,redirectCount:p,startTime:h,transferSize:f,type:m}=e;if(0===o)return null;return{type:`${t}.${m}`,start:Ul(h),end:Ul(s),name:n,data:{size:f,decodedBodySize:r,encodedBodySize:i,duration:o,domInteractive:u,domContentLoadedEventStart:a,domContentLoadedEventEnd:c,loadEventStart:l,loadEventEnd:d,domComplete:s,redirectCount:p}}}};function $l(e,t){return({metric:n})=>{t.replayPerformanceEntries.push(e(n))}}function Bl(e){const t=Fl[e.entryType];return t?t(e):null}function Ul(e){return((be||Ja.performance.timeOrigin)+e)/1e3}function jl(e){const t=e.entries[e.entries.length-1];return Yl(e,"largest-contentful-paint",t&&t.element?[t.element]:void 0)}function Hl(e){return void 0!==e.sources}function zl(e){const t=[],n=[];for(const r of e.entries)if(Hl(r)){const e=[];for(const t of r.sources)if(t.node){n.push(t.node);const r=ul.mirror.getId(t.node);r&&e.push(r)}t.push({value:r.value,nodeIds:e.length?e:void 0})}return Yl(e,"cumulative-layout-shift",n,t)}function ql(e){const t=e.entries[e.entries.length-1];return Yl(e,"first-input-delay",t&&t.target?[t.target]:void 0)}function Wl(e){const t=e.entries[e.entries.length-1];return Yl(e,"interaction-to-next-paint",t&&t.target?[t.target]:void 0)}function Yl(e,t,n,r){const o=e.value,s=e.rating,i=Ul(o);return{type:"web-vital",name:t,start:i,end:i,data:{value:o,size:o,rating:s,nodeIds:n?n.map((e=>ul.mirror.getId(e))):void 0,attributions:r}}}const Vl='var t=Uint8Array,n=Uint16Array,r=Int32Array,e=new t([0,0,0,0,0,0,0,0,1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4,5,5,5,5,0,0,0,0]),i=new t([0,0,0,0,1,1,2,2,3,3,4,4,5,5,6,6,7,7,8,8,9,9,10,10,11,11,12,12,13,13,0,0]),a=new t([16,17,18,0,8,7,9,6,10,5,11,4,12,3,13,2,14,1,15]),s=function(t,e){for(var i=new n(31),a=0;a<31;++a)i[a]=e+=1<<t[a-1];var s=new r(i[30]);for(a=1;a<30;++a)for(var o=i[a];o<i[a+1];++o)s[o]=o-i[a]<<5|a;return{b:i,r:s}},o=s(e,2),f=o.b,h=o.r;f[28]=258,h[258]=28;for(var l=s(i,0).r,u=new n(32768),c=0;c<32768;++c){var v=(43690&c)>>1|(21845&c)<<1;v=(61680&(v=(52428&v)>>2|(13107&v)<<2))>>4|(3855&v)<<4,u[c]=((65280&v)>>8|(255&v)<<8)>>1}var d=function(t,r,e){for(var i=t.length,a=0,s=new n(r);a<i;++a)t[a]&&++s[t[a]-1];var o,f=new n(r);for(a=1;a<r;++a)f[a]=f[a-1]+s[a-1]<<1;if(e){o=new n(1<<r);var h=15-r;for(a=0;a<i;++a)if(t[a])for(var l=a<<4|t[a],c=r-t[a],v=f[t[a]-1]++<<c,d=v|(1<<c)-1;v<=d;++v)o[u[v]>>h]=l}else for(o=new n(i),a=0;a<i;++a)t[a]&&(o[a]=u[f[t[a]-1]++]>>15-t[a]);return o},g=new t(288);for(c=0;c<144;++c)g[c]=8;for(c=144;c<256;++c)g[c]=9;for(c=256;c<280;++c)g[c]=7;for(c=280;c<288;++c)g[c]=8;var w=new t(32);for(c=0;c<32;++c)w[c]=5;var p=d(g,9,0),y=d(w,5,0),m=function(t){return(t+7)/8|0},b=function(n,r,e){return(null==r||r<0)&&(r=0),(null==e||e>n.length)&&(e=n.length),new t(n.subarray(r,e))},M=["unexpected EOF","invalid block type","invalid length/literal","invalid distance","stream finished","no stream handler",,"no callback","invalid UTF-8 data","extra field too long","date not in range 1980-2099","filename too long","stream finishing","invalid zip data"],E=function(t,n,r){var e=new Error(n||M[t]);if(e.code=t,Error.captureStackTrace&&Error.captureStackTrace(e,E),!r)throw e;return e},z=function(t,n,r){r<<=7&n;var e=n/8|0;t[e]|=r,t[e+1]|=r>>8},A=function(t,n,r){r<<=7&n;var e=n/8|0;t[e]|=r,t[e+1]|=r>>8,t[e+2]|=r>>16},_=function(r,e){for(var i=[],a=0;a<r.length;++a)r[a]&&i.push({s:a,f:r[a]});var s=i.length,o=i.slice();if(!s)return{t:F,l:0};if(1==s){var f=new t(i[0].s+1);return f[i[0].s]=1,{t:f,l:1}}i.sort((function(t,n){return t.f-n.f})),i.push({s:-1,f:25001});var h=i[0],l=i[1],u=0,c=1,v=2;for(i[0]={s:-1,f:h.f+l.f,l:h,r:l};c!=s-1;)h=i[i[u].f<i[v].f?u++:v++],l=i[u!=c&&i[u].f<i[v].f?u++:v++],i[c++]={s:-1,f:h.f+l.f,l:h,r:l};var d=o[0].s;for(a=1;a<s;++a)o[a].s>d&&(d=o[a].s);var g=new n(d+1),w=x(i[c-1],g,0);if(w>e){a=0;var p=0,y=w-e,m=1<<y;for(o.sort((function(t,n){return g[n.s]-g[t.s]||t.f-n.f}));a<s;++a){var b=o[a].s;if(!(g[b]>e))break;p+=m-(1<<w-g[b]),g[b]=e}for(p>>=y;p>0;){var M=o[a].s;g[M]<e?p-=1<<e-g[M]++-1:++a}for(;a>=0&&p;--a){var E=o[a].s;g[E]==e&&(--g[E],++p)}w=e}return{t:new t(g),l:w}},x=function(t,n,r){return-1==t.s?Math.max(x(t.l,n,r+1),x(t.r,n,r+1)):n[t.s]=r},D=function(t){for(var r=t.length;r&&!t[--r];);for(var e=new n(++r),i=0,a=t[0],s=1,o=function(t){e[i++]=t},f=1;f<=r;++f)if(t[f]==a&&f!=r)++s;else{if(!a&&s>2){for(;s>138;s-=138)o(32754);s>2&&(o(s>10?s-11<<5|28690:s-3<<5|12305),s=0)}else if(s>3){for(o(a),--s;s>6;s-=6)o(8304);s>2&&(o(s-3<<5|8208),s=0)}for(;s--;)o(a);s=1,a=t[f]}return{c:e.subarray(0,i),n:r}},T=function(t,n){for(var r=0,e=0;e<n.length;++e)r+=t[e]*n[e];return r},k=function(t,n,r){var e=r.length,i=m(n+2);t[i]=255&e,t[i+1]=e>>8,t[i+2]=255^t[i],t[i+3]=255^t[i+1];for(var a=0;a<e;++a)t[i+a+4]=r[a];return 8*(i+4+e)},C=function(t,r,s,o,f,h,l,u,c,v,m){z(r,m++,s),++f[256];for(var b=_(f,15),M=b.t,E=b.l,x=_(h,15),C=x.t,U=x.l,F=D(M),I=F.c,S=F.n,L=D(C),O=L.c,j=L.n,q=new n(19),B=0;B<I.length;++B)++q[31&I[B]];for(B=0;B<O.length;++B)++q[31&O[B]];for(var G=_(q,7),H=G.t,J=G.l,K=19;K>4&&!H[a[K-1]];--K);var N,P,Q,R,V=v+5<<3,W=T(f,g)+T(h,w)+l,X=T(f,M)+T(h,C)+l+14+3*K+T(q,H)+2*q[16]+3*q[17]+7*q[18];if(c>=0&&V<=W&&V<=X)return k(r,m,t.subarray(c,c+v));if(z(r,m,1+(X<W)),m+=2,X<W){N=d(M,E,0),P=M,Q=d(C,U,0),R=C;var Y=d(H,J,0);z(r,m,S-257),z(r,m+5,j-1),z(r,m+10,K-4),m+=14;for(B=0;B<K;++B)z(r,m+3*B,H[a[B]]);m+=3*K;for(var Z=[I,O],$=0;$<2;++$){var tt=Z[$];for(B=0;B<tt.length;++B){var nt=31&tt[B];z(r,m,Y[nt]),m+=H[nt],nt>15&&(z(r,m,tt[B]>>5&127),m+=tt[B]>>12)}}}else N=p,P=g,Q=y,R=w;for(B=0;B<u;++B){var rt=o[B];if(rt>255){A(r,m,N[(nt=rt>>18&31)+257]),m+=P[nt+257],nt>7&&(z(r,m,rt>>23&31),m+=e[nt]);var et=31&rt;A(r,m,Q[et]),m+=R[et],et>3&&(A(r,m,rt>>5&8191),m+=i[et])}else A(r,m,N[rt]),m+=P[rt]}return A(r,m,N[256]),m+P[256]},U=new r([65540,131080,131088,131104,262176,1048704,1048832,2114560,2117632]),F=new t(0),I=function(){for(var t=new Int32Array(256),n=0;n<256;++n){for(var r=n,e=9;--e;)r=(1&r&&-306674912)^r>>>1;t[n]=r}return t}(),S=function(){var t=-1;return{p:function(n){for(var r=t,e=0;e<n.length;++e)r=I[255&r^n[e]]^r>>>8;t=r},d:function(){return~t}}},L=function(){var t=1,n=0;return{p:function(r){for(var e=t,i=n,a=0|r.length,s=0;s!=a;){for(var o=Math.min(s+2655,a);s<o;++s)i+=e+=r[s];e=(65535&e)+15*(e>>16),i=(65535&i)+15*(i>>16)}t=e,n=i},d:function(){return(255&(t%=65521))<<24|(65280&t)<<8|(255&(n%=65521))<<8|n>>8}}},O=function(a,s,o,f,u){if(!u&&(u={l:1},s.dictionary)){var c=s.dictionary.subarray(-32768),v=new t(c.length+a.length);v.set(c),v.set(a,c.length),a=v,u.w=c.length}return function(a,s,o,f,u,c){var v=c.z||a.length,d=new t(f+v+5*(1+Math.ceil(v/7e3))+u),g=d.subarray(f,d.length-u),w=c.l,p=7&(c.r||0);if(s){p&&(g[0]=c.r>>3);for(var y=U[s-1],M=y>>13,E=8191&y,z=(1<<o)-1,A=c.p||new n(32768),_=c.h||new n(z+1),x=Math.ceil(o/3),D=2*x,T=function(t){return(a[t]^a[t+1]<<x^a[t+2]<<D)&z},F=new r(25e3),I=new n(288),S=new n(32),L=0,O=0,j=c.i||0,q=0,B=c.w||0,G=0;j+2<v;++j){var H=T(j),J=32767&j,K=_[H];if(A[J]=K,_[H]=J,B<=j){var N=v-j;if((L>7e3||q>24576)&&(N>423||!w)){p=C(a,g,0,F,I,S,O,q,G,j-G,p),q=L=O=0,G=j;for(var P=0;P<286;++P)I[P]=0;for(P=0;P<30;++P)S[P]=0}var Q=2,R=0,V=E,W=J-K&32767;if(N>2&&H==T(j-W))for(var X=Math.min(M,N)-1,Y=Math.min(32767,j),Z=Math.min(258,N);W<=Y&&--V&&J!=K;){if(a[j+Q]==a[j+Q-W]){for(var $=0;$<Z&&a[j+$]==a[j+$-W];++$);if($>Q){if(Q=$,R=W,$>X)break;var tt=Math.min(W,$-2),nt=0;for(P=0;P<tt;++P){var rt=j-W+P&32767,et=rt-A[rt]&32767;et>nt&&(nt=et,K=rt)}}}W+=(J=K)-(K=A[J])&32767}if(R){F[q++]=268435456|h[Q]<<18|l[R];var it=31&h[Q],at=31&l[R];O+=e[it]+i[at],++I[257+it],++S[at],B=j+Q,++L}else F[q++]=a[j],++I[a[j]]}}for(j=Math.max(j,B);j<v;++j)F[q++]=a[j],++I[a[j]];p=C(a,g,w,F,I,S,O,q,G,j-G,p),w||(c.r=7&p|g[p/8|0]<<3,p-=7,c.h=_,c.p=A,c.i=j,c.w=B)}else{for(j=c.w||0;j<v+w;j+=65535){var st=j+65535;st>=v&&(g[p/8|0]=w,st=v),p=k(g,p+1,a.subarray(j,st))}c.i=v}return b(d,0,f+m(p)+u)}(a,null==s.level?6:s.level,null==s.mem?Math.ceil(1.5*Math.max(8,Math.min(13,Math.log(a.length)))):12+s.mem,o,f,u)},j=function(t,n,r){for(;r;++n)t[n]=r,r>>>=8},q=function(t,n){var r=n.filename;if(t[0]=31,t[1]=139,t[2]=8,t[8]=n.level<2?4:9==n.level?2:0,t[9]=3,0!=n.mtime&&j(t,4,Math.floor(new Date(n.mtime||Date.now())/1e3)),r){t[3]=8;for(var e=0;e<=r.length;++e)t[e+10]=r.charCodeAt(e)}},B=function(t){return 10+(t.filename?t.filename.length+1:0)},G=function(){function n(n,r){if("function"==typeof n&&(r=n,n={}),this.ondata=r,this.o=n||{},this.s={l:0,i:32768,w:32768,z:32768},this.b=new t(98304),this.o.dictionary){var e=this.o.dictionary.subarray(-32768);this.b.set(e,32768-e.length),this.s.i=32768-e.length}}return n.prototype.p=function(t,n){this.ondata(O(t,this.o,0,0,this.s),n)},n.prototype.push=function(n,r){this.ondata||E(5),this.s.l&&E(4);var e=n.length+this.s.z;if(e>this.b.length){if(e>2*this.b.length-32768){var i=new t(-32768&e);i.set(this.b.subarray(0,this.s.z)),this.b=i}var a=this.b.length-this.s.z;a&&(this.b.set(n.subarray(0,a),this.s.z),this.s.z=this.b.length,this.p(this.b,!1)),this.b.set(this.b.subarray(-32768)),this.b.set(n.subarray(a),32768),this.s.z=n.length-a+32768,this.s.i=32766,this.s.w=32768}else this.b.set(n,this.s.z),this.s.z+=n.length;this.s.l=1&r,(this.s.z>this.s.w+8191||r)&&(this.p(this.b,r||!1),this.s.w=this.s.i,this.s.i-=2)},n}();var H=function(){function t(t,n){this.c=L(),this.v=1,G.call(this,t,n)}return t.prototype.push=function(t,n){this.c.p(t),G.prototype.push.call(this,t,n)},t.prototype.p=function(t,n){var r=O(t,this.o,this.v&&(this.o.dictionary?6:2),n&&4,this.s);this.v&&(function(t,n){var r=n.level,e=0==r?0:r<6?1:9==r?3:2;if(t[0]=120,t[1]=e<<6|(n.dictionary&&32),t[1]|=31-(t[0]<<8|t[1])%31,n.dictionary){var i=L();i.p(n.dictionary),j(t,2,i.d())}}(r,this.o),this.v=0),n&&j(r,r.length-4,this.c.d()),this.ondata(r,n)},t}(),J="undefined"!=typeof TextEncoder&&new TextEncoder,K="undefined"!=typeof TextDecoder&&new TextDecoder;try{K.decode(F,{stream:!0})}catch(t){}var N=function(){function t(t){this.ondata=t}return t.prototype.push=function(t,n){this.ondata||E(5),this.d&&E(4),this.ondata(P(t),this.d=n||!1)},t}();function P(n,r){if(r){for(var e=new t(n.length),i=0;i<n.length;++i)e[i]=n.charCodeAt(i);return e}if(J)return J.encode(n);var a=n.length,s=new t(n.length+(n.length>>1)),o=0,f=function(t){s[o++]=t};for(i=0;i<a;++i){if(o+5>s.length){var h=new t(o+8+(a-i<<1));h.set(s),s=h}var l=n.charCodeAt(i);l<128||r?f(l):l<2048?(f(192|l>>6),f(128|63&l)):l>55295&&l<57344?(f(240|(l=65536+(1047552&l)|1023&n.charCodeAt(++i))>>18),f(128|l>>12&63),f(128|l>>6&63),f(128|63&l)):(f(224|l>>12),f(128|l>>6&63),f(128|63&l))}return b(s,0,o)}function Q(t){return function(t,n){n||(n={});var r=S(),e=t.length;r.p(t);var i=O(t,n,B(n),8),a=i.length;return q(i,n),j(i,a-8,r.d()),j(i,a-4,e),i}(P(t))}const R=new class{constructor(){this._init()}clear(){this._init()}addEvent(t){if(!t)throw new Error("Adding invalid event");const n=this._hasEvents?",":"";this.stream.push(n+t),this._hasEvents=!0}finish(){this.stream.push("]",!0);const t=function(t){let n=0;for(const r of t)n+=r.length;const r=new Uint8Array(n);for(let n=0,e=0,i=t.length;n<i;n++){const i=t[n];r.set(i,e),e+=i.length}return r}(this._deflatedData);return this._init(),t}_init(){this._hasEvents=!1,this._deflatedData=[],this.deflate=new H,this.deflate.ondata=(t,n)=>{this._deflatedData.push(t)},this.stream=new N(((t,n)=>{this.deflate.push(t,n)})),this.stream.push("[")}},V={clear:()=>{R.clear()},addEvent:t=>R.addEvent(t),finish:()=>R.finish(),compress:t=>Q(t)};addEventListener("message",(function(t){const n=t.data.method,r=t.data.id,e=t.data.arg;if(n in V&&"function"==typeof V[n])try{const t=V[n](e);postMessage({id:r,method:n,success:!0,response:t})}catch(t){postMessage({id:r,method:n,success:!1,response:t.message}),console.error(t)}})),postMessage({id:void 0,method:"init",success:!0,response:void 0});';class Gl extends Error{constructor(){super("Event buffer exceeded maximum size of 20000000.")}}class Xl{constructor(){this.events=[],this._totalSize=0,this.hasCheckout=!1}get hasEvents(){return this.events.length>0}get type(){return"sync"}destroy(){this.events=[]}async addEvent(e){const t=JSON.stringify(e).length;if(this._totalSize+=t,this._totalSize>nc)throw new Gl;this.events.push(e)}finish(){return new Promise((e=>{const t=this.events;this.clear(),e(JSON.stringify(t))}))}clear(){this.events=[],this._totalSize=0,this.hasCheckout=!1}getEarliestTimestamp(){const e=this.events.map((e=>e.timestamp)).sort()[0];return e?gl(e):null}}class Jl{constructor(e){this._worker=e,this._id=0}ensureReady(){return this._ensureReadyPromise||(this._ensureReadyPromise=new Promise(((e,t)=>{this._worker.addEventListener("message",(({data:n})=>{n.success?e():t()}),{once:!0}),this._worker.addEventListener("error",(e=>{t(e)}),{once:!0})}))),this._ensureReadyPromise}destroy(){ll&&fl.info("Destroying compression worker"),this._worker.terminate()}postMessage(e,t){const n=this._getAndIncrementId();return new Promise(((r,o)=>{const s=({data:t})=>{const i=t;if(i.method===e&&i.id===n){if(this._worker.removeEventListener("message",s),!i.success)return ll&&fl.error("Error in compression worker: ",i.response),void o(new Error("Error in compression worker"));r(i.response)}};this._worker.addEventListener("message",s),this._worker.postMessage({id:n,method:e,arg:t})}))}_getAndIncrementId(){return this._id++}}class Kl{constructor(e){this._worker=new Jl(e),this._earliestTimestamp=null,this._totalSize=0,this.hasCheckout=!1}get hasEvents(){return!!this._earliestTimestamp}get type(){return"worker"}ensureReady(){return this._worker.ensureReady()}destroy(){this._worker.destroy()}addEvent(e){const t=gl(e.timestamp);(!this._earliestTimestamp||t<this._earliestTimestamp)&&(this._earliestTimestamp=t);const n=JSON.stringify(e);return this._totalSize+=n.length,this._totalSize>nc?Promise.reject(new Gl):this._sendEventToWorker(n)}finish(){return this._finishRequest()}clear(){this._earliestTimestamp=null,this._totalSize=0,this.hasCheckout=!1,this._worker.postMessage("clear").then(null,(e=>{ll&&fl.exception(e,'Sending "clear" message to worker failed',e)}))}getEarliestTimestamp(){return this._earliestTimestamp}_sendEventToWorker(e){return this._worker.postMessage("addEvent",e)}async _finishRequest(){const e=await this._worker.postMessage("finish");return this._earliestTimestamp=null,this._totalSize=0,e}}class Ql{constructor(e){this._fallback=new Xl,this._compression=new Kl(e),this._used=this._fallback,this._ensureWorkerIsLoadedPromise=this._ensureWorkerIsLoaded()}get type(){return this._used.type}get hasEvents(){return this._used.hasEvents}get hasCheckout(){return this._used.hasCheckout}set hasCheckout(e){this._used.hasCheckout=e}destroy(){this._fallback.destroy(),this._compression.destroy()}clear(){return this._used.clear()}getEarliestTimestamp(){return this._used.getEarliestTimestamp()}addEvent(e){return this._used.addEvent(e)}async finish(){return await this.ensureWorkerIsLoaded(),this._used.finish()}ensureWorkerIsLoaded(){return this._ensureWorkerIsLoadedPromise}async _ensureWorkerIsLoaded(){try{await this._compression.ensureReady()}catch(e){return void(ll&&fl.exception(e,"Failed to load the compression worker, falling back to simple buffer"))}await this._switchToCompressionWorker()}async _switchToCompressionWorker(){const{events:e,hasCheckout:t}=this._fallback,n=[];for(const t of e)n.push(this._compression.addEvent(t));this._compression.hasCheckout=t,this._used=this._compression;try{await Promise.all(n)}catch(e){ll&&fl.exception(e,"Failed to add events when switching buffers.")}}}function Zl({useCompression:e,workerUrl:t}){if(e&&window.Worker){const e=function(e){try{const t=e||function(){if("undefined"==typeof __SENTRY_EXCLUDE_REPLAY_WORKER__||!__SENTRY_EXCLUDE_REPLAY_WORKER__)return function(){const e=new Blob([Vl]);return URL.createObjectURL(e)}();return""}();if(!t)return;ll&&fl.info("Using compression worker"+(e?` from ${e}`:""));const n=new Worker(t);return new Ql(n)}catch(e){ll&&fl.exception(e,"Failed to create compression worker")}}(t);if(e)return e}return ll&&fl.info("Using simple buffer"),new Xl}function ed(){try{return"sessionStorage"in Ja&&!!Ja.sessionStorage}catch(e){return!1}}function td(e){!function(){if(!ed())return;try{Ja.sessionStorage.removeItem(Ka)}catch(e){}}(),e.session=void 0}function nd(e){return void 0!==e&&Math.random()<e}function rd(e){const t=Date.now();return{id:e.id||De(),started:e.started||t,lastActivity:e.lastActivity||t,segmentId:e.segmentId||0,sampled:e.sampled,previousSessionId:e.previousSessionId}}function od(e){if(ed())try{Ja.sessionStorage.setItem(Ka,JSON.stringify(e))}catch(e){}}function sd({sessionSampleRate:e,allowBuffering:t,stickySession:n=!1},{previousSessionId:r}={}){const o=function(e,t){return nd(e)?"session":!!t&&"buffer"}(e,t),s=rd({sampled:o,previousSessionId:r});return n&&od(s),s}function id(e,t,n=+new Date){return null===e||void 0===t||t<0||0!==t&&e+t<=n}function ad(e,{maxReplayDuration:t,sessionIdleExpire:n,targetTime:r=Date.now()}){return id(e.started,t,r)||id(e.lastActivity,n,r)}function cd(e,{sessionIdleExpire:t,maxReplayDuration:n}){return!!ad(e,{sessionIdleExpire:t,maxReplayDuration:n})&&("buffer"!==e.sampled||0!==e.segmentId)}function ud({sessionIdleExpire:e,maxReplayDuration:t,previousSessionId:n},r){const o=r.stickySession&&function(){if(!ed())return null;try{const e=Ja.sessionStorage.getItem(Ka);if(!e)return null;const t=JSON.parse(e);return ll&&fl.infoTick("Loading existing session"),rd(t)}catch(e){return null}}();return o?cd(o,{sessionIdleExpire:e,maxReplayDuration:t})?(ll&&fl.infoTick("Session in sessionStorage is expired, creating new one..."),sd(r,{previousSessionId:o.id})):o:(ll&&fl.infoTick("Creating new session"),sd(r,{previousSessionId:n}))}function ld(e,t,n){return!!pd(e,t)&&(dd(e,t,n),!0)}async function dd(e,t,n){if(!e.eventBuffer)return null;try{n&&"buffer"===e.recordingMode&&e.eventBuffer.clear(),n&&(e.eventBuffer.hasCheckout=!0);const r=function(e,t){try{if("function"==typeof t&&function(e){return e.type===bu.Custom}(e))return t(e)}catch(e){return ll&&fl.exception(e,"An error occured in the `beforeAddRecordingEvent` callback, skipping the event..."),null}return e}(t,e.getOptions().beforeAddRecordingEvent);if(!r)return;return await e.eventBuffer.addEvent(r)}catch(t){const n=t&&t instanceof Gl?"addEventSizeExceeded":"addEvent";e.handleException(t),await e.stop({reason:n});const r=Qt();r&&r.recordDroppedEvent("internal_sdk_error","replay")}}function pd(e,t){if(!e.eventBuffer||e.isPaused()||!e.isEnabled())return!1;const n=gl(t.timestamp);return!(n+e.timeouts.sessionIdlePause<Date.now())&&(!(n>e.getContext().initialTimestamp+e.getOptions().maxReplayDuration)||(ll&&fl.infoTick(`Skipping event with timestamp ${n} because it is after maxReplayDuration`),!1))}function hd(e){return!e.type}function fd(e){return"transaction"===e.type}function md(e){return"feedback"===e.type}function gd(e){return(t,n)=>{if(!e.isEnabled()||!hd(t)&&!fd(t))return;const r=n&&n.statusCode;!r||r<200||r>=300||(fd(t)?function(e,t){const n=e.getContext();t.contexts&&t.contexts.trace&&t.contexts.trace.trace_id&&n.traceIds.size<100&&n.traceIds.add(t.contexts.trace.trace_id)}(e,t):function(e,t){const n=e.getContext();t.event_id&&n.errorIds.size<100&&n.errorIds.add(t.event_id);if("buffer"!==e.recordingMode||!t.tags||!t.tags.replayId)return;const{beforeErrorSampling:r}=e.getOptions();if("function"==typeof r&&!r(t))return;Gi((()=>{e.sendBufferedReplayOrFlush()}))}(e,t))}}function _d(e){return t=>{e.isEnabled()&&hd(t)&&function(e,t){const n=t.exception&&t.exception.values&&t.exception.values[0]&&t.exception.values[0].value;if("string"!=typeof n)return;if(n.match(/(reactjs\.org\/docs\/error-decoder\.html\?invariant=|react\.dev\/errors\/)(418|419|422|423|425)/)||n.match(/(does not match server-rendered HTML|Hydration failed because)/i)){yl(e,Rl({category:"replay.hydrate-error",data:{url:M()}}))}}(e,t)}}function yd(e){const t=Qt();t&&t.on("beforeAddBreadcrumb",(t=>function(e,t){if(!e.isEnabled()||!vd(t))return;const n=function(e){if(!vd(e)||["fetch","xhr","sentry.event","sentry.transaction"].includes(e.category)||e.category.startsWith("ui."))return null;if("console"===e.category)return function(e){const t=e.data&&e.data.arguments;if(!Array.isArray(t)||0===t.length)return Rl(e);let n=!1;const r=t.map((e=>{if(!e)return e;if("string"==typeof e)return e.length>tc?(n=!0,`${e.slice(0,tc)}…`):e;if("object"==typeof e)try{const t=je(e,7);return JSON.stringify(t).length>tc?(n=!0,`${JSON.stringify(t,null,2).slice(0,tc)}…`):t}catch(e){}return e}));return Rl({...e,data:{...e.data,arguments:r,...n?{_meta:{warnings:["CONSOLE_ARG_TRUNCATED"]}}:{}}})}(e);return Rl(e)}(t);n&&yl(e,n)}(e,t)))}function vd(e){return!!e.category}function bd(e){return Object.assign(((t,n)=>{if(!e.isEnabled())return t;if(function(e){return"replay_event"===e.type}(t))return delete t.breadcrumbs,t;if(!hd(t)&&!fd(t)&&!md(t))return t;if(!e.checkAndHandleExpiredSession())return t;if(md(t))return e.flush(),t.contexts.feedback.replay_id=e.getSessionId(),function(e,t){e.triggerUserActivity(),e.addUpdate((()=>!t.timestamp||(e.throttledAddEvent({type:bu.Custom,timestamp:1e3*t.timestamp,data:{tag:"breadcrumb",payload:{timestamp:t.timestamp,type:"default",category:"sentry.feedback",data:{feedbackId:t.event_id}}}}),!1)))}(e,t),t;if(function(e,t){return!(e.type||!e.exception||!e.exception.values||!e.exception.values.length||!t.originalException||!t.originalException.__rrweb__)}(t,n)&&!e.getOptions()._experiments.captureExceptions)return ll&&fl.log("Ignoring error from rrweb internals",t),null;const r=function(e,t){return"buffer"===e.recordingMode&&t.message!==Za&&!(!t.exception||t.type)&&nd(e.getOptions().errorSampleRate)}(e,t);return(r||"session"===e.recordingMode)&&(t.tags={...t.tags,replayId:e.getSessionId()}),t}),{id:"Replay"})}function Sd(e,t){return t.map((({type:t,start:n,end:r,name:o,data:s})=>{const i=e.throttledAddEvent({type:bu.Custom,timestamp:n,data:{tag:"performanceSpan",payload:{op:t,description:o,startTimestamp:n,endTimestamp:r,data:s}}});return"string"==typeof i?Promise.resolve(null):i}))}function wd(e){return t=>{if(!e.isEnabled())return;const n=function(e){const{from:t,to:n}=e,r=Date.now()/1e3;return{type:"navigation.push",start:r,end:r,name:n,data:{previous:t}}}(t);null!==n&&(e.getContext().urls.push(n.name),e.triggerUserActivity(),e.addUpdate((()=>(Sd(e,[n]),!1))))}}function kd(e,t){e.isEnabled()&&null!==t&&(function(e,t){return(!ll||!e.getOptions()._experiments.traceInternals)&&ro(t,Qt())}(e,t.name)||e.addUpdate((()=>(Sd(e,[t]),!0))))}function xd(e){if(!e)return;const t=new TextEncoder;try{if("string"==typeof e)return t.encode(e).length;if(e instanceof URLSearchParams)return t.encode(e.toString()).length;if(e instanceof FormData){const n=Nd(e);return t.encode(n).length}if(e instanceof Blob)return e.size;if(e instanceof ArrayBuffer)return e.byteLength}catch(e){}}function Ed(e){if(!e)return;const t=parseInt(e,10);return isNaN(t)?void 0:t}function Cd(e){try{if("string"==typeof e)return[e];if(e instanceof URLSearchParams)return[e.toString()];if(e instanceof FormData)return[Nd(e)];if(!e)return[void 0]}catch(t){return ll&&fl.exception(t,"Failed to serialize body",e),[void 0,"BODY_PARSE_ERROR"]}return ll&&fl.info("Skipping network body because of body type",e),[void 0,"UNPARSEABLE_BODY_TYPE"]}function Td(e,t){if(!e)return{headers:{},size:void 0,_meta:{warnings:[t]}};const n={...e._meta},r=n.warnings||[];return n.warnings=[...r,t],e._meta=n,e}function Id(e,t){if(!t)return null;const{startTimestamp:n,endTimestamp:r,url:o,method:s,statusCode:i,request:a,response:c}=t;return{type:e,start:n/1e3,end:r/1e3,name:o,data:X({method:s,statusCode:i,request:a,response:c})}}function Md(e){return{headers:{},size:e,_meta:{warnings:["URL_SKIPPED"]}}}function Rd(e,t,n){if(!t&&0===Object.keys(e).length)return;if(!t)return{headers:e};if(!n)return{headers:e,size:t};const r={headers:e,size:t},{body:o,warnings:s}=function(e){if(!e||"string"!=typeof e)return{body:e};const t=e.length>ec,n=function(e){const t=e[0],n=e[e.length-1];return"["===t&&"]"===n||"{"===t&&"}"===n}(e);if(t){const t=e.slice(0,ec);return n?{body:t,warnings:["MAYBE_JSON_TRUNCATED"]}:{body:`${t}…`,warnings:["TEXT_TRUNCATED"]}}if(n)try{return{body:JSON.parse(e)}}catch(e){}return{body:e}}(n);return r.body=o,s&&s.length>0&&(r._meta={warnings:s}),r}function Ad(e,t){return Object.entries(e).reduce(((n,[r,o])=>{const s=r.toLowerCase();return t.includes(s)&&e[r]&&(n[s]=o),n}),{})}function Nd(e){return new URLSearchParams(e).toString()}function Od(e,t){const n=function(e,t=Ja.document.baseURI){if(e.startsWith("http://")||e.startsWith("https://")||e.startsWith(Ja.location.origin))return e;const n=new URL(e,t);if(n.origin!==new URL(t).origin)return e;const r=n.href;if(!e.endsWith("/")&&r.endsWith("/"))return r.slice(0,-1);return r}(e);return _(n,t)}async function Dd(e,t,n){try{const r=await async function(e,t,n){const r=Date.now(),{startTimestamp:o=r,endTimestamp:s=r}=t,{url:i,method:a,status_code:c=0,request_body_size:u,response_body_size:l}=e.data,d=Od(i,n.networkDetailAllowUrls)&&!Od(i,n.networkDetailDenyUrls),p=d?function({networkCaptureBodies:e,networkRequestHeaders:t},n,r){const o=n?function(e,t){if(1===e.length&&"string"!=typeof e[0])return Fd(e[0],t);if(2===e.length)return Fd(e[1],t);return{}}(n,t):{};if(!e)return Rd(o,r,void 0);const s=Ld(n),[i,a]=Cd(s),c=Rd(o,r,i);if(a)return Td(c,a);return c}(n,t.input,u):Md(u),h=await async function(e,{networkCaptureBodies:t,networkResponseHeaders:n},r,o){if(!e&&void 0!==o)return Md(o);const s=r?Pd(r.headers,n):{};if(!r||!t&&void 0!==o)return Rd(s,o,void 0);const[i,a]=await async function(e){const t=function(e){try{return e.clone()}catch(e){ll&&fl.exception(e,"Failed to clone response body")}}(e);if(!t)return[void 0,"BODY_PARSE_ERROR"];try{const e=await function(e){return new Promise(((t,n)=>{const r=Gi((()=>n(new Error("Timeout while trying to read response body"))),500);(async function(e){return await e.text()})(e).then((e=>t(e)),(e=>n(e))).finally((()=>clearTimeout(r)))}))}(t);return[e]}catch(e){return ll&&fl.exception(e,"Failed to get text body from response"),[void 0,"BODY_PARSE_ERROR"]}}(r),c=function(e,{networkCaptureBodies:t,responseBodySize:n,captureDetails:r,headers:o}){try{const s=e&&e.length&&void 0===n?xd(e):n;return r?Rd(o,s,t?e:void 0):Md(s)}catch(e){return ll&&fl.exception(e,"Failed to serialize response body"),Rd(o,n,void 0)}}(i,{networkCaptureBodies:t,responseBodySize:o,captureDetails:e,headers:s});if(a)return Td(c,a);return c}(d,n,t.response,l);return{startTimestamp:o,endTimestamp:s,url:i,method:a,statusCode:c,request:p,response:h}}(e,t,n),o=Id("resource.fetch",r);kd(n.replay,o)}catch(e){ll&&fl.exception(e,"Failed to capture fetch breadcrumb")}}function Ld(e=[]){if(2===e.length&&"object"==typeof e[1])return e[1].body}function Pd(e,t){const n={};return t.forEach((t=>{e.get(t)&&(n[t]=e.get(t))})),n}function Fd(e,t){if(!e)return{};const n=e.headers;return n?n instanceof Headers?Pd(n,t):Array.isArray(n)?{}:Ad(n,t):{}}async function $d(e,t,n){try{const r=function(e,t,n){const r=Date.now(),{startTimestamp:o=r,endTimestamp:s=r,input:i,xhr:a}=t,{url:c,method:u,status_code:l=0,request_body_size:d,response_body_size:p}=e.data;if(!c)return null;if(!a||!Od(c,n.networkDetailAllowUrls)||Od(c,n.networkDetailDenyUrls)){return{startTimestamp:o,endTimestamp:s,url:c,method:u,statusCode:l,request:Md(d),response:Md(p)}}const h=a[Xi],f=h?Ad(h.request_headers,n.networkRequestHeaders):{},m=Ad(function(e){const t=e.getAllResponseHeaders();if(!t)return{};return t.split("\r\n").reduce(((e,t)=>{const[n,r]=t.split(": ");return r&&(e[n.toLowerCase()]=r),e}),{})}(a),n.networkResponseHeaders),[g,_]=n.networkCaptureBodies?Cd(i):[void 0],[y,v]=n.networkCaptureBodies?function(e){const t=[];try{return[e.responseText]}catch(e){t.push(e)}try{return function(e,t){try{if("string"==typeof e)return[e];if(e instanceof Document)return[e.body.outerHTML];if("json"===t&&e&&"object"==typeof e)return[JSON.stringify(e)];if(!e)return[void 0]}catch(t){return ll&&fl.exception(t,"Failed to serialize body",e),[void 0,"BODY_PARSE_ERROR"]}return ll&&fl.info("Skipping network body because of body type",e),[void 0,"UNPARSEABLE_BODY_TYPE"]}(e.response,e.responseType)}catch(e){t.push(e)}return ll&&fl.warn("Failed to get xhr response body",...t),[void 0]}(a):[void 0],b=Rd(f,d,g),S=Rd(m,p,y);return{startTimestamp:o,endTimestamp:s,url:c,method:u,statusCode:l,request:_?Td(b,_):b,response:v?Td(S,v):S}}(e,t,n),o=Id("resource.xhr",r);kd(n.replay,o)}catch(e){ll&&fl.exception(e,"Failed to capture xhr breadcrumb")}}function Bd(e,t){const{xhr:n,input:r}=t;if(!n)return;const o=xd(r),s=n.getResponseHeader("content-length")?Ed(n.getResponseHeader("content-length")):function(e,t){try{return xd("json"===t&&e&&"object"==typeof e?JSON.stringify(e):e)}catch(e){return}}(n.response,n.responseType);void 0!==o&&(e.data.request_body_size=o),void 0!==s&&(e.data.response_body_size=s)}function Ud(e){const t=Qt();try{const{networkDetailAllowUrls:n,networkDetailDenyUrls:r,networkCaptureBodies:o,networkRequestHeaders:s,networkResponseHeaders:i}=e.getOptions(),a={replay:e,networkDetailAllowUrls:n,networkDetailDenyUrls:r,networkCaptureBodies:o,networkRequestHeaders:s,networkResponseHeaders:i};t&&t.on("beforeAddBreadcrumb",((e,t)=>function(e,t,n){if(!t.data)return;try{(function(e){return"xhr"===e.category})(t)&&function(e){return e&&e.xhr}(n)&&(Bd(t,n),$d(t,n,e)),function(e){return"fetch"===e.category}(t)&&function(e){return e&&e.response}(n)&&(!function(e,t){const{input:n,response:r}=t,o=xd(n?Ld(n):void 0),s=r?Ed(r.headers.get("content-length")):void 0;void 0!==o&&(e.data.request_body_size=o),void 0!==s&&(e.data.response_body_size=s)}(t,n),Dd(t,n,e))}catch(e){ll&&fl.exception(e,"Error when enriching network breadcrumb")}}(a,e,t)))}catch(e){}}function jd(e){const{jsHeapSizeLimit:t,totalJSHeapSize:n,usedJSHeapSize:r}=e,o=Date.now()/1e3;return{type:"memory",name:"memory",start:o,end:o,data:{memory:{jsHeapSizeLimit:t,totalJSHeapSize:n,usedJSHeapSize:r}}}}function Hd(e){let t=!1;return(n,r)=>{if(!e.checkAndHandleExpiredSession())return void(ll&&fl.warn("Received replay event after session expired."));const o=r||!t;t=!0,e.clickDetector&&Ml(e.clickDetector,n),e.addUpdate((()=>{if("buffer"===e.recordingMode&&o&&e.setInitialState(),!ld(e,n,o))return!0;if(!o)return!1;if(function(e,t){if(!t||!e.session||0!==e.session.segmentId)return;ld(e,function(e){const t=e.getOptions();return{type:bu.Custom,timestamp:Date.now(),data:{tag:"options",payload:{shouldRecordCanvas:e.isRecordingCanvas(),sessionSampleRate:t.sessionSampleRate,errorSampleRate:t.errorSampleRate,useCompressionOption:t.useCompression,blockAllMedia:t.blockAllMedia,maskAllText:t.maskAllText,maskAllInputs:t.maskAllInputs,useCompression:!!e.eventBuffer&&"worker"===e.eventBuffer.type,networkDetailHasUrls:t.networkDetailAllowUrls.length>0,networkCaptureBodies:t.networkCaptureBodies,networkRequestHasHeaders:t.networkRequestHeaders.length>0,networkResponseHasHeaders:t.networkResponseHeaders.length>0}}}}(e),!1)}(e,o),e.session&&e.session.previousSessionId)return!0;if("buffer"===e.recordingMode&&e.session&&e.eventBuffer){const t=e.eventBuffer.getEarliestTimestamp();t&&(ll&&fl.info(`Updating session start time to earliest event in buffer to ${new Date(t)}`),e.session.started=t,e.getOptions().stickySession&&od(e.session))}return"session"===e.recordingMode&&e.flush(),!0}))}}async function zd({recordingData:e,replayId:t,segmentId:n,eventContext:r,timestamp:o,session:s}){const i=function({recordingData:e,headers:t}){let n;const r=`${JSON.stringify(t)}\n`;if("string"==typeof e)n=`${r}${e}`;else{const t=(new TextEncoder).encode(r);n=new Uint8Array(t.length+e.length),n.set(t),n.set(e,t.length)}return n}({recordingData:e,headers:{segment_id:n}}),{urls:a,errorIds:c,traceIds:u,initialTimestamp:l}=r,d=Qt(),p=Gt(),h=d&&d.getTransport(),f=d&&d.getDsn();if(!(d&&h&&f&&s.sampled))return Xe({});const m={type:Qa,replay_start_timestamp:l/1e3,timestamp:o/1e3,error_ids:c,trace_ids:u,urls:a,replay_id:t,segment_id:n,replay_type:s.sampled},g=await async function({client:e,scope:t,replayId:n,event:r}){const o={event_id:n,integrations:"object"!=typeof e._integrations||null===e._integrations||Array.isArray(e._integrations)?void 0:Object.keys(e._integrations)};e.emit("preprocessEvent",r,o);const s=await _r(e.getOptions(),r,o,t,e,Xt());if(!s)return null;s.platform=s.platform||"javascript";const i=e.getSdkMetadata(),{name:a,version:c}=i&&i.sdk||{};return s.sdk={...s.sdk,name:a||"sentry.javascript.unknown",version:c||"0.0.0"},s}({scope:p,client:d,replayId:t,event:m});if(!g)return d.recordDroppedEvent("event_processor","replay",m),ll&&fl.info("An event processor returned `null`, will not send event."),Xe({});delete g.sdkProcessingMetadata;const _=function(e,t,n,r){return pt(xt(e,kt(e),r,n),[[{type:"replay_event"},e],[{type:"replay_recording",length:"string"==typeof t?(new TextEncoder).encode(t).length:t.length},t]])}(g,i,f,d.getOptions().tunnel);let y;try{y=await h.send(_)}catch(e){const t=new Error(Za);try{t.cause=e}catch(e){}throw t}if("number"==typeof y.statusCode&&(y.statusCode<200||y.statusCode>=300))throw new qd(y.statusCode);const v=It({},y);if(Tt(v,"replay"))throw new Wd(v);return y}class qd extends Error{constructor(e){super(`Transport returned status code ${e}`)}}class Wd extends Error{constructor(e){super("Rate limit hit"),this.rateLimits=e}}async function Yd(e,t={count:0,interval:5e3}){const{recordingData:n,options:r}=e;if(n.length)try{return await zd(e),!0}catch(n){if(n instanceof qd||n instanceof Wd)throw n;if(xr("Replays",{_retryCount:t.count}),ll&&r._experiments&&r._experiments.captureExceptions&&Sr(n),t.count>=3){const e=new Error(`${Za} - max retries exceeded`);try{e.cause=n}catch(e){}throw e}return t.interval*=++t.count,new Promise(((n,r)=>{Gi((async()=>{try{await Yd(e,t),n(!0)}catch(e){r(e)}}),t.interval)}))}}const Vd="__THROTTLED";function Gd(e,t,n){const r=new Map;let o=!1;return(...s)=>{const i=Math.floor(Date.now()/1e3);if((e=>{const t=e-n;r.forEach(((e,n)=>{n<t&&r.delete(n)}))})(i),[...r.values()].reduce(((e,t)=>e+t),0)>=t){const e=o;return o=!0,e?"__SKIPPED":Vd}o=!1;const a=r.get(i)||0;return r.set(i,a+1),e(...s)}}class Xd{constructor({options:e,recordingOptions:t}){Xd.prototype.__init.call(this),Xd.prototype.__init2.call(this),Xd.prototype.__init3.call(this),Xd.prototype.__init4.call(this),Xd.prototype.__init5.call(this),Xd.prototype.__init6.call(this),this.eventBuffer=null,this.performanceEntries=[],this.replayPerformanceEntries=[],this.recordingMode="session",this.timeouts={sessionIdlePause:3e5,sessionIdleExpire:9e5},this._lastActivity=Date.now(),this._isEnabled=!1,this._isPaused=!1,this._requiresManualStart=!1,this._hasInitializedCoreListeners=!1,this._context={errorIds:new Set,traceIds:new Set,urls:[],initialTimestamp:Date.now(),initialUrl:""},this._recordingOptions=t,this._options=e,this._debouncedFlush=function(e,t,n){let r,o,s;const i=n&&n.maxWait?Math.max(n.maxWait,t):0;function a(){return c(),r=e(),r}function c(){void 0!==o&&clearTimeout(o),void 0!==s&&clearTimeout(s),o=s=void 0}function u(){return o&&clearTimeout(o),o=Gi(a,t),i&&void 0===s&&(s=Gi(a,i)),r}return u.cancel=c,u.flush=function(){return void 0!==o||void 0!==s?a():r},u}((()=>this._flush()),this._options.flushMinDelay,{maxWait:this._options.flushMaxDelay}),this._throttledAddEvent=Gd(((e,t)=>function(e,t,n){return pd(e,t)?dd(e,t,n):Promise.resolve(null)}(this,e,t)),300,5);const{slowClickTimeout:n,slowClickIgnoreSelectors:r}=this.getOptions(),o=n?{threshold:Math.min(3e3,n),timeout:n,scrollTimeout:300,ignoreSelector:r?r.join(","):""}:void 0;if(o&&(this.clickDetector=new Cl(this,o)),ll){const t=e._experiments;fl.setConfig({captureExceptions:!!t.captureExceptions,traceInternals:!!t.traceInternals})}}getContext(){return this._context}isEnabled(){return this._isEnabled}isPaused(){return this._isPaused}isRecordingCanvas(){return Boolean(this._canvas)}getOptions(){return this._options}handleException(e){ll&&fl.exception(e)}initializeSampling(e){const{errorSampleRate:t,sessionSampleRate:n}=this._options,r=t<=0&&n<=0;this._requiresManualStart=r,r||(this._initializeSessionForSampling(e),this.session?!1!==this.session.sampled&&(this.recordingMode="buffer"===this.session.sampled&&0===this.session.segmentId?"buffer":"session",ll&&fl.infoTick(`Starting replay in ${this.recordingMode} mode`),this._initializeRecording()):ll&&fl.exception(new Error("Unable to initialize and create session")))}start(){if(this._isEnabled&&"session"===this.recordingMode)return void(ll&&fl.info("Recording is already in progress"));if(this._isEnabled&&"buffer"===this.recordingMode)return void(ll&&fl.info("Buffering is in progress, call `flush()` to save the replay"));ll&&fl.infoTick("Starting replay in session mode"),this._updateUserActivity();const e=ud({maxReplayDuration:this._options.maxReplayDuration,sessionIdleExpire:this.timeouts.sessionIdleExpire},{stickySession:this._options.stickySession,sessionSampleRate:1,allowBuffering:!1});this.session=e,this._initializeRecording()}startBuffering(){if(this._isEnabled)return void(ll&&fl.info("Buffering is in progress, call `flush()` to save the replay"));ll&&fl.infoTick("Starting replay in buffer mode");const e=ud({sessionIdleExpire:this.timeouts.sessionIdleExpire,maxReplayDuration:this._options.maxReplayDuration},{stickySession:this._options.stickySession,sessionSampleRate:0,allowBuffering:!0});this.session=e,this.recordingMode="buffer",this._initializeRecording()}startRecording(){try{const e=this._canvas;this._stopRecording=ul({...this._recordingOptions,..."buffer"===this.recordingMode&&{checkoutEveryNms:6e4},emit:Hd(this),onMutation:this._onMutationHandler,...e?{recordCanvas:e.recordCanvas,getCanvasManager:e.getCanvasManager,sampling:e.sampling,dataURLOptions:e.dataURLOptions}:{}})}catch(e){this.handleException(e)}}stopRecording(){try{return this._stopRecording&&(this._stopRecording(),this._stopRecording=void 0),!0}catch(e){return this.handleException(e),!1}}async stop({forceFlush:e=!1,reason:t}={}){if(this._isEnabled){this._isEnabled=!1;try{ll&&fl.info("Stopping Replay"+(t?` triggered by ${t}`:"")),this._removeListeners(),this.stopRecording(),this._debouncedFlush.cancel(),e&&await this._flush({force:!0}),this.eventBuffer&&this.eventBuffer.destroy(),this.eventBuffer=null,td(this)}catch(e){this.handleException(e)}}}pause(){this._isPaused||(this._isPaused=!0,this.stopRecording(),ll&&fl.info("Pausing replay"))}resume(){this._isPaused&&this._checkSession()&&(this._isPaused=!1,this.startRecording(),ll&&fl.info("Resuming replay"))}async sendBufferedReplayOrFlush({continueRecording:e=!0}={}){if("session"===this.recordingMode)return this.flushImmediate();const t=Date.now();ll&&fl.info("Converting buffer to session"),await this.flushImmediate();const n=this.stopRecording();e&&n&&"session"!==this.recordingMode&&(this.recordingMode="session",this.session&&(this._updateUserActivity(t),this._updateSessionActivity(t),this._maybeSaveSession()),this.startRecording())}addUpdate(e){const t=e();"buffer"!==this.recordingMode&&!0!==t&&this._debouncedFlush()}triggerUserActivity(){if(this._updateUserActivity(),this._stopRecording)this.checkAndHandleExpiredSession(),this._updateSessionActivity();else{if(!this._checkSession())return;this.resume()}}updateUserActivity(){this._updateUserActivity(),this._updateSessionActivity()}conditionalFlush(){return"buffer"===this.recordingMode?Promise.resolve():this.flushImmediate()}flush(){return this._debouncedFlush()}flushImmediate(){return this._debouncedFlush(),this._debouncedFlush.flush()}cancelFlush(){this._debouncedFlush.cancel()}getSessionId(){return this.session&&this.session.id}checkAndHandleExpiredSession(){if(!(this._lastActivity&&id(this._lastActivity,this.timeouts.sessionIdlePause)&&this.session&&"session"===this.session.sampled))return!!this._checkSession();this.pause()}setInitialState(){const e=`${Ja.location.pathname}${Ja.location.hash}${Ja.location.search}`,t=`${Ja.location.origin}${e}`;this.performanceEntries=[],this.replayPerformanceEntries=[],this._clearContext(),this._context.initialUrl=t,this._context.initialTimestamp=Date.now(),this._context.urls.push(t)}throttledAddEvent(e,t){const n=this._throttledAddEvent(e,t);if(n===Vd){const e=Rl({category:"replay.throttled"});this.addUpdate((()=>!ld(this,{type:5,timestamp:e.timestamp||0,data:{tag:"breadcrumb",payload:e,metric:!0}})))}return n}getCurrentRoute(){const e=this.lastActiveSpan||Mn(),t=e&&In(e),n=(t&&Sn(t).data||{})[tn];if(t&&n&&["route","custom"].includes(n))return Sn(t).description}_initializeRecording(){this.setInitialState(),this._updateSessionActivity(),this.eventBuffer=Zl({useCompression:this._options.useCompression,workerUrl:this._options.workerUrl}),this._removeListeners(),this._addListeners(),this._isEnabled=!0,this._isPaused=!1,this.startRecording()}_initializeSessionForSampling(e){const t=this._options.errorSampleRate>0,n=ud({sessionIdleExpire:this.timeouts.sessionIdleExpire,maxReplayDuration:this._options.maxReplayDuration,previousSessionId:e},{stickySession:this._options.stickySession,sessionSampleRate:this._options.sessionSampleRate,allowBuffering:t});this.session=n}_checkSession(){if(!this.session)return!1;const e=this.session;return!cd(e,{sessionIdleExpire:this.timeouts.sessionIdleExpire,maxReplayDuration:this._options.maxReplayDuration})||(this._refreshSession(e),!1)}async _refreshSession(e){this._isEnabled&&(await this.stop({reason:"refresh session"}),this.initializeSampling(e.id))}_addListeners(){try{Ja.document.addEventListener("visibilitychange",this._handleVisibilityChange),Ja.addEventListener("blur",this._handleWindowBlur),Ja.addEventListener("focus",this._handleWindowFocus),Ja.addEventListener("keydown",this._handleKeyboardEvent),this.clickDetector&&this.clickDetector.addListeners(),this._hasInitializedCoreListeners||(!function(e){const t=Qt();Ui(Dl(e)),zi(wd(e)),yd(e),Ud(e),Ar(bd(e)),t&&(t.on("beforeSendEvent",_d(e)),t.on("afterSendEvent",gd(e)),t.on("createDsc",(t=>{const n=e.getSessionId();n&&e.isEnabled()&&"session"===e.recordingMode&&e.checkAndHandleExpiredSession()&&(t.replay_id=n)})),t.on("spanStart",(t=>{e.lastActiveSpan=t})),t.on("spanEnd",(t=>{e.lastActiveSpan=t})),t.on("beforeSendFeedback",((t,n)=>{const r=e.getSessionId();n&&n.includeReplay&&e.isEnabled()&&r&&t.contexts&&t.contexts.feedback&&(t.contexts.feedback.replay_id=r)})))}(this),this._hasInitializedCoreListeners=!0)}catch(e){this.handleException(e)}this._performanceCleanupCallback=function(e){function t(t){e.performanceEntries.includes(t)||e.performanceEntries.push(t)}function n({entries:e}){e.forEach(t)}const r=[];return["navigation","paint","resource"].forEach((e=>{r.push(li(e,n))})),r.push(ai($l(jl,e)),ii($l(zl,e)),ci($l(ql,e)),ui($l(Wl,e))),()=>{r.forEach((e=>e()))}}(this)}_removeListeners(){try{Ja.document.removeEventListener("visibilitychange",this._handleVisibilityChange),Ja.removeEventListener("blur",this._handleWindowBlur),Ja.removeEventListener("focus",this._handleWindowFocus),Ja.removeEventListener("keydown",this._handleKeyboardEvent),this.clickDetector&&this.clickDetector.removeListeners(),this._performanceCleanupCallback&&this._performanceCleanupCallback()}catch(e){this.handleException(e)}}__init(){this._handleVisibilityChange=()=>{"visible"===Ja.document.visibilityState?this._doChangeToForegroundTasks():this._doChangeToBackgroundTasks()}}__init2(){this._handleWindowBlur=()=>{const e=Rl({category:"ui.blur"});this._doChangeToBackgroundTasks(e)}}__init3(){this._handleWindowFocus=()=>{const e=Rl({category:"ui.focus"});this._doChangeToForegroundTasks(e)}}__init4(){this._handleKeyboardEvent=e=>{Pl(this,e)}}_doChangeToBackgroundTasks(e){if(!this.session)return;ad(this.session,{maxReplayDuration:this._options.maxReplayDuration,sessionIdleExpire:this.timeouts.sessionIdleExpire})||(e&&this._createCustomBreadcrumb(e),this.conditionalFlush())}_doChangeToForegroundTasks(e){if(!this.session)return;this.checkAndHandleExpiredSession()?e&&this._createCustomBreadcrumb(e):ll&&fl.info("Document has become active, but session has expired")}_updateUserActivity(e=Date.now()){this._lastActivity=e}_updateSessionActivity(e=Date.now()){this.session&&(this.session.lastActivity=e,this._maybeSaveSession())}_createCustomBreadcrumb(e){this.addUpdate((()=>{this.throttledAddEvent({type:bu.Custom,timestamp:e.timestamp||0,data:{tag:"breadcrumb",payload:e}})}))}_addPerformanceEntries(){const e=(t=this.performanceEntries,t.map(Bl).filter(Boolean)).concat(this.replayPerformanceEntries);var t;return this.performanceEntries=[],this.replayPerformanceEntries=[],Promise.all(Sd(this,e))}_clearContext(){this._context.errorIds.clear(),this._context.traceIds.clear(),this._context.urls=[]}_updateInitialTimestampFromEventBuffer(){const{session:e,eventBuffer:t}=this;if(!e||!t||this._requiresManualStart)return;if(e.segmentId)return;const n=t.getEarliestTimestamp();n&&n<this._context.initialTimestamp&&(this._context.initialTimestamp=n)}_popEventContext(){const e={initialTimestamp:this._context.initialTimestamp,initialUrl:this._context.initialUrl,errorIds:Array.from(this._context.errorIds),traceIds:Array.from(this._context.traceIds),urls:this._context.urls};return this._clearContext(),e}async _runFlush(){const e=this.getSessionId();if(this.session&&this.eventBuffer&&e){if(await this._addPerformanceEntries(),this.eventBuffer&&this.eventBuffer.hasEvents&&(await async function(e){try{return Promise.all(Sd(e,[jd(Ja.performance.memory)]))}catch(e){return[]}}(this),this.eventBuffer&&e===this.getSessionId()))try{this._updateInitialTimestampFromEventBuffer();const t=Date.now();if(t-this._context.initialTimestamp>this._options.maxReplayDuration+3e4)throw new Error("Session is too long, not sending replay");const n=this._popEventContext(),r=this.session.segmentId++;this._maybeSaveSession();const o=await this.eventBuffer.finish();await Yd({replayId:e,recordingData:o,segmentId:r,eventContext:n,session:this.session,options:this.getOptions(),timestamp:t})}catch(e){this.handleException(e),this.stop({reason:"sendReplay"});const t=Qt();t&&t.recordDroppedEvent("send_error","replay")}}else ll&&fl.error("No session or eventBuffer found to flush.")}__init5(){this._flush=async({force:e=!1}={})=>{if(!this._isEnabled&&!e)return;if(!this.checkAndHandleExpiredSession())return void(ll&&fl.error("Attempting to finish replay event after session expired."));if(!this.session)return;const t=this.session.started,n=Date.now()-t;this._debouncedFlush.cancel();const r=n<this._options.minReplayDuration,o=n>this._options.maxReplayDuration+5e3;if(r||o)return ll&&fl.info(`Session duration (${Math.floor(n/1e3)}s) is too ${r?"short":"long"}, not sending replay.`),void(r&&this._debouncedFlush());const s=this.eventBuffer;if(s&&0===this.session.segmentId&&!s.hasCheckout&&ll&&fl.info("Flushing initial segment without checkout."),!this._flushLock)return this._flushLock=this._runFlush(),await this._flushLock,void(this._flushLock=void 0);try{await this._flushLock}catch(e){ll&&fl.error(e)}finally{this._debouncedFlush()}}}_maybeSaveSession(){this.session&&this._options.stickySession&&od(this.session)}__init6(){this._onMutationHandler=e=>{const t=e.length,n=this._options.mutationLimit,r=n&&t>n;if(t>this._options.mutationBreadcrumbLimit||r){const e=Rl({category:"replay.mutations",data:{count:t,limit:r}});this._createCustomBreadcrumb(e)}return!r||(this.stop({reason:"mutationLimit",forceFlush:"session"===this.recordingMode}),!1)}}}function Jd(e,t){return[...e,...t].join(",")}const Kd='img,image,svg,video,object,picture,embed,map,audio,link[rel="icon"],link[rel="apple-touch-icon"]',Qd=["content-length","content-type","accept"];let Zd=!1;class ep{static __initStatic(){this.id="Replay"}constructor({flushMinDelay:e=5e3,flushMaxDelay:t=5500,minReplayDuration:n=4999,maxReplayDuration:r=36e5,stickySession:o=!0,useCompression:s=!0,workerUrl:i,_experiments:a={},maskAllText:c=!0,maskAllInputs:u=!0,blockAllMedia:l=!0,mutationBreadcrumbLimit:d=750,mutationLimit:p=1e4,slowClickTimeout:h=7e3,slowClickIgnoreSelectors:f=[],networkDetailAllowUrls:m=[],networkDetailDenyUrls:g=[],networkCaptureBodies:_=!0,networkRequestHeaders:y=[],networkResponseHeaders:v=[],mask:b=[],maskAttributes:S=["title","placeholder"],unmask:w=[],block:k=[],unblock:x=[],ignore:E=[],maskFn:C,beforeAddRecordingEvent:T,beforeErrorSampling:I}={}){this.name=ep.id;const M=function({mask:e,unmask:t,block:n,unblock:r,ignore:o}){return{maskTextSelector:Jd(e,[".sentry-mask","[data-sentry-mask]"]),unmaskTextSelector:Jd(t,[]),blockSelector:Jd(n,[".sentry-block","[data-sentry-block]",'base[href="/"]']),unblockSelector:Jd(r,[]),ignoreSelector:Jd(o,[".sentry-ignore","[data-sentry-ignore]",'input[type="file"]'])}}({mask:b,unmask:w,block:k,unblock:x,ignore:E});if(this._recordingOptions={maskAllInputs:u,maskAllText:c,maskInputOptions:{password:!0},maskTextFn:C,maskInputFn:C,maskAttributeFn:(e,t,n)=>function({el:e,key:t,maskAttributes:n,maskAllText:r,privacyOptions:o,value:s}){return r?o.unmaskTextSelector&&e.matches(o.unmaskTextSelector)?s:n.includes(t)||"value"===t&&"INPUT"===e.tagName&&["submit","button"].includes(e.getAttribute("type")||"")?s.replace(/[\S]/g,"*"):s:s}({maskAttributes:S,maskAllText:c,privacyOptions:M,key:e,value:t,el:n}),...M,slimDOMOptions:"all",inlineStylesheet:!0,inlineImages:!1,collectFonts:!0,errorHandler:e=>{try{e.__rrweb__=!0}catch(e){}}},this._initialOptions={flushMinDelay:e,flushMaxDelay:t,minReplayDuration:Math.min(n,15e3),maxReplayDuration:Math.min(r,rc),stickySession:o,useCompression:s,workerUrl:i,blockAllMedia:l,maskAllInputs:u,maskAllText:c,mutationBreadcrumbLimit:d,mutationLimit:p,slowClickTimeout:h,slowClickIgnoreSelectors:f,networkDetailAllowUrls:m,networkDetailDenyUrls:g,networkCaptureBodies:_,networkRequestHeaders:tp(y),networkResponseHeaders:tp(v),beforeAddRecordingEvent:T,beforeErrorSampling:I,_experiments:a},this._initialOptions.blockAllMedia&&(this._recordingOptions.blockSelector=this._recordingOptions.blockSelector?`${this._recordingOptions.blockSelector},${Kd}`:Kd),this._isInitialized&&Oe())throw new Error("Multiple Sentry Session Replay instances are not supported");this._isInitialized=!0}get _isInitialized(){return Zd}set _isInitialized(e){Zd=e}afterAllSetup(e){Oe()&&!this._replay&&(this._setup(e),this._initialize(e))}start(){this._replay&&this._replay.start()}startBuffering(){this._replay&&this._replay.startBuffering()}stop(){return this._replay?this._replay.stop({forceFlush:"session"===this._replay.recordingMode}):Promise.resolve()}flush(e){return this._replay?this._replay.isEnabled()?this._replay.sendBufferedReplayOrFlush(e):(this._replay.start(),Promise.resolve()):Promise.resolve()}getReplayId(){if(this._replay&&this._replay.isEnabled())return this._replay.getSessionId()}_initialize(e){this._replay&&(this._maybeLoadFromReplayCanvasIntegration(e),this._replay.initializeSampling())}_setup(e){const t=function(e,t){const n=t.getOptions(),r={sessionSampleRate:0,errorSampleRate:0,...X(e)},o=Wn(n.replaysSessionSampleRate),s=Wn(n.replaysOnErrorSampleRate);null==o&&null==s&&D((()=>{console.warn("Replay is disabled because neither `replaysSessionSampleRate` nor `replaysOnErrorSampleRate` are set.")}));null!=o&&(r.sessionSampleRate=o);null!=s&&(r.errorSampleRate=s);return r}(this._initialOptions,e);this._replay=new Xd({options:t,recordingOptions:this._recordingOptions})}_maybeLoadFromReplayCanvasIntegration(e){try{const t=e.getIntegrationByName("ReplayCanvas");if(!t)return;this._replay._canvas=t.getOptions()}catch(e){}}}function tp(e){return[...Qd,...e.map((e=>e.toLowerCase()))]}var np;function rp(e,t,n=1/0,r=0){return e?e.nodeType!==e.ELEMENT_NODE||r>n?-1:t(e)?r:rp(e.parentNode,t,n,r+1):-1}function op(e,t){return n=>{const r=n;if(null===r)return!1;try{if(e)if("string"==typeof e){if(r.matches(`.${e}`))return!0}else if(function(e,t){for(let n=e.classList.length;n--;){const r=e.classList[n];if(t.test(r))return!0}return!1}(r,e))return!0;return!(!t||!r.matches(t))}catch(e){return!1}}}ep.__initStatic(),function(e){e[e.Document=0]="Document",e[e.DocumentType=1]="DocumentType",e[e.Element=2]="Element",e[e.Text=3]="Text",e[e.CDATA=4]="CDATA",e[e.Comment=5]="Comment"}(np||(np={}));const sp="Please stop import mirror directly. Instead of that,\r\nnow you can use replayer.getMirror() to access the mirror instance of a replayer,\r\nor you can use record.mirror to access the mirror instance during recording.";let ip={map:{},getId:()=>(console.error(sp),-1),getNode:()=>(console.error(sp),null),removeNodeFromMap(){console.error(sp)},has:()=>(console.error(sp),!1),reset(){console.error(sp)}};function ap(e,t,n,r,o=window){const s=o.Object.getOwnPropertyDescriptor(e,t);return o.Object.defineProperty(e,t,r?n:{set(e){hp((()=>{n.set.call(this,e)}),0),s&&s.set&&s.set.call(this,e)}}),()=>ap(e,t,s||{},!0)}function cp(e,t,n){try{if(!(t in e))return()=>{};const r=e[t],o=n(r);return"function"==typeof o&&(o.prototype=o.prototype||{},Object.defineProperties(o,{__rrweb_original__:{enumerable:!1,value:r}})),e[t]=o,()=>{e[t]=r}}catch(e){return()=>{}}}function up(e,t,n,r,o){if(!e)return!1;const s=function(e){return e?e.nodeType===e.ELEMENT_NODE?e:e.parentElement:null}(e);if(!s)return!1;const i=op(t,n);if(!o){const e=r&&s.matches(r);return i(s)&&!e}const a=rp(s,i);let c=-1;return!(a<0)&&(r&&(c=rp(s,op(null,r))),a>-1&&c<0||a<c)}"undefined"!=typeof window&&window.Proxy&&window.Reflect&&(ip=new Proxy(ip,{get:(e,t,n)=>("map"===t&&console.error(sp),Reflect.get(e,t,n))})),/[1-9][0-9]{12}/.test(Date.now().toString());const lp={};function dp(e){const t=lp[e];if(t)return t;const n=window.document;let r=window[e];if(n&&"function"==typeof n.createElement)try{const t=n.createElement("iframe");t.hidden=!0,n.head.appendChild(t);const o=t.contentWindow;o&&o[e]&&(r=o[e]),n.head.removeChild(t)}catch(e){}return lp[e]=r.bind(window)}function pp(...e){return dp("requestAnimationFrame")(...e)}function hp(...e){return dp("setTimeout")(...e)}var fp=(e=>(e[e["2D"]=0]="2D",e[e.WebGL=1]="WebGL",e[e.WebGL2=2]="WebGL2",e))(fp||{});let mp;function gp(e){mp=e}const _p=e=>{if(!mp)return e;return(...t)=>{try{return e(...t)}catch(e){if(mp&&!0===mp(e))return()=>{};throw e}}};for(var yp="ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",vp="undefined"==typeof Uint8Array?[]:new Uint8Array(256),bp=0;bp<64;bp++)vp[yp.charCodeAt(bp)]=bp;const Sp=new Map;const wp=(e,t,n)=>{if(!e||!Ep(e,t)&&"object"!=typeof e)return;const r=function(e,t){let n=Sp.get(e);return n||(n=new Map,Sp.set(e,n)),n.has(t)||n.set(t,[]),n.get(t)}(n,e.constructor.name);let o=r.indexOf(e);return-1===o&&(o=r.length,r.push(e)),o};function kp(e,t,n){if(e instanceof Array)return e.map((e=>kp(e,t,n)));if(null===e)return e;if(e instanceof Float32Array||e instanceof Float64Array||e instanceof Int32Array||e instanceof Uint32Array||e instanceof Uint8Array||e instanceof Uint16Array||e instanceof Int16Array||e instanceof Int8Array||e instanceof Uint8ClampedArray){return{rr_type:e.constructor.name,args:[Object.values(e)]}}if(e instanceof ArrayBuffer){const t=e.constructor.name,n=function(e){var t,n=new Uint8Array(e),r=n.length,o="";for(t=0;t<r;t+=3)o+=yp[n[t]>>2],o+=yp[(3&n[t])<<4|n[t+1]>>4],o+=yp[(15&n[t+1])<<2|n[t+2]>>6],o+=yp[63&n[t+2]];return r%3==2?o=o.substring(0,o.length-1)+"=":r%3==1&&(o=o.substring(0,o.length-2)+"=="),o}(e);return{rr_type:t,base64:n}}if(e instanceof DataView){return{rr_type:e.constructor.name,args:[kp(e.buffer,t,n),e.byteOffset,e.byteLength]}}if(e instanceof HTMLImageElement){const t=e.constructor.name,{src:n}=e;return{rr_type:t,src:n}}if(e instanceof HTMLCanvasElement){return{rr_type:"HTMLImageElement",src:e.toDataURL()}}if(e instanceof ImageData){return{rr_type:e.constructor.name,args:[kp(e.data,t,n),e.width,e.height]}}if(Ep(e,t)||"object"==typeof e){return{rr_type:e.constructor.name,index:wp(e,t,n)}}return e}const xp=(e,t,n)=>e.map((e=>kp(e,t,n))),Ep=(e,t)=>{const n=["WebGLActiveInfo","WebGLBuffer","WebGLFramebuffer","WebGLProgram","WebGLRenderbuffer","WebGLShader","WebGLShaderPrecisionFormat","WebGLTexture","WebGLUniformLocation","WebGLVertexArrayObject","WebGLVertexArrayObjectOES"].filter((e=>"function"==typeof t[e]));return Boolean(n.find((n=>e instanceof t[n])))};function Cp(e,t,n,r,o){const s=[];try{const i=cp(e.HTMLCanvasElement.prototype,"getContext",(function(e){return function(s,...i){if(!up(this,t,n,r,!0)){const e=function(e){return"experimental-webgl"===e?"webgl":e}(s);if("__context"in this||(this.__context=e),o&&["webgl","webgl2"].includes(e))if(i[0]&&"object"==typeof i[0]){const e=i[0];e.preserveDrawingBuffer||(e.preserveDrawingBuffer=!0)}else i.splice(0,1,{preserveDrawingBuffer:!0})}return e.apply(this,[s,...i])}}));s.push(i)}catch(e){console.error("failed to patch HTMLCanvasElement.prototype.getContext")}return()=>{s.forEach((e=>e()))}}function Tp(e,t,n,r,o,s,i,a){const c=[],u=Object.getOwnPropertyNames(e);for(const i of u)if(!["isContextLost","canvas","drawingBufferWidth","drawingBufferHeight"].includes(i))try{if("function"!=typeof e[i])continue;const u=cp(e,i,(function(e){return function(...c){const u=e.apply(this,c);if(wp(u,a,this),"tagName"in this.canvas&&!up(this.canvas,r,o,s,!0)){const e=xp(c,a,this),r={type:t,property:i,args:e};n(this.canvas,r)}return u}}));c.push(u)}catch(r){const o=ap(e,i,{set(e){n(this.canvas,{type:t,property:i,args:[e],setter:!0})}});c.push(o)}return c}class Ip{reset(){this.pendingCanvasMutations.clear(),this.restoreHandlers.forEach((e=>{try{e()}catch(e){}})),this.restoreHandlers=[],this.windowsSet=new WeakSet,this.windows=[],this.shadowDoms=new Set,Mt([this,"access",e=>e.worker,"optionalAccess",e=>e.terminate,"call",e=>e()]),this.worker=null,this.snapshotInProgressMap=new Map,(this.options.recordCanvas&&"number"==typeof this.options.sampling||this.options.enableManualSnapshot)&&(this.worker=this.initFPSWorker())}freeze(){this.frozen=!0}unfreeze(){this.frozen=!1}lock(){this.locked=!0}unlock(){this.locked=!1}constructor(e){this.pendingCanvasMutations=new Map,this.rafStamps={latestId:0,invokeId:null},this.shadowDoms=new Set,this.windowsSet=new WeakSet,this.windows=[],this.restoreHandlers=[],this.frozen=!1,this.locked=!1,this.snapshotInProgressMap=new Map,this.worker=null,this.processMutation=(e,t)=>{!(this.rafStamps.invokeId&&this.rafStamps.latestId!==this.rafStamps.invokeId)&&this.rafStamps.invokeId||(this.rafStamps.invokeId=this.rafStamps.latestId),this.pendingCanvasMutations.has(e)||this.pendingCanvasMutations.set(e,[]),this.pendingCanvasMutations.get(e).push(t)};const{sampling:t="all",win:n,blockClass:r,blockSelector:o,unblockSelector:s,maxCanvasSize:i,recordCanvas:a,dataURLOptions:c,errorHandler:u}=e;this.mutationCb=e.mutationCb,this.mirror=e.mirror,this.options=e,u&&gp(u),(a&&"number"==typeof t||e.enableManualSnapshot)&&(this.worker=this.initFPSWorker()),this.addWindow(n),e.enableManualSnapshot||_p((()=>{a&&"all"===t&&(this.startRAFTimestamping(),this.startPendingCanvasMutationFlusher()),a&&"number"==typeof t&&this.initCanvasFPSObserver(t,r,o,s,i,{dataURLOptions:c})}))()}addWindow(e){const{sampling:t="all",blockClass:n,blockSelector:r,unblockSelector:o,recordCanvas:s,enableManualSnapshot:i}=this.options;if(!this.windowsSet.has(e)){if(i)return this.windowsSet.add(e),void this.windows.push(new WeakRef(e));_p((()=>{if(s&&"all"===t&&this.initCanvasMutationObserver(e,n,r,o),s&&"number"==typeof t){const t=Cp(e,n,r,o,!0);this.restoreHandlers.push((()=>{t()}))}}))(),this.windowsSet.add(e),this.windows.push(new WeakRef(e))}}addShadowRoot(e){this.shadowDoms.add(new WeakRef(e))}resetShadowRoots(){this.shadowDoms=new Set}initFPSWorker(){const e=new Worker(function(){const e=new Blob(['for(var e="ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",t="undefined"==typeof Uint8Array?[]:new Uint8Array(256),a=0;a<64;a++)t[e.charCodeAt(a)]=a;var n=function(t){var a,n=new Uint8Array(t),r=n.length,s="";for(a=0;a<r;a+=3)s+=e[n[a]>>2],s+=e[(3&n[a])<<4|n[a+1]>>4],s+=e[(15&n[a+1])<<2|n[a+2]>>6],s+=e[63&n[a+2]];return r%3==2?s=s.substring(0,s.length-1)+"=":r%3==1&&(s=s.substring(0,s.length-2)+"=="),s};const r=new Map,s=new Map;const i=self;i.onmessage=async function(e){if(!("OffscreenCanvas"in globalThis))return i.postMessage({id:e.data.id});{const{id:t,bitmap:a,width:o,height:f,maxCanvasSize:c,dataURLOptions:g}=e.data,u=async function(e,t,a){const r=e+"-"+t;if("OffscreenCanvas"in globalThis){if(s.has(r))return s.get(r);const i=new OffscreenCanvas(e,t);i.getContext("2d");const o=await i.convertToBlob(a),f=await o.arrayBuffer(),c=n(f);return s.set(r,c),c}return""}(o,f,g),[h,d]=function(e,t,a){if(!a)return[e,t];const[n,r]=a;if(e<=n&&t<=r)return[e,t];let s=e,i=t;return s>n&&(i=Math.floor(n*t/e),s=n),i>r&&(s=Math.floor(r*e/t),i=r),[s,i]}(o,f,c),l=new OffscreenCanvas(h,d),w=l.getContext("bitmaprenderer"),p=h===o&&d===f?a:await createImageBitmap(a,{resizeWidth:h,resizeHeight:d,resizeQuality:"low"});w.transferFromImageBitmap(p),a.close();const y=await l.convertToBlob(g),v=y.type,b=await y.arrayBuffer(),m=n(b);if(p.close(),!r.has(t)&&await u===m)return r.set(t,m),i.postMessage({id:t});if(r.get(t)===m)return i.postMessage({id:t});i.postMessage({id:t,type:v,base64:m,width:o,height:f}),r.set(t,m)}};']);return URL.createObjectURL(e)}());return e.onmessage=e=>{const t=e.data,{id:n}=t;if(this.snapshotInProgressMap.set(n,!1),!("base64"in t))return;const{base64:r,type:o,width:s,height:i}=t;this.mutationCb({id:n,type:fp["2D"],commands:[{property:"clearRect",args:[0,0,s,i]},{property:"drawImage",args:[{rr_type:"ImageBitmap",args:[{rr_type:"Blob",data:[{rr_type:"ArrayBuffer",base64:r}],type:o}]},0,0,s,i]}]})},e}initCanvasFPSObserver(e,t,n,r,o,s){const i=this.takeSnapshot(!1,e,t,n,r,o,s.dataURLOptions);this.restoreHandlers.push((()=>{cancelAnimationFrame(i)}))}initCanvasMutationObserver(e,t,n,r){const o=Cp(e,t,n,r,!1),s=function(e,t,n,r,o){const s=[],i=Object.getOwnPropertyNames(t.CanvasRenderingContext2D.prototype);for(const a of i)try{if("function"!=typeof t.CanvasRenderingContext2D.prototype[a])continue;const i=cp(t.CanvasRenderingContext2D.prototype,a,(function(s){return function(...i){return up(this.canvas,n,r,o,!0)||hp((()=>{const n=xp(i,t,this);e(this.canvas,{type:fp["2D"],property:a,args:n})}),0),s.apply(this,i)}}));s.push(i)}catch(n){const r=ap(t.CanvasRenderingContext2D.prototype,a,{set(t){e(this.canvas,{type:fp["2D"],property:a,args:[t],setter:!0})}});s.push(r)}return()=>{s.forEach((e=>e()))}}(this.processMutation.bind(this),e,t,n,r),i=function(e,t,n,r,o){const s=[];return s.push(...Tp(t.WebGLRenderingContext.prototype,fp.WebGL,e,n,r,o,0,t)),void 0!==t.WebGL2RenderingContext&&s.push(...Tp(t.WebGL2RenderingContext.prototype,fp.WebGL2,e,n,r,o,0,t)),()=>{s.forEach((e=>e()))}}(this.processMutation.bind(this),e,t,n,r,this.mirror);this.restoreHandlers.push((()=>{o(),s(),i()}))}snapshot(e){const{options:t}=this,n=this.takeSnapshot(!0,"all"===t.sampling?2:t.sampling||2,t.blockClass,t.blockSelector,t.unblockSelector,t.maxCanvasSize,t.dataURLOptions,e);this.restoreHandlers.push((()=>{cancelAnimationFrame(n)}))}takeSnapshot(e,t,n,r,o,s,i,a){const c=1e3/t;let u,l=0;const d=e=>{if(e)return[e];const t=[],s=e=>{e.querySelectorAll("canvas").forEach((e=>{up(e,n,r,o,!0)||t.push(e)}))};for(const e of this.windows){const t=e.deref();t&&s(t.document)}for(const e of this.shadowDoms){const t=e.deref();t&&s(t)}return t},p=t=>{this.windows.length&&(l&&t-l<c?u=pp(p):(l=t,d(a).forEach((t=>{if(!this.mirror.hasNode(t))return;const n=this.mirror.getId(t);if(!this.snapshotInProgressMap.get(n)&&t.width&&t.height){if(this.snapshotInProgressMap.set(n,!0),!e&&["webgl","webgl2"].includes(t.__context)){const e=t.getContext(t.__context);!1===Mt([e,"optionalAccess",e=>e.getContextAttributes,"call",e=>e(),"optionalAccess",e=>e.preserveDrawingBuffer])&&e.clear(e.COLOR_BUFFER_BIT)}createImageBitmap(t).then((e=>{Mt([this,"access",e=>e.worker,"optionalAccess",e=>e.postMessage,"call",r=>r({id:n,bitmap:e,width:t.width,height:t.height,dataURLOptions:i,maxCanvasSize:s},[e])])})).catch((e=>{_p((()=>{throw e}))()}))}})),e||(u=pp(p))))};return u=pp(p),u}startPendingCanvasMutationFlusher(){pp((()=>this.flushPendingCanvasMutations()))}startRAFTimestamping(){const e=t=>{this.rafStamps.latestId=t,pp(e)};pp(e)}flushPendingCanvasMutations(){this.pendingCanvasMutations.forEach(((e,t)=>{const n=this.mirror.getId(t);this.flushPendingCanvasMutationFor(t,n)})),pp((()=>this.flushPendingCanvasMutations()))}flushPendingCanvasMutationFor(e,t){if(this.frozen||this.locked)return;const n=this.pendingCanvasMutations.get(e);if(!n||-1===t)return;const r=n.map((e=>{const{type:t,...n}=e;return n})),{type:o}=n[0];this.mutationCb({id:t,type:o,commands:r}),this.pendingCanvasMutations.delete(e)}}const Mp={low:{sampling:{canvas:1},dataURLOptions:{type:"image/webp",quality:.25}},medium:{sampling:{canvas:2},dataURLOptions:{type:"image/webp",quality:.4}},high:{sampling:{canvas:4},dataURLOptions:{type:"image/webp",quality:.5}}},Rp=1280,Ap=(e={})=>{const[t,n]=e.maxCanvasSize||[],r={quality:e.quality||"medium",enableManualSnapshot:e.enableManualSnapshot,maxCanvasSize:[t?Math.min(t,Rp):Rp,n?Math.min(n,Rp):Rp]};let o;const s=new Promise((e=>o=e));return{name:"ReplayCanvas",getOptions(){const{quality:e,enableManualSnapshot:t,maxCanvasSize:n}=r;return{enableManualSnapshot:t,recordCanvas:!0,getCanvasManager:e=>{const r=new Ip({...e,enableManualSnapshot:t,maxCanvasSize:n,errorHandler:e=>{try{"object"==typeof e&&(e.__rrweb__=!0)}catch(e){}}});return o(r),r},...Mp[e||"medium"]||Mp.medium}},async snapshot(e){(await s).snapshot(e)}}},Np=k,Op=Np.document,Dp=Np.navigator,Lp="Report a Bug",Pp="widget",Fp=(e,t={includeReplay:!0})=>{if(!e.message)throw new Error("Unable to submit feedback with empty message");const n=Qt();if(!n)throw new Error("No client setup, cannot send feedback.");e.tags&&Object.keys(e.tags).length&&Gt().setTags(e.tags);const r=Ko({source:"api",url:M(),...e},t);return new Promise(((e,t)=>{const o=setTimeout((()=>t("Unable to determine if Feedback was correctly sent.")),5e3),s=n.on("afterSendEvent",((n,i)=>{if(n.event_id===r)return clearTimeout(o),s(),i&&"number"==typeof i.statusCode&&i.statusCode>=200&&i.statusCode<300&&e(r),i&&"number"==typeof i.statusCode&&0===i.statusCode?t("Unable to send Feedback. This is because of network issues, or because you are using an ad-blocker."):i&&"number"==typeof i.statusCode&&403===i.statusCode?t("Unable to send Feedback. This could be because this domain is not in your list of allowed domains."):t("Unable to send Feedback. This could be because of network issues, or because you are using an ad-blocker")}))}))},$p="undefined"==typeof __SENTRY_DEBUG__||__SENTRY_DEBUG__;function Bp(e,t){return{...e,...t,tags:{...e.tags,...t.tags},onFormOpen:()=>{t.onFormOpen&&t.onFormOpen(),e.onFormOpen&&e.onFormOpen()},onFormClose:()=>{t.onFormClose&&t.onFormClose(),e.onFormClose&&e.onFormClose()},onSubmitSuccess:n=>{t.onSubmitSuccess&&t.onSubmitSuccess(n),e.onSubmitSuccess&&e.onSubmitSuccess(n)},onSubmitError:n=>{t.onSubmitError&&t.onSubmitError(n),e.onSubmitError&&e.onSubmitError(n)},onFormSubmitted:()=>{t.onFormSubmitted&&t.onFormSubmitted(),e.onFormSubmitted&&e.onFormSubmitted()},themeDark:{...e.themeDark,...t.themeDark},themeLight:{...e.themeLight,...t.themeLight}}}function Up(e,t){return Object.entries(t).forEach((([t,n])=>{e.setAttributeNS(null,t,n)})),e}const jp=20,Hp="http://www.w3.org/2000/svg";function zp({triggerLabel:e,triggerAriaLabel:t,shadow:n,styleNonce:r}){const o=Op.createElement("button");if(o.type="button",o.className="widget__actor",o.ariaHidden="false",o.ariaLabel=t||e||Lp,o.appendChild(function(){const e=e=>Np.document.createElementNS(Hp,e),t=Up(e("svg"),{width:`${jp}`,height:`${jp}`,viewBox:`0 0 ${jp} ${jp}`,fill:"var(--foreground)"}),n=Up(e("g"),{clipPath:"url(#clip0_57_80)"}),r=Up(e("path"),{"fill-rule":"evenodd","clip-rule":"evenodd",d:"M15.6622 15H12.3997C12.2129 14.9959 12.031 14.9396 11.8747 14.8375L8.04965 12.2H7.49956V19.1C7.4875 19.3348 7.3888 19.5568 7.22256 19.723C7.05632 19.8892 6.83435 19.9879 6.59956 20H2.04956C1.80193 19.9968 1.56535 19.8969 1.39023 19.7218C1.21511 19.5467 1.1153 19.3101 1.11206 19.0625V12.2H0.949652C0.824431 12.2017 0.700142 12.1783 0.584123 12.1311C0.468104 12.084 0.362708 12.014 0.274155 11.9255C0.185602 11.8369 0.115689 11.7315 0.0685419 11.6155C0.0213952 11.4995 -0.00202913 11.3752 -0.00034808 11.25V3.75C-0.00900498 3.62067 0.0092504 3.49095 0.0532651 3.36904C0.0972798 3.24712 0.166097 3.13566 0.255372 3.04168C0.344646 2.94771 0.452437 2.87327 0.571937 2.82307C0.691437 2.77286 0.82005 2.74798 0.949652 2.75H8.04965L11.8747 0.1625C12.031 0.0603649 12.2129 0.00407221 12.3997 0H15.6622C15.9098 0.00323746 16.1464 0.103049 16.3215 0.278167C16.4966 0.453286 16.5964 0.689866 16.5997 0.9375V3.25269C17.3969 3.42959 18.1345 3.83026 18.7211 4.41679C19.5322 5.22788 19.9878 6.32796 19.9878 7.47502C19.9878 8.62209 19.5322 9.72217 18.7211 10.5333C18.1345 11.1198 17.3969 11.5205 16.5997 11.6974V14.0125C16.6047 14.1393 16.5842 14.2659 16.5395 14.3847C16.4948 14.5035 16.4268 14.6121 16.3394 14.7042C16.252 14.7962 16.147 14.8698 16.0307 14.9206C15.9144 14.9714 15.7891 14.9984 15.6622 15ZM1.89695 10.325H1.88715V4.625H8.33715C8.52423 4.62301 8.70666 4.56654 8.86215 4.4625L12.6872 1.875H14.7247V13.125H12.6872L8.86215 10.4875C8.70666 10.3835 8.52423 10.327 8.33715 10.325H2.20217C2.15205 10.3167 2.10102 10.3125 2.04956 10.3125C1.9981 10.3125 1.94708 10.3167 1.89695 10.325ZM2.98706 12.2V18.1625H5.66206V12.2H2.98706ZM16.5997 9.93612V5.01393C16.6536 5.02355 16.7072 5.03495 16.7605 5.04814C17.1202 5.13709 17.4556 5.30487 17.7425 5.53934C18.0293 5.77381 18.2605 6.06912 18.4192 6.40389C18.578 6.73866 18.6603 7.10452 18.6603 7.47502C18.6603 7.84552 18.578 8.21139 18.4192 8.54616C18.2605 8.88093 18.0293 9.17624 17.7425 9.41071C17.4556 9.64518 17.1202 9.81296 16.7605 9.90191C16.7072 9.91509 16.6536 9.9265 16.5997 9.93612Z"});t.appendChild(n).appendChild(r);const o=e("defs"),s=Up(e("clipPath"),{id:"clip0_57_80"}),i=Up(e("rect"),{width:`${jp}`,height:`${jp}`,fill:"white"});return s.appendChild(i),o.appendChild(s),t.appendChild(o).appendChild(s).appendChild(i),t}()),e){const t=Op.createElement("span");t.appendChild(Op.createTextNode(e)),o.appendChild(t)}const s=function(e){const t=Op.createElement("style");return t.textContent='\n.widget__actor {\n  position: fixed;\n  z-index: var(--z-index);\n  margin: var(--page-margin);\n  inset: var(--actor-inset);\n\n  display: flex;\n  align-items: center;\n  gap: 8px;\n  padding: 16px;\n\n  font-family: inherit;\n  font-size: var(--font-size);\n  font-weight: 600;\n  line-height: 1.14em;\n  text-decoration: none;\n\n  background: var(--actor-background, var(--background));\n  border-radius: var(--actor-border-radius, 1.7em/50%);\n  border: var(--actor-border, var(--border));\n  box-shadow: var(--actor-box-shadow, var(--box-shadow));\n  color: var(--actor-color, var(--foreground));\n  fill: var(--actor-color, var(--foreground));\n  cursor: pointer;\n  opacity: 1;\n  transition: transform 0.2s ease-in-out;\n  transform: translate(0, 0) scale(1);\n}\n.widget__actor[aria-hidden="true"] {\n  opacity: 0;\n  pointer-events: none;\n  visibility: hidden;\n  transform: translate(0, 16px) scale(0.98);\n}\n\n.widget__actor:hover {\n  background: var(--actor-hover-background, var(--background));\n  filter: var(--interactive-filter);\n}\n\n.widget__actor svg {\n  width: 1.14em;\n  height: 1.14em;\n}\n\n@media (max-width: 600px) {\n  .widget__actor span {\n    display: none;\n  }\n}\n',e&&t.setAttribute("nonce",e),t}(r);return{el:o,appendToDom(){n.appendChild(s),n.appendChild(o)},removeFromDom(){n.removeChild(o),n.removeChild(s)},show(){o.ariaHidden="false"},hide(){o.ariaHidden="true"}}}const qp="rgba(88, 74, 192, 1)",Wp={foreground:"#2b2233",background:"#ffffff",accentForeground:"white",accentBackground:qp,successColor:"#268d75",errorColor:"#df3338",border:"1.5px solid rgba(41, 35, 47, 0.13)",boxShadow:"0px 4px 24px 0px rgba(43, 34, 51, 0.12)",outline:"1px auto var(--accent-background)",interactiveFilter:"brightness(95%)"},Yp={foreground:"#ebe6ef",background:"#29232f",accentForeground:"white",accentBackground:qp,successColor:"#2da98c",errorColor:"#f55459",border:"1.5px solid rgba(235, 230, 239, 0.15)",boxShadow:"0px 4px 24px 0px rgba(43, 34, 51, 0.12)",outline:"1px auto var(--accent-background)",interactiveFilter:"brightness(150%)"};function Vp(e){return`\n  --foreground: ${e.foreground};\n  --background: ${e.background};\n  --accent-foreground: ${e.accentForeground};\n  --accent-background: ${e.accentBackground};\n  --success-color: ${e.successColor};\n  --error-color: ${e.errorColor};\n  --border: ${e.border};\n  --box-shadow: ${e.boxShadow};\n  --outline: ${e.outline};\n  --interactive-filter: ${e.interactiveFilter};\n  `}const Gp=({lazyLoadIntegration:e,getModalIntegration:t,getScreenshotIntegration:n})=>({id:r="sentry-feedback",autoInject:o=!0,showBranding:s=!0,isEmailRequired:i=!1,isNameRequired:a=!1,showEmail:c=!0,showName:u=!0,enableScreenshot:l=!0,useSentryUser:d={email:"email",name:"username"},tags:p,styleNonce:h,scriptNonce:f,colorScheme:m="system",themeLight:g={},themeDark:_={},addScreenshotButtonLabel:y="Add a screenshot",cancelButtonLabel:v="Cancel",confirmButtonLabel:b="Confirm",emailLabel:S="Email",emailPlaceholder:w="your.email@example.org",formTitle:k="Report a Bug",isRequiredLabel:x="(required)",messageLabel:E="Description",messagePlaceholder:C="What's the bug? What did you expect?",nameLabel:T="Name",namePlaceholder:I="Your Name",removeScreenshotButtonLabel:M="Remove screenshot",submitButtonLabel:R="Send Bug Report",successMessageText:A="Thank you for your report!",triggerLabel:N=Lp,triggerAriaLabel:O="",onFormOpen:D,onFormClose:P,onSubmitSuccess:F,onSubmitError:$,onFormSubmitted:B}={})=>{const U={id:r,autoInject:o,showBranding:s,isEmailRequired:i,isNameRequired:a,showEmail:c,showName:u,enableScreenshot:l,useSentryUser:d,tags:p,styleNonce:h,scriptNonce:f,colorScheme:m,themeDark:_,themeLight:g,triggerLabel:N,triggerAriaLabel:O,cancelButtonLabel:v,submitButtonLabel:R,confirmButtonLabel:b,formTitle:k,emailLabel:S,emailPlaceholder:w,messageLabel:E,messagePlaceholder:C,nameLabel:T,namePlaceholder:I,successMessageText:A,isRequiredLabel:x,addScreenshotButtonLabel:y,removeScreenshotButtonLabel:M,onFormClose:P,onFormOpen:D,onSubmitError:$,onSubmitSuccess:F,onFormSubmitted:B};let j=null,H=[];const z=e=>{if(!j){const t=Op.createElement("div");t.id=String(e.id),Op.body.appendChild(t),j=t.attachShadow({mode:"open"}),j.appendChild(function({colorScheme:e,themeDark:t,themeLight:n,styleNonce:r}){const o=Op.createElement("style");return o.textContent=`\n:host {\n  --font-family: system-ui, 'Helvetica Neue', Arial, sans-serif;\n  --font-size: 14px;\n  --z-index: 100000;\n\n  --page-margin: 16px;\n  --inset: auto 0 0 auto;\n  --actor-inset: var(--inset);\n\n  font-family: var(--font-family);\n  font-size: var(--font-size);\n\n  ${"system"!==e?"color-scheme: only light;":""}\n\n  ${Vp("dark"===e?{...Yp,...t}:{...Wp,...n})}\n}\n\n${"system"===e?`\n@media (prefers-color-scheme: dark) {\n  :host {\n    ${Vp({...Yp,...t})}\n  }\n}`:""}\n}\n`,r&&o.setAttribute("nonce",r),o}(e))}return j},q=async(t,n,r)=>{const o=Qt(),s=o&&o.getIntegrationByName(t);if(s)return s;const i=(n&&n()||await e(r,f))();return o&&o.addIntegration(i),i},W=async e=>{const r=e.enableScreenshot&&!(/Android|webOS|iPhone|iPad|iPod|BlackBerry|IEMobile|Opera Mini/i.test(Dp.userAgent)||/Macintosh/i.test(Dp.userAgent)&&Dp.maxTouchPoints&&Dp.maxTouchPoints>1||!isSecureContext),[o,s]=await Promise.all([q("FeedbackModal",t,"feedbackModalIntegration"),r?q("FeedbackScreenshot",n,"feedbackScreenshotIntegration"):void 0]);if(!o)throw $p&&L.error("[Feedback] Missing feedback modal integration. Try using `feedbackSyncIntegration` in your `Sentry.init`."),new Error("[Feedback] Missing feedback modal integration!");r&&!s&&$p&&L.error("[Feedback] Missing feedback screenshot integration. Proceeding without screenshots.");const i=o.createDialog({options:{...e,onFormClose:()=>{i&&i.close(),e.onFormClose&&e.onFormClose()},onFormSubmitted:()=>{i&&i.close(),e.onFormSubmitted&&e.onFormSubmitted()}},screenshotIntegration:r?s:void 0,sendFeedback:Fp,shadow:z(e)});return i},Y=(e,t={})=>{const n=Bp(U,t),r="string"==typeof e?Op.querySelector(e):"function"==typeof e.addEventListener?e:null;if(!r)throw $p&&L.error("[Feedback] Unable to attach to target element"),new Error("Unable to attach to target element");let o=null;const s=async()=>{o||(o=await W({...n,onFormSubmitted:()=>{o&&o.removeFromDom(),n.onFormSubmitted&&n.onFormSubmitted()}})),o.appendToDom(),o.open()};r.addEventListener("click",s);const i=()=>{H=H.filter((e=>e!==i)),o&&o.removeFromDom(),o=null,r.removeEventListener("click",s)};return H.push(i),i},V=(e={})=>{const t=Bp(U,e),n=z(t),r=zp({triggerLabel:t.triggerLabel,triggerAriaLabel:t.triggerAriaLabel,shadow:n,styleNonce:h});return Y(r.el,{...t,onFormOpen(){r.hide()},onFormClose(){r.show()},onFormSubmitted(){r.show()}}),r};return{name:"Feedback",setupOnce(){Oe()&&U.autoInject&&("loading"===Op.readyState?Op.addEventListener("DOMContentLoaded",(()=>V().appendToDom())):V().appendToDom())},attachTo:Y,createWidget(e={}){const t=V(Bp(U,e));return t.appendToDom(),t},createForm:async(e={})=>W(Bp(U,e)),remove(){j&&(j.parentElement&&j.parentElement.remove(),j=null),H.forEach((e=>e())),H=[]}}};var Xp,Jp,Kp,Qp,Zp,eh,th,nh={},rh=[],oh=/acit|ex(?:s|g|n|p|$)|rph|grid|ows|mnc|ntw|ine[ch]|zoo|^ord|itera/i,sh=Array.isArray;function ih(e,t){for(var n in t)e[n]=t[n];return e}function ah(e){var t=e.parentNode;t&&t.removeChild(e)}function ch(e,t,n){var r,o,s,i={};for(s in t)"key"==s?r=t[s]:"ref"==s?o=t[s]:i[s]=t[s];if(arguments.length>2&&(i.children=arguments.length>3?Xp.call(arguments,2):n),"function"==typeof e&&null!=e.defaultProps)for(s in e.defaultProps)void 0===i[s]&&(i[s]=e.defaultProps[s]);return uh(e,i,r,o,null)}function uh(e,t,n,r,o){var s={type:e,props:t,key:n,ref:r,__k:null,__:null,__b:0,__e:null,__d:void 0,__c:null,constructor:void 0,__v:null==o?++Kp:o,__i:-1,__u:0};return null==o&&null!=Jp.vnode&&Jp.vnode(s),s}function lh(e){return e.children}function dh(e,t){this.props=e,this.context=t}function ph(e,t){if(null==t)return e.__?ph(e.__,e.__i+1):null;for(var n;t<e.__k.length;t++)if(null!=(n=e.__k[t])&&null!=n.__e)return n.__e;return"function"==typeof e.type?ph(e):null}function hh(e,t,n){var r,o=e.__v,s=o.__e,i=e.__P;if(i)return(r=ih({},o)).__v=o.__v+1,Jp.vnode&&Jp.vnode(r),xh(i,r,o,e.__n,void 0!==i.ownerSVGElement,32&o.__u?[s]:null,t,null==s?ph(o):s,!!(32&o.__u),n),r.__.__k[r.__i]=r,r.__d=void 0,r.__e!=s&&fh(r),r}function fh(e){var t,n;if(null!=(e=e.__)&&null!=e.__c){for(e.__e=e.__c.base=null,t=0;t<e.__k.length;t++)if(null!=(n=e.__k[t])&&null!=n.__e){e.__e=e.__c.base=n.__e;break}return fh(e)}}function mh(e){(!e.__d&&(e.__d=!0)&&Qp.push(e)&&!gh.__r++||Zp!==Jp.debounceRendering)&&((Zp=Jp.debounceRendering)||eh)(gh)}function gh(){var e,t,n,r=[],o=[];for(Qp.sort(th);e=Qp.shift();)e.__d&&(n=Qp.length,t=hh(e,r,o)||t,0===n||Qp.length>n?(Eh(r,t,o),o.length=r.length=0,t=void 0,Qp.sort(th)):t&&Jp.__c&&Jp.__c(t,rh));t&&Eh(r,t,o),gh.__r=0}function _h(e,t,n,r,o,s,i,a,c,u,l){var d,p,h,f,m,g=r&&r.__k||rh,_=t.length;for(n.__d=c,function(e,t,n){var r,o,s,i,a,c=t.length,u=n.length,l=u,d=0;for(e.__k=[],r=0;r<c;r++)null!=(o=e.__k[r]=null==(o=t[r])||"boolean"==typeof o||"function"==typeof o?null:"string"==typeof o||"number"==typeof o||"bigint"==typeof o||o.constructor==String?uh(null,o,null,null,o):sh(o)?uh(lh,{children:o},null,null,null):void 0===o.constructor&&o.__b>0?uh(o.type,o.props,o.key,o.ref?o.ref:null,o.__v):o)?(o.__=e,o.__b=e.__b+1,a=vh(o,n,i=r+d,l),o.__i=a,s=null,-1!==a&&(l--,(s=n[a])&&(s.__u|=131072)),null==s||null===s.__v?(-1==a&&d--,"function"!=typeof o.type&&(o.__u|=65536)):a!==i&&(a===i+1?d++:a>i?l>c-i?d+=a-i:d--:d=a<i&&a==i-1?a-i:0,a!==r+d&&(o.__u|=65536))):(s=n[r])&&null==s.key&&s.__e&&(s.__e==e.__d&&(e.__d=ph(s)),Ih(s,s,!1),n[r]=null,l--);if(l)for(r=0;r<u;r++)null!=(s=n[r])&&!(131072&s.__u)&&(s.__e==e.__d&&(e.__d=ph(s)),Ih(s,s))}(n,t,g),c=n.__d,d=0;d<_;d++)null!=(h=n.__k[d])&&"boolean"!=typeof h&&"function"!=typeof h&&(p=-1===h.__i?nh:g[h.__i]||nh,h.__i=d,xh(e,h,p,o,s,i,a,c,u,l),f=h.__e,h.ref&&p.ref!=h.ref&&(p.ref&&Th(p.ref,null,h),l.push(h.ref,h.__c||f,h)),null==m&&null!=f&&(m=f),65536&h.__u||p.__k===h.__k?c=yh(h,c,e):"function"==typeof h.type&&void 0!==h.__d?c=h.__d:f&&(c=f.nextSibling),h.__d=void 0,h.__u&=-196609);n.__d=c,n.__e=m}function yh(e,t,n){var r,o;if("function"==typeof e.type){for(r=e.__k,o=0;r&&o<r.length;o++)r[o]&&(r[o].__=e,t=yh(r[o],t,n));return t}e.__e!=t&&(n.insertBefore(e.__e,t||null),t=e.__e);do{t=t&&t.nextSibling}while(null!=t&&8===t.nodeType);return t}function vh(e,t,n,r){var o=e.key,s=e.type,i=n-1,a=n+1,c=t[n];if(null===c||c&&o==c.key&&s===c.type)return n;if(r>(null==c||131072&c.__u?0:1))for(;i>=0||a<t.length;){if(i>=0){if((c=t[i])&&!(131072&c.__u)&&o==c.key&&s===c.type)return i;i--}if(a<t.length){if((c=t[a])&&!(131072&c.__u)&&o==c.key&&s===c.type)return a;a++}}return-1}function bh(e,t,n){"-"===t[0]?e.setProperty(t,null==n?"":n):e[t]=null==n?"":"number"!=typeof n||oh.test(t)?n:n+"px"}function Sh(e,t,n,r,o){var s;e:if("style"===t)if("string"==typeof n)e.style.cssText=n;else{if("string"==typeof r&&(e.style.cssText=r=""),r)for(t in r)n&&t in n||bh(e.style,t,"");if(n)for(t in n)r&&n[t]===r[t]||bh(e.style,t,n[t])}else if("o"===t[0]&&"n"===t[1])s=t!==(t=t.replace(/(PointerCapture)$|Capture$/i,"$1")),t=t.toLowerCase()in e?t.toLowerCase().slice(2):t.slice(2),e.l||(e.l={}),e.l[t+s]=n,n?r?n.u=r.u:(n.u=Date.now(),e.addEventListener(t,s?kh:wh,s)):e.removeEventListener(t,s?kh:wh,s);else{if(o)t=t.replace(/xlink(H|:h)/,"h").replace(/sName$/,"s");else if("width"!==t&&"height"!==t&&"href"!==t&&"list"!==t&&"form"!==t&&"tabIndex"!==t&&"download"!==t&&"rowSpan"!==t&&"colSpan"!==t&&"role"!==t&&t in e)try{e[t]=null==n?"":n;break e}catch(e){}"function"==typeof n||(null==n||!1===n&&"-"!==t[4]?e.removeAttribute(t):e.setAttribute(t,n))}}function wh(e){if(this.l){var t=this.l[e.type+!1];if(e.t){if(e.t<=t.u)return}else e.t=Date.now();return t(Jp.event?Jp.event(e):e)}}function kh(e){if(this.l)return this.l[e.type+!0](Jp.event?Jp.event(e):e)}function xh(e,t,n,r,o,s,i,a,c,u){var l,d,p,h,f,m,g,_,y,v,b,S,w,k,x,E=t.type;if(void 0!==t.constructor)return null;128&n.__u&&(c=!!(32&n.__u),s=[a=t.__e=n.__e]),(l=Jp.__b)&&l(t);e:if("function"==typeof E)try{if(_=t.props,y=(l=E.contextType)&&r[l.__c],v=l?y?y.props.value:l.__:r,n.__c?g=(d=t.__c=n.__c).__=d.__E:("prototype"in E&&E.prototype.render?t.__c=d=new E(_,v):(t.__c=d=new dh(_,v),d.constructor=E,d.render=Mh),y&&y.sub(d),d.props=_,d.state||(d.state={}),d.context=v,d.__n=r,p=d.__d=!0,d.__h=[],d._sb=[]),null==d.__s&&(d.__s=d.state),null!=E.getDerivedStateFromProps&&(d.__s==d.state&&(d.__s=ih({},d.__s)),ih(d.__s,E.getDerivedStateFromProps(_,d.__s))),h=d.props,f=d.state,d.__v=t,p)null==E.getDerivedStateFromProps&&null!=d.componentWillMount&&d.componentWillMount(),null!=d.componentDidMount&&d.__h.push(d.componentDidMount);else{if(null==E.getDerivedStateFromProps&&_!==h&&null!=d.componentWillReceiveProps&&d.componentWillReceiveProps(_,v),!d.__e&&(null!=d.shouldComponentUpdate&&!1===d.shouldComponentUpdate(_,d.__s,v)||t.__v===n.__v)){for(t.__v!==n.__v&&(d.props=_,d.state=d.__s,d.__d=!1),t.__e=n.__e,t.__k=n.__k,t.__k.forEach((function(e){e&&(e.__=t)})),b=0;b<d._sb.length;b++)d.__h.push(d._sb[b]);d._sb=[],d.__h.length&&i.push(d);break e}null!=d.componentWillUpdate&&d.componentWillUpdate(_,d.__s,v),null!=d.componentDidUpdate&&d.__h.push((function(){d.componentDidUpdate(h,f,m)}))}if(d.context=v,d.props=_,d.__P=e,d.__e=!1,S=Jp.__r,w=0,"prototype"in E&&E.prototype.render){for(d.state=d.__s,d.__d=!1,S&&S(t),l=d.render(d.props,d.state,d.context),k=0;k<d._sb.length;k++)d.__h.push(d._sb[k]);d._sb=[]}else do{d.__d=!1,S&&S(t),l=d.render(d.props,d.state,d.context),d.state=d.__s}while(d.__d&&++w<25);d.state=d.__s,null!=d.getChildContext&&(r=ih(ih({},r),d.getChildContext())),p||null==d.getSnapshotBeforeUpdate||(m=d.getSnapshotBeforeUpdate(h,f)),_h(e,sh(x=null!=l&&l.type===lh&&null==l.key?l.props.children:l)?x:[x],t,n,r,o,s,i,a,c,u),d.base=t.__e,t.__u&=-161,d.__h.length&&i.push(d),g&&(d.__E=d.__=null)}catch(e){t.__v=null,c||null!=s?(t.__e=a,t.__u|=c?160:32,s[s.indexOf(a)]=null):(t.__e=n.__e,t.__k=n.__k),Jp.__e(e,t,n)}else null==s&&t.__v===n.__v?(t.__k=n.__k,t.__e=n.__e):t.__e=Ch(n.__e,t,n,r,o,s,i,c,u);(l=Jp.diffed)&&l(t)}function Eh(e,t,n){for(var r=0;r<n.length;r++)Th(n[r],n[++r],n[++r]);Jp.__c&&Jp.__c(t,e),e.some((function(t){try{e=t.__h,t.__h=[],e.some((function(e){e.call(t)}))}catch(e){Jp.__e(e,t.__v)}}))}function Ch(e,t,n,r,o,s,i,a,c){var u,l,d,p,h,f,m,g=n.props,_=t.props,y=t.type;if("svg"===y&&(o=!0),null!=s)for(u=0;u<s.length;u++)if((h=s[u])&&"setAttribute"in h==!!y&&(y?h.localName===y:3===h.nodeType)){e=h,s[u]=null;break}if(null==e){if(null===y)return document.createTextNode(_);e=o?document.createElementNS("http://www.w3.org/2000/svg",y):document.createElement(y,_.is&&_),s=null,a=!1}if(null===y)g===_||a&&e.data===_||(e.data=_);else{if(s=s&&Xp.call(e.childNodes),g=n.props||nh,!a&&null!=s)for(g={},u=0;u<e.attributes.length;u++)g[(h=e.attributes[u]).name]=h.value;for(u in g)h=g[u],"children"==u||("dangerouslySetInnerHTML"==u?d=h:"key"===u||u in _||Sh(e,u,null,h,o));for(u in _)h=_[u],"children"==u?p=h:"dangerouslySetInnerHTML"==u?l=h:"value"==u?f=h:"checked"==u?m=h:"key"===u||a&&"function"!=typeof h||g[u]===h||Sh(e,u,h,g[u],o);if(l)a||d&&(l.__html===d.__html||l.__html===e.innerHTML)||(e.innerHTML=l.__html),t.__k=[];else if(d&&(e.innerHTML=""),_h(e,sh(p)?p:[p],t,n,r,o&&"foreignObject"!==y,s,i,s?s[0]:n.__k&&ph(n,0),a,c),null!=s)for(u=s.length;u--;)null!=s[u]&&ah(s[u]);a||(u="value",void 0!==f&&(f!==e[u]||"progress"===y&&!f||"option"===y&&f!==g[u])&&Sh(e,u,f,g[u],!1),u="checked",void 0!==m&&m!==e[u]&&Sh(e,u,m,g[u],!1))}return e}function Th(e,t,n){try{"function"==typeof e?e(t):e.current=t}catch(e){Jp.__e(e,n)}}function Ih(e,t,n){var r,o;if(Jp.unmount&&Jp.unmount(e),(r=e.ref)&&(r.current&&r.current!==e.__e||Th(r,null,t)),null!=(r=e.__c)){if(r.componentWillUnmount)try{r.componentWillUnmount()}catch(e){Jp.__e(e,t)}r.base=r.__P=null,e.__c=void 0}if(r=e.__k)for(o=0;o<r.length;o++)r[o]&&Ih(r[o],t,n||"function"!=typeof e.type);n||null==e.__e||ah(e.__e),e.__=e.__e=e.__d=void 0}function Mh(e,t,n){return this.constructor(e,n)}Xp=rh.slice,Jp={__e:function(e,t,n,r){for(var o,s,i;t=t.__;)if((o=t.__c)&&!o.__)try{if((s=o.constructor)&&null!=s.getDerivedStateFromError&&(o.setState(s.getDerivedStateFromError(e)),i=o.__d),null!=o.componentDidCatch&&(o.componentDidCatch(e,r||{}),i=o.__d),i)return o.__E=o}catch(t){e=t}throw e}},Kp=0,dh.prototype.setState=function(e,t){var n;n=null!=this.__s&&this.__s!==this.state?this.__s:this.__s=ih({},this.state),"function"==typeof e&&(e=e(ih({},n),this.props)),e&&ih(n,e),null!=e&&this.__v&&(t&&this._sb.push(t),mh(this))},dh.prototype.forceUpdate=function(e){this.__v&&(this.__e=!0,e&&this.__h.push(e),mh(this))},dh.prototype.render=lh,Qp=[],eh="function"==typeof Promise?Promise.prototype.then.bind(Promise.resolve()):setTimeout,th=function(e,t){return e.__v.__b-t.__v.__b},gh.__r=0;var Rh,Ah,Nh,Oh,Dh=0,Lh=[],Ph=[],Fh=Jp,$h=Fh.__b,Bh=Fh.__r,Uh=Fh.diffed,jh=Fh.__c,Hh=Fh.unmount,zh=Fh.__;function qh(e,t){Fh.__h&&Fh.__h(Ah,e,Dh||t),Dh=0;var n=Ah.__H||(Ah.__H={__:[],__h:[]});return e>=n.__.length&&n.__.push({__V:Ph}),n.__[e]}function Wh(e){return Dh=1,Yh(nf,e)}function Yh(e,t,n){var r=qh(Rh++,2);if(r.t=e,!r.__c&&(r.__=[n?n(t):nf(void 0,t),function(e){var t=r.__N?r.__N[0]:r.__[0],n=r.t(t,e);t!==n&&(r.__N=[n,r.__[1]],r.__c.setState({}))}],r.__c=Ah,!Ah.u)){var o=function(e,t,n){if(!r.__c.__H)return!0;var o=r.__c.__H.__.filter((function(e){return!!e.__c}));if(o.every((function(e){return!e.__N})))return!s||s.call(this,e,t,n);var i=!1;return o.forEach((function(e){if(e.__N){var t=e.__[0];e.__=e.__N,e.__N=void 0,t!==e.__[0]&&(i=!0)}})),!(!i&&r.__c.props===e)&&(!s||s.call(this,e,t,n))};Ah.u=!0;var s=Ah.shouldComponentUpdate,i=Ah.componentWillUpdate;Ah.componentWillUpdate=function(e,t,n){if(this.__e){var r=s;s=void 0,o(e,t,n),s=r}i&&i.call(this,e,t,n)},Ah.shouldComponentUpdate=o}return r.__N||r.__}function Vh(e,t){var n=qh(Rh++,4);!Fh.__s&&tf(n.__H,t)&&(n.__=e,n.i=t,Ah.__h.push(n))}function Gh(e,t){var n=qh(Rh++,7);return tf(n.__H,t)?(n.__V=e(),n.i=t,n.__h=e,n.__V):n.__}function Xh(e,t){return Dh=8,Gh((function(){return e}),t)}function Jh(){for(var e;e=Lh.shift();)if(e.__P&&e.__H)try{e.__H.__h.forEach(Zh),e.__H.__h.forEach(ef),e.__H.__h=[]}catch(t){e.__H.__h=[],Fh.__e(t,e.__v)}}Fh.__b=function(e){Ah=null,$h&&$h(e)},Fh.__=function(e,t){t.__k&&t.__k.__m&&(e.__m=t.__k.__m),zh&&zh(e,t)},Fh.__r=function(e){Bh&&Bh(e),Rh=0;var t=(Ah=e.__c).__H;t&&(Nh===Ah?(t.__h=[],Ah.__h=[],t.__.forEach((function(e){e.__N&&(e.__=e.__N),e.__V=Ph,e.__N=e.i=void 0}))):(t.__h.forEach(Zh),t.__h.forEach(ef),t.__h=[],Rh=0)),Nh=Ah},Fh.diffed=function(e){Uh&&Uh(e);var t=e.__c;t&&t.__H&&(t.__H.__h.length&&(1!==Lh.push(t)&&Oh===Fh.requestAnimationFrame||((Oh=Fh.requestAnimationFrame)||Qh)(Jh)),t.__H.__.forEach((function(e){e.i&&(e.__H=e.i),e.__V!==Ph&&(e.__=e.__V),e.i=void 0,e.__V=Ph}))),Nh=Ah=null},Fh.__c=function(e,t){t.some((function(e){try{e.__h.forEach(Zh),e.__h=e.__h.filter((function(e){return!e.__||ef(e)}))}catch(n){t.some((function(e){e.__h&&(e.__h=[])})),t=[],Fh.__e(n,e.__v)}})),jh&&jh(e,t)},Fh.unmount=function(e){Hh&&Hh(e);var t,n=e.__c;n&&n.__H&&(n.__H.__.forEach((function(e){try{Zh(e)}catch(e){t=e}})),n.__H=void 0,t&&Fh.__e(t,n.__v))};var Kh="function"==typeof requestAnimationFrame;function Qh(e){var t,n=function(){clearTimeout(r),Kh&&cancelAnimationFrame(t),setTimeout(e)},r=setTimeout(n,100);Kh&&(t=requestAnimationFrame(n))}function Zh(e){var t=Ah,n=e.__c;"function"==typeof n&&(e.__c=void 0,n()),Ah=t}function ef(e){var t=Ah;e.__c=e.__(),Ah=t}function tf(e,t){return!e||e.length!==t.length||t.some((function(t,n){return t!==e[n]}))}function nf(e,t){return"function"==typeof t?t(e):t}const rf={__proto__:null,useCallback:Xh,useContext:function(e){var t=Ah.context[e.__c],n=qh(Rh++,9);return n.c=e,t?(null==n.__&&(n.__=!0,t.sub(Ah)),t.props.value):e.__},useDebugValue:function(e,t){Fh.useDebugValue&&Fh.useDebugValue(t?t(e):e)},useEffect:function(e,t){var n=qh(Rh++,3);!Fh.__s&&tf(n.__H,t)&&(n.__=e,n.i=t,Ah.__H.__h.push(n))},useErrorBoundary:function(e){var t=qh(Rh++,10),n=Wh();return t.__=e,Ah.componentDidCatch||(Ah.componentDidCatch=function(e,r){t.__&&t.__(e,r),n[1](e)}),[n[0],function(){n[1](void 0)}]},useId:function(){var e=qh(Rh++,11);if(!e.__){for(var t=Ah.__v;null!==t&&!t.__m&&null!==t.__;)t=t.__;var n=t.__m||(t.__m=[0,0]);e.__="P"+n[0]+"-"+n[1]++}return e.__},useImperativeHandle:function(e,t,n){Dh=6,Vh((function(){return"function"==typeof e?(e(t()),function(){return e(null)}):e?(e.current=t(),function(){return e.current=null}):void 0}),null==n?n:n.concat(e))},useLayoutEffect:Vh,useMemo:Gh,useReducer:Yh,useRef:function(e){return Dh=5,Gh((function(){return{current:e}}),[])},useState:Wh},of="http://www.w3.org/2000/svg";function sf(){const e=e=>Op.createElementNS(of,e),t=Up(e("svg"),{width:"32",height:"30",viewBox:"0 0 72 66",fill:"inherit"}),n=Up(e("path"),{transform:"translate(11, 11)",d:"M29,2.26a4.67,4.67,0,0,0-8,0L14.42,13.53A32.21,32.21,0,0,1,32.17,40.19H27.55A27.68,27.68,0,0,0,12.09,17.47L6,28a15.92,15.92,0,0,1,9.23,12.17H4.62A.76.76,0,0,1,4,39.06l2.94-5a10.74,10.74,0,0,0-3.36-1.9l-2.91,5a4.54,4.54,0,0,0,1.69,6.24A4.66,4.66,0,0,0,4.62,44H19.15a19.4,19.4,0,0,0-8-17.31l2.31-4A23.87,23.87,0,0,1,23.76,44H36.07a35.88,35.88,0,0,0-16.41-31.8l4.67-8a.77.77,0,0,1,1.05-.27c.53.29,20.29,34.77,20.66,35.17a.76.76,0,0,1-.68,1.13H40.6q.09,1.91,0,3.81h4.78A4.59,4.59,0,0,0,50,39.43a4.49,4.49,0,0,0-.62-2.28Z"});return t.appendChild(n),t}const af="/home/runner/work/sentry-javascript/sentry-javascript/packages/feedback/src/modal/components/DialogHeader.tsx";function cf({options:e}){const t=Gh((()=>({__html:sf().outerHTML})),[]);return ch("h2",{class:"dialog__header",__self:this,__source:{fileName:af,lineNumber:16}},e.formTitle,e.showBranding?ch("a",{class:"brand-link",target:"_blank",href:"https://sentry.io/welcome/",title:"Powered by Sentry",rel:"noopener noreferrer",dangerouslySetInnerHTML:t,__self:this,__source:{fileName:af,lineNumber:19}}):null)}const uf="/home/runner/work/sentry-javascript/sentry-javascript/packages/feedback/src/modal/components/Form.tsx";function lf(e,t){const n=e.get(t);return"string"==typeof n?n.trim():""}function df({options:e,defaultEmail:t,defaultName:n,onFormClose:r,onSubmit:o,onSubmitSuccess:s,onSubmitError:i,showEmail:a,showName:c,screenshotInput:u}){const{tags:l,addScreenshotButtonLabel:d,removeScreenshotButtonLabel:p,cancelButtonLabel:h,emailLabel:f,emailPlaceholder:m,isEmailRequired:g,isNameRequired:_,messageLabel:y,messagePlaceholder:v,nameLabel:b,namePlaceholder:S,submitButtonLabel:w,isRequiredLabel:k}=e,[x,E]=Wh(null),[C,T]=Wh(!1),I=u&&u.input,[M,R]=Wh(null),A=Xh((e=>{R(e),T(!1)}),[]),N=Xh((e=>{const t=function(e,t){const n=[];return t.isNameRequired&&!e.name&&n.push(t.nameLabel),t.isEmailRequired&&!e.email&&n.push(t.emailLabel),e.message||n.push(t.messageLabel),n}(e,{emailLabel:f,isEmailRequired:g,isNameRequired:_,messageLabel:y,nameLabel:b});return t.length>0?E(`Please enter in the following required fields: ${t.join(", ")}`):E(null),0===t.length}),[f,g,_,y,b]),O=Xh((async e=>{try{if(e.preventDefault(),!(e.target instanceof HTMLFormElement))return;const t=new FormData(e.target),n=await(u&&C?u.value():void 0),r={name:lf(t,"name"),email:lf(t,"email"),message:lf(t,"message"),attachments:n?[n]:void 0};if(!N(r))return;try{await o({name:r.name,email:r.email,message:r.message,source:Pp,tags:l},{attachments:r.attachments}),s(r)}catch(e){$p&&L.error(e),E(e),i(e)}}catch(e){}}),[u&&C,s,i]);return ch("form",{class:"form",onSubmit:O,__self:this,__source:{fileName:uf,lineNumber:144}},I&&C?ch(I,{onError:A,__self:this,__source:{fileName:uf,lineNumber:146}}):null,ch("div",{class:"form__right","data-sentry-feedback":!0,__self:this,__source:{fileName:uf,lineNumber:149}},ch("div",{class:"form__top",__self:this,__source:{fileName:uf,lineNumber:150}},x?ch("div",{class:"form__error-container",__self:this,__source:{fileName:uf,lineNumber:151}},x):null,c?ch("label",{for:"name",class:"form__label",__self:this,__source:{fileName:uf,lineNumber:154}},ch(pf,{label:b,isRequiredLabel:k,isRequired:_,__self:this,__source:{fileName:uf,lineNumber:155}}),ch("input",{class:"form__input",defaultValue:n,id:"name",name:"name",placeholder:S,required:_,type:"text",__self:this,__source:{fileName:uf,lineNumber:156}})):ch("input",{"aria-hidden":!0,value:n,name:"name",type:"hidden",__self:this,__source:{fileName:uf,lineNumber:167}}),a?ch("label",{for:"email",class:"form__label",__self:this,__source:{fileName:uf,lineNumber:171}},ch(pf,{label:f,isRequiredLabel:k,isRequired:g,__self:this,__source:{fileName:uf,lineNumber:172}}),ch("input",{class:"form__input",defaultValue:t,id:"email",name:"email",placeholder:m,required:g,type:"email",__self:this,__source:{fileName:uf,lineNumber:173}})):ch("input",{"aria-hidden":!0,value:t,name:"email",type:"hidden",__self:this,__source:{fileName:uf,lineNumber:184}}),ch("label",{for:"message",class:"form__label",__self:this,__source:{fileName:uf,lineNumber:187}},ch(pf,{label:y,isRequiredLabel:k,isRequired:!0,__self:this,__source:{fileName:uf,lineNumber:188}}),ch("textarea",{autoFocus:!0,class:"form__input form__input--textarea",id:"message",name:"message",placeholder:v,required:!0,rows:5,__self:this,__source:{fileName:uf,lineNumber:189}})),I?ch("label",{for:"screenshot",class:"form__label",__self:this,__source:{fileName:uf,lineNumber:201}},ch("button",{class:"btn btn--default",type:"button",onClick:()=>{R(null),T((e=>!e))},__self:this,__source:{fileName:uf,lineNumber:202}},C?p:d),M?ch("div",{class:"form__error-container",__self:this,__source:{fileName:uf,lineNumber:212}},M.message):null):null),ch("div",{class:"btn-group",__self:this,__source:{fileName:uf,lineNumber:216}},ch("button",{class:"btn btn--primary",type:"submit",__self:this,__source:{fileName:uf,lineNumber:217}},w),ch("button",{class:"btn btn--default",type:"button",onClick:r,__self:this,__source:{fileName:uf,lineNumber:220}},h))))}function pf({label:e,isRequired:t,isRequiredLabel:n}){return ch("span",{class:"form__label__text",__self:this,__source:{fileName:uf,lineNumber:239}},e,t&&ch("span",{class:"form__label__text--required",__self:this,__source:{fileName:uf,lineNumber:241}},n))}const hf=16,ff=17,mf="http://www.w3.org/2000/svg";function gf(){const e=e=>Np.document.createElementNS(mf,e),t=Up(e("svg"),{width:`${hf}`,height:`${ff}`,viewBox:`0 0 ${hf} ${ff}`,fill:"inherit"}),n=Up(e("g"),{clipPath:"url(#clip0_57_156)"}),r=Up(e("path"),{"fill-rule":"evenodd","clip-rule":"evenodd",d:"M3.55544 15.1518C4.87103 16.0308 6.41775 16.5 8 16.5C10.1217 16.5 12.1566 15.6571 13.6569 14.1569C15.1571 12.6566 16 10.6217 16 8.5C16 6.91775 15.5308 5.37103 14.6518 4.05544C13.7727 2.73985 12.5233 1.71447 11.0615 1.10897C9.59966 0.503466 7.99113 0.34504 6.43928 0.653721C4.88743 0.962403 3.46197 1.72433 2.34315 2.84315C1.22433 3.96197 0.462403 5.38743 0.153721 6.93928C-0.15496 8.49113 0.00346625 10.0997 0.608967 11.5615C1.21447 13.0233 2.23985 14.2727 3.55544 15.1518ZM4.40546 3.1204C5.46945 2.40946 6.72036 2.03 8 2.03C9.71595 2.03 11.3616 2.71166 12.575 3.92502C13.7883 5.13838 14.47 6.78405 14.47 8.5C14.47 9.77965 14.0905 11.0306 13.3796 12.0945C12.6687 13.1585 11.6582 13.9878 10.476 14.4775C9.29373 14.9672 7.99283 15.0953 6.73777 14.8457C5.48271 14.596 4.32987 13.9798 3.42502 13.075C2.52018 12.1701 1.90397 11.0173 1.65432 9.76224C1.40468 8.50718 1.5328 7.20628 2.0225 6.02404C2.5122 4.8418 3.34148 3.83133 4.40546 3.1204Z"}),o=Up(e("path"),{d:"M6.68775 12.4297C6.78586 12.4745 6.89218 12.4984 7 12.5C7.11275 12.4955 7.22315 12.4664 7.32337 12.4145C7.4236 12.3627 7.51121 12.2894 7.58 12.2L12 5.63999C12.0848 5.47724 12.1071 5.28902 12.0625 5.11098C12.0178 4.93294 11.9095 4.77744 11.7579 4.67392C11.6064 4.57041 11.4221 4.52608 11.24 4.54931C11.0579 4.57254 10.8907 4.66173 10.77 4.79999L6.88 10.57L5.13 8.56999C5.06508 8.49566 4.98613 8.43488 4.89768 8.39111C4.80922 8.34735 4.713 8.32148 4.61453 8.31498C4.51605 8.30847 4.41727 8.32147 4.32382 8.35322C4.23038 8.38497 4.14413 8.43484 4.07 8.49999C3.92511 8.63217 3.83692 8.81523 3.82387 9.01092C3.81083 9.2066 3.87393 9.39976 4 9.54999L6.43 12.24C6.50187 12.3204 6.58964 12.385 6.68775 12.4297Z"});t.appendChild(n).append(o,r);const s=e("defs"),i=Up(e("clipPath"),{id:"clip0_57_156"}),a=Up(e("rect"),{width:`${hf}`,height:`${hf}`,fill:"white",transform:"translate(0 0.5)"});return i.appendChild(a),s.appendChild(i),t.appendChild(s).appendChild(i).appendChild(a),t}const _f="/home/runner/work/sentry-javascript/sentry-javascript/packages/feedback/src/modal/components/Dialog.tsx";function yf({open:e,onFormSubmitted:t,...n}){const r=n.options,o=Gh((()=>({__html:gf().outerHTML})),[]),[s,i]=Wh(null),a=Xh((()=>{s&&(clearTimeout(s),i(null)),t()}),[s]),c=Xh((e=>{n.onSubmitSuccess(e),i(setTimeout((()=>{t(),i(null)}),5e3))}),[t]);return ch(lh,{__self:this,__source:{fileName:_f,lineNumber:48}},s?ch("div",{class:"success__position",onClick:a,__self:this,__source:{fileName:_f,lineNumber:50}},ch("div",{class:"success__content",__self:this,__source:{fileName:_f,lineNumber:51}},r.successMessageText,ch("span",{class:"success__icon",dangerouslySetInnerHTML:o,__self:this,__source:{fileName:_f,lineNumber:53}}))):ch("dialog",{class:"dialog",onClick:r.onFormClose,open:e,__self:this,__source:{fileName:_f,lineNumber:57}},ch("div",{class:"dialog__position",__self:this,__source:{fileName:_f,lineNumber:58}},ch("div",{class:"dialog__content",onClick:e=>{e.stopPropagation()},__self:this,__source:{fileName:_f,lineNumber:59}},ch(cf,{options:r,__self:this,__source:{fileName:_f,lineNumber:66}}),ch(df,{...n,onSubmitSuccess:c,__self:this,__source:{fileName:_f,lineNumber:67}})))))}const vf=()=>({name:"FeedbackModal",setupOnce(){},createDialog:({options:e,screenshotIntegration:t,sendFeedback:n,shadow:r})=>{const o=r,s=e.useSentryUser,i=function(){const e=Gt().getUser(),t=Xt().getUser(),n=Jt().getUser();return e&&Object.keys(e).length?e:t&&Object.keys(t).length?t:n}(),a=Op.createElement("div"),c=function(e){const t=Op.createElement("style");return t.textContent="\n:host {\n  --dialog-inset: var(--inset);\n}\n\n\n.dialog {\n  position: fixed;\n  z-index: var(--z-index);\n  margin: 0;\n  inset: 0;\n\n  display: flex;\n  align-items: center;\n  justify-content: center;\n  padding: 0;\n  height: 100vh;\n  width: 100vw;\n\n  color: var(--dialog-color, var(--foreground));\n  fill: var(--dialog-color, var(--foreground));\n  line-height: 1.75em;\n\n  background-color: rgba(0, 0, 0, 0.05);\n  border: none;\n  inset: 0;\n  opacity: 1;\n  transition: opacity 0.2s ease-in-out;\n}\n\n.dialog__position {\n  position: fixed;\n  z-index: var(--z-index);\n  inset: var(--dialog-inset);\n  padding: var(--page-margin);\n  display: flex;\n  max-height: calc(100vh - (2 * var(--page-margin)));\n}\n@media (max-width: 600px) {\n  .dialog__position {\n    inset: var(--page-margin);\n    padding: 0;\n  }\n}\n\n.dialog__position:has(.editor) {\n  inset: var(--page-margin);\n  padding: 0;\n}\n\n.dialog:not([open]) {\n  opacity: 0;\n  pointer-events: none;\n  visibility: hidden;\n}\n.dialog:not([open]) .dialog__content {\n  transform: translate(0, -16px) scale(0.98);\n}\n\n.dialog__content {\n  display: flex;\n  flex-direction: column;\n  gap: 16px;\n  padding: var(--dialog-padding, 24px);\n  max-width: 100%;\n  width: 100%;\n  max-height: 100%;\n  overflow: auto;\n\n  background: var(--dialog-background, var(--background));\n  border-radius: var(--dialog-border-radius, 20px);\n  border: var(--dialog-border, var(--border));\n  box-shadow: var(--dialog-box-shadow, var(--box-shadow));\n  transform: translate(0, 0) scale(1);\n  transition: transform 0.2s ease-in-out;\n}\n\n\n.dialog__header {\n  display: flex;\n  align-items: center;\n  justify-content: space-between;\n  font-weight: var(--dialog-header-weight, 600);\n  margin: 0;\n}\n\n.brand-link {\n  display: inline-flex;\n}\n.brand-link:focus-visible {\n  outline: var(--outline);\n}\n\n\n.form {\n  display: flex;\n  overflow: auto;\n  flex-direction: row;\n  gap: 16px;\n  flex: 1 0;\n}\n\n.form__right {\n  flex: 0 0 var(--form-width, 272px);\n  width: var(--form-width, 272px);\n  display: flex;\n  overflow: auto;\n  flex-direction: column;\n  justify-content: space-between;\n  gap: 20px;\n}\n\n@media (max-width: 600px) {\n  .form__right {\n    width: auto;\n  }\n}\n\n.form__top {\n  display: flex;\n  flex-direction: column;\n  gap: 8px;\n}\n\n.form__error-container {\n  color: var(--error-color);\n  fill: var(--error-color);\n}\n\n.form__label {\n  display: flex;\n  flex-direction: column;\n  gap: 4px;\n  margin: 0px;\n}\n\n.form__label__text {\n  display: flex;\n  gap: 4px;\n  align-items: center;\n}\n\n.form__label__text--required {\n  font-size: 0.85em;\n}\n\n.form__input {\n  font-family: inherit;\n  line-height: inherit;\n  background: transparent;\n  box-sizing: border-box;\n  border: var(--input-border, var(--border));\n  border-radius: var(--input-border-radius, 6px);\n  color: var(--input-color, inherit);\n  fill: var(--input-color, inherit);\n  font-size: var(--input-font-size, inherit);\n  font-weight: var(--input-font-weight, 500);\n  padding: 6px 12px;\n}\n\n.form__input::placeholder {\n  opacity: 0.65;\n  color: var(--input-placeholder-color, inherit);\n  filter: var(--interactive-filter);\n}\n\n.form__input:focus-visible {\n  outline: var(--input-focus-outline, var(--outline));\n}\n\n.form__input--textarea {\n  font-family: inherit;\n  resize: vertical;\n}\n\n.error {\n  color: var(--error-color);\n  fill: var(--error-color);\n}\n\n\n.btn-group {\n  display: grid;\n  gap: 8px;\n}\n\n.btn {\n  line-height: inherit;\n  border: var(--button-border, var(--border));\n  border-radius: var(--button-border-radius, 6px);\n  cursor: pointer;\n  font-family: inherit;\n  font-size: var(--button-font-size, inherit);\n  font-weight: var(--button-font-weight, 600);\n  padding: var(--button-padding, 6px 16px);\n}\n.btn[disabled] {\n  opacity: 0.6;\n  pointer-events: none;\n}\n\n.btn--primary {\n  color: var(--button-primary-color, var(--accent-foreground));\n  fill: var(--button-primary-color, var(--accent-foreground));\n  background: var(--button-primary-background, var(--accent-background));\n  border: var(--button-primary-border, var(--border));\n  border-radius: var(--button-primary-border-radius, 6px);\n  font-weight: var(--button-primary-font-weight, 500);\n}\n.btn--primary:hover {\n  color: var(--button-primary-hover-color, var(--accent-foreground));\n  fill: var(--button-primary-hover-color, var(--accent-foreground));\n  background: var(--button-primary-hover-background, var(--accent-background));\n  filter: var(--interactive-filter);\n}\n.btn--primary:focus-visible {\n  background: var(--button-primary-hover-background, var(--accent-background));\n  filter: var(--interactive-filter);\n  outline: var(--button-primary-focus-outline, var(--outline));\n}\n\n.btn--default {\n  color: var(--button-color, var(--foreground));\n  fill: var(--button-color, var(--foreground));\n  background: var(--button-background, var(--background));\n  border: var(--button-border, var(--border));\n  border-radius: var(--button-border-radius, 6px);\n  font-weight: var(--button-font-weight, 500);\n}\n.btn--default:hover {\n  color: var(--button-color, var(--foreground));\n  fill: var(--button-color, var(--foreground));\n  background: var(--button-hover-background, var(--background));\n  filter: var(--interactive-filter);\n}\n.btn--default:focus-visible {\n  background: var(--button-hover-background, var(--background));\n  filter: var(--interactive-filter);\n  outline: var(--button-focus-outline, var(--outline));\n}\n\n\n.success__position {\n  position: fixed;\n  inset: var(--dialog-inset);\n  padding: var(--page-margin);\n  z-index: var(--z-index);\n}\n.success__content {\n  background: var(--success-background, var(--background));\n  border: var(--success-border, var(--border));\n  border-radius: var(--success-border-radius, 1.7em/50%);\n  box-shadow: var(--success-box-shadow, var(--box-shadow));\n  font-weight: var(--success-font-weight, 600);\n  color: var(--success-color);\n  fill: var(--success-color);\n  padding: 12px 24px;\n  line-height: 1.75em;\n\n  display: grid;\n  align-items: center;\n  grid-auto-flow: column;\n  gap: 6px;\n  cursor: default;\n}\n\n.success__icon {\n  display: flex;\n}\n\n",e&&t.setAttribute("nonce",e),t}(e.styleNonce);let u="";const l={get el(){return a},appendToDom(){o.contains(c)||o.contains(a)||(o.appendChild(c),o.appendChild(a))},removeFromDom(){o.removeChild(a),o.removeChild(c),Op.body.style.overflow=u},open(){p(!0),e.onFormOpen&&e.onFormOpen(),u=Op.body.style.overflow,Op.body.style.overflow="hidden"},close(){p(!1),Op.body.style.overflow=u}},d=t&&t.createInput({h:ch,hooks:rf,dialog:l,options:e}),p=t=>{!function(e,t,n){var r,o,s,i;Jp.__&&Jp.__(e,t),o=(r="function"==typeof n)?null:n&&n.__k||t.__k,s=[],i=[],xh(t,e=(!r&&n||t).__k=ch(lh,null,[e]),o||nh,nh,void 0!==t.ownerSVGElement,!r&&n?[n]:o?null:t.firstChild?Xp.call(t.childNodes):null,s,!r&&n?n:o?o.__e:t.firstChild,r,i),e.__d=void 0,Eh(s,e,i)}(ch(yf,{options:e,screenshotInput:d,showName:e.showName||e.isNameRequired,showEmail:e.showEmail||e.isEmailRequired,defaultName:s&&i&&i[s.name]||"",defaultEmail:s&&i&&i[s.email]||"",onFormClose:()=>{p(!1),e.onFormClose&&e.onFormClose()},onSubmit:n,onSubmitSuccess:t=>{p(!1),e.onSubmitSuccess&&e.onSubmitSuccess(t)},onSubmitError:t=>{e.onSubmitError&&e.onSubmitError(t)},onFormSubmitted:()=>{e.onFormSubmitted&&e.onFormSubmitted()},open:t,__self:void 0,__source:{fileName:"/home/runner/work/sentry-javascript/sentry-javascript/packages/feedback/src/modal/integration.tsx",lineNumber:67}}),a)};return l}}),bf="/home/runner/work/sentry-javascript/sentry-javascript/packages/feedback/src/screenshot/components/CropCorner.tsx";function Sf(e){const t=Op.createElement("style"),n="#1A141F",r="#302735";return t.textContent=`\n.editor {\n  padding: 10px;\n  padding-top: 65px;\n  padding-bottom: 65px;\n  flex-grow: 1;\n\n  background-color: ${n};\n  background-image: repeating-linear-gradient(\n      -145deg,\n      transparent,\n      transparent 8px,\n      ${n} 8px,\n      ${n} 11px\n    ),\n    repeating-linear-gradient(\n      -45deg,\n      transparent,\n      transparent 15px,\n      ${r} 15px,\n      ${r} 16px\n    );\n}\n\n.editor__canvas-container {\n  width: 100%;\n  height: 100%;\n  position: relative;\n  display: flex;\n  align-items: center;\n  justify-content: center;\n}\n\n.editor__canvas-container canvas {\n  object-fit: contain;\n  position: relative;\n}\n\n.editor__crop-btn-group {\n  padding: 8px;\n  gap: 8px;\n  border-radius: var(--menu-border-radius, 6px);\n  background: var(--button-primary-background, var(--background));\n  width: 175px;\n  position: absolute;\n}\n\n.editor__crop-corner {\n  width: 30px;\n  height: 30px;\n  position: absolute;\n  background: none;\n  border: 3px solid #ffffff;\n}\n\n.editor__crop-corner--top-left {\n  cursor: nwse-resize;\n  border-right: none;\n  border-bottom: none;\n}\n.editor__crop-corner--top-right {\n  cursor: nesw-resize;\n  border-left: none;\n  border-bottom: none;\n}\n.editor__crop-corner--bottom-left {\n  cursor: nesw-resize;\n  border-right: none;\n  border-top: none;\n}\n.editor__crop-corner--bottom-right {\n  cursor: nwse-resize;\n  border-left: none;\n  border-top: none;\n}\n`,e&&t.setAttribute("nonce",e),t}const wf="/home/runner/work/sentry-javascript/sentry-javascript/packages/feedback/src/screenshot/components/ScreenshotEditor.tsx",kf=33,xf=Np.devicePixelRatio,Ef=e=>({x:Math.min(e.startX,e.endX),y:Math.min(e.startY,e.endY),width:Math.abs(e.startX-e.endX),height:Math.abs(e.startY-e.endY)}),Cf=e=>{const t=e.clientHeight,n=e.clientWidth,r=e.width/e.height;let o=t*r,s=t;o>n&&(o=n,s=n/r);const i=(n-o)/2,a=(t-s)/2;return{startX:i,startY:a,endX:o+i,endY:s+a}};function Tf({h:e,hooks:t,imageBuffer:n,dialog:r,options:o}){const s=function({hooks:e}){return function({onBeforeScreenshot:t,onScreenshot:n,onAfterScreenshot:r,onError:o}){e.useEffect((()=>{(async()=>{t();const e=await Dp.mediaDevices.getDisplayMedia({video:{width:Np.innerWidth*Np.devicePixelRatio,height:Np.innerHeight*Np.devicePixelRatio},audio:!1,monitorTypeSurfaces:"exclude",preferCurrentTab:!0,selfBrowserSurface:"include",surfaceSwitching:"exclude"}),o=Op.createElement("video");await new Promise(((t,r)=>{o.srcObject=e,o.onloadedmetadata=()=>{n(o),e.getTracks().forEach((e=>e.stop())),t()},o.play().catch(r)})),r()})().catch(o)}),[])}}({hooks:t});return function({onError:i}){const a=t.useMemo((()=>({__html:Sf(o.styleNonce).innerText})),[]),c=function({h:e}){return function({top:t,left:n,corner:r,onGrabButton:o}){return e("button",{class:`editor__crop-corner editor__crop-corner--${r} `,style:{top:t,left:n},onMouseDown:e=>{e.preventDefault(),o(e,r)},onClick:e=>{e.preventDefault()},__self:this,__source:{fileName:bf,lineNumber:22}})}}({h:e}),u=t.useRef(null),l=t.useRef(null),d=t.useRef(null),[p,h]=t.useState({startX:0,startY:0,endX:0,endY:0}),[f,m]=t.useState(!1),[g,_]=t.useState(!1);function y(){const e=d.current,t=Ef(Cf(n));if(e){e.width=t.width*xf,e.height=t.height*xf,e.style.width=`${t.width}px`,e.style.height=`${t.height}px`;const n=e.getContext("2d");n&&n.scale(xf,xf)}const r=l.current;r&&(r.style.width=`${t.width}px`,r.style.height=`${t.height}px`),h({startX:0,startY:0,endX:t.width,endY:t.height})}function v(e,t){m(!1),_(!0);const n=b(t),r=()=>{Op.removeEventListener("mousemove",n),Op.removeEventListener("mouseup",r),m(!0),_(!1)};Op.addEventListener("mouseup",r),Op.addEventListener("mousemove",n)}t.useEffect((()=>{Np.addEventListener("resize",y,!1)}),[]),t.useEffect((()=>{const e=d.current;if(!e)return;const t=e.getContext("2d");if(!t)return;const r=Ef(Cf(n)),o=Ef(p);t.clearRect(0,0,r.width,r.height),t.fillStyle="rgba(0, 0, 0, 0.5)",t.fillRect(0,0,r.width,r.height),t.clearRect(o.x,o.y,o.width,o.height),t.strokeStyle="#ffffff",t.lineWidth=3,t.strokeRect(o.x+1,o.y+1,o.width-2,o.height-2),t.strokeStyle="#000000",t.lineWidth=1,t.strokeRect(o.x+3,o.y+3,o.width-6,o.height-6)}),[p]);const b=t.useCallback((e=>function(t){if(!d.current)return;const n=d.current,r=n.getBoundingClientRect(),o=t.clientX-r.x,s=t.clientY-r.y;switch(e){case"top-left":h((e=>({...e,startX:Math.min(Math.max(0,o),e.endX-kf),startY:Math.min(Math.max(0,s),e.endY-kf)})));break;case"top-right":h((e=>({...e,endX:Math.max(Math.min(o,n.width/xf),e.startX+kf),startY:Math.min(Math.max(0,s),e.endY-kf)})));break;case"bottom-left":h((e=>({...e,startX:Math.min(Math.max(0,o),e.endX-kf),endY:Math.max(Math.min(s,n.height/xf),e.startY+kf)})));break;case"bottom-right":h((e=>({...e,endX:Math.max(Math.min(o,n.width/xf),e.startX+kf),endY:Math.max(Math.min(s,n.height/xf),e.startY+kf)})))}}),[]),S=t.useRef({initialX:0,initialY:0});return s({onBeforeScreenshot:t.useCallback((()=>{r.el.style.display="none"}),[]),onScreenshot:t.useCallback((e=>{const t=n.getContext("2d");if(!t)throw new Error("Could not get canvas context");n.width=e.videoWidth,n.height=e.videoHeight,n.style.width="100%",n.style.height="100%",t.drawImage(e,0,0)}),[n]),onAfterScreenshot:t.useCallback((()=>{r.el.style.display="block";const e=u.current;e&&e.appendChild(n),y()}),[]),onError:t.useCallback((e=>{r.el.style.display="block",i(e)}),[])}),e("div",{class:"editor",__self:this,__source:{fileName:wf,lineNumber:315}},e("style",{nonce:o.styleNonce,dangerouslySetInnerHTML:a,__self:this,__source:{fileName:wf,lineNumber:316}}),e("div",{class:"editor__canvas-container",ref:u,__self:this,__source:{fileName:wf,lineNumber:317}},e("div",{class:"editor__crop-container",style:{position:"absolute",zIndex:1},ref:l,__self:this,__source:{fileName:wf,lineNumber:318}},e("canvas",{onMouseDown:function(e){if(g)return;S.current={initialX:e.clientX,initialY:e.clientY};const t=e=>{const t=d.current;if(!t)return;const n=e.clientX-S.current.initialX,r=e.clientY-S.current.initialY;h((o=>{const s=Math.max(0,Math.min(o.startX+n,t.width/xf-(o.endX-o.startX))),i=Math.max(0,Math.min(o.startY+r,t.height/xf-(o.endY-o.startY))),a=s+(o.endX-o.startX),c=i+(o.endY-o.startY);return S.current.initialX=e.clientX,S.current.initialY=e.clientY,{startX:s,startY:i,endX:a,endY:c}}))},n=()=>{Op.removeEventListener("mousemove",t),Op.removeEventListener("mouseup",n)};Op.addEventListener("mousemove",t),Op.addEventListener("mouseup",n)},style:{position:"absolute",cursor:f?"move":"auto"},ref:d,__self:this,__source:{fileName:wf,lineNumber:319}}),e(c,{left:p.startX-3,top:p.startY-3,onGrabButton:v,corner:"top-left",__self:this,__source:{fileName:wf,lineNumber:324}}),e(c,{left:p.endX-30+3,top:p.startY-3,onGrabButton:v,corner:"top-right",__self:this,__source:{fileName:wf,lineNumber:330}}),e(c,{left:p.startX-3,top:p.endY-30+3,onGrabButton:v,corner:"bottom-left",__self:this,__source:{fileName:wf,lineNumber:336}}),e(c,{left:p.endX-30+3,top:p.endY-30+3,onGrabButton:v,corner:"bottom-right",__self:this,__source:{fileName:wf,lineNumber:342}}),e("div",{style:{left:Math.max(0,p.endX-191),top:Math.max(0,p.endY+8),display:f?"flex":"none"},class:"editor__crop-btn-group",__self:this,__source:{fileName:wf,lineNumber:348}},e("button",{onClick:e=>{e.preventDefault(),d.current&&h({startX:0,startY:0,endX:d.current.width/xf,endY:d.current.height/xf}),m(!1)},class:"btn btn--default",__self:this,__source:{fileName:wf,lineNumber:356}},o.cancelButtonLabel),e("button",{onClick:e=>{e.preventDefault(),function(){const e=Op.createElement("canvas"),t=Ef(Cf(n)),r=Ef(p);e.width=r.width*xf,e.height=r.height*xf;const o=e.getContext("2d");o&&n&&o.drawImage(n,r.x/t.width*n.width,r.y/t.height*n.height,r.width/t.width*n.width,r.height/t.height*n.height,0,0,e.width,e.height);const s=n.getContext("2d");s&&(s.clearRect(0,0,n.width,n.height),n.width=e.width,n.height=e.height,n.style.width=`${r.width}px`,n.style.height=`${r.height}px`,s.drawImage(e,0,0),y())}(),m(!1)},class:"btn btn--primary",__self:this,__source:{fileName:wf,lineNumber:373}},o.confirmButtonLabel)))))}}const If=()=>({name:"FeedbackScreenshot",setupOnce(){},createInput:({h:e,hooks:t,dialog:n,options:r})=>{const o=Op.createElement("canvas");return{input:Tf({h:e,hooks:t,imageBuffer:o,dialog:n,options:r}),value:async()=>{const e=await new Promise((e=>{o.toBlob(e,"image/png")}));if(e){return{data:new Uint8Array(await e.arrayBuffer()),filename:"screenshot.png",contentType:"application/png"}}}}}}),Mf=Gp({lazyLoadIntegration:Fa}),Rf=Gp({lazyLoadIntegration:Fa,getModalIntegration:()=>vf,getScreenshotIntegration:()=>If});const Af={increment:function(e,t=1,n){Ho.increment(Xo,e,t,n)},distribution:function(e,t,n){Ho.distribution(Xo,e,t,n)},set:function(e,t,n){Ho.set(Xo,e,t,n)},gauge:function(e,t,n){Ho.gauge(Xo,e,t,n)},timing:function(e,t,n="second",r){return Ho.timing(Xo,e,t,n,r)}},Nf=new WeakMap,Of=new Map,Df={traceFetch:!0,traceXHR:!0,enableHTTPTimings:!0};function Lf(e,t){const{traceFetch:n,traceXHR:r,shouldCreateSpanForRequest:o,enableHTTPTimings:s,tracePropagationTargets:i}={traceFetch:Df.traceFetch,traceXHR:Df.traceXHR,...t},a="function"==typeof o?o:e=>!0,c=e=>function(e,t){const n=Zo.location&&Zo.location.href;if(n){let r,o;try{r=new URL(e,n),o=new URL(n).origin}catch(e){return!1}const s=r.origin===o;return t?_(r.toString(),t)||s&&_(r.pathname,t):s}{const n=!!e.match(/^\/(?!\/)/);return t?_(e,t):n}}(e,i),u={};n&&(e.addEventProcessor((e=>("transaction"===e.type&&e.spans&&e.spans.forEach((e=>{if("http.client"===e.op){const t=Of.get(e.span_id);t&&(e.timestamp=t/1e3,Of.delete(e.span_id))}})),e))),function(e){const t="fetch-body-resolved";ce(t,e),ue(t,(()=>we(ke)))}((e=>{if(e.response){const t=Nf.get(e.response);t&&e.endTimestamp&&Of.set(t,e.endTimestamp)}})),Se((e=>{const t=Jo(e,a,c,u);if(e.response&&e.fetchData.__span&&Nf.set(e.response,e.fetchData.__span),t){const n=$f(e.fetchData.url),r=n?Ze(n).host:void 0;t.setAttributes({"http.url":n,"server.address":r})}s&&t&&Pf(t)}))),r&&Ji((e=>{const t=function(e,t,n,r){const o=e.xhr,s=o&&o[Xi];if(!o||o.__sentry_own_request__||!s)return;const i=Fn()&&t(s.url);if(e.endTimestamp&&i){const e=o.__sentry_xhr_span_id__;if(!e)return;const t=r[e];return void(t&&void 0!==s.status_code&&(fn(t,s.status_code),t.end(),delete r[e]))}const a=$f(s.url),c=a?Ze(a).host:void 0,u=!!Mn(),l=i&&u?Zn({name:`${s.method} ${s.url}`,attributes:{type:"xhr","http.method":s.method,"http.url":a,url:s.url,"server.address":c,[on]:"auto.http.browser",[rn]:"http.client"}}):new $n;o.__sentry_xhr_span_id__=l.spanContext().spanId,r[o.__sentry_xhr_span_id__]=l;const d=Qt();o.setRequestHeader&&n(s.url)&&d&&function(e,t,n){const r=Gt(),o=Xt(),{traceId:s,spanId:i,sampled:a,dsc:c}={...o.getPropagationContext(),...r.getPropagationContext()},u=n&&Fn()?yn(n):dt(s,i,a),l=at(c||(n?qn(n):zn(s,t)));!function(e,t,n){try{e.setRequestHeader("sentry-trace",t),n&&e.setRequestHeader(nt,n)}catch(e){}}(e,u,l)}(o,d,Fn()&&u?l:void 0);return l}(e,a,c,u);s&&t&&Pf(t)}))}function Pf(e){const{url:t}=Sn(e).data||{};if(!t||"string"!=typeof t)return;const n=li("resource",(({entries:r})=>{r.forEach((r=>{if(function(e){return"resource"===e.entryType&&"initiatorType"in e&&"string"==typeof e.nextHopProtocol&&("fetch"===e.initiatorType||"xmlhttprequest"===e.initiatorType)}(r)&&r.name.endsWith(t)){(function(e){const{name:t,version:n}=function(e){let t="unknown",n="unknown",r="";for(const o of e){if("/"===o){[t,n]=e.split("/");break}if(!isNaN(Number(o))){t="h"===r?"http":r,n=e.split(r)[1];break}r+=o}r===e&&(t=r);return{name:t,version:n}}(e.nextHopProtocol),r=[];if(r.push(["network.protocol.version",n],["network.protocol.name",t]),!be)return r;return[...r,["http.request.redirect_start",Ff(e.redirectStart)],["http.request.fetch_start",Ff(e.fetchStart)],["http.request.domain_lookup_start",Ff(e.domainLookupStart)],["http.request.domain_lookup_end",Ff(e.domainLookupEnd)],["http.request.connect_start",Ff(e.connectStart)],["http.request.secure_connection_start",Ff(e.secureConnectionStart)],["http.request.connection_end",Ff(e.connectEnd)],["http.request.request_start",Ff(e.requestStart)],["http.request.response_start",Ff(e.responseStart)],["http.request.response_end",Ff(e.responseEnd)]]})(r).forEach((t=>e.setAttribute(...t))),setTimeout(n)}}))}))}function Ff(e=0){return((be||performance.timeOrigin)+e)/1e3}function $f(e){try{return new URL(e,Zo.location.origin).href}catch(e){return}}const Bf={...ar,instrumentNavigation:!0,instrumentPageLoad:!0,markBackgroundSpan:!0,enableLongTask:!0,enableLongAnimationFrame:!0,enableInp:!0,_experiments:{},...Df};function Uf(e,t,n){e.emit("startPageLoadSpan",t,n),Gt().setTransactionName(t.name);const r=Mn();return"pageload"===(r&&Sn(r).op)?r:void 0}function jf(e,t){Xt().setPropagationContext(Rt()),Gt().setPropagationContext(Rt()),e.emit("startNavigationSpan",t),Gt().setTransactionName(t.name);const n=Mn();return"navigation"===(n&&Sn(n).op)?n:void 0}function Hf(e){const t=(n=`meta[name=${e}]`,E.document&&E.document.querySelector?E.document.querySelector(n):null);var n;return t?t.getAttribute("content"):void 0}function zf(e){return new Promise(((t,n)=>{e.oncomplete=e.onsuccess=()=>t(e.result),e.onabort=e.onerror=()=>n(e.error)}))}function qf(e){return zf(e.getAllKeys())}function Wf(e){let t;function n(){return null==t&&(t=function(e,t){const n=indexedDB.open(e);n.onupgradeneeded=()=>n.result.createObjectStore(t);const r=zf(n);return e=>r.then((n=>e(n.transaction(t,"readwrite").objectStore(t))))}(e.dbName||"sentry-offline",e.storeName||"queue")),t}return{push:async t=>{try{const r=await _t(t);await function(e,t,n){return e((e=>qf(e).then((r=>{if(!(r.length>=n))return e.put(t,Math.max(...r,0)+1),zf(e.transaction)}))))}(n(),r,e.maxQueueSize||30)}catch(e){}},unshift:async t=>{try{const r=await _t(t);await function(e,t,n){return e((e=>qf(e).then((r=>{if(!(r.length>=n))return e.put(t,Math.min(...r,0)-1),zf(e.transaction)}))))}(n(),r,e.maxQueueSize||30)}catch(e){}},shift:async()=>{try{const e=await function(e){return e((e=>qf(e).then((t=>{const n=t[0];if(null!=n)return zf(e.get(n)).then((t=>(e.delete(n),zf(e.transaction).then((()=>t)))))}))))}(n());if(e)return yt(e)}catch(e){}}}}const Yf=1e6,Vf=String(0),Gf="main";let Xf="",Jf="",Kf="",Qf=Zo.navigator&&Zo.navigator.userAgent||"",Zf="";const em=Zo.navigator&&Zo.navigator.language||Zo.navigator&&Zo.navigator.languages&&Zo.navigator.languages[0]||"";const tm=Zo.navigator&&Zo.navigator.userAgentData;var nm;function rm(e){return function(e){return!("thread_metadata"in e)}(e)?function(e){let t,n=0;const r={samples:[],stacks:[],frames:[],thread_metadata:{[Vf]:{name:Gf}}},o=e.samples[0];if(!o)return r;const s=o.timestamp,i="number"==typeof performance.timeOrigin?performance.timeOrigin:be||0,a=i-(be||i);return e.samples.forEach(((o,i)=>{if(void 0===o.stackId)return void 0===t&&(t=n,r.stacks[t]=[],n++),void(r.samples[i]={elapsed_since_start_ns:((o.timestamp+a-s)*Yf).toFixed(0),stack_id:t,thread_id:Vf});let c=e.stacks[o.stackId];const u=[];for(;c;){u.push(c.frameId);const t=e.frames[c.frameId];t&&void 0===r.frames[c.frameId]&&(r.frames[c.frameId]={function:t.name,abs_path:"number"==typeof t.resourceId?e.resources[t.resourceId]:void 0,lineno:t.line,colno:t.column}),c=void 0===c.parentId?void 0:e.stacks[c.parentId]}const l={elapsed_since_start_ns:((o.timestamp+a-s)*Yf).toFixed(0),stack_id:n,thread_id:Vf};r.stacks[n]=u,r.samples[i]=l,n++})),r}(e):e}function om(e,t,n,r){if("transaction"!==r.type)throw new TypeError("Profiling events may only be attached to transactions, this should never occur.");if(null==n)throw new TypeError(`Cannot construct profiling event envelope without a valid profile. Got ${n} instead.`);const o=function(e){const t=e&&e.contexts&&e.contexts.trace&&e.contexts.trace.trace_id;return"string"==typeof t&&32!==t.length&&rs&&L.log(`[Profiling] Invalid traceId: ${t} on profiled event`),"string"!=typeof t?"":t}(r),s=rm(n),i=t||("number"==typeof r.start_timestamp?1e3*r.start_timestamp:1e3*ve()),a="number"==typeof r.timestamp?1e3*r.timestamp:1e3*ve();return{event_id:e,timestamp:new Date(i).toISOString(),platform:"javascript",version:"1",release:r.release||"",environment:r.environment||Un,runtime:{name:"javascript",version:Zo.navigator.userAgent},os:{name:Xf,version:Jf,build_number:Qf},device:{locale:em,model:Zf,manufacturer:Qf,architecture:Kf,is_emulator:!1},debug_meta:{images:am(n.resources)},profile:s,transactions:[{name:r.transaction||"",id:r.event_id||De(),trace_id:o,active_thread_id:Vf,relative_start_ns:"0",relative_end_ns:(1e6*(a-i)).toFixed(0)}]}}function sm(e){return"pageload"===Sn(e).op}"object"==typeof(nm=tm)&&null!==nm&&"getHighEntropyValues"in nm&&tm.getHighEntropyValues(["architecture","model","platform","platformVersion","fullVersionList"]).then((e=>{if(Xf=e.platform||"",Kf=e.architecture||"",Zf=e.model||"",Jf=e.platformVersion||"",e.fullVersionList&&e.fullVersionList.length>0){const t=e.fullVersionList[e.fullVersionList.length-1];Qf=`${t.brand} ${t.version}`}})).catch((e=>{}));const im=new WeakMap;function am(e){const t=k._sentryDebugIds;if(!t)return[];const n=Qt(),r=n&&n.getOptions(),o=r&&r.stackParser;if(!o)return[];let s;const i=im.get(o);i?s=i:(s=new Map,im.set(o,s));const a=Object.keys(t).reduce(((e,n)=>{let r;const i=s.get(n);i?r=i:(r=o(n),s.set(n,r));for(let o=r.length-1;o>=0;o--){const s=r[o],i=s&&s.filename;if(s&&i){e[i]=t[n];break}}return e}),{}),c=[];for(const t of e)t&&a[t]&&c.push({type:"sourcemap",code_file:t,debug_id:a[t]});return c}let cm=!1;const um=3e4;function lm(e){if(cm)return rs&&L.log("[Profiling] Profiling has been disabled for the duration of the current user session."),!1;if(!e.isRecording())return rs&&L.log("[Profiling] Discarding profile because transaction was not sampled."),!1;const t=Qt(),n=t&&t.getOptions();if(!n)return rs&&L.log("[Profiling] Profiling disabled, no options found."),!1;const r=n.profilesSampleRate;if(!("number"!=typeof(o=r)&&"boolean"!=typeof o||"number"==typeof o&&isNaN(o)?(rs&&L.warn(`[Profiling] Invalid sample rate. Sample rate must be a boolean or a number between 0 and 1. Got ${JSON.stringify(o)} of type ${JSON.stringify(typeof o)}.`),0):!0===o||!1===o||!(o<0||o>1)||(rs&&L.warn(`[Profiling] Invalid sample rate. Sample rate must be between 0 and 1. Got ${o}.`),0)))return rs&&L.warn("[Profiling] Discarding profile because of invalid sample rate."),!1;var o;if(!r)return rs&&L.log("[Profiling] Discarding profile because a negative sampling decision was inherited or profileSampleRate is set to 0"),!1;return!!(!0===r||Math.random()<r)||(rs&&L.log(`[Profiling] Discarding profile because it's not included in the random sample (sampling rate = ${Number(r)})`),!1)}function dm(e,t,n,r){return function(e){return e.samples.length<2?(rs&&L.log("[Profiling] Discarding profile because it contains less than 2 samples"),!1):!!e.frames.length||(rs&&L.log("[Profiling] Discarding profile because it contains no frames"),!1)}(n)?om(e,t,n,r):null}const pm=new Map;function hm(e){const t=pm.get(e);return t&&pm.delete(e),t}function fm(e){let t;sm(e)&&(t=1e3*ve());const n=function(){const e=Zo.Profiler;if("function"!=typeof e)return void(rs&&L.log("[Profiling] Profiling is not supported by this browser, Profiler interface missing on window object."));const t=Math.floor(um/10);try{return new e({sampleInterval:10,maxBufferSize:t})}catch(e){rs&&(L.log("[Profiling] Failed to initialize the Profiling constructor, this is likely due to a missing 'Document-Policy': 'js-profiling' header."),L.log("[Profiling] Disabling profiling for current user session.")),cm=!0}}();if(!n)return;rs&&L.log(`[Profiling] started profiling span: ${Sn(e).description}`);const r=De();async function o(){if(e&&n)return n.stop().then((t=>{s&&(Zo.clearTimeout(s),s=void 0),rs&&L.log(`[Profiling] stopped profiling of span: ${Sn(e).description}`),t?function(e,t){if(pm.set(e,t),pm.size>30){const e=pm.keys().next().value;pm.delete(e)}}(r,t):rs&&L.log(`[Profiling] profiler returned null profile for: ${Sn(e).description}`,"this may indicate an overlapping span or a call to stopProfiling with a profile title that was never started")})).catch((e=>{rs&&L.log("[Profiling] error while stopping profiler:",e)}))}Gt().setContext("profile",{profile_id:r,start_timestamp:t});let s=Zo.setTimeout((()=>{rs&&L.log("[Profiling] max profile duration elapsed, stopping profiling for:",Sn(e).description),o()}),um);const i=e.end.bind(e);e.end=function(){return e?(o().then((()=>{i()}),(()=>{i()})),e):i()}}const mm=()=>({name:"BrowserProfiling",setup(e){const t=Mn(),n=t&&In(t);n&&sm(n)&&lm(n)&&fm(n),e.on("spanStart",(e=>{e===In(e)&&lm(e)&&fm(e)})),e.on("beforeEnvelope",(e=>{if(!pm.size)return;const t=function(e){const t=[];return ft(e,((e,n)=>{if("transaction"===n)for(let n=1;n<e.length;n++){const r=e[n];r&&r.contexts&&r.contexts.profile&&r.contexts.profile.profile_id&&t.push(e[n])}})),t}(e);if(!t.length)return;const n=[];for(const e of t){const t=e&&e.contexts,r=t&&t.profile&&t.profile.profile_id,o=t&&t.profile&&t.profile.start_timestamp;if("string"!=typeof r){rs&&L.log("[Profiling] cannot find profile for a span without a profile context");continue}if(!r){rs&&L.log("[Profiling] cannot find profile for a span without a profile context");continue}t&&t.profile&&delete t.profile;const s=hm(r);if(!s){rs&&L.log(`[Profiling] Could not retrieve profile for span: ${r}`);continue}const i=dm(r,o,s,e);i&&n.push(i)}!function(e,t){if(!t.length)return e;for(const n of t)e[1].push([{type:"profile"},n])}(e,n)}))}});var gm=Object.freeze({__proto__:null,SDK_VERSION:w,SEMANTIC_ATTRIBUTE_SENTRY_OP:rn,SEMANTIC_ATTRIBUTE_SENTRY_ORIGIN:on,SEMANTIC_ATTRIBUTE_SENTRY_SAMPLE_RATE:nn,SEMANTIC_ATTRIBUTE_SENTRY_SOURCE:tn,Scope:jt,addBreadcrumb:io,addEventProcessor:Ar,addIntegration:function(e){const t=Qt();t?t.addIntegration(e):Nt&&L.warn(`Cannot add integration "${e.name}" because no SDK Client is available.`)},addTracingExtensions:function(){Nn()},captureConsoleIntegration:bo,captureEvent:kr,captureException:Sr,captureFeedback:Ko,captureMessage:wr,captureSession:Lr,close:async function(e){const t=Qt();return t?t.close(e):(Nt&&L.warn("Cannot flush events and disable SDK. No client defined."),Promise.resolve(!1))},continueTrace:({sentryTrace:e,baggage:t},n)=>Kt((r=>{const o=lt(e,t);return r.setPropagationContext(o),n()})),createTransport:Jr,debugIntegration:So,dedupeIntegration:wo,endSession:Or,extraErrorDataIntegration:Co,flush:async function(e){const t=Qt();return t?t.flush(e):(Nt&&L.warn("Cannot flush events. No client defined."),Promise.resolve(!1))},functionToStringIntegration:uo,getActiveSpan:Mn,getClient:Qt,getCurrentHub:Qo,getCurrentScope:Gt,getGlobalScope:Jt,getIsolationScope:Xt,getRootSpan:In,getSpanDescendants:Tn,getSpanStatusFromHttpCode:hn,inboundFiltersIntegration:po,isInitialized:function(){return!!Qt()},lastEventId:Rr,makeMultiplexedTransport:function(e,t){return n=>{const r=e(n),o=new Map;function s(t,r){const s=r?`${t}:${r}`:t;let i=o.get(s);if(!i){const a=$(t);if(!a)return;const c=Br(a,n.tunnel);i=r?function(e,t){return n=>{const r=e(n);return{...r,send:async e=>{const n=no(e,["event","transaction","profile","replay_event"]);return n&&(n.release=t),r.send(e)}}}}(e,r)({...n,url:c}):e({...n,url:c}),o.set(s,i)}return[t,i]}return{send:async function(e){const n=t({envelope:e,getEvent:function(t){const n=t&&t.length?t:["event"];return no(e,n)}}).map((e=>"string"==typeof e?s(e,void 0):s(e.dsn,e.release))).filter((e=>!!e)),o=n.length?n:[["",r]];return(await Promise.all(o.map((([t,n])=>n.send(function(e,t){return pt(t?{...e[0],dsn:t}:e[0],e[1])}(e,t))))))[0]},flush:async function(e){const t=[...o.values(),r];return(await Promise.all(t.map((t=>t.flush(e))))).every((e=>e))}}}},moduleMetadataIntegration:vo,parameterize:function(e,...t){const n=new String(String.raw(e,...t));return n.__sentry_template_string__=e.join("\0").replace(/%/g,"%%").replace(/\0/g,"%s"),n.__sentry_template_values__=t,n},registerSpanErrorInstrumentation:Nn,rewriteFramesIntegration:To,sessionTimingIntegration:Io,setContext:xr,setCurrentClient:Gr,setExtra:Cr,setExtras:Er,setHttpStatus:fn,setMeasurement:Yn,setTag:Ir,setTags:Tr,setUser:Mr,spanToBaggageHeader:function(e){return at(qn(e))},spanToJSON:Sn,spanToTraceHeader:yn,startInactiveSpan:Zn,startNewTrace:function(e){return Kt((t=>(t.setPropagationContext(Rt()),Nt&&L.info(`Starting a new trace with id ${t.getPropagationContext().traceId}`),er(null,e))))},startSession:Nr,startSpan:function(e,t){const n=rr();if(n.startSpan)return n.startSpan(e,t);const r=nr(e),{forceTransaction:o,parentSpan:s}=e;return Kt(e.scope,(()=>ir(s)((()=>{const n=Gt(),s=sr(n),i=e.onlyIfParent&&!s?new $n:tr({parentSpan:s,spanArguments:r,forceTransaction:o,scope:n});return $t(n,i),Bn((()=>t(i)),(()=>{const{status:e}=Sn(i);!i.isRecording()||e&&"ok"!==e||i.setStatus({code:pn,message:"internal_error"})}),(()=>i.end()))}))))},startSpanManual:Qn,thirdPartyErrorFilterIntegration:Oo,withActiveSpan:er,withIsolationScope:function(...e){const t=Vt(Ot());if(2===e.length){const[n,r]=e;return n?t.withSetIsolationScope(n,r):t.withIsolationScope(r)}return t.withIsolationScope(e[0])},withScope:Kt,zodErrorsIntegration:No,WINDOW:Zo,BrowserClient:gs,makeFetchTransport:na,chromeStackLineParser:aa,defaultStackLineParsers:_a,defaultStackParser:ya,geckoStackLineParser:la,opera10StackLineParser:fa,opera11StackLineParser:ga,winjsStackLineParser:pa,eventFromException:ls,eventFromMessage:ds,exceptionFromError:os,createUserFeedbackEnvelope:ms,captureUserFeedback:function(e){const t=Qt();t&&t.captureUserFeedback(e)},forceLoad:function(){},getDefaultIntegrations:Oa,init:Da,onLoad:function(e){e()},showReportDialog:function(e={}){if(!Zo.document)return void(rs&&L.error("Global document not defined in showReportDialog call"));const t=Gt(),n=t.getClient(),r=n&&n.getDsn();if(!r)return void(rs&&L.error("DSN not configured for showReportDialog call"));if(t&&(e.user={...t.getUser(),...e.user}),!e.eventId){const t=Rr();t&&(e.eventId=t)}const o=Zo.document.createElement("script");o.async=!0,o.crossOrigin="anonymous",o.src=function(e,t){const n=U(e);if(!n)return"";const r=`${Fr(n)}embed/error-page/`;let o=`dsn=${F(n)}`;for(const e in t)if("dsn"!==e&&"onClose"!==e)if("user"===e){const e=t.user;if(!e)continue;e.name&&(o+=`&name=${encodeURIComponent(e.name)}`),e.email&&(o+=`&email=${encodeURIComponent(e.email)}`)}else o+=`&${encodeURIComponent(e)}=${encodeURIComponent(t[e])}`;return`${r}?${o}`}(r,e),e.onLoad&&(o.onload=e.onLoad);const{onClose:s}=e;if(s){const e=t=>{if("__sentry_reportdialog_closed__"===t.data)try{s()}finally{Zo.removeEventListener("message",e)}};Zo.addEventListener("message",e)}const i=Zo.document.head||Zo.document.body;i?i.appendChild(o):rs&&L.error("Not injecting report dialog. No injection point found in HTML")},breadcrumbsIntegration:Sa,globalHandlersIntegration:Ia,httpContextIntegration:Aa,linkedErrorsIntegration:Na,browserApiErrorsIntegration:ka,lazyLoadIntegration:Fa,reportingObserverIntegration:Ua,httpClientIntegration:ja,contextLinesIntegration:Xa,getReplay:function(){const e=Qt();return e&&e.getIntegrationByName("Replay")},replayIntegration:e=>new ep(e),replayCanvasIntegration:Ap,feedbackAsyncIntegration:Mf,feedbackIntegration:Rf,feedbackSyncIntegration:Rf,getFeedback:function(){const e=Qt();return e&&e.getIntegrationByName("Feedback")},sendFeedback:Fp,metrics:Af,defaultRequestInstrumentationOptions:Df,instrumentOutgoingRequests:Lf,browserTracingIntegration:(e={})=>{Nn();const{enableInp:t,enableLongTask:n,enableLongAnimationFrame:r,_experiments:{enableInteractions:o,enableStandaloneClsSpans:s},beforeStartSpan:i,idleTimeout:a,finalTimeout:c,childSpanTimeout:u,markBackgroundSpan:l,traceFetch:d,traceXHR:p,shouldCreateSpanForRequest:h,enableHTTPTimings:f,instrumentPageLoad:m,instrumentNavigation:g}={...Bf,...e},_=Ai({recordClsStandaloneSpans:s||!1});t&&ea(),r&&k.PerformanceObserver&&PerformanceObserver.supportedEntryTypes&&PerformanceObserver.supportedEntryTypes.includes("long-animation-frame")?new PerformanceObserver((e=>{if(Mn())for(const t of e.getEntries()){if(!t.scripts[0])continue;const e=xi(be+t.startTime),n=xi(t.duration),r={[on]:"auto.ui.browser.metrics"},o=t.scripts[0],{invoker:s,invokerType:i,sourceURL:a,sourceFunctionName:c,sourceCharPosition:u}=o;r["browser.script.invoker"]=s,r["browser.script.invoker_type"]=i,a&&(r["code.filepath"]=a),c&&(r["code.function"]=c),-1!==u&&(r["browser.script.source_char_position"]=u);const l=Zn({name:"Main UI thread blocked",op:"ui.long-animation-frame",startTime:e,attributes:r});l&&l.end(e+n)}})).observe({type:"long-animation-frame",buffered:!0}):n&&li("longtask",(({entries:e})=>{if(Mn())for(const t of e){const e=xi(be+t.startTime),n=xi(t.duration),r=Zn({name:"Main UI thread blocked",op:"ui.long-task",startTime:e,attributes:{[on]:"auto.ui.browser.metrics"}});r&&r.end(e+n)}})),o&&li("event",(({entries:e})=>{if(Mn())for(const t of e)if("click"===t.name){const e=xi(be+t.startTime),n=xi(t.duration),r={name:T(t.target),op:`ui.interaction.${t.name}`,startTime:e,attributes:{[on]:"auto.ui.browser.metrics"}},o=R(t.target);o&&(r.attributes["ui.component_name"]=o);const s=Zn(r);s&&s.end(e+n)}}));const y={name:void 0,source:void 0};function v(e,t){const n="pageload"===t.op,r=i?i(t):t,o=r.attributes||{};t.name!==r.name&&(o[tn]="custom",r.attributes=o),y.name=r.name,y.source=o[tn];const l=pr(r,{idleTimeout:a,finalTimeout:c,childSpanTimeout:u,disableAutoFinish:n,beforeSpanEnd:e=>{_(),Ni(e,{recordClsOnPageloadSpan:!s})}});function d(){["interactive","complete"].includes(Zo.document.readyState)&&e.emit("idleSpanEnableAutoFinish",l)}return n&&Zo.document&&(Zo.document.addEventListener("readystatechange",(()=>{d()})),d()),l}return{name:"BrowserTracing",afterAllSetup(e){let n,r=Zo.location&&Zo.location.href;e.on("startNavigationSpan",(t=>{Qt()===e&&(n&&!Sn(n).timestamp&&(rs&&L.log(`[Tracing] Finishing current root span with op: ${Sn(n).op}`),n.end()),n=v(e,{op:"navigation",...t}))})),e.on("startPageLoadSpan",((t,r={})=>{if(Qt()!==e)return;n&&!Sn(n).timestamp&&(rs&&L.log(`[Tracing] Finishing current root span with op: ${Sn(n).op}`),n.end());const o=lt(r.sentryTrace||Hf("sentry-trace"),r.baggage||Hf("baggage"));Gt().setPropagationContext(o),n=v(e,{op:"pageload",...t})})),e.on("spanEnd",(e=>{const t=Sn(e).op;if(e!==In(e)||"navigation"!==t&&"pageload"!==t)return;const n=Gt(),r=n.getPropagationContext();n.setPropagationContext({...r,sampled:void 0!==r.sampled?r.sampled:wn(e),dsc:r.dsc||qn(e)})})),Zo.location&&(m&&Uf(e,{name:Zo.location.pathname,startTime:be?be/1e3:void 0,attributes:{[tn]:"url",[on]:"auto.pageload.browser"}}),g&&zi((({to:t,from:n})=>{void 0===n&&r&&-1!==r.indexOf(t)?r=void 0:n!==t&&(r=void 0,jf(e,{name:Zo.location.pathname,attributes:{[tn]:"url",[on]:"auto.navigation.browser"}}))}))),l&&(Zo&&Zo.document?Zo.document.addEventListener("visibilitychange",(()=>{const e=Mn();if(!e)return;const t=In(e);if(Zo.document.hidden&&t){const e="cancelled",{op:n,status:r}=Sn(t);rs&&L.log(`[Tracing] Transaction: ${e} -> since tab moved to the background, op: ${n}`),r||t.setStatus({code:pn,message:e}),t.setAttribute("sentry.cancellation_reason","document.hidden"),t.end()}})):rs&&L.warn("[Tracing] Could not set up background tab detection due to lack of global document")),o&&function(e,t,n,r){let o;const s=()=>{const s="ui.action.click",i=Mn(),a=i&&In(i);if(a){const e=Sn(a).op;if(["navigation","pageload"].includes(e))return void(rs&&L.warn(`[Tracing] Did not create ${s} span because a pageload or navigation span is in progress.`))}o&&(o.setAttribute(sn,"interactionInterrupted"),o.end(),o=void 0),r.name?o=pr({name:r.name,op:s,attributes:{[tn]:r.source||"url"}},{idleTimeout:e,finalTimeout:t,childSpanTimeout:n}):rs&&L.warn(`[Tracing] Did not create ${s} transaction because _latestRouteName is missing.`)};Zo.document&&addEventListener("click",s,{once:!1,capture:!0})}(a,c,u,y),t&&function(){const e=({entries:e})=>{const t=Mn(),n=t&&In(t);e.forEach((e=>{if(!function(e){return"duration"in e}(e)||!n)return;const t=e.interactionId;if(null!=t&&!Zi.has(t)){if(Qi.length>10){const e=Qi.shift();Zi.delete(e)}Qi.push(t),Zi.set(t,n)}}))};li("event",e),li("first-input",e)}(),Lf(e,{traceFetch:d,traceXHR:p,tracePropagationTargets:e.getOptions().tracePropagationTargets,shouldCreateSpanForRequest:h,enableHTTPTimings:f})}}},startBrowserTracingNavigationSpan:jf,startBrowserTracingPageLoadSpan:Uf,makeBrowserOfflineTransport:function(e=na){return function(e){return t=>e({...t,createStore:Wf})}(to(e))},browserProfilingIntegration:mm});async function _m(e,t={},n){return window.__TAURI_INTERNALS__.invoke(e,t,n)}"function"==typeof SuppressedError&&SuppressedError;const ym={dsn:"https://123456@dummy.dsn/0",autoSessionTracking:!1,beforeSend:async function(e){var t,n;return delete e.sdk,delete e.breadcrumbs,delete e.environment,delete e.sdkProcessingMetadata,(null===(n=null===(t=null==e?void 0:e.request)||void 0===t?void 0:t.headers)||void 0===n?void 0:n["User-Agent"])&&delete e.request.headers["User-Agent"],await _m("plugin:sentry|event",{event:e}),null},beforeBreadcrumb:function(e){var t,n;return"fetch"===e.category&&(null===(n=null===(t=e.data)||void 0===t?void 0:t.url)||void 0===n?void 0:n.includes("ipc:"))?(console.log(e),null):(_m("plugin:sentry|breadcrumb",{breadcrumb:e}),null)}};window.Sentry=gm,Da({...ym,debug:__DEBUG__})}();
+!function(){"use strict";const e=Object.prototype.toString;function t(t){switch(e.call(t)){case"[object Error]":case"[object Exception]":case"[object DOMException]":return!0;default:return d(t,Error)}}function n(t,n){return e.call(t)===`[object ${n}]`}function r(e){return n(e,"ErrorEvent")}function o(e){return n(e,"DOMError")}function s(e){return n(e,"String")}function i(e){return"object"==typeof e&&null!==e&&"__sentry_template_string__"in e&&"__sentry_template_values__"in e}function a(e){return null===e||i(e)||"object"!=typeof e&&"function"!=typeof e}function c(e){return n(e,"Object")}function u(e){return"undefined"!=typeof Event&&d(e,Event)}function l(e){return Boolean(e&&e.then&&"function"==typeof e.then)}function d(e,t){try{return e instanceof t}catch(e){return!1}}function p(e){return!("object"!=typeof e||null===e||!e.__isVue&&!e._isVue)}function h(e,t=0){return"string"!=typeof e||0===t||e.length<=t?e:`${e.slice(0,t)}...`}function f(e,t){let n=e;const r=n.length;if(r<=150)return n;t>r&&(t=r);let o=Math.max(t-60,0);o<5&&(o=0);let s=Math.min(o+140,r);return s>r-5&&(s=r),s===r&&(o=Math.max(s-140,0)),n=n.slice(o,s),o>0&&(n=`'{snip} ${n}`),s<r&&(n+=" {snip}"),n}function m(e,t){if(!Array.isArray(e))return"";const n=[];for(let t=0;t<e.length;t++){const r=e[t];try{p(r)?n.push("[VueViewModel]"):n.push(String(r))}catch(e){n.push("[value cannot be serialized]")}}return n.join(t)}function g(e,t,r=!1){return!!s(e)&&(n(t,"RegExp")?t.test(e):!!s(t)&&(r?e===t:e.includes(t)))}function _(e,t=[],n=!1){return t.some((t=>g(e,t,n)))}function y(e,t,n=250,r,o,s,i){if(!(s.exception&&s.exception.values&&i&&d(i.originalException,Error)))return;const a=s.exception.values.length>0?s.exception.values[s.exception.values.length-1]:void 0;var c,u;a&&(s.exception.values=(c=v(e,t,o,i.originalException,r,s.exception.values,a,0),u=n,c.map((e=>(e.value&&(e.value=h(e.value,u)),e)))))}function v(e,t,n,r,o,s,i,a){if(s.length>=n+1)return s;let c=[...s];if(d(r[o],Error)){b(i,a);const s=e(t,r[o]),u=c.length;S(s,o,u,a),c=v(e,t,n,r[o],o,[s,...c],s,u)}return Array.isArray(r.errors)&&r.errors.forEach(((r,s)=>{if(d(r,Error)){b(i,a);const u=e(t,r),l=c.length;S(u,`errors[${s}]`,l,a),c=v(e,t,n,r,o,[u,...c],u,l)}})),c}function b(e,t){e.mechanism=e.mechanism||{type:"generic",handled:!0},e.mechanism={...e.mechanism,..."AggregateError"===e.type&&{is_exception_group:!0},exception_id:t}}function S(e,t,n,r){e.mechanism=e.mechanism||{type:"generic",handled:!0},e.mechanism={...e.mechanism,type:"chained",source:t,exception_id:n,parent_id:r}}const w="8.30.0",k=globalThis;function x(e,t,n){const r=n||k,o=r.__SENTRY__=r.__SENTRY__||{},s=o[w]=o[w]||{};return s[e]||(s[e]=t())}const E=k,C=80;function T(e,t={}){if(!e)return"<unknown>";try{let n=e;const r=5,o=[];let s=0,i=0;const a=" > ",c=a.length;let u;const l=Array.isArray(t)?t:t.keyAttrs,d=!Array.isArray(t)&&t.maxStringLength||C;for(;n&&s++<r&&(u=I(n,l),!("html"===u||s>1&&i+o.length*c+u.length>=d));)o.push(u),i+=u.length,n=n.parentNode;return o.reverse().join(a)}catch(e){return"<unknown>"}}function I(e,t){const n=e,r=[];if(!n||!n.tagName)return"";if(E.HTMLElement&&n instanceof HTMLElement&&n.dataset){if(n.dataset.sentryComponent)return n.dataset.sentryComponent;if(n.dataset.sentryElement)return n.dataset.sentryElement}r.push(n.tagName.toLowerCase());const o=t&&t.length?t.filter((e=>n.getAttribute(e))).map((e=>[e,n.getAttribute(e)])):null;if(o&&o.length)o.forEach((e=>{r.push(`[${e[0]}="${e[1]}"]`)}));else{n.id&&r.push(`#${n.id}`);const e=n.className;if(e&&s(e)){const t=e.split(/\s+/);for(const e of t)r.push(`.${e}`)}}const i=["aria-label","type","name","title","alt"];for(const e of i){const t=n.getAttribute(e);t&&r.push(`[${e}="${t}"]`)}return r.join("")}function M(){try{return E.document.location.href}catch(e){return""}}function R(e){if(!E.HTMLElement)return null;let t=e;for(let e=0;e<5;e++){if(!t)return null;if(t instanceof HTMLElement){if(t.dataset.sentryComponent)return t.dataset.sentryComponent;if(t.dataset.sentryElement)return t.dataset.sentryElement}t=t.parentNode}return null}const A="undefined"==typeof __SENTRY_DEBUG__||__SENTRY_DEBUG__,N=["debug","info","warn","error","log","assert","trace"],O={};function D(e){if(!("console"in k))return e();const t=k.console,n={},r=Object.keys(O);r.forEach((e=>{const r=O[e];n[e]=t[e],t[e]=r}));try{return e()}finally{r.forEach((e=>{t[e]=n[e]}))}}const L=x("logger",(function(){let e=!1;const t={enable:()=>{e=!0},disable:()=>{e=!1},isEnabled:()=>e};return A?N.forEach((n=>{t[n]=(...t)=>{e&&D((()=>{k.console[n](`Sentry Logger [${n}]:`,...t)}))}})):N.forEach((e=>{t[e]=()=>{}})),t})),P=/^(?:(\w+):)\/\/(?:(\w+)(?::(\w+)?)?@)([\w.-]+)(?::(\d+))?\/(.+)/;function F(e,t=!1){const{host:n,path:r,pass:o,port:s,projectId:i,protocol:a,publicKey:c}=e;return`${a}://${c}${t&&o?`:${o}`:""}@${n}${s?`:${s}`:""}/${r?`${r}/`:r}${i}`}function $(e){const t=P.exec(e);if(!t)return void D((()=>{console.error(`Invalid Sentry Dsn: ${e}`)}));const[n,r,o="",s="",i="",a=""]=t.slice(1);let c="",u=a;const l=u.split("/");if(l.length>1&&(c=l.slice(0,-1).join("/"),u=l.pop()),u){const e=u.match(/^\d+/);e&&(u=e[0])}return B({host:s,pass:o,path:c,projectId:u,port:i,protocol:n,publicKey:r})}function B(e){return{protocol:e.protocol,publicKey:e.publicKey||"",pass:e.pass||"",host:e.host,port:e.port||"",path:e.path||"",projectId:e.projectId}}function U(e){const t="string"==typeof e?$(e):B(e);if(t&&function(e){if(!A)return!0;const{port:t,projectId:n,protocol:r}=e;return!(["protocol","publicKey","host","projectId"].find((t=>!e[t]&&(L.error(`Invalid Sentry Dsn: ${t} missing`),!0)))||(n.match(/^\d+$/)?function(e){return"http"===e||"https"===e}(r)?t&&isNaN(parseInt(t,10))&&(L.error(`Invalid Sentry Dsn: Invalid port ${t}`),1):(L.error(`Invalid Sentry Dsn: Invalid protocol ${r}`),1):(L.error(`Invalid Sentry Dsn: Invalid projectId ${n}`),1)))}(t))return t}class j extends Error{constructor(e,t="warn"){super(e),this.message=e,this.name=new.target.prototype.constructor.name,Object.setPrototypeOf(this,new.target.prototype),this.logLevel=t}}function H(e,t,n){if(!(t in e))return;const r=e[t],o=n(r);"function"==typeof o&&q(o,r),e[t]=o}function z(e,t,n){try{Object.defineProperty(e,t,{value:n,writable:!0,configurable:!0})}catch(n){A&&L.log(`Failed to add non-enumerable property "${t}" to object`,e)}}function q(e,t){try{const n=t.prototype||{};e.prototype=t.prototype=n,z(e,"__sentry_original__",t)}catch(e){}}function W(e){return e.__sentry_original__}function Y(e){if(t(e))return{message:e.message,name:e.name,stack:e.stack,...G(e)};if(u(e)){const t={type:e.type,target:V(e.target),currentTarget:V(e.currentTarget),...G(e)};return"undefined"!=typeof CustomEvent&&d(e,CustomEvent)&&(t.detail=e.detail),t}return e}function V(e){try{return t=e,"undefined"!=typeof Element&&d(t,Element)?T(e):Object.prototype.toString.call(e)}catch(e){return"<unknown>"}var t}function G(e){if("object"==typeof e&&null!==e){const t={};for(const n in e)Object.prototype.hasOwnProperty.call(e,n)&&(t[n]=e[n]);return t}return{}}function X(e){return J(e,new Map)}function J(e,t){if(function(e){if(!c(e))return!1;try{const t=Object.getPrototypeOf(e).constructor.name;return!t||"Object"===t}catch(e){return!0}}(e)){const n=t.get(e);if(void 0!==n)return n;const r={};t.set(e,r);for(const n of Object.keys(e))void 0!==e[n]&&(r[n]=J(e[n],t));return r}if(Array.isArray(e)){const n=t.get(e);if(void 0!==n)return n;const r=[];return t.set(e,r),e.forEach((e=>{r.push(J(e,t))})),r}return e}const K=50,Q="?",Z=/\(error: (.*)\)/,ee=/captureMessage|captureException/;function te(...e){const t=e.sort(((e,t)=>e[0]-t[0])).map((e=>e[1]));return(e,n=0,r=0)=>{const o=[],s=e.split("\n");for(let e=n;e<s.length;e++){const n=s[e];if(n.length>1024)continue;const i=Z.test(n)?n.replace(Z,"$1"):n;if(!i.match(/\S*Error: /)){for(const e of t){const t=e(i);if(t){o.push(t);break}}if(o.length>=K+r)break}}return function(e){if(!e.length)return[];const t=Array.from(e);/sentryWrapped/.test(ne(t).function||"")&&t.pop();t.reverse(),ee.test(ne(t).function||"")&&(t.pop(),ee.test(ne(t).function||"")&&t.pop());return t.slice(0,K).map((e=>({...e,filename:e.filename||ne(t).filename,function:e.function||Q})))}(o.slice(r))}}function ne(e){return e[e.length-1]||{}}const re="<anonymous>";function oe(e){try{return e&&"function"==typeof e&&e.name||re}catch(e){return re}}function se(e){const t=e.exception;if(t){const e=[];try{return t.values.forEach((t=>{t.stacktrace.frames&&e.push(...t.stacktrace.frames)})),e}catch(e){return}}}const ie={},ae={};function ce(e,t){ie[e]=ie[e]||[],ie[e].push(t)}function ue(e,t){ae[e]||(t(),ae[e]=!0)}function le(e,t){const n=e&&ie[e];if(n)for(const r of n)try{r(t)}catch(t){A&&L.error(`Error while triggering instrumentation handler.\nType: ${e}\nName: ${oe(r)}\nError:`,t)}}function de(e){const t="console";ce(t,e),ue(t,pe)}function pe(){"console"in k&&N.forEach((function(e){e in k.console&&H(k.console,e,(function(t){return O[e]=t,function(...t){le("console",{args:t,level:e});const n=O[e];n&&n.apply(k.console,t)}}))}))}const he=k;function fe(){if(!("fetch"in he))return!1;try{return new Headers,new Request("http://www.example.com"),new Response,!0}catch(e){return!1}}function me(e){return e&&/^function\s+\w+\(\)\s+\{\s+\[native code\]\s+\}$/.test(e.toString())}function ge(){if("string"==typeof EdgeRuntime)return!0;if(!fe())return!1;if(me(he.fetch))return!0;let e=!1;const t=he.document;if(t&&"function"==typeof t.createElement)try{const n=t.createElement("iframe");n.hidden=!0,t.head.appendChild(n),n.contentWindow&&n.contentWindow.fetch&&(e=me(n.contentWindow.fetch)),t.head.removeChild(n)}catch(e){A&&L.warn("Could not create sandbox iframe for pure fetch check, bailing to window.fetch: ",e)}return e}const _e=1e3;function ye(){return Date.now()/_e}const ve=function(){const{performance:e}=k;if(!e||!e.now)return ye;const t=Date.now()-e.now(),n=null==e.timeOrigin?t:e.timeOrigin;return()=>(n+e.now())/_e}(),be=(()=>{const{performance:e}=k;if(!e||!e.now)return;const t=36e5,n=e.now(),r=Date.now(),o=e.timeOrigin?Math.abs(e.timeOrigin+n-r):t,s=o<t,i=e.timing&&e.timing.navigationStart,a="number"==typeof i?Math.abs(i+n-r):t;return s||a<t?o<=a?e.timeOrigin:i:r})();function Se(e,t){const n="fetch";ce(n,e),ue(n,(()=>we(void 0,t)))}function we(e,n=!1){n&&!ge()||H(k,"fetch",(function(n){return function(...r){const{method:o,url:s}=function(e){if(0===e.length)return{method:"GET",url:""};if(2===e.length){const[t,n]=e;return{url:Ee(t),method:xe(n,"method")?String(n.method).toUpperCase():"GET"}}const t=e[0];return{url:Ee(t),method:xe(t,"method")?String(t.method).toUpperCase():"GET"}}(r),i={args:r,fetchData:{method:o,url:s},startTimestamp:1e3*ve()};e||le("fetch",{...i});const a=(new Error).stack;return n.apply(k,r).then((async t=>(e?e(t):le("fetch",{...i,endTimestamp:1e3*ve(),response:t}),t)),(e=>{throw le("fetch",{...i,endTimestamp:1e3*ve(),error:e}),t(e)&&void 0===e.stack&&(e.stack=a,z(e,"framesToPop",1)),e}))}}))}async function ke(e){let t;try{t=e.clone()}catch(e){return}await async function(e,t){if(e&&e.body&&e.body.getReader){const n=e.body.getReader();async function r({done:e}){if(e)return Promise.resolve();try{const e=await Promise.race([n.read(),new Promise((e=>{setTimeout((()=>{e({done:!0})}),5e3)}))]);await r(e)}catch(e){}}return n.read().then(r).then(t).catch((()=>{}))}}(t,(()=>{le("fetch-body-resolved",{endTimestamp:1e3*ve(),response:e})}))}function xe(e,t){return!!e&&"object"==typeof e&&!!e[t]}function Ee(e){return"string"==typeof e?e:e?xe(e,"url")?e.url:e.toString?e.toString():"":""}let Ce=null;function Te(e){const t="error";ce(t,e),ue(t,Ie)}function Ie(){Ce=k.onerror,k.onerror=function(e,t,n,r,o){return le("error",{column:r,error:o,line:n,msg:e,url:t}),!(!Ce||Ce.__SENTRY_LOADER__)&&Ce.apply(this,arguments)},k.onerror.__SENTRY_INSTRUMENTED__=!0}let Me=null;function Re(e){const t="unhandledrejection";ce(t,e),ue(t,Ae)}function Ae(){Me=k.onunhandledrejection,k.onunhandledrejection=function(e){return le("unhandledrejection",e),!(Me&&!Me.__SENTRY_LOADER__)||Me.apply(this,arguments)},k.onunhandledrejection.__SENTRY_INSTRUMENTED__=!0}function Ne(){return!("undefined"!=typeof __SENTRY_BROWSER_BUNDLE__&&__SENTRY_BROWSER_BUNDLE__)&&"[object process]"===Object.prototype.toString.call("undefined"!=typeof process?process:0)}function Oe(){return"undefined"!=typeof window&&(!Ne()||void 0!==k.process&&"renderer"===k.process.type)}function De(){const e=k,t=e.crypto||e.msCrypto;let n=()=>16*Math.random();try{if(t&&t.randomUUID)return t.randomUUID().replace(/-/g,"");t&&t.getRandomValues&&(n=()=>{const e=new Uint8Array(1);return t.getRandomValues(e),e[0]})}catch(e){}return([1e7]+1e3+4e3+8e3+1e11).replace(/[018]/g,(e=>(e^(15&n())>>e/4).toString(16)))}function Le(e){return e.exception&&e.exception.values?e.exception.values[0]:void 0}function Pe(e){const{message:t,event_id:n}=e;if(t)return t;const r=Le(e);return r?r.type&&r.value?`${r.type}: ${r.value}`:r.type||r.value||n||"<unknown>":n||"<unknown>"}function Fe(e,t,n){const r=e.exception=e.exception||{},o=r.values=r.values||[],s=o[0]=o[0]||{};s.value||(s.value=t||""),s.type||(s.type=n||"Error")}function $e(e,t){const n=Le(e);if(!n)return;const r=n.mechanism;if(n.mechanism={type:"generic",handled:!0,...r,...t},t&&"data"in t){const e={...r&&r.data,...t.data};n.mechanism.data=e}}function Be(e){if(e&&e.__sentry_captured__)return!0;try{z(e,"__sentry_captured__",!0)}catch(e){}return!1}function Ue(e){return Array.isArray(e)?e:[e]}function je(e,t=100,n=1/0){try{return ze("",e,t,n)}catch(e){return{ERROR:`**non-serializable** (${e})`}}}function He(e,t=3,n=102400){const r=je(e,t);return o=r,function(e){return~-encodeURI(e).split(/%..|./).length}(JSON.stringify(o))>n?He(e,t-1,n):r;var o}function ze(e,t,n=1/0,r=1/0,o=function(){const e="function"==typeof WeakSet,t=e?new WeakSet:[];return[function(n){if(e)return!!t.has(n)||(t.add(n),!1);for(let e=0;e<t.length;e++)if(t[e]===n)return!0;return t.push(n),!1},function(n){if(e)t.delete(n);else for(let e=0;e<t.length;e++)if(t[e]===n){t.splice(e,1);break}}]}()){const[s,i]=o;if(null==t||["number","boolean","string"].includes(typeof t)&&!Number.isNaN(t))return t;const a=function(e,t){try{if("domain"===e&&t&&"object"==typeof t&&t._events)return"[Domain]";if("domainEmitter"===e)return"[DomainEmitter]";if("undefined"!=typeof global&&t===global)return"[Global]";if("undefined"!=typeof window&&t===window)return"[Window]";if("undefined"!=typeof document&&t===document)return"[Document]";if(p(t))return"[VueViewModel]";if(c(n=t)&&"nativeEvent"in n&&"preventDefault"in n&&"stopPropagation"in n)return"[SyntheticEvent]";if("number"==typeof t&&t!=t)return"[NaN]";if("function"==typeof t)return`[Function: ${oe(t)}]`;if("symbol"==typeof t)return`[${String(t)}]`;if("bigint"==typeof t)return`[BigInt: ${String(t)}]`;const r=function(e){const t=Object.getPrototypeOf(e);return t?t.constructor.name:"null prototype"}(t);return/^HTML(\w*)Element$/.test(r)?`[HTMLElement: ${r}]`:`[object ${r}]`}catch(e){return`**non-serializable** (${e})`}var n}(e,t);if(!a.startsWith("[object "))return a;if(t.__sentry_skip_normalization__)return t;const u="number"==typeof t.__sentry_override_normalization_depth__?t.__sentry_override_normalization_depth__:n;if(0===u)return a.replace("object ","");if(s(t))return"[Circular ~]";const l=t;if(l&&"function"==typeof l.toJSON)try{return ze("",l.toJSON(),u-1,r,o)}catch(e){}const d=Array.isArray(t)?[]:{};let h=0;const f=Y(t);for(const e in f){if(!Object.prototype.hasOwnProperty.call(f,e))continue;if(h>=r){d[e]="[MaxProperties ~]";break}const t=f[e];d[e]=ze(e,t,u-1,r,o),h++}return i(t),d}const qe=/^(\S+:\\|\/?)([\s\S]*?)((?:\.{1,2}|[^/\\]+?|)(\.[^./\\]*|))(?:[/\\]*)$/;function We(...e){let t="",n=!1;for(let r=e.length-1;r>=-1&&!n;r--){const o=r>=0?e[r]:"/";o&&(t=`${o}/${t}`,n="/"===o.charAt(0))}return t=function(e,t){let n=0;for(let t=e.length-1;t>=0;t--){const r=e[t];"."===r?e.splice(t,1):".."===r?(e.splice(t,1),n++):n&&(e.splice(t,1),n--)}if(t)for(;n--;n)e.unshift("..");return e}(t.split("/").filter((e=>!!e)),!n).join("/"),(n?"/":"")+t||"."}function Ye(e){let t=0;for(;t<e.length&&""===e[t];t++);let n=e.length-1;for(;n>=0&&""===e[n];n--);return t>n?[]:e.slice(t,n-t+1)}function Ve(e,t){let n=function(e){const t=e.length>1024?`<truncated>${e.slice(-1024)}`:e,n=qe.exec(t);return n?n.slice(1):[]}(e)[2]||"";return t&&n.slice(-1*t.length)===t&&(n=n.slice(0,n.length-t.length)),n}var Ge;function Xe(e){return new Ke((t=>{t(e)}))}function Je(e){return new Ke(((t,n)=>{n(e)}))}!function(e){e[e.PENDING=0]="PENDING";e[e.RESOLVED=1]="RESOLVED";e[e.REJECTED=2]="REJECTED"}(Ge||(Ge={}));class Ke{constructor(e){Ke.prototype.__init.call(this),Ke.prototype.__init2.call(this),Ke.prototype.__init3.call(this),Ke.prototype.__init4.call(this),this._state=Ge.PENDING,this._handlers=[];try{e(this._resolve,this._reject)}catch(e){this._reject(e)}}then(e,t){return new Ke(((n,r)=>{this._handlers.push([!1,t=>{if(e)try{n(e(t))}catch(e){r(e)}else n(t)},e=>{if(t)try{n(t(e))}catch(e){r(e)}else r(e)}]),this._executeHandlers()}))}catch(e){return this.then((e=>e),e)}finally(e){return new Ke(((t,n)=>{let r,o;return this.then((t=>{o=!1,r=t,e&&e()}),(t=>{o=!0,r=t,e&&e()})).then((()=>{o?n(r):t(r)}))}))}__init(){this._resolve=e=>{this._setResult(Ge.RESOLVED,e)}}__init2(){this._reject=e=>{this._setResult(Ge.REJECTED,e)}}__init3(){this._setResult=(e,t)=>{this._state===Ge.PENDING&&(l(t)?t.then(this._resolve,this._reject):(this._state=e,this._value=t,this._executeHandlers()))}}__init4(){this._executeHandlers=()=>{if(this._state===Ge.PENDING)return;const e=this._handlers.slice();this._handlers=[],e.forEach((e=>{e[0]||(this._state===Ge.RESOLVED&&e[1](this._value),this._state===Ge.REJECTED&&e[2](this._value),e[0]=!0)}))}}}function Qe(e){const t=[];function n(e){return t.splice(t.indexOf(e),1)[0]||Promise.resolve(void 0)}return{$:t,add:function(r){if(!(void 0===e||t.length<e))return Je(new j("Not adding Promise because buffer limit was reached."));const o=r();return-1===t.indexOf(o)&&t.push(o),o.then((()=>n(o))).then(null,(()=>n(o).then(null,(()=>{})))),o},drain:function(e){return new Ke(((n,r)=>{let o=t.length;if(!o)return n(!0);const s=setTimeout((()=>{e&&e>0&&n(!1)}),e);t.forEach((e=>{Xe(e).then((()=>{--o||(clearTimeout(s),n(!0))}),r)}))}))}}}function Ze(e){if(!e)return{};const t=e.match(/^(([^:/?#]+):)?(\/\/([^/?#]*))?([^?#]*)(\?([^#]*))?(#(.*))?$/);if(!t)return{};const n=t[6]||"",r=t[8]||"";return{host:t[4],path:t[5],protocol:t[2],search:n,hash:r,relative:t[5]+n+r}}const et=["fatal","error","warning","log","info","debug"];function tt(e){return"warn"===e?"warning":et.includes(e)?e:"log"}const nt="baggage",rt="sentry-",ot=/^sentry-/,st=8192;function it(e){const t=function(e){if(!e||!s(e)&&!Array.isArray(e))return;if(Array.isArray(e))return e.reduce(((e,t)=>{const n=ct(t);return Object.entries(n).forEach((([t,n])=>{e[t]=n})),e}),{});return ct(e)}(e);if(!t)return;const n=Object.entries(t).reduce(((e,[t,n])=>{if(t.match(ot)){e[t.slice(rt.length)]=n}return e}),{});return Object.keys(n).length>0?n:void 0}function at(e){if(!e)return;return function(e){if(0===Object.keys(e).length)return;return Object.entries(e).reduce(((e,[t,n],r)=>{const o=`${encodeURIComponent(t)}=${encodeURIComponent(n)}`,s=0===r?o:`${e},${o}`;return s.length>st?(A&&L.warn(`Not adding key: ${t} with val: ${n} to baggage header due to exceeding baggage size limits.`),e):s}),"")}(Object.entries(e).reduce(((e,[t,n])=>(n&&(e[`${rt}${t}`]=n),e)),{}))}function ct(e){return e.split(",").map((e=>e.split("=").map((e=>decodeURIComponent(e.trim()))))).reduce(((e,[t,n])=>(t&&n&&(e[t]=n),e)),{})}const ut=new RegExp("^[ \\t]*([0-9a-f]{32})?-?([0-9a-f]{16})?-?([01])?[ \\t]*$");function lt(e,t){const n=function(e){if(!e)return;const t=e.match(ut);if(!t)return;let n;return"1"===t[3]?n=!0:"0"===t[3]&&(n=!1),{traceId:t[1],parentSampled:n,parentSpanId:t[2]}}(e),r=it(t),{traceId:o,parentSpanId:s,parentSampled:i}=n||{};return n?{traceId:o||De(),parentSpanId:s||De().substring(16),spanId:De().substring(16),sampled:i,dsc:r||{}}:{traceId:o||De(),spanId:De().substring(16)}}function dt(e=De(),t=De().substring(16),n){let r="";return void 0!==n&&(r=n?"-1":"-0"),`${e}-${t}${r}`}function pt(e,t=[]){return[e,t]}function ht(e,t){const[n,r]=e;return[n,[...r,t]]}function ft(e,t){const n=e[1];for(const e of n){if(t(e,e[0].type))return!0}return!1}function mt(e,t){return ft(e,((e,n)=>t.includes(n)))}function gt(e){return k.__SENTRY__&&k.__SENTRY__.encodePolyfill?k.__SENTRY__.encodePolyfill(e):(new TextEncoder).encode(e)}function _t(e){const[t,n]=e;let r=JSON.stringify(t);function o(e){"string"==typeof r?r="string"==typeof e?r+e:[gt(r),e]:r.push("string"==typeof e?gt(e):e)}for(const e of n){const[t,n]=e;if(o(`\n${JSON.stringify(t)}\n`),"string"==typeof n||n instanceof Uint8Array)o(n);else{let e;try{e=JSON.stringify(n)}catch(t){e=JSON.stringify(je(n))}o(e)}}return"string"==typeof r?r:function(e){const t=e.reduce(((e,t)=>e+t.length),0),n=new Uint8Array(t);let r=0;for(const t of e)n.set(t,r),r+=t.length;return n}(r)}function yt(e){let t="string"==typeof e?gt(e):e;function n(e){const n=t.subarray(0,e);return t=t.subarray(e+1),n}function r(){let e=t.indexOf(10);return e<0&&(e=t.length),JSON.parse((r=n(e),k.__SENTRY__&&k.__SENTRY__.decodePolyfill?k.__SENTRY__.decodePolyfill(r):(new TextDecoder).decode(r)));var r}const o=r(),s=[];for(;t.length;){const e=r(),t="number"==typeof e.length?e.length:void 0;s.push([e,t?n(t):r()])}return[o,s]}function vt(e){return[{type:"span"},e]}function bt(e){const t="string"==typeof e.data?gt(e.data):e.data;return[X({type:"attachment",length:t.length,filename:e.filename,content_type:e.contentType,attachment_type:e.attachmentType}),t]}const St={session:"session",sessions:"session",attachment:"attachment",transaction:"transaction",event:"error",client_report:"internal",user_report:"default",profile:"profile",profile_chunk:"profile",replay_event:"replay",replay_recording:"replay",check_in:"monitor",feedback:"feedback",span:"span",statsd:"metric_bucket"};function wt(e){return St[e]}function kt(e){if(!e||!e.sdk)return;const{name:t,version:n}=e.sdk;return{name:t,version:n}}function xt(e,t,n,r){const o=e.sdkProcessingMetadata&&e.sdkProcessingMetadata.dynamicSamplingContext;return{event_id:e.event_id,sent_at:(new Date).toISOString(),...t&&{sdk:t},...!!n&&r&&{dsn:F(r)},...o&&{trace:X({...o})}}}const Et=6e4;function Ct(e,t=Date.now()){const n=parseInt(`${e}`,10);if(!isNaN(n))return 1e3*n;const r=Date.parse(`${e}`);return isNaN(r)?Et:r-t}function Tt(e,t,n=Date.now()){return function(e,t){return e[t]||e.all||0}(e,t)>n}function It(e,{statusCode:t,headers:n},r=Date.now()){const o={...e},s=n&&n["x-sentry-rate-limits"],i=n&&n["retry-after"];if(s)for(const e of s.trim().split(",")){const[t,n,,,s]=e.split(":",5),i=parseInt(t,10),a=1e3*(isNaN(i)?60:i);if(n)for(const e of n.split(";"))"metric_bucket"===e&&s&&!s.split(";").includes("custom")||(o[e]=r+a);else o.all=r+a}else i?o.all=r+Ct(i,r):429===t&&(o.all=r+6e4);return o}function Mt(e){let t,n=e[0],r=1;for(;r<e.length;){const o=e[r],s=e[r+1];if(r+=2,("optionalAccess"===o||"optionalCall"===o)&&null==n)return;"access"===o||"optionalAccess"===o?(t=n,n=s(n)):"call"!==o&&"optionalCall"!==o||(n=s(((...e)=>n.call(t,...e))),t=void 0)}return n}function Rt(){return{traceId:De(),spanId:De().substring(16)}}const At=k;const Nt="undefined"==typeof __SENTRY_DEBUG__||__SENTRY_DEBUG__;function Ot(){return Dt(k),k}function Dt(e){const t=e.__SENTRY__=e.__SENTRY__||{};return t.version=t.version||w,t[w]=t[w]||{}}function Lt(e){const t=ve(),n={sid:De(),init:!0,timestamp:t,started:t,duration:0,status:"ok",errors:0,ignoreDuration:!1,toJSON:()=>function(e){return X({sid:`${e.sid}`,init:e.init,started:new Date(1e3*e.started).toISOString(),timestamp:new Date(1e3*e.timestamp).toISOString(),status:e.status,errors:e.errors,did:"number"==typeof e.did||"string"==typeof e.did?`${e.did}`:void 0,duration:e.duration,abnormal_mechanism:e.abnormal_mechanism,attrs:{release:e.release,environment:e.environment,ip_address:e.ipAddress,user_agent:e.userAgent}})}(n)};return e&&Pt(n,e),n}function Pt(e,t={}){if(t.user&&(!e.ipAddress&&t.user.ip_address&&(e.ipAddress=t.user.ip_address),e.did||t.did||(e.did=t.user.id||t.user.email||t.user.username)),e.timestamp=t.timestamp||ve(),t.abnormal_mechanism&&(e.abnormal_mechanism=t.abnormal_mechanism),t.ignoreDuration&&(e.ignoreDuration=t.ignoreDuration),t.sid&&(e.sid=32===t.sid.length?t.sid:De()),void 0!==t.init&&(e.init=t.init),!e.did&&t.did&&(e.did=`${t.did}`),"number"==typeof t.started&&(e.started=t.started),e.ignoreDuration)e.duration=void 0;else if("number"==typeof t.duration)e.duration=t.duration;else{const t=e.timestamp-e.started;e.duration=t>=0?t:0}t.release&&(e.release=t.release),t.environment&&(e.environment=t.environment),!e.ipAddress&&t.ipAddress&&(e.ipAddress=t.ipAddress),!e.userAgent&&t.userAgent&&(e.userAgent=t.userAgent),"number"==typeof t.errors&&(e.errors=t.errors),t.status&&(e.status=t.status)}const Ft="_sentrySpan";function $t(e,t){t?z(e,Ft,t):delete e[Ft]}function Bt(e){return e[Ft]}class Ut{constructor(){this._notifyingListeners=!1,this._scopeListeners=[],this._eventProcessors=[],this._breadcrumbs=[],this._attachments=[],this._user={},this._tags={},this._extra={},this._contexts={},this._sdkProcessingMetadata={},this._propagationContext=Rt()}clone(){const e=new Ut;return e._breadcrumbs=[...this._breadcrumbs],e._tags={...this._tags},e._extra={...this._extra},e._contexts={...this._contexts},e._user=this._user,e._level=this._level,e._session=this._session,e._transactionName=this._transactionName,e._fingerprint=this._fingerprint,e._eventProcessors=[...this._eventProcessors],e._requestSession=this._requestSession,e._attachments=[...this._attachments],e._sdkProcessingMetadata={...this._sdkProcessingMetadata},e._propagationContext={...this._propagationContext},e._client=this._client,e._lastEventId=this._lastEventId,$t(e,Bt(this)),e}setClient(e){this._client=e}setLastEventId(e){this._lastEventId=e}getClient(){return this._client}lastEventId(){return this._lastEventId}addScopeListener(e){this._scopeListeners.push(e)}addEventProcessor(e){return this._eventProcessors.push(e),this}setUser(e){return this._user=e||{email:void 0,id:void 0,ip_address:void 0,username:void 0},this._session&&Pt(this._session,{user:e}),this._notifyScopeListeners(),this}getUser(){return this._user}getRequestSession(){return this._requestSession}setRequestSession(e){return this._requestSession=e,this}setTags(e){return this._tags={...this._tags,...e},this._notifyScopeListeners(),this}setTag(e,t){return this._tags={...this._tags,[e]:t},this._notifyScopeListeners(),this}setExtras(e){return this._extra={...this._extra,...e},this._notifyScopeListeners(),this}setExtra(e,t){return this._extra={...this._extra,[e]:t},this._notifyScopeListeners(),this}setFingerprint(e){return this._fingerprint=e,this._notifyScopeListeners(),this}setLevel(e){return this._level=e,this._notifyScopeListeners(),this}setTransactionName(e){return this._transactionName=e,this._notifyScopeListeners(),this}setContext(e,t){return null===t?delete this._contexts[e]:this._contexts[e]=t,this._notifyScopeListeners(),this}setSession(e){return e?this._session=e:delete this._session,this._notifyScopeListeners(),this}getSession(){return this._session}update(e){if(!e)return this;const t="function"==typeof e?e(this):e,[n,r]=t instanceof jt?[t.getScopeData(),t.getRequestSession()]:c(t)?[e,e.requestSession]:[],{tags:o,extra:s,user:i,contexts:a,level:u,fingerprint:l=[],propagationContext:d}=n||{};return this._tags={...this._tags,...o},this._extra={...this._extra,...s},this._contexts={...this._contexts,...a},i&&Object.keys(i).length&&(this._user=i),u&&(this._level=u),l.length&&(this._fingerprint=l),d&&(this._propagationContext=d),r&&(this._requestSession=r),this}clear(){return this._breadcrumbs=[],this._tags={},this._extra={},this._user={},this._contexts={},this._level=void 0,this._transactionName=void 0,this._fingerprint=void 0,this._requestSession=void 0,this._session=void 0,$t(this,void 0),this._attachments=[],this._propagationContext=Rt(),this._notifyScopeListeners(),this}addBreadcrumb(e,t){const n="number"==typeof t?t:100;if(n<=0)return this;const r={timestamp:ye(),...e},o=this._breadcrumbs;return o.push(r),this._breadcrumbs=o.length>n?o.slice(-n):o,this._notifyScopeListeners(),this}getLastBreadcrumb(){return this._breadcrumbs[this._breadcrumbs.length-1]}clearBreadcrumbs(){return this._breadcrumbs=[],this._notifyScopeListeners(),this}addAttachment(e){return this._attachments.push(e),this}clearAttachments(){return this._attachments=[],this}getScopeData(){return{breadcrumbs:this._breadcrumbs,attachments:this._attachments,contexts:this._contexts,tags:this._tags,extra:this._extra,user:this._user,level:this._level,fingerprint:this._fingerprint||[],eventProcessors:this._eventProcessors,propagationContext:this._propagationContext,sdkProcessingMetadata:this._sdkProcessingMetadata,transactionName:this._transactionName,span:Bt(this)}}setSDKProcessingMetadata(e){return this._sdkProcessingMetadata={...this._sdkProcessingMetadata,...e},this}setPropagationContext(e){return this._propagationContext=e,this}getPropagationContext(){return this._propagationContext}captureException(e,t){const n=t&&t.event_id?t.event_id:De();if(!this._client)return L.warn("No client configured on scope - will not capture exception!"),n;const r=new Error("Sentry syntheticException");return this._client.captureException(e,{originalException:e,syntheticException:r,...t,event_id:n},this),n}captureMessage(e,t,n){const r=n&&n.event_id?n.event_id:De();if(!this._client)return L.warn("No client configured on scope - will not capture message!"),r;const o=new Error(e);return this._client.captureMessage(e,t,{originalException:e,syntheticException:o,...n,event_id:r},this),r}captureEvent(e,t){const n=t&&t.event_id?t.event_id:De();return this._client?(this._client.captureEvent(e,{...t,event_id:n},this),n):(L.warn("No client configured on scope - will not capture event!"),n)}_notifyScopeListeners(){this._notifyingListeners||(this._notifyingListeners=!0,this._scopeListeners.forEach((e=>{e(this)})),this._notifyingListeners=!1)}}const jt=Ut;class Ht{constructor(e,t){let n,r;n=e||new jt,r=t||new jt,this._stack=[{scope:n}],this._isolationScope=r}withScope(e){const t=this._pushScope();let n;try{n=e(t)}catch(e){throw this._popScope(),e}return l(n)?n.then((e=>(this._popScope(),e)),(e=>{throw this._popScope(),e})):(this._popScope(),n)}getClient(){return this.getStackTop().client}getScope(){return this.getStackTop().scope}getIsolationScope(){return this._isolationScope}getStackTop(){return this._stack[this._stack.length-1]}_pushScope(){const e=this.getScope().clone();return this._stack.push({client:this.getClient(),scope:e}),e}_popScope(){return!(this._stack.length<=1)&&!!this._stack.pop()}}function zt(){const e=Dt(Ot());return e.stack=e.stack||new Ht(x("defaultCurrentScope",(()=>new jt)),x("defaultIsolationScope",(()=>new jt)))}function qt(e){return zt().withScope(e)}function Wt(e,t){const n=zt();return n.withScope((()=>(n.getStackTop().scope=e,t(e))))}function Yt(e){return zt().withScope((()=>e(zt().getIsolationScope())))}function Vt(e){const t=Dt(e);return t.acs?t.acs:{withIsolationScope:Yt,withScope:qt,withSetScope:Wt,withSetIsolationScope:(e,t)=>Yt(t),getCurrentScope:()=>zt().getScope(),getIsolationScope:()=>zt().getIsolationScope()}}function Gt(){return Vt(Ot()).getCurrentScope()}function Xt(){return Vt(Ot()).getIsolationScope()}function Jt(){return x("globalScope",(()=>new jt))}function Kt(...e){const t=Vt(Ot());if(2===e.length){const[n,r]=e;return n?t.withSetScope(n,r):t.withScope(r)}return t.withScope(e[0])}function Qt(){return Gt().getClient()}const Zt="_sentryMetrics";function en(e){const t=e[Zt];if(!t)return;const n={};for(const[,[e,r]]of t){(n[e]||(n[e]=[])).push(X(r))}return n}const tn="sentry.source",nn="sentry.sample_rate",rn="sentry.op",on="sentry.origin",sn="sentry.idle_span_finish_reason",an="sentry.measurement_unit",cn="sentry.measurement_value",un="sentry.exclusive_time",ln=0,dn=1,pn=2;function hn(e){if(e<400&&e>=100)return{code:dn};if(e>=400&&e<500)switch(e){case 401:return{code:pn,message:"unauthenticated"};case 403:return{code:pn,message:"permission_denied"};case 404:return{code:pn,message:"not_found"};case 409:return{code:pn,message:"already_exists"};case 413:return{code:pn,message:"failed_precondition"};case 429:return{code:pn,message:"resource_exhausted"};case 499:return{code:pn,message:"cancelled"};default:return{code:pn,message:"invalid_argument"}}if(e>=500&&e<600)switch(e){case 501:return{code:pn,message:"unimplemented"};case 503:return{code:pn,message:"unavailable"};case 504:return{code:pn,message:"deadline_exceeded"};default:return{code:pn,message:"internal_error"}}return{code:pn,message:"unknown_error"}}function fn(e,t){e.setAttribute("http.response.status_code",t);const n=hn(t);"unknown_error"!==n.message&&e.setStatus(n)}const mn=1;function gn(e){const{spanId:t,traceId:n}=e.spanContext(),{data:r,op:o,parent_span_id:s,status:i,origin:a}=Sn(e);return X({parent_span_id:s,span_id:t,trace_id:n,data:r,op:o,status:i,origin:a})}function _n(e){const{spanId:t,traceId:n}=e.spanContext(),{parent_span_id:r}=Sn(e);return X({parent_span_id:r,span_id:t,trace_id:n})}function yn(e){const{traceId:t,spanId:n}=e.spanContext();return dt(t,n,wn(e))}function vn(e){return"number"==typeof e?bn(e):Array.isArray(e)?e[0]+e[1]/1e9:e instanceof Date?bn(e.getTime()):ve()}function bn(e){return e>9999999999?e/1e3:e}function Sn(e){if(function(e){return"function"==typeof e.getSpanJSON}(e))return e.getSpanJSON();try{const{spanId:t,traceId:n}=e.spanContext();if(function(e){const t=e;return!!(t.attributes&&t.startTime&&t.name&&t.endTime&&t.status)}(e)){const{attributes:r,startTime:o,name:s,endTime:i,parentSpanId:a,status:c}=e;return X({span_id:t,trace_id:n,data:r,description:s,parent_span_id:a,start_timestamp:vn(o),timestamp:vn(i)||void 0,status:kn(c),op:r[rn],origin:r[on],_metrics_summary:en(e)})}return{span_id:t,trace_id:n}}catch(e){return{}}}function wn(e){const{traceFlags:t}=e.spanContext();return t===mn}function kn(e){if(e&&e.code!==ln)return e.code===dn?"ok":e.message||"unknown_error"}const xn="_sentryChildSpans",En="_sentryRootSpan";function Cn(e,t){const n=e[En]||e;z(t,En,n),e[xn]?e[xn].add(t):z(e,xn,new Set([t]))}function Tn(e){const t=new Set;return function e(n){if(!t.has(n)&&wn(n)){t.add(n);const r=n[xn]?Array.from(n[xn]):[];for(const t of r)e(t)}}(e),Array.from(t)}function In(e){return e[En]||e}function Mn(){const e=Vt(Ot());return e.getActiveSpan?e.getActiveSpan():Bt(Gt())}function Rn(e,t,n,r,o,s){const i=Mn();i&&function(e,t,n,r,o,s,i){const a=e[Zt]||(e[Zt]=new Map),c=`${t}:${n}@${o}`,u=a.get(i);if(u){const[,e]=u;a.set(i,[c,{min:Math.min(e.min,r),max:Math.max(e.max,r),count:e.count+=1,sum:e.sum+=r,tags:e.tags}])}else a.set(i,[c,{min:r,max:r,count:1,sum:r,tags:s}])}(i,e,t,n,r,o,s)}let An=!1;function Nn(){An||(An=!0,Te(On),Re(On))}function On(){const e=Mn(),t=e&&In(e);if(t){const e="internal_error";Nt&&L.log(`[Tracing] Root span: ${e} -> Global error occured`),t.setStatus({code:pn,message:e})}}On.tag="sentry_tracingErrorCallback";const Dn="_sentryScope",Ln="_sentryIsolationScope";function Pn(e){return{scope:e[Dn],isolationScope:e[Ln]}}function Fn(e){if("boolean"==typeof __SENTRY_TRACING__&&!__SENTRY_TRACING__)return!1;const t=Qt(),n=e||t&&t.getOptions();return!!n&&(n.enableTracing||"tracesSampleRate"in n||"tracesSampler"in n)}class $n{constructor(e={}){this._traceId=e.traceId||De(),this._spanId=e.spanId||De().substring(16)}spanContext(){return{spanId:this._spanId,traceId:this._traceId,traceFlags:0}}end(e){}setAttribute(e,t){return this}setAttributes(e){return this}setStatus(e){return this}updateName(e){return this}isRecording(){return!1}addEvent(e,t,n){return this}addLink(e){return this}addLinks(e){return this}recordException(e,t){}}function Bn(e,t,n=()=>{}){let r;try{r=e()}catch(e){throw t(e),n(),e}return function(e,t,n){if(l(e))return e.then((e=>(n(),e)),(e=>{throw t(e),n(),e}));return n(),e}(r,t,n)}const Un="production",jn="_frozenDsc";function Hn(e,t){z(e,jn,t)}function zn(e,t){const n=t.getOptions(),{publicKey:r}=t.getDsn()||{},o=X({environment:n.environment||Un,release:n.release,public_key:r,trace_id:e});return t.emit("createDsc",o),o}function qn(e){const t=Qt();if(!t)return{};const n=zn(Sn(e).trace_id||"",t),r=In(e),o=r[jn];if(o)return o;const s=r.spanContext().traceState,i=s&&s.get("sentry.dsc"),a=i&&it(i);if(a)return a;const c=Sn(r),u=c.data||{},l=u[nn];null!=l&&(n.sample_rate=`${l}`);const d=u[tn],p=c.description;return"url"!==d&&p&&(n.transaction=p),n.sampled=String(wn(r)),t.emit("createDsc",n,r),n}function Wn(e){if("boolean"==typeof e)return Number(e);const t="string"==typeof e?parseFloat(e):e;if(!("number"!=typeof t||isNaN(t)||t<0||t>1))return t;Nt&&L.warn(`[Tracing] Given sample rate is invalid. Sample rate must be a boolean or a number between 0 and 1. Got ${JSON.stringify(e)} of type ${JSON.stringify(typeof e)}.`)}function Yn(e,t,n,r=Mn()){const o=r&&In(r);o&&o.addEvent(e,{[cn]:t,[an]:n})}function Vn(e){if(!e||0===e.length)return;const t={};return e.forEach((e=>{const n=e.attributes||{},r=n[an],o=n[cn];"string"==typeof r&&"number"==typeof o&&(t[e.name]={value:o,unit:r})})),t}class Gn{constructor(e={}){this._traceId=e.traceId||De(),this._spanId=e.spanId||De().substring(16),this._startTime=e.startTimestamp||ve(),this._attributes={},this.setAttributes({[on]:"manual",[rn]:e.op,...e.attributes}),this._name=e.name,e.parentSpanId&&(this._parentSpanId=e.parentSpanId),"sampled"in e&&(this._sampled=e.sampled),e.endTimestamp&&(this._endTime=e.endTimestamp),this._events=[],this._isStandaloneSpan=e.isStandalone,this._endTime&&this._onSpanEnded()}addLink(e){return this}addLinks(e){return this}recordException(e,t){}spanContext(){const{_spanId:e,_traceId:t,_sampled:n}=this;return{spanId:e,traceId:t,traceFlags:n?mn:0}}setAttribute(e,t){return void 0===t?delete this._attributes[e]:this._attributes[e]=t,this}setAttributes(e){return Object.keys(e).forEach((t=>this.setAttribute(t,e[t]))),this}updateStartTime(e){this._startTime=vn(e)}setStatus(e){return this._status=e,this}updateName(e){return this._name=e,this}end(e){this._endTime||(this._endTime=vn(e),function(e){if(!Nt)return;const{description:t="< unknown name >",op:n="< unknown op >"}=Sn(e),{spanId:r}=e.spanContext(),o=`[Tracing] Finishing "${n}" ${In(e)===e?"root ":""}span "${t}" with ID ${r}`;L.log(o)}(this),this._onSpanEnded())}getSpanJSON(){return X({data:this._attributes,description:this._name,op:this._attributes[rn],parent_span_id:this._parentSpanId,span_id:this._spanId,start_timestamp:this._startTime,status:kn(this._status),timestamp:this._endTime,trace_id:this._traceId,origin:this._attributes[on],_metrics_summary:en(this),profile_id:this._attributes["sentry.profile_id"],exclusive_time:this._attributes[un],measurements:Vn(this._events),is_segment:this._isStandaloneSpan&&In(this)===this||void 0,segment_id:this._isStandaloneSpan?In(this).spanContext().spanId:void 0})}isRecording(){return!this._endTime&&!!this._sampled}addEvent(e,t,n){Nt&&L.log("[Tracing] Adding an event to span:",e);const r=Xn(t)?t:n||ve(),o=Xn(t)?{}:t||{},s={name:e,time:vn(r),attributes:o};return this._events.push(s),this}isStandaloneSpan(){return!!this._isStandaloneSpan}_onSpanEnded(){const e=Qt();e&&e.emit("spanEnd",this);if(!(this._isStandaloneSpan||this===In(this)))return;if(this._isStandaloneSpan)return void(this._sampled?function(e){const t=Qt();if(!t)return;const n=e[1];if(!n||0===n.length)return void t.recordDroppedEvent("before_send","span");const r=t.getTransport();r&&r.send(e).then(null,(e=>{Nt&&L.error("Error while sending span:",e)}))}(function(e,t){const n=qn(e[0]),r=t&&t.getDsn(),o=t&&t.getOptions().tunnel,s={sent_at:(new Date).toISOString(),...function(e){return!!e.trace_id&&!!e.public_key}(n)&&{trace:n},...!!o&&r&&{dsn:F(r)}},i=t&&t.getOptions().beforeSendSpan,a=i?e=>i(Sn(e)):e=>Sn(e),c=[];for(const t of e){const e=a(t);e&&c.push(vt(e))}return pt(s,c)}([this],e)):(Nt&&L.log("[Tracing] Discarding standalone span because its trace was not chosen to be sampled."),e&&e.recordDroppedEvent("sample_rate","span")));const t=this._convertSpanToTransaction();if(t){(Pn(this).scope||Gt()).captureEvent(t)}}_convertSpanToTransaction(){if(!Jn(Sn(this)))return;this._name||(Nt&&L.warn("Transaction has no name, falling back to `<unlabeled transaction>`."),this._name="<unlabeled transaction>");const{scope:e,isolationScope:t}=Pn(this),n=(e||Gt()).getClient()||Qt();if(!0!==this._sampled)return Nt&&L.log("[Tracing] Discarding transaction because its trace was not chosen to be sampled."),void(n&&n.recordDroppedEvent("sample_rate","transaction"));const r=Tn(this).filter((e=>e!==this&&!function(e){return e instanceof Gn&&e.isStandaloneSpan()}(e))).map((e=>Sn(e))).filter(Jn),o=this._attributes[tn],s={contexts:{trace:gn(this)},spans:r.length>1e3?r.sort(((e,t)=>e.start_timestamp-t.start_timestamp)).slice(0,1e3):r,start_timestamp:this._startTime,timestamp:this._endTime,transaction:this._name,type:"transaction",sdkProcessingMetadata:{capturedSpanScope:e,capturedSpanIsolationScope:t,...X({dynamicSamplingContext:qn(this)})},_metrics_summary:en(this),...o&&{transaction_info:{source:o}}},i=Vn(this._events);return i&&Object.keys(i).length&&(Nt&&L.log("[Measurements] Adding measurements to transaction event",JSON.stringify(i,void 0,2)),s.measurements=i),s}}function Xn(e){return e&&"number"==typeof e||e instanceof Date||Array.isArray(e)}function Jn(e){return!!(e.start_timestamp&&e.timestamp&&e.span_id&&e.trace_id)}const Kn="__SENTRY_SUPPRESS_TRACING__";function Qn(e,t){const n=rr();if(n.startSpanManual)return n.startSpanManual(e,t);const r=nr(e),{forceTransaction:o,parentSpan:s}=e;return Kt(e.scope,(()=>ir(s)((()=>{const n=Gt(),s=sr(n),i=e.onlyIfParent&&!s?new $n:tr({parentSpan:s,spanArguments:r,forceTransaction:o,scope:n});function a(){i.end()}return $t(n,i),Bn((()=>t(i,a)),(()=>{const{status:e}=Sn(i);!i.isRecording()||e&&"ok"!==e||i.setStatus({code:pn,message:"internal_error"})}))}))))}function Zn(e){const t=rr();if(t.startInactiveSpan)return t.startInactiveSpan(e);const n=nr(e),{forceTransaction:r,parentSpan:o}=e;return(e.scope?t=>Kt(e.scope,t):void 0!==o?e=>er(o,e):e=>e())((()=>{const t=Gt(),o=sr(t);return e.onlyIfParent&&!o?new $n:tr({parentSpan:o,spanArguments:n,forceTransaction:r,scope:t})}))}function er(e,t){const n=rr();return n.withActiveSpan?n.withActiveSpan(e,t):Kt((n=>($t(n,e||void 0),t(n))))}function tr({parentSpan:e,spanArguments:t,forceTransaction:n,scope:r}){if(!Fn())return new $n;const o=Xt();let s;if(e&&!n)s=function(e,t,n){const{spanId:r,traceId:o}=e.spanContext(),s=!t.getScopeData().sdkProcessingMetadata[Kn]&&wn(e),i=s?new Gn({...n,parentSpanId:r,traceId:o,sampled:s}):new $n({traceId:o});Cn(e,i);const a=Qt();a&&(a.emit("spanStart",i),n.endTimestamp&&a.emit("spanEnd",i));return i}(e,r,t),Cn(e,s);else if(e){const n=qn(e),{traceId:o,spanId:i}=e.spanContext(),a=wn(e);s=or({traceId:o,parentSpanId:i,...t},r,a),Hn(s,n)}else{const{traceId:e,dsc:n,parentSpanId:i,sampled:a}={...o.getPropagationContext(),...r.getPropagationContext()};s=or({traceId:e,parentSpanId:i,...t},r,a),n&&Hn(s,n)}return function(e){if(!Nt)return;const{description:t="< unknown name >",op:n="< unknown op >",parent_span_id:r}=Sn(e),{spanId:o}=e.spanContext(),s=wn(e),i=In(e),a=i===e,c=`[Tracing] Starting ${s?"sampled":"unsampled"} ${a?"root ":""}span`,u=[`op: ${n}`,`name: ${t}`,`ID: ${o}`];if(r&&u.push(`parent ID: ${r}`),!a){const{op:e,description:t}=Sn(i);u.push(`root ID: ${i.spanContext().spanId}`),e&&u.push(`root op: ${e}`),t&&u.push(`root description: ${t}`)}L.log(`${c}\n  ${u.join("\n  ")}`)}(s),function(e,t,n){e&&(z(e,Ln,n),z(e,Dn,t))}(s,r,o),s}function nr(e){const t={isStandalone:(e.experimental||{}).standalone,...e};if(e.startTime){const n={...t};return n.startTimestamp=vn(e.startTime),delete n.startTime,n}return t}function rr(){return Vt(Ot())}function or(e,t,n){const r=Qt(),o=r&&r.getOptions()||{},{name:s="",attributes:i}=e,[a,c]=t.getScopeData().sdkProcessingMetadata[Kn]?[!1]:function(e,t){if(!Fn(e))return[!1];let n;n="function"==typeof e.tracesSampler?e.tracesSampler(t):void 0!==t.parentSampled?t.parentSampled:void 0!==e.tracesSampleRate?e.tracesSampleRate:1;const r=Wn(n);return void 0===r?(Nt&&L.warn("[Tracing] Discarding transaction because of invalid sample rate."),[!1]):r?Math.random()<r?[!0,r]:(Nt&&L.log(`[Tracing] Discarding transaction because it's not included in the random sample (sampling rate = ${Number(n)})`),[!1,r]):(Nt&&L.log("[Tracing] Discarding transaction because "+("function"==typeof e.tracesSampler?"tracesSampler returned 0 or false":"a negative sampling decision was inherited or tracesSampleRate is set to 0")),[!1,r])}(o,{name:s,parentSampled:n,attributes:i,transactionContext:{name:s,parentSampled:n}}),u=new Gn({...e,attributes:{[tn]:"custom",...e.attributes},sampled:a});return void 0!==c&&u.setAttribute(nn,c),r&&r.emit("spanStart",u),u}function sr(e){const t=Bt(e);if(!t)return;const n=Qt();return(n?n.getOptions():{}).parentSpanIsAlwaysRootSpan?In(t):t}function ir(e){return void 0!==e?t=>er(e,t):e=>e()}const ar={idleTimeout:1e3,finalTimeout:3e4,childSpanTimeout:15e3},cr="heartbeatFailed",ur="idleTimeout",lr="finalTimeout",dr="externalFinish";function pr(e,t={}){const n=new Map;let r,o=!1,s=dr,i=!t.disableAutoFinish;const a=[],{idleTimeout:c=ar.idleTimeout,finalTimeout:u=ar.finalTimeout,childSpanTimeout:l=ar.childSpanTimeout,beforeSpanEnd:d}=t,p=Qt();if(!p||!Fn())return new $n;const h=Gt(),f=Mn(),m=function(e){const t=Zn(e);return $t(Gt(),t),Nt&&L.log("[Tracing] Started span is an idle span"),t}(e);function g(){r&&(clearTimeout(r),r=void 0)}function _(e){g(),r=setTimeout((()=>{!o&&0===n.size&&i&&(s=ur,m.end(e))}),c)}function y(e){r=setTimeout((()=>{!o&&i&&(s=cr,m.end(e))}),l)}function v(e){o=!0,n.clear(),a.forEach((e=>e())),$t(h,f);const t=Sn(m),{start_timestamp:r}=t;if(!r)return;(t.data||{})[sn]||m.setAttribute(sn,s),L.log(`[Tracing] Idle span "${t.op}" finished`);const i=Tn(m).filter((e=>e!==m));let l=0;i.forEach((t=>{t.isRecording()&&(t.setStatus({code:pn,message:"cancelled"}),t.end(e),Nt&&L.log("[Tracing] Cancelling span since span ended early",JSON.stringify(t,void 0,2)));const n=Sn(t),{timestamp:r=0,start_timestamp:o=0}=n,s=o<=e,i=r-o<=(u+c)/1e3;if(Nt){const e=JSON.stringify(t,void 0,2);s?i||L.log("[Tracing] Discarding span since it finished after idle span final timeout",e):L.log("[Tracing] Discarding span since it happened after idle span was finished",e)}i&&s||(!function(e,t){e[xn]&&e[xn].delete(t)}(m,t),l++)})),l>0&&m.setAttribute("sentry.idle_span_discarded_spans",l)}return m.end=new Proxy(m.end,{apply(e,t,n){d&&d(m);const[r,...o]=n,s=vn(r||ve()),i=Tn(m).filter((e=>e!==m));if(!i.length)return v(s),Reflect.apply(e,t,[s,...o]);const a=i.map((e=>Sn(e).timestamp)).filter((e=>!!e)),c=a.length?Math.max(...a):void 0,l=Sn(m).start_timestamp,p=Math.min(l?l+u/1e3:1/0,Math.max(l||-1/0,Math.min(s,c||1/0)));return v(p),Reflect.apply(e,t,[p,...o])}}),a.push(p.on("spanStart",(e=>{if(o||e===m||Sn(e).timestamp)return;var t;Tn(m).includes(e)&&(t=e.spanContext().spanId,g(),n.set(t,!0),y(ve()+l/1e3))}))),a.push(p.on("spanEnd",(e=>{var t;o||(t=e.spanContext().spanId,n.has(t)&&n.delete(t),0===n.size&&_(ve()+c/1e3))}))),a.push(p.on("idleSpanEnableAutoFinish",(e=>{e===m&&(i=!0,_(),n.size&&y())}))),t.disableAutoFinish||_(),setTimeout((()=>{o||(m.setStatus({code:pn,message:"deadline_exceeded"}),s=lr,m.end())}),u),m}function hr(e,t,n,r=0){return new Ke(((o,s)=>{const i=e[r];if(null===t||"function"!=typeof i)o(t);else{const a=i({...t},n);Nt&&i.id&&null===a&&L.log(`Event processor "${i.id}" dropped event`),l(a)?a.then((t=>hr(e,t,n,r+1).then(o))).then(null,s):hr(e,a,n,r+1).then(o).then(null,s)}}))}function fr(e,t){const{fingerprint:n,span:r,breadcrumbs:o,sdkProcessingMetadata:s}=t;!function(e,t){const{extra:n,tags:r,user:o,contexts:s,level:i,transactionName:a}=t,c=X(n);c&&Object.keys(c).length&&(e.extra={...c,...e.extra});const u=X(r);u&&Object.keys(u).length&&(e.tags={...u,...e.tags});const l=X(o);l&&Object.keys(l).length&&(e.user={...l,...e.user});const d=X(s);d&&Object.keys(d).length&&(e.contexts={...d,...e.contexts});i&&(e.level=i);a&&"transaction"!==e.type&&(e.transaction=a)}(e,t),r&&function(e,t){e.contexts={trace:_n(t),...e.contexts},e.sdkProcessingMetadata={dynamicSamplingContext:qn(t),...e.sdkProcessingMetadata};const n=In(t),r=Sn(n).description;r&&!e.transaction&&"transaction"===e.type&&(e.transaction=r)}(e,r),function(e,t){e.fingerprint=e.fingerprint?Ue(e.fingerprint):[],t&&(e.fingerprint=e.fingerprint.concat(t));e.fingerprint&&!e.fingerprint.length&&delete e.fingerprint}(e,n),function(e,t){const n=[...e.breadcrumbs||[],...t];e.breadcrumbs=n.length?n:void 0}(e,o),function(e,t){e.sdkProcessingMetadata={...e.sdkProcessingMetadata,...t}}(e,s)}function mr(e,t){const{extra:n,tags:r,user:o,contexts:s,level:i,sdkProcessingMetadata:a,breadcrumbs:c,fingerprint:u,eventProcessors:l,attachments:d,propagationContext:p,transactionName:h,span:f}=t;gr(e,"extra",n),gr(e,"tags",r),gr(e,"user",o),gr(e,"contexts",s),gr(e,"sdkProcessingMetadata",a),i&&(e.level=i),h&&(e.transactionName=h),f&&(e.span=f),c.length&&(e.breadcrumbs=[...e.breadcrumbs,...c]),u.length&&(e.fingerprint=[...e.fingerprint,...u]),l.length&&(e.eventProcessors=[...e.eventProcessors,...l]),d.length&&(e.attachments=[...e.attachments,...d]),e.propagationContext={...e.propagationContext,...p}}function gr(e,t,n){if(n&&Object.keys(n).length){e[t]={...e[t]};for(const r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[t][r]=n[r])}}function _r(e,t,n,r,o,s){const{normalizeDepth:i=3,normalizeMaxBreadth:a=1e3}=e,c={...t,event_id:t.event_id||n.event_id||De(),timestamp:t.timestamp||ye()},u=n.integrations||e.integrations.map((e=>e.name));!function(e,t){const{environment:n,release:r,dist:o,maxValueLength:s=250}=t;"environment"in e||(e.environment="environment"in t?n:Un);void 0===e.release&&void 0!==r&&(e.release=r);void 0===e.dist&&void 0!==o&&(e.dist=o);e.message&&(e.message=h(e.message,s));const i=e.exception&&e.exception.values&&e.exception.values[0];i&&i.value&&(i.value=h(i.value,s));const a=e.request;a&&a.url&&(a.url=h(a.url,s))}(c,e),function(e,t){t.length>0&&(e.sdk=e.sdk||{},e.sdk.integrations=[...e.sdk.integrations||[],...t])}(c,u),o&&o.emit("applyFrameMetadata",t),void 0===t.type&&function(e,t){const n=k._sentryDebugIds;if(!n)return;let r;const o=yr.get(t);o?r=o:(r=new Map,yr.set(t,r));const s=Object.entries(n).reduce(((e,[n,o])=>{let s;const i=r.get(n);i?s=i:(s=t(n),r.set(n,s));for(let t=s.length-1;t>=0;t--){const n=s[t];if(n.filename){e[n.filename]=o;break}}return e}),{});try{e.exception.values.forEach((e=>{e.stacktrace.frames.forEach((e=>{e.filename&&(e.debug_id=s[e.filename])}))}))}catch(e){}}(c,e.stackParser);const l=function(e,t){if(!t)return e;const n=e?e.clone():new jt;return n.update(t),n}(r,n.captureContext);n.mechanism&&$e(c,n.mechanism);const d=o?o.getEventProcessors():[],p=Jt().getScopeData();if(s){mr(p,s.getScopeData())}if(l){mr(p,l.getScopeData())}const f=[...n.attachments||[],...p.attachments];f.length&&(n.attachments=f),fr(c,p);return hr([...d,...p.eventProcessors],c,n).then((e=>(e&&function(e){const t={};try{e.exception.values.forEach((e=>{e.stacktrace.frames.forEach((e=>{e.debug_id&&(e.abs_path?t[e.abs_path]=e.debug_id:e.filename&&(t[e.filename]=e.debug_id),delete e.debug_id)}))}))}catch(e){}if(0===Object.keys(t).length)return;e.debug_meta=e.debug_meta||{},e.debug_meta.images=e.debug_meta.images||[];const n=e.debug_meta.images;Object.entries(t).forEach((([e,t])=>{n.push({type:"sourcemap",code_file:e,debug_id:t})}))}(e),"number"==typeof i&&i>0?function(e,t,n){if(!e)return null;const r={...e,...e.breadcrumbs&&{breadcrumbs:e.breadcrumbs.map((e=>({...e,...e.data&&{data:je(e.data,t,n)}})))},...e.user&&{user:je(e.user,t,n)},...e.contexts&&{contexts:je(e.contexts,t,n)},...e.extra&&{extra:je(e.extra,t,n)}};e.contexts&&e.contexts.trace&&r.contexts&&(r.contexts.trace=e.contexts.trace,e.contexts.trace.data&&(r.contexts.trace.data=je(e.contexts.trace.data,t,n)));e.spans&&(r.spans=e.spans.map((e=>({...e,...e.data&&{data:je(e.data,t,n)}}))));return r}(e,i,a):e)))}const yr=new WeakMap;function vr(e){if(e)return function(e){return e instanceof jt||"function"==typeof e}(e)||function(e){return Object.keys(e).some((e=>br.includes(e)))}(e)?{captureContext:e}:e}const br=["user","level","extra","contexts","tags","fingerprint","requestSession","propagationContext"];function Sr(e,t){return Gt().captureException(e,vr(t))}function wr(e,t){const n="string"==typeof t?t:void 0,r="string"!=typeof t?{captureContext:t}:void 0;return Gt().captureMessage(e,n,r)}function kr(e,t){return Gt().captureEvent(e,t)}function xr(e,t){Xt().setContext(e,t)}function Er(e){Xt().setExtras(e)}function Cr(e,t){Xt().setExtra(e,t)}function Tr(e){Xt().setTags(e)}function Ir(e,t){Xt().setTag(e,t)}function Mr(e){Xt().setUser(e)}function Rr(){return Xt().lastEventId()}function Ar(e){Xt().addEventProcessor(e)}function Nr(e){const t=Qt(),n=Xt(),r=Gt(),{release:o,environment:s=Un}=t&&t.getOptions()||{},{userAgent:i}=k.navigator||{},a=Lt({release:o,environment:s,user:r.getUser()||n.getUser(),...i&&{userAgent:i},...e}),c=n.getSession();return c&&"ok"===c.status&&Pt(c,{status:"exited"}),Or(),n.setSession(a),r.setSession(a),a}function Or(){const e=Xt(),t=Gt(),n=t.getSession()||e.getSession();n&&function(e,t){let n={};t?n={status:t}:"ok"===e.status&&(n={status:"exited"}),Pt(e,n)}(n),Dr(),e.setSession(),t.setSession()}function Dr(){const e=Xt(),t=Gt(),n=Qt(),r=t.getSession()||e.getSession();r&&n&&n.captureSession(r)}function Lr(e=!1){e?Or():Dr()}const Pr="7";function Fr(e){const t=e.protocol?`${e.protocol}:`:"",n=e.port?`:${e.port}`:"";return`${t}//${e.host}${n}${e.path?`/${e.path}`:""}/api/`}function $r(e,t){return n={sentry_key:e.publicKey,sentry_version:Pr,...t&&{sentry_client:`${t.name}/${t.version}`}},Object.keys(n).map((e=>`${encodeURIComponent(e)}=${encodeURIComponent(n[e])}`)).join("&");var n}function Br(e,t,n){return t||`${function(e){return`${Fr(e)}${e.projectId}/envelope/`}(e)}?${$r(e,n)}`}const Ur=[];function jr(e){const t=e.defaultIntegrations||[],n=e.integrations;let r;t.forEach((e=>{e.isDefaultInstance=!0})),r=Array.isArray(n)?[...t,...n]:"function"==typeof n?Ue(n(t)):t;const o=function(e){const t={};return e.forEach((e=>{const{name:n}=e,r=t[n];r&&!r.isDefaultInstance&&e.isDefaultInstance||(t[n]=e)})),Object.values(t)}(r),s=o.findIndex((e=>"Debug"===e.name));if(s>-1){const[e]=o.splice(s,1);o.push(e)}return o}function Hr(e,t){for(const n of t)n&&n.afterAllSetup&&n.afterAllSetup(e)}function zr(e,t,n){if(n[t.name])Nt&&L.log(`Integration skipped because it was already installed: ${t.name}`);else{if(n[t.name]=t,-1===Ur.indexOf(t.name)&&"function"==typeof t.setupOnce&&(t.setupOnce(),Ur.push(t.name)),t.setup&&"function"==typeof t.setup&&t.setup(e),"function"==typeof t.preprocessEvent){const n=t.preprocessEvent.bind(t);e.on("preprocessEvent",((t,r)=>n(t,r,e)))}if("function"==typeof t.processEvent){const n=t.processEvent.bind(t),r=Object.assign(((t,r)=>n(t,r,e)),{id:t.name});e.addEventProcessor(r)}Nt&&L.log(`Integration installed: ${t.name}`)}}const qr="Not capturing exception because it's already been captured.";class Wr{constructor(e){if(this._options=e,this._integrations={},this._numProcessing=0,this._outcomes={},this._hooks={},this._eventProcessors=[],e.dsn?this._dsn=U(e.dsn):Nt&&L.warn("No DSN provided, client will not send events."),this._dsn){const t=Br(this._dsn,e.tunnel,e._metadata?e._metadata.sdk:void 0);this._transport=e.transport({tunnel:this._options.tunnel,recordDroppedEvent:this.recordDroppedEvent.bind(this),...e.transportOptions,url:t})}}captureException(e,t,n){const r=De();if(Be(e))return Nt&&L.log(qr),r;const o={event_id:r,...t};return this._process(this.eventFromException(e,o).then((e=>this._captureEvent(e,o,n)))),o.event_id}captureMessage(e,t,n,r){const o={event_id:De(),...n},s=i(e)?e:String(e),c=a(e)?this.eventFromMessage(s,t,o):this.eventFromException(e,o);return this._process(c.then((e=>this._captureEvent(e,o,r)))),o.event_id}captureEvent(e,t,n){const r=De();if(t&&t.originalException&&Be(t.originalException))return Nt&&L.log(qr),r;const o={event_id:r,...t},s=(e.sdkProcessingMetadata||{}).capturedSpanScope;return this._process(this._captureEvent(e,o,s||n)),o.event_id}captureSession(e){"string"!=typeof e.release?Nt&&L.warn("Discarded session because of missing or non-string release"):(this.sendSession(e),Pt(e,{init:!1}))}getDsn(){return this._dsn}getOptions(){return this._options}getSdkMetadata(){return this._options._metadata}getTransport(){return this._transport}flush(e){const t=this._transport;return t?(this.emit("flush"),this._isClientDoneProcessing(e).then((n=>t.flush(e).then((e=>n&&e))))):Xe(!0)}close(e){return this.flush(e).then((e=>(this.getOptions().enabled=!1,this.emit("close"),e)))}getEventProcessors(){return this._eventProcessors}addEventProcessor(e){this._eventProcessors.push(e)}init(){(this._isEnabled()||this._options.integrations.some((({name:e})=>e.startsWith("Spotlight"))))&&this._setupIntegrations()}getIntegrationByName(e){return this._integrations[e]}addIntegration(e){const t=this._integrations[e.name];zr(this,e,this._integrations),t||Hr(this,[e])}sendEvent(e,t={}){this.emit("beforeSendEvent",e,t);let n=function(e,t,n,r){const o=kt(n),s=e.type&&"replay_event"!==e.type?e.type:"event";!function(e,t){t&&(e.sdk=e.sdk||{},e.sdk.name=e.sdk.name||t.name,e.sdk.version=e.sdk.version||t.version,e.sdk.integrations=[...e.sdk.integrations||[],...t.integrations||[]],e.sdk.packages=[...e.sdk.packages||[],...t.packages||[]])}(e,n&&n.sdk);const i=xt(e,o,r,t);return delete e.sdkProcessingMetadata,pt(i,[[{type:s},e]])}(e,this._dsn,this._options._metadata,this._options.tunnel);for(const e of t.attachments||[])n=ht(n,bt(e));const r=this.sendEnvelope(n);r&&r.then((t=>this.emit("afterSendEvent",e,t)),null)}sendSession(e){const t=function(e,t,n,r){const o=kt(n);return pt({sent_at:(new Date).toISOString(),...o&&{sdk:o},...!!r&&t&&{dsn:F(t)}},["aggregates"in e?[{type:"sessions"},e]:[{type:"session"},e.toJSON()]])}(e,this._dsn,this._options._metadata,this._options.tunnel);this.sendEnvelope(t)}recordDroppedEvent(e,t,n){if(this._options.sendClientReports){const r="number"==typeof n?n:1,o=`${e}:${t}`;Nt&&L.log(`Recording outcome: "${o}"${r>1?` (${r} times)`:""}`),this._outcomes[o]=(this._outcomes[o]||0)+r}}on(e,t){const n=this._hooks[e]=this._hooks[e]||[];return n.push(t),()=>{const e=n.indexOf(t);e>-1&&n.splice(e,1)}}emit(e,...t){const n=this._hooks[e];n&&n.forEach((e=>e(...t)))}sendEnvelope(e){return this.emit("beforeEnvelope",e),this._isEnabled()&&this._transport?this._transport.send(e).then(null,(e=>(Nt&&L.error("Error while sending event:",e),e))):(Nt&&L.error("Transport disabled"),Xe({}))}_setupIntegrations(){const{integrations:e}=this._options;this._integrations=function(e,t){const n={};return t.forEach((t=>{t&&zr(e,t,n)})),n}(this,e),Hr(this,e)}_updateSessionFromEvent(e,t){let n=!1,r=!1;const o=t.exception&&t.exception.values;if(o){r=!0;for(const e of o){const t=e.mechanism;if(t&&!1===t.handled){n=!0;break}}}const s="ok"===e.status;(s&&0===e.errors||s&&n)&&(Pt(e,{...n&&{status:"crashed"},errors:e.errors||Number(r||n)}),this.captureSession(e))}_isClientDoneProcessing(e){return new Ke((t=>{let n=0;const r=setInterval((()=>{0==this._numProcessing?(clearInterval(r),t(!0)):(n+=1,e&&n>=e&&(clearInterval(r),t(!1)))}),1)}))}_isEnabled(){return!1!==this.getOptions().enabled&&void 0!==this._transport}_prepareEvent(e,t,n,r=Xt()){const o=this.getOptions(),s=Object.keys(this._integrations);return!t.integrations&&s.length>0&&(t.integrations=s),this.emit("preprocessEvent",e,t),e.type||r.setLastEventId(e.event_id||t.event_id),_r(o,e,t,n,this,r).then((e=>{if(null===e)return e;const t={...r.getPropagationContext(),...n?n.getPropagationContext():void 0};if(!(e.contexts&&e.contexts.trace)&&t){const{traceId:n,spanId:r,parentSpanId:o,dsc:s}=t;e.contexts={trace:X({trace_id:n,span_id:r,parent_span_id:o}),...e.contexts};const i=s||zn(n,this);e.sdkProcessingMetadata={dynamicSamplingContext:i,...e.sdkProcessingMetadata}}return e}))}_captureEvent(e,t={},n){return this._processEvent(e,t,n).then((e=>e.event_id),(e=>{if(Nt){const t=e;"log"===t.logLevel?L.log(t.message):L.warn(t)}}))}_processEvent(e,t,n){const r=this.getOptions(),{sampleRate:o}=r,s=Vr(e),i=Yr(e),a=e.type||"error",u=`before send for type \`${a}\``,d=void 0===o?void 0:Wn(o);if(i&&"number"==typeof d&&Math.random()>d)return this.recordDroppedEvent("sample_rate","error",e),Je(new j(`Discarding event because it's not included in the random sample (sampling rate = ${o})`,"log"));const p="replay_event"===a?"replay":a,h=(e.sdkProcessingMetadata||{}).capturedSpanIsolationScope;return this._prepareEvent(e,t,n,h).then((n=>{if(null===n)throw this.recordDroppedEvent("event_processor",p,e),new j("An event processor returned `null`, will not send event.","log");if(t.data&&!0===t.data.__sentry__)return n;const o=function(e,t,n,r){const{beforeSend:o,beforeSendTransaction:s,beforeSendSpan:i}=t;if(Yr(n)&&o)return o(n,r);if(Vr(n)){if(n.spans&&i){const t=[];for(const r of n.spans){const n=i(r);n?t.push(n):e.recordDroppedEvent("before_send","span")}n.spans=t}if(s){if(n.spans){const e=n.spans.length;n.sdkProcessingMetadata={...n.sdkProcessingMetadata,spanCountBeforeProcessing:e}}return s(n,r)}}return n}(this,r,n,t);return function(e,t){const n=`${t} must return \`null\` or a valid event.`;if(l(e))return e.then((e=>{if(!c(e)&&null!==e)throw new j(n);return e}),(e=>{throw new j(`${t} rejected with ${e}`)}));if(!c(e)&&null!==e)throw new j(n);return e}(o,u)})).then((r=>{if(null===r){if(this.recordDroppedEvent("before_send",p,e),s){const t=1+(e.spans||[]).length;this.recordDroppedEvent("before_send","span",t)}throw new j(`${u} returned \`null\`, will not send event.`,"log")}const o=n&&n.getSession();if(!s&&o&&this._updateSessionFromEvent(o,r),s){const e=(r.sdkProcessingMetadata&&r.sdkProcessingMetadata.spanCountBeforeProcessing||0)-(r.spans?r.spans.length:0);e>0&&this.recordDroppedEvent("before_send","span",e)}const i=r.transaction_info;if(s&&i&&r.transaction!==e.transaction){const e="custom";r.transaction_info={...i,source:e}}return this.sendEvent(r,t),r})).then(null,(e=>{if(e instanceof j)throw e;throw this.captureException(e,{data:{__sentry__:!0},originalException:e}),new j(`Event processing pipeline threw an error, original event will not be sent. Details have been sent as a new event.\nReason: ${e}`)}))}_process(e){this._numProcessing++,e.then((e=>(this._numProcessing--,e)),(e=>(this._numProcessing--,e)))}_clearOutcomes(){const e=this._outcomes;return this._outcomes={},Object.entries(e).map((([e,t])=>{const[n,r]=e.split(":");return{reason:n,category:r,quantity:t}}))}_flushOutcomes(){Nt&&L.log("Flushing outcomes...");const e=this._clearOutcomes();if(0===e.length)return void(Nt&&L.log("No outcomes to send"));if(!this._dsn)return void(Nt&&L.log("No dsn provided, will not send outcomes"));Nt&&L.log("Sending outcomes:",e);const t=(n=e,pt((r=this._options.tunnel&&F(this._dsn))?{dsn:r}:{},[[{type:"client_report"},{timestamp:o||ye(),discarded_events:n}]]));var n,r,o;this.sendEnvelope(t)}}function Yr(e){return void 0===e.type}function Vr(e){return"transaction"===e.type}function Gr(e){Gt().setClient(e)}const Xr=64;function Jr(e,t,n=Qe(e.bufferSize||Xr)){let r={};return{send:function(o){const s=[];if(ft(o,((t,n)=>{const o=wt(n);if(Tt(r,o)){const r=Kr(t,n);e.recordDroppedEvent("ratelimit_backoff",o,r)}else s.push(t)})),0===s.length)return Xe({});const i=pt(o[0],s),a=t=>{ft(i,((n,r)=>{const o=Kr(n,r);e.recordDroppedEvent(t,wt(r),o)}))};return n.add((()=>t({body:_t(i)}).then((e=>(void 0!==e.statusCode&&(e.statusCode<200||e.statusCode>=300)&&Nt&&L.warn(`Sentry responded with status code ${e.statusCode} to sent event.`),r=It(r,e),e)),(e=>{throw a("network_error"),e})))).then((e=>e),(e=>{if(e instanceof j)return Nt&&L.error("Skipped sending event because buffer is full."),a("queue_overflow"),Xe({});throw e}))},flush:e=>n.drain(e)}}function Kr(e,t){if("event"===t||"transaction"===t)return Array.isArray(e)?e[1]:void 0}const Qr=100,Zr=5e3,eo=36e5;function to(e){function t(...e){Nt&&L.info("[Offline]:",...e)}return n=>{const r=e(n);if(!n.createStore)throw new Error("No `createStore` function was provided");const o=n.createStore(n);let s,i=Zr;function a(e){s&&clearTimeout(s),s=setTimeout((async()=>{s=void 0;const e=await o.shift();e&&(t("Attempting to send previously queued event"),e[0].sent_at=(new Date).toISOString(),u(e,!0).catch((e=>{t("Failed to retry sending",e)})))}),e),"number"!=typeof s&&s.unref&&s.unref()}function c(){s||(a(i),i=Math.min(2*i,eo))}async function u(e,s=!1){if(!s&&mt(e,["replay_event","replay_recording"]))return await o.push(e),a(Qr),{};try{const t=await r.send(e);let n=Qr;if(t)if(t.headers&&t.headers["retry-after"])n=Ct(t.headers["retry-after"]);else if(t.headers&&t.headers["x-sentry-rate-limits"])n=6e4;else if((t.statusCode||0)>=400)return t;return a(n),i=Zr,t}catch(r){if(await function(e,t,r){return!mt(e,["client_report"])&&(!n.shouldStore||n.shouldStore(e,t,r))}(e,r,i))return s?await o.unshift(e):await o.push(e),c(),t("Error sending. Event queued.",r),{};throw r}}return n.flushAtStartup&&c(),{send:u,flush:e=>r.flush(e)}}}function no(e,t){let n;return ft(e,((e,r)=>(t.includes(r)&&(n=Array.isArray(e)?e[1]:void 0),!!n))),n}function ro(e,t){const n=t&&t.getDsn(),r=t&&t.getOptions().tunnel;return function(e,t){return!!t&&e.includes(t.host)}(e,n)||function(e,t){if(!t)return!1;return oo(e)===oo(t)}(e,r)}function oo(e){return"/"===e[e.length-1]?e.slice(0,-1):e}const so=100;function io(e,t){const n=Qt(),r=Xt();if(!n)return;const{beforeBreadcrumb:o=null,maxBreadcrumbs:s=so}=n.getOptions();if(s<=0)return;const i={timestamp:ye(),...e},a=o?D((()=>o(i,t))):i;null!==a&&(n.emit&&n.emit("beforeAddBreadcrumb",a,t),r.addBreadcrumb(a,s))}let ao;const co=new WeakMap,uo=()=>({name:"FunctionToString",setupOnce(){ao=Function.prototype.toString;try{Function.prototype.toString=function(...e){const t=W(this),n=co.has(Qt())&&void 0!==t?t:this;return ao.apply(n,e)}}catch(e){}},setup(e){co.set(e,!0)}}),lo=[/^Script error\.?$/,/^Javascript error: Script error\.? on line 0$/,/^ResizeObserver loop completed with undelivered notifications.$/,/^Cannot redefine property: googletag$/,"undefined is not an object (evaluating 'a.L')",'can\'t redefine non-configurable property "solana"',"vv().getRestrictions is not a function. (In 'vv().getRestrictions(1,a)', 'vv().getRestrictions' is undefined)","Can't find variable: _AutofillCallbackHandler"],po=(e={})=>({name:"InboundFilters",processEvent(t,n,r){const o=r.getOptions(),s=function(e={},t={}){return{allowUrls:[...e.allowUrls||[],...t.allowUrls||[]],denyUrls:[...e.denyUrls||[],...t.denyUrls||[]],ignoreErrors:[...e.ignoreErrors||[],...t.ignoreErrors||[],...e.disableErrorDefaults?[]:lo],ignoreTransactions:[...e.ignoreTransactions||[],...t.ignoreTransactions||[]],ignoreInternal:void 0===e.ignoreInternal||e.ignoreInternal}}(e,o);return function(e,t){if(t.ignoreInternal&&function(e){try{return"SentryError"===e.exception.values[0].type}catch(e){}return!1}(e))return Nt&&L.warn(`Event dropped due to being internal Sentry Error.\nEvent: ${Pe(e)}`),!0;if(function(e,t){if(e.type||!t||!t.length)return!1;return function(e){const t=[];e.message&&t.push(e.message);let n;try{n=e.exception.values[e.exception.values.length-1]}catch(e){}n&&n.value&&(t.push(n.value),n.type&&t.push(`${n.type}: ${n.value}`));return t}(e).some((e=>_(e,t)))}(e,t.ignoreErrors))return Nt&&L.warn(`Event dropped due to being matched by \`ignoreErrors\` option.\nEvent: ${Pe(e)}`),!0;if(function(e){if(e.type)return!1;if(!e.exception||!e.exception.values||0===e.exception.values.length)return!1;return!e.message&&!e.exception.values.some((e=>e.stacktrace||e.type&&"Error"!==e.type||e.value))}(e))return Nt&&L.warn(`Event dropped due to not having an error message, error type or stacktrace.\nEvent: ${Pe(e)}`),!0;if(function(e,t){if("transaction"!==e.type||!t||!t.length)return!1;const n=e.transaction;return!!n&&_(n,t)}(e,t.ignoreTransactions))return Nt&&L.warn(`Event dropped due to being matched by \`ignoreTransactions\` option.\nEvent: ${Pe(e)}`),!0;if(function(e,t){if(!t||!t.length)return!1;const n=ho(e);return!!n&&_(n,t)}(e,t.denyUrls))return Nt&&L.warn(`Event dropped due to being matched by \`denyUrls\` option.\nEvent: ${Pe(e)}.\nUrl: ${ho(e)}`),!0;if(!function(e,t){if(!t||!t.length)return!0;const n=ho(e);return!n||_(n,t)}(e,t.allowUrls))return Nt&&L.warn(`Event dropped due to not being matched by \`allowUrls\` option.\nEvent: ${Pe(e)}.\nUrl: ${ho(e)}`),!0;return!1}(t,s)?null:t}});function ho(e){try{let t;try{t=e.exception.values[0].stacktrace.frames}catch(e){}return t?function(e=[]){for(let t=e.length-1;t>=0;t--){const n=e[t];if(n&&"<anonymous>"!==n.filename&&"[native code]"!==n.filename)return n.filename||null}return null}(t):null}catch(t){return Nt&&L.error(`Cannot extract url for event ${Pe(e)}`),null}}const fo=new Map,mo=new Set;function go(e,t){return function(e){if(k._sentryModuleMetadata)for(const t of Object.keys(k._sentryModuleMetadata)){const n=k._sentryModuleMetadata[t];if(mo.has(t))continue;mo.add(t);const r=e(t);for(const e of r.reverse())if(e.filename){fo.set(e.filename,n);break}}}(e),fo.get(t)}function _o(e,t){try{t.exception.values.forEach((t=>{if(t.stacktrace)for(const n of t.stacktrace.frames||[]){if(!n.filename||n.module_metadata)continue;const t=go(e,n.filename);t&&(n.module_metadata=t)}}))}catch(e){}}function yo(e){try{e.exception.values.forEach((e=>{if(e.stacktrace)for(const t of e.stacktrace.frames||[])delete t.module_metadata}))}catch(e){}}const vo=()=>({name:"ModuleMetadata",setup(e){e.on("beforeEnvelope",(e=>{ft(e,((e,t)=>{if("event"===t){const t=Array.isArray(e)?e[1]:void 0;t&&(yo(t),e[1]=t)}}))})),e.on("applyFrameMetadata",(t=>{if(t.type)return;_o(e.getOptions().stackParser,t)}))}}),bo=(e={})=>{const t=e.levels||N;return{name:"CaptureConsole",setup(e){"console"in k&&de((({args:n,level:r})=>{Qt()===e&&t.includes(r)&&function(e,t){const n={level:tt(t),extra:{arguments:e}};Kt((r=>{if(r.addEventProcessor((e=>(e.logger="console",$e(e,{handled:!1,type:"console"}),e))),"assert"===t){if(!e[0]){const t=`Assertion failed: ${m(e.slice(1)," ")||"console.assert"}`;r.setExtra("arguments",e.slice(1)),wr(t,n)}return}const o=e.find((e=>e instanceof Error));if(o)return void Sr(o,n);wr(m(e," "),n)}))}(n,r)}))}}};const So=(e={})=>{const t={debugger:!1,stringify:!1,...e};return{name:"Debug",setup(e){e.on("beforeSendEvent",((e,n)=>{t.debugger,D((()=>{t.stringify?(console.log(JSON.stringify(e,null,2)),n&&Object.keys(n).length&&console.log(JSON.stringify(n,null,2))):(console.log(e),n&&Object.keys(n).length&&console.log(n))}))}))}}},wo=()=>{let e;return{name:"Dedupe",processEvent(t){if(t.type)return t;try{if(function(e,t){if(!t)return!1;if(function(e,t){const n=e.message,r=t.message;if(!n&&!r)return!1;if(n&&!r||!n&&r)return!1;if(n!==r)return!1;if(!xo(e,t))return!1;if(!ko(e,t))return!1;return!0}(e,t))return!0;if(function(e,t){const n=Eo(t),r=Eo(e);if(!n||!r)return!1;if(n.type!==r.type||n.value!==r.value)return!1;if(!xo(e,t))return!1;if(!ko(e,t))return!1;return!0}(e,t))return!0;return!1}(t,e))return Nt&&L.warn("Event dropped due to being a duplicate of previously captured event."),null}catch(e){}return e=t}}};function ko(e,t){let n=se(e),r=se(t);if(!n&&!r)return!0;if(n&&!r||!n&&r)return!1;if(r.length!==n.length)return!1;for(let e=0;e<r.length;e++){const t=r[e],o=n[e];if(t.filename!==o.filename||t.lineno!==o.lineno||t.colno!==o.colno||t.function!==o.function)return!1}return!0}function xo(e,t){let n=e.fingerprint,r=t.fingerprint;if(!n&&!r)return!0;if(n&&!r||!n&&r)return!1;try{return!(n.join("")!==r.join(""))}catch(e){return!1}}function Eo(e){return e.exception&&e.exception.values&&e.exception.values[0]}const Co=(e={})=>{const{depth:n=3,captureErrorCause:r=!0}=e;return{name:"ExtraErrorData",processEvent(e,o,s){const{maxValueLength:i=250}=s.getOptions();return function(e,n={},r,o,s){if(!n.originalException||!t(n.originalException))return e;const i=n.originalException.name||n.originalException.constructor.name,a=function(e,n,r){try{const o=["name","message","stack","line","column","fileName","lineNumber","columnNumber","toJSON"],s={};for(const n of Object.keys(e)){if(-1!==o.indexOf(n))continue;const i=e[n];s[n]=t(i)||"string"==typeof i?h(`${i}`,r):i}if(n&&void 0!==e.cause&&(s.cause=t(e.cause)?e.cause.toString():e.cause),"function"==typeof e.toJSON){const n=e.toJSON();for(const e of Object.keys(n)){const r=n[e];s[e]=t(r)?r.toString():r}}return s}catch(e){Nt&&L.error("Unable to extract extra data from the Error object:",e)}return null}(n.originalException,o,s);if(a){const t={...e.contexts},n=je(a,r);return c(n)&&(z(n,"__sentry_skip_normalization__",!0),t[i]=n),{...e,contexts:t}}return e}(e,o,n,r,i)}}};const To=(e={})=>{const t=e.root,n=e.prefix||"app:///",r="window"in k&&void 0!==k.window,o=e.iteratee||function({isBrowser:e,root:t,prefix:n}){return r=>{if(!r.filename)return r;const o=/^[a-zA-Z]:\\/.test(r.filename)||r.filename.includes("\\")&&!r.filename.includes("/"),s=/^\//.test(r.filename);if(e){if(t){const e=r.filename;0===e.indexOf(t)&&(r.filename=e.replace(t,n))}}else if(o||s){const e=o?r.filename.replace(/^[a-zA-Z]:/,"").replace(/\\/g,"/"):r.filename,s=t?function(e,t){e=We(e).slice(1),t=We(t).slice(1);const n=Ye(e.split("/")),r=Ye(t.split("/")),o=Math.min(n.length,r.length);let s=o;for(let e=0;e<o;e++)if(n[e]!==r[e]){s=e;break}let i=[];for(let e=s;e<n.length;e++)i.push("..");return i=i.concat(r.slice(s)),i.join("/")}(t,e):Ve(e);r.filename=`${n}${s}`}return r}}({isBrowser:r,root:t,prefix:n});return{name:"RewriteFrames",processEvent(e){let t=e;return e.exception&&Array.isArray(e.exception.values)&&(t=function(e){try{return{...e,exception:{...e.exception,values:e.exception.values.map((e=>{return{...e,...e.stacktrace&&{stacktrace:(t=e.stacktrace,{...t,frames:t&&t.frames&&t.frames.map((e=>o(e)))})}};var t}))}}}catch(t){return e}}(t)),t}}};const Io=()=>{const e=1e3*ve();return{name:"SessionTiming",processEvent(t){const n=1e3*ve();return{...t,extra:{...t.extra,"session:start":e,"session:duration":n-e,"session:end":n}}}}};function Mo(e){return{...e,path:"path"in e&&Array.isArray(e.path)?e.path.join("."):void 0,keys:"keys"in e?JSON.stringify(e.keys):void 0,unionErrors:"unionErrors"in e?JSON.stringify(e.unionErrors):void 0}}function Ro(e){const t=new Set;for(const n of e.issues)n.path&&n.path[0]&&t.add(n.path[0]);return`Failed to validate keys: ${h(Array.from(t).join(", "),100)}`}function Ao(e,n,r){return n.exception&&n.exception.values&&r&&r.originalException&&(t(o=r.originalException)&&"ZodError"===o.name&&Array.isArray(o.errors))&&0!==r.originalException.issues.length?{...n,exception:{...n.exception,values:[{...n.exception.values[0],value:Ro(r.originalException)},...n.exception.values.slice(1)]},extra:{...n.extra,"zoderror.issues":r.originalException.errors.slice(0,e).map(Mo)}}:n;var o}const No=(e={})=>{const t=e.limit||10;return{name:"ZodErrors",processEvent:(e,n)=>Ao(t,e,n)}},Oo=e=>({name:"ThirdPartyErrorsFilter",setup(e){e.on("beforeEnvelope",(e=>{ft(e,((e,t)=>{if("event"===t){const t=Array.isArray(e)?e[1]:void 0;t&&(yo(t),e[1]=t)}}))})),e.on("applyFrameMetadata",(t=>{if(t.type)return;_o(e.getOptions().stackParser,t)}))},processEvent(t){const n=function(e){const t=se(e);if(!t)return;return t.filter((e=>!!e.filename)).map((e=>e.module_metadata?Object.keys(e.module_metadata).filter((e=>e.startsWith(Do))).map((e=>e.slice(Do.length))):[]))}(t);if(n){const r=n["drop-error-if-contains-third-party-frames"===e.behaviour||"apply-tag-if-contains-third-party-frames"===e.behaviour?"some":"every"]((t=>!t.some((t=>e.filterKeys.includes(t)))));if(r){if("drop-error-if-contains-third-party-frames"===e.behaviour||"drop-error-if-exclusively-contains-third-party-frames"===e.behaviour)return null;t.tags={...t.tags,third_party_code:!0}}}return t}});const Do="_sentryBundlerPluginAppKey:",Lo="c",Po="g",Fo="s",$o="d";function Bo(e,t){const n=x("globalMetricsAggregators",(()=>new WeakMap)),r=n.get(e);if(r)return r;const o=new t(e);return e.on("flush",(()=>o.flush())),e.on("close",(()=>o.close())),n.set(e,o),o}function Uo(e,t,n,r,o={}){const s=o.client||Qt();if(!s)return;const i=Mn(),a=i?In(i):void 0,c=a&&Sn(a).description,{unit:u,tags:l,timestamp:d}=o,{release:p,environment:h}=s.getOptions(),f={};p&&(f.release=p),h&&(f.environment=h),c&&(f.transaction=c),Nt&&L.log(`Adding value of ${r} to ${t} metric ${n}`);Bo(s,e).add(t,n,r,u,{...f,...l},d)}function jo(e,t,n,r){Uo(e,$o,t,zo(n),r)}const Ho={increment:function(e,t,n=1,r){Uo(e,"c",t,zo(n),r)},distribution:jo,set:function(e,t,n,r){Uo(e,"s",t,n,r)},gauge:function(e,t,n,r){Uo(e,"g",t,zo(n),r)},timing:function(e,t,n,r="second",o){if("function"==typeof n){const r=ve();return Qn({op:"metrics.timing",name:t,startTime:r,onlyIfParent:!0},(s=>Bn((()=>n()),(()=>{}),(()=>{const n=ve();jo(e,t,n-r,{...o,unit:"second"}),s.end(n)}))))}jo(e,t,n,{...o,unit:r})},getMetricsAggregatorForClient:Bo};function zo(e){return"string"==typeof e?parseInt(e):e}function qo(e){return e.replace(/[^\w\-./]+/gi,"")}const Wo=[["\n","\\n"],["\r","\\r"],["\t","\\t"],["\\","\\\\"],["|","\\u{7c}"],[",","\\u{2c}"]];function Yo(e){return[...e].reduce(((e,t)=>e+function(e){for(const[t,n]of Wo)if(e===t)return n;return e}(t)),"")}function Vo(e,t){L.log(`Flushing aggregated metrics, number of metrics: ${t.length}`);const n=function(e,t,n,r){const o={sent_at:(new Date).toISOString()};n&&n.sdk&&(o.sdk={name:n.sdk.name,version:n.sdk.version});r&&t&&(o.dsn=F(t));const s=function(e){const t=function(e){let t="";for(const n of e){const e=Object.entries(n.tags),r=e.length>0?`|#${e.map((([e,t])=>`${e}:${t}`)).join(",")}`:"";t+=`${n.name}@${n.unit}:${n.metric}|${n.metricType}${r}|T${n.timestamp}\n`}return t}(e);return[{type:"statsd",length:t.length},t]}(e);return pt(o,[s])}(t,e.getDsn(),e.getSdkMetadata(),e.getOptions().tunnel);e.sendEnvelope(n)}const Go={[Lo]:class{constructor(e){this._value=e}get weight(){return 1}add(e){this._value+=e}toString(){return`${this._value}`}},[Po]:class{constructor(e){this._last=e,this._min=e,this._max=e,this._sum=e,this._count=1}get weight(){return 5}add(e){this._last=e,e<this._min&&(this._min=e),e>this._max&&(this._max=e),this._sum+=e,this._count++}toString(){return`${this._last}:${this._min}:${this._max}:${this._sum}:${this._count}`}},[$o]:class{constructor(e){this._value=[e]}get weight(){return this._value.length}add(e){this._value.push(e)}toString(){return this._value.join(":")}},[Fo]:class{constructor(e){this.first=e,this._value=new Set([e])}get weight(){return this._value.size}add(e){this._value.add(e)}toString(){return Array.from(this._value).map((e=>"string"==typeof e?function(e){let t=0;for(let n=0;n<e.length;n++)t=(t<<5)-t+e.charCodeAt(n),t&=t;return t>>>0}(e):e)).join(":")}}};class Xo{constructor(e){this._client=e,this._buckets=new Map,this._interval=setInterval((()=>this.flush()),5e3)}add(e,t,n,r="none",o={},s=ve()){const i=Math.floor(s),a=t.replace(/[^\w\-.]+/gi,"_");const c=function(e){const t={};for(const n in e)Object.prototype.hasOwnProperty.call(e,n)&&(t[qo(n)]=Yo(String(e[n])));return t}(o),u=function(e){return e.replace(/[^\w]+/gi,"_")}(r),l=function(e,t,n,r){const o=Object.entries(X(r)).sort(((e,t)=>e[0].localeCompare(t[0])));return`${e}${t}${n}${o}`}(e,a,u,c);let d=this._buckets.get(l);const p=d&&"s"===e?d.metric.weight:0;d?(d.metric.add(n),d.timestamp<i&&(d.timestamp=i)):(d={metric:new Go[e](n),timestamp:i,metricType:e,name:a,unit:u,tags:c},this._buckets.set(l,d));Rn(e,a,"string"==typeof n?d.metric.weight-p:n,u,o,l)}flush(){if(0===this._buckets.size)return;const e=Array.from(this._buckets.values());Vo(this._client,e),this._buckets.clear()}close(){clearInterval(this._interval),this.flush()}}function Jo(e,t,n,r,o="auto.http.browser"){if(!e.fetchData)return;const s=Fn()&&t(e.fetchData.url);if(e.endTimestamp&&s){const t=e.fetchData.__span;if(!t)return;const n=r[t];return void(n&&(!function(e,t){if(t.response){fn(e,t.response.status);const n=t.response&&t.response.headers&&t.response.headers.get("content-length");if(n){const t=parseInt(n);t>0&&e.setAttribute("http.response_content_length",t)}}else t.error&&e.setStatus({code:pn,message:"internal_error"});e.end()}(n,e),delete r[t]))}const i=Gt(),a=Qt(),{method:c,url:u}=e.fetchData,l=function(e){try{return new URL(e).href}catch(e){return}}(u),p=l?Ze(l).host:void 0,h=!!Mn(),f=s&&h?Zn({name:`${c} ${u}`,attributes:{url:u,type:"fetch","http.method":c,"http.url":l,"server.address":p,[on]:o,[rn]:"http.client"}}):new $n;if(e.fetchData.__span=f.spanContext().spanId,r[f.spanContext().spanId]=f,n(e.fetchData.url)&&a){const t=e.args[0];e.args[1]=e.args[1]||{};const n=e.args[1];n.headers=function(e,t,n,r,o){const s=Xt(),{traceId:i,spanId:a,sampled:c,dsc:u}={...s.getPropagationContext(),...n.getPropagationContext()},l=o?yn(o):dt(i,a,c),p=at(u||(o?qn(o):zn(i,t))),h=r.headers||("undefined"!=typeof Request&&d(e,Request)?e.headers:void 0);if(h){if("undefined"!=typeof Headers&&d(h,Headers)){const e=new Headers(h);return e.append("sentry-trace",l),p&&e.append(nt,p),e}if(Array.isArray(h)){const e=[...h,["sentry-trace",l]];return p&&e.push([nt,p]),e}{const e="baggage"in h?h.baggage:void 0,t=[];return Array.isArray(e)?t.push(...e):e&&t.push(e),p&&t.push(p),{...h,"sentry-trace":l,baggage:t.length>0?t.join(","):void 0}}}return{"sentry-trace":l,baggage:p}}(t,a,i,n,Fn()&&h?f:void 0)}return f}function Ko(e,t={},n=Gt()){const{message:r,name:o,email:s,url:i,source:a,associatedEventId:c,tags:u}=e,l={contexts:{feedback:X({contact_email:s,name:o,message:r,url:i,source:a,associated_event_id:c})},type:"feedback",level:"info",tags:u},d=n&&n.getClient()||Qt();d&&d.emit("beforeSendFeedback",l,t);return n.captureEvent(l,t)}const Qo=function(){return{bindClient(e){Gt().setClient(e)},withScope:Kt,getClient:()=>Qt(),getScope:Gt,getIsolationScope:Xt,captureException:(e,t)=>Gt().captureException(e,t),captureMessage:(e,t,n)=>Gt().captureMessage(e,t,n),captureEvent:kr,addBreadcrumb:io,setUser:Mr,setTags:Tr,setTag:Ir,setExtra:Cr,setExtras:Er,setContext:xr,getIntegration(e){const t=Qt();return t&&t.getIntegrationByName(e.id)||null},startSession:Nr,endSession:Or,captureSession(e){if(e)return Or();!function(){const e=Gt(),t=Qt(),n=e.getSession();t&&n&&t.captureSession(n)}()}}};const Zo=k;let es=0;function ts(){return es>0}function ns(e,t={},n){if("function"!=typeof e)return e;try{const t=e.__sentry_wrapped__;if(t)return t;if(W(e))return e}catch(t){return e}const r=function(){const r=Array.prototype.slice.call(arguments);try{n&&"function"==typeof n&&n.apply(this,arguments);const o=r.map((e=>ns(e,t)));return e.apply(this,o)}catch(e){throw es++,setTimeout((()=>{es--})),Kt((n=>{n.addEventProcessor((e=>(t.mechanism&&(Fe(e,void 0,void 0),$e(e,t.mechanism)),e.extra={...e.extra,arguments:r},e))),Sr(e)})),e}};try{for(const t in e)Object.prototype.hasOwnProperty.call(e,t)&&(r[t]=e[t])}catch(e){}q(r,e),z(e,"__sentry_wrapped__",r);try{Object.getOwnPropertyDescriptor(r,"name").configurable&&Object.defineProperty(r,"name",{get:()=>e.name})}catch(e){}return r}const rs="undefined"==typeof __SENTRY_DEBUG__||__SENTRY_DEBUG__;function os(e,t){const n=as(e,t),r={type:t&&t.name,value:us(t)};return n.length&&(r.stacktrace={frames:n}),void 0===r.type&&""===r.value&&(r.value="Unrecoverable error caught"),r}function ss(e,t,n,r){const o=Qt(),s=o&&o.getOptions().normalizeDepth,i=function(e){for(const t in e)if(Object.prototype.hasOwnProperty.call(e,t)){const n=e[t];if(n instanceof Error)return n}return}(t),a={__serialized__:He(t,s)};if(i)return{exception:{values:[os(e,i)]},extra:a};const c={exception:{values:[{type:u(t)?t.constructor.name:r?"UnhandledRejection":"Error",value:fs(t,{isUnhandledRejection:r})}]},extra:a};if(n){const t=as(e,n);t.length&&(c.exception.values[0].stacktrace={frames:t})}return c}function is(e,t){return{exception:{values:[os(e,t)]}}}function as(e,t){const n=t.stacktrace||t.stack||"",r=function(e){if(e&&cs.test(e.message))return 1;return 0}(t),o=function(e){if("number"==typeof e.framesToPop)return e.framesToPop;return 0}(t);try{return e(n,r,o)}catch(e){}return[]}const cs=/Minified React error #\d+;/i;function us(e){const t=e&&e.message;return t?t.error&&"string"==typeof t.error.message?t.error.message:t:"No error message"}function ls(e,t,n,r){const o=ps(e,t,n&&n.syntheticException||void 0,r);return $e(o),o.level="error",n&&n.event_id&&(o.event_id=n.event_id),Xe(o)}function ds(e,t,n="info",r,o){const s=hs(e,t,r&&r.syntheticException||void 0,o);return s.level=n,r&&r.event_id&&(s.event_id=r.event_id),Xe(s)}function ps(e,s,i,a,l){let d;if(r(s)&&s.error){return is(e,s.error)}if(o(s)||n(s,"DOMException")){const t=s;if("stack"in s)d=is(e,s);else{const n=t.name||(o(t)?"DOMError":"DOMException"),r=t.message?`${n}: ${t.message}`:n;d=hs(e,r,i,a),Fe(d,r)}return"code"in t&&(d.tags={...d.tags,"DOMException.code":`${t.code}`}),d}if(t(s))return is(e,s);if(c(s)||u(s)){return d=ss(e,s,i,l),$e(d,{synthetic:!0}),d}return d=hs(e,s,i,a),Fe(d,`${s}`,void 0),$e(d,{synthetic:!0}),d}function hs(e,t,n,r){const o={};if(r&&n){const r=as(e,n);r.length&&(o.exception={values:[{value:t,stacktrace:{frames:r}}]})}if(i(t)){const{__sentry_template_string__:e,__sentry_template_values__:n}=t;return o.logentry={message:e,params:n},o}return o.message=t,o}function fs(e,{isUnhandledRejection:t}){const n=function(e,t=40){const n=Object.keys(Y(e));n.sort();const r=n[0];if(!r)return"[object has no keys]";if(r.length>=t)return h(r,t);for(let e=n.length;e>0;e--){const r=n.slice(0,e).join(", ");if(!(r.length>t))return e===n.length?r:h(r,t)}return""}(e),o=t?"promise rejection":"exception";if(r(e))return`Event \`ErrorEvent\` captured as ${o} with message \`${e.message}\``;if(u(e)){return`Event \`${function(e){try{const t=Object.getPrototypeOf(e);return t?t.constructor.name:void 0}catch(e){}}(e)}\` (type=${e.type}) captured as ${o}`}return`Object captured as ${o} with keys: ${n}`}function ms(e,{metadata:t,tunnel:n,dsn:r}){const o={event_id:e.event_id,sent_at:(new Date).toISOString(),...t&&t.sdk&&{sdk:{name:t.sdk.name,version:t.sdk.version}},...!!n&&!!r&&{dsn:F(r)}},s=function(e){return[{type:"user_report"},e]}(e);return pt(o,[s])}class gs extends Wr{constructor(e){const t={parentSpanIsAlwaysRootSpan:!0,...e};!function(e,t,n=[t],r="npm"){const o=e._metadata||{};o.sdk||(o.sdk={name:`sentry.javascript.${t}`,packages:n.map((e=>({name:`${r}:@sentry/${e}`,version:w}))),version:w}),e._metadata=o}(t,"browser",["browser"],Zo.SENTRY_SDK_SOURCE||"npm"),super(t),t.sendClientReports&&Zo.document&&Zo.document.addEventListener("visibilitychange",(()=>{"hidden"===Zo.document.visibilityState&&this._flushOutcomes()}))}eventFromException(e,t){return ls(this._options.stackParser,e,t,this._options.attachStacktrace)}eventFromMessage(e,t="info",n){return ds(this._options.stackParser,e,t,n,this._options.attachStacktrace)}captureUserFeedback(e){if(!this._isEnabled())return void(rs&&L.warn("SDK not enabled, will not capture user feedback."));const t=ms(e,{metadata:this.getSdkMetadata(),dsn:this.getDsn(),tunnel:this.getOptions().tunnel});this.sendEnvelope(t)}_prepareEvent(e,t,n){return e.platform=e.platform||"javascript",super._prepareEvent(e,t,n)}}const _s="undefined"==typeof __SENTRY_DEBUG__||__SENTRY_DEBUG__,ys=(e,t,n,r)=>{let o,s;return i=>{t.value>=0&&(i||r)&&(s=t.value-(o||0),(s||void 0===o)&&(o=t.value,t.delta=s,t.rating=((e,t)=>e>t[1]?"poor":e>t[0]?"needs-improvement":"good")(t.value,n),e(t)))}},vs=k,bs=()=>vs.performance&&performance.getEntriesByType&&performance.getEntriesByType("navigation")[0],Ss=()=>{const e=bs();return e&&e.activationStart||0},ws=(e,t)=>{const n=bs();let r="navigate";n&&(vs.document&&vs.document.prerendering||Ss()>0?r="prerender":vs.document&&vs.document.wasDiscarded?r="restore":n.type&&(r=n.type.replace(/_/g,"-")));return{name:e,value:void 0===t?-1:t,rating:"good",delta:0,entries:[],id:`v3-${Date.now()}-${Math.floor(8999999999999*Math.random())+1e12}`,navigationType:r}},ks=(e,t,n)=>{try{if(PerformanceObserver.supportedEntryTypes.includes(e)){const r=new PerformanceObserver((e=>{Promise.resolve().then((()=>{t(e.getEntries())}))}));return r.observe(Object.assign({type:e,buffered:!0},n||{})),r}}catch(e){}},xs=e=>{const t=t=>{("pagehide"===t.type||vs.document&&"hidden"===vs.document.visibilityState)&&e(t)};vs.document&&(addEventListener("visibilitychange",t,!0),addEventListener("pagehide",t,!0))},Es=e=>{let t=!1;return n=>{t||(e(n),t=!0)}};let Cs=-1;const Ts=e=>{"hidden"===vs.document.visibilityState&&Cs>-1&&(Cs="visibilitychange"===e.type?e.timeStamp:0,removeEventListener("visibilitychange",Ts,!0),removeEventListener("prerenderingchange",Ts,!0))},Is=()=>(vs.document&&Cs<0&&(Cs="hidden"!==vs.document.visibilityState||vs.document.prerendering?1/0:0,addEventListener("visibilitychange",Ts,!0),addEventListener("prerenderingchange",Ts,!0)),{get firstHiddenTime(){return Cs}}),Ms=e=>{vs.document&&vs.document.prerendering?addEventListener("prerenderingchange",(()=>e()),!0):e()},Rs=[1800,3e3],As=[.1,.25],Ns=(e,t={})=>{((e,t={})=>{Ms((()=>{const n=Is(),r=ws("FCP");let o;const s=ks("paint",(e=>{e.forEach((e=>{"first-contentful-paint"===e.name&&(s.disconnect(),e.startTime<n.firstHiddenTime&&(r.value=Math.max(e.startTime-Ss(),0),r.entries.push(e),o(!0)))}))}));s&&(o=ys(e,r,Rs,t.reportAllChanges))}))})(Es((()=>{const n=ws("CLS",0);let r,o=0,s=[];const i=e=>{e.forEach((e=>{if(!e.hadRecentInput){const t=s[0],n=s[s.length-1];o&&t&&n&&e.startTime-n.startTime<1e3&&e.startTime-t.startTime<5e3?(o+=e.value,s.push(e)):(o=e.value,s=[e])}})),o>n.value&&(n.value=o,n.entries=s,r())},a=ks("layout-shift",i);a&&(r=ys(e,n,As,t.reportAllChanges),xs((()=>{i(a.takeRecords()),r(!0)})),setTimeout(r,0))})))},Os=[100,300],Ds=(e,t={})=>{Ms((()=>{const n=Is(),r=ws("FID");let o;const s=e=>{e.startTime<n.firstHiddenTime&&(r.value=e.processingStart-e.startTime,r.entries.push(e),o(!0))},i=e=>{e.forEach(s)},a=ks("first-input",i);o=ys(e,r,Os,t.reportAllChanges),a&&xs(Es((()=>{i(a.takeRecords()),a.disconnect()})))}))};let Ls=0,Ps=1/0,Fs=0;const $s=e=>{e.forEach((e=>{e.interactionId&&(Ps=Math.min(Ps,e.interactionId),Fs=Math.max(Fs,e.interactionId),Ls=Fs?(Fs-Ps)/7+1:0)}))};let Bs;const Us=()=>{"interactionCount"in performance||Bs||(Bs=ks("event",$s,{type:"event",buffered:!0,durationThreshold:0}))},js=[200,500],Hs=()=>(Bs?Ls:performance.interactionCount||0)-0,zs=[],qs={},Ws=e=>{const t=zs[zs.length-1],n=qs[e.interactionId];if(n||zs.length<10||t&&e.duration>t.latency){if(n)n.entries.push(e),n.latency=Math.max(n.latency,e.duration);else{const t={id:e.interactionId,latency:e.duration,entries:[e]};qs[t.id]=t,zs.push(t)}zs.sort(((e,t)=>t.latency-e.latency)),zs.splice(10).forEach((e=>{delete qs[e.id]}))}},Ys=(e,t={})=>{Ms((()=>{Us();const n=ws("INP");let r;const o=e=>{e.forEach((e=>{if(e.interactionId&&Ws(e),"first-input"===e.entryType){!zs.some((t=>t.entries.some((t=>e.duration===t.duration&&e.startTime===t.startTime))))&&Ws(e)}}));const t=(()=>{const e=Math.min(zs.length-1,Math.floor(Hs()/50));return zs[e]})();t&&t.latency!==n.value&&(n.value=t.latency,n.entries=t.entries,r())},s=ks("event",o,{durationThreshold:null!=t.durationThreshold?t.durationThreshold:40});r=ys(e,n,js,t.reportAllChanges),s&&("PerformanceEventTiming"in vs&&"interactionId"in PerformanceEventTiming.prototype&&s.observe({type:"first-input",buffered:!0}),xs((()=>{o(s.takeRecords()),n.value<0&&Hs()>0&&(n.value=0,n.entries=[]),r(!0)})))}))},Vs=[2500,4e3],Gs={},Xs=(e,t={})=>{Ms((()=>{const n=Is(),r=ws("LCP");let o;const s=e=>{const t=e[e.length-1];t&&t.startTime<n.firstHiddenTime&&(r.value=Math.max(t.startTime-Ss(),0),r.entries=[t],o())},i=ks("largest-contentful-paint",s);if(i){o=ys(e,r,Vs,t.reportAllChanges);const n=Es((()=>{Gs[r.id]||(s(i.takeRecords()),i.disconnect(),Gs[r.id]=!0,o(!0))}));["keydown","click"].forEach((e=>{vs.document&&addEventListener(e,(()=>setTimeout(n,0)),!0)})),xs(n)}}))},Js=[800,1800],Ks=e=>{vs.document&&vs.document.prerendering?Ms((()=>Ks(e))):vs.document&&"complete"!==vs.document.readyState?addEventListener("load",(()=>Ks(e)),!0):setTimeout(e,0)},Qs=(e,t={})=>{const n=ws("TTFB"),r=ys(e,n,Js,t.reportAllChanges);Ks((()=>{const e=bs();if(e){const t=e.responseStart;if(t<=0||t>performance.now())return;n.value=Math.max(t-Ss(),0),n.entries=[e],r(!0)}}))},Zs={},ei={};let ti,ni,ri,oi,si;function ii(e,t=!1){return _i("cls",e,pi,ti,t)}function ai(e,t=!1){return _i("lcp",e,fi,ri,t)}function ci(e){return _i("fid",e,hi,ni)}function ui(e){return _i("inp",e,gi,si)}function li(e,t){return yi(e,t),ei[e]||(!function(e){const t={};"event"===e&&(t.durationThreshold=0);ks(e,(t=>{di(e,{entries:t})}),t)}(e),ei[e]=!0),vi(e,t)}function di(e,t){const n=Zs[e];if(n&&n.length)for(const r of n)try{r(t)}catch(t){_s&&L.error(`Error while triggering instrumentation handler.\nType: ${e}\nName: ${oe(r)}\nError:`,t)}}function pi(){return Ns((e=>{di("cls",{metric:e}),ti=e}),{reportAllChanges:!0})}function hi(){return Ds((e=>{di("fid",{metric:e}),ni=e}))}function fi(){return Xs((e=>{di("lcp",{metric:e}),ri=e}),{reportAllChanges:!0})}function mi(){return Qs((e=>{di("ttfb",{metric:e}),oi=e}))}function gi(){return Ys((e=>{di("inp",{metric:e}),si=e}))}function _i(e,t,n,r,o=!1){let s;return yi(e,t),ei[e]||(s=n(),ei[e]=!0),r&&t({metric:r}),vi(e,t,o?s:void 0)}function yi(e,t){Zs[e]=Zs[e]||[],Zs[e].push(t)}function vi(e,t,n){return()=>{n&&n();const r=Zs[e];if(!r)return;const o=r.indexOf(t);-1!==o&&r.splice(o,1)}}function bi(e){return"number"==typeof e&&isFinite(e)}function Si(e,t,n,{...r}){const o=Sn(e).start_timestamp;return o&&o>t&&"function"==typeof e.updateStartTime&&e.updateStartTime(t),er(e,(()=>{const e=Zn({startTime:t,...r});return e&&e.end(n),e}))}function wi(e){const t=Qt();if(!t)return;const{name:n,transaction:r,attributes:o,startTime:s}=e,{release:i,environment:a}=t.getOptions(),c=t.getIntegrationByName("Replay"),u=c&&c.getReplayId(),l=Gt(),d=l.getUser(),p=void 0!==d?d.email||d.id||d.ip_address:void 0;let h;try{h=l.getScopeData().contexts.profile.profile_id}catch(e){}return Zn({name:n,attributes:{release:i,environment:a,user:p||void 0,profile_id:h||void 0,replay_id:u||void 0,transaction:r,"user_agent.original":vs.navigator&&vs.navigator.userAgent,...o},startTime:s,experimental:{standalone:!0}})}function ki(){return vs&&vs.addEventListener&&vs.performance}function xi(e){return e/1e3}function Ei(){let e,t,n=0;if(!function(){try{return Mt([PerformanceObserver,"access",e=>e.supportedEntryTypes,"optionalAccess",e=>e.includes,"call",e=>e("layout-shift")])}catch(e){return!1}}())return;let r=!1;function o(){r||(r=!0,t&&function(e,t,n){_s&&L.log(`Sending CLS span (${e})`);const r=xi((be||0)+(Mt([t,"optionalAccess",e=>e.startTime])||0)),o=Gt().getScopeData().transactionName,s=t?T(Mt([t,"access",e=>e.sources,"access",e=>e[0],"optionalAccess",e=>e.node])):"Layout shift",i=X({[on]:"auto.http.browser.cls",[rn]:"ui.webvital.cls",[un]:Mt([t,"optionalAccess",e=>e.duration])||0,"sentry.pageload.span_id":n}),a=wi({name:s,transaction:o,attributes:i,startTime:r});Mt([a,"optionalAccess",e=>e.addEvent,"call",t=>t("cls",{[an]:"",[cn]:e})]),Mt([a,"optionalAccess",e=>e.end,"call",e=>e(r)])}(n,e,t),s())}const s=ii((({metric:t})=>{const r=t.entries[t.entries.length-1];r&&(n=t.value,e=r)}),!0);xs((()=>{o()})),setTimeout((()=>{const e=Mt([Qt(),"optionalAccess",e=>e.on,"call",t=>t("startNavigationSpan",(()=>{o(),e&&e()}))]),n=Mn(),r=n&&In(n),s=r&&Sn(r);s&&"pageload"===s.op&&(t=r.spanContext().spanId)}),0)}const Ci=2147483647;let Ti,Ii,Mi=0,Ri={};function Ai({recordClsStandaloneSpans:e}){const t=ki();if(t&&be){t.mark&&vs.performance.mark("sentry-tracing-init");const n=ci((({metric:e})=>{const t=e.entries[e.entries.length-1];if(!t)return;const n=xi(be),r=xi(t.startTime);_s&&L.log("[Measurements] Adding FID"),Ri.fid={value:e.value,unit:"millisecond"},Ri["mark.fid"]={value:n+r,unit:"second"}})),r=ai((({metric:e})=>{const t=e.entries[e.entries.length-1];t&&(_s&&L.log("[Measurements] Adding LCP"),Ri.lcp={value:e.value,unit:"millisecond"},Ti=t)}),!0),o=_i("ttfb",(({metric:e})=>{e.entries[e.entries.length-1]&&(_s&&L.log("[Measurements] Adding TTFB"),Ri.ttfb={value:e.value,unit:"millisecond"})}),mi,oi),s=e?Ei():ii((({metric:e})=>{const t=e.entries[e.entries.length-1];t&&(_s&&L.log(`[Measurements] Adding CLS ${e.value}`),Ri.cls={value:e.value,unit:""},Ii=t)}),!0);return()=>{n(),r(),o(),s&&s()}}return()=>{}}function Ni(e,t){const n=ki();if(!n||!vs.performance.getEntries||!be)return;_s&&L.log("[Tracing] Adding & adjusting spans using Performance API");const r=xi(be),o=n.getEntries(),{op:s,start_timestamp:i}=Sn(e);if(o.slice(Mi).forEach((t=>{const n=xi(t.startTime),o=xi(Math.max(0,t.duration));if(!("navigation"===s&&i&&r+n<i))switch(t.entryType){case"navigation":!function(e,t,n){["unloadEvent","redirect","domContentLoadedEvent","loadEvent","connect"].forEach((r=>{Oi(e,t,r,n)})),Oi(e,t,"secureConnection",n,"TLS/SSL","connectEnd"),Oi(e,t,"fetch",n,"cache","domainLookupStart"),Oi(e,t,"domainLookup",n,"DNS"),function(e,t,n){const r=n+xi(t.requestStart),o=n+xi(t.responseEnd),s=n+xi(t.responseStart);t.responseEnd&&(Si(e,r,o,{op:"browser",name:"request",attributes:{[on]:"auto.ui.browser.metrics"}}),Si(e,s,o,{op:"browser",name:"response",attributes:{[on]:"auto.ui.browser.metrics"}}))}(e,t,n)}(e,t,r);break;case"mark":case"paint":case"measure":{!function(e,t,n,r,o){const s=bs(),i=xi(s?s.requestStart:0),a=o+Math.max(n,i),c=o+n,u=c+r,l={[on]:"auto.resource.browser.metrics"};a!==c&&(l["sentry.browser.measure_happened_before_request"]=!0,l["sentry.browser.measure_start_time"]=a);Si(e,a,u,{name:t.name,op:t.entryType,attributes:l})}(e,t,n,o,r);const s=Is(),i=t.startTime<s.firstHiddenTime;"first-paint"===t.name&&i&&(_s&&L.log("[Measurements] Adding FP"),Ri.fp={value:t.startTime,unit:"millisecond"}),"first-contentful-paint"===t.name&&i&&(_s&&L.log("[Measurements] Adding FCP"),Ri.fcp={value:t.startTime,unit:"millisecond"});break}case"resource":!function(e,t,n,r,o,s){if("xmlhttprequest"===t.initiatorType||"fetch"===t.initiatorType)return;const i=Ze(n),a={[on]:"auto.resource.browser.metrics"};Di(a,t,"transferSize","http.response_transfer_size"),Di(a,t,"encodedBodySize","http.response_content_length"),Di(a,t,"decodedBodySize","http.decoded_response_content_length"),"renderBlockingStatus"in t&&(a["resource.render_blocking_status"]=t.renderBlockingStatus);i.protocol&&(a["url.scheme"]=i.protocol.split(":").pop());i.host&&(a["server.address"]=i.host);a["url.same_origin"]=n.includes(vs.location.origin);const c=s+r,u=c+o;Si(e,c,u,{name:n.replace(vs.location.origin,""),op:t.initiatorType?`resource.${t.initiatorType}`:"resource.other",attributes:a})}(e,t,t.name,n,o,r)}})),Mi=Math.max(o.length-1,0),function(e){const t=vs.navigator;if(!t)return;const n=t.connection;n&&(n.effectiveType&&e.setAttribute("effectiveConnectionType",n.effectiveType),n.type&&e.setAttribute("connectionType",n.type),bi(n.rtt)&&(Ri["connection.rtt"]={value:n.rtt,unit:"millisecond"}));bi(t.deviceMemory)&&e.setAttribute("deviceMemory",`${t.deviceMemory} GB`);bi(t.hardwareConcurrency)&&e.setAttribute("hardwareConcurrency",String(t.hardwareConcurrency))}(e),"pageload"===s){!function(e){const t=bs();if(!t)return;const{responseStart:n,requestStart:r}=t;r<=n&&(_s&&L.log("[Measurements] Adding TTFB Request Time"),e["ttfb.requestTime"]={value:n-r,unit:"millisecond"})}(Ri);const n=Ri["mark.fid"];n&&Ri.fid&&(Si(e,n.value,n.value+xi(Ri.fid.value),{name:"first input delay",op:"ui.action",attributes:{[on]:"auto.ui.browser.metrics"}}),delete Ri["mark.fid"]),"fcp"in Ri&&t.recordClsOnPageloadSpan||delete Ri.cls,Object.entries(Ri).forEach((([e,t])=>{Yn(e,t.value,t.unit)})),e.setAttribute("performance.timeOrigin",r),function(e){Ti&&(_s&&L.log("[Measurements] Adding LCP Data"),Ti.element&&e.setAttribute("lcp.element",T(Ti.element)),Ti.id&&e.setAttribute("lcp.id",Ti.id),Ti.url&&e.setAttribute("lcp.url",Ti.url.trim().slice(0,200)),e.setAttribute("lcp.size",Ti.size));Ii&&Ii.sources&&(_s&&L.log("[Measurements] Adding CLS Data"),Ii.sources.forEach(((t,n)=>e.setAttribute(`cls.source.${n+1}`,T(t.node)))))}(e)}Ti=void 0,Ii=void 0,Ri={}}function Oi(e,t,n,r,o,s){const i=s?t[s]:t[`${n}End`],a=t[`${n}Start`];a&&i&&Si(e,r+xi(a),r+xi(i),{op:"browser",name:o||n,attributes:{[on]:"auto.ui.browser.metrics"}})}function Di(e,t,n,r){const o=t[n];null!=o&&o<Ci&&(e[r]=o)}const Li=1e3;let Pi,Fi,$i,Bi;function Ui(e){ce("dom",e),ue("dom",ji)}function ji(){if(!vs.document)return;const e=le.bind(null,"dom"),t=Hi(e,!0);vs.document.addEventListener("click",t,!1),vs.document.addEventListener("keypress",t,!1),["EventTarget","Node"].forEach((t=>{const n=vs[t]&&vs[t].prototype;n&&n.hasOwnProperty&&n.hasOwnProperty("addEventListener")&&(H(n,"addEventListener",(function(t){return function(n,r,o){if("click"===n||"keypress"==n)try{const r=this,s=r.__sentry_instrumentation_handlers__=r.__sentry_instrumentation_handlers__||{},i=s[n]=s[n]||{refCount:0};if(!i.handler){const r=Hi(e);i.handler=r,t.call(this,n,r,o)}i.refCount++}catch(e){}return t.call(this,n,r,o)}})),H(n,"removeEventListener",(function(e){return function(t,n,r){if("click"===t||"keypress"==t)try{const n=this,o=n.__sentry_instrumentation_handlers__||{},s=o[t];s&&(s.refCount--,s.refCount<=0&&(e.call(this,t,s.handler,r),s.handler=void 0,delete o[t]),0===Object.keys(o).length&&delete n.__sentry_instrumentation_handlers__)}catch(e){}return e.call(this,t,n,r)}})))}))}function Hi(e,t=!1){return n=>{if(!n||n._sentryCaptured)return;const r=function(e){try{return e.target}catch(e){return null}}(n);if(function(e,t){return"keypress"===e&&(!t||!t.tagName||"INPUT"!==t.tagName&&"TEXTAREA"!==t.tagName&&!t.isContentEditable)}(n.type,r))return;z(n,"_sentryCaptured",!0),r&&!r._sentryId&&z(r,"_sentryId",De());const o="keypress"===n.type?"input":n.type;if(!function(e){if(e.type!==Fi)return!1;try{if(!e.target||e.target._sentryId!==$i)return!1}catch(e){}return!0}(n)){e({event:n,name:o,global:t}),Fi=n.type,$i=r?r._sentryId:void 0}clearTimeout(Pi),Pi=vs.setTimeout((()=>{$i=void 0,Fi=void 0}),Li)}}function zi(e){const t="history";ce(t,e),ue(t,qi)}function qi(){if(!function(){const e=At.chrome,t=e&&e.app&&e.app.runtime,n="history"in At&&!!At.history.pushState&&!!At.history.replaceState;return!t&&n}())return;const e=vs.onpopstate;function t(e){return function(...t){const n=t.length>2?t[2]:void 0;if(n){const e=Bi,t=String(n);Bi=t;le("history",{from:e,to:t})}return e.apply(this,t)}}vs.onpopstate=function(...t){const n=vs.location.href,r=Bi;Bi=n;if(le("history",{from:r,to:n}),e)try{return e.apply(this,t)}catch(e){}},H(vs.history,"pushState",t),H(vs.history,"replaceState",t)}const Wi={};function Yi(e){const t=Wi[e];if(t)return t;let n=vs[e];if(me(n))return Wi[e]=n.bind(vs);const r=vs.document;if(r&&"function"==typeof r.createElement)try{const t=r.createElement("iframe");t.hidden=!0,r.head.appendChild(t);const o=t.contentWindow;o&&o[e]&&(n=o[e]),r.head.removeChild(t)}catch(t){_s&&L.warn(`Could not create sandbox iframe for ${e} check, bailing to window.${e}: `,t)}return n?Wi[e]=n.bind(vs):n}function Vi(e){Wi[e]=void 0}function Gi(...e){return Yi("setTimeout")(...e)}const Xi="__sentry_xhr_v3__";function Ji(e){ce("xhr",e),ue("xhr",Ki)}function Ki(){if(!vs.XMLHttpRequest)return;const e=XMLHttpRequest.prototype;e.open=new Proxy(e.open,{apply(e,t,n){const r=1e3*ve(),o=s(n[0])?n[0].toUpperCase():void 0,i=function(e){if(s(e))return e;try{return e.toString()}catch(e){}return}(n[1]);if(!o||!i)return e.apply(t,n);t[Xi]={method:o,url:i,request_headers:{}},"POST"===o&&i.match(/sentry_key/)&&(t.__sentry_own_request__=!0);const a=()=>{const e=t[Xi];if(e&&4===t.readyState){try{e.status_code=t.status}catch(e){}le("xhr",{endTimestamp:1e3*ve(),startTimestamp:r,xhr:t})}};return"onreadystatechange"in t&&"function"==typeof t.onreadystatechange?t.onreadystatechange=new Proxy(t.onreadystatechange,{apply:(e,t,n)=>(a(),e.apply(t,n))}):t.addEventListener("readystatechange",a),t.setRequestHeader=new Proxy(t.setRequestHeader,{apply(e,t,n){const[r,o]=n,i=t[Xi];return i&&s(r)&&s(o)&&(i.request_headers[r.toLowerCase()]=o),e.apply(t,n)}}),e.apply(t,n)}}),e.send=new Proxy(e.send,{apply(e,t,n){const r=t[Xi];if(!r)return e.apply(t,n);void 0!==n[0]&&(r.body=n[0]);return le("xhr",{startTimestamp:1e3*ve(),xhr:t}),e.apply(t,n)}})}const Qi=[],Zi=new Map;function ea(){if(ki()&&be){const e=ui((({metric:e})=>{if(null==e.value)return;const t=e.entries.find((t=>t.duration===e.value&&ta[t.name]));if(!t)return;const{interactionId:n}=t,r=ta[t.name],o=xi(be+t.startTime),s=xi(e.value),i=Mn(),a=i?In(i):void 0,c=(null!=n?Zi.get(n):void 0)||a,u=c?Sn(c).description:Gt().getScopeData().transactionName,l=wi({name:T(t.target),transaction:u,attributes:X({[on]:"auto.http.browser.inp",[rn]:`ui.interaction.${r}`,[un]:t.duration}),startTime:o});Mt([l,"optionalAccess",e=>e.addEvent,"call",t=>t("inp",{[an]:"millisecond",[cn]:e.value})]),Mt([l,"optionalAccess",e=>e.end,"call",e=>e(o+s)])}));return()=>{e()}}return()=>{}}const ta={click:"click",pointerdown:"click",pointerup:"click",mousedown:"click",mouseup:"click",touchstart:"click",touchend:"click",mouseover:"hover",mouseout:"hover",mouseenter:"hover",mouseleave:"hover",pointerover:"hover",pointerout:"hover",pointerenter:"hover",pointerleave:"hover",dragstart:"drag",dragend:"drag",drag:"drag",dragenter:"drag",dragleave:"drag",dragover:"drag",drop:"drag",keydown:"press",keyup:"press",keypress:"press",input:"press"};function na(e,t=Yi("fetch")){let n=0,r=0;return Jr(e,(function(o){const s=o.body.length;n+=s,r++;const i={body:o.body,method:"POST",referrerPolicy:"origin",headers:e.headers,keepalive:n<=6e4&&r<15,...e.fetchOptions};if(!t)return Vi("fetch"),Je("No fetch implementation available");try{return t(e.url,i).then((e=>(n-=s,r--,{statusCode:e.status,headers:{"x-sentry-rate-limits":e.headers.get("X-Sentry-Rate-Limits"),"retry-after":e.headers.get("Retry-After")}})))}catch(e){return Vi("fetch"),n-=s,r--,Je(e)}}))}function ra(e,t,n,r){const o={filename:e,function:"<anonymous>"===t?Q:t,in_app:!0};return void 0!==n&&(o.lineno=n),void 0!==r&&(o.colno=r),o}const oa=/^\s*at (\S+?)(?::(\d+))(?::(\d+))\s*$/i,sa=/^\s*at (?:(.+?\)(?: \[.+\])?|.*?) ?\((?:address at )?)?(?:async )?((?:<anonymous>|[-a-z]+:|.*bundle|\/)?.*?)(?::(\d+))?(?::(\d+))?\)?\s*$/i,ia=/\((\S*)(?::(\d+))(?::(\d+))\)/,aa=[30,e=>{const t=oa.exec(e);if(t){const[,e,n,r]=t;return ra(e,Q,+n,+r)}const n=sa.exec(e);if(n){if(n[2]&&0===n[2].indexOf("eval")){const e=ia.exec(n[2]);e&&(n[2]=e[1],n[3]=e[2],n[4]=e[3])}const[e,t]=va(n[1]||Q,n[2]);return ra(t,e,n[3]?+n[3]:void 0,n[4]?+n[4]:void 0)}}],ca=/^\s*(.*?)(?:\((.*?)\))?(?:^|@)?((?:[-a-z]+)?:\/.*?|\[native code\]|[^@]*(?:bundle|\d+\.js)|\/[\w\-. /=]+)(?::(\d+))?(?::(\d+))?\s*$/i,ua=/(\S+) line (\d+)(?: > eval line \d+)* > eval/i,la=[50,e=>{const t=ca.exec(e);if(t){if(t[3]&&t[3].indexOf(" > eval")>-1){const e=ua.exec(t[3]);e&&(t[1]=t[1]||"eval",t[3]=e[1],t[4]=e[2],t[5]="")}let e=t[3],n=t[1]||Q;return[n,e]=va(n,e),ra(e,n,t[4]?+t[4]:void 0,t[5]?+t[5]:void 0)}}],da=/^\s*at (?:((?:\[object object\])?.+) )?\(?((?:[-a-z]+):.*?):(\d+)(?::(\d+))?\)?\s*$/i,pa=[40,e=>{const t=da.exec(e);return t?ra(t[2],t[1]||Q,+t[3],t[4]?+t[4]:void 0):void 0}],ha=/ line (\d+).*script (?:in )?(\S+)(?:: in function (\S+))?$/i,fa=[10,e=>{const t=ha.exec(e);return t?ra(t[2],t[3]||Q,+t[1]):void 0}],ma=/ line (\d+), column (\d+)\s*(?:in (?:<anonymous function: ([^>]+)>|([^)]+))\(.*\))? in (.*):\s*$/i,ga=[20,e=>{const t=ma.exec(e);return t?ra(t[5],t[3]||t[4]||Q,+t[1],+t[2]):void 0}],_a=[aa,la],ya=te(..._a),va=(e,t)=>{const n=-1!==e.indexOf("safari-extension"),r=-1!==e.indexOf("safari-web-extension");return n||r?[-1!==e.indexOf("@")?e.split("@")[0]:Q,n?`safari-extension:${t}`:`safari-web-extension:${t}`]:[e,t]},ba=1024,Sa=(e={})=>{const t={console:!0,dom:!0,fetch:!0,history:!0,sentry:!0,xhr:!0,...e};return{name:"Breadcrumbs",setup(e){t.console&&de(function(e){return function(t){if(Qt()!==e)return;const n={category:"console",data:{arguments:t.args,logger:"console"},level:tt(t.level),message:m(t.args," ")};if("assert"===t.level){if(!1!==t.args[0])return;n.message=`Assertion failed: ${m(t.args.slice(1)," ")||"console.assert"}`,n.data.arguments=t.args.slice(1)}io(n,{input:t.args,level:t.level})}}(e)),t.dom&&Ui(function(e,t){return function(n){if(Qt()!==e)return;let r,o,s="object"==typeof t?t.serializeAttribute:void 0,i="object"==typeof t&&"number"==typeof t.maxStringLength?t.maxStringLength:void 0;i&&i>ba&&(rs&&L.warn(`\`dom.maxStringLength\` cannot exceed 1024, but a value of ${i} was configured. Sentry will use 1024 instead.`),i=ba),"string"==typeof s&&(s=[s]);try{const e=n.event,t=function(e){return!!e&&!!e.target}(e)?e.target:e;r=T(t,{keyAttrs:s,maxStringLength:i}),o=R(t)}catch(e){r="<unknown>"}if(0===r.length)return;const a={category:`ui.${n.name}`,message:r};o&&(a.data={"ui.component_name":o}),io(a,{event:n.event,name:n.name,global:n.global})}}(e,t.dom)),t.xhr&&Ji(function(e){return function(t){if(Qt()!==e)return;const{startTimestamp:n,endTimestamp:r}=t,o=t.xhr[Xi];if(!n||!r||!o)return;const{method:s,url:i,status_code:a,body:c}=o;io({category:"xhr",data:{method:s,url:i,status_code:a},type:"http"},{xhr:t.xhr,input:c,startTimestamp:n,endTimestamp:r})}}(e)),t.fetch&&Se(function(e){return function(t){if(Qt()!==e)return;const{startTimestamp:n,endTimestamp:r}=t;if(r&&(!t.fetchData.url.match(/sentry_key/)||"POST"!==t.fetchData.method))if(t.error){io({category:"fetch",data:t.fetchData,level:"error",type:"http"},{data:t.error,input:t.args,startTimestamp:n,endTimestamp:r})}else{const e=t.response;io({category:"fetch",data:{...t.fetchData,status_code:e&&e.status},type:"http"},{input:t.args,response:e,startTimestamp:n,endTimestamp:r})}}}(e)),t.history&&zi(function(e){return function(t){if(Qt()!==e)return;let n=t.from,r=t.to;const o=Ze(Zo.location.href);let s=n?Ze(n):void 0;const i=Ze(r);s&&s.path||(s=o),o.protocol===i.protocol&&o.host===i.host&&(r=i.relative),o.protocol===s.protocol&&o.host===s.host&&(n=s.relative),io({category:"navigation",data:{from:n,to:r}})}}(e)),t.sentry&&e.on("beforeSendEvent",function(e){return function(t){Qt()===e&&io({category:"sentry."+("transaction"===t.type?"transaction":"event"),event_id:t.event_id,level:t.level,message:Pe(t)},{event:t})}}(e))}}};const wa=["EventTarget","Window","Node","ApplicationCache","AudioTrackList","BroadcastChannel","ChannelMergerNode","CryptoOperation","EventSource","FileReader","HTMLUnknownElement","IDBDatabase","IDBRequest","IDBTransaction","KeyOperation","MediaController","MessagePort","ModalWindow","Notification","SVGElementInstance","Screen","SharedWorker","TextTrack","TextTrackCue","TextTrackList","WebSocket","WebSocketWorker","Worker","XMLHttpRequest","XMLHttpRequestEventTarget","XMLHttpRequestUpload"],ka=(e={})=>{const t={XMLHttpRequest:!0,eventTarget:!0,requestAnimationFrame:!0,setInterval:!0,setTimeout:!0,...e};return{name:"BrowserApiErrors",setupOnce(){t.setTimeout&&H(Zo,"setTimeout",xa),t.setInterval&&H(Zo,"setInterval",xa),t.requestAnimationFrame&&H(Zo,"requestAnimationFrame",Ea),t.XMLHttpRequest&&"XMLHttpRequest"in Zo&&H(XMLHttpRequest.prototype,"send",Ca);const e=t.eventTarget;if(e){(Array.isArray(e)?e:wa).forEach(Ta)}}}};function xa(e){return function(...t){const n=t[0];return t[0]=ns(n,{mechanism:{data:{function:oe(e)},handled:!1,type:"instrument"}}),e.apply(this,t)}}function Ea(e){return function(t){return e.apply(this,[ns(t,{mechanism:{data:{function:"requestAnimationFrame",handler:oe(e)},handled:!1,type:"instrument"}})])}}function Ca(e){return function(...t){const n=this;return["onload","onerror","onprogress","onreadystatechange"].forEach((e=>{e in n&&"function"==typeof n[e]&&H(n,e,(function(t){const n={mechanism:{data:{function:e,handler:oe(t)},handled:!1,type:"instrument"}},r=W(t);return r&&(n.mechanism.data.handler=oe(r)),ns(t,n)}))})),e.apply(this,t)}}function Ta(e){const t=Zo,n=t[e]&&t[e].prototype;n&&n.hasOwnProperty&&n.hasOwnProperty("addEventListener")&&(H(n,"addEventListener",(function(t){return function(n,r,o){try{"function"==typeof r.handleEvent&&(r.handleEvent=ns(r.handleEvent,{mechanism:{data:{function:"handleEvent",handler:oe(r),target:e},handled:!1,type:"instrument"}}))}catch(e){}return t.apply(this,[n,ns(r,{mechanism:{data:{function:"addEventListener",handler:oe(r),target:e},handled:!1,type:"instrument"}}),o])}})),H(n,"removeEventListener",(function(e){return function(t,n,r){const o=n;try{const n=o&&o.__sentry_wrapped__;n&&e.call(this,t,n,r)}catch(e){}return e.call(this,t,o,r)}})))}const Ia=(e={})=>{const t={onerror:!0,onunhandledrejection:!0,...e};return{name:"GlobalHandlers",setupOnce(){Error.stackTraceLimit=50},setup(e){t.onerror&&(!function(e){Te((t=>{const{stackParser:n,attachStacktrace:r}=Ra();if(Qt()!==e||ts())return;const{msg:o,url:i,line:a,column:c,error:u}=t,l=function(e,t,n,r){const o=e.exception=e.exception||{},i=o.values=o.values||[],a=i[0]=i[0]||{},c=a.stacktrace=a.stacktrace||{},u=c.frames=c.frames||[],l=isNaN(parseInt(r,10))?void 0:r,d=isNaN(parseInt(n,10))?void 0:n,p=s(t)&&t.length>0?t:M();0===u.length&&u.push({colno:l,filename:p,function:Q,in_app:!0,lineno:d});return e}(ps(n,u||o,void 0,r,!1),i,a,c);l.level="error",kr(l,{originalException:u,mechanism:{handled:!1,type:"onerror"}})}))}(e),Ma("onerror")),t.onunhandledrejection&&(!function(e){Re((t=>{const{stackParser:n,attachStacktrace:r}=Ra();if(Qt()!==e||ts())return;const o=function(e){if(a(e))return e;try{if("reason"in e)return e.reason;if("detail"in e&&"reason"in e.detail)return e.detail.reason}catch(e){}return e}(t),s=a(o)?{exception:{values:[{type:"UnhandledRejection",value:`Non-Error promise rejection captured with value: ${String(o)}`}]}}:ps(n,o,void 0,r,!0);s.level="error",kr(s,{originalException:o,mechanism:{handled:!1,type:"onunhandledrejection"}})}))}(e),Ma("onunhandledrejection"))}}};function Ma(e){rs&&L.log(`Global Handler attached: ${e}`)}function Ra(){const e=Qt();return e&&e.getOptions()||{stackParser:()=>[],attachStacktrace:!1}}const Aa=()=>({name:"HttpContext",preprocessEvent(e){if(!Zo.navigator&&!Zo.location&&!Zo.document)return;const t=e.request&&e.request.url||Zo.location&&Zo.location.href,{referrer:n}=Zo.document||{},{userAgent:r}=Zo.navigator||{},o={...e.request&&e.request.headers,...n&&{Referer:n},...r&&{"User-Agent":r}},s={...e.request,...t&&{url:t},headers:o};e.request=s}}),Na=(e={})=>{const t=e.limit||5,n=e.key||"cause";return{name:"LinkedErrors",preprocessEvent(e,r,o){const s=o.getOptions();y(os,s.stackParser,s.maxValueLength,n,t,e,r)}}};function Oa(e){return[po(),uo(),ka(),Sa(),Ia(),Na(),wo(),Aa()]}function Da(e={}){const t=function(e={}){const t={defaultIntegrations:Oa(),release:"string"==typeof __SENTRY_RELEASE__?__SENTRY_RELEASE__:Zo.SENTRY_RELEASE&&Zo.SENTRY_RELEASE.id?Zo.SENTRY_RELEASE.id:void 0,autoSessionTracking:!0,sendClientReports:!0};return null==e.defaultIntegrations&&delete e.defaultIntegrations,{...t,...e}}(e);if(function(){const e=void 0!==Zo.window&&Zo;if(!e)return!1;const t=e[e.chrome?"chrome":"browser"],n=t&&t.runtime&&t.runtime.id,r=Zo.location&&Zo.location.href||"",o=!!n&&Zo===Zo.top&&["chrome-extension:","moz-extension:","ms-browser-extension:","safari-web-extension:"].some((e=>r.startsWith(`${e}//`))),s=void 0!==e.nw;return!!n&&!o&&!s}())return void D((()=>{console.error("[Sentry] You cannot run Sentry this way in a browser extension, check: https://docs.sentry.io/platforms/javascript/best-practices/browser-extensions/")}));rs&&(fe()||L.warn("No Fetch API detected. The Sentry SDK requires a Fetch API compatible environment to send events. Please add a Fetch API polyfill."));const n={...t,stackParser:(r=t.stackParser||ya,Array.isArray(r)?te(...r):r),integrations:jr(t),transport:t.transport||na};var r;const o=function(e,t){!0===t.debug&&(Nt?L.enable():D((()=>{console.warn("[Sentry] Cannot initialize SDK with `debug` option using a non-debug bundle.")}))),Gt().update(t.initialScope);const n=new e(t);return Gr(n),n.init(),n}(gs,n);return t.autoSessionTracking&&function(){if(void 0===Zo.document)return void(rs&&L.warn("Session tracking in non-browser environment with @sentry/browser is not supported."));Nr({ignoreDuration:!0}),Lr(),zi((({from:e,to:t})=>{void 0!==e&&e!==t&&(Nr({ignoreDuration:!0}),Lr())}))}(),o}const La={replayIntegration:"replay",replayCanvasIntegration:"replay-canvas",feedbackIntegration:"feedback",feedbackModalIntegration:"feedback-modal",feedbackScreenshotIntegration:"feedback-screenshot",captureConsoleIntegration:"captureconsole",contextLinesIntegration:"contextlines",linkedErrorsIntegration:"linkederrors",debugIntegration:"debug",dedupeIntegration:"dedupe",extraErrorDataIntegration:"extraerrordata",httpClientIntegration:"httpclient",reportingObserverIntegration:"reportingobserver",rewriteFramesIntegration:"rewriteframes",sessionTimingIntegration:"sessiontiming",browserProfilingIntegration:"browserprofiling"},Pa=Zo;async function Fa(e,t){const n=La[e],r=Pa.Sentry=Pa.Sentry||{};if(!n)throw new Error(`Cannot lazy load integration: ${e}`);const o=r[e];if("function"==typeof o&&!("_isShim"in o))return o;const s=function(e){const t=Qt(),n=t&&t.getOptions(),r=n&&n.cdnBaseUrl||"https://browser.sentry-cdn.com";return new URL(`/${w}/${e}.min.js`,r).toString()}(n),i=Zo.document.createElement("script");i.src=s,i.crossOrigin="anonymous",i.referrerPolicy="origin",t&&i.setAttribute("nonce",t);const a=new Promise(((e,t)=>{i.addEventListener("load",(()=>e())),i.addEventListener("error",t)}));Zo.document.body.appendChild(i);try{await a}catch(t){throw new Error(`Error when loading integration: ${e}`)}const c=r[e];if("function"!=typeof c)throw new Error(`Could not load integration: ${e}`);return c}const $a=k,Ba=new WeakMap,Ua=(e={})=>{const t=e.types||["crash","deprecation","intervention"];function n(e){if(Ba.has(Qt()))for(const t of e)Kt((e=>{e.setExtra("url",t.url);const n=`ReportingObserver [${t.type}]`;let r="No details available";if(t.body){const n={};for(const e in t.body)n[e]=t.body[e];if(e.setExtra("body",n),"crash"===t.type){const e=t.body;r=[e.crashId||"",e.reason||""].join(" ").trim()||r}else{r=t.body.message||r}}wr(`${n}: ${r}`)}))}return{name:"ReportingObserver",setupOnce(){if(!("ReportingObserver"in he))return;new $a.ReportingObserver(n,{buffered:!0,types:t}).observe()},setup(e){Ba.set(e,!0)}}},ja=(e={})=>{const t={failedRequestStatusCodes:[[500,599]],failedRequestTargets:[/.*/],...e};return{name:"HttpClient",setup(e){!function(e,t){if(!ge())return;Se((n=>{if(Qt()!==e)return;const{response:r,args:o}=n,[s,i]=o;r&&function(e,t,n,r){if(Wa(e,n.status,n.url)){const e=function(e,t){if(!t&&e instanceof Request)return e;if(e instanceof Request&&e.bodyUsed)return e;return new Request(e,t)}(t,r);let o,s,i,a;Va()&&([o,i]=Ha("Cookie",e),[s,a]=Ha("Set-Cookie",n));kr(Ya({url:e.url,method:e.method,status:n.status,requestHeaders:o,responseHeaders:s,requestCookies:i,responseCookies:a}))}}(t,s,r,i)}))}(e,t),function(e,t){if(!("XMLHttpRequest"in k))return;Ji((n=>{if(Qt()!==e)return;const r=n.xhr,o=r[Xi];if(!o)return;const{method:s,request_headers:i}=o;try{!function(e,t,n,r){if(Wa(e,t.status,t.responseURL)){let e,o,s;if(Va()){try{const e=t.getResponseHeader("Set-Cookie")||t.getResponseHeader("set-cookie")||void 0;e&&(o=qa(e))}catch(e){rs&&L.log("Could not extract cookies from response headers")}try{s=function(e){const t=e.getAllResponseHeaders();if(!t)return{};return t.split("\r\n").reduce(((e,t)=>{const[n,r]=t.split(": ");return n&&r&&(e[n]=r),e}),{})}(t)}catch(e){rs&&L.log("Could not extract headers from response")}e=r}kr(Ya({url:t.responseURL,method:n,status:t.status,requestHeaders:e,responseHeaders:s,responseCookies:o}))}}(t,r,s,i)}catch(e){rs&&L.warn("Error while extracting response event form XHR response",e)}}))}(e,t)}}};function Ha(e,t){const n=function(e){const t={};return e.forEach(((e,n)=>{t[n]=e})),t}(t.headers);let r;try{const t=n[e]||n[e.toLowerCase()]||void 0;t&&(r=qa(t))}catch(t){rs&&L.log(`Could not extract cookies from header ${e}`)}return[n,r]}function za(e){if(e){const t=e["Content-Length"]||e["content-length"];if(t)return parseInt(t,10)}}function qa(e){return e.split("; ").reduce(((e,t)=>{const[n,r]=t.split("=");return n&&r&&(e[n]=r),e}),{})}function Wa(e,t,n){return function(e,t){return e.some((e=>"number"==typeof e?e===t:t>=e[0]&&t<=e[1]))}(e.failedRequestStatusCodes,t)&&(r=e.failedRequestTargets,o=n,r.some((e=>"string"==typeof e?o.includes(e):e.test(o))))&&!ro(n,Qt());var r,o}function Ya(e){const t=`HTTP Client Error with status code: ${e.status}`,n={message:t,exception:{values:[{type:"Error",value:t}]},request:{url:e.url,method:e.method,headers:e.requestHeaders,cookies:e.requestCookies},contexts:{response:{status_code:e.status,headers:e.responseHeaders,cookies:e.responseCookies,body_size:za(e.responseHeaders)}}};return $e(n,{type:"http.client",handled:!1}),n}function Va(){const e=Qt();return!!e&&Boolean(e.getOptions().sendDefaultPii)}const Ga=k,Xa=(e={})=>{const t=null!=e.frameContextLines?e.frameContextLines:7;return{name:"ContextLines",processEvent:e=>function(e,t){const n=Ga.document,r=Ga.location&&(o=Ga.location.href,o.split(/[?#]/,1)[0]);var o;if(!n||!r)return e;const s=e.exception&&e.exception.values;if(!s||!s.length)return e;const i=n.documentElement.innerHTML;if(!i)return e;const a=["<!DOCTYPE html>","<html>",...i.split("\n"),"</html>"];return s.forEach((e=>{const n=e.stacktrace;n&&n.frames&&(n.frames=n.frames.map((e=>function(e,t,n,r){if(e.filename!==n||!e.lineno||!t.length)return e;return function(e,t,n=5){if(void 0===t.lineno)return;const r=e.length,o=Math.max(Math.min(r-1,t.lineno-1),0);t.pre_context=e.slice(Math.max(0,o-n),o).map((e=>f(e,0)));const s=Math.min(r-1,o);t.context_line=f(e[s],t.colno||0),t.post_context=e.slice(Math.min(o+1,r),o+1+n).map((e=>f(e,0)))}(t,e,r),e}(e,a,r,t))))})),e}(e,t)}};const Ja=k,Ka="sentryReplaySession",Qa="replay_event",Za="Unable to send Replay",ec=15e4,tc=5e3,nc=2e7,rc=36e5;function oc(e,t){return null!=e?e:t()}function sc(e){let t,n=e[0],r=1;for(;r<e.length;){const o=e[r],s=e[r+1];if(r+=2,("optionalAccess"===o||"optionalCall"===o)&&null==n)return;"access"===o||"optionalAccess"===o?(t=n,n=s(n)):"call"!==o&&"optionalCall"!==o||(n=s(((...e)=>n.call(t,...e))),t=void 0)}return n}var ic;function ac(e){const t=sc([e,"optionalAccess",e=>e.host]);return Boolean(sc([t,"optionalAccess",e=>e.shadowRoot])===e)}function cc(e){return"[object ShadowRoot]"===Object.prototype.toString.call(e)}function uc(e){try{const n=e.rules||e.cssRules;return n?((t=Array.from(n,lc).join("")).includes(" background-clip: text;")&&!t.includes(" -webkit-background-clip: text;")&&(t=t.replace(" background-clip: text;"," -webkit-background-clip: text; background-clip: text;")),t):null}catch(e){return null}var t}function lc(e){let t;if(function(e){return"styleSheet"in e}(e))try{t=uc(e.styleSheet)||function(e){const{cssText:t}=e;if(t.split('"').length<3)return t;const n=["@import",`url(${JSON.stringify(e.href)})`];return""===e.layerName?n.push("layer"):e.layerName&&n.push(`layer(${e.layerName})`),e.supportsText&&n.push(`supports(${e.supportsText})`),e.media.length&&n.push(e.media.mediaText),n.join(" ")+";"}(e)}catch(e){}else if(function(e){return"selectorText"in e}(e)&&e.selectorText.includes(":"))return function(e){const t=/(\[(?:[\w-]+)[^\\])(:(?:[\w-]+)\])/gm;return e.replace(t,"$1\\$2")}(e.cssText);return t||e.cssText}!function(e){e[e.Document=0]="Document",e[e.DocumentType=1]="DocumentType",e[e.Element=2]="Element",e[e.Text=3]="Text",e[e.CDATA=4]="CDATA",e[e.Comment=5]="Comment"}(ic||(ic={}));class dc{constructor(){this.idNodeMap=new Map,this.nodeMetaMap=new WeakMap}getId(e){if(!e)return-1;return oc(sc([this,"access",e=>e.getMeta,"call",t=>t(e),"optionalAccess",e=>e.id]),(()=>-1))}getNode(e){return this.idNodeMap.get(e)||null}getIds(){return Array.from(this.idNodeMap.keys())}getMeta(e){return this.nodeMetaMap.get(e)||null}removeNodeFromMap(e){const t=this.getId(e);this.idNodeMap.delete(t),e.childNodes&&e.childNodes.forEach((e=>this.removeNodeFromMap(e)))}has(e){return this.idNodeMap.has(e)}hasNode(e){return this.nodeMetaMap.has(e)}add(e,t){const n=t.id;this.idNodeMap.set(n,e),this.nodeMetaMap.set(e,t)}replace(e,t){const n=this.getNode(e);if(n){const e=this.nodeMetaMap.get(n);e&&this.nodeMetaMap.set(t,e)}this.idNodeMap.set(e,t)}reset(){this.idNodeMap=new Map,this.nodeMetaMap=new WeakMap}}function pc({maskInputOptions:e,tagName:t,type:n}){return"OPTION"===t&&(t="SELECT"),Boolean(e[t.toLowerCase()]||n&&e[n]||"password"===n||"INPUT"===t&&!n&&e.text)}function hc({isMasked:e,element:t,value:n,maskInputFn:r}){let o=n||"";return e?(r&&(o=r(o,t)),"*".repeat(o.length)):o}function fc(e){return e.toLowerCase()}function mc(e){return e.toUpperCase()}const gc="__rrweb_original__";function _c(e){const t=e.type;return e.hasAttribute("data-rr-is-password")?"password":t?fc(t):null}function yc(e,t,n){return"INPUT"!==t||"radio"!==n&&"checkbox"!==n?e.value:e.getAttribute("value")||""}function vc(e,t){let n;try{n=new URL(e,oc(t,(()=>window.location.href)))}catch(e){return null}return oc(sc([n.pathname.match(/\.([0-9a-z]+)(?:$)/i),"optionalAccess",e=>e[1]]),(()=>null))}const bc={};function Sc(e){const t=bc[e];if(t)return t;const n=window.document;let r=window[e];if(n&&"function"==typeof n.createElement)try{const t=n.createElement("iframe");t.hidden=!0,n.head.appendChild(t);const o=t.contentWindow;o&&o[e]&&(r=o[e]),n.head.removeChild(t)}catch(e){}return bc[e]=r.bind(window)}function wc(...e){return Sc("setTimeout")(...e)}function kc(...e){return Sc("clearTimeout")(...e)}let xc=1;const Ec=new RegExp("[^a-z0-9-_:]"),Cc=-2;function Tc(){return xc++}let Ic,Mc;const Rc=/url\((?:(')([^']*)'|(")(.*?)"|([^)]*))\)/gm,Ac=/^(?:[a-z+]+:)?\/\//i,Nc=/^www\..*/i,Oc=/^(data:)([^,]*),(.*)/i;function Dc(e,t){return(e||"").replace(Rc,((e,n,r,o,s,i)=>{const a=r||s||i,c=n||o||"";if(!a)return e;if(Ac.test(a)||Nc.test(a))return`url(${c}${a}${c})`;if(Oc.test(a))return`url(${c}${a}${c})`;if("/"===a[0])return`url(${c}${function(e){let t="";return t=e.indexOf("//")>-1?e.split("/").slice(0,3).join("/"):e.split("/")[0],t=t.split("?")[0],t}(t)+a}${c})`;const u=t.split("/"),l=a.split("/");u.pop();for(const e of l)"."!==e&&(".."===e?u.pop():u.push(e));return`url(${c}${u.join("/")}${c})`}))}const Lc=/^[^ \t\n\r\u000c]+/,Pc=/^[, \t\n\r\u000c]+/;function Fc(e,t){if(!t||""===t.trim())return t;const n=e.createElement("a");return n.href=t,n.href}function $c(e){return Boolean("svg"===e.tagName||e.ownerSVGElement)}function Bc(){const e=document.createElement("a");return e.href="",e.href}function Uc(e,t,n,r,o,s){return r?"src"===n||"href"===n&&("use"!==t||"#"!==r[0])||"xlink:href"===n&&"#"!==r[0]?Fc(e,r):"background"!==n||"table"!==t&&"td"!==t&&"th"!==t?"srcset"===n?function(e,t){if(""===t.trim())return t;let n=0;function r(e){let r;const o=e.exec(t.substring(n));return o?(r=o[0],n+=r.length,r):""}const o=[];for(;r(Pc),!(n>=t.length);){let s=r(Lc);if(","===s.slice(-1))s=Fc(e,s.substring(0,s.length-1)),o.push(s);else{let r="";s=Fc(e,s);let i=!1;for(;;){const e=t.charAt(n);if(""===e){o.push((s+r).trim());break}if(i)")"===e&&(i=!1);else{if(","===e){n+=1,o.push((s+r).trim());break}"("===e&&(i=!0)}r+=e,n+=1}}}return o.join(", ")}(e,r):"style"===n?Dc(r,Bc()):"object"===t&&"data"===n?Fc(e,r):"function"==typeof s?s(n,r,o):r:Fc(e,r):r}function jc(e,t,n){return("video"===e||"audio"===e)&&"autoplay"===t}function Hc(e,t,n=1/0,r=0){return e?e.nodeType!==e.ELEMENT_NODE||r>n?-1:t(e)?r:Hc(e.parentNode,t,n,r+1):-1}function zc(e,t){return n=>{const r=n;if(null===r)return!1;try{if(e)if("string"==typeof e){if(r.matches(`.${e}`))return!0}else if(function(e,t){for(let n=e.classList.length;n--;){const r=e.classList[n];if(t.test(r))return!0}return!1}(r,e))return!0;return!(!t||!r.matches(t))}catch(e){return!1}}}function qc(e,t,n,r,o,s){try{const i=e.nodeType===e.ELEMENT_NODE?e:e.parentElement;if(null===i)return!1;if("INPUT"===i.tagName){const e=i.getAttribute("autocomplete");if(["current-password","new-password","cc-number","cc-exp","cc-exp-month","cc-exp-year","cc-csc"].includes(e))return!0}let a=-1,c=-1;if(s){if(c=Hc(i,zc(r,o)),c<0)return!0;a=Hc(i,zc(t,n),c>=0?c:1/0)}else{if(a=Hc(i,zc(t,n)),a<0)return!1;c=Hc(i,zc(r,o),a>=0?a:1/0)}return a>=0?!(c>=0)||a<=c:!(c>=0)&&!!s}catch(e){}return!!s}function Wc(e,t){const{doc:n,mirror:r,blockClass:o,blockSelector:s,unblockSelector:i,maskAllText:a,maskAttributeFn:c,maskTextClass:u,unmaskTextClass:l,maskTextSelector:d,unmaskTextSelector:p,inlineStylesheet:h,maskInputOptions:f={},maskTextFn:m,maskInputFn:g,dataURLOptions:_={},inlineImages:y,recordCanvas:v,keepIframeSrcFn:b,newlyAddedElement:S=!1}=t,w=function(e,t){if(!t.hasNode(e))return;const n=t.getId(e);return 1===n?void 0:n}(n,r);switch(e.nodeType){case e.DOCUMENT_NODE:return"CSS1Compat"!==e.compatMode?{type:ic.Document,childNodes:[],compatMode:e.compatMode}:{type:ic.Document,childNodes:[]};case e.DOCUMENT_TYPE_NODE:return{type:ic.DocumentType,name:e.name,publicId:e.publicId,systemId:e.systemId,rootId:w};case e.ELEMENT_NODE:return function(e,t){const{doc:n,blockClass:r,blockSelector:o,unblockSelector:s,inlineStylesheet:i,maskInputOptions:a={},maskAttributeFn:c,maskInputFn:u,dataURLOptions:l={},inlineImages:d,recordCanvas:p,keepIframeSrcFn:h,newlyAddedElement:f=!1,rootId:m,maskAllText:g,maskTextClass:_,unmaskTextClass:y,maskTextSelector:v,unmaskTextSelector:b}=t,S=function(e,t,n,r){try{if(r&&e.matches(r))return!1;if("string"==typeof t){if(e.classList.contains(t))return!0}else for(let n=e.classList.length;n--;){const r=e.classList[n];if(t.test(r))return!0}if(n)return e.matches(n)}catch(e){}return!1}(e,r,o,s),w=function(e){if(e instanceof HTMLFormElement)return"form";const t=fc(e.tagName);return Ec.test(t)?"div":t}(e);let k={};const x=e.attributes.length;for(let t=0;t<x;t++){const r=e.attributes[t];r.name&&!jc(w,r.name,r.value)&&(k[r.name]=Uc(n,w,fc(r.name),r.value,e,c))}if("link"===w&&i){const t=Array.from(n.styleSheets).find((t=>t.href===e.href));let r=null;t&&(r=uc(t)),r&&(delete k.rel,delete k.href,k._cssText=Dc(r,t.href))}if("style"===w&&e.sheet&&!(e.innerText||e.textContent||"").trim().length){const t=uc(e.sheet);t&&(k._cssText=Dc(t,Bc()))}if("input"===w||"textarea"===w||"select"===w||"option"===w){const t=e,n=_c(t),r=yc(t,mc(w),n),o=t.checked;if("submit"!==n&&"button"!==n&&r){const e=qc(t,_,v,y,b,pc({type:n,tagName:mc(w),maskInputOptions:a}));k.value=hc({isMasked:e,element:t,value:r,maskInputFn:u})}o&&(k.checked=o)}"option"===w&&(e.selected&&!a.select?k.selected=!0:delete k.selected);if("canvas"===w&&p)if("2d"===e.__context)(function(e){const t=e.getContext("2d");if(!t)return!0;for(let n=0;n<e.width;n+=50)for(let r=0;r<e.height;r+=50){const o=t.getImageData,s=gc in o?o[gc]:o;if(new Uint32Array(s.call(t,n,r,Math.min(50,e.width-n),Math.min(50,e.height-r)).data.buffer).some((e=>0!==e)))return!1}return!0})(e)||(k.rr_dataURL=e.toDataURL(l.type,l.quality));else if(!("__context"in e)){const t=e.toDataURL(l.type,l.quality),n=document.createElement("canvas");n.width=e.width,n.height=e.height;t!==n.toDataURL(l.type,l.quality)&&(k.rr_dataURL=t)}if("img"===w&&d){Ic||(Ic=n.createElement("canvas"),Mc=Ic.getContext("2d"));const t=e,r=t.crossOrigin;t.crossOrigin="anonymous";const o=()=>{t.removeEventListener("load",o);try{Ic.width=t.naturalWidth,Ic.height=t.naturalHeight,Mc.drawImage(t,0,0),k.rr_dataURL=Ic.toDataURL(l.type,l.quality)}catch(e){console.warn(`Cannot inline img src=${t.currentSrc}! Error: ${e}`)}r?k.crossOrigin=r:t.removeAttribute("crossorigin")};t.complete&&0!==t.naturalWidth?o():t.addEventListener("load",o)}"audio"!==w&&"video"!==w||(k.rr_mediaState=e.paused?"paused":"played",k.rr_mediaCurrentTime=e.currentTime);f||(e.scrollLeft&&(k.rr_scrollLeft=e.scrollLeft),e.scrollTop&&(k.rr_scrollTop=e.scrollTop));if(S){const{width:t,height:n}=e.getBoundingClientRect();k={class:k.class,rr_width:`${t}px`,rr_height:`${n}px`}}"iframe"!==w||h(k.src)||(S||e.contentDocument||(k.rr_src=k.src),delete k.src);let E;try{customElements.get(w)&&(E=!0)}catch(e){}return{type:ic.Element,tagName:w,attributes:k,childNodes:[],isSVG:$c(e)||void 0,needBlock:S,rootId:m,isCustom:E}}(e,{doc:n,blockClass:o,blockSelector:s,unblockSelector:i,inlineStylesheet:h,maskAttributeFn:c,maskInputOptions:f,maskInputFn:g,dataURLOptions:_,inlineImages:y,recordCanvas:v,keepIframeSrcFn:b,newlyAddedElement:S,rootId:w,maskAllText:a,maskTextClass:u,unmaskTextClass:l,maskTextSelector:d,unmaskTextSelector:p});case e.TEXT_NODE:return function(e,t){const{maskAllText:n,maskTextClass:r,unmaskTextClass:o,maskTextSelector:s,unmaskTextSelector:i,maskTextFn:a,maskInputOptions:c,maskInputFn:u,rootId:l}=t,d=e.parentNode&&e.parentNode.tagName;let p=e.textContent;const h="STYLE"===d||void 0,f="SCRIPT"===d||void 0,m="TEXTAREA"===d||void 0;if(h&&p){try{e.nextSibling||e.previousSibling||sc([e,"access",e=>e.parentNode,"access",e=>e.sheet,"optionalAccess",e=>e.cssRules])&&(p=uc(e.parentNode.sheet))}catch(t){console.warn(`Cannot get CSS styles from text's parentNode. Error: ${t}`,e)}p=Dc(p,Bc())}f&&(p="SCRIPT_PLACEHOLDER");const g=qc(e,r,s,o,i,n);h||f||m||!p||!g||(p=a?a(p,e.parentElement):p.replace(/[\S]/g,"*"));m&&p&&(c.textarea||g)&&(p=u?u(p,e.parentNode):p.replace(/[\S]/g,"*"));if("OPTION"===d&&p){p=hc({isMasked:qc(e,r,s,o,i,pc({type:null,tagName:d,maskInputOptions:c})),element:e,value:p,maskInputFn:u})}return{type:ic.Text,textContent:p||"",isStyle:h,rootId:l}}(e,{maskAllText:a,maskTextClass:u,unmaskTextClass:l,maskTextSelector:d,unmaskTextSelector:p,maskTextFn:m,maskInputOptions:f,maskInputFn:g,rootId:w});case e.CDATA_SECTION_NODE:return{type:ic.CDATA,textContent:"",rootId:w};case e.COMMENT_NODE:return{type:ic.Comment,textContent:e.textContent||"",rootId:w};default:return!1}}function Yc(e){return null==e?"":e.toLowerCase()}function Vc(e,t){const{doc:n,mirror:r,blockClass:o,blockSelector:s,unblockSelector:i,maskAllText:a,maskTextClass:c,unmaskTextClass:u,maskTextSelector:l,unmaskTextSelector:d,skipChild:p=!1,inlineStylesheet:h=!0,maskInputOptions:f={},maskAttributeFn:m,maskTextFn:g,maskInputFn:_,slimDOMOptions:y,dataURLOptions:v={},inlineImages:b=!1,recordCanvas:S=!1,onSerialize:w,onIframeLoad:k,iframeLoadTimeout:x=5e3,onStylesheetLoad:E,stylesheetLoadTimeout:C=5e3,keepIframeSrcFn:T=()=>!1,newlyAddedElement:I=!1}=t;let{preserveWhiteSpace:M=!0}=t;const R=Wc(e,{doc:n,mirror:r,blockClass:o,blockSelector:s,maskAllText:a,unblockSelector:i,maskTextClass:c,unmaskTextClass:u,maskTextSelector:l,unmaskTextSelector:d,inlineStylesheet:h,maskInputOptions:f,maskAttributeFn:m,maskTextFn:g,maskInputFn:_,dataURLOptions:v,inlineImages:b,recordCanvas:S,keepIframeSrcFn:T,newlyAddedElement:I});if(!R)return console.warn(e,"not serialized"),null;let A;A=r.hasNode(e)?r.getId(e):!function(e,t){if(t.comment&&e.type===ic.Comment)return!0;if(e.type===ic.Element){if(t.script&&("script"===e.tagName||"link"===e.tagName&&("preload"===e.attributes.rel||"modulepreload"===e.attributes.rel)&&"script"===e.attributes.as||"link"===e.tagName&&"prefetch"===e.attributes.rel&&"string"==typeof e.attributes.href&&"js"===vc(e.attributes.href)))return!0;if(t.headFavicon&&("link"===e.tagName&&"shortcut icon"===e.attributes.rel||"meta"===e.tagName&&(Yc(e.attributes.name).match(/^msapplication-tile(image|color)$/)||"application-name"===Yc(e.attributes.name)||"icon"===Yc(e.attributes.rel)||"apple-touch-icon"===Yc(e.attributes.rel)||"shortcut icon"===Yc(e.attributes.rel))))return!0;if("meta"===e.tagName){if(t.headMetaDescKeywords&&Yc(e.attributes.name).match(/^description|keywords$/))return!0;if(t.headMetaSocial&&(Yc(e.attributes.property).match(/^(og|twitter|fb):/)||Yc(e.attributes.name).match(/^(og|twitter):/)||"pinterest"===Yc(e.attributes.name)))return!0;if(t.headMetaRobots&&("robots"===Yc(e.attributes.name)||"googlebot"===Yc(e.attributes.name)||"bingbot"===Yc(e.attributes.name)))return!0;if(t.headMetaHttpEquiv&&void 0!==e.attributes["http-equiv"])return!0;if(t.headMetaAuthorship&&("author"===Yc(e.attributes.name)||"generator"===Yc(e.attributes.name)||"framework"===Yc(e.attributes.name)||"publisher"===Yc(e.attributes.name)||"progid"===Yc(e.attributes.name)||Yc(e.attributes.property).match(/^article:/)||Yc(e.attributes.property).match(/^product:/)))return!0;if(t.headMetaVerification&&("google-site-verification"===Yc(e.attributes.name)||"yandex-verification"===Yc(e.attributes.name)||"csrf-token"===Yc(e.attributes.name)||"p:domain_verify"===Yc(e.attributes.name)||"verify-v1"===Yc(e.attributes.name)||"verification"===Yc(e.attributes.name)||"shopify-checkout-api-token"===Yc(e.attributes.name)))return!0}}return!1}(R,y)&&(M||R.type!==ic.Text||R.isStyle||R.textContent.replace(/^\s+|\s+$/gm,"").length)?Tc():Cc;const N=Object.assign(R,{id:A});if(r.add(e,N),A===Cc)return null;w&&w(e);let O=!p;if(N.type===ic.Element){O=O&&!N.needBlock,delete N.needBlock;const t=e.shadowRoot;t&&cc(t)&&(N.isShadowHost=!0)}if((N.type===ic.Document||N.type===ic.Element)&&O){y.headWhitespace&&N.type===ic.Element&&"head"===N.tagName&&(M=!1);const t={doc:n,mirror:r,blockClass:o,blockSelector:s,maskAllText:a,unblockSelector:i,maskTextClass:c,unmaskTextClass:u,maskTextSelector:l,unmaskTextSelector:d,skipChild:p,inlineStylesheet:h,maskInputOptions:f,maskAttributeFn:m,maskTextFn:g,maskInputFn:_,slimDOMOptions:y,dataURLOptions:v,inlineImages:b,recordCanvas:S,preserveWhiteSpace:M,onSerialize:w,onIframeLoad:k,iframeLoadTimeout:x,onStylesheetLoad:E,stylesheetLoadTimeout:C,keepIframeSrcFn:T};for(const n of Array.from(e.childNodes)){const e=Vc(n,t);e&&N.childNodes.push(e)}if(function(e){return e.nodeType===e.ELEMENT_NODE}(e)&&e.shadowRoot)for(const n of Array.from(e.shadowRoot.childNodes)){const r=Vc(n,t);r&&(cc(e.shadowRoot)&&(r.isShadow=!0),N.childNodes.push(r))}}return e.parentNode&&ac(e.parentNode)&&cc(e.parentNode)&&(N.isShadow=!0),N.type===ic.Element&&"iframe"===N.tagName&&function(e,t,n){const r=e.contentWindow;if(!r)return;let o,s=!1;try{o=r.document.readyState}catch(e){return}if("complete"!==o){const r=wc((()=>{s||(t(),s=!0)}),n);return void e.addEventListener("load",(()=>{kc(r),s=!0,t()}))}const i="about:blank";if(r.location.href!==i||e.src===i||""===e.src)return wc(t,0),e.addEventListener("load",t);e.addEventListener("load",t)}(e,(()=>{const t=e.contentDocument;if(t&&k){const n=Vc(t,{doc:t,mirror:r,blockClass:o,blockSelector:s,unblockSelector:i,maskAllText:a,maskTextClass:c,unmaskTextClass:u,maskTextSelector:l,unmaskTextSelector:d,skipChild:!1,inlineStylesheet:h,maskInputOptions:f,maskAttributeFn:m,maskTextFn:g,maskInputFn:_,slimDOMOptions:y,dataURLOptions:v,inlineImages:b,recordCanvas:S,preserveWhiteSpace:M,onSerialize:w,onIframeLoad:k,iframeLoadTimeout:x,onStylesheetLoad:E,stylesheetLoadTimeout:C,keepIframeSrcFn:T});n&&k(e,n)}}),x),N.type===ic.Element&&"link"===N.tagName&&"string"==typeof N.attributes.rel&&("stylesheet"===N.attributes.rel||"preload"===N.attributes.rel&&"string"==typeof N.attributes.href&&"css"===vc(N.attributes.href))&&function(e,t,n){let r,o=!1;try{r=e.sheet}catch(e){return}if(r)return;const s=wc((()=>{o||(t(),o=!0)}),n);e.addEventListener("load",(()=>{kc(s),o=!0,t()}))}(e,(()=>{if(E){const t=Vc(e,{doc:n,mirror:r,blockClass:o,blockSelector:s,unblockSelector:i,maskAllText:a,maskTextClass:c,unmaskTextClass:u,maskTextSelector:l,unmaskTextSelector:d,skipChild:!1,inlineStylesheet:h,maskInputOptions:f,maskAttributeFn:m,maskTextFn:g,maskInputFn:_,slimDOMOptions:y,dataURLOptions:v,inlineImages:b,recordCanvas:S,preserveWhiteSpace:M,onSerialize:w,onIframeLoad:k,iframeLoadTimeout:x,onStylesheetLoad:E,stylesheetLoadTimeout:C,keepIframeSrcFn:T});t&&E(e,t)}}),C),N}function Gc(e){let t,n=e[0],r=1;for(;r<e.length;){const o=e[r],s=e[r+1];if(r+=2,("optionalAccess"===o||"optionalCall"===o)&&null==n)return;"access"===o||"optionalAccess"===o?(t=n,n=s(n)):"call"!==o&&"optionalCall"!==o||(n=s(((...e)=>n.call(t,...e))),t=void 0)}return n}function Xc(e,t,n=document){const r={capture:!0,passive:!0};return n.addEventListener(e,t,r),()=>n.removeEventListener(e,t,r)}const Jc="Please stop import mirror directly. Instead of that,\r\nnow you can use replayer.getMirror() to access the mirror instance of a replayer,\r\nor you can use record.mirror to access the mirror instance during recording.";let Kc={map:{},getId:()=>(console.error(Jc),-1),getNode:()=>(console.error(Jc),null),removeNodeFromMap(){console.error(Jc)},has:()=>(console.error(Jc),!1),reset(){console.error(Jc)}};function Qc(e,t,n={}){let r=null,o=0;return function(...s){const i=Date.now();o||!1!==n.leading||(o=i);const a=t-(i-o),c=this;a<=0||a>t?(r&&(!function(...e){yu("clearTimeout")(...e)}(r),r=null),o=i,e.apply(c,s)):r||!1===n.trailing||(r=vu((()=>{o=!1===n.leading?0:Date.now(),r=null,e.apply(c,s)}),a))}}function Zc(e,t,n,r,o=window){const s=o.Object.getOwnPropertyDescriptor(e,t);return o.Object.defineProperty(e,t,r?n:{set(e){vu((()=>{n.set.call(this,e)}),0),s&&s.set&&s.set.call(this,e)}}),()=>Zc(e,t,s||{},!0)}function eu(e,t,n){try{if(!(t in e))return()=>{};const r=e[t],o=n(r);return"function"==typeof o&&(o.prototype=o.prototype||{},Object.defineProperties(o,{__rrweb_original__:{enumerable:!1,value:r}})),e[t]=o,()=>{e[t]=r}}catch(e){return()=>{}}}"undefined"!=typeof window&&window.Proxy&&window.Reflect&&(Kc=new Proxy(Kc,{get:(e,t,n)=>("map"===t&&console.error(Jc),Reflect.get(e,t,n))}));let tu=Date.now;function nu(e){const t=e.document;return{left:t.scrollingElement?t.scrollingElement.scrollLeft:void 0!==e.pageXOffset?e.pageXOffset:Gc([t,"optionalAccess",e=>e.documentElement,"access",e=>e.scrollLeft])||Gc([t,"optionalAccess",e=>e.body,"optionalAccess",e=>e.parentElement,"optionalAccess",e=>e.scrollLeft])||Gc([t,"optionalAccess",e=>e.body,"optionalAccess",e=>e.scrollLeft])||0,top:t.scrollingElement?t.scrollingElement.scrollTop:void 0!==e.pageYOffset?e.pageYOffset:Gc([t,"optionalAccess",e=>e.documentElement,"access",e=>e.scrollTop])||Gc([t,"optionalAccess",e=>e.body,"optionalAccess",e=>e.parentElement,"optionalAccess",e=>e.scrollTop])||Gc([t,"optionalAccess",e=>e.body,"optionalAccess",e=>e.scrollTop])||0}}function ru(){return window.innerHeight||document.documentElement&&document.documentElement.clientHeight||document.body&&document.body.clientHeight}function ou(){return window.innerWidth||document.documentElement&&document.documentElement.clientWidth||document.body&&document.body.clientWidth}function su(e){if(!e)return null;return e.nodeType===e.ELEMENT_NODE?e:e.parentElement}function iu(e,t,n,r,o){if(!e)return!1;const s=su(e);if(!s)return!1;const i=zc(t,n);if(!o){const e=r&&s.matches(r);return i(s)&&!e}const a=Hc(s,i);let c=-1;return!(a<0)&&(r&&(c=Hc(s,zc(null,r))),a>-1&&c<0||a<c)}function au(e,t){return t.getId(e)===Cc}function cu(e,t){if(ac(e))return!1;const n=t.getId(e);return!t.has(n)||(!e.parentNode||e.parentNode.nodeType!==e.DOCUMENT_NODE)&&(!e.parentNode||cu(e.parentNode,t))}function uu(e){return Boolean(e.changedTouches)}function lu(e,t){return Boolean("IFRAME"===e.nodeName&&t.getMeta(e))}function du(e,t){return Boolean("LINK"===e.nodeName&&e.nodeType===e.ELEMENT_NODE&&e.getAttribute&&"stylesheet"===e.getAttribute("rel")&&t.getMeta(e))}function pu(e){return Boolean(Gc([e,"optionalAccess",e=>e.shadowRoot]))}/[1-9][0-9]{12}/.test(Date.now().toString())||(tu=()=>(new Date).getTime());class hu{constructor(){this.id=1,this.styleIDMap=new WeakMap,this.idStyleMap=new Map}getId(e){return t=this.styleIDMap.get(e),n=()=>-1,null!=t?t:n();var t,n}has(e){return this.styleIDMap.has(e)}add(e,t){if(this.has(e))return this.getId(e);let n;return n=void 0===t?this.id++:t,this.styleIDMap.set(e,n),this.idStyleMap.set(n,e),n}getStyle(e){return this.idStyleMap.get(e)||null}reset(){this.styleIDMap=new WeakMap,this.idStyleMap=new Map,this.id=1}generateId(){return this.id++}}function fu(e){let t=null;return Gc([e,"access",e=>e.getRootNode,"optionalCall",e=>e(),"optionalAccess",e=>e.nodeType])===Node.DOCUMENT_FRAGMENT_NODE&&e.getRootNode().host&&(t=e.getRootNode().host),t}function mu(e){const t=e.ownerDocument;if(!t)return!1;const n=function(e){let t,n=e;for(;t=fu(n);)n=t;return n}(e);return t.contains(n)}function gu(e){const t=e.ownerDocument;return!!t&&(t.contains(e)||mu(e))}const _u={};function yu(e){const t=_u[e];if(t)return t;const n=window.document;let r=window[e];if(n&&"function"==typeof n.createElement)try{const t=n.createElement("iframe");t.hidden=!0,n.head.appendChild(t);const o=t.contentWindow;o&&o[e]&&(r=o[e]),n.head.removeChild(t)}catch(e){}return _u[e]=r.bind(window)}function vu(...e){return yu("setTimeout")(...e)}var bu=(e=>(e[e.DomContentLoaded=0]="DomContentLoaded",e[e.Load=1]="Load",e[e.FullSnapshot=2]="FullSnapshot",e[e.IncrementalSnapshot=3]="IncrementalSnapshot",e[e.Meta=4]="Meta",e[e.Custom=5]="Custom",e[e.Plugin=6]="Plugin",e))(bu||{}),Su=(e=>(e[e.Mutation=0]="Mutation",e[e.MouseMove=1]="MouseMove",e[e.MouseInteraction=2]="MouseInteraction",e[e.Scroll=3]="Scroll",e[e.ViewportResize=4]="ViewportResize",e[e.Input=5]="Input",e[e.TouchMove=6]="TouchMove",e[e.MediaInteraction=7]="MediaInteraction",e[e.StyleSheetRule=8]="StyleSheetRule",e[e.CanvasMutation=9]="CanvasMutation",e[e.Font=10]="Font",e[e.Log=11]="Log",e[e.Drag=12]="Drag",e[e.StyleDeclaration=13]="StyleDeclaration",e[e.Selection=14]="Selection",e[e.AdoptedStyleSheet=15]="AdoptedStyleSheet",e[e.CustomElement=16]="CustomElement",e))(Su||{}),wu=(e=>(e[e.MouseUp=0]="MouseUp",e[e.MouseDown=1]="MouseDown",e[e.Click=2]="Click",e[e.ContextMenu=3]="ContextMenu",e[e.DblClick=4]="DblClick",e[e.Focus=5]="Focus",e[e.Blur=6]="Blur",e[e.TouchStart=7]="TouchStart",e[e.TouchMove_Departed=8]="TouchMove_Departed",e[e.TouchEnd=9]="TouchEnd",e[e.TouchCancel=10]="TouchCancel",e))(wu||{}),ku=(e=>(e[e.Mouse=0]="Mouse",e[e.Pen=1]="Pen",e[e.Touch=2]="Touch",e))(ku||{});function xu(e){let t,n=e[0],r=1;for(;r<e.length;){const o=e[r],s=e[r+1];if(r+=2,("optionalAccess"===o||"optionalCall"===o)&&null==n)return;"access"===o||"optionalAccess"===o?(t=n,n=s(n)):"call"!==o&&"optionalCall"!==o||(n=s(((...e)=>n.call(t,...e))),t=void 0)}return n}function Eu(e){return"__ln"in e}class Cu{constructor(){this.length=0,this.head=null,this.tail=null}get(e){if(e>=this.length)throw new Error("Position outside of list range");let t=this.head;for(let n=0;n<e;n++)t=xu([t,"optionalAccess",e=>e.next])||null;return t}addNode(e){const t={value:e,previous:null,next:null};if(e.__ln=t,e.previousSibling&&Eu(e.previousSibling)){const n=e.previousSibling.__ln.next;t.next=n,t.previous=e.previousSibling.__ln,e.previousSibling.__ln.next=t,n&&(n.previous=t)}else if(e.nextSibling&&Eu(e.nextSibling)&&e.nextSibling.__ln.previous){const n=e.nextSibling.__ln.previous;t.previous=n,t.next=e.nextSibling.__ln,e.nextSibling.__ln.previous=t,n&&(n.next=t)}else this.head&&(this.head.previous=t),t.next=this.head,this.head=t;null===t.next&&(this.tail=t),this.length++}removeNode(e){const t=e.__ln;this.head&&(t.previous?(t.previous.next=t.next,t.next?t.next.previous=t.previous:this.tail=t.previous):(this.head=t.next,this.head?this.head.previous=null:this.tail=null),e.__ln&&delete e.__ln,this.length--)}}const Tu=(e,t)=>`${e}@${t}`;class Iu{constructor(){this.frozen=!1,this.locked=!1,this.texts=[],this.attributes=[],this.attributeMap=new WeakMap,this.removes=[],this.mapRemoves=[],this.movedMap={},this.addedSet=new Set,this.movedSet=new Set,this.droppedSet=new Set,this.processMutations=e=>{e.forEach(this.processMutation),this.emit()},this.emit=()=>{if(this.frozen||this.locked)return;const e=[],t=new Set,n=new Cu,r=e=>{let t=e,n=Cc;for(;n===Cc;)t=t&&t.nextSibling,n=t&&this.mirror.getId(t);return n},o=o=>{if(!o.parentNode||!gu(o))return;const s=ac(o.parentNode)?this.mirror.getId(fu(o)):this.mirror.getId(o.parentNode),i=r(o);if(-1===s||-1===i)return n.addNode(o);const a=Vc(o,{doc:this.doc,mirror:this.mirror,blockClass:this.blockClass,blockSelector:this.blockSelector,maskAllText:this.maskAllText,unblockSelector:this.unblockSelector,maskTextClass:this.maskTextClass,unmaskTextClass:this.unmaskTextClass,maskTextSelector:this.maskTextSelector,unmaskTextSelector:this.unmaskTextSelector,skipChild:!0,newlyAddedElement:!0,inlineStylesheet:this.inlineStylesheet,maskInputOptions:this.maskInputOptions,maskAttributeFn:this.maskAttributeFn,maskTextFn:this.maskTextFn,maskInputFn:this.maskInputFn,slimDOMOptions:this.slimDOMOptions,dataURLOptions:this.dataURLOptions,recordCanvas:this.recordCanvas,inlineImages:this.inlineImages,onSerialize:e=>{lu(e,this.mirror)&&!iu(e,this.blockClass,this.blockSelector,this.unblockSelector,!1)&&this.iframeManager.addIframe(e),du(e,this.mirror)&&this.stylesheetManager.trackLinkElement(e),pu(o)&&this.shadowDomManager.addShadowRoot(o.shadowRoot,this.doc)},onIframeLoad:(e,t)=>{iu(e,this.blockClass,this.blockSelector,this.unblockSelector,!1)||(this.iframeManager.attachIframe(e,t),e.contentWindow&&this.canvasManager.addWindow(e.contentWindow),this.shadowDomManager.observeAttachShadow(e))},onStylesheetLoad:(e,t)=>{this.stylesheetManager.attachLinkElement(e,t)}});a&&(e.push({parentId:s,nextId:i,node:a}),t.add(a.id))};for(;this.mapRemoves.length;)this.mirror.removeNodeFromMap(this.mapRemoves.shift());for(const e of this.movedSet)Ru(this.removes,e,this.mirror)&&!this.movedSet.has(e.parentNode)||o(e);for(const e of this.addedSet)Nu(this.droppedSet,e)||Ru(this.removes,e,this.mirror)?Nu(this.movedSet,e)?o(e):this.droppedSet.add(e):o(e);let s=null;for(;n.length;){let e=null;if(s){const t=this.mirror.getId(s.value.parentNode),n=r(s.value);-1!==t&&-1!==n&&(e=s)}if(!e){let t=n.tail;for(;t;){const n=t;if(t=t.previous,n){const t=this.mirror.getId(n.value.parentNode);if(-1===r(n.value))continue;if(-1!==t){e=n;break}{const t=n.value;if(t.parentNode&&t.parentNode.nodeType===Node.DOCUMENT_FRAGMENT_NODE){const r=t.parentNode.host;if(-1!==this.mirror.getId(r)){e=n;break}}}}}}if(!e){for(;n.head;)n.removeNode(n.head.value);break}s=e.previous,n.removeNode(e.value),o(e.value)}const i={texts:this.texts.map((e=>({id:this.mirror.getId(e.node),value:e.value}))).filter((e=>!t.has(e.id))).filter((e=>this.mirror.has(e.id))),attributes:this.attributes.map((e=>{const{attributes:t}=e;if("string"==typeof t.style){const n=JSON.stringify(e.styleDiff),r=JSON.stringify(e._unchangedStyles);n.length<t.style.length&&(n+r).split("var(").length===t.style.split("var(").length&&(t.style=e.styleDiff)}return{id:this.mirror.getId(e.node),attributes:t}})).filter((e=>!t.has(e.id))).filter((e=>this.mirror.has(e.id))),removes:this.removes,adds:e};(i.texts.length||i.attributes.length||i.removes.length||i.adds.length)&&(this.texts=[],this.attributes=[],this.attributeMap=new WeakMap,this.removes=[],this.addedSet=new Set,this.movedSet=new Set,this.droppedSet=new Set,this.movedMap={},this.mutationCb(i))},this.processMutation=e=>{if(!au(e.target,this.mirror))switch(e.type){case"characterData":{const t=e.target.textContent;iu(e.target,this.blockClass,this.blockSelector,this.unblockSelector,!1)||t===e.oldValue||this.texts.push({value:qc(e.target,this.maskTextClass,this.maskTextSelector,this.unmaskTextClass,this.unmaskTextSelector,this.maskAllText)&&t?this.maskTextFn?this.maskTextFn(t,su(e.target)):t.replace(/[\S]/g,"*"):t,node:e.target});break}case"attributes":{const t=e.target;let n=e.attributeName,r=e.target.getAttribute(n);if("value"===n){const n=_c(t),o=t.tagName;r=yc(t,o,n);const s=pc({maskInputOptions:this.maskInputOptions,tagName:o,type:n});r=hc({isMasked:qc(e.target,this.maskTextClass,this.maskTextSelector,this.unmaskTextClass,this.unmaskTextSelector,s),element:t,value:r,maskInputFn:this.maskInputFn})}if(iu(e.target,this.blockClass,this.blockSelector,this.unblockSelector,!1)||r===e.oldValue)return;let o=this.attributeMap.get(e.target);if("IFRAME"===t.tagName&&"src"===n&&!this.keepIframeSrcFn(r)){if(t.contentDocument)return;n="rr_src"}if(o||(o={node:e.target,attributes:{},styleDiff:{},_unchangedStyles:{}},this.attributes.push(o),this.attributeMap.set(e.target,o)),"type"===n&&"INPUT"===t.tagName&&"password"===(e.oldValue||"").toLowerCase()&&t.setAttribute("data-rr-is-password","true"),!jc(t.tagName,n)&&(o.attributes[n]=Uc(this.doc,fc(t.tagName),fc(n),r,t,this.maskAttributeFn),"style"===n)){if(!this.unattachedDoc)try{this.unattachedDoc=document.implementation.createHTMLDocument()}catch(e){this.unattachedDoc=this.doc}const n=this.unattachedDoc.createElement("span");e.oldValue&&n.setAttribute("style",e.oldValue);for(const e of Array.from(t.style)){const r=t.style.getPropertyValue(e),s=t.style.getPropertyPriority(e);r!==n.style.getPropertyValue(e)||s!==n.style.getPropertyPriority(e)?o.styleDiff[e]=""===s?r:[r,s]:o._unchangedStyles[e]=[r,s]}for(const e of Array.from(n.style))""===t.style.getPropertyValue(e)&&(o.styleDiff[e]=!1)}break}case"childList":if(iu(e.target,this.blockClass,this.blockSelector,this.unblockSelector,!0))return;e.addedNodes.forEach((t=>this.genAdds(t,e.target))),e.removedNodes.forEach((t=>{const n=this.mirror.getId(t),r=ac(e.target)?this.mirror.getId(e.target.host):this.mirror.getId(e.target);iu(e.target,this.blockClass,this.blockSelector,this.unblockSelector,!1)||au(t,this.mirror)||!function(e,t){return-1!==t.getId(e)}(t,this.mirror)||(this.addedSet.has(t)?(Mu(this.addedSet,t),this.droppedSet.add(t)):this.addedSet.has(e.target)&&-1===n||cu(e.target,this.mirror)||(this.movedSet.has(t)&&this.movedMap[Tu(n,r)]?Mu(this.movedSet,t):this.removes.push({parentId:r,id:n,isShadow:!(!ac(e.target)||!cc(e.target))||void 0})),this.mapRemoves.push(t))}))}},this.genAdds=(e,t)=>{if(!this.processedNodeManager.inOtherBuffer(e,this)&&!this.addedSet.has(e)&&!this.movedSet.has(e)){if(this.mirror.hasNode(e)){if(au(e,this.mirror))return;this.movedSet.add(e);let n=null;t&&this.mirror.hasNode(t)&&(n=this.mirror.getId(t)),n&&-1!==n&&(this.movedMap[Tu(this.mirror.getId(e),n)]=!0)}else this.addedSet.add(e),this.droppedSet.delete(e);iu(e,this.blockClass,this.blockSelector,this.unblockSelector,!1)||(e.childNodes.forEach((e=>this.genAdds(e))),pu(e)&&e.shadowRoot.childNodes.forEach((t=>{this.processedNodeManager.add(t,this),this.genAdds(t,e)})))}}}init(e){["mutationCb","blockClass","blockSelector","unblockSelector","maskAllText","maskTextClass","unmaskTextClass","maskTextSelector","unmaskTextSelector","inlineStylesheet","maskInputOptions","maskAttributeFn","maskTextFn","maskInputFn","keepIframeSrcFn","recordCanvas","inlineImages","slimDOMOptions","dataURLOptions","doc","mirror","iframeManager","stylesheetManager","shadowDomManager","canvasManager","processedNodeManager"].forEach((t=>{this[t]=e[t]}))}freeze(){this.frozen=!0,this.canvasManager.freeze()}unfreeze(){this.frozen=!1,this.canvasManager.unfreeze(),this.emit()}isFrozen(){return this.frozen}lock(){this.locked=!0,this.canvasManager.lock()}unlock(){this.locked=!1,this.canvasManager.unlock(),this.emit()}reset(){this.shadowDomManager.reset(),this.canvasManager.reset()}}function Mu(e,t){e.delete(t),t.childNodes.forEach((t=>Mu(e,t)))}function Ru(e,t,n){return 0!==e.length&&Au(e,t,n)}function Au(e,t,n){const{parentNode:r}=t;if(!r)return!1;const o=n.getId(r);return!!e.some((e=>e.id===o))||Au(e,r,n)}function Nu(e,t){return 0!==e.size&&Ou(e,t)}function Ou(e,t){const{parentNode:n}=t;return!!n&&(!!e.has(n)||Ou(e,n))}let Du;const Lu=e=>{if(!Du)return e;return(...t)=>{try{return e(...t)}catch(e){if(Du&&!0===Du(e))return()=>{};throw e}}};function Pu(e){let t,n=e[0],r=1;for(;r<e.length;){const o=e[r],s=e[r+1];if(r+=2,("optionalAccess"===o||"optionalCall"===o)&&null==n)return;"access"===o||"optionalAccess"===o?(t=n,n=s(n)):"call"!==o&&"optionalCall"!==o||(n=s(((...e)=>n.call(t,...e))),t=void 0)}return n}const Fu=[];function $u(e){try{if("composedPath"in e){const t=e.composedPath();if(t.length)return t[0]}else if("path"in e&&e.path.length)return e.path[0]}catch(e){}return e&&e.target}function Bu(e,t){const n=new Iu;Fu.push(n),n.init(e);let r=window.MutationObserver||window.__rrMutationObserver;const o=Pu([window,"optionalAccess",e=>e.Zone,"optionalAccess",e=>e.__symbol__,"optionalCall",e=>e("MutationObserver")]);o&&window[o]&&(r=window[o]);const s=new r(Lu((t=>{e.onMutation&&!1===e.onMutation(t)||n.processMutations.bind(n)(t)})));return s.observe(t,{attributes:!0,attributeOldValue:!0,characterData:!0,characterDataOldValue:!0,childList:!0,subtree:!0}),s}function Uu({mouseInteractionCb:e,doc:t,mirror:n,blockClass:r,blockSelector:o,unblockSelector:s,sampling:i}){if(!1===i.mouseInteraction)return()=>{};const a=!0===i.mouseInteraction||void 0===i.mouseInteraction?{}:i.mouseInteraction,c=[];let u=null;return Object.keys(wu).filter((e=>Number.isNaN(Number(e))&&!e.endsWith("_Departed")&&!1!==a[e])).forEach((i=>{let a=fc(i);const l=(t=>i=>{const a=$u(i);if(iu(a,r,o,s,!0))return;let c=null,l=t;if("pointerType"in i){switch(i.pointerType){case"mouse":c=ku.Mouse;break;case"touch":c=ku.Touch;break;case"pen":c=ku.Pen}c===ku.Touch?wu[t]===wu.MouseDown?l="TouchStart":wu[t]===wu.MouseUp&&(l="TouchEnd"):ku.Pen}else uu(i)&&(c=ku.Touch);null!==c?(u=c,(l.startsWith("Touch")&&c===ku.Touch||l.startsWith("Mouse")&&c===ku.Mouse)&&(c=null)):wu[t]===wu.Click&&(c=u,u=null);const d=uu(i)?i.changedTouches[0]:i;if(!d)return;const p=n.getId(a),{clientX:h,clientY:f}=d;Lu(e)({type:wu[l],id:p,x:h,y:f,...null!==c&&{pointerType:c}})})(i);if(window.PointerEvent)switch(wu[i]){case wu.MouseDown:case wu.MouseUp:a=a.replace("mouse","pointer");break;case wu.TouchStart:case wu.TouchEnd:return}c.push(Xc(a,l,t))})),Lu((()=>{c.forEach((e=>e()))}))}function ju({scrollCb:e,doc:t,mirror:n,blockClass:r,blockSelector:o,unblockSelector:s,sampling:i}){return Xc("scroll",Lu(Qc(Lu((i=>{const a=$u(i);if(!a||iu(a,r,o,s,!0))return;const c=n.getId(a);if(a===t&&t.defaultView){const n=nu(t.defaultView);e({id:c,x:n.left,y:n.top})}else e({id:c,x:a.scrollLeft,y:a.scrollTop})})),i.scroll||100)),t)}const Hu=["INPUT","TEXTAREA","SELECT"],zu=new WeakMap;function qu({inputCb:e,doc:t,mirror:n,blockClass:r,blockSelector:o,unblockSelector:s,ignoreClass:i,ignoreSelector:a,maskInputOptions:c,maskInputFn:u,sampling:l,userTriggeredOnInput:d,maskTextClass:p,unmaskTextClass:h,maskTextSelector:f,unmaskTextSelector:m}){function g(e){let n=$u(e);const l=e.isTrusted,g=n&&mc(n.tagName);if("OPTION"===g&&(n=n.parentElement),!n||!g||Hu.indexOf(g)<0||iu(n,r,o,s,!0))return;const y=n;if(y.classList.contains(i)||a&&y.matches(a))return;const v=_c(n);let b=yc(y,g,v),S=!1;const w=pc({maskInputOptions:c,tagName:g,type:v}),k=qc(n,p,f,h,m,w);"radio"!==v&&"checkbox"!==v||(S=n.checked),b=hc({isMasked:k,element:n,value:b,maskInputFn:u}),_(n,d?{text:b,isChecked:S,userTriggered:l}:{text:b,isChecked:S});const x=n.name;"radio"===v&&x&&S&&t.querySelectorAll(`input[type="radio"][name="${x}"]`).forEach((e=>{if(e!==n){const t=hc({isMasked:k,element:e,value:yc(e,g,v),maskInputFn:u});_(e,d?{text:t,isChecked:!S,userTriggered:!1}:{text:t,isChecked:!S})}}))}function _(t,r){const o=zu.get(t);if(!o||o.text!==r.text||o.isChecked!==r.isChecked){zu.set(t,r);const o=n.getId(t);Lu(e)({...r,id:o})}}const y=("last"===l.input?["change"]:["input","change"]).map((e=>Xc(e,Lu(g),t))),v=t.defaultView;if(!v)return()=>{y.forEach((e=>e()))};const b=v.Object.getOwnPropertyDescriptor(v.HTMLInputElement.prototype,"value"),S=[[v.HTMLInputElement.prototype,"value"],[v.HTMLInputElement.prototype,"checked"],[v.HTMLSelectElement.prototype,"value"],[v.HTMLTextAreaElement.prototype,"value"],[v.HTMLSelectElement.prototype,"selectedIndex"],[v.HTMLOptionElement.prototype,"selected"]];return b&&b.set&&y.push(...S.map((e=>Zc(e[0],e[1],{set(){Lu(g)({target:this,isTrusted:!1})}},!1,v)))),Lu((()=>{y.forEach((e=>e()))}))}function Wu(e){return function(e,t){if(Xu("CSSGroupingRule")&&e.parentRule instanceof CSSGroupingRule||Xu("CSSMediaRule")&&e.parentRule instanceof CSSMediaRule||Xu("CSSSupportsRule")&&e.parentRule instanceof CSSSupportsRule||Xu("CSSConditionRule")&&e.parentRule instanceof CSSConditionRule){const n=Array.from(e.parentRule.cssRules).indexOf(e);t.unshift(n)}else if(e.parentStyleSheet){const n=Array.from(e.parentStyleSheet.cssRules).indexOf(e);t.unshift(n)}return t}(e,[])}function Yu(e,t,n){let r,o;return e?(e.ownerNode?r=t.getId(e.ownerNode):o=n.getId(e),{styleId:o,id:r}):{}}function Vu({mirror:e,stylesheetManager:t},n){let r=null;r="#document"===n.nodeName?e.getId(n):e.getId(n.host);const o="#document"===n.nodeName?Pu([n,"access",e=>e.defaultView,"optionalAccess",e=>e.Document]):Pu([n,"access",e=>e.ownerDocument,"optionalAccess",e=>e.defaultView,"optionalAccess",e=>e.ShadowRoot]),s=Pu([o,"optionalAccess",e=>e.prototype])?Object.getOwnPropertyDescriptor(Pu([o,"optionalAccess",e=>e.prototype]),"adoptedStyleSheets"):void 0;return null!==r&&-1!==r&&o&&s?(Object.defineProperty(n,"adoptedStyleSheets",{configurable:s.configurable,enumerable:s.enumerable,get(){return Pu([s,"access",e=>e.get,"optionalAccess",e=>e.call,"call",e=>e(this)])},set(e){const n=Pu([s,"access",e=>e.set,"optionalAccess",e=>e.call,"call",t=>t(this,e)]);if(null!==r&&-1!==r)try{t.adoptStyleSheets(e,r)}catch(e){}return n}}),Lu((()=>{Object.defineProperty(n,"adoptedStyleSheets",{configurable:s.configurable,enumerable:s.enumerable,get:s.get,set:s.set})}))):()=>{}}function Gu(e,t={}){const n=e.doc.defaultView;if(!n)return()=>{};let r;e.recordDOM&&(r=Bu(e,e.doc));const o=function({mousemoveCb:e,sampling:t,doc:n,mirror:r}){if(!1===t.mousemove)return()=>{};const o="number"==typeof t.mousemove?t.mousemove:50,s="number"==typeof t.mousemoveCallback?t.mousemoveCallback:500;let i,a=[];const c=Qc(Lu((t=>{const n=Date.now()-i;e(a.map((e=>(e.timeOffset-=n,e))),t),a=[],i=null})),s),u=Lu(Qc(Lu((e=>{const t=$u(e),{clientX:n,clientY:o}=uu(e)?e.changedTouches[0]:e;i||(i=tu()),a.push({x:n,y:o,id:r.getId(t),timeOffset:tu()-i}),c("undefined"!=typeof DragEvent&&e instanceof DragEvent?Su.Drag:e instanceof MouseEvent?Su.MouseMove:Su.TouchMove)})),o,{trailing:!1})),l=[Xc("mousemove",u,n),Xc("touchmove",u,n),Xc("drag",u,n)];return Lu((()=>{l.forEach((e=>e()))}))}(e),s=Uu(e),i=ju(e),a=function({viewportResizeCb:e},{win:t}){let n=-1,r=-1;return Xc("resize",Lu(Qc(Lu((()=>{const t=ru(),o=ou();n===t&&r===o||(e({width:Number(o),height:Number(t)}),n=t,r=o)})),200)),t)}(e,{win:n}),c=qu(e),u=function({mediaInteractionCb:e,blockClass:t,blockSelector:n,unblockSelector:r,mirror:o,sampling:s,doc:i}){const a=Lu((i=>Qc(Lu((s=>{const a=$u(s);if(!a||iu(a,t,n,r,!0))return;const{currentTime:c,volume:u,muted:l,playbackRate:d}=a;e({type:i,id:o.getId(a),currentTime:c,volume:u,muted:l,playbackRate:d})})),s.media||500))),c=[Xc("play",a(0),i),Xc("pause",a(1),i),Xc("seeked",a(2),i),Xc("volumechange",a(3),i),Xc("ratechange",a(4),i)];return Lu((()=>{c.forEach((e=>e()))}))}(e);let l=()=>{},d=()=>{},p=()=>{},h=()=>{};e.recordDOM&&(l=function({styleSheetRuleCb:e,mirror:t,stylesheetManager:n},{win:r}){if(!r.CSSStyleSheet||!r.CSSStyleSheet.prototype)return()=>{};const o=r.CSSStyleSheet.prototype.insertRule;r.CSSStyleSheet.prototype.insertRule=new Proxy(o,{apply:Lu(((r,o,s)=>{const[i,a]=s,{id:c,styleId:u}=Yu(o,t,n.styleMirror);return(c&&-1!==c||u&&-1!==u)&&e({id:c,styleId:u,adds:[{rule:i,index:a}]}),r.apply(o,s)}))});const s=r.CSSStyleSheet.prototype.deleteRule;let i,a;r.CSSStyleSheet.prototype.deleteRule=new Proxy(s,{apply:Lu(((r,o,s)=>{const[i]=s,{id:a,styleId:c}=Yu(o,t,n.styleMirror);return(a&&-1!==a||c&&-1!==c)&&e({id:a,styleId:c,removes:[{index:i}]}),r.apply(o,s)}))}),r.CSSStyleSheet.prototype.replace&&(i=r.CSSStyleSheet.prototype.replace,r.CSSStyleSheet.prototype.replace=new Proxy(i,{apply:Lu(((r,o,s)=>{const[i]=s,{id:a,styleId:c}=Yu(o,t,n.styleMirror);return(a&&-1!==a||c&&-1!==c)&&e({id:a,styleId:c,replace:i}),r.apply(o,s)}))})),r.CSSStyleSheet.prototype.replaceSync&&(a=r.CSSStyleSheet.prototype.replaceSync,r.CSSStyleSheet.prototype.replaceSync=new Proxy(a,{apply:Lu(((r,o,s)=>{const[i]=s,{id:a,styleId:c}=Yu(o,t,n.styleMirror);return(a&&-1!==a||c&&-1!==c)&&e({id:a,styleId:c,replaceSync:i}),r.apply(o,s)}))}));const c={};Ju("CSSGroupingRule")?c.CSSGroupingRule=r.CSSGroupingRule:(Ju("CSSMediaRule")&&(c.CSSMediaRule=r.CSSMediaRule),Ju("CSSConditionRule")&&(c.CSSConditionRule=r.CSSConditionRule),Ju("CSSSupportsRule")&&(c.CSSSupportsRule=r.CSSSupportsRule));const u={};return Object.entries(c).forEach((([r,o])=>{u[r]={insertRule:o.prototype.insertRule,deleteRule:o.prototype.deleteRule},o.prototype.insertRule=new Proxy(u[r].insertRule,{apply:Lu(((r,o,s)=>{const[i,a]=s,{id:c,styleId:u}=Yu(o.parentStyleSheet,t,n.styleMirror);return(c&&-1!==c||u&&-1!==u)&&e({id:c,styleId:u,adds:[{rule:i,index:[...Wu(o),a||0]}]}),r.apply(o,s)}))}),o.prototype.deleteRule=new Proxy(u[r].deleteRule,{apply:Lu(((r,o,s)=>{const[i]=s,{id:a,styleId:c}=Yu(o.parentStyleSheet,t,n.styleMirror);return(a&&-1!==a||c&&-1!==c)&&e({id:a,styleId:c,removes:[{index:[...Wu(o),i]}]}),r.apply(o,s)}))})})),Lu((()=>{r.CSSStyleSheet.prototype.insertRule=o,r.CSSStyleSheet.prototype.deleteRule=s,i&&(r.CSSStyleSheet.prototype.replace=i),a&&(r.CSSStyleSheet.prototype.replaceSync=a),Object.entries(c).forEach((([e,t])=>{t.prototype.insertRule=u[e].insertRule,t.prototype.deleteRule=u[e].deleteRule}))}))}(e,{win:n}),d=Vu(e,e.doc),p=function({styleDeclarationCb:e,mirror:t,ignoreCSSAttributes:n,stylesheetManager:r},{win:o}){const s=o.CSSStyleDeclaration.prototype.setProperty;o.CSSStyleDeclaration.prototype.setProperty=new Proxy(s,{apply:Lu(((o,i,a)=>{const[c,u,l]=a;if(n.has(c))return s.apply(i,[c,u,l]);const{id:d,styleId:p}=Yu(Pu([i,"access",e=>e.parentRule,"optionalAccess",e=>e.parentStyleSheet]),t,r.styleMirror);return(d&&-1!==d||p&&-1!==p)&&e({id:d,styleId:p,set:{property:c,value:u,priority:l},index:Wu(i.parentRule)}),o.apply(i,a)}))});const i=o.CSSStyleDeclaration.prototype.removeProperty;return o.CSSStyleDeclaration.prototype.removeProperty=new Proxy(i,{apply:Lu(((o,s,a)=>{const[c]=a;if(n.has(c))return i.apply(s,[c]);const{id:u,styleId:l}=Yu(Pu([s,"access",e=>e.parentRule,"optionalAccess",e=>e.parentStyleSheet]),t,r.styleMirror);return(u&&-1!==u||l&&-1!==l)&&e({id:u,styleId:l,remove:{property:c},index:Wu(s.parentRule)}),o.apply(s,a)}))}),Lu((()=>{o.CSSStyleDeclaration.prototype.setProperty=s,o.CSSStyleDeclaration.prototype.removeProperty=i}))}(e,{win:n}),e.collectFonts&&(h=function({fontCb:e,doc:t}){const n=t.defaultView;if(!n)return()=>{};const r=[],o=new WeakMap,s=n.FontFace;n.FontFace=function(e,t,n){const r=new s(e,t,n);return o.set(r,{family:e,buffer:"string"!=typeof t,descriptors:n,fontSource:"string"==typeof t?t:JSON.stringify(Array.from(new Uint8Array(t)))}),r};const i=eu(t.fonts,"add",(function(t){return function(n){return vu(Lu((()=>{const t=o.get(n);t&&(e(t),o.delete(n))})),0),t.apply(this,[n])}}));return r.push((()=>{n.FontFace=s})),r.push(i),Lu((()=>{r.forEach((e=>e()))}))}(e)));const f=function(e){const{doc:t,mirror:n,blockClass:r,blockSelector:o,unblockSelector:s,selectionCb:i}=e;let a=!0;const c=Lu((()=>{const e=t.getSelection();if(!e||a&&Pu([e,"optionalAccess",e=>e.isCollapsed]))return;a=e.isCollapsed||!1;const c=[],u=e.rangeCount||0;for(let t=0;t<u;t++){const i=e.getRangeAt(t),{startContainer:a,startOffset:u,endContainer:l,endOffset:d}=i;iu(a,r,o,s,!0)||iu(l,r,o,s,!0)||c.push({start:n.getId(a),startOffset:u,end:n.getId(l),endOffset:d})}i({ranges:c})}));return c(),Xc("selectionchange",c)}(e),m=function({doc:e,customElementCb:t}){const n=e.defaultView;return n&&n.customElements?eu(n.customElements,"define",(function(e){return function(n,r,o){try{t({define:{name:n}})}catch(e){}return e.apply(this,[n,r,o])}})):()=>{}}(e),g=[];for(const t of e.plugins)g.push(t.observer(t.callback,n,t.options));return Lu((()=>{Fu.forEach((e=>e.reset())),Pu([r,"optionalAccess",e=>e.disconnect,"call",e=>e()]),o(),s(),i(),a(),c(),u(),l(),d(),p(),h(),f(),m(),g.forEach((e=>e()))}))}function Xu(e){return void 0!==window[e]}function Ju(e){return Boolean(void 0!==window[e]&&window[e].prototype&&"insertRule"in window[e].prototype&&"deleteRule"in window[e].prototype)}class Ku{constructor(e){this.generateIdFn=e,this.iframeIdToRemoteIdMap=new WeakMap,this.iframeRemoteIdToIdMap=new WeakMap}getId(e,t,n,r){const o=n||this.getIdToRemoteIdMap(e),s=r||this.getRemoteIdToIdMap(e);let i=o.get(t);return i||(i=this.generateIdFn(),o.set(t,i),s.set(i,t)),i}getIds(e,t){const n=this.getIdToRemoteIdMap(e),r=this.getRemoteIdToIdMap(e);return t.map((t=>this.getId(e,t,n,r)))}getRemoteId(e,t,n){const r=n||this.getRemoteIdToIdMap(e);if("number"!=typeof t)return t;const o=r.get(t);return o||-1}getRemoteIds(e,t){const n=this.getRemoteIdToIdMap(e);return t.map((t=>this.getRemoteId(e,t,n)))}reset(e){if(!e)return this.iframeIdToRemoteIdMap=new WeakMap,void(this.iframeRemoteIdToIdMap=new WeakMap);this.iframeIdToRemoteIdMap.delete(e),this.iframeRemoteIdToIdMap.delete(e)}getIdToRemoteIdMap(e){let t=this.iframeIdToRemoteIdMap.get(e);return t||(t=new Map,this.iframeIdToRemoteIdMap.set(e,t)),t}getRemoteIdToIdMap(e){let t=this.iframeRemoteIdToIdMap.get(e);return t||(t=new Map,this.iframeRemoteIdToIdMap.set(e,t)),t}}function Qu(e){let t,n=e[0],r=1;for(;r<e.length;){const o=e[r],s=e[r+1];if(r+=2,("optionalAccess"===o||"optionalCall"===o)&&null==n)return;"access"===o||"optionalAccess"===o?(t=n,n=s(n)):"call"!==o&&"optionalCall"!==o||(n=s(((...e)=>n.call(t,...e))),t=void 0)}return n}class Zu{constructor(){this.crossOriginIframeMirror=new Ku(Tc),this.crossOriginIframeRootIdMap=new WeakMap}addIframe(){}addLoadListener(){}attachIframe(){}}class el{constructor(e){this.iframes=new WeakMap,this.crossOriginIframeMap=new WeakMap,this.crossOriginIframeMirror=new Ku(Tc),this.crossOriginIframeRootIdMap=new WeakMap,this.mutationCb=e.mutationCb,this.wrappedEmit=e.wrappedEmit,this.stylesheetManager=e.stylesheetManager,this.recordCrossOriginIframes=e.recordCrossOriginIframes,this.crossOriginIframeStyleMirror=new Ku(this.stylesheetManager.styleMirror.generateId.bind(this.stylesheetManager.styleMirror)),this.mirror=e.mirror,this.recordCrossOriginIframes&&window.addEventListener("message",this.handleMessage.bind(this))}addIframe(e){this.iframes.set(e,!0),e.contentWindow&&this.crossOriginIframeMap.set(e.contentWindow,e)}addLoadListener(e){this.loadListener=e}attachIframe(e,t){this.mutationCb({adds:[{parentId:this.mirror.getId(e),nextId:null,node:t}],removes:[],texts:[],attributes:[],isAttachIframe:!0}),Qu([this,"access",e=>e.loadListener,"optionalCall",t=>t(e)]),e.contentDocument&&e.contentDocument.adoptedStyleSheets&&e.contentDocument.adoptedStyleSheets.length>0&&this.stylesheetManager.adoptStyleSheets(e.contentDocument.adoptedStyleSheets,this.mirror.getId(e.contentDocument))}handleMessage(e){const t=e;if("rrweb"!==t.data.type||t.origin!==t.data.origin)return;if(!e.source)return;const n=this.crossOriginIframeMap.get(e.source);if(!n)return;const r=this.transformCrossOriginEvent(n,t.data.event);r&&this.wrappedEmit(r,t.data.isCheckout)}transformCrossOriginEvent(e,t){switch(t.type){case bu.FullSnapshot:{this.crossOriginIframeMirror.reset(e),this.crossOriginIframeStyleMirror.reset(e),this.replaceIdOnNode(t.data.node,e);const n=t.data.node.id;return this.crossOriginIframeRootIdMap.set(e,n),this.patchRootIdOnNode(t.data.node,n),{timestamp:t.timestamp,type:bu.IncrementalSnapshot,data:{source:Su.Mutation,adds:[{parentId:this.mirror.getId(e),nextId:null,node:t.data.node}],removes:[],texts:[],attributes:[],isAttachIframe:!0}}}case bu.Meta:case bu.Load:case bu.DomContentLoaded:return!1;case bu.Plugin:return t;case bu.Custom:return this.replaceIds(t.data.payload,e,["id","parentId","previousId","nextId"]),t;case bu.IncrementalSnapshot:switch(t.data.source){case Su.Mutation:return t.data.adds.forEach((t=>{this.replaceIds(t,e,["parentId","nextId","previousId"]),this.replaceIdOnNode(t.node,e);const n=this.crossOriginIframeRootIdMap.get(e);n&&this.patchRootIdOnNode(t.node,n)})),t.data.removes.forEach((t=>{this.replaceIds(t,e,["parentId","id"])})),t.data.attributes.forEach((t=>{this.replaceIds(t,e,["id"])})),t.data.texts.forEach((t=>{this.replaceIds(t,e,["id"])})),t;case Su.Drag:case Su.TouchMove:case Su.MouseMove:return t.data.positions.forEach((t=>{this.replaceIds(t,e,["id"])})),t;case Su.ViewportResize:return!1;case Su.MediaInteraction:case Su.MouseInteraction:case Su.Scroll:case Su.CanvasMutation:case Su.Input:return this.replaceIds(t.data,e,["id"]),t;case Su.StyleSheetRule:case Su.StyleDeclaration:return this.replaceIds(t.data,e,["id"]),this.replaceStyleIds(t.data,e,["styleId"]),t;case Su.Font:return t;case Su.Selection:return t.data.ranges.forEach((t=>{this.replaceIds(t,e,["start","end"])})),t;case Su.AdoptedStyleSheet:return this.replaceIds(t.data,e,["id"]),this.replaceStyleIds(t.data,e,["styleIds"]),Qu([t,"access",e=>e.data,"access",e=>e.styles,"optionalAccess",e=>e.forEach,"call",t=>t((t=>{this.replaceStyleIds(t,e,["styleId"])}))]),t}}return!1}replace(e,t,n,r){for(const o of r)(Array.isArray(t[o])||"number"==typeof t[o])&&(Array.isArray(t[o])?t[o]=e.getIds(n,t[o]):t[o]=e.getId(n,t[o]));return t}replaceIds(e,t,n){return this.replace(this.crossOriginIframeMirror,e,t,n)}replaceStyleIds(e,t,n){return this.replace(this.crossOriginIframeStyleMirror,e,t,n)}replaceIdOnNode(e,t){this.replaceIds(e,t,["id","rootId"]),"childNodes"in e&&e.childNodes.forEach((e=>{this.replaceIdOnNode(e,t)}))}patchRootIdOnNode(e,t){e.type===ic.Document||e.rootId||(e.rootId=t),"childNodes"in e&&e.childNodes.forEach((e=>{this.patchRootIdOnNode(e,t)}))}}class tl{init(){}addShadowRoot(){}observeAttachShadow(){}reset(){}}class nl{constructor(e){this.shadowDoms=new WeakSet,this.restoreHandlers=[],this.mutationCb=e.mutationCb,this.scrollCb=e.scrollCb,this.bypassOptions=e.bypassOptions,this.mirror=e.mirror,this.init()}init(){this.reset(),this.patchAttachShadow(Element,document)}addShadowRoot(e,t){if(!cc(e))return;if(this.shadowDoms.has(e))return;this.shadowDoms.add(e),this.bypassOptions.canvasManager.addShadowRoot(e);const n=Bu({...this.bypassOptions,doc:t,mutationCb:this.mutationCb,mirror:this.mirror,shadowDomManager:this},e);this.restoreHandlers.push((()=>n.disconnect())),this.restoreHandlers.push(ju({...this.bypassOptions,scrollCb:this.scrollCb,doc:e,mirror:this.mirror})),vu((()=>{e.adoptedStyleSheets&&e.adoptedStyleSheets.length>0&&this.bypassOptions.stylesheetManager.adoptStyleSheets(e.adoptedStyleSheets,this.mirror.getId(e.host)),this.restoreHandlers.push(Vu({mirror:this.mirror,stylesheetManager:this.bypassOptions.stylesheetManager},e))}),0)}observeAttachShadow(e){e.contentWindow&&e.contentDocument&&this.patchAttachShadow(e.contentWindow.Element,e.contentDocument)}patchAttachShadow(e,t){const n=this;this.restoreHandlers.push(eu(e.prototype,"attachShadow",(function(e){return function(r){const o=e.call(this,r);return this.shadowRoot&&gu(this)&&n.addShadowRoot(this.shadowRoot,t),o}})))}reset(){this.restoreHandlers.forEach((e=>{try{e()}catch(e){}})),this.restoreHandlers=[],this.shadowDoms=new WeakSet,this.bypassOptions.canvasManager.resetShadowRoots()}}class rl{reset(){}freeze(){}unfreeze(){}lock(){}unlock(){}snapshot(){}addWindow(){}addShadowRoot(){}resetShadowRoots(){}}class ol{constructor(e){this.trackedLinkElements=new WeakSet,this.styleMirror=new hu,this.mutationCb=e.mutationCb,this.adoptedStyleSheetCb=e.adoptedStyleSheetCb}attachLinkElement(e,t){"_cssText"in t.attributes&&this.mutationCb({adds:[],removes:[],texts:[],attributes:[{id:t.id,attributes:t.attributes}]}),this.trackLinkElement(e)}trackLinkElement(e){this.trackedLinkElements.has(e)||(this.trackedLinkElements.add(e),this.trackStylesheetInLinkElement(e))}adoptStyleSheets(e,t){if(0===e.length)return;const n={id:t,styleIds:[]},r=[];for(const t of e){let e;this.styleMirror.has(t)?e=this.styleMirror.getId(t):(e=this.styleMirror.add(t),r.push({styleId:e,rules:Array.from(t.rules||CSSRule,((e,t)=>({rule:lc(e),index:t})))})),n.styleIds.push(e)}r.length>0&&(n.styles=r),this.adoptedStyleSheetCb(n)}reset(){this.styleMirror.reset(),this.trackedLinkElements=new WeakSet}trackStylesheetInLinkElement(e){}}class sl{constructor(){this.nodeMap=new WeakMap,this.loop=!0,this.periodicallyClear()}periodicallyClear(){!function(...e){yu("requestAnimationFrame")(...e)}((()=>{this.clear(),this.loop&&this.periodicallyClear()}))}inOtherBuffer(e,t){const n=this.nodeMap.get(e);return n&&Array.from(n).some((e=>e!==t))}add(e,t){this.nodeMap.set(e,(this.nodeMap.get(e)||new Set).add(t))}clear(){this.nodeMap=new WeakMap}destroy(){this.loop=!1}}let il,al;try{if(2!==Array.from([1],(e=>2*e))[0]){const e=document.createElement("iframe");document.body.appendChild(e),Array.from=Mt([e,"access",e=>e.contentWindow,"optionalAccess",e=>e.Array,"access",e=>e.from])||Array.from,document.body.removeChild(e)}}catch(e){console.debug("Unable to override Array.from",e)}const cl=new dc;function ul(e={}){const{emit:t,checkoutEveryNms:n,checkoutEveryNth:r,blockClass:o="rr-block",blockSelector:s=null,unblockSelector:i=null,ignoreClass:a="rr-ignore",ignoreSelector:c=null,maskAllText:u=!1,maskTextClass:l="rr-mask",unmaskTextClass:d=null,maskTextSelector:p=null,unmaskTextSelector:h=null,inlineStylesheet:f=!0,maskAllInputs:m,maskInputOptions:g,slimDOMOptions:_,maskAttributeFn:y,maskInputFn:v,maskTextFn:b,maxCanvasSize:S=null,packFn:w,sampling:k={},dataURLOptions:x={},mousemoveWait:E,recordDOM:C=!0,recordCanvas:T=!1,recordCrossOriginIframes:I=!1,recordAfter:M=("DOMContentLoaded"===e.recordAfter?e.recordAfter:"load"),userTriggeredOnInput:R=!1,collectFonts:A=!1,inlineImages:N=!1,plugins:O,keepIframeSrcFn:D=()=>!1,ignoreCSSAttributes:L=new Set([]),errorHandler:P,onMutation:F,getCanvasManager:$}=e;Du=P;const B=!I||window.parent===window;let U=!1;if(!B)try{window.parent.document&&(U=!1)}catch(e){U=!0}if(B&&!t)throw new Error("emit function is required");void 0!==E&&void 0===k.mousemove&&(k.mousemove=E),cl.reset();const j=!0===m?{color:!0,date:!0,"datetime-local":!0,email:!0,month:!0,number:!0,range:!0,search:!0,tel:!0,text:!0,time:!0,url:!0,week:!0,textarea:!0,select:!0,radio:!0,checkbox:!0}:void 0!==g?g:{},H=!0===_||"all"===_?{script:!0,comment:!0,headFavicon:!0,headWhitespace:!0,headMetaSocial:!0,headMetaRobots:!0,headMetaHttpEquiv:!0,headMetaVerification:!0,headMetaAuthorship:"all"===_,headMetaDescKeywords:"all"===_}:_||{};let z;!function(e=window){"NodeList"in e&&!e.NodeList.prototype.forEach&&(e.NodeList.prototype.forEach=Array.prototype.forEach),"DOMTokenList"in e&&!e.DOMTokenList.prototype.forEach&&(e.DOMTokenList.prototype.forEach=Array.prototype.forEach),Node.prototype.contains||(Node.prototype.contains=(...e)=>{let t=e[0];if(!(0 in e))throw new TypeError("1 argument is required");do{if(this===t)return!0}while(t=t&&t.parentNode);return!1})}();let q=0;const W=e=>{for(const t of O||[])t.eventProcessor&&(e=t.eventProcessor(e));return w&&!U&&(e=w(e)),e};il=(e,o)=>{const s=e;if(s.timestamp=tu(),!Mt([Fu,"access",e=>e[0],"optionalAccess",e=>e.isFrozen,"call",e=>e()])||s.type===bu.FullSnapshot||s.type===bu.IncrementalSnapshot&&s.data.source===Su.Mutation||Fu.forEach((e=>e.unfreeze())),B)Mt([t,"optionalCall",e=>e(W(s),o)]);else if(U){const e={type:"rrweb",event:W(s),origin:window.location.origin,isCheckout:o};window.parent.postMessage(e,"*")}if(s.type===bu.FullSnapshot)z=s,q=0;else if(s.type===bu.IncrementalSnapshot){if(s.data.source===Su.Mutation&&s.data.isAttachIframe)return;q++;const e=r&&q>=r,t=n&&z&&s.timestamp-z.timestamp>n;(e||t)&&ee(!0)}};const Y=e=>{il({type:bu.IncrementalSnapshot,data:{source:Su.Mutation,...e}})},V=e=>il({type:bu.IncrementalSnapshot,data:{source:Su.Scroll,...e}}),G=e=>il({type:bu.IncrementalSnapshot,data:{source:Su.CanvasMutation,...e}}),X=new ol({mutationCb:Y,adoptedStyleSheetCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.AdoptedStyleSheet,...e}})}),J="boolean"==typeof __RRWEB_EXCLUDE_IFRAME__&&__RRWEB_EXCLUDE_IFRAME__?new Zu:new el({mirror:cl,mutationCb:Y,stylesheetManager:X,recordCrossOriginIframes:I,wrappedEmit:il});for(const e of O||[])e.getMirror&&e.getMirror({nodeMirror:cl,crossOriginIframeMirror:J.crossOriginIframeMirror,crossOriginIframeStyleMirror:J.crossOriginIframeStyleMirror});const K=new sl,Q=function(e,t){try{return e?e(t):new rl}catch(e){return console.warn("Unable to initialize CanvasManager"),new rl}}($,{mirror:cl,win:window,mutationCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.CanvasMutation,...e}}),recordCanvas:T,blockClass:o,blockSelector:s,unblockSelector:i,maxCanvasSize:S,sampling:k.canvas,dataURLOptions:x,errorHandler:P}),Z="boolean"==typeof __RRWEB_EXCLUDE_SHADOW_DOM__&&__RRWEB_EXCLUDE_SHADOW_DOM__?new tl:new nl({mutationCb:Y,scrollCb:V,bypassOptions:{onMutation:F,blockClass:o,blockSelector:s,unblockSelector:i,maskAllText:u,maskTextClass:l,unmaskTextClass:d,maskTextSelector:p,unmaskTextSelector:h,inlineStylesheet:f,maskInputOptions:j,dataURLOptions:x,maskAttributeFn:y,maskTextFn:b,maskInputFn:v,recordCanvas:T,inlineImages:N,sampling:k,slimDOMOptions:H,iframeManager:J,stylesheetManager:X,canvasManager:Q,keepIframeSrcFn:D,processedNodeManager:K},mirror:cl}),ee=(e=!1)=>{if(!C)return;il({type:bu.Meta,data:{href:window.location.href,width:ou(),height:ru()}},e),X.reset(),Z.init(),Fu.forEach((e=>e.lock()));const t=function(e,t){const{mirror:n=new dc,blockClass:r="rr-block",blockSelector:o=null,unblockSelector:s=null,maskAllText:i=!1,maskTextClass:a="rr-mask",unmaskTextClass:c=null,maskTextSelector:u=null,unmaskTextSelector:l=null,inlineStylesheet:d=!0,inlineImages:p=!1,recordCanvas:h=!1,maskAllInputs:f=!1,maskAttributeFn:m,maskTextFn:g,maskInputFn:_,slimDOM:y=!1,dataURLOptions:v,preserveWhiteSpace:b,onSerialize:S,onIframeLoad:w,iframeLoadTimeout:k,onStylesheetLoad:x,stylesheetLoadTimeout:E,keepIframeSrcFn:C=()=>!1}=t||{};return Vc(e,{doc:e,mirror:n,blockClass:r,blockSelector:o,unblockSelector:s,maskAllText:i,maskTextClass:a,unmaskTextClass:c,maskTextSelector:u,unmaskTextSelector:l,skipChild:!1,inlineStylesheet:d,maskInputOptions:!0===f?{color:!0,date:!0,"datetime-local":!0,email:!0,month:!0,number:!0,range:!0,search:!0,tel:!0,text:!0,time:!0,url:!0,week:!0,textarea:!0,select:!0}:!1===f?{}:f,maskAttributeFn:m,maskTextFn:g,maskInputFn:_,slimDOMOptions:!0===y||"all"===y?{script:!0,comment:!0,headFavicon:!0,headWhitespace:!0,headMetaDescKeywords:"all"===y,headMetaSocial:!0,headMetaRobots:!0,headMetaHttpEquiv:!0,headMetaAuthorship:!0,headMetaVerification:!0}:!1===y?{}:y,dataURLOptions:v,inlineImages:p,recordCanvas:h,preserveWhiteSpace:b,onSerialize:S,onIframeLoad:w,iframeLoadTimeout:k,onStylesheetLoad:x,stylesheetLoadTimeout:E,keepIframeSrcFn:C,newlyAddedElement:!1})}(document,{mirror:cl,blockClass:o,blockSelector:s,unblockSelector:i,maskAllText:u,maskTextClass:l,unmaskTextClass:d,maskTextSelector:p,unmaskTextSelector:h,inlineStylesheet:f,maskAllInputs:j,maskAttributeFn:y,maskInputFn:v,maskTextFn:b,slimDOM:H,dataURLOptions:x,recordCanvas:T,inlineImages:N,onSerialize:e=>{lu(e,cl)&&J.addIframe(e),du(e,cl)&&X.trackLinkElement(e),pu(e)&&Z.addShadowRoot(e.shadowRoot,document)},onIframeLoad:(e,t)=>{J.attachIframe(e,t),e.contentWindow&&Q.addWindow(e.contentWindow),Z.observeAttachShadow(e)},onStylesheetLoad:(e,t)=>{X.attachLinkElement(e,t)},keepIframeSrcFn:D});if(!t)return console.warn("Failed to snapshot the document");il({type:bu.FullSnapshot,data:{node:t,initialOffset:nu(window)}}),Fu.forEach((e=>e.unlock())),document.adoptedStyleSheets&&document.adoptedStyleSheets.length>0&&X.adoptStyleSheets(document.adoptedStyleSheets,cl.getId(document))};al=ee;try{const e=[],t=e=>Lu(Gu)({onMutation:F,mutationCb:Y,mousemoveCb:(e,t)=>il({type:bu.IncrementalSnapshot,data:{source:t,positions:e}}),mouseInteractionCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.MouseInteraction,...e}}),scrollCb:V,viewportResizeCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.ViewportResize,...e}}),inputCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.Input,...e}}),mediaInteractionCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.MediaInteraction,...e}}),styleSheetRuleCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.StyleSheetRule,...e}}),styleDeclarationCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.StyleDeclaration,...e}}),canvasMutationCb:G,fontCb:e=>il({type:bu.IncrementalSnapshot,data:{source:Su.Font,...e}}),selectionCb:e=>{il({type:bu.IncrementalSnapshot,data:{source:Su.Selection,...e}})},customElementCb:e=>{il({type:bu.IncrementalSnapshot,data:{source:Su.CustomElement,...e}})},blockClass:o,ignoreClass:a,ignoreSelector:c,maskAllText:u,maskTextClass:l,unmaskTextClass:d,maskTextSelector:p,unmaskTextSelector:h,maskInputOptions:j,inlineStylesheet:f,sampling:k,recordDOM:C,recordCanvas:T,inlineImages:N,userTriggeredOnInput:R,collectFonts:A,doc:e,maskAttributeFn:y,maskInputFn:v,maskTextFn:b,keepIframeSrcFn:D,blockSelector:s,unblockSelector:i,slimDOMOptions:H,dataURLOptions:x,mirror:cl,iframeManager:J,stylesheetManager:X,shadowDomManager:Z,processedNodeManager:K,canvasManager:Q,ignoreCSSAttributes:L,plugins:Mt([O,"optionalAccess",e=>e.filter,"call",e=>e((e=>e.observer)),"optionalAccess",e=>e.map,"call",e=>e((e=>({observer:e.observer,options:e.options,callback:t=>il({type:bu.Plugin,data:{plugin:e.name,payload:t}})})))])||[]},{});J.addLoadListener((n=>{try{e.push(t(n.contentDocument))}catch(e){console.warn(e)}}));const n=()=>{ee(),e.push(t(document))};return"interactive"===document.readyState||"complete"===document.readyState?n():(e.push(Xc("DOMContentLoaded",(()=>{il({type:bu.DomContentLoaded,data:{}}),"DOMContentLoaded"===M&&n()}))),e.push(Xc("load",(()=>{il({type:bu.Load,data:{}}),"load"===M&&n()}),window))),()=>{e.forEach((e=>e())),K.destroy(),al=void 0,Du=void 0}}catch(e){console.warn(e)}}ul.mirror=cl,ul.takeFullSnapshot=function(e){if(!al)throw new Error("please take full snapshot after start recording");al(e)};const ll="undefined"==typeof __SENTRY_DEBUG__||__SENTRY_DEBUG__,dl=["info","warn","error","log"],pl="[Replay] ";function hl(e,t="info"){io({category:"console",data:{logger:"replay"},level:t,message:`${pl}${e}`},{level:t})}const fl=function(){let e=!1,t=!1;const n={exception:()=>{},infoTick:()=>{},setConfig:n=>{e=n.captureExceptions,t=n.traceInternals}};return ll?(dl.forEach((e=>{n[e]=(...n)=>{L[e](pl,...n),t&&hl(n[0])}})),n.exception=(r,...o)=>{n.error&&n.error(...o),L.error(pl,r),e?Sr(r):t&&hl(r)},n.infoTick=(...e)=>{L.info(pl,...e),t&&setTimeout((()=>hl(e[0])),0)}):dl.forEach((e=>{n[e]=()=>{}})),n}(),ml=3;function gl(e){return e>9999999999?e:1e3*e}function _l(e){return e>9999999999?e/1e3:e}function yl(e,t){"sentry.transaction"!==t.category&&(["ui.click","ui.input"].includes(t.category)?e.triggerUserActivity():e.checkAndHandleExpiredSession(),e.addUpdate((()=>(e.throttledAddEvent({type:bu.Custom,timestamp:1e3*(t.timestamp||0),data:{tag:"breadcrumb",payload:je(t,10,1e3)}}),"console"===t.category))))}const vl="button,a";function bl(e){return e.closest(vl)||e}function Sl(e){const t=wl(e);return t&&t instanceof Element?bl(t):t}function wl(e){return function(e){return"object"==typeof e&&!!e&&"target"in e}(e)?e.target:e}let kl;function xl(e){return kl||(kl=[],H(Ja,"open",(function(e){return function(...t){if(kl)try{kl.forEach((e=>e()))}catch(e){}return e.apply(Ja,t)}}))),kl.push(e),()=>{const t=kl?kl.indexOf(e):-1;t>-1&&kl.splice(t,1)}}const El=new Set([Su.Mutation,Su.StyleSheetRule,Su.StyleDeclaration,Su.AdoptedStyleSheet,Su.CanvasMutation,Su.Selection,Su.MediaInteraction]);class Cl{constructor(e,t,n=yl){this._lastMutation=0,this._lastScroll=0,this._clicks=[],this._timeout=t.timeout/1e3,this._threshold=t.threshold/1e3,this._scollTimeout=t.scrollTimeout/1e3,this._replay=e,this._ignoreSelector=t.ignoreSelector,this._addBreadcrumbEvent=n}addListeners(){const e=xl((()=>{this._lastMutation=Il()}));this._teardown=()=>{e(),this._clicks=[],this._lastMutation=0,this._lastScroll=0}}removeListeners(){this._teardown&&this._teardown(),this._checkClickTimeout&&clearTimeout(this._checkClickTimeout)}handleClick(e,t){if(function(e,t){if(!Tl.includes(e.tagName))return!0;if("INPUT"===e.tagName&&!["submit","button"].includes(e.getAttribute("type")||""))return!0;if("A"===e.tagName&&(e.hasAttribute("download")||e.hasAttribute("target")&&"_self"!==e.getAttribute("target")))return!0;if(t&&e.matches(t))return!0;return!1}(t,this._ignoreSelector)||!function(e){return!(!e.data||"number"!=typeof e.data.nodeId||!e.timestamp)}(e))return;const n={timestamp:_l(e.timestamp),clickBreadcrumb:e,clickCount:0,node:t};this._clicks.some((e=>e.node===n.node&&Math.abs(e.timestamp-n.timestamp)<1))||(this._clicks.push(n),1===this._clicks.length&&this._scheduleCheckClicks())}registerMutation(e=Date.now()){this._lastMutation=_l(e)}registerScroll(e=Date.now()){this._lastScroll=_l(e)}registerClick(e){const t=bl(e);this._handleMultiClick(t)}_handleMultiClick(e){this._getClicks(e).forEach((e=>{e.clickCount++}))}_getClicks(e){return this._clicks.filter((t=>t.node===e))}_checkClicks(){const e=[],t=Il();this._clicks.forEach((n=>{!n.mutationAfter&&this._lastMutation&&(n.mutationAfter=n.timestamp<=this._lastMutation?this._lastMutation-n.timestamp:void 0),!n.scrollAfter&&this._lastScroll&&(n.scrollAfter=n.timestamp<=this._lastScroll?this._lastScroll-n.timestamp:void 0),n.timestamp+this._timeout<=t&&e.push(n)}));for(const t of e){const e=this._clicks.indexOf(t);e>-1&&(this._generateBreadcrumbs(t),this._clicks.splice(e,1))}this._clicks.length&&this._scheduleCheckClicks()}_generateBreadcrumbs(e){const t=this._replay,n=e.scrollAfter&&e.scrollAfter<=this._scollTimeout,r=e.mutationAfter&&e.mutationAfter<=this._threshold,o=!n&&!r,{clickCount:s,clickBreadcrumb:i}=e;if(o){const n=1e3*Math.min(e.mutationAfter||this._timeout,this._timeout),r=n<1e3*this._timeout?"mutation":"timeout",o={type:"default",message:i.message,timestamp:i.timestamp,category:"ui.slowClickDetected",data:{...i.data,url:Ja.location.href,route:t.getCurrentRoute(),timeAfterClickMs:n,endReason:r,clickCount:s||1}};this._addBreadcrumbEvent(t,o)}else if(s>1){const e={type:"default",message:i.message,timestamp:i.timestamp,category:"ui.multiClick",data:{...i.data,url:Ja.location.href,route:t.getCurrentRoute(),clickCount:s,metric:!0}};this._addBreadcrumbEvent(t,e)}}_scheduleCheckClicks(){this._checkClickTimeout&&clearTimeout(this._checkClickTimeout),this._checkClickTimeout=Gi((()=>this._checkClicks()),1e3)}}const Tl=["A","BUTTON","INPUT"];function Il(){return Date.now()/1e3}function Ml(e,t){try{if(!function(e){return e.type===ml}(t))return;const{source:n}=t.data;if(El.has(n)&&e.registerMutation(t.timestamp),n===Su.Scroll&&e.registerScroll(t.timestamp),function(e){return e.data.source===Su.MouseInteraction}(t)){const{type:n,id:r}=t.data,o=ul.mirror.getNode(r);o instanceof HTMLElement&&n===wu.Click&&e.registerClick(o)}}catch(e){}}function Rl(e){return{timestamp:Date.now()/1e3,type:"default",...e}}var Al;!function(e){e[e.Document=0]="Document",e[e.DocumentType=1]="DocumentType",e[e.Element=2]="Element",e[e.Text=3]="Text",e[e.CDATA=4]="CDATA",e[e.Comment=5]="Comment"}(Al||(Al={}));const Nl=new Set(["id","class","aria-label","role","name","alt","title","data-test-id","data-testid","disabled","aria-disabled","data-sentry-component"]);function Ol(e){const t={};!e["data-sentry-component"]&&e["data-sentry-element"]&&(e["data-sentry-component"]=e["data-sentry-element"]);for(const n in e)if(Nl.has(n)){let r=n;"data-testid"!==n&&"data-test-id"!==n||(r="testId"),t[r]=e[n]}return t}const Dl=e=>t=>{if(!e.isEnabled())return;const n=function(e){const{target:t,message:n}=function(e){const t="click"===e.name;let n,r=null;try{r=t?Sl(e.event):wl(e.event),n=T(r,{maxStringLength:200})||"<unknown>"}catch(e){n="<unknown>"}return{target:r,message:n}}(e);return Rl({category:`ui.${e.name}`,...Ll(t,n)})}(t);if(!n)return;const r="click"===t.name,o=r?t.event:void 0;var s,i,a;!(r&&e.clickDetector&&o&&o.target)||o.altKey||o.metaKey||o.ctrlKey||o.shiftKey||(s=e.clickDetector,i=n,a=Sl(t.event),s.handleClick(i,a)),yl(e,n)};function Ll(e,t){const n=ul.mirror.getId(e),r=n&&ul.mirror.getNode(n),o=r&&ul.mirror.getMeta(r),s=o&&function(e){return e.type===Al.Element}(o)?o:null;return{message:t,data:s?{nodeId:n,node:{id:n,tagName:s.tagName,textContent:Array.from(s.childNodes).map((e=>e.type===Al.Text&&e.textContent)).filter(Boolean).map((e=>e.trim())).join(""),attributes:Ol(s.attributes)}}:{}}}function Pl(e,t){if(!e.isEnabled())return;e.updateUserActivity();const n=function(e){const{metaKey:t,shiftKey:n,ctrlKey:r,altKey:o,key:s,target:i}=e;if(!i||function(e){return"INPUT"===e.tagName||"TEXTAREA"===e.tagName||e.isContentEditable}(i)||!s)return null;const a=t||r||o,c=1===s.length;if(!a&&c)return null;const u=T(i,{maxStringLength:200})||"<unknown>",l=Ll(i,u);return Rl({category:"ui.keyDown",message:u,data:{...l.data,metaKey:t,shiftKey:n,ctrlKey:r,altKey:o,key:s}})}(t);n&&yl(e,n)}const Fl={resource:function(e){const{entryType:t,initiatorType:n,name:r,responseEnd:o,startTime:s,decodedBodySize:i,encodedBodySize:a,responseStatus:c,transferSize:u}=e;if(["fetch","xmlhttprequest"].includes(n))return null;return{type:`${t}.${n}`,start:Ul(s),end:Ul(o),name:r,data:{size:u,statusCode:c,decodedBodySize:i,encodedBodySize:a}}},paint:function(e){const{duration:t,entryType:n,name:r,startTime:o}=e,s=Ul(o);return{type:n,name:r,start:s,end:s+t,data:void 0}},navigation:function(e){const{entryType:t,name:n,decodedBodySize:r,duration:o,domComplete:s,encodedBodySize:i,domContentLoadedEventStart:a,domContentLoadedEventEnd:c,domInteractive:u,loadEventStart:l,loadEventEnd:d,redirectCount:p,startTime:h,transferSize:f,type:m}=e;if(0===o)return null;return{type:`${t}.${m}`,start:Ul(h),end:Ul(s),name:n,data:{size:f,decodedBodySize:r,encodedBodySize:i,duration:o,domInteractive:u,domContentLoadedEventStart:a,domContentLoadedEventEnd:c,loadEventStart:l,loadEventEnd:d,domComplete:s,redirectCount:p}}}};function $l(e,t){return({metric:n})=>{t.replayPerformanceEntries.push(e(n))}}function Bl(e){const t=Fl[e.entryType];return t?t(e):null}function Ul(e){return((be||Ja.performance.timeOrigin)+e)/1e3}function jl(e){const t=e.entries[e.entries.length-1];return Yl(e,"largest-contentful-paint",t&&t.element?[t.element]:void 0)}function Hl(e){return void 0!==e.sources}function zl(e){const t=[],n=[];for(const r of e.entries)if(Hl(r)){const e=[];for(const t of r.sources)if(t.node){n.push(t.node);const r=ul.mirror.getId(t.node);r&&e.push(r)}t.push({value:r.value,nodeIds:e.length?e:void 0})}return Yl(e,"cumulative-layout-shift",n,t)}function ql(e){const t=e.entries[e.entries.length-1];return Yl(e,"first-input-delay",t&&t.target?[t.target]:void 0)}function Wl(e){const t=e.entries[e.entries.length-1];return Yl(e,"interaction-to-next-paint",t&&t.target?[t.target]:void 0)}function Yl(e,t,n,r){const o=e.value,s=e.rating,i=Ul(o);return{type:"web-vital",name:t,start:i,end:i,data:{value:o,size:o,rating:s,nodeIds:n?n.map((e=>ul.mirror.getId(e))):void 0,attributions:r}}}const Vl='var t=Uint8Array,n=Uint16Array,r=Int32Array,e=new t([0,0,0,0,0,0,0,0,1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4,5,5,5,5,0,0,0,0]),i=new t([0,0,0,0,1,1,2,2,3,3,4,4,5,5,6,6,7,7,8,8,9,9,10,10,11,11,12,12,13,13,0,0]),a=new t([16,17,18,0,8,7,9,6,10,5,11,4,12,3,13,2,14,1,15]),s=function(t,e){for(var i=new n(31),a=0;a<31;++a)i[a]=e+=1<<t[a-1];var s=new r(i[30]);for(a=1;a<30;++a)for(var o=i[a];o<i[a+1];++o)s[o]=o-i[a]<<5|a;return{b:i,r:s}},o=s(e,2),f=o.b,h=o.r;f[28]=258,h[258]=28;for(var l=s(i,0).r,u=new n(32768),c=0;c<32768;++c){var v=(43690&c)>>1|(21845&c)<<1;v=(61680&(v=(52428&v)>>2|(13107&v)<<2))>>4|(3855&v)<<4,u[c]=((65280&v)>>8|(255&v)<<8)>>1}var d=function(t,r,e){for(var i=t.length,a=0,s=new n(r);a<i;++a)t[a]&&++s[t[a]-1];var o,f=new n(r);for(a=1;a<r;++a)f[a]=f[a-1]+s[a-1]<<1;if(e){o=new n(1<<r);var h=15-r;for(a=0;a<i;++a)if(t[a])for(var l=a<<4|t[a],c=r-t[a],v=f[t[a]-1]++<<c,d=v|(1<<c)-1;v<=d;++v)o[u[v]>>h]=l}else for(o=new n(i),a=0;a<i;++a)t[a]&&(o[a]=u[f[t[a]-1]++]>>15-t[a]);return o},g=new t(288);for(c=0;c<144;++c)g[c]=8;for(c=144;c<256;++c)g[c]=9;for(c=256;c<280;++c)g[c]=7;for(c=280;c<288;++c)g[c]=8;var w=new t(32);for(c=0;c<32;++c)w[c]=5;var p=d(g,9,0),y=d(w,5,0),m=function(t){return(t+7)/8|0},b=function(n,r,e){return(null==r||r<0)&&(r=0),(null==e||e>n.length)&&(e=n.length),new t(n.subarray(r,e))},M=["unexpected EOF","invalid block type","invalid length/literal","invalid distance","stream finished","no stream handler",,"no callback","invalid UTF-8 data","extra field too long","date not in range 1980-2099","filename too long","stream finishing","invalid zip data"],E=function(t,n,r){var e=new Error(n||M[t]);if(e.code=t,Error.captureStackTrace&&Error.captureStackTrace(e,E),!r)throw e;return e},z=function(t,n,r){r<<=7&n;var e=n/8|0;t[e]|=r,t[e+1]|=r>>8},A=function(t,n,r){r<<=7&n;var e=n/8|0;t[e]|=r,t[e+1]|=r>>8,t[e+2]|=r>>16},_=function(r,e){for(var i=[],a=0;a<r.length;++a)r[a]&&i.push({s:a,f:r[a]});var s=i.length,o=i.slice();if(!s)return{t:F,l:0};if(1==s){var f=new t(i[0].s+1);return f[i[0].s]=1,{t:f,l:1}}i.sort((function(t,n){return t.f-n.f})),i.push({s:-1,f:25001});var h=i[0],l=i[1],u=0,c=1,v=2;for(i[0]={s:-1,f:h.f+l.f,l:h,r:l};c!=s-1;)h=i[i[u].f<i[v].f?u++:v++],l=i[u!=c&&i[u].f<i[v].f?u++:v++],i[c++]={s:-1,f:h.f+l.f,l:h,r:l};var d=o[0].s;for(a=1;a<s;++a)o[a].s>d&&(d=o[a].s);var g=new n(d+1),w=x(i[c-1],g,0);if(w>e){a=0;var p=0,y=w-e,m=1<<y;for(o.sort((function(t,n){return g[n.s]-g[t.s]||t.f-n.f}));a<s;++a){var b=o[a].s;if(!(g[b]>e))break;p+=m-(1<<w-g[b]),g[b]=e}for(p>>=y;p>0;){var M=o[a].s;g[M]<e?p-=1<<e-g[M]++-1:++a}for(;a>=0&&p;--a){var E=o[a].s;g[E]==e&&(--g[E],++p)}w=e}return{t:new t(g),l:w}},x=function(t,n,r){return-1==t.s?Math.max(x(t.l,n,r+1),x(t.r,n,r+1)):n[t.s]=r},D=function(t){for(var r=t.length;r&&!t[--r];);for(var e=new n(++r),i=0,a=t[0],s=1,o=function(t){e[i++]=t},f=1;f<=r;++f)if(t[f]==a&&f!=r)++s;else{if(!a&&s>2){for(;s>138;s-=138)o(32754);s>2&&(o(s>10?s-11<<5|28690:s-3<<5|12305),s=0)}else if(s>3){for(o(a),--s;s>6;s-=6)o(8304);s>2&&(o(s-3<<5|8208),s=0)}for(;s--;)o(a);s=1,a=t[f]}return{c:e.subarray(0,i),n:r}},T=function(t,n){for(var r=0,e=0;e<n.length;++e)r+=t[e]*n[e];return r},k=function(t,n,r){var e=r.length,i=m(n+2);t[i]=255&e,t[i+1]=e>>8,t[i+2]=255^t[i],t[i+3]=255^t[i+1];for(var a=0;a<e;++a)t[i+a+4]=r[a];return 8*(i+4+e)},C=function(t,r,s,o,f,h,l,u,c,v,m){z(r,m++,s),++f[256];for(var b=_(f,15),M=b.t,E=b.l,x=_(h,15),C=x.t,U=x.l,F=D(M),I=F.c,S=F.n,L=D(C),O=L.c,j=L.n,q=new n(19),B=0;B<I.length;++B)++q[31&I[B]];for(B=0;B<O.length;++B)++q[31&O[B]];for(var G=_(q,7),H=G.t,J=G.l,K=19;K>4&&!H[a[K-1]];--K);var N,P,Q,R,V=v+5<<3,W=T(f,g)+T(h,w)+l,X=T(f,M)+T(h,C)+l+14+3*K+T(q,H)+2*q[16]+3*q[17]+7*q[18];if(c>=0&&V<=W&&V<=X)return k(r,m,t.subarray(c,c+v));if(z(r,m,1+(X<W)),m+=2,X<W){N=d(M,E,0),P=M,Q=d(C,U,0),R=C;var Y=d(H,J,0);z(r,m,S-257),z(r,m+5,j-1),z(r,m+10,K-4),m+=14;for(B=0;B<K;++B)z(r,m+3*B,H[a[B]]);m+=3*K;for(var Z=[I,O],$=0;$<2;++$){var tt=Z[$];for(B=0;B<tt.length;++B){var nt=31&tt[B];z(r,m,Y[nt]),m+=H[nt],nt>15&&(z(r,m,tt[B]>>5&127),m+=tt[B]>>12)}}}else N=p,P=g,Q=y,R=w;for(B=0;B<u;++B){var rt=o[B];if(rt>255){A(r,m,N[(nt=rt>>18&31)+257]),m+=P[nt+257],nt>7&&(z(r,m,rt>>23&31),m+=e[nt]);var et=31&rt;A(r,m,Q[et]),m+=R[et],et>3&&(A(r,m,rt>>5&8191),m+=i[et])}else A(r,m,N[rt]),m+=P[rt]}return A(r,m,N[256]),m+P[256]},U=new r([65540,131080,131088,131104,262176,1048704,1048832,2114560,2117632]),F=new t(0),I=function(){for(var t=new Int32Array(256),n=0;n<256;++n){for(var r=n,e=9;--e;)r=(1&r&&-306674912)^r>>>1;t[n]=r}return t}(),S=function(){var t=-1;return{p:function(n){for(var r=t,e=0;e<n.length;++e)r=I[255&r^n[e]]^r>>>8;t=r},d:function(){return~t}}},L=function(){var t=1,n=0;return{p:function(r){for(var e=t,i=n,a=0|r.length,s=0;s!=a;){for(var o=Math.min(s+2655,a);s<o;++s)i+=e+=r[s];e=(65535&e)+15*(e>>16),i=(65535&i)+15*(i>>16)}t=e,n=i},d:function(){return(255&(t%=65521))<<24|(65280&t)<<8|(255&(n%=65521))<<8|n>>8}}},O=function(a,s,o,f,u){if(!u&&(u={l:1},s.dictionary)){var c=s.dictionary.subarray(-32768),v=new t(c.length+a.length);v.set(c),v.set(a,c.length),a=v,u.w=c.length}return function(a,s,o,f,u,c){var v=c.z||a.length,d=new t(f+v+5*(1+Math.ceil(v/7e3))+u),g=d.subarray(f,d.length-u),w=c.l,p=7&(c.r||0);if(s){p&&(g[0]=c.r>>3);for(var y=U[s-1],M=y>>13,E=8191&y,z=(1<<o)-1,A=c.p||new n(32768),_=c.h||new n(z+1),x=Math.ceil(o/3),D=2*x,T=function(t){return(a[t]^a[t+1]<<x^a[t+2]<<D)&z},F=new r(25e3),I=new n(288),S=new n(32),L=0,O=0,j=c.i||0,q=0,B=c.w||0,G=0;j+2<v;++j){var H=T(j),J=32767&j,K=_[H];if(A[J]=K,_[H]=J,B<=j){var N=v-j;if((L>7e3||q>24576)&&(N>423||!w)){p=C(a,g,0,F,I,S,O,q,G,j-G,p),q=L=O=0,G=j;for(var P=0;P<286;++P)I[P]=0;for(P=0;P<30;++P)S[P]=0}var Q=2,R=0,V=E,W=J-K&32767;if(N>2&&H==T(j-W))for(var X=Math.min(M,N)-1,Y=Math.min(32767,j),Z=Math.min(258,N);W<=Y&&--V&&J!=K;){if(a[j+Q]==a[j+Q-W]){for(var $=0;$<Z&&a[j+$]==a[j+$-W];++$);if($>Q){if(Q=$,R=W,$>X)break;var tt=Math.min(W,$-2),nt=0;for(P=0;P<tt;++P){var rt=j-W+P&32767,et=rt-A[rt]&32767;et>nt&&(nt=et,K=rt)}}}W+=(J=K)-(K=A[J])&32767}if(R){F[q++]=268435456|h[Q]<<18|l[R];var it=31&h[Q],at=31&l[R];O+=e[it]+i[at],++I[257+it],++S[at],B=j+Q,++L}else F[q++]=a[j],++I[a[j]]}}for(j=Math.max(j,B);j<v;++j)F[q++]=a[j],++I[a[j]];p=C(a,g,w,F,I,S,O,q,G,j-G,p),w||(c.r=7&p|g[p/8|0]<<3,p-=7,c.h=_,c.p=A,c.i=j,c.w=B)}else{for(j=c.w||0;j<v+w;j+=65535){var st=j+65535;st>=v&&(g[p/8|0]=w,st=v),p=k(g,p+1,a.subarray(j,st))}c.i=v}return b(d,0,f+m(p)+u)}(a,null==s.level?6:s.level,null==s.mem?Math.ceil(1.5*Math.max(8,Math.min(13,Math.log(a.length)))):12+s.mem,o,f,u)},j=function(t,n,r){for(;r;++n)t[n]=r,r>>>=8},q=function(t,n){var r=n.filename;if(t[0]=31,t[1]=139,t[2]=8,t[8]=n.level<2?4:9==n.level?2:0,t[9]=3,0!=n.mtime&&j(t,4,Math.floor(new Date(n.mtime||Date.now())/1e3)),r){t[3]=8;for(var e=0;e<=r.length;++e)t[e+10]=r.charCodeAt(e)}},B=function(t){return 10+(t.filename?t.filename.length+1:0)},G=function(){function n(n,r){if("function"==typeof n&&(r=n,n={}),this.ondata=r,this.o=n||{},this.s={l:0,i:32768,w:32768,z:32768},this.b=new t(98304),this.o.dictionary){var e=this.o.dictionary.subarray(-32768);this.b.set(e,32768-e.length),this.s.i=32768-e.length}}return n.prototype.p=function(t,n){this.ondata(O(t,this.o,0,0,this.s),n)},n.prototype.push=function(n,r){this.ondata||E(5),this.s.l&&E(4);var e=n.length+this.s.z;if(e>this.b.length){if(e>2*this.b.length-32768){var i=new t(-32768&e);i.set(this.b.subarray(0,this.s.z)),this.b=i}var a=this.b.length-this.s.z;a&&(this.b.set(n.subarray(0,a),this.s.z),this.s.z=this.b.length,this.p(this.b,!1)),this.b.set(this.b.subarray(-32768)),this.b.set(n.subarray(a),32768),this.s.z=n.length-a+32768,this.s.i=32766,this.s.w=32768}else this.b.set(n,this.s.z),this.s.z+=n.length;this.s.l=1&r,(this.s.z>this.s.w+8191||r)&&(this.p(this.b,r||!1),this.s.w=this.s.i,this.s.i-=2)},n}();var H=function(){function t(t,n){this.c=L(),this.v=1,G.call(this,t,n)}return t.prototype.push=function(t,n){this.c.p(t),G.prototype.push.call(this,t,n)},t.prototype.p=function(t,n){var r=O(t,this.o,this.v&&(this.o.dictionary?6:2),n&&4,this.s);this.v&&(function(t,n){var r=n.level,e=0==r?0:r<6?1:9==r?3:2;if(t[0]=120,t[1]=e<<6|(n.dictionary&&32),t[1]|=31-(t[0]<<8|t[1])%31,n.dictionary){var i=L();i.p(n.dictionary),j(t,2,i.d())}}(r,this.o),this.v=0),n&&j(r,r.length-4,this.c.d()),this.ondata(r,n)},t}(),J="undefined"!=typeof TextEncoder&&new TextEncoder,K="undefined"!=typeof TextDecoder&&new TextDecoder;try{K.decode(F,{stream:!0})}catch(t){}var N=function(){function t(t){this.ondata=t}return t.prototype.push=function(t,n){this.ondata||E(5),this.d&&E(4),this.ondata(P(t),this.d=n||!1)},t}();function P(n,r){if(r){for(var e=new t(n.length),i=0;i<n.length;++i)e[i]=n.charCodeAt(i);return e}if(J)return J.encode(n);var a=n.length,s=new t(n.length+(n.length>>1)),o=0,f=function(t){s[o++]=t};for(i=0;i<a;++i){if(o+5>s.length){var h=new t(o+8+(a-i<<1));h.set(s),s=h}var l=n.charCodeAt(i);l<128||r?f(l):l<2048?(f(192|l>>6),f(128|63&l)):l>55295&&l<57344?(f(240|(l=65536+(1047552&l)|1023&n.charCodeAt(++i))>>18),f(128|l>>12&63),f(128|l>>6&63),f(128|63&l)):(f(224|l>>12),f(128|l>>6&63),f(128|63&l))}return b(s,0,o)}function Q(t){return function(t,n){n||(n={});var r=S(),e=t.length;r.p(t);var i=O(t,n,B(n),8),a=i.length;return q(i,n),j(i,a-8,r.d()),j(i,a-4,e),i}(P(t))}const R=new class{constructor(){this._init()}clear(){this._init()}addEvent(t){if(!t)throw new Error("Adding invalid event");const n=this._hasEvents?",":"";this.stream.push(n+t),this._hasEvents=!0}finish(){this.stream.push("]",!0);const t=function(t){let n=0;for(const r of t)n+=r.length;const r=new Uint8Array(n);for(let n=0,e=0,i=t.length;n<i;n++){const i=t[n];r.set(i,e),e+=i.length}return r}(this._deflatedData);return this._init(),t}_init(){this._hasEvents=!1,this._deflatedData=[],this.deflate=new H,this.deflate.ondata=(t,n)=>{this._deflatedData.push(t)},this.stream=new N(((t,n)=>{this.deflate.push(t,n)})),this.stream.push("[")}},V={clear:()=>{R.clear()},addEvent:t=>R.addEvent(t),finish:()=>R.finish(),compress:t=>Q(t)};addEventListener("message",(function(t){const n=t.data.method,r=t.data.id,e=t.data.arg;if(n in V&&"function"==typeof V[n])try{const t=V[n](e);postMessage({id:r,method:n,success:!0,response:t})}catch(t){postMessage({id:r,method:n,success:!1,response:t.message}),console.error(t)}})),postMessage({id:void 0,method:"init",success:!0,response:void 0});';class Gl extends Error{constructor(){super("Event buffer exceeded maximum size of 20000000.")}}class Xl{constructor(){this.events=[],this._totalSize=0,this.hasCheckout=!1}get hasEvents(){return this.events.length>0}get type(){return"sync"}destroy(){this.events=[]}async addEvent(e){const t=JSON.stringify(e).length;if(this._totalSize+=t,this._totalSize>nc)throw new Gl;this.events.push(e)}finish(){return new Promise((e=>{const t=this.events;this.clear(),e(JSON.stringify(t))}))}clear(){this.events=[],this._totalSize=0,this.hasCheckout=!1}getEarliestTimestamp(){const e=this.events.map((e=>e.timestamp)).sort()[0];return e?gl(e):null}}class Jl{constructor(e){this._worker=e,this._id=0}ensureReady(){return this._ensureReadyPromise||(this._ensureReadyPromise=new Promise(((e,t)=>{this._worker.addEventListener("message",(({data:n})=>{n.success?e():t()}),{once:!0}),this._worker.addEventListener("error",(e=>{t(e)}),{once:!0})}))),this._ensureReadyPromise}destroy(){ll&&fl.info("Destroying compression worker"),this._worker.terminate()}postMessage(e,t){const n=this._getAndIncrementId();return new Promise(((r,o)=>{const s=({data:t})=>{const i=t;if(i.method===e&&i.id===n){if(this._worker.removeEventListener("message",s),!i.success)return ll&&fl.error("Error in compression worker: ",i.response),void o(new Error("Error in compression worker"));r(i.response)}};this._worker.addEventListener("message",s),this._worker.postMessage({id:n,method:e,arg:t})}))}_getAndIncrementId(){return this._id++}}class Kl{constructor(e){this._worker=new Jl(e),this._earliestTimestamp=null,this._totalSize=0,this.hasCheckout=!1}get hasEvents(){return!!this._earliestTimestamp}get type(){return"worker"}ensureReady(){return this._worker.ensureReady()}destroy(){this._worker.destroy()}addEvent(e){const t=gl(e.timestamp);(!this._earliestTimestamp||t<this._earliestTimestamp)&&(this._earliestTimestamp=t);const n=JSON.stringify(e);return this._totalSize+=n.length,this._totalSize>nc?Promise.reject(new Gl):this._sendEventToWorker(n)}finish(){return this._finishRequest()}clear(){this._earliestTimestamp=null,this._totalSize=0,this.hasCheckout=!1,this._worker.postMessage("clear").then(null,(e=>{ll&&fl.exception(e,'Sending "clear" message to worker failed',e)}))}getEarliestTimestamp(){return this._earliestTimestamp}_sendEventToWorker(e){return this._worker.postMessage("addEvent",e)}async _finishRequest(){const e=await this._worker.postMessage("finish");return this._earliestTimestamp=null,this._totalSize=0,e}}class Ql{constructor(e){this._fallback=new Xl,this._compression=new Kl(e),this._used=this._fallback,this._ensureWorkerIsLoadedPromise=this._ensureWorkerIsLoaded()}get type(){return this._used.type}get hasEvents(){return this._used.hasEvents}get hasCheckout(){return this._used.hasCheckout}set hasCheckout(e){this._used.hasCheckout=e}destroy(){this._fallback.destroy(),this._compression.destroy()}clear(){return this._used.clear()}getEarliestTimestamp(){return this._used.getEarliestTimestamp()}addEvent(e){return this._used.addEvent(e)}async finish(){return await this.ensureWorkerIsLoaded(),this._used.finish()}ensureWorkerIsLoaded(){return this._ensureWorkerIsLoadedPromise}async _ensureWorkerIsLoaded(){try{await this._compression.ensureReady()}catch(e){return void(ll&&fl.exception(e,"Failed to load the compression worker, falling back to simple buffer"))}await this._switchToCompressionWorker()}async _switchToCompressionWorker(){const{events:e,hasCheckout:t}=this._fallback,n=[];for(const t of e)n.push(this._compression.addEvent(t));this._compression.hasCheckout=t,this._used=this._compression;try{await Promise.all(n)}catch(e){ll&&fl.exception(e,"Failed to add events when switching buffers.")}}}function Zl({useCompression:e,workerUrl:t}){if(e&&window.Worker){const e=function(e){try{const t=e||function(){if("undefined"==typeof __SENTRY_EXCLUDE_REPLAY_WORKER__||!__SENTRY_EXCLUDE_REPLAY_WORKER__)return function(){const e=new Blob([Vl]);return URL.createObjectURL(e)}();return""}();if(!t)return;ll&&fl.info("Using compression worker"+(e?` from ${e}`:""));const n=new Worker(t);return new Ql(n)}catch(e){ll&&fl.exception(e,"Failed to create compression worker")}}(t);if(e)return e}return ll&&fl.info("Using simple buffer"),new Xl}function ed(){try{return"sessionStorage"in Ja&&!!Ja.sessionStorage}catch(e){return!1}}function td(e){!function(){if(!ed())return;try{Ja.sessionStorage.removeItem(Ka)}catch(e){}}(),e.session=void 0}function nd(e){return void 0!==e&&Math.random()<e}function rd(e){const t=Date.now();return{id:e.id||De(),started:e.started||t,lastActivity:e.lastActivity||t,segmentId:e.segmentId||0,sampled:e.sampled,previousSessionId:e.previousSessionId}}function od(e){if(ed())try{Ja.sessionStorage.setItem(Ka,JSON.stringify(e))}catch(e){}}function sd({sessionSampleRate:e,allowBuffering:t,stickySession:n=!1},{previousSessionId:r}={}){const o=function(e,t){return nd(e)?"session":!!t&&"buffer"}(e,t),s=rd({sampled:o,previousSessionId:r});return n&&od(s),s}function id(e,t,n=+new Date){return null===e||void 0===t||t<0||0!==t&&e+t<=n}function ad(e,{maxReplayDuration:t,sessionIdleExpire:n,targetTime:r=Date.now()}){return id(e.started,t,r)||id(e.lastActivity,n,r)}function cd(e,{sessionIdleExpire:t,maxReplayDuration:n}){return!!ad(e,{sessionIdleExpire:t,maxReplayDuration:n})&&("buffer"!==e.sampled||0!==e.segmentId)}function ud({sessionIdleExpire:e,maxReplayDuration:t,previousSessionId:n},r){const o=r.stickySession&&function(){if(!ed())return null;try{const e=Ja.sessionStorage.getItem(Ka);if(!e)return null;const t=JSON.parse(e);return ll&&fl.infoTick("Loading existing session"),rd(t)}catch(e){return null}}();return o?cd(o,{sessionIdleExpire:e,maxReplayDuration:t})?(ll&&fl.infoTick("Session in sessionStorage is expired, creating new one..."),sd(r,{previousSessionId:o.id})):o:(ll&&fl.infoTick("Creating new session"),sd(r,{previousSessionId:n}))}function ld(e,t,n){return!!pd(e,t)&&(dd(e,t,n),!0)}async function dd(e,t,n){if(!e.eventBuffer)return null;try{n&&"buffer"===e.recordingMode&&e.eventBuffer.clear(),n&&(e.eventBuffer.hasCheckout=!0);const r=function(e,t){try{if("function"==typeof t&&function(e){return e.type===bu.Custom}(e))return t(e)}catch(e){return ll&&fl.exception(e,"An error occured in the `beforeAddRecordingEvent` callback, skipping the event..."),null}return e}(t,e.getOptions().beforeAddRecordingEvent);if(!r)return;return await e.eventBuffer.addEvent(r)}catch(t){const n=t&&t instanceof Gl?"addEventSizeExceeded":"addEvent";e.handleException(t),await e.stop({reason:n});const r=Qt();r&&r.recordDroppedEvent("internal_sdk_error","replay")}}function pd(e,t){if(!e.eventBuffer||e.isPaused()||!e.isEnabled())return!1;const n=gl(t.timestamp);return!(n+e.timeouts.sessionIdlePause<Date.now())&&(!(n>e.getContext().initialTimestamp+e.getOptions().maxReplayDuration)||(ll&&fl.infoTick(`Skipping event with timestamp ${n} because it is after maxReplayDuration`),!1))}function hd(e){return!e.type}function fd(e){return"transaction"===e.type}function md(e){return"feedback"===e.type}function gd(e){return(t,n)=>{if(!e.isEnabled()||!hd(t)&&!fd(t))return;const r=n&&n.statusCode;!r||r<200||r>=300||(fd(t)?function(e,t){const n=e.getContext();t.contexts&&t.contexts.trace&&t.contexts.trace.trace_id&&n.traceIds.size<100&&n.traceIds.add(t.contexts.trace.trace_id)}(e,t):function(e,t){const n=e.getContext();t.event_id&&n.errorIds.size<100&&n.errorIds.add(t.event_id);if("buffer"!==e.recordingMode||!t.tags||!t.tags.replayId)return;const{beforeErrorSampling:r}=e.getOptions();if("function"==typeof r&&!r(t))return;Gi((()=>{e.sendBufferedReplayOrFlush()}))}(e,t))}}function _d(e){return t=>{e.isEnabled()&&hd(t)&&function(e,t){const n=t.exception&&t.exception.values&&t.exception.values[0]&&t.exception.values[0].value;if("string"!=typeof n)return;if(n.match(/(reactjs\.org\/docs\/error-decoder\.html\?invariant=|react\.dev\/errors\/)(418|419|422|423|425)/)||n.match(/(does not match server-rendered HTML|Hydration failed because)/i)){yl(e,Rl({category:"replay.hydrate-error",data:{url:M()}}))}}(e,t)}}function yd(e){const t=Qt();t&&t.on("beforeAddBreadcrumb",(t=>function(e,t){if(!e.isEnabled()||!vd(t))return;const n=function(e){if(!vd(e)||["fetch","xhr","sentry.event","sentry.transaction"].includes(e.category)||e.category.startsWith("ui."))return null;if("console"===e.category)return function(e){const t=e.data&&e.data.arguments;if(!Array.isArray(t)||0===t.length)return Rl(e);let n=!1;const r=t.map((e=>{if(!e)return e;if("string"==typeof e)return e.length>tc?(n=!0,`${e.slice(0,tc)}…`):e;if("object"==typeof e)try{const t=je(e,7);return JSON.stringify(t).length>tc?(n=!0,`${JSON.stringify(t,null,2).slice(0,tc)}…`):t}catch(e){}return e}));return Rl({...e,data:{...e.data,arguments:r,...n?{_meta:{warnings:["CONSOLE_ARG_TRUNCATED"]}}:{}}})}(e);return Rl(e)}(t);n&&yl(e,n)}(e,t)))}function vd(e){return!!e.category}function bd(e){return Object.assign(((t,n)=>{if(!e.isEnabled())return t;if(function(e){return"replay_event"===e.type}(t))return delete t.breadcrumbs,t;if(!hd(t)&&!fd(t)&&!md(t))return t;if(!e.checkAndHandleExpiredSession())return t;if(md(t))return e.flush(),t.contexts.feedback.replay_id=e.getSessionId(),function(e,t){e.triggerUserActivity(),e.addUpdate((()=>!t.timestamp||(e.throttledAddEvent({type:bu.Custom,timestamp:1e3*t.timestamp,data:{tag:"breadcrumb",payload:{timestamp:t.timestamp,type:"default",category:"sentry.feedback",data:{feedbackId:t.event_id}}}}),!1)))}(e,t),t;if(function(e,t){return!(e.type||!e.exception||!e.exception.values||!e.exception.values.length||!t.originalException||!t.originalException.__rrweb__)}(t,n)&&!e.getOptions()._experiments.captureExceptions)return ll&&fl.log("Ignoring error from rrweb internals",t),null;const r=function(e,t){return"buffer"===e.recordingMode&&t.message!==Za&&!(!t.exception||t.type)&&nd(e.getOptions().errorSampleRate)}(e,t);return(r||"session"===e.recordingMode)&&(t.tags={...t.tags,replayId:e.getSessionId()}),t}),{id:"Replay"})}function Sd(e,t){return t.map((({type:t,start:n,end:r,name:o,data:s})=>{const i=e.throttledAddEvent({type:bu.Custom,timestamp:n,data:{tag:"performanceSpan",payload:{op:t,description:o,startTimestamp:n,endTimestamp:r,data:s}}});return"string"==typeof i?Promise.resolve(null):i}))}function wd(e){return t=>{if(!e.isEnabled())return;const n=function(e){const{from:t,to:n}=e,r=Date.now()/1e3;return{type:"navigation.push",start:r,end:r,name:n,data:{previous:t}}}(t);null!==n&&(e.getContext().urls.push(n.name),e.triggerUserActivity(),e.addUpdate((()=>(Sd(e,[n]),!1))))}}function kd(e,t){e.isEnabled()&&null!==t&&(function(e,t){return(!ll||!e.getOptions()._experiments.traceInternals)&&ro(t,Qt())}(e,t.name)||e.addUpdate((()=>(Sd(e,[t]),!0))))}function xd(e){if(!e)return;const t=new TextEncoder;try{if("string"==typeof e)return t.encode(e).length;if(e instanceof URLSearchParams)return t.encode(e.toString()).length;if(e instanceof FormData){const n=Nd(e);return t.encode(n).length}if(e instanceof Blob)return e.size;if(e instanceof ArrayBuffer)return e.byteLength}catch(e){}}function Ed(e){if(!e)return;const t=parseInt(e,10);return isNaN(t)?void 0:t}function Cd(e){try{if("string"==typeof e)return[e];if(e instanceof URLSearchParams)return[e.toString()];if(e instanceof FormData)return[Nd(e)];if(!e)return[void 0]}catch(t){return ll&&fl.exception(t,"Failed to serialize body",e),[void 0,"BODY_PARSE_ERROR"]}return ll&&fl.info("Skipping network body because of body type",e),[void 0,"UNPARSEABLE_BODY_TYPE"]}function Td(e,t){if(!e)return{headers:{},size:void 0,_meta:{warnings:[t]}};const n={...e._meta},r=n.warnings||[];return n.warnings=[...r,t],e._meta=n,e}function Id(e,t){if(!t)return null;const{startTimestamp:n,endTimestamp:r,url:o,method:s,statusCode:i,request:a,response:c}=t;return{type:e,start:n/1e3,end:r/1e3,name:o,data:X({method:s,statusCode:i,request:a,response:c})}}function Md(e){return{headers:{},size:e,_meta:{warnings:["URL_SKIPPED"]}}}function Rd(e,t,n){if(!t&&0===Object.keys(e).length)return;if(!t)return{headers:e};if(!n)return{headers:e,size:t};const r={headers:e,size:t},{body:o,warnings:s}=function(e){if(!e||"string"!=typeof e)return{body:e};const t=e.length>ec,n=function(e){const t=e[0],n=e[e.length-1];return"["===t&&"]"===n||"{"===t&&"}"===n}(e);if(t){const t=e.slice(0,ec);return n?{body:t,warnings:["MAYBE_JSON_TRUNCATED"]}:{body:`${t}…`,warnings:["TEXT_TRUNCATED"]}}if(n)try{return{body:JSON.parse(e)}}catch(e){}return{body:e}}(n);return r.body=o,s&&s.length>0&&(r._meta={warnings:s}),r}function Ad(e,t){return Object.entries(e).reduce(((n,[r,o])=>{const s=r.toLowerCase();return t.includes(s)&&e[r]&&(n[s]=o),n}),{})}function Nd(e){return new URLSearchParams(e).toString()}function Od(e,t){const n=function(e,t=Ja.document.baseURI){if(e.startsWith("http://")||e.startsWith("https://")||e.startsWith(Ja.location.origin))return e;const n=new URL(e,t);if(n.origin!==new URL(t).origin)return e;const r=n.href;if(!e.endsWith("/")&&r.endsWith("/"))return r.slice(0,-1);return r}(e);return _(n,t)}async function Dd(e,t,n){try{const r=await async function(e,t,n){const r=Date.now(),{startTimestamp:o=r,endTimestamp:s=r}=t,{url:i,method:a,status_code:c=0,request_body_size:u,response_body_size:l}=e.data,d=Od(i,n.networkDetailAllowUrls)&&!Od(i,n.networkDetailDenyUrls),p=d?function({networkCaptureBodies:e,networkRequestHeaders:t},n,r){const o=n?function(e,t){if(1===e.length&&"string"!=typeof e[0])return Fd(e[0],t);if(2===e.length)return Fd(e[1],t);return{}}(n,t):{};if(!e)return Rd(o,r,void 0);const s=Ld(n),[i,a]=Cd(s),c=Rd(o,r,i);if(a)return Td(c,a);return c}(n,t.input,u):Md(u),h=await async function(e,{networkCaptureBodies:t,networkResponseHeaders:n},r,o){if(!e&&void 0!==o)return Md(o);const s=r?Pd(r.headers,n):{};if(!r||!t&&void 0!==o)return Rd(s,o,void 0);const[i,a]=await async function(e){const t=function(e){try{return e.clone()}catch(e){ll&&fl.exception(e,"Failed to clone response body")}}(e);if(!t)return[void 0,"BODY_PARSE_ERROR"];try{const e=await function(e){return new Promise(((t,n)=>{const r=Gi((()=>n(new Error("Timeout while trying to read response body"))),500);(async function(e){return await e.text()})(e).then((e=>t(e)),(e=>n(e))).finally((()=>clearTimeout(r)))}))}(t);return[e]}catch(e){return ll&&fl.exception(e,"Failed to get text body from response"),[void 0,"BODY_PARSE_ERROR"]}}(r),c=function(e,{networkCaptureBodies:t,responseBodySize:n,captureDetails:r,headers:o}){try{const s=e&&e.length&&void 0===n?xd(e):n;return r?Rd(o,s,t?e:void 0):Md(s)}catch(e){return ll&&fl.exception(e,"Failed to serialize response body"),Rd(o,n,void 0)}}(i,{networkCaptureBodies:t,responseBodySize:o,captureDetails:e,headers:s});if(a)return Td(c,a);return c}(d,n,t.response,l);return{startTimestamp:o,endTimestamp:s,url:i,method:a,statusCode:c,request:p,response:h}}(e,t,n),o=Id("resource.fetch",r);kd(n.replay,o)}catch(e){ll&&fl.exception(e,"Failed to capture fetch breadcrumb")}}function Ld(e=[]){if(2===e.length&&"object"==typeof e[1])return e[1].body}function Pd(e,t){const n={};return t.forEach((t=>{e.get(t)&&(n[t]=e.get(t))})),n}function Fd(e,t){if(!e)return{};const n=e.headers;return n?n instanceof Headers?Pd(n,t):Array.isArray(n)?{}:Ad(n,t):{}}async function $d(e,t,n){try{const r=function(e,t,n){const r=Date.now(),{startTimestamp:o=r,endTimestamp:s=r,input:i,xhr:a}=t,{url:c,method:u,status_code:l=0,request_body_size:d,response_body_size:p}=e.data;if(!c)return null;if(!a||!Od(c,n.networkDetailAllowUrls)||Od(c,n.networkDetailDenyUrls)){return{startTimestamp:o,endTimestamp:s,url:c,method:u,statusCode:l,request:Md(d),response:Md(p)}}const h=a[Xi],f=h?Ad(h.request_headers,n.networkRequestHeaders):{},m=Ad(function(e){const t=e.getAllResponseHeaders();if(!t)return{};return t.split("\r\n").reduce(((e,t)=>{const[n,r]=t.split(": ");return r&&(e[n.toLowerCase()]=r),e}),{})}(a),n.networkResponseHeaders),[g,_]=n.networkCaptureBodies?Cd(i):[void 0],[y,v]=n.networkCaptureBodies?function(e){const t=[];try{return[e.responseText]}catch(e){t.push(e)}try{return function(e,t){try{if("string"==typeof e)return[e];if(e instanceof Document)return[e.body.outerHTML];if("json"===t&&e&&"object"==typeof e)return[JSON.stringify(e)];if(!e)return[void 0]}catch(t){return ll&&fl.exception(t,"Failed to serialize body",e),[void 0,"BODY_PARSE_ERROR"]}return ll&&fl.info("Skipping network body because of body type",e),[void 0,"UNPARSEABLE_BODY_TYPE"]}(e.response,e.responseType)}catch(e){t.push(e)}return ll&&fl.warn("Failed to get xhr response body",...t),[void 0]}(a):[void 0],b=Rd(f,d,g),S=Rd(m,p,y);return{startTimestamp:o,endTimestamp:s,url:c,method:u,statusCode:l,request:_?Td(b,_):b,response:v?Td(S,v):S}}(e,t,n),o=Id("resource.xhr",r);kd(n.replay,o)}catch(e){ll&&fl.exception(e,"Failed to capture xhr breadcrumb")}}function Bd(e,t){const{xhr:n,input:r}=t;if(!n)return;const o=xd(r),s=n.getResponseHeader("content-length")?Ed(n.getResponseHeader("content-length")):function(e,t){try{return xd("json"===t&&e&&"object"==typeof e?JSON.stringify(e):e)}catch(e){return}}(n.response,n.responseType);void 0!==o&&(e.data.request_body_size=o),void 0!==s&&(e.data.response_body_size=s)}function Ud(e){const t=Qt();try{const{networkDetailAllowUrls:n,networkDetailDenyUrls:r,networkCaptureBodies:o,networkRequestHeaders:s,networkResponseHeaders:i}=e.getOptions(),a={replay:e,networkDetailAllowUrls:n,networkDetailDenyUrls:r,networkCaptureBodies:o,networkRequestHeaders:s,networkResponseHeaders:i};t&&t.on("beforeAddBreadcrumb",((e,t)=>function(e,t,n){if(!t.data)return;try{(function(e){return"xhr"===e.category})(t)&&function(e){return e&&e.xhr}(n)&&(Bd(t,n),$d(t,n,e)),function(e){return"fetch"===e.category}(t)&&function(e){return e&&e.response}(n)&&(!function(e,t){const{input:n,response:r}=t,o=xd(n?Ld(n):void 0),s=r?Ed(r.headers.get("content-length")):void 0;void 0!==o&&(e.data.request_body_size=o),void 0!==s&&(e.data.response_body_size=s)}(t,n),Dd(t,n,e))}catch(e){ll&&fl.exception(e,"Error when enriching network breadcrumb")}}(a,e,t)))}catch(e){}}function jd(e){const{jsHeapSizeLimit:t,totalJSHeapSize:n,usedJSHeapSize:r}=e,o=Date.now()/1e3;return{type:"memory",name:"memory",start:o,end:o,data:{memory:{jsHeapSizeLimit:t,totalJSHeapSize:n,usedJSHeapSize:r}}}}function Hd(e){let t=!1;return(n,r)=>{if(!e.checkAndHandleExpiredSession())return void(ll&&fl.warn("Received replay event after session expired."));const o=r||!t;t=!0,e.clickDetector&&Ml(e.clickDetector,n),e.addUpdate((()=>{if("buffer"===e.recordingMode&&o&&e.setInitialState(),!ld(e,n,o))return!0;if(!o)return!1;if(function(e,t){if(!t||!e.session||0!==e.session.segmentId)return;ld(e,function(e){const t=e.getOptions();return{type:bu.Custom,timestamp:Date.now(),data:{tag:"options",payload:{shouldRecordCanvas:e.isRecordingCanvas(),sessionSampleRate:t.sessionSampleRate,errorSampleRate:t.errorSampleRate,useCompressionOption:t.useCompression,blockAllMedia:t.blockAllMedia,maskAllText:t.maskAllText,maskAllInputs:t.maskAllInputs,useCompression:!!e.eventBuffer&&"worker"===e.eventBuffer.type,networkDetailHasUrls:t.networkDetailAllowUrls.length>0,networkCaptureBodies:t.networkCaptureBodies,networkRequestHasHeaders:t.networkRequestHeaders.length>0,networkResponseHasHeaders:t.networkResponseHeaders.length>0}}}}(e),!1)}(e,o),e.session&&e.session.previousSessionId)return!0;if("buffer"===e.recordingMode&&e.session&&e.eventBuffer){const t=e.eventBuffer.getEarliestTimestamp();t&&(ll&&fl.info(`Updating session start time to earliest event in buffer to ${new Date(t)}`),e.session.started=t,e.getOptions().stickySession&&od(e.session))}return"session"===e.recordingMode&&e.flush(),!0}))}}async function zd({recordingData:e,replayId:t,segmentId:n,eventContext:r,timestamp:o,session:s}){const i=function({recordingData:e,headers:t}){let n;const r=`${JSON.stringify(t)}\n`;if("string"==typeof e)n=`${r}${e}`;else{const t=(new TextEncoder).encode(r);n=new Uint8Array(t.length+e.length),n.set(t),n.set(e,t.length)}return n}({recordingData:e,headers:{segment_id:n}}),{urls:a,errorIds:c,traceIds:u,initialTimestamp:l}=r,d=Qt(),p=Gt(),h=d&&d.getTransport(),f=d&&d.getDsn();if(!(d&&h&&f&&s.sampled))return Xe({});const m={type:Qa,replay_start_timestamp:l/1e3,timestamp:o/1e3,error_ids:c,trace_ids:u,urls:a,replay_id:t,segment_id:n,replay_type:s.sampled},g=await async function({client:e,scope:t,replayId:n,event:r}){const o={event_id:n,integrations:"object"!=typeof e._integrations||null===e._integrations||Array.isArray(e._integrations)?void 0:Object.keys(e._integrations)};e.emit("preprocessEvent",r,o);const s=await _r(e.getOptions(),r,o,t,e,Xt());if(!s)return null;s.platform=s.platform||"javascript";const i=e.getSdkMetadata(),{name:a,version:c}=i&&i.sdk||{};return s.sdk={...s.sdk,name:a||"sentry.javascript.unknown",version:c||"0.0.0"},s}({scope:p,client:d,replayId:t,event:m});if(!g)return d.recordDroppedEvent("event_processor","replay",m),ll&&fl.info("An event processor returned `null`, will not send event."),Xe({});delete g.sdkProcessingMetadata;const _=function(e,t,n,r){return pt(xt(e,kt(e),r,n),[[{type:"replay_event"},e],[{type:"replay_recording",length:"string"==typeof t?(new TextEncoder).encode(t).length:t.length},t]])}(g,i,f,d.getOptions().tunnel);let y;try{y=await h.send(_)}catch(e){const t=new Error(Za);try{t.cause=e}catch(e){}throw t}if("number"==typeof y.statusCode&&(y.statusCode<200||y.statusCode>=300))throw new qd(y.statusCode);const v=It({},y);if(Tt(v,"replay"))throw new Wd(v);return y}class qd extends Error{constructor(e){super(`Transport returned status code ${e}`)}}class Wd extends Error{constructor(e){super("Rate limit hit"),this.rateLimits=e}}async function Yd(e,t={count:0,interval:5e3}){const{recordingData:n,options:r}=e;if(n.length)try{return await zd(e),!0}catch(n){if(n instanceof qd||n instanceof Wd)throw n;if(xr("Replays",{_retryCount:t.count}),ll&&r._experiments&&r._experiments.captureExceptions&&Sr(n),t.count>=3){const e=new Error(`${Za} - max retries exceeded`);try{e.cause=n}catch(e){}throw e}return t.interval*=++t.count,new Promise(((n,r)=>{Gi((async()=>{try{await Yd(e,t),n(!0)}catch(e){r(e)}}),t.interval)}))}}const Vd="__THROTTLED";function Gd(e,t,n){const r=new Map;let o=!1;return(...s)=>{const i=Math.floor(Date.now()/1e3);if((e=>{const t=e-n;r.forEach(((e,n)=>{n<t&&r.delete(n)}))})(i),[...r.values()].reduce(((e,t)=>e+t),0)>=t){const e=o;return o=!0,e?"__SKIPPED":Vd}o=!1;const a=r.get(i)||0;return r.set(i,a+1),e(...s)}}class Xd{constructor({options:e,recordingOptions:t}){Xd.prototype.__init.call(this),Xd.prototype.__init2.call(this),Xd.prototype.__init3.call(this),Xd.prototype.__init4.call(this),Xd.prototype.__init5.call(this),Xd.prototype.__init6.call(this),this.eventBuffer=null,this.performanceEntries=[],this.replayPerformanceEntries=[],this.recordingMode="session",this.timeouts={sessionIdlePause:3e5,sessionIdleExpire:9e5},this._lastActivity=Date.now(),this._isEnabled=!1,this._isPaused=!1,this._requiresManualStart=!1,this._hasInitializedCoreListeners=!1,this._context={errorIds:new Set,traceIds:new Set,urls:[],initialTimestamp:Date.now(),initialUrl:""},this._recordingOptions=t,this._options=e,this._debouncedFlush=function(e,t,n){let r,o,s;const i=n&&n.maxWait?Math.max(n.maxWait,t):0;function a(){return c(),r=e(),r}function c(){void 0!==o&&clearTimeout(o),void 0!==s&&clearTimeout(s),o=s=void 0}function u(){return o&&clearTimeout(o),o=Gi(a,t),i&&void 0===s&&(s=Gi(a,i)),r}return u.cancel=c,u.flush=function(){return void 0!==o||void 0!==s?a():r},u}((()=>this._flush()),this._options.flushMinDelay,{maxWait:this._options.flushMaxDelay}),this._throttledAddEvent=Gd(((e,t)=>function(e,t,n){return pd(e,t)?dd(e,t,n):Promise.resolve(null)}(this,e,t)),300,5);const{slowClickTimeout:n,slowClickIgnoreSelectors:r}=this.getOptions(),o=n?{threshold:Math.min(3e3,n),timeout:n,scrollTimeout:300,ignoreSelector:r?r.join(","):""}:void 0;if(o&&(this.clickDetector=new Cl(this,o)),ll){const t=e._experiments;fl.setConfig({captureExceptions:!!t.captureExceptions,traceInternals:!!t.traceInternals})}}getContext(){return this._context}isEnabled(){return this._isEnabled}isPaused(){return this._isPaused}isRecordingCanvas(){return Boolean(this._canvas)}getOptions(){return this._options}handleException(e){ll&&fl.exception(e)}initializeSampling(e){const{errorSampleRate:t,sessionSampleRate:n}=this._options,r=t<=0&&n<=0;this._requiresManualStart=r,r||(this._initializeSessionForSampling(e),this.session?!1!==this.session.sampled&&(this.recordingMode="buffer"===this.session.sampled&&0===this.session.segmentId?"buffer":"session",ll&&fl.infoTick(`Starting replay in ${this.recordingMode} mode`),this._initializeRecording()):ll&&fl.exception(new Error("Unable to initialize and create session")))}start(){if(this._isEnabled&&"session"===this.recordingMode)return void(ll&&fl.info("Recording is already in progress"));if(this._isEnabled&&"buffer"===this.recordingMode)return void(ll&&fl.info("Buffering is in progress, call `flush()` to save the replay"));ll&&fl.infoTick("Starting replay in session mode"),this._updateUserActivity();const e=ud({maxReplayDuration:this._options.maxReplayDuration,sessionIdleExpire:this.timeouts.sessionIdleExpire},{stickySession:this._options.stickySession,sessionSampleRate:1,allowBuffering:!1});this.session=e,this._initializeRecording()}startBuffering(){if(this._isEnabled)return void(ll&&fl.info("Buffering is in progress, call `flush()` to save the replay"));ll&&fl.infoTick("Starting replay in buffer mode");const e=ud({sessionIdleExpire:this.timeouts.sessionIdleExpire,maxReplayDuration:this._options.maxReplayDuration},{stickySession:this._options.stickySession,sessionSampleRate:0,allowBuffering:!0});this.session=e,this.recordingMode="buffer",this._initializeRecording()}startRecording(){try{const e=this._canvas;this._stopRecording=ul({...this._recordingOptions,..."buffer"===this.recordingMode&&{checkoutEveryNms:6e4},emit:Hd(this),onMutation:this._onMutationHandler,...e?{recordCanvas:e.recordCanvas,getCanvasManager:e.getCanvasManager,sampling:e.sampling,dataURLOptions:e.dataURLOptions}:{}})}catch(e){this.handleException(e)}}stopRecording(){try{return this._stopRecording&&(this._stopRecording(),this._stopRecording=void 0),!0}catch(e){return this.handleException(e),!1}}async stop({forceFlush:e=!1,reason:t}={}){if(this._isEnabled){this._isEnabled=!1;try{ll&&fl.info("Stopping Replay"+(t?` triggered by ${t}`:"")),this._removeListeners(),this.stopRecording(),this._debouncedFlush.cancel(),e&&await this._flush({force:!0}),this.eventBuffer&&this.eventBuffer.destroy(),this.eventBuffer=null,td(this)}catch(e){this.handleException(e)}}}pause(){this._isPaused||(this._isPaused=!0,this.stopRecording(),ll&&fl.info("Pausing replay"))}resume(){this._isPaused&&this._checkSession()&&(this._isPaused=!1,this.startRecording(),ll&&fl.info("Resuming replay"))}async sendBufferedReplayOrFlush({continueRecording:e=!0}={}){if("session"===this.recordingMode)return this.flushImmediate();const t=Date.now();ll&&fl.info("Converting buffer to session"),await this.flushImmediate();const n=this.stopRecording();e&&n&&"session"!==this.recordingMode&&(this.recordingMode="session",this.session&&(this._updateUserActivity(t),this._updateSessionActivity(t),this._maybeSaveSession()),this.startRecording())}addUpdate(e){const t=e();"buffer"!==this.recordingMode&&!0!==t&&this._debouncedFlush()}triggerUserActivity(){if(this._updateUserActivity(),this._stopRecording)this.checkAndHandleExpiredSession(),this._updateSessionActivity();else{if(!this._checkSession())return;this.resume()}}updateUserActivity(){this._updateUserActivity(),this._updateSessionActivity()}conditionalFlush(){return"buffer"===this.recordingMode?Promise.resolve():this.flushImmediate()}flush(){return this._debouncedFlush()}flushImmediate(){return this._debouncedFlush(),this._debouncedFlush.flush()}cancelFlush(){this._debouncedFlush.cancel()}getSessionId(){return this.session&&this.session.id}checkAndHandleExpiredSession(){if(!(this._lastActivity&&id(this._lastActivity,this.timeouts.sessionIdlePause)&&this.session&&"session"===this.session.sampled))return!!this._checkSession();this.pause()}setInitialState(){const e=`${Ja.location.pathname}${Ja.location.hash}${Ja.location.search}`,t=`${Ja.location.origin}${e}`;this.performanceEntries=[],this.replayPerformanceEntries=[],this._clearContext(),this._context.initialUrl=t,this._context.initialTimestamp=Date.now(),this._context.urls.push(t)}throttledAddEvent(e,t){const n=this._throttledAddEvent(e,t);if(n===Vd){const e=Rl({category:"replay.throttled"});this.addUpdate((()=>!ld(this,{type:5,timestamp:e.timestamp||0,data:{tag:"breadcrumb",payload:e,metric:!0}})))}return n}getCurrentRoute(){const e=this.lastActiveSpan||Mn(),t=e&&In(e),n=(t&&Sn(t).data||{})[tn];if(t&&n&&["route","custom"].includes(n))return Sn(t).description}_initializeRecording(){this.setInitialState(),this._updateSessionActivity(),this.eventBuffer=Zl({useCompression:this._options.useCompression,workerUrl:this._options.workerUrl}),this._removeListeners(),this._addListeners(),this._isEnabled=!0,this._isPaused=!1,this.startRecording()}_initializeSessionForSampling(e){const t=this._options.errorSampleRate>0,n=ud({sessionIdleExpire:this.timeouts.sessionIdleExpire,maxReplayDuration:this._options.maxReplayDuration,previousSessionId:e},{stickySession:this._options.stickySession,sessionSampleRate:this._options.sessionSampleRate,allowBuffering:t});this.session=n}_checkSession(){if(!this.session)return!1;const e=this.session;return!cd(e,{sessionIdleExpire:this.timeouts.sessionIdleExpire,maxReplayDuration:this._options.maxReplayDuration})||(this._refreshSession(e),!1)}async _refreshSession(e){this._isEnabled&&(await this.stop({reason:"refresh session"}),this.initializeSampling(e.id))}_addListeners(){try{Ja.document.addEventListener("visibilitychange",this._handleVisibilityChange),Ja.addEventListener("blur",this._handleWindowBlur),Ja.addEventListener("focus",this._handleWindowFocus),Ja.addEventListener("keydown",this._handleKeyboardEvent),this.clickDetector&&this.clickDetector.addListeners(),this._hasInitializedCoreListeners||(!function(e){const t=Qt();Ui(Dl(e)),zi(wd(e)),yd(e),Ud(e),Ar(bd(e)),t&&(t.on("beforeSendEvent",_d(e)),t.on("afterSendEvent",gd(e)),t.on("createDsc",(t=>{const n=e.getSessionId();n&&e.isEnabled()&&"session"===e.recordingMode&&e.checkAndHandleExpiredSession()&&(t.replay_id=n)})),t.on("spanStart",(t=>{e.lastActiveSpan=t})),t.on("spanEnd",(t=>{e.lastActiveSpan=t})),t.on("beforeSendFeedback",((t,n)=>{const r=e.getSessionId();n&&n.includeReplay&&e.isEnabled()&&r&&t.contexts&&t.contexts.feedback&&(t.contexts.feedback.replay_id=r)})))}(this),this._hasInitializedCoreListeners=!0)}catch(e){this.handleException(e)}this._performanceCleanupCallback=function(e){function t(t){e.performanceEntries.includes(t)||e.performanceEntries.push(t)}function n({entries:e}){e.forEach(t)}const r=[];return["navigation","paint","resource"].forEach((e=>{r.push(li(e,n))})),r.push(ai($l(jl,e)),ii($l(zl,e)),ci($l(ql,e)),ui($l(Wl,e))),()=>{r.forEach((e=>e()))}}(this)}_removeListeners(){try{Ja.document.removeEventListener("visibilitychange",this._handleVisibilityChange),Ja.removeEventListener("blur",this._handleWindowBlur),Ja.removeEventListener("focus",this._handleWindowFocus),Ja.removeEventListener("keydown",this._handleKeyboardEvent),this.clickDetector&&this.clickDetector.removeListeners(),this._performanceCleanupCallback&&this._performanceCleanupCallback()}catch(e){this.handleException(e)}}__init(){this._handleVisibilityChange=()=>{"visible"===Ja.document.visibilityState?this._doChangeToForegroundTasks():this._doChangeToBackgroundTasks()}}__init2(){this._handleWindowBlur=()=>{const e=Rl({category:"ui.blur"});this._doChangeToBackgroundTasks(e)}}__init3(){this._handleWindowFocus=()=>{const e=Rl({category:"ui.focus"});this._doChangeToForegroundTasks(e)}}__init4(){this._handleKeyboardEvent=e=>{Pl(this,e)}}_doChangeToBackgroundTasks(e){if(!this.session)return;ad(this.session,{maxReplayDuration:this._options.maxReplayDuration,sessionIdleExpire:this.timeouts.sessionIdleExpire})||(e&&this._createCustomBreadcrumb(e),this.conditionalFlush())}_doChangeToForegroundTasks(e){if(!this.session)return;this.checkAndHandleExpiredSession()?e&&this._createCustomBreadcrumb(e):ll&&fl.info("Document has become active, but session has expired")}_updateUserActivity(e=Date.now()){this._lastActivity=e}_updateSessionActivity(e=Date.now()){this.session&&(this.session.lastActivity=e,this._maybeSaveSession())}_createCustomBreadcrumb(e){this.addUpdate((()=>{this.throttledAddEvent({type:bu.Custom,timestamp:e.timestamp||0,data:{tag:"breadcrumb",payload:e}})}))}_addPerformanceEntries(){const e=(t=this.performanceEntries,t.map(Bl).filter(Boolean)).concat(this.replayPerformanceEntries);var t;return this.performanceEntries=[],this.replayPerformanceEntries=[],Promise.all(Sd(this,e))}_clearContext(){this._context.errorIds.clear(),this._context.traceIds.clear(),this._context.urls=[]}_updateInitialTimestampFromEventBuffer(){const{session:e,eventBuffer:t}=this;if(!e||!t||this._requiresManualStart)return;if(e.segmentId)return;const n=t.getEarliestTimestamp();n&&n<this._context.initialTimestamp&&(this._context.initialTimestamp=n)}_popEventContext(){const e={initialTimestamp:this._context.initialTimestamp,initialUrl:this._context.initialUrl,errorIds:Array.from(this._context.errorIds),traceIds:Array.from(this._context.traceIds),urls:this._context.urls};return this._clearContext(),e}async _runFlush(){const e=this.getSessionId();if(this.session&&this.eventBuffer&&e){if(await this._addPerformanceEntries(),this.eventBuffer&&this.eventBuffer.hasEvents&&(await async function(e){try{return Promise.all(Sd(e,[jd(Ja.performance.memory)]))}catch(e){return[]}}(this),this.eventBuffer&&e===this.getSessionId()))try{this._updateInitialTimestampFromEventBuffer();const t=Date.now();if(t-this._context.initialTimestamp>this._options.maxReplayDuration+3e4)throw new Error("Session is too long, not sending replay");const n=this._popEventContext(),r=this.session.segmentId++;this._maybeSaveSession();const o=await this.eventBuffer.finish();await Yd({replayId:e,recordingData:o,segmentId:r,eventContext:n,session:this.session,options:this.getOptions(),timestamp:t})}catch(e){this.handleException(e),this.stop({reason:"sendReplay"});const t=Qt();t&&t.recordDroppedEvent("send_error","replay")}}else ll&&fl.error("No session or eventBuffer found to flush.")}__init5(){this._flush=async({force:e=!1}={})=>{if(!this._isEnabled&&!e)return;if(!this.checkAndHandleExpiredSession())return void(ll&&fl.error("Attempting to finish replay event after session expired."));if(!this.session)return;const t=this.session.started,n=Date.now()-t;this._debouncedFlush.cancel();const r=n<this._options.minReplayDuration,o=n>this._options.maxReplayDuration+5e3;if(r||o)return ll&&fl.info(`Session duration (${Math.floor(n/1e3)}s) is too ${r?"short":"long"}, not sending replay.`),void(r&&this._debouncedFlush());const s=this.eventBuffer;if(s&&0===this.session.segmentId&&!s.hasCheckout&&ll&&fl.info("Flushing initial segment without checkout."),!this._flushLock)return this._flushLock=this._runFlush(),await this._flushLock,void(this._flushLock=void 0);try{await this._flushLock}catch(e){ll&&fl.error(e)}finally{this._debouncedFlush()}}}_maybeSaveSession(){this.session&&this._options.stickySession&&od(this.session)}__init6(){this._onMutationHandler=e=>{const t=e.length,n=this._options.mutationLimit,r=n&&t>n;if(t>this._options.mutationBreadcrumbLimit||r){const e=Rl({category:"replay.mutations",data:{count:t,limit:r}});this._createCustomBreadcrumb(e)}return!r||(this.stop({reason:"mutationLimit",forceFlush:"session"===this.recordingMode}),!1)}}}function Jd(e,t){return[...e,...t].join(",")}const Kd='img,image,svg,video,object,picture,embed,map,audio,link[rel="icon"],link[rel="apple-touch-icon"]',Qd=["content-length","content-type","accept"];let Zd=!1;class ep{static __initStatic(){this.id="Replay"}constructor({flushMinDelay:e=5e3,flushMaxDelay:t=5500,minReplayDuration:n=4999,maxReplayDuration:r=36e5,stickySession:o=!0,useCompression:s=!0,workerUrl:i,_experiments:a={},maskAllText:c=!0,maskAllInputs:u=!0,blockAllMedia:l=!0,mutationBreadcrumbLimit:d=750,mutationLimit:p=1e4,slowClickTimeout:h=7e3,slowClickIgnoreSelectors:f=[],networkDetailAllowUrls:m=[],networkDetailDenyUrls:g=[],networkCaptureBodies:_=!0,networkRequestHeaders:y=[],networkResponseHeaders:v=[],mask:b=[],maskAttributes:S=["title","placeholder"],unmask:w=[],block:k=[],unblock:x=[],ignore:E=[],maskFn:C,beforeAddRecordingEvent:T,beforeErrorSampling:I}={}){this.name=ep.id;const M=function({mask:e,unmask:t,block:n,unblock:r,ignore:o}){return{maskTextSelector:Jd(e,[".sentry-mask","[data-sentry-mask]"]),unmaskTextSelector:Jd(t,[]),blockSelector:Jd(n,[".sentry-block","[data-sentry-block]",'base[href="/"]']),unblockSelector:Jd(r,[]),ignoreSelector:Jd(o,[".sentry-ignore","[data-sentry-ignore]",'input[type="file"]'])}}({mask:b,unmask:w,block:k,unblock:x,ignore:E});if(this._recordingOptions={maskAllInputs:u,maskAllText:c,maskInputOptions:{password:!0},maskTextFn:C,maskInputFn:C,maskAttributeFn:(e,t,n)=>function({el:e,key:t,maskAttributes:n,maskAllText:r,privacyOptions:o,value:s}){return r?o.unmaskTextSelector&&e.matches(o.unmaskTextSelector)?s:n.includes(t)||"value"===t&&"INPUT"===e.tagName&&["submit","button"].includes(e.getAttribute("type")||"")?s.replace(/[\S]/g,"*"):s:s}({maskAttributes:S,maskAllText:c,privacyOptions:M,key:e,value:t,el:n}),...M,slimDOMOptions:"all",inlineStylesheet:!0,inlineImages:!1,collectFonts:!0,errorHandler:e=>{try{e.__rrweb__=!0}catch(e){}}},this._initialOptions={flushMinDelay:e,flushMaxDelay:t,minReplayDuration:Math.min(n,15e3),maxReplayDuration:Math.min(r,rc),stickySession:o,useCompression:s,workerUrl:i,blockAllMedia:l,maskAllInputs:u,maskAllText:c,mutationBreadcrumbLimit:d,mutationLimit:p,slowClickTimeout:h,slowClickIgnoreSelectors:f,networkDetailAllowUrls:m,networkDetailDenyUrls:g,networkCaptureBodies:_,networkRequestHeaders:tp(y),networkResponseHeaders:tp(v),beforeAddRecordingEvent:T,beforeErrorSampling:I,_experiments:a},this._initialOptions.blockAllMedia&&(this._recordingOptions.blockSelector=this._recordingOptions.blockSelector?`${this._recordingOptions.blockSelector},${Kd}`:Kd),this._isInitialized&&Oe())throw new Error("Multiple Sentry Session Replay instances are not supported");this._isInitialized=!0}get _isInitialized(){return Zd}set _isInitialized(e){Zd=e}afterAllSetup(e){Oe()&&!this._replay&&(this._setup(e),this._initialize(e))}start(){this._replay&&this._replay.start()}startBuffering(){this._replay&&this._replay.startBuffering()}stop(){return this._replay?this._replay.stop({forceFlush:"session"===this._replay.recordingMode}):Promise.resolve()}flush(e){return this._replay?this._replay.isEnabled()?this._replay.sendBufferedReplayOrFlush(e):(this._replay.start(),Promise.resolve()):Promise.resolve()}getReplayId(){if(this._replay&&this._replay.isEnabled())return this._replay.getSessionId()}_initialize(e){this._replay&&(this._maybeLoadFromReplayCanvasIntegration(e),this._replay.initializeSampling())}_setup(e){const t=function(e,t){const n=t.getOptions(),r={sessionSampleRate:0,errorSampleRate:0,...X(e)},o=Wn(n.replaysSessionSampleRate),s=Wn(n.replaysOnErrorSampleRate);null==o&&null==s&&D((()=>{console.warn("Replay is disabled because neither `replaysSessionSampleRate` nor `replaysOnErrorSampleRate` are set.")}));null!=o&&(r.sessionSampleRate=o);null!=s&&(r.errorSampleRate=s);return r}(this._initialOptions,e);this._replay=new Xd({options:t,recordingOptions:this._recordingOptions})}_maybeLoadFromReplayCanvasIntegration(e){try{const t=e.getIntegrationByName("ReplayCanvas");if(!t)return;this._replay._canvas=t.getOptions()}catch(e){}}}function tp(e){return[...Qd,...e.map((e=>e.toLowerCase()))]}var np;function rp(e,t,n=1/0,r=0){return e?e.nodeType!==e.ELEMENT_NODE||r>n?-1:t(e)?r:rp(e.parentNode,t,n,r+1):-1}function op(e,t){return n=>{const r=n;if(null===r)return!1;try{if(e)if("string"==typeof e){if(r.matches(`.${e}`))return!0}else if(function(e,t){for(let n=e.classList.length;n--;){const r=e.classList[n];if(t.test(r))return!0}return!1}(r,e))return!0;return!(!t||!r.matches(t))}catch(e){return!1}}}ep.__initStatic(),function(e){e[e.Document=0]="Document",e[e.DocumentType=1]="DocumentType",e[e.Element=2]="Element",e[e.Text=3]="Text",e[e.CDATA=4]="CDATA",e[e.Comment=5]="Comment"}(np||(np={}));const sp="Please stop import mirror directly. Instead of that,\r\nnow you can use replayer.getMirror() to access the mirror instance of a replayer,\r\nor you can use record.mirror to access the mirror instance during recording.";let ip={map:{},getId:()=>(console.error(sp),-1),getNode:()=>(console.error(sp),null),removeNodeFromMap(){console.error(sp)},has:()=>(console.error(sp),!1),reset(){console.error(sp)}};function ap(e,t,n,r,o=window){const s=o.Object.getOwnPropertyDescriptor(e,t);return o.Object.defineProperty(e,t,r?n:{set(e){hp((()=>{n.set.call(this,e)}),0),s&&s.set&&s.set.call(this,e)}}),()=>ap(e,t,s||{},!0)}function cp(e,t,n){try{if(!(t in e))return()=>{};const r=e[t],o=n(r);return"function"==typeof o&&(o.prototype=o.prototype||{},Object.defineProperties(o,{__rrweb_original__:{enumerable:!1,value:r}})),e[t]=o,()=>{e[t]=r}}catch(e){return()=>{}}}function up(e,t,n,r,o){if(!e)return!1;const s=function(e){return e?e.nodeType===e.ELEMENT_NODE?e:e.parentElement:null}(e);if(!s)return!1;const i=op(t,n);if(!o){const e=r&&s.matches(r);return i(s)&&!e}const a=rp(s,i);let c=-1;return!(a<0)&&(r&&(c=rp(s,op(null,r))),a>-1&&c<0||a<c)}"undefined"!=typeof window&&window.Proxy&&window.Reflect&&(ip=new Proxy(ip,{get:(e,t,n)=>("map"===t&&console.error(sp),Reflect.get(e,t,n))})),/[1-9][0-9]{12}/.test(Date.now().toString());const lp={};function dp(e){const t=lp[e];if(t)return t;const n=window.document;let r=window[e];if(n&&"function"==typeof n.createElement)try{const t=n.createElement("iframe");t.hidden=!0,n.head.appendChild(t);const o=t.contentWindow;o&&o[e]&&(r=o[e]),n.head.removeChild(t)}catch(e){}return lp[e]=r.bind(window)}function pp(...e){return dp("requestAnimationFrame")(...e)}function hp(...e){return dp("setTimeout")(...e)}var fp=(e=>(e[e["2D"]=0]="2D",e[e.WebGL=1]="WebGL",e[e.WebGL2=2]="WebGL2",e))(fp||{});let mp;function gp(e){mp=e}const _p=e=>{if(!mp)return e;return(...t)=>{try{return e(...t)}catch(e){if(mp&&!0===mp(e))return()=>{};throw e}}};for(var yp="ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",vp="undefined"==typeof Uint8Array?[]:new Uint8Array(256),bp=0;bp<64;bp++)vp[yp.charCodeAt(bp)]=bp;const Sp=new Map;const wp=(e,t,n)=>{if(!e||!Ep(e,t)&&"object"!=typeof e)return;const r=function(e,t){let n=Sp.get(e);return n||(n=new Map,Sp.set(e,n)),n.has(t)||n.set(t,[]),n.get(t)}(n,e.constructor.name);let o=r.indexOf(e);return-1===o&&(o=r.length,r.push(e)),o};function kp(e,t,n){if(e instanceof Array)return e.map((e=>kp(e,t,n)));if(null===e)return e;if(e instanceof Float32Array||e instanceof Float64Array||e instanceof Int32Array||e instanceof Uint32Array||e instanceof Uint8Array||e instanceof Uint16Array||e instanceof Int16Array||e instanceof Int8Array||e instanceof Uint8ClampedArray){return{rr_type:e.constructor.name,args:[Object.values(e)]}}if(e instanceof ArrayBuffer){const t=e.constructor.name,n=function(e){var t,n=new Uint8Array(e),r=n.length,o="";for(t=0;t<r;t+=3)o+=yp[n[t]>>2],o+=yp[(3&n[t])<<4|n[t+1]>>4],o+=yp[(15&n[t+1])<<2|n[t+2]>>6],o+=yp[63&n[t+2]];return r%3==2?o=o.substring(0,o.length-1)+"=":r%3==1&&(o=o.substring(0,o.length-2)+"=="),o}(e);return{rr_type:t,base64:n}}if(e instanceof DataView){return{rr_type:e.constructor.name,args:[kp(e.buffer,t,n),e.byteOffset,e.byteLength]}}if(e instanceof HTMLImageElement){const t=e.constructor.name,{src:n}=e;return{rr_type:t,src:n}}if(e instanceof HTMLCanvasElement){return{rr_type:"HTMLImageElement",src:e.toDataURL()}}if(e instanceof ImageData){return{rr_type:e.constructor.name,args:[kp(e.data,t,n),e.width,e.height]}}if(Ep(e,t)||"object"==typeof e){return{rr_type:e.constructor.name,index:wp(e,t,n)}}return e}const xp=(e,t,n)=>e.map((e=>kp(e,t,n))),Ep=(e,t)=>{const n=["WebGLActiveInfo","WebGLBuffer","WebGLFramebuffer","WebGLProgram","WebGLRenderbuffer","WebGLShader","WebGLShaderPrecisionFormat","WebGLTexture","WebGLUniformLocation","WebGLVertexArrayObject","WebGLVertexArrayObjectOES"].filter((e=>"function"==typeof t[e]));return Boolean(n.find((n=>e instanceof t[n])))};function Cp(e,t,n,r,o){const s=[];try{const i=cp(e.HTMLCanvasElement.prototype,"getContext",(function(e){return function(s,...i){if(!up(this,t,n,r,!0)){const e=function(e){return"experimental-webgl"===e?"webgl":e}(s);if("__context"in this||(this.__context=e),o&&["webgl","webgl2"].includes(e))if(i[0]&&"object"==typeof i[0]){const e=i[0];e.preserveDrawingBuffer||(e.preserveDrawingBuffer=!0)}else i.splice(0,1,{preserveDrawingBuffer:!0})}return e.apply(this,[s,...i])}}));s.push(i)}catch(e){console.error("failed to patch HTMLCanvasElement.prototype.getContext")}return()=>{s.forEach((e=>e()))}}function Tp(e,t,n,r,o,s,i,a){const c=[],u=Object.getOwnPropertyNames(e);for(const i of u)if(!["isContextLost","canvas","drawingBufferWidth","drawingBufferHeight"].includes(i))try{if("function"!=typeof e[i])continue;const u=cp(e,i,(function(e){return function(...c){const u=e.apply(this,c);if(wp(u,a,this),"tagName"in this.canvas&&!up(this.canvas,r,o,s,!0)){const e=xp(c,a,this),r={type:t,property:i,args:e};n(this.canvas,r)}return u}}));c.push(u)}catch(r){const o=ap(e,i,{set(e){n(this.canvas,{type:t,property:i,args:[e],setter:!0})}});c.push(o)}return c}class Ip{reset(){this.pendingCanvasMutations.clear(),this.restoreHandlers.forEach((e=>{try{e()}catch(e){}})),this.restoreHandlers=[],this.windowsSet=new WeakSet,this.windows=[],this.shadowDoms=new Set,Mt([this,"access",e=>e.worker,"optionalAccess",e=>e.terminate,"call",e=>e()]),this.worker=null,this.snapshotInProgressMap=new Map,(this.options.recordCanvas&&"number"==typeof this.options.sampling||this.options.enableManualSnapshot)&&(this.worker=this.initFPSWorker())}freeze(){this.frozen=!0}unfreeze(){this.frozen=!1}lock(){this.locked=!0}unlock(){this.locked=!1}constructor(e){this.pendingCanvasMutations=new Map,this.rafStamps={latestId:0,invokeId:null},this.shadowDoms=new Set,this.windowsSet=new WeakSet,this.windows=[],this.restoreHandlers=[],this.frozen=!1,this.locked=!1,this.snapshotInProgressMap=new Map,this.worker=null,this.processMutation=(e,t)=>{!(this.rafStamps.invokeId&&this.rafStamps.latestId!==this.rafStamps.invokeId)&&this.rafStamps.invokeId||(this.rafStamps.invokeId=this.rafStamps.latestId),this.pendingCanvasMutations.has(e)||this.pendingCanvasMutations.set(e,[]),this.pendingCanvasMutations.get(e).push(t)};const{sampling:t="all",win:n,blockClass:r,blockSelector:o,unblockSelector:s,maxCanvasSize:i,recordCanvas:a,dataURLOptions:c,errorHandler:u}=e;this.mutationCb=e.mutationCb,this.mirror=e.mirror,this.options=e,u&&gp(u),(a&&"number"==typeof t||e.enableManualSnapshot)&&(this.worker=this.initFPSWorker()),this.addWindow(n),e.enableManualSnapshot||_p((()=>{a&&"all"===t&&(this.startRAFTimestamping(),this.startPendingCanvasMutationFlusher()),a&&"number"==typeof t&&this.initCanvasFPSObserver(t,r,o,s,i,{dataURLOptions:c})}))()}addWindow(e){const{sampling:t="all",blockClass:n,blockSelector:r,unblockSelector:o,recordCanvas:s,enableManualSnapshot:i}=this.options;if(!this.windowsSet.has(e)){if(i)return this.windowsSet.add(e),void this.windows.push(new WeakRef(e));_p((()=>{if(s&&"all"===t&&this.initCanvasMutationObserver(e,n,r,o),s&&"number"==typeof t){const t=Cp(e,n,r,o,!0);this.restoreHandlers.push((()=>{t()}))}}))(),this.windowsSet.add(e),this.windows.push(new WeakRef(e))}}addShadowRoot(e){this.shadowDoms.add(new WeakRef(e))}resetShadowRoots(){this.shadowDoms=new Set}initFPSWorker(){const e=new Worker(function(){const e=new Blob(['for(var e="ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",t="undefined"==typeof Uint8Array?[]:new Uint8Array(256),a=0;a<64;a++)t[e.charCodeAt(a)]=a;var n=function(t){var a,n=new Uint8Array(t),r=n.length,s="";for(a=0;a<r;a+=3)s+=e[n[a]>>2],s+=e[(3&n[a])<<4|n[a+1]>>4],s+=e[(15&n[a+1])<<2|n[a+2]>>6],s+=e[63&n[a+2]];return r%3==2?s=s.substring(0,s.length-1)+"=":r%3==1&&(s=s.substring(0,s.length-2)+"=="),s};const r=new Map,s=new Map;const i=self;i.onmessage=async function(e){if(!("OffscreenCanvas"in globalThis))return i.postMessage({id:e.data.id});{const{id:t,bitmap:a,width:o,height:f,maxCanvasSize:c,dataURLOptions:g}=e.data,u=async function(e,t,a){const r=e+"-"+t;if("OffscreenCanvas"in globalThis){if(s.has(r))return s.get(r);const i=new OffscreenCanvas(e,t);i.getContext("2d");const o=await i.convertToBlob(a),f=await o.arrayBuffer(),c=n(f);return s.set(r,c),c}return""}(o,f,g),[h,d]=function(e,t,a){if(!a)return[e,t];const[n,r]=a;if(e<=n&&t<=r)return[e,t];let s=e,i=t;return s>n&&(i=Math.floor(n*t/e),s=n),i>r&&(s=Math.floor(r*e/t),i=r),[s,i]}(o,f,c),l=new OffscreenCanvas(h,d),w=l.getContext("bitmaprenderer"),p=h===o&&d===f?a:await createImageBitmap(a,{resizeWidth:h,resizeHeight:d,resizeQuality:"low"});w.transferFromImageBitmap(p),a.close();const y=await l.convertToBlob(g),v=y.type,b=await y.arrayBuffer(),m=n(b);if(p.close(),!r.has(t)&&await u===m)return r.set(t,m),i.postMessage({id:t});if(r.get(t)===m)return i.postMessage({id:t});i.postMessage({id:t,type:v,base64:m,width:o,height:f}),r.set(t,m)}};']);return URL.createObjectURL(e)}());return e.onmessage=e=>{const t=e.data,{id:n}=t;if(this.snapshotInProgressMap.set(n,!1),!("base64"in t))return;const{base64:r,type:o,width:s,height:i}=t;this.mutationCb({id:n,type:fp["2D"],commands:[{property:"clearRect",args:[0,0,s,i]},{property:"drawImage",args:[{rr_type:"ImageBitmap",args:[{rr_type:"Blob",data:[{rr_type:"ArrayBuffer",base64:r}],type:o}]},0,0,s,i]}]})},e}initCanvasFPSObserver(e,t,n,r,o,s){const i=this.takeSnapshot(!1,e,t,n,r,o,s.dataURLOptions);this.restoreHandlers.push((()=>{cancelAnimationFrame(i)}))}initCanvasMutationObserver(e,t,n,r){const o=Cp(e,t,n,r,!1),s=function(e,t,n,r,o){const s=[],i=Object.getOwnPropertyNames(t.CanvasRenderingContext2D.prototype);for(const a of i)try{if("function"!=typeof t.CanvasRenderingContext2D.prototype[a])continue;const i=cp(t.CanvasRenderingContext2D.prototype,a,(function(s){return function(...i){return up(this.canvas,n,r,o,!0)||hp((()=>{const n=xp(i,t,this);e(this.canvas,{type:fp["2D"],property:a,args:n})}),0),s.apply(this,i)}}));s.push(i)}catch(n){const r=ap(t.CanvasRenderingContext2D.prototype,a,{set(t){e(this.canvas,{type:fp["2D"],property:a,args:[t],setter:!0})}});s.push(r)}return()=>{s.forEach((e=>e()))}}(this.processMutation.bind(this),e,t,n,r),i=function(e,t,n,r,o){const s=[];return s.push(...Tp(t.WebGLRenderingContext.prototype,fp.WebGL,e,n,r,o,0,t)),void 0!==t.WebGL2RenderingContext&&s.push(...Tp(t.WebGL2RenderingContext.prototype,fp.WebGL2,e,n,r,o,0,t)),()=>{s.forEach((e=>e()))}}(this.processMutation.bind(this),e,t,n,r,this.mirror);this.restoreHandlers.push((()=>{o(),s(),i()}))}snapshot(e){const{options:t}=this,n=this.takeSnapshot(!0,"all"===t.sampling?2:t.sampling||2,t.blockClass,t.blockSelector,t.unblockSelector,t.maxCanvasSize,t.dataURLOptions,e);this.restoreHandlers.push((()=>{cancelAnimationFrame(n)}))}takeSnapshot(e,t,n,r,o,s,i,a){const c=1e3/t;let u,l=0;const d=e=>{if(e)return[e];const t=[],s=e=>{e.querySelectorAll("canvas").forEach((e=>{up(e,n,r,o,!0)||t.push(e)}))};for(const e of this.windows){const t=e.deref();t&&s(t.document)}for(const e of this.shadowDoms){const t=e.deref();t&&s(t)}return t},p=t=>{this.windows.length&&(l&&t-l<c?u=pp(p):(l=t,d(a).forEach((t=>{if(!this.mirror.hasNode(t))return;const n=this.mirror.getId(t);if(!this.snapshotInProgressMap.get(n)&&t.width&&t.height){if(this.snapshotInProgressMap.set(n,!0),!e&&["webgl","webgl2"].includes(t.__context)){const e=t.getContext(t.__context);!1===Mt([e,"optionalAccess",e=>e.getContextAttributes,"call",e=>e(),"optionalAccess",e=>e.preserveDrawingBuffer])&&e.clear(e.COLOR_BUFFER_BIT)}createImageBitmap(t).then((e=>{Mt([this,"access",e=>e.worker,"optionalAccess",e=>e.postMessage,"call",r=>r({id:n,bitmap:e,width:t.width,height:t.height,dataURLOptions:i,maxCanvasSize:s},[e])])})).catch((e=>{_p((()=>{throw e}))()}))}})),e||(u=pp(p))))};return u=pp(p),u}startPendingCanvasMutationFlusher(){pp((()=>this.flushPendingCanvasMutations()))}startRAFTimestamping(){const e=t=>{this.rafStamps.latestId=t,pp(e)};pp(e)}flushPendingCanvasMutations(){this.pendingCanvasMutations.forEach(((e,t)=>{const n=this.mirror.getId(t);this.flushPendingCanvasMutationFor(t,n)})),pp((()=>this.flushPendingCanvasMutations()))}flushPendingCanvasMutationFor(e,t){if(this.frozen||this.locked)return;const n=this.pendingCanvasMutations.get(e);if(!n||-1===t)return;const r=n.map((e=>{const{type:t,...n}=e;return n})),{type:o}=n[0];this.mutationCb({id:t,type:o,commands:r}),this.pendingCanvasMutations.delete(e)}}const Mp={low:{sampling:{canvas:1},dataURLOptions:{type:"image/webp",quality:.25}},medium:{sampling:{canvas:2},dataURLOptions:{type:"image/webp",quality:.4}},high:{sampling:{canvas:4},dataURLOptions:{type:"image/webp",quality:.5}}},Rp=1280,Ap=(e={})=>{const[t,n]=e.maxCanvasSize||[],r={quality:e.quality||"medium",enableManualSnapshot:e.enableManualSnapshot,maxCanvasSize:[t?Math.min(t,Rp):Rp,n?Math.min(n,Rp):Rp]};let o;const s=new Promise((e=>o=e));return{name:"ReplayCanvas",getOptions(){const{quality:e,enableManualSnapshot:t,maxCanvasSize:n}=r;return{enableManualSnapshot:t,recordCanvas:!0,getCanvasManager:e=>{const r=new Ip({...e,enableManualSnapshot:t,maxCanvasSize:n,errorHandler:e=>{try{"object"==typeof e&&(e.__rrweb__=!0)}catch(e){}}});return o(r),r},...Mp[e||"medium"]||Mp.medium}},async snapshot(e){(await s).snapshot(e)}}},Np=k,Op=Np.document,Dp=Np.navigator,Lp="Report a Bug",Pp="widget",Fp=(e,t={includeReplay:!0})=>{if(!e.message)throw new Error("Unable to submit feedback with empty message");const n=Qt();if(!n)throw new Error("No client setup, cannot send feedback.");e.tags&&Object.keys(e.tags).length&&Gt().setTags(e.tags);const r=Ko({source:"api",url:M(),...e},t);return new Promise(((e,t)=>{const o=setTimeout((()=>t("Unable to determine if Feedback was correctly sent.")),5e3),s=n.on("afterSendEvent",((n,i)=>{if(n.event_id===r)return clearTimeout(o),s(),i&&"number"==typeof i.statusCode&&i.statusCode>=200&&i.statusCode<300&&e(r),i&&"number"==typeof i.statusCode&&0===i.statusCode?t("Unable to send Feedback. This is because of network issues, or because you are using an ad-blocker."):i&&"number"==typeof i.statusCode&&403===i.statusCode?t("Unable to send Feedback. This could be because this domain is not in your list of allowed domains."):t("Unable to send Feedback. This could be because of network issues, or because you are using an ad-blocker")}))}))},$p="undefined"==typeof __SENTRY_DEBUG__||__SENTRY_DEBUG__;function Bp(e,t){return{...e,...t,tags:{...e.tags,...t.tags},onFormOpen:()=>{t.onFormOpen&&t.onFormOpen(),e.onFormOpen&&e.onFormOpen()},onFormClose:()=>{t.onFormClose&&t.onFormClose(),e.onFormClose&&e.onFormClose()},onSubmitSuccess:n=>{t.onSubmitSuccess&&t.onSubmitSuccess(n),e.onSubmitSuccess&&e.onSubmitSuccess(n)},onSubmitError:n=>{t.onSubmitError&&t.onSubmitError(n),e.onSubmitError&&e.onSubmitError(n)},onFormSubmitted:()=>{t.onFormSubmitted&&t.onFormSubmitted(),e.onFormSubmitted&&e.onFormSubmitted()},themeDark:{...e.themeDark,...t.themeDark},themeLight:{...e.themeLight,...t.themeLight}}}function Up(e,t){return Object.entries(t).forEach((([t,n])=>{e.setAttributeNS(null,t,n)})),e}const jp=20,Hp="http://www.w3.org/2000/svg";function zp({triggerLabel:e,triggerAriaLabel:t,shadow:n,styleNonce:r}){const o=Op.createElement("button");if(o.type="button",o.className="widget__actor",o.ariaHidden="false",o.ariaLabel=t||e||Lp,o.appendChild(function(){const e=e=>Np.document.createElementNS(Hp,e),t=Up(e("svg"),{width:`${jp}`,height:`${jp}`,viewBox:`0 0 ${jp} ${jp}`,fill:"var(--foreground)"}),n=Up(e("g"),{clipPath:"url(#clip0_57_80)"}),r=Up(e("path"),{"fill-rule":"evenodd","clip-rule":"evenodd",d:"M15.6622 15H12.3997C12.2129 14.9959 12.031 14.9396 11.8747 14.8375L8.04965 12.2H7.49956V19.1C7.4875 19.3348 7.3888 19.5568 7.22256 19.723C7.05632 19.8892 6.83435 19.9879 6.59956 20H2.04956C1.80193 19.9968 1.56535 19.8969 1.39023 19.7218C1.21511 19.5467 1.1153 19.3101 1.11206 19.0625V12.2H0.949652C0.824431 12.2017 0.700142 12.1783 0.584123 12.1311C0.468104 12.084 0.362708 12.014 0.274155 11.9255C0.185602 11.8369 0.115689 11.7315 0.0685419 11.6155C0.0213952 11.4995 -0.00202913 11.3752 -0.00034808 11.25V3.75C-0.00900498 3.62067 0.0092504 3.49095 0.0532651 3.36904C0.0972798 3.24712 0.166097 3.13566 0.255372 3.04168C0.344646 2.94771 0.452437 2.87327 0.571937 2.82307C0.691437 2.77286 0.82005 2.74798 0.949652 2.75H8.04965L11.8747 0.1625C12.031 0.0603649 12.2129 0.00407221 12.3997 0H15.6622C15.9098 0.00323746 16.1464 0.103049 16.3215 0.278167C16.4966 0.453286 16.5964 0.689866 16.5997 0.9375V3.25269C17.3969 3.42959 18.1345 3.83026 18.7211 4.41679C19.5322 5.22788 19.9878 6.32796 19.9878 7.47502C19.9878 8.62209 19.5322 9.72217 18.7211 10.5333C18.1345 11.1198 17.3969 11.5205 16.5997 11.6974V14.0125C16.6047 14.1393 16.5842 14.2659 16.5395 14.3847C16.4948 14.5035 16.4268 14.6121 16.3394 14.7042C16.252 14.7962 16.147 14.8698 16.0307 14.9206C15.9144 14.9714 15.7891 14.9984 15.6622 15ZM1.89695 10.325H1.88715V4.625H8.33715C8.52423 4.62301 8.70666 4.56654 8.86215 4.4625L12.6872 1.875H14.7247V13.125H12.6872L8.86215 10.4875C8.70666 10.3835 8.52423 10.327 8.33715 10.325H2.20217C2.15205 10.3167 2.10102 10.3125 2.04956 10.3125C1.9981 10.3125 1.94708 10.3167 1.89695 10.325ZM2.98706 12.2V18.1625H5.66206V12.2H2.98706ZM16.5997 9.93612V5.01393C16.6536 5.02355 16.7072 5.03495 16.7605 5.04814C17.1202 5.13709 17.4556 5.30487 17.7425 5.53934C18.0293 5.77381 18.2605 6.06912 18.4192 6.40389C18.578 6.73866 18.6603 7.10452 18.6603 7.47502C18.6603 7.84552 18.578 8.21139 18.4192 8.54616C18.2605 8.88093 18.0293 9.17624 17.7425 9.41071C17.4556 9.64518 17.1202 9.81296 16.7605 9.90191C16.7072 9.91509 16.6536 9.9265 16.5997 9.93612Z"});t.appendChild(n).appendChild(r);const o=e("defs"),s=Up(e("clipPath"),{id:"clip0_57_80"}),i=Up(e("rect"),{width:`${jp}`,height:`${jp}`,fill:"white"});return s.appendChild(i),o.appendChild(s),t.appendChild(o).appendChild(s).appendChild(i),t}()),e){const t=Op.createElement("span");t.appendChild(Op.createTextNode(e)),o.appendChild(t)}const s=function(e){const t=Op.createElement("style");return t.textContent='\n.widget__actor {\n  position: fixed;\n  z-index: var(--z-index);\n  margin: var(--page-margin);\n  inset: var(--actor-inset);\n\n  display: flex;\n  align-items: center;\n  gap: 8px;\n  padding: 16px;\n\n  font-family: inherit;\n  font-size: var(--font-size);\n  font-weight: 600;\n  line-height: 1.14em;\n  text-decoration: none;\n\n  background: var(--actor-background, var(--background));\n  border-radius: var(--actor-border-radius, 1.7em/50%);\n  border: var(--actor-border, var(--border));\n  box-shadow: var(--actor-box-shadow, var(--box-shadow));\n  color: var(--actor-color, var(--foreground));\n  fill: var(--actor-color, var(--foreground));\n  cursor: pointer;\n  opacity: 1;\n  transition: transform 0.2s ease-in-out;\n  transform: translate(0, 0) scale(1);\n}\n.widget__actor[aria-hidden="true"] {\n  opacity: 0;\n  pointer-events: none;\n  visibility: hidden;\n  transform: translate(0, 16px) scale(0.98);\n}\n\n.widget__actor:hover {\n  background: var(--actor-hover-background, var(--background));\n  filter: var(--interactive-filter);\n}\n\n.widget__actor svg {\n  width: 1.14em;\n  height: 1.14em;\n}\n\n@media (max-width: 600px) {\n  .widget__actor span {\n    display: none;\n  }\n}\n',e&&t.setAttribute("nonce",e),t}(r);return{el:o,appendToDom(){n.appendChild(s),n.appendChild(o)},removeFromDom(){n.removeChild(o),n.removeChild(s)},show(){o.ariaHidden="false"},hide(){o.ariaHidden="true"}}}const qp="rgba(88, 74, 192, 1)",Wp={foreground:"#2b2233",background:"#ffffff",accentForeground:"white",accentBackground:qp,successColor:"#268d75",errorColor:"#df3338",border:"1.5px solid rgba(41, 35, 47, 0.13)",boxShadow:"0px 4px 24px 0px rgba(43, 34, 51, 0.12)",outline:"1px auto var(--accent-background)",interactiveFilter:"brightness(95%)"},Yp={foreground:"#ebe6ef",background:"#29232f",accentForeground:"white",accentBackground:qp,successColor:"#2da98c",errorColor:"#f55459",border:"1.5px solid rgba(235, 230, 239, 0.15)",boxShadow:"0px 4px 24px 0px rgba(43, 34, 51, 0.12)",outline:"1px auto var(--accent-background)",interactiveFilter:"brightness(150%)"};function Vp(e){return`\n  --foreground: ${e.foreground};\n  --background: ${e.background};\n  --accent-foreground: ${e.accentForeground};\n  --accent-background: ${e.accentBackground};\n  --success-color: ${e.successColor};\n  --error-color: ${e.errorColor};\n  --border: ${e.border};\n  --box-shadow: ${e.boxShadow};\n  --outline: ${e.outline};\n  --interactive-filter: ${e.interactiveFilter};\n  `}const Gp=({lazyLoadIntegration:e,getModalIntegration:t,getScreenshotIntegration:n})=>({id:r="sentry-feedback",autoInject:o=!0,showBranding:s=!0,isEmailRequired:i=!1,isNameRequired:a=!1,showEmail:c=!0,showName:u=!0,enableScreenshot:l=!0,useSentryUser:d={email:"email",name:"username"},tags:p,styleNonce:h,scriptNonce:f,colorScheme:m="system",themeLight:g={},themeDark:_={},addScreenshotButtonLabel:y="Add a screenshot",cancelButtonLabel:v="Cancel",confirmButtonLabel:b="Confirm",emailLabel:S="Email",emailPlaceholder:w="your.email@example.org",formTitle:k="Report a Bug",isRequiredLabel:x="(required)",messageLabel:E="Description",messagePlaceholder:C="What's the bug? What did you expect?",nameLabel:T="Name",namePlaceholder:I="Your Name",removeScreenshotButtonLabel:M="Remove screenshot",submitButtonLabel:R="Send Bug Report",successMessageText:A="Thank you for your report!",triggerLabel:N=Lp,triggerAriaLabel:O="",onFormOpen:D,onFormClose:P,onSubmitSuccess:F,onSubmitError:$,onFormSubmitted:B}={})=>{const U={id:r,autoInject:o,showBranding:s,isEmailRequired:i,isNameRequired:a,showEmail:c,showName:u,enableScreenshot:l,useSentryUser:d,tags:p,styleNonce:h,scriptNonce:f,colorScheme:m,themeDark:_,themeLight:g,triggerLabel:N,triggerAriaLabel:O,cancelButtonLabel:v,submitButtonLabel:R,confirmButtonLabel:b,formTitle:k,emailLabel:S,emailPlaceholder:w,messageLabel:E,messagePlaceholder:C,nameLabel:T,namePlaceholder:I,successMessageText:A,isRequiredLabel:x,addScreenshotButtonLabel:y,removeScreenshotButtonLabel:M,onFormClose:P,onFormOpen:D,onSubmitError:$,onSubmitSuccess:F,onFormSubmitted:B};let j=null,H=[];const z=e=>{if(!j){const t=Op.createElement("div");t.id=String(e.id),Op.body.appendChild(t),j=t.attachShadow({mode:"open"}),j.appendChild(function({colorScheme:e,themeDark:t,themeLight:n,styleNonce:r}){const o=Op.createElement("style");return o.textContent=`\n:host {\n  --font-family: system-ui, 'Helvetica Neue', Arial, sans-serif;\n  --font-size: 14px;\n  --z-index: 100000;\n\n  --page-margin: 16px;\n  --inset: auto 0 0 auto;\n  --actor-inset: var(--inset);\n\n  font-family: var(--font-family);\n  font-size: var(--font-size);\n\n  ${"system"!==e?"color-scheme: only light;":""}\n\n  ${Vp("dark"===e?{...Yp,...t}:{...Wp,...n})}\n}\n\n${"system"===e?`\n@media (prefers-color-scheme: dark) {\n  :host {\n    ${Vp({...Yp,...t})}\n  }\n}`:""}\n}\n`,r&&o.setAttribute("nonce",r),o}(e))}return j},q=async(t,n,r)=>{const o=Qt(),s=o&&o.getIntegrationByName(t);if(s)return s;const i=(n&&n()||await e(r,f))();return o&&o.addIntegration(i),i},W=async e=>{const r=e.enableScreenshot&&!(/Android|webOS|iPhone|iPad|iPod|BlackBerry|IEMobile|Opera Mini/i.test(Dp.userAgent)||/Macintosh/i.test(Dp.userAgent)&&Dp.maxTouchPoints&&Dp.maxTouchPoints>1||!isSecureContext),[o,s]=await Promise.all([q("FeedbackModal",t,"feedbackModalIntegration"),r?q("FeedbackScreenshot",n,"feedbackScreenshotIntegration"):void 0]);if(!o)throw $p&&L.error("[Feedback] Missing feedback modal integration. Try using `feedbackSyncIntegration` in your `Sentry.init`."),new Error("[Feedback] Missing feedback modal integration!");r&&!s&&$p&&L.error("[Feedback] Missing feedback screenshot integration. Proceeding without screenshots.");const i=o.createDialog({options:{...e,onFormClose:()=>{i&&i.close(),e.onFormClose&&e.onFormClose()},onFormSubmitted:()=>{i&&i.close(),e.onFormSubmitted&&e.onFormSubmitted()}},screenshotIntegration:r?s:void 0,sendFeedback:Fp,shadow:z(e)});return i},Y=(e,t={})=>{const n=Bp(U,t),r="string"==typeof e?Op.querySelector(e):"function"==typeof e.addEventListener?e:null;if(!r)throw $p&&L.error("[Feedback] Unable to attach to target element"),new Error("Unable to attach to target element");let o=null;const s=async()=>{o||(o=await W({...n,onFormSubmitted:()=>{o&&o.removeFromDom(),n.onFormSubmitted&&n.onFormSubmitted()}})),o.appendToDom(),o.open()};r.addEventListener("click",s);const i=()=>{H=H.filter((e=>e!==i)),o&&o.removeFromDom(),o=null,r.removeEventListener("click",s)};return H.push(i),i},V=(e={})=>{const t=Bp(U,e),n=z(t),r=zp({triggerLabel:t.triggerLabel,triggerAriaLabel:t.triggerAriaLabel,shadow:n,styleNonce:h});return Y(r.el,{...t,onFormOpen(){r.hide()},onFormClose(){r.show()},onFormSubmitted(){r.show()}}),r};return{name:"Feedback",setupOnce(){Oe()&&U.autoInject&&("loading"===Op.readyState?Op.addEventListener("DOMContentLoaded",(()=>V().appendToDom())):V().appendToDom())},attachTo:Y,createWidget(e={}){const t=V(Bp(U,e));return t.appendToDom(),t},createForm:async(e={})=>W(Bp(U,e)),remove(){j&&(j.parentElement&&j.parentElement.remove(),j=null),H.forEach((e=>e())),H=[]}}};var Xp,Jp,Kp,Qp,Zp,eh,th,nh={},rh=[],oh=/acit|ex(?:s|g|n|p|$)|rph|grid|ows|mnc|ntw|ine[ch]|zoo|^ord|itera/i,sh=Array.isArray;function ih(e,t){for(var n in t)e[n]=t[n];return e}function ah(e){var t=e.parentNode;t&&t.removeChild(e)}function ch(e,t,n){var r,o,s,i={};for(s in t)"key"==s?r=t[s]:"ref"==s?o=t[s]:i[s]=t[s];if(arguments.length>2&&(i.children=arguments.length>3?Xp.call(arguments,2):n),"function"==typeof e&&null!=e.defaultProps)for(s in e.defaultProps)void 0===i[s]&&(i[s]=e.defaultProps[s]);return uh(e,i,r,o,null)}function uh(e,t,n,r,o){var s={type:e,props:t,key:n,ref:r,__k:null,__:null,__b:0,__e:null,__d:void 0,__c:null,constructor:void 0,__v:null==o?++Kp:o,__i:-1,__u:0};return null==o&&null!=Jp.vnode&&Jp.vnode(s),s}function lh(e){return e.children}function dh(e,t){this.props=e,this.context=t}function ph(e,t){if(null==t)return e.__?ph(e.__,e.__i+1):null;for(var n;t<e.__k.length;t++)if(null!=(n=e.__k[t])&&null!=n.__e)return n.__e;return"function"==typeof e.type?ph(e):null}function hh(e,t,n){var r,o=e.__v,s=o.__e,i=e.__P;if(i)return(r=ih({},o)).__v=o.__v+1,Jp.vnode&&Jp.vnode(r),xh(i,r,o,e.__n,void 0!==i.ownerSVGElement,32&o.__u?[s]:null,t,null==s?ph(o):s,!!(32&o.__u),n),r.__.__k[r.__i]=r,r.__d=void 0,r.__e!=s&&fh(r),r}function fh(e){var t,n;if(null!=(e=e.__)&&null!=e.__c){for(e.__e=e.__c.base=null,t=0;t<e.__k.length;t++)if(null!=(n=e.__k[t])&&null!=n.__e){e.__e=e.__c.base=n.__e;break}return fh(e)}}function mh(e){(!e.__d&&(e.__d=!0)&&Qp.push(e)&&!gh.__r++||Zp!==Jp.debounceRendering)&&((Zp=Jp.debounceRendering)||eh)(gh)}function gh(){var e,t,n,r=[],o=[];for(Qp.sort(th);e=Qp.shift();)e.__d&&(n=Qp.length,t=hh(e,r,o)||t,0===n||Qp.length>n?(Eh(r,t,o),o.length=r.length=0,t=void 0,Qp.sort(th)):t&&Jp.__c&&Jp.__c(t,rh));t&&Eh(r,t,o),gh.__r=0}function _h(e,t,n,r,o,s,i,a,c,u,l){var d,p,h,f,m,g=r&&r.__k||rh,_=t.length;for(n.__d=c,function(e,t,n){var r,o,s,i,a,c=t.length,u=n.length,l=u,d=0;for(e.__k=[],r=0;r<c;r++)null!=(o=e.__k[r]=null==(o=t[r])||"boolean"==typeof o||"function"==typeof o?null:"string"==typeof o||"number"==typeof o||"bigint"==typeof o||o.constructor==String?uh(null,o,null,null,o):sh(o)?uh(lh,{children:o},null,null,null):void 0===o.constructor&&o.__b>0?uh(o.type,o.props,o.key,o.ref?o.ref:null,o.__v):o)?(o.__=e,o.__b=e.__b+1,a=vh(o,n,i=r+d,l),o.__i=a,s=null,-1!==a&&(l--,(s=n[a])&&(s.__u|=131072)),null==s||null===s.__v?(-1==a&&d--,"function"!=typeof o.type&&(o.__u|=65536)):a!==i&&(a===i+1?d++:a>i?l>c-i?d+=a-i:d--:d=a<i&&a==i-1?a-i:0,a!==r+d&&(o.__u|=65536))):(s=n[r])&&null==s.key&&s.__e&&(s.__e==e.__d&&(e.__d=ph(s)),Ih(s,s,!1),n[r]=null,l--);if(l)for(r=0;r<u;r++)null!=(s=n[r])&&!(131072&s.__u)&&(s.__e==e.__d&&(e.__d=ph(s)),Ih(s,s))}(n,t,g),c=n.__d,d=0;d<_;d++)null!=(h=n.__k[d])&&"boolean"!=typeof h&&"function"!=typeof h&&(p=-1===h.__i?nh:g[h.__i]||nh,h.__i=d,xh(e,h,p,o,s,i,a,c,u,l),f=h.__e,h.ref&&p.ref!=h.ref&&(p.ref&&Th(p.ref,null,h),l.push(h.ref,h.__c||f,h)),null==m&&null!=f&&(m=f),65536&h.__u||p.__k===h.__k?c=yh(h,c,e):"function"==typeof h.type&&void 0!==h.__d?c=h.__d:f&&(c=f.nextSibling),h.__d=void 0,h.__u&=-196609);n.__d=c,n.__e=m}function yh(e,t,n){var r,o;if("function"==typeof e.type){for(r=e.__k,o=0;r&&o<r.length;o++)r[o]&&(r[o].__=e,t=yh(r[o],t,n));return t}e.__e!=t&&(n.insertBefore(e.__e,t||null),t=e.__e);do{t=t&&t.nextSibling}while(null!=t&&8===t.nodeType);return t}function vh(e,t,n,r){var o=e.key,s=e.type,i=n-1,a=n+1,c=t[n];if(null===c||c&&o==c.key&&s===c.type)return n;if(r>(null==c||131072&c.__u?0:1))for(;i>=0||a<t.length;){if(i>=0){if((c=t[i])&&!(131072&c.__u)&&o==c.key&&s===c.type)return i;i--}if(a<t.length){if((c=t[a])&&!(131072&c.__u)&&o==c.key&&s===c.type)return a;a++}}return-1}function bh(e,t,n){"-"===t[0]?e.setProperty(t,null==n?"":n):e[t]=null==n?"":"number"!=typeof n||oh.test(t)?n:n+"px"}function Sh(e,t,n,r,o){var s;e:if("style"===t)if("string"==typeof n)e.style.cssText=n;else{if("string"==typeof r&&(e.style.cssText=r=""),r)for(t in r)n&&t in n||bh(e.style,t,"");if(n)for(t in n)r&&n[t]===r[t]||bh(e.style,t,n[t])}else if("o"===t[0]&&"n"===t[1])s=t!==(t=t.replace(/(PointerCapture)$|Capture$/i,"$1")),t=t.toLowerCase()in e?t.toLowerCase().slice(2):t.slice(2),e.l||(e.l={}),e.l[t+s]=n,n?r?n.u=r.u:(n.u=Date.now(),e.addEventListener(t,s?kh:wh,s)):e.removeEventListener(t,s?kh:wh,s);else{if(o)t=t.replace(/xlink(H|:h)/,"h").replace(/sName$/,"s");else if("width"!==t&&"height"!==t&&"href"!==t&&"list"!==t&&"form"!==t&&"tabIndex"!==t&&"download"!==t&&"rowSpan"!==t&&"colSpan"!==t&&"role"!==t&&t in e)try{e[t]=null==n?"":n;break e}catch(e){}"function"==typeof n||(null==n||!1===n&&"-"!==t[4]?e.removeAttribute(t):e.setAttribute(t,n))}}function wh(e){if(this.l){var t=this.l[e.type+!1];if(e.t){if(e.t<=t.u)return}else e.t=Date.now();return t(Jp.event?Jp.event(e):e)}}function kh(e){if(this.l)return this.l[e.type+!0](Jp.event?Jp.event(e):e)}function xh(e,t,n,r,o,s,i,a,c,u){var l,d,p,h,f,m,g,_,y,v,b,S,w,k,x,E=t.type;if(void 0!==t.constructor)return null;128&n.__u&&(c=!!(32&n.__u),s=[a=t.__e=n.__e]),(l=Jp.__b)&&l(t);e:if("function"==typeof E)try{if(_=t.props,y=(l=E.contextType)&&r[l.__c],v=l?y?y.props.value:l.__:r,n.__c?g=(d=t.__c=n.__c).__=d.__E:("prototype"in E&&E.prototype.render?t.__c=d=new E(_,v):(t.__c=d=new dh(_,v),d.constructor=E,d.render=Mh),y&&y.sub(d),d.props=_,d.state||(d.state={}),d.context=v,d.__n=r,p=d.__d=!0,d.__h=[],d._sb=[]),null==d.__s&&(d.__s=d.state),null!=E.getDerivedStateFromProps&&(d.__s==d.state&&(d.__s=ih({},d.__s)),ih(d.__s,E.getDerivedStateFromProps(_,d.__s))),h=d.props,f=d.state,d.__v=t,p)null==E.getDerivedStateFromProps&&null!=d.componentWillMount&&d.componentWillMount(),null!=d.componentDidMount&&d.__h.push(d.componentDidMount);else{if(null==E.getDerivedStateFromProps&&_!==h&&null!=d.componentWillReceiveProps&&d.componentWillReceiveProps(_,v),!d.__e&&(null!=d.shouldComponentUpdate&&!1===d.shouldComponentUpdate(_,d.__s,v)||t.__v===n.__v)){for(t.__v!==n.__v&&(d.props=_,d.state=d.__s,d.__d=!1),t.__e=n.__e,t.__k=n.__k,t.__k.forEach((function(e){e&&(e.__=t)})),b=0;b<d._sb.length;b++)d.__h.push(d._sb[b]);d._sb=[],d.__h.length&&i.push(d);break e}null!=d.componentWillUpdate&&d.componentWillUpdate(_,d.__s,v),null!=d.componentDidUpdate&&d.__h.push((function(){d.componentDidUpdate(h,f,m)}))}if(d.context=v,d.props=_,d.__P=e,d.__e=!1,S=Jp.__r,w=0,"prototype"in E&&E.prototype.render){for(d.state=d.__s,d.__d=!1,S&&S(t),l=d.render(d.props,d.state,d.context),k=0;k<d._sb.length;k++)d.__h.push(d._sb[k]);d._sb=[]}else do{d.__d=!1,S&&S(t),l=d.render(d.props,d.state,d.context),d.state=d.__s}while(d.__d&&++w<25);d.state=d.__s,null!=d.getChildContext&&(r=ih(ih({},r),d.getChildContext())),p||null==d.getSnapshotBeforeUpdate||(m=d.getSnapshotBeforeUpdate(h,f)),_h(e,sh(x=null!=l&&l.type===lh&&null==l.key?l.props.children:l)?x:[x],t,n,r,o,s,i,a,c,u),d.base=t.__e,t.__u&=-161,d.__h.length&&i.push(d),g&&(d.__E=d.__=null)}catch(e){t.__v=null,c||null!=s?(t.__e=a,t.__u|=c?160:32,s[s.indexOf(a)]=null):(t.__e=n.__e,t.__k=n.__k),Jp.__e(e,t,n)}else null==s&&t.__v===n.__v?(t.__k=n.__k,t.__e=n.__e):t.__e=Ch(n.__e,t,n,r,o,s,i,c,u);(l=Jp.diffed)&&l(t)}function Eh(e,t,n){for(var r=0;r<n.length;r++)Th(n[r],n[++r],n[++r]);Jp.__c&&Jp.__c(t,e),e.some((function(t){try{e=t.__h,t.__h=[],e.some((function(e){e.call(t)}))}catch(e){Jp.__e(e,t.__v)}}))}function Ch(e,t,n,r,o,s,i,a,c){var u,l,d,p,h,f,m,g=n.props,_=t.props,y=t.type;if("svg"===y&&(o=!0),null!=s)for(u=0;u<s.length;u++)if((h=s[u])&&"setAttribute"in h==!!y&&(y?h.localName===y:3===h.nodeType)){e=h,s[u]=null;break}if(null==e){if(null===y)return document.createTextNode(_);e=o?document.createElementNS("http://www.w3.org/2000/svg",y):document.createElement(y,_.is&&_),s=null,a=!1}if(null===y)g===_||a&&e.data===_||(e.data=_);else{if(s=s&&Xp.call(e.childNodes),g=n.props||nh,!a&&null!=s)for(g={},u=0;u<e.attributes.length;u++)g[(h=e.attributes[u]).name]=h.value;for(u in g)h=g[u],"children"==u||("dangerouslySetInnerHTML"==u?d=h:"key"===u||u in _||Sh(e,u,null,h,o));for(u in _)h=_[u],"children"==u?p=h:"dangerouslySetInnerHTML"==u?l=h:"value"==u?f=h:"checked"==u?m=h:"key"===u||a&&"function"!=typeof h||g[u]===h||Sh(e,u,h,g[u],o);if(l)a||d&&(l.__html===d.__html||l.__html===e.innerHTML)||(e.innerHTML=l.__html),t.__k=[];else if(d&&(e.innerHTML=""),_h(e,sh(p)?p:[p],t,n,r,o&&"foreignObject"!==y,s,i,s?s[0]:n.__k&&ph(n,0),a,c),null!=s)for(u=s.length;u--;)null!=s[u]&&ah(s[u]);a||(u="value",void 0!==f&&(f!==e[u]||"progress"===y&&!f||"option"===y&&f!==g[u])&&Sh(e,u,f,g[u],!1),u="checked",void 0!==m&&m!==e[u]&&Sh(e,u,m,g[u],!1))}return e}function Th(e,t,n){try{"function"==typeof e?e(t):e.current=t}catch(e){Jp.__e(e,n)}}function Ih(e,t,n){var r,o;if(Jp.unmount&&Jp.unmount(e),(r=e.ref)&&(r.current&&r.current!==e.__e||Th(r,null,t)),null!=(r=e.__c)){if(r.componentWillUnmount)try{r.componentWillUnmount()}catch(e){Jp.__e(e,t)}r.base=r.__P=null,e.__c=void 0}if(r=e.__k)for(o=0;o<r.length;o++)r[o]&&Ih(r[o],t,n||"function"!=typeof e.type);n||null==e.__e||ah(e.__e),e.__=e.__e=e.__d=void 0}function Mh(e,t,n){return this.constructor(e,n)}Xp=rh.slice,Jp={__e:function(e,t,n,r){for(var o,s,i;t=t.__;)if((o=t.__c)&&!o.__)try{if((s=o.constructor)&&null!=s.getDerivedStateFromError&&(o.setState(s.getDerivedStateFromError(e)),i=o.__d),null!=o.componentDidCatch&&(o.componentDidCatch(e,r||{}),i=o.__d),i)return o.__E=o}catch(t){e=t}throw e}},Kp=0,dh.prototype.setState=function(e,t){var n;n=null!=this.__s&&this.__s!==this.state?this.__s:this.__s=ih({},this.state),"function"==typeof e&&(e=e(ih({},n),this.props)),e&&ih(n,e),null!=e&&this.__v&&(t&&this._sb.push(t),mh(this))},dh.prototype.forceUpdate=function(e){this.__v&&(this.__e=!0,e&&this.__h.push(e),mh(this))},dh.prototype.render=lh,Qp=[],eh="function"==typeof Promise?Promise.prototype.then.bind(Promise.resolve()):setTimeout,th=function(e,t){return e.__v.__b-t.__v.__b},gh.__r=0;var Rh,Ah,Nh,Oh,Dh=0,Lh=[],Ph=[],Fh=Jp,$h=Fh.__b,Bh=Fh.__r,Uh=Fh.diffed,jh=Fh.__c,Hh=Fh.unmount,zh=Fh.__;function qh(e,t){Fh.__h&&Fh.__h(Ah,e,Dh||t),Dh=0;var n=Ah.__H||(Ah.__H={__:[],__h:[]});return e>=n.__.length&&n.__.push({__V:Ph}),n.__[e]}function Wh(e){return Dh=1,Yh(nf,e)}function Yh(e,t,n){var r=qh(Rh++,2);if(r.t=e,!r.__c&&(r.__=[n?n(t):nf(void 0,t),function(e){var t=r.__N?r.__N[0]:r.__[0],n=r.t(t,e);t!==n&&(r.__N=[n,r.__[1]],r.__c.setState({}))}],r.__c=Ah,!Ah.u)){var o=function(e,t,n){if(!r.__c.__H)return!0;var o=r.__c.__H.__.filter((function(e){return!!e.__c}));if(o.every((function(e){return!e.__N})))return!s||s.call(this,e,t,n);var i=!1;return o.forEach((function(e){if(e.__N){var t=e.__[0];e.__=e.__N,e.__N=void 0,t!==e.__[0]&&(i=!0)}})),!(!i&&r.__c.props===e)&&(!s||s.call(this,e,t,n))};Ah.u=!0;var s=Ah.shouldComponentUpdate,i=Ah.componentWillUpdate;Ah.componentWillUpdate=function(e,t,n){if(this.__e){var r=s;s=void 0,o(e,t,n),s=r}i&&i.call(this,e,t,n)},Ah.shouldComponentUpdate=o}return r.__N||r.__}function Vh(e,t){var n=qh(Rh++,4);!Fh.__s&&tf(n.__H,t)&&(n.__=e,n.i=t,Ah.__h.push(n))}function Gh(e,t){var n=qh(Rh++,7);return tf(n.__H,t)?(n.__V=e(),n.i=t,n.__h=e,n.__V):n.__}function Xh(e,t){return Dh=8,Gh((function(){return e}),t)}function Jh(){for(var e;e=Lh.shift();)if(e.__P&&e.__H)try{e.__H.__h.forEach(Zh),e.__H.__h.forEach(ef),e.__H.__h=[]}catch(t){e.__H.__h=[],Fh.__e(t,e.__v)}}Fh.__b=function(e){Ah=null,$h&&$h(e)},Fh.__=function(e,t){t.__k&&t.__k.__m&&(e.__m=t.__k.__m),zh&&zh(e,t)},Fh.__r=function(e){Bh&&Bh(e),Rh=0;var t=(Ah=e.__c).__H;t&&(Nh===Ah?(t.__h=[],Ah.__h=[],t.__.forEach((function(e){e.__N&&(e.__=e.__N),e.__V=Ph,e.__N=e.i=void 0}))):(t.__h.forEach(Zh),t.__h.forEach(ef),t.__h=[],Rh=0)),Nh=Ah},Fh.diffed=function(e){Uh&&Uh(e);var t=e.__c;t&&t.__H&&(t.__H.__h.length&&(1!==Lh.push(t)&&Oh===Fh.requestAnimationFrame||((Oh=Fh.requestAnimationFrame)||Qh)(Jh)),t.__H.__.forEach((function(e){e.i&&(e.__H=e.i),e.__V!==Ph&&(e.__=e.__V),e.i=void 0,e.__V=Ph}))),Nh=Ah=null},Fh.__c=function(e,t){t.some((function(e){try{e.__h.forEach(Zh),e.__h=e.__h.filter((function(e){return!e.__||ef(e)}))}catch(n){t.some((function(e){e.__h&&(e.__h=[])})),t=[],Fh.__e(n,e.__v)}})),jh&&jh(e,t)},Fh.unmount=function(e){Hh&&Hh(e);var t,n=e.__c;n&&n.__H&&(n.__H.__.forEach((function(e){try{Zh(e)}catch(e){t=e}})),n.__H=void 0,t&&Fh.__e(t,n.__v))};var Kh="function"==typeof requestAnimationFrame;function Qh(e){var t,n=function(){clearTimeout(r),Kh&&cancelAnimationFrame(t),setTimeout(e)},r=setTimeout(n,100);Kh&&(t=requestAnimationFrame(n))}function Zh(e){var t=Ah,n=e.__c;"function"==typeof n&&(e.__c=void 0,n()),Ah=t}function ef(e){var t=Ah;e.__c=e.__(),Ah=t}function tf(e,t){return!e||e.length!==t.length||t.some((function(t,n){return t!==e[n]}))}function nf(e,t){return"function"==typeof t?t(e):t}const rf={__proto__:null,useCallback:Xh,useContext:function(e){var t=Ah.context[e.__c],n=qh(Rh++,9);return n.c=e,t?(null==n.__&&(n.__=!0,t.sub(Ah)),t.props.value):e.__},useDebugValue:function(e,t){Fh.useDebugValue&&Fh.useDebugValue(t?t(e):e)},useEffect:function(e,t){var n=qh(Rh++,3);!Fh.__s&&tf(n.__H,t)&&(n.__=e,n.i=t,Ah.__H.__h.push(n))},useErrorBoundary:function(e){var t=qh(Rh++,10),n=Wh();return t.__=e,Ah.componentDidCatch||(Ah.componentDidCatch=function(e,r){t.__&&t.__(e,r),n[1](e)}),[n[0],function(){n[1](void 0)}]},useId:function(){var e=qh(Rh++,11);if(!e.__){for(var t=Ah.__v;null!==t&&!t.__m&&null!==t.__;)t=t.__;var n=t.__m||(t.__m=[0,0]);e.__="P"+n[0]+"-"+n[1]++}return e.__},useImperativeHandle:function(e,t,n){Dh=6,Vh((function(){return"function"==typeof e?(e(t()),function(){return e(null)}):e?(e.current=t(),function(){return e.current=null}):void 0}),null==n?n:n.concat(e))},useLayoutEffect:Vh,useMemo:Gh,useReducer:Yh,useRef:function(e){return Dh=5,Gh((function(){return{current:e}}),[])},useState:Wh},of="http://www.w3.org/2000/svg";function sf(){const e=e=>Op.createElementNS(of,e),t=Up(e("svg"),{width:"32",height:"30",viewBox:"0 0 72 66",fill:"inherit"}),n=Up(e("path"),{transform:"translate(11, 11)",d:"M29,2.26a4.67,4.67,0,0,0-8,0L14.42,13.53A32.21,32.21,0,0,1,32.17,40.19H27.55A27.68,27.68,0,0,0,12.09,17.47L6,28a15.92,15.92,0,0,1,9.23,12.17H4.62A.76.76,0,0,1,4,39.06l2.94-5a10.74,10.74,0,0,0-3.36-1.9l-2.91,5a4.54,4.54,0,0,0,1.69,6.24A4.66,4.66,0,0,0,4.62,44H19.15a19.4,19.4,0,0,0-8-17.31l2.31-4A23.87,23.87,0,0,1,23.76,44H36.07a35.88,35.88,0,0,0-16.41-31.8l4.67-8a.77.77,0,0,1,1.05-.27c.53.29,20.29,34.77,20.66,35.17a.76.76,0,0,1-.68,1.13H40.6q.09,1.91,0,3.81h4.78A4.59,4.59,0,0,0,50,39.43a4.49,4.49,0,0,0-.62-2.28Z"});return t.appendChild(n),t}const af="/home/runner/work/sentry-javascript/sentry-javascript/packages/feedback/src/modal/components/DialogHeader.tsx";function cf({options:e}){const t=Gh((()=>({__html:sf().outerHTML})),[]);return ch("h2",{class:"dialog__header",__self:this,__source:{fileName:af,lineNumber:16}},e.formTitle,e.showBranding?ch("a",{class:"brand-link",target:"_blank",href:"https://sentry.io/welcome/",title:"Powered by Sentry",rel:"noopener noreferrer",dangerouslySetInnerHTML:t,__self:this,__source:{fileName:af,lineNumber:19}}):null)}const uf="/home/runner/work/sentry-javascript/sentry-javascript/packages/feedback/src/modal/components/Form.tsx";function lf(e,t){const n=e.get(t);return"string"==typeof n?n.trim():""}function df({options:e,defaultEmail:t,defaultName:n,onFormClose:r,onSubmit:o,onSubmitSuccess:s,onSubmitError:i,showEmail:a,showName:c,screenshotInput:u}){const{tags:l,addScreenshotButtonLabel:d,removeScreenshotButtonLabel:p,cancelButtonLabel:h,emailLabel:f,emailPlaceholder:m,isEmailRequired:g,isNameRequired:_,messageLabel:y,messagePlaceholder:v,nameLabel:b,namePlaceholder:S,submitButtonLabel:w,isRequiredLabel:k}=e,[x,E]=Wh(null),[C,T]=Wh(!1),I=u&&u.input,[M,R]=Wh(null),A=Xh((e=>{R(e),T(!1)}),[]),N=Xh((e=>{const t=function(e,t){const n=[];return t.isNameRequired&&!e.name&&n.push(t.nameLabel),t.isEmailRequired&&!e.email&&n.push(t.emailLabel),e.message||n.push(t.messageLabel),n}(e,{emailLabel:f,isEmailRequired:g,isNameRequired:_,messageLabel:y,nameLabel:b});return t.length>0?E(`Please enter in the following required fields: ${t.join(", ")}`):E(null),0===t.length}),[f,g,_,y,b]),O=Xh((async e=>{try{if(e.preventDefault(),!(e.target instanceof HTMLFormElement))return;const t=new FormData(e.target),n=await(u&&C?u.value():void 0),r={name:lf(t,"name"),email:lf(t,"email"),message:lf(t,"message"),attachments:n?[n]:void 0};if(!N(r))return;try{await o({name:r.name,email:r.email,message:r.message,source:Pp,tags:l},{attachments:r.attachments}),s(r)}catch(e){$p&&L.error(e),E(e),i(e)}}catch(e){}}),[u&&C,s,i]);return ch("form",{class:"form",onSubmit:O,__self:this,__source:{fileName:uf,lineNumber:144}},I&&C?ch(I,{onError:A,__self:this,__source:{fileName:uf,lineNumber:146}}):null,ch("div",{class:"form__right","data-sentry-feedback":!0,__self:this,__source:{fileName:uf,lineNumber:149}},ch("div",{class:"form__top",__self:this,__source:{fileName:uf,lineNumber:150}},x?ch("div",{class:"form__error-container",__self:this,__source:{fileName:uf,lineNumber:151}},x):null,c?ch("label",{for:"name",class:"form__label",__self:this,__source:{fileName:uf,lineNumber:154}},ch(pf,{label:b,isRequiredLabel:k,isRequired:_,__self:this,__source:{fileName:uf,lineNumber:155}}),ch("input",{class:"form__input",defaultValue:n,id:"name",name:"name",placeholder:S,required:_,type:"text",__self:this,__source:{fileName:uf,lineNumber:156}})):ch("input",{"aria-hidden":!0,value:n,name:"name",type:"hidden",__self:this,__source:{fileName:uf,lineNumber:167}}),a?ch("label",{for:"email",class:"form__label",__self:this,__source:{fileName:uf,lineNumber:171}},ch(pf,{label:f,isRequiredLabel:k,isRequired:g,__self:this,__source:{fileName:uf,lineNumber:172}}),ch("input",{class:"form__input",defaultValue:t,id:"email",name:"email",placeholder:m,required:g,type:"email",__self:this,__source:{fileName:uf,lineNumber:173}})):ch("input",{"aria-hidden":!0,value:t,name:"email",type:"hidden",__self:this,__source:{fileName:uf,lineNumber:184}}),ch("label",{for:"message",class:"form__label",__self:this,__source:{fileName:uf,lineNumber:187}},ch(pf,{label:y,isRequiredLabel:k,isRequired:!0,__self:this,__source:{fileName:uf,lineNumber:188}}),ch("textarea",{autoFocus:!0,class:"form__input form__input--textarea",id:"message",name:"message",placeholder:v,required:!0,rows:5,__self:this,__source:{fileName:uf,lineNumber:189}})),I?ch("label",{for:"screenshot",class:"form__label",__self:this,__source:{fileName:uf,lineNumber:201}},ch("button",{class:"btn btn--default",type:"button",onClick:()=>{R(null),T((e=>!e))},__self:this,__source:{fileName:uf,lineNumber:202}},C?p:d),M?ch("div",{class:"form__error-container",__self:this,__source:{fileName:uf,lineNumber:212}},M.message):null):null),ch("div",{class:"btn-group",__self:this,__source:{fileName:uf,lineNumber:216}},ch("button",{class:"btn btn--primary",type:"submit",__self:this,__source:{fileName:uf,lineNumber:217}},w),ch("button",{class:"btn btn--default",type:"button",onClick:r,__self:this,__source:{fileName:uf,lineNumber:220}},h))))}function pf({label:e,isRequired:t,isRequiredLabel:n}){return ch("span",{class:"form__label__text",__self:this,__source:{fileName:uf,lineNumber:239}},e,t&&ch("span",{class:"form__label__text--required",__self:this,__source:{fileName:uf,lineNumber:241}},n))}const hf=16,ff=17,mf="http://www.w3.org/2000/svg";function gf(){const e=e=>Np.document.createElementNS(mf,e),t=Up(e("svg"),{width:`${hf}`,height:`${ff}`,viewBox:`0 0 ${hf} ${ff}`,fill:"inherit"}),n=Up(e("g"),{clipPath:"url(#clip0_57_156)"}),r=Up(e("path"),{"fill-rule":"evenodd","clip-rule":"evenodd",d:"M3.55544 15.1518C4.87103 16.0308 6.41775 16.5 8 16.5C10.1217 16.5 12.1566 15.6571 13.6569 14.1569C15.1571 12.6566 16 10.6217 16 8.5C16 6.91775 15.5308 5.37103 14.6518 4.05544C13.7727 2.73985 12.5233 1.71447 11.0615 1.10897C9.59966 0.503466 7.99113 0.34504 6.43928 0.653721C4.88743 0.962403 3.46197 1.72433 2.34315 2.84315C1.22433 3.96197 0.462403 5.38743 0.153721 6.93928C-0.15496 8.49113 0.00346625 10.0997 0.608967 11.5615C1.21447 13.0233 2.23985 14.2727 3.55544 15.1518ZM4.40546 3.1204C5.46945 2.40946 6.72036 2.03 8 2.03C9.71595 2.03 11.3616 2.71166 12.575 3.92502C13.7883 5.13838 14.47 6.78405 14.47 8.5C14.47 9.77965 14.0905 11.0306 13.3796 12.0945C12.6687 13.1585 11.6582 13.9878 10.476 14.4775C9.29373 14.9672 7.99283 15.0953 6.73777 14.8457C5.48271 14.596 4.32987 13.9798 3.42502 13.075C2.52018 12.1701 1.90397 11.0173 1.65432 9.76224C1.40468 8.50718 1.5328 7.20628 2.0225 6.02404C2.5122 4.8418 3.34148 3.83133 4.40546 3.1204Z"}),o=Up(e("path"),{d:"M6.68775 12.4297C6.78586 12.4745 6.89218 12.4984 7 12.5C7.11275 12.4955 7.22315 12.4664 7.32337 12.4145C7.4236 12.3627 7.51121 12.2894 7.58 12.2L12 5.63999C12.0848 5.47724 12.1071 5.28902 12.0625 5.11098C12.0178 4.93294 11.9095 4.77744 11.7579 4.67392C11.6064 4.57041 11.4221 4.52608 11.24 4.54931C11.0579 4.57254 10.8907 4.66173 10.77 4.79999L6.88 10.57L5.13 8.56999C5.06508 8.49566 4.98613 8.43488 4.89768 8.39111C4.80922 8.34735 4.713 8.32148 4.61453 8.31498C4.51605 8.30847 4.41727 8.32147 4.32382 8.35322C4.23038 8.38497 4.14413 8.43484 4.07 8.49999C3.92511 8.63217 3.83692 8.81523 3.82387 9.01092C3.81083 9.2066 3.87393 9.39976 4 9.54999L6.43 12.24C6.50187 12.3204 6.58964 12.385 6.68775 12.4297Z"});t.appendChild(n).append(o,r);const s=e("defs"),i=Up(e("clipPath"),{id:"clip0_57_156"}),a=Up(e("rect"),{width:`${hf}`,height:`${hf}`,fill:"white",transform:"translate(0 0.5)"});return i.appendChild(a),s.appendChild(i),t.appendChild(s).appendChild(i).appendChild(a),t}const _f="/home/runner/work/sentry-javascript/sentry-javascript/packages/feedback/src/modal/components/Dialog.tsx";function yf({open:e,onFormSubmitted:t,...n}){const r=n.options,o=Gh((()=>({__html:gf().outerHTML})),[]),[s,i]=Wh(null),a=Xh((()=>{s&&(clearTimeout(s),i(null)),t()}),[s]),c=Xh((e=>{n.onSubmitSuccess(e),i(setTimeout((()=>{t(),i(null)}),5e3))}),[t]);return ch(lh,{__self:this,__source:{fileName:_f,lineNumber:48}},s?ch("div",{class:"success__position",onClick:a,__self:this,__source:{fileName:_f,lineNumber:50}},ch("div",{class:"success__content",__self:this,__source:{fileName:_f,lineNumber:51}},r.successMessageText,ch("span",{class:"success__icon",dangerouslySetInnerHTML:o,__self:this,__source:{fileName:_f,lineNumber:53}}))):ch("dialog",{class:"dialog",onClick:r.onFormClose,open:e,__self:this,__source:{fileName:_f,lineNumber:57}},ch("div",{class:"dialog__position",__self:this,__source:{fileName:_f,lineNumber:58}},ch("div",{class:"dialog__content",onClick:e=>{e.stopPropagation()},__self:this,__source:{fileName:_f,lineNumber:59}},ch(cf,{options:r,__self:this,__source:{fileName:_f,lineNumber:66}}),ch(df,{...n,onSubmitSuccess:c,__self:this,__source:{fileName:_f,lineNumber:67}})))))}const vf=()=>({name:"FeedbackModal",setupOnce(){},createDialog:({options:e,screenshotIntegration:t,sendFeedback:n,shadow:r})=>{const o=r,s=e.useSentryUser,i=function(){const e=Gt().getUser(),t=Xt().getUser(),n=Jt().getUser();return e&&Object.keys(e).length?e:t&&Object.keys(t).length?t:n}(),a=Op.createElement("div"),c=function(e){const t=Op.createElement("style");return t.textContent="\n:host {\n  --dialog-inset: var(--inset);\n}\n\n\n.dialog {\n  position: fixed;\n  z-index: var(--z-index);\n  margin: 0;\n  inset: 0;\n\n  display: flex;\n  align-items: center;\n  justify-content: center;\n  padding: 0;\n  height: 100vh;\n  width: 100vw;\n\n  color: var(--dialog-color, var(--foreground));\n  fill: var(--dialog-color, var(--foreground));\n  line-height: 1.75em;\n\n  background-color: rgba(0, 0, 0, 0.05);\n  border: none;\n  inset: 0;\n  opacity: 1;\n  transition: opacity 0.2s ease-in-out;\n}\n\n.dialog__position {\n  position: fixed;\n  z-index: var(--z-index);\n  inset: var(--dialog-inset);\n  padding: var(--page-margin);\n  display: flex;\n  max-height: calc(100vh - (2 * var(--page-margin)));\n}\n@media (max-width: 600px) {\n  .dialog__position {\n    inset: var(--page-margin);\n    padding: 0;\n  }\n}\n\n.dialog__position:has(.editor) {\n  inset: var(--page-margin);\n  padding: 0;\n}\n\n.dialog:not([open]) {\n  opacity: 0;\n  pointer-events: none;\n  visibility: hidden;\n}\n.dialog:not([open]) .dialog__content {\n  transform: translate(0, -16px) scale(0.98);\n}\n\n.dialog__content {\n  display: flex;\n  flex-direction: column;\n  gap: 16px;\n  padding: var(--dialog-padding, 24px);\n  max-width: 100%;\n  width: 100%;\n  max-height: 100%;\n  overflow: auto;\n\n  background: var(--dialog-background, var(--background));\n  border-radius: var(--dialog-border-radius, 20px);\n  border: var(--dialog-border, var(--border));\n  box-shadow: var(--dialog-box-shadow, var(--box-shadow));\n  transform: translate(0, 0) scale(1);\n  transition: transform 0.2s ease-in-out;\n}\n\n\n.dialog__header {\n  display: flex;\n  align-items: center;\n  justify-content: space-between;\n  font-weight: var(--dialog-header-weight, 600);\n  margin: 0;\n}\n\n.brand-link {\n  display: inline-flex;\n}\n.brand-link:focus-visible {\n  outline: var(--outline);\n}\n\n\n.form {\n  display: flex;\n  overflow: auto;\n  flex-direction: row;\n  gap: 16px;\n  flex: 1 0;\n}\n\n.form__right {\n  flex: 0 0 var(--form-width, 272px);\n  width: var(--form-width, 272px);\n  display: flex;\n  overflow: auto;\n  flex-direction: column;\n  justify-content: space-between;\n  gap: 20px;\n}\n\n@media (max-width: 600px) {\n  .form__right {\n    width: auto;\n  }\n}\n\n.form__top {\n  display: flex;\n  flex-direction: column;\n  gap: 8px;\n}\n\n.form__error-container {\n  color: var(--error-color);\n  fill: var(--error-color);\n}\n\n.form__label {\n  display: flex;\n  flex-direction: column;\n  gap: 4px;\n  margin: 0px;\n}\n\n.form__label__text {\n  display: flex;\n  gap: 4px;\n  align-items: center;\n}\n\n.form__label__text--required {\n  font-size: 0.85em;\n}\n\n.form__input {\n  font-family: inherit;\n  line-height: inherit;\n  background: transparent;\n  box-sizing: border-box;\n  border: var(--input-border, var(--border));\n  border-radius: var(--input-border-radius, 6px);\n  color: var(--input-color, inherit);\n  fill: var(--input-color, inherit);\n  font-size: var(--input-font-size, inherit);\n  font-weight: var(--input-font-weight, 500);\n  padding: 6px 12px;\n}\n\n.form__input::placeholder {\n  opacity: 0.65;\n  color: var(--input-placeholder-color, inherit);\n  filter: var(--interactive-filter);\n}\n\n.form__input:focus-visible {\n  outline: var(--input-focus-outline, var(--outline));\n}\n\n.form__input--textarea {\n  font-family: inherit;\n  resize: vertical;\n}\n\n.error {\n  color: var(--error-color);\n  fill: var(--error-color);\n}\n\n\n.btn-group {\n  display: grid;\n  gap: 8px;\n}\n\n.btn {\n  line-height: inherit;\n  border: var(--button-border, var(--border));\n  border-radius: var(--button-border-radius, 6px);\n  cursor: pointer;\n  font-family: inherit;\n  font-size: var(--button-font-size, inherit);\n  font-weight: var(--button-font-weight, 600);\n  padding: var(--button-padding, 6px 16px);\n}\n.btn[disabled] {\n  opacity: 0.6;\n  pointer-events: none;\n}\n\n.btn--primary {\n  color: var(--button-primary-color, var(--accent-foreground));\n  fill: var(--button-primary-color, var(--accent-foreground));\n  background: var(--button-primary-background, var(--accent-background));\n  border: var(--button-primary-border, var(--border));\n  border-radius: var(--button-primary-border-radius, 6px);\n  font-weight: var(--button-primary-font-weight, 500);\n}\n.btn--primary:hover {\n  color: var(--button-primary-hover-color, var(--accent-foreground));\n  fill: var(--button-primary-hover-color, var(--accent-foreground));\n  background: var(--button-primary-hover-background, var(--accent-background));\n  filter: var(--interactive-filter);\n}\n.btn--primary:focus-visible {\n  background: var(--button-primary-hover-background, var(--accent-background));\n  filter: var(--interactive-filter);\n  outline: var(--button-primary-focus-outline, var(--outline));\n}\n\n.btn--default {\n  color: var(--button-color, var(--foreground));\n  fill: var(--button-color, var(--foreground));\n  background: var(--button-background, var(--background));\n  border: var(--button-border, var(--border));\n  border-radius: var(--button-border-radius, 6px);\n  font-weight: var(--button-font-weight, 500);\n}\n.btn--default:hover {\n  color: var(--button-color, var(--foreground));\n  fill: var(--button-color, var(--foreground));\n  background: var(--button-hover-background, var(--background));\n  filter: var(--interactive-filter);\n}\n.btn--default:focus-visible {\n  background: var(--button-hover-background, var(--background));\n  filter: var(--interactive-filter);\n  outline: var(--button-focus-outline, var(--outline));\n}\n\n\n.success__position {\n  position: fixed;\n  inset: var(--dialog-inset);\n  padding: var(--page-margin);\n  z-index: var(--z-index);\n}\n.success__content {\n  background: var(--success-background, var(--background));\n  border: var(--success-border, var(--border));\n  border-radius: var(--success-border-radius, 1.7em/50%);\n  box-shadow: var(--success-box-shadow, var(--box-shadow));\n  font-weight: var(--success-font-weight, 600);\n  color: var(--success-color);\n  fill: var(--success-color);\n  padding: 12px 24px;\n  line-height: 1.75em;\n\n  display: grid;\n  align-items: center;\n  grid-auto-flow: column;\n  gap: 6px;\n  cursor: default;\n}\n\n.success__icon {\n  display: flex;\n}\n\n",e&&t.setAttribute("nonce",e),t}(e.styleNonce);let u="";const l={get el(){return a},appendToDom(){o.contains(c)||o.contains(a)||(o.appendChild(c),o.appendChild(a))},removeFromDom(){o.removeChild(a),o.removeChild(c),Op.body.style.overflow=u},open(){p(!0),e.onFormOpen&&e.onFormOpen(),u=Op.body.style.overflow,Op.body.style.overflow="hidden"},close(){p(!1),Op.body.style.overflow=u}},d=t&&t.createInput({h:ch,hooks:rf,dialog:l,options:e}),p=t=>{!function(e,t,n){var r,o,s,i;Jp.__&&Jp.__(e,t),o=(r="function"==typeof n)?null:n&&n.__k||t.__k,s=[],i=[],xh(t,e=(!r&&n||t).__k=ch(lh,null,[e]),o||nh,nh,void 0!==t.ownerSVGElement,!r&&n?[n]:o?null:t.firstChild?Xp.call(t.childNodes):null,s,!r&&n?n:o?o.__e:t.firstChild,r,i),e.__d=void 0,Eh(s,e,i)}(ch(yf,{options:e,screenshotInput:d,showName:e.showName||e.isNameRequired,showEmail:e.showEmail||e.isEmailRequired,defaultName:s&&i&&i[s.name]||"",defaultEmail:s&&i&&i[s.email]||"",onFormClose:()=>{p(!1),e.onFormClose&&e.onFormClose()},onSubmit:n,onSubmitSuccess:t=>{p(!1),e.onSubmitSuccess&&e.onSubmitSuccess(t)},onSubmitError:t=>{e.onSubmitError&&e.onSubmitError(t)},onFormSubmitted:()=>{e.onFormSubmitted&&e.onFormSubmitted()},open:t,__self:void 0,__source:{fileName:"/home/runner/work/sentry-javascript/sentry-javascript/packages/feedback/src/modal/integration.tsx",lineNumber:67}}),a)};return l}}),bf="/home/runner/work/sentry-javascript/sentry-javascript/packages/feedback/src/screenshot/components/CropCorner.tsx";function Sf(e){const t=Op.createElement("style"),n="#1A141F",r="#302735";return t.textContent=`\n.editor {\n  padding: 10px;\n  padding-top: 65px;\n  padding-bottom: 65px;\n  flex-grow: 1;\n\n  background-color: ${n};\n  background-image: repeating-linear-gradient(\n      -145deg,\n      transparent,\n      transparent 8px,\n      ${n} 8px,\n      ${n} 11px\n    ),\n    repeating-linear-gradient(\n      -45deg,\n      transparent,\n      transparent 15px,\n      ${r} 15px,\n      ${r} 16px\n    );\n}\n\n.editor__canvas-container {\n  width: 100%;\n  height: 100%;\n  position: relative;\n  display: flex;\n  align-items: center;\n  justify-content: center;\n}\n\n.editor__canvas-container canvas {\n  object-fit: contain;\n  position: relative;\n}\n\n.editor__crop-btn-group {\n  padding: 8px;\n  gap: 8px;\n  border-radius: var(--menu-border-radius, 6px);\n  background: var(--button-primary-background, var(--background));\n  width: 175px;\n  position: absolute;\n}\n\n.editor__crop-corner {\n  width: 30px;\n  height: 30px;\n  position: absolute;\n  background: none;\n  border: 3px solid #ffffff;\n}\n\n.editor__crop-corner--top-left {\n  cursor: nwse-resize;\n  border-right: none;\n  border-bottom: none;\n}\n.editor__crop-corner--top-right {\n  cursor: nesw-resize;\n  border-left: none;\n  border-bottom: none;\n}\n.editor__crop-corner--bottom-left {\n  cursor: nesw-resize;\n  border-right: none;\n  border-top: none;\n}\n.editor__crop-corner--bottom-right {\n  cursor: nwse-resize;\n  border-left: none;\n  border-top: none;\n}\n`,e&&t.setAttribute("nonce",e),t}const wf="/home/runner/work/sentry-javascript/sentry-javascript/packages/feedback/src/screenshot/components/ScreenshotEditor.tsx",kf=33,xf=Np.devicePixelRatio,Ef=e=>({x:Math.min(e.startX,e.endX),y:Math.min(e.startY,e.endY),width:Math.abs(e.startX-e.endX),height:Math.abs(e.startY-e.endY)}),Cf=e=>{const t=e.clientHeight,n=e.clientWidth,r=e.width/e.height;let o=t*r,s=t;o>n&&(o=n,s=n/r);const i=(n-o)/2,a=(t-s)/2;return{startX:i,startY:a,endX:o+i,endY:s+a}};function Tf({h:e,hooks:t,imageBuffer:n,dialog:r,options:o}){const s=function({hooks:e}){return function({onBeforeScreenshot:t,onScreenshot:n,onAfterScreenshot:r,onError:o}){e.useEffect((()=>{(async()=>{t();const e=await Dp.mediaDevices.getDisplayMedia({video:{width:Np.innerWidth*Np.devicePixelRatio,height:Np.innerHeight*Np.devicePixelRatio},audio:!1,monitorTypeSurfaces:"exclude",preferCurrentTab:!0,selfBrowserSurface:"include",surfaceSwitching:"exclude"}),o=Op.createElement("video");await new Promise(((t,r)=>{o.srcObject=e,o.onloadedmetadata=()=>{n(o),e.getTracks().forEach((e=>e.stop())),t()},o.play().catch(r)})),r()})().catch(o)}),[])}}({hooks:t});return function({onError:i}){const a=t.useMemo((()=>({__html:Sf(o.styleNonce).innerText})),[]),c=function({h:e}){return function({top:t,left:n,corner:r,onGrabButton:o}){return e("button",{class:`editor__crop-corner editor__crop-corner--${r} `,style:{top:t,left:n},onMouseDown:e=>{e.preventDefault(),o(e,r)},onClick:e=>{e.preventDefault()},__self:this,__source:{fileName:bf,lineNumber:22}})}}({h:e}),u=t.useRef(null),l=t.useRef(null),d=t.useRef(null),[p,h]=t.useState({startX:0,startY:0,endX:0,endY:0}),[f,m]=t.useState(!1),[g,_]=t.useState(!1);function y(){const e=d.current,t=Ef(Cf(n));if(e){e.width=t.width*xf,e.height=t.height*xf,e.style.width=`${t.width}px`,e.style.height=`${t.height}px`;const n=e.getContext("2d");n&&n.scale(xf,xf)}const r=l.current;r&&(r.style.width=`${t.width}px`,r.style.height=`${t.height}px`),h({startX:0,startY:0,endX:t.width,endY:t.height})}function v(e,t){m(!1),_(!0);const n=b(t),r=()=>{Op.removeEventListener("mousemove",n),Op.removeEventListener("mouseup",r),m(!0),_(!1)};Op.addEventListener("mouseup",r),Op.addEventListener("mousemove",n)}t.useEffect((()=>{Np.addEventListener("resize",y,!1)}),[]),t.useEffect((()=>{const e=d.current;if(!e)return;const t=e.getContext("2d");if(!t)return;const r=Ef(Cf(n)),o=Ef(p);t.clearRect(0,0,r.width,r.height),t.fillStyle="rgba(0, 0, 0, 0.5)",t.fillRect(0,0,r.width,r.height),t.clearRect(o.x,o.y,o.width,o.height),t.strokeStyle="#ffffff",t.lineWidth=3,t.strokeRect(o.x+1,o.y+1,o.width-2,o.height-2),t.strokeStyle="#000000",t.lineWidth=1,t.strokeRect(o.x+3,o.y+3,o.width-6,o.height-6)}),[p]);const b=t.useCallback((e=>function(t){if(!d.current)return;const n=d.current,r=n.getBoundingClientRect(),o=t.clientX-r.x,s=t.clientY-r.y;switch(e){case"top-left":h((e=>({...e,startX:Math.min(Math.max(0,o),e.endX-kf),startY:Math.min(Math.max(0,s),e.endY-kf)})));break;case"top-right":h((e=>({...e,endX:Math.max(Math.min(o,n.width/xf),e.startX+kf),startY:Math.min(Math.max(0,s),e.endY-kf)})));break;case"bottom-left":h((e=>({...e,startX:Math.min(Math.max(0,o),e.endX-kf),endY:Math.max(Math.min(s,n.height/xf),e.startY+kf)})));break;case"bottom-right":h((e=>({...e,endX:Math.max(Math.min(o,n.width/xf),e.startX+kf),endY:Math.max(Math.min(s,n.height/xf),e.startY+kf)})))}}),[]),S=t.useRef({initialX:0,initialY:0});return s({onBeforeScreenshot:t.useCallback((()=>{r.el.style.display="none"}),[]),onScreenshot:t.useCallback((e=>{const t=n.getContext("2d");if(!t)throw new Error("Could not get canvas context");n.width=e.videoWidth,n.height=e.videoHeight,n.style.width="100%",n.style.height="100%",t.drawImage(e,0,0)}),[n]),onAfterScreenshot:t.useCallback((()=>{r.el.style.display="block";const e=u.current;e&&e.appendChild(n),y()}),[]),onError:t.useCallback((e=>{r.el.style.display="block",i(e)}),[])}),e("div",{class:"editor",__self:this,__source:{fileName:wf,lineNumber:315}},e("style",{nonce:o.styleNonce,dangerouslySetInnerHTML:a,__self:this,__source:{fileName:wf,lineNumber:316}}),e("div",{class:"editor__canvas-container",ref:u,__self:this,__source:{fileName:wf,lineNumber:317}},e("div",{class:"editor__crop-container",style:{position:"absolute",zIndex:1},ref:l,__self:this,__source:{fileName:wf,lineNumber:318}},e("canvas",{onMouseDown:function(e){if(g)return;S.current={initialX:e.clientX,initialY:e.clientY};const t=e=>{const t=d.current;if(!t)return;const n=e.clientX-S.current.initialX,r=e.clientY-S.current.initialY;h((o=>{const s=Math.max(0,Math.min(o.startX+n,t.width/xf-(o.endX-o.startX))),i=Math.max(0,Math.min(o.startY+r,t.height/xf-(o.endY-o.startY))),a=s+(o.endX-o.startX),c=i+(o.endY-o.startY);return S.current.initialX=e.clientX,S.current.initialY=e.clientY,{startX:s,startY:i,endX:a,endY:c}}))},n=()=>{Op.removeEventListener("mousemove",t),Op.removeEventListener("mouseup",n)};Op.addEventListener("mousemove",t),Op.addEventListener("mouseup",n)},style:{position:"absolute",cursor:f?"move":"auto"},ref:d,__self:this,__source:{fileName:wf,lineNumber:319}}),e(c,{left:p.startX-3,top:p.startY-3,onGrabButton:v,corner:"top-left",__self:this,__source:{fileName:wf,lineNumber:324}}),e(c,{left:p.endX-30+3,top:p.startY-3,onGrabButton:v,corner:"top-right",__self:this,__source:{fileName:wf,lineNumber:330}}),e(c,{left:p.startX-3,top:p.endY-30+3,onGrabButton:v,corner:"bottom-left",__self:this,__source:{fileName:wf,lineNumber:336}}),e(c,{left:p.endX-30+3,top:p.endY-30+3,onGrabButton:v,corner:"bottom-right",__self:this,__source:{fileName:wf,lineNumber:342}}),e("div",{style:{left:Math.max(0,p.endX-191),top:Math.max(0,p.endY+8),display:f?"flex":"none"},class:"editor__crop-btn-group",__self:this,__source:{fileName:wf,lineNumber:348}},e("button",{onClick:e=>{e.preventDefault(),d.current&&h({startX:0,startY:0,endX:d.current.width/xf,endY:d.current.height/xf}),m(!1)},class:"btn btn--default",__self:this,__source:{fileName:wf,lineNumber:356}},o.cancelButtonLabel),e("button",{onClick:e=>{e.preventDefault(),function(){const e=Op.createElement("canvas"),t=Ef(Cf(n)),r=Ef(p);e.width=r.width*xf,e.height=r.height*xf;const o=e.getContext("2d");o&&n&&o.drawImage(n,r.x/t.width*n.width,r.y/t.height*n.height,r.width/t.width*n.width,r.height/t.height*n.height,0,0,e.width,e.height);const s=n.getContext("2d");s&&(s.clearRect(0,0,n.width,n.height),n.width=e.width,n.height=e.height,n.style.width=`${r.width}px`,n.style.height=`${r.height}px`,s.drawImage(e,0,0),y())}(),m(!1)},class:"btn btn--primary",__self:this,__source:{fileName:wf,lineNumber:373}},o.confirmButtonLabel)))))}}const If=()=>({name:"FeedbackScreenshot",setupOnce(){},createInput:({h:e,hooks:t,dialog:n,options:r})=>{const o=Op.createElement("canvas");return{input:Tf({h:e,hooks:t,imageBuffer:o,dialog:n,options:r}),value:async()=>{const e=await new Promise((e=>{o.toBlob(e,"image/png")}));if(e){return{data:new Uint8Array(await e.arrayBuffer()),filename:"screenshot.png",contentType:"application/png"}}}}}}),Mf=Gp({lazyLoadIntegration:Fa}),Rf=Gp({lazyLoadIntegration:Fa,getModalIntegration:()=>vf,getScreenshotIntegration:()=>If});const Af={increment:function(e,t=1,n){Ho.increment(Xo,e,t,n)},distribution:function(e,t,n){Ho.distribution(Xo,e,t,n)},set:function(e,t,n){Ho.set(Xo,e,t,n)},gauge:function(e,t,n){Ho.gauge(Xo,e,t,n)},timing:function(e,t,n="second",r){return Ho.timing(Xo,e,t,n,r)}},Nf=new WeakMap,Of=new Map,Df={traceFetch:!0,traceXHR:!0,enableHTTPTimings:!0};function Lf(e,t){const{traceFetch:n,traceXHR:r,shouldCreateSpanForRequest:o,enableHTTPTimings:s,tracePropagationTargets:i}={traceFetch:Df.traceFetch,traceXHR:Df.traceXHR,...t},a="function"==typeof o?o:e=>!0,c=e=>function(e,t){const n=Zo.location&&Zo.location.href;if(n){let r,o;try{r=new URL(e,n),o=new URL(n).origin}catch(e){return!1}const s=r.origin===o;return t?_(r.toString(),t)||s&&_(r.pathname,t):s}{const n=!!e.match(/^\/(?!\/)/);return t?_(e,t):n}}(e,i),u={};n&&(e.addEventProcessor((e=>("transaction"===e.type&&e.spans&&e.spans.forEach((e=>{if("http.client"===e.op){const t=Of.get(e.span_id);t&&(e.timestamp=t/1e3,Of.delete(e.span_id))}})),e))),function(e){const t="fetch-body-resolved";ce(t,e),ue(t,(()=>we(ke)))}((e=>{if(e.response){const t=Nf.get(e.response);t&&e.endTimestamp&&Of.set(t,e.endTimestamp)}})),Se((e=>{const t=Jo(e,a,c,u);if(e.response&&e.fetchData.__span&&Nf.set(e.response,e.fetchData.__span),t){const n=$f(e.fetchData.url),r=n?Ze(n).host:void 0;t.setAttributes({"http.url":n,"server.address":r})}s&&t&&Pf(t)}))),r&&Ji((e=>{const t=function(e,t,n,r){const o=e.xhr,s=o&&o[Xi];if(!o||o.__sentry_own_request__||!s)return;const i=Fn()&&t(s.url);if(e.endTimestamp&&i){const e=o.__sentry_xhr_span_id__;if(!e)return;const t=r[e];return void(t&&void 0!==s.status_code&&(fn(t,s.status_code),t.end(),delete r[e]))}const a=$f(s.url),c=a?Ze(a).host:void 0,u=!!Mn(),l=i&&u?Zn({name:`${s.method} ${s.url}`,attributes:{type:"xhr","http.method":s.method,"http.url":a,url:s.url,"server.address":c,[on]:"auto.http.browser",[rn]:"http.client"}}):new $n;o.__sentry_xhr_span_id__=l.spanContext().spanId,r[o.__sentry_xhr_span_id__]=l;const d=Qt();o.setRequestHeader&&n(s.url)&&d&&function(e,t,n){const r=Gt(),o=Xt(),{traceId:s,spanId:i,sampled:a,dsc:c}={...o.getPropagationContext(),...r.getPropagationContext()},u=n&&Fn()?yn(n):dt(s,i,a),l=at(c||(n?qn(n):zn(s,t)));!function(e,t,n){try{e.setRequestHeader("sentry-trace",t),n&&e.setRequestHeader(nt,n)}catch(e){}}(e,u,l)}(o,d,Fn()&&u?l:void 0);return l}(e,a,c,u);s&&t&&Pf(t)}))}function Pf(e){const{url:t}=Sn(e).data||{};if(!t||"string"!=typeof t)return;const n=li("resource",(({entries:r})=>{r.forEach((r=>{if(function(e){return"resource"===e.entryType&&"initiatorType"in e&&"string"==typeof e.nextHopProtocol&&("fetch"===e.initiatorType||"xmlhttprequest"===e.initiatorType)}(r)&&r.name.endsWith(t)){(function(e){const{name:t,version:n}=function(e){let t="unknown",n="unknown",r="";for(const o of e){if("/"===o){[t,n]=e.split("/");break}if(!isNaN(Number(o))){t="h"===r?"http":r,n=e.split(r)[1];break}r+=o}r===e&&(t=r);return{name:t,version:n}}(e.nextHopProtocol),r=[];if(r.push(["network.protocol.version",n],["network.protocol.name",t]),!be)return r;return[...r,["http.request.redirect_start",Ff(e.redirectStart)],["http.request.fetch_start",Ff(e.fetchStart)],["http.request.domain_lookup_start",Ff(e.domainLookupStart)],["http.request.domain_lookup_end",Ff(e.domainLookupEnd)],["http.request.connect_start",Ff(e.connectStart)],["http.request.secure_connection_start",Ff(e.secureConnectionStart)],["http.request.connection_end",Ff(e.connectEnd)],["http.request.request_start",Ff(e.requestStart)],["http.request.response_start",Ff(e.responseStart)],["http.request.response_end",Ff(e.responseEnd)]]})(r).forEach((t=>e.setAttribute(...t))),setTimeout(n)}}))}))}function Ff(e=0){return((be||performance.timeOrigin)+e)/1e3}function $f(e){try{return new URL(e,Zo.location.origin).href}catch(e){return}}const Bf={...ar,instrumentNavigation:!0,instrumentPageLoad:!0,markBackgroundSpan:!0,enableLongTask:!0,enableLongAnimationFrame:!0,enableInp:!0,_experiments:{},...Df};function Uf(e,t,n){e.emit("startPageLoadSpan",t,n),Gt().setTransactionName(t.name);const r=Mn();return"pageload"===(r&&Sn(r).op)?r:void 0}function jf(e,t){Xt().setPropagationContext(Rt()),Gt().setPropagationContext(Rt()),e.emit("startNavigationSpan",t),Gt().setTransactionName(t.name);const n=Mn();return"navigation"===(n&&Sn(n).op)?n:void 0}function Hf(e){const t=(n=`meta[name=${e}]`,E.document&&E.document.querySelector?E.document.querySelector(n):null);var n;return t?t.getAttribute("content"):void 0}function zf(e){return new Promise(((t,n)=>{e.oncomplete=e.onsuccess=()=>t(e.result),e.onabort=e.onerror=()=>n(e.error)}))}function qf(e){return zf(e.getAllKeys())}function Wf(e){let t;function n(){return null==t&&(t=function(e,t){const n=indexedDB.open(e);n.onupgradeneeded=()=>n.result.createObjectStore(t);const r=zf(n);return e=>r.then((n=>e(n.transaction(t,"readwrite").objectStore(t))))}(e.dbName||"sentry-offline",e.storeName||"queue")),t}return{push:async t=>{try{const r=await _t(t);await function(e,t,n){return e((e=>qf(e).then((r=>{if(!(r.length>=n))return e.put(t,Math.max(...r,0)+1),zf(e.transaction)}))))}(n(),r,e.maxQueueSize||30)}catch(e){}},unshift:async t=>{try{const r=await _t(t);await function(e,t,n){return e((e=>qf(e).then((r=>{if(!(r.length>=n))return e.put(t,Math.min(...r,0)-1),zf(e.transaction)}))))}(n(),r,e.maxQueueSize||30)}catch(e){}},shift:async()=>{try{const e=await function(e){return e((e=>qf(e).then((t=>{const n=t[0];if(null!=n)return zf(e.get(n)).then((t=>(e.delete(n),zf(e.transaction).then((()=>t)))))}))))}(n());if(e)return yt(e)}catch(e){}}}}const Yf=1e6,Vf=String(0),Gf="main";let Xf="",Jf="",Kf="",Qf=Zo.navigator&&Zo.navigator.userAgent||"",Zf="";const em=Zo.navigator&&Zo.navigator.language||Zo.navigator&&Zo.navigator.languages&&Zo.navigator.languages[0]||"";const tm=Zo.navigator&&Zo.navigator.userAgentData;var nm;function rm(e){return function(e){return!("thread_metadata"in e)}(e)?function(e){let t,n=0;const r={samples:[],stacks:[],frames:[],thread_metadata:{[Vf]:{name:Gf}}},o=e.samples[0];if(!o)return r;const s=o.timestamp,i="number"==typeof performance.timeOrigin?performance.timeOrigin:be||0,a=i-(be||i);return e.samples.forEach(((o,i)=>{if(void 0===o.stackId)return void 0===t&&(t=n,r.stacks[t]=[],n++),void(r.samples[i]={elapsed_since_start_ns:((o.timestamp+a-s)*Yf).toFixed(0),stack_id:t,thread_id:Vf});let c=e.stacks[o.stackId];const u=[];for(;c;){u.push(c.frameId);const t=e.frames[c.frameId];t&&void 0===r.frames[c.frameId]&&(r.frames[c.frameId]={function:t.name,abs_path:"number"==typeof t.resourceId?e.resources[t.resourceId]:void 0,lineno:t.line,colno:t.column}),c=void 0===c.parentId?void 0:e.stacks[c.parentId]}const l={elapsed_since_start_ns:((o.timestamp+a-s)*Yf).toFixed(0),stack_id:n,thread_id:Vf};r.stacks[n]=u,r.samples[i]=l,n++})),r}(e):e}function om(e,t,n,r){if("transaction"!==r.type)throw new TypeError("Profiling events may only be attached to transactions, this should never occur.");if(null==n)throw new TypeError(`Cannot construct profiling event envelope without a valid profile. Got ${n} instead.`);const o=function(e){const t=e&&e.contexts&&e.contexts.trace&&e.contexts.trace.trace_id;return"string"==typeof t&&32!==t.length&&rs&&L.log(`[Profiling] Invalid traceId: ${t} on profiled event`),"string"!=typeof t?"":t}(r),s=rm(n),i=t||("number"==typeof r.start_timestamp?1e3*r.start_timestamp:1e3*ve()),a="number"==typeof r.timestamp?1e3*r.timestamp:1e3*ve();return{event_id:e,timestamp:new Date(i).toISOString(),platform:"javascript",version:"1",release:r.release||"",environment:r.environment||Un,runtime:{name:"javascript",version:Zo.navigator.userAgent},os:{name:Xf,version:Jf,build_number:Qf},device:{locale:em,model:Zf,manufacturer:Qf,architecture:Kf,is_emulator:!1},debug_meta:{images:am(n.resources)},profile:s,transactions:[{name:r.transaction||"",id:r.event_id||De(),trace_id:o,active_thread_id:Vf,relative_start_ns:"0",relative_end_ns:(1e6*(a-i)).toFixed(0)}]}}function sm(e){return"pageload"===Sn(e).op}"object"==typeof(nm=tm)&&null!==nm&&"getHighEntropyValues"in nm&&tm.getHighEntropyValues(["architecture","model","platform","platformVersion","fullVersionList"]).then((e=>{if(Xf=e.platform||"",Kf=e.architecture||"",Zf=e.model||"",Jf=e.platformVersion||"",e.fullVersionList&&e.fullVersionList.length>0){const t=e.fullVersionList[e.fullVersionList.length-1];Qf=`${t.brand} ${t.version}`}})).catch((e=>{}));const im=new WeakMap;function am(e){const t=k._sentryDebugIds;if(!t)return[];const n=Qt(),r=n&&n.getOptions(),o=r&&r.stackParser;if(!o)return[];let s;const i=im.get(o);i?s=i:(s=new Map,im.set(o,s));const a=Object.keys(t).reduce(((e,n)=>{let r;const i=s.get(n);i?r=i:(r=o(n),s.set(n,r));for(let o=r.length-1;o>=0;o--){const s=r[o],i=s&&s.filename;if(s&&i){e[i]=t[n];break}}return e}),{}),c=[];for(const t of e)t&&a[t]&&c.push({type:"sourcemap",code_file:t,debug_id:a[t]});return c}let cm=!1;const um=3e4;function lm(e){if(cm)return rs&&L.log("[Profiling] Profiling has been disabled for the duration of the current user session."),!1;if(!e.isRecording())return rs&&L.log("[Profiling] Discarding profile because transaction was not sampled."),!1;const t=Qt(),n=t&&t.getOptions();if(!n)return rs&&L.log("[Profiling] Profiling disabled, no options found."),!1;const r=n.profilesSampleRate;if(!("number"!=typeof(o=r)&&"boolean"!=typeof o||"number"==typeof o&&isNaN(o)?(rs&&L.warn(`[Profiling] Invalid sample rate. Sample rate must be a boolean or a number between 0 and 1. Got ${JSON.stringify(o)} of type ${JSON.stringify(typeof o)}.`),0):!0===o||!1===o||!(o<0||o>1)||(rs&&L.warn(`[Profiling] Invalid sample rate. Sample rate must be between 0 and 1. Got ${o}.`),0)))return rs&&L.warn("[Profiling] Discarding profile because of invalid sample rate."),!1;var o;if(!r)return rs&&L.log("[Profiling] Discarding profile because a negative sampling decision was inherited or profileSampleRate is set to 0"),!1;return!!(!0===r||Math.random()<r)||(rs&&L.log(`[Profiling] Discarding profile because it's not included in the random sample (sampling rate = ${Number(r)})`),!1)}function dm(e,t,n,r){return function(e){return e.samples.length<2?(rs&&L.log("[Profiling] Discarding profile because it contains less than 2 samples"),!1):!!e.frames.length||(rs&&L.log("[Profiling] Discarding profile because it contains no frames"),!1)}(n)?om(e,t,n,r):null}const pm=new Map;function hm(e){const t=pm.get(e);return t&&pm.delete(e),t}function fm(e){let t;sm(e)&&(t=1e3*ve());const n=function(){const e=Zo.Profiler;if("function"!=typeof e)return void(rs&&L.log("[Profiling] Profiling is not supported by this browser, Profiler interface missing on window object."));const t=Math.floor(um/10);try{return new e({sampleInterval:10,maxBufferSize:t})}catch(e){rs&&(L.log("[Profiling] Failed to initialize the Profiling constructor, this is likely due to a missing 'Document-Policy': 'js-profiling' header."),L.log("[Profiling] Disabling profiling for current user session.")),cm=!0}}();if(!n)return;rs&&L.log(`[Profiling] started profiling span: ${Sn(e).description}`);const r=De();async function o(){if(e&&n)return n.stop().then((t=>{s&&(Zo.clearTimeout(s),s=void 0),rs&&L.log(`[Profiling] stopped profiling of span: ${Sn(e).description}`),t?function(e,t){if(pm.set(e,t),pm.size>30){const e=pm.keys().next().value;pm.delete(e)}}(r,t):rs&&L.log(`[Profiling] profiler returned null profile for: ${Sn(e).description}`,"this may indicate an overlapping span or a call to stopProfiling with a profile title that was never started")})).catch((e=>{rs&&L.log("[Profiling] error while stopping profiler:",e)}))}Gt().setContext("profile",{profile_id:r,start_timestamp:t});let s=Zo.setTimeout((()=>{rs&&L.log("[Profiling] max profile duration elapsed, stopping profiling for:",Sn(e).description),o()}),um);const i=e.end.bind(e);e.end=function(){return e?(o().then((()=>{i()}),(()=>{i()})),e):i()}}const mm=()=>({name:"BrowserProfiling",setup(e){const t=Mn(),n=t&&In(t);n&&sm(n)&&lm(n)&&fm(n),e.on("spanStart",(e=>{e===In(e)&&lm(e)&&fm(e)})),e.on("beforeEnvelope",(e=>{if(!pm.size)return;const t=function(e){const t=[];return ft(e,((e,n)=>{if("transaction"===n)for(let n=1;n<e.length;n++){const r=e[n];r&&r.contexts&&r.contexts.profile&&r.contexts.profile.profile_id&&t.push(e[n])}})),t}(e);if(!t.length)return;const n=[];for(const e of t){const t=e&&e.contexts,r=t&&t.profile&&t.profile.profile_id,o=t&&t.profile&&t.profile.start_timestamp;if("string"!=typeof r){rs&&L.log("[Profiling] cannot find profile for a span without a profile context");continue}if(!r){rs&&L.log("[Profiling] cannot find profile for a span without a profile context");continue}t&&t.profile&&delete t.profile;const s=hm(r);if(!s){rs&&L.log(`[Profiling] Could not retrieve profile for span: ${r}`);continue}const i=dm(r,o,s,e);i&&n.push(i)}!function(e,t){if(!t.length)return e;for(const n of t)e[1].push([{type:"profile"},n])}(e,n)}))}});var gm=Object.freeze({__proto__:null,SDK_VERSION:w,SEMANTIC_ATTRIBUTE_SENTRY_OP:rn,SEMANTIC_ATTRIBUTE_SENTRY_ORIGIN:on,SEMANTIC_ATTRIBUTE_SENTRY_SAMPLE_RATE:nn,SEMANTIC_ATTRIBUTE_SENTRY_SOURCE:tn,Scope:jt,addBreadcrumb:io,addEventProcessor:Ar,addIntegration:function(e){const t=Qt();t?t.addIntegration(e):Nt&&L.warn(`Cannot add integration "${e.name}" because no SDK Client is available.`)},addTracingExtensions:function(){Nn()},captureConsoleIntegration:bo,captureEvent:kr,captureException:Sr,captureFeedback:Ko,captureMessage:wr,captureSession:Lr,close:async function(e){const t=Qt();return t?t.close(e):(Nt&&L.warn("Cannot flush events and disable SDK. No client defined."),Promise.resolve(!1))},continueTrace:({sentryTrace:e,baggage:t},n)=>Kt((r=>{const o=lt(e,t);return r.setPropagationContext(o),n()})),createTransport:Jr,debugIntegration:So,dedupeIntegration:wo,endSession:Or,extraErrorDataIntegration:Co,flush:async function(e){const t=Qt();return t?t.flush(e):(Nt&&L.warn("Cannot flush events. No client defined."),Promise.resolve(!1))},functionToStringIntegration:uo,getActiveSpan:Mn,getClient:Qt,getCurrentHub:Qo,getCurrentScope:Gt,getGlobalScope:Jt,getIsolationScope:Xt,getRootSpan:In,getSpanDescendants:Tn,getSpanStatusFromHttpCode:hn,inboundFiltersIntegration:po,isInitialized:function(){return!!Qt()},lastEventId:Rr,makeMultiplexedTransport:function(e,t){return n=>{const r=e(n),o=new Map;function s(t,r){const s=r?`${t}:${r}`:t;let i=o.get(s);if(!i){const a=$(t);if(!a)return;const c=Br(a,n.tunnel);i=r?function(e,t){return n=>{const r=e(n);return{...r,send:async e=>{const n=no(e,["event","transaction","profile","replay_event"]);return n&&(n.release=t),r.send(e)}}}}(e,r)({...n,url:c}):e({...n,url:c}),o.set(s,i)}return[t,i]}return{send:async function(e){const n=t({envelope:e,getEvent:function(t){const n=t&&t.length?t:["event"];return no(e,n)}}).map((e=>"string"==typeof e?s(e,void 0):s(e.dsn,e.release))).filter((e=>!!e)),o=n.length?n:[["",r]];return(await Promise.all(o.map((([t,n])=>n.send(function(e,t){return pt(t?{...e[0],dsn:t}:e[0],e[1])}(e,t))))))[0]},flush:async function(e){const t=[...o.values(),r];return(await Promise.all(t.map((t=>t.flush(e))))).every((e=>e))}}}},moduleMetadataIntegration:vo,parameterize:function(e,...t){const n=new String(String.raw(e,...t));return n.__sentry_template_string__=e.join("\0").replace(/%/g,"%%").replace(/\0/g,"%s"),n.__sentry_template_values__=t,n},registerSpanErrorInstrumentation:Nn,rewriteFramesIntegration:To,sessionTimingIntegration:Io,setContext:xr,setCurrentClient:Gr,setExtra:Cr,setExtras:Er,setHttpStatus:fn,setMeasurement:Yn,setTag:Ir,setTags:Tr,setUser:Mr,spanToBaggageHeader:function(e){return at(qn(e))},spanToJSON:Sn,spanToTraceHeader:yn,startInactiveSpan:Zn,startNewTrace:function(e){return Kt((t=>(t.setPropagationContext(Rt()),Nt&&L.info(`Starting a new trace with id ${t.getPropagationContext().traceId}`),er(null,e))))},startSession:Nr,startSpan:function(e,t){const n=rr();if(n.startSpan)return n.startSpan(e,t);const r=nr(e),{forceTransaction:o,parentSpan:s}=e;return Kt(e.scope,(()=>ir(s)((()=>{const n=Gt(),s=sr(n),i=e.onlyIfParent&&!s?new $n:tr({parentSpan:s,spanArguments:r,forceTransaction:o,scope:n});return $t(n,i),Bn((()=>t(i)),(()=>{const{status:e}=Sn(i);!i.isRecording()||e&&"ok"!==e||i.setStatus({code:pn,message:"internal_error"})}),(()=>i.end()))}))))},startSpanManual:Qn,thirdPartyErrorFilterIntegration:Oo,withActiveSpan:er,withIsolationScope:function(...e){const t=Vt(Ot());if(2===e.length){const[n,r]=e;return n?t.withSetIsolationScope(n,r):t.withIsolationScope(r)}return t.withIsolationScope(e[0])},withScope:Kt,zodErrorsIntegration:No,WINDOW:Zo,BrowserClient:gs,makeFetchTransport:na,chromeStackLineParser:aa,defaultStackLineParsers:_a,defaultStackParser:ya,geckoStackLineParser:la,opera10StackLineParser:fa,opera11StackLineParser:ga,winjsStackLineParser:pa,eventFromException:ls,eventFromMessage:ds,exceptionFromError:os,createUserFeedbackEnvelope:ms,captureUserFeedback:function(e){const t=Qt();t&&t.captureUserFeedback(e)},forceLoad:function(){},getDefaultIntegrations:Oa,init:Da,onLoad:function(e){e()},showReportDialog:function(e={}){if(!Zo.document)return void(rs&&L.error("Global document not defined in showReportDialog call"));const t=Gt(),n=t.getClient(),r=n&&n.getDsn();if(!r)return void(rs&&L.error("DSN not configured for showReportDialog call"));if(t&&(e.user={...t.getUser(),...e.user}),!e.eventId){const t=Rr();t&&(e.eventId=t)}const o=Zo.document.createElement("script");o.async=!0,o.crossOrigin="anonymous",o.src=function(e,t){const n=U(e);if(!n)return"";const r=`${Fr(n)}embed/error-page/`;let o=`dsn=${F(n)}`;for(const e in t)if("dsn"!==e&&"onClose"!==e)if("user"===e){const e=t.user;if(!e)continue;e.name&&(o+=`&name=${encodeURIComponent(e.name)}`),e.email&&(o+=`&email=${encodeURIComponent(e.email)}`)}else o+=`&${encodeURIComponent(e)}=${encodeURIComponent(t[e])}`;return`${r}?${o}`}(r,e),e.onLoad&&(o.onload=e.onLoad);const{onClose:s}=e;if(s){const e=t=>{if("__sentry_reportdialog_closed__"===t.data)try{s()}finally{Zo.removeEventListener("message",e)}};Zo.addEventListener("message",e)}const i=Zo.document.head||Zo.document.body;i?i.appendChild(o):rs&&L.error("Not injecting report dialog. No injection point found in HTML")},breadcrumbsIntegration:Sa,globalHandlersIntegration:Ia,httpContextIntegration:Aa,linkedErrorsIntegration:Na,browserApiErrorsIntegration:ka,lazyLoadIntegration:Fa,reportingObserverIntegration:Ua,httpClientIntegration:ja,contextLinesIntegration:Xa,getReplay:function(){const e=Qt();return e&&e.getIntegrationByName("Replay")},replayIntegration:e=>new ep(e),replayCanvasIntegration:Ap,feedbackAsyncIntegration:Mf,feedbackIntegration:Rf,feedbackSyncIntegration:Rf,getFeedback:function(){const e=Qt();return e&&e.getIntegrationByName("Feedback")},sendFeedback:Fp,metrics:Af,defaultRequestInstrumentationOptions:Df,instrumentOutgoingRequests:Lf,browserTracingIntegration:(e={})=>{Nn();const{enableInp:t,enableLongTask:n,enableLongAnimationFrame:r,_experiments:{enableInteractions:o,enableStandaloneClsSpans:s},beforeStartSpan:i,idleTimeout:a,finalTimeout:c,childSpanTimeout:u,markBackgroundSpan:l,traceFetch:d,traceXHR:p,shouldCreateSpanForRequest:h,enableHTTPTimings:f,instrumentPageLoad:m,instrumentNavigation:g}={...Bf,...e},_=Ai({recordClsStandaloneSpans:s||!1});t&&ea(),r&&k.PerformanceObserver&&PerformanceObserver.supportedEntryTypes&&PerformanceObserver.supportedEntryTypes.includes("long-animation-frame")?new PerformanceObserver((e=>{if(Mn())for(const t of e.getEntries()){if(!t.scripts[0])continue;const e=xi(be+t.startTime),n=xi(t.duration),r={[on]:"auto.ui.browser.metrics"},o=t.scripts[0],{invoker:s,invokerType:i,sourceURL:a,sourceFunctionName:c,sourceCharPosition:u}=o;r["browser.script.invoker"]=s,r["browser.script.invoker_type"]=i,a&&(r["code.filepath"]=a),c&&(r["code.function"]=c),-1!==u&&(r["browser.script.source_char_position"]=u);const l=Zn({name:"Main UI thread blocked",op:"ui.long-animation-frame",startTime:e,attributes:r});l&&l.end(e+n)}})).observe({type:"long-animation-frame",buffered:!0}):n&&li("longtask",(({entries:e})=>{if(Mn())for(const t of e){const e=xi(be+t.startTime),n=xi(t.duration),r=Zn({name:"Main UI thread blocked",op:"ui.long-task",startTime:e,attributes:{[on]:"auto.ui.browser.metrics"}});r&&r.end(e+n)}})),o&&li("event",(({entries:e})=>{if(Mn())for(const t of e)if("click"===t.name){const e=xi(be+t.startTime),n=xi(t.duration),r={name:T(t.target),op:`ui.interaction.${t.name}`,startTime:e,attributes:{[on]:"auto.ui.browser.metrics"}},o=R(t.target);o&&(r.attributes["ui.component_name"]=o);const s=Zn(r);s&&s.end(e+n)}}));const y={name:void 0,source:void 0};function v(e,t){const n="pageload"===t.op,r=i?i(t):t,o=r.attributes||{};t.name!==r.name&&(o[tn]="custom",r.attributes=o),y.name=r.name,y.source=o[tn];const l=pr(r,{idleTimeout:a,finalTimeout:c,childSpanTimeout:u,disableAutoFinish:n,beforeSpanEnd:e=>{_(),Ni(e,{recordClsOnPageloadSpan:!s})}});function d(){["interactive","complete"].includes(Zo.document.readyState)&&e.emit("idleSpanEnableAutoFinish",l)}return n&&Zo.document&&(Zo.document.addEventListener("readystatechange",(()=>{d()})),d()),l}return{name:"BrowserTracing",afterAllSetup(e){let n,r=Zo.location&&Zo.location.href;e.on("startNavigationSpan",(t=>{Qt()===e&&(n&&!Sn(n).timestamp&&(rs&&L.log(`[Tracing] Finishing current root span with op: ${Sn(n).op}`),n.end()),n=v(e,{op:"navigation",...t}))})),e.on("startPageLoadSpan",((t,r={})=>{if(Qt()!==e)return;n&&!Sn(n).timestamp&&(rs&&L.log(`[Tracing] Finishing current root span with op: ${Sn(n).op}`),n.end());const o=lt(r.sentryTrace||Hf("sentry-trace"),r.baggage||Hf("baggage"));Gt().setPropagationContext(o),n=v(e,{op:"pageload",...t})})),e.on("spanEnd",(e=>{const t=Sn(e).op;if(e!==In(e)||"navigation"!==t&&"pageload"!==t)return;const n=Gt(),r=n.getPropagationContext();n.setPropagationContext({...r,sampled:void 0!==r.sampled?r.sampled:wn(e),dsc:r.dsc||qn(e)})})),Zo.location&&(m&&Uf(e,{name:Zo.location.pathname,startTime:be?be/1e3:void 0,attributes:{[tn]:"url",[on]:"auto.pageload.browser"}}),g&&zi((({to:t,from:n})=>{void 0===n&&r&&-1!==r.indexOf(t)?r=void 0:n!==t&&(r=void 0,jf(e,{name:Zo.location.pathname,attributes:{[tn]:"url",[on]:"auto.navigation.browser"}}))}))),l&&(Zo&&Zo.document?Zo.document.addEventListener("visibilitychange",(()=>{const e=Mn();if(!e)return;const t=In(e);if(Zo.document.hidden&&t){const e="cancelled",{op:n,status:r}=Sn(t);rs&&L.log(`[Tracing] Transaction: ${e} -> since tab moved to the background, op: ${n}`),r||t.setStatus({code:pn,message:e}),t.setAttribute("sentry.cancellation_reason","document.hidden"),t.end()}})):rs&&L.warn("[Tracing] Could not set up background tab detection due to lack of global document")),o&&function(e,t,n,r){let o;const s=()=>{const s="ui.action.click",i=Mn(),a=i&&In(i);if(a){const e=Sn(a).op;if(["navigation","pageload"].includes(e))return void(rs&&L.warn(`[Tracing] Did not create ${s} span because a pageload or navigation span is in progress.`))}o&&(o.setAttribute(sn,"interactionInterrupted"),o.end(),o=void 0),r.name?o=pr({name:r.name,op:s,attributes:{[tn]:r.source||"url"}},{idleTimeout:e,finalTimeout:t,childSpanTimeout:n}):rs&&L.warn(`[Tracing] Did not create ${s} transaction because _latestRouteName is missing.`)};Zo.document&&addEventListener("click",s,{once:!1,capture:!0})}(a,c,u,y),t&&function(){const e=({entries:e})=>{const t=Mn(),n=t&&In(t);e.forEach((e=>{if(!function(e){return"duration"in e}(e)||!n)return;const t=e.interactionId;if(null!=t&&!Zi.has(t)){if(Qi.length>10){const e=Qi.shift();Zi.delete(e)}Qi.push(t),Zi.set(t,n)}}))};li("event",e),li("first-input",e)}(),Lf(e,{traceFetch:d,traceXHR:p,tracePropagationTargets:e.getOptions().tracePropagationTargets,shouldCreateSpanForRequest:h,enableHTTPTimings:f})}}},startBrowserTracingNavigationSpan:jf,startBrowserTracingPageLoadSpan:Uf,makeBrowserOfflineTransport:function(e=na){return function(e){return t=>e({...t,createStore:Wf})}(to(e))},browserProfilingIntegration:mm});async function _m(e,t={},n){return window.__TAURI_INTERNALS__.invoke(e,t,n)}"function"==typeof SuppressedError&&SuppressedError;let vm="sentry",Em=[],xm,Tm=!1,Mm=!1;async function Cm(){if(clearTimeout(xm),xm=void 0,0===Em.length)return;const e=Em;Em=[],await _m(`plugin:${vm}|breadcrumbs`,{breadcrumbs:e})}function Am(e){Em.push(e),void 0===xm&&(xm=setTimeout((()=>Cm().catch((()=>{}))),100))}function Pm(e){return void 0===e?void 0:new Date(1e3*e).toISOString()}const ym={dsn:"https://123456@dummy.dsn/0",autoSessionTracking:!1,beforeSend:async function(e){var t,n;return delete e.sdk,delete e.breadcrumbs,delete e.environment,delete e.sdkProcessingMetadata,(null===(n=null===(t=null==e?void 0:e.request)||void 0===t?void 0:t.headers)||void 0===n?void 0:n["User-Agent"])&&delete e.request.headers["User-Agent"],await Cm().catch((()=>{})),await _m(`plugin:${vm}|event`,{event:e}),null},beforeBreadcrumb:function(e){var t,n;return"fetch"===e.category&&(null===(n=null===(t=e.data)||void 0===t?void 0:t.url)||void 0===n?void 0:n.includes("ipc:"))?(console.log(e),null):(Tm&&"console"===e.category&&"error"===e.level||Am(e),null)},beforeSendTransaction:async function(e){var t;delete e.sdk,delete e.breadcrumbs,delete e.environment,delete e.sdkProcessingMetadata;const n={...e,timestamp:Pm(e.timestamp),spans:(null!==(t=e.spans)&&void 0!==t?t:[]).map((e=>({...e,timestamp:Pm(e.timestamp)})))};return await _m(`plugin:${vm}|transaction`,{transaction:n}),null}};function bm(){const e=window.__TAURI_INTERNALS__,t=e.invoke;e.invoke=(e,...n)=>(e.startsWith(`plugin:${vm}|`)||Am({category:"ui.action",message:`Invoked ${e}`,data:{command:e},timestamp:Date.now()/1e3}),t(e,...n))}async function wm(){var e,t;const n=null!==(t=null===(e=Qt())||void 0===e?void 0:e.getOptions().integrations.map((e=>e.name)))&&void 0!==t?t:[];await _m(`plugin:${vm}|sentry_js_ready`,{sdkVersion:w,integrations:n})}function Fm(e){try{return String(e)}catch(t){return Object.prototype.toString.call(e)}}function Om(){Tm=!0;const e=console.error;console.error=(...t)=>{const n=t.map(Fm).join(" ");n.trim()&&Am({category:"console",level:"error",message:n,timestamp:Date.now()/1e3}),e.apply(console,t)},window.addEventListener("error",(e=>{var t;return Im(null!==(t=e.error)&&void 0!==t?t:e.message,"onerror")})),Nm()}function Nm(){Mm||(Mm=!0,window.addEventListener("unhandledrejection",(e=>Im(e.reason,"onunhandledrejection"))))}function jm(e){var t;const n=null==e?void 0:e.stack,r=null===(t=Qt())||void 0===t?void 0:t.getOptions().stackParser;if("string"!=typeof n||!r)return;const o=r(n);return o.length>0?{frames:o}:void 0}function Im(e,t){ym.beforeSend({level:"error",exception:{values:[{type:e instanceof Error?e.name:"Error",value:e instanceof Error?e.message:String(e),mechanism:{type:t,handled:!1},stacktrace:jm(e)}]},timestamp:Date.now()/1e3}).catch((()=>{}))}function km(e){setInterval((()=>_m(`plugin:${vm}|heartbeat`)),e)}window.Sentry=gm;const Rm=__CAPTURE_CONSOLE__,Lm=__TRACES_SAMPLE_RATE__;vm=__PLUGIN_NAME__,Da({...ym,...__INIT_OPTIONS__,debug:__DEBUG__,...null===Lm?{}:{tracesSampleRate:Lm},integrations:e=>{const t=e.filter((e=>"GlobalHandlers"!==e.name));return Rm||t.push(gm.globalHandlersIntegration({onunhandledrejection:!1})),null===Lm?t:[...t,gm.browserTracingIntegration()]}}),wm(),Nm(),Rm&&Om(),__COMMAND_BREADCRUMBS__&&bm();const Sm=__HEARTBEAT_INTERVAL__;Sm>0&&km(Sm)}();
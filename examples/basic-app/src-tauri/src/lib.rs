@@ -7,10 +7,7 @@ use tauri_plugin_sentry::sentry;
 
 #[tauri::command]
 fn rust_breadcrumb() {
-    sentry::add_breadcrumb(sentry::Breadcrumb {
-        message: Some("This is a breadcrumb from Rust".to_owned()),
-        ..Default::default()
-    })
+    tauri_plugin_sentry::breadcrumb("This is a breadcrumb from Rust");
 }
 
 #[tauri::command]
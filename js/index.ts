@@ -1,6 +1,56 @@
-import { BrowserOptions, ErrorEvent } from "@sentry/browser";
+import {
+  BrowserOptions,
+  continueTrace,
+  ErrorEvent,
+  getActiveSpan,
+  getClient,
+  SDK_VERSION,
+  spanToTraceHeader,
+} from "@sentry/browser";
 import { invoke } from "@tauri-apps/api/core";
-import { Breadcrumb, Event } from "@sentry/types";
+import { Breadcrumb, Event, Stacktrace, TransactionEvent } from "@sentry/types";
+
+let pluginName = "sentry";
+
+/** How long breadcrumbs are batched before being sent to the Rust process. */
+const BREADCRUMBS_FLUSH_INTERVAL_MS = 100;
+
+let pendingBreadcrumbs: Breadcrumb[] = [];
+let breadcrumbsTimeout: ReturnType<typeof setTimeout> | undefined;
+let consoleCaptured = false;
+let rejectionsCaptured = false;
+
+/**
+ * Sets the name the Sentry plugin has been registered with in the Rust process.
+ */
+export function setPluginName(name: string): void {
+  pluginName = name;
+}
+
+/**
+ * Sends the batched breadcrumbs to the Rust process in a single call.
+ */
+export async function flushBreadcrumbs(): Promise<void> {
+  clearTimeout(breadcrumbsTimeout);
+  breadcrumbsTimeout = undefined;
+  if (pendingBreadcrumbs.length === 0) {
+    return;
+  }
+
+  const breadcrumbs = pendingBreadcrumbs;
+  pendingBreadcrumbs = [];
+  await invoke(`plugin:${pluginName}|breadcrumbs`, { breadcrumbs });
+}
+
+function queueBreadcrumb(breadcrumb: Breadcrumb): void {
+  pendingBreadcrumbs.push(breadcrumb);
+  if (breadcrumbsTimeout === undefined) {
+    breadcrumbsTimeout = setTimeout(
+      () => flushBreadcrumbs().catch(() => undefined),
+      BREADCRUMBS_FLUSH_INTERVAL_MS,
+    );
+  }
+}
 
 /**
  * A simple `beforeSend` that sends the envelope to the Rust process via Tauri invoke.
@@ -20,12 +70,44 @@ export async function sendEventToRust(event: Event): Promise<ErrorEvent | null>
     delete event.request.headers["User-Agent"];
   }
 
-  await invoke("plugin:sentry|event", { event });
+  // The breadcrumbs recorded before the event must be attached to it
+  await flushBreadcrumbs().catch(() => undefined);
+  await invoke(`plugin:${pluginName}|event`, { event });
 
   // Stop events from being sent from the browser
   return null;
 }
 
+/**
+ * Converts a timestamp in seconds to the RFC 3339 format expected by the Rust types.
+ */
+function toRfc3339(timestamp: number | undefined): string | undefined {
+  return timestamp === undefined ? undefined : new Date(timestamp * 1000).toISOString();
+}
+
+/**
+ * A simple `beforeSendTransaction` that sends the transaction to the Rust process via Tauri invoke.
+ */
+export async function sendTransactionToRust(
+  event: TransactionEvent
+): Promise<TransactionEvent | null> {
+  // Like for events, these are either not understood or overridden in the host
+  delete event.sdk;
+  delete event.breadcrumbs;
+  delete event.environment;
+  delete event.sdkProcessingMetadata;
+
+  const transaction = {
+    ...event,
+    timestamp: toRfc3339(event.timestamp),
+    spans: (event.spans ?? []).map((span) => ({ ...span, timestamp: toRfc3339(span.timestamp) })),
+  };
+  await invoke(`plugin:${pluginName}|transaction`, { transaction });
+
+  // Stop transactions from being sent from the browser
+  return null;
+}
+
 /**
  * A simple `beforeBreadcrumb` hook that sends the breadcrumb to the Rust process via Tauri invoke.
  */
@@ -37,11 +119,164 @@ export function sendBreadcrumbToRust(
     return null;
   }
 
-  invoke("plugin:sentry|breadcrumb", { breadcrumb });
+  // Already recorded by the `console.error` hook of `captureConsole`
+  if (consoleCaptured && breadcrumb.category === "console" && breadcrumb.level === "error") {
+    return null;
+  }
+
+  queueBreadcrumb(breadcrumb);
   // We don't collect breadcrumbs in the renderer since they are passed to Rust
   return null;
 }
 
+/**
+ * Records a breadcrumb for every Tauri command invoked by the webview.
+ *
+ * The commands of this plugin are not recorded, as they are invoked to report
+ * the breadcrumbs themselves.
+ */
+export function recordCommandBreadcrumbs(): void {
+  const internals = (window as any).__TAURI_INTERNALS__;
+  const next = internals.invoke;
+  internals.invoke = (cmd: string, ...args: unknown[]) => {
+    if (!cmd.startsWith(`plugin:${pluginName}|`)) {
+      const breadcrumb: Breadcrumb = {
+        category: "ui.action",
+        message: `Invoked ${cmd}`,
+        data: { command: cmd },
+        timestamp: Date.now() / 1000,
+      };
+      queueBreadcrumb(breadcrumb);
+    }
+
+    return next(cmd, ...args);
+  };
+}
+
+/**
+ * Converts a `console.error` argument to a string, even if it has no
+ * `toString` method (e.g. `Object.create(null)`) or if that method throws.
+ */
+function stringify(value: unknown): string {
+  try {
+    return String(value);
+  } catch {
+    return Object.prototype.toString.call(value);
+  }
+}
+
+/**
+ * Reports the errors of the webview to the Rust process, even if they are not
+ * seen by the Sentry browser SDK: `console.error` calls are recorded as
+ * breadcrumbs, uncaught errors and unhandled promise rejections are captured
+ * as events.
+ */
+export function captureConsole(): void {
+  consoleCaptured = true;
+
+  const next = console.error;
+  console.error = (...args: unknown[]) => {
+    const message = args.map(stringify).join(" ");
+    // The Rust process rejects the breadcrumbs without a message
+    if (message.trim()) {
+      queueBreadcrumb({
+        category: "console",
+        level: "error",
+        message,
+        timestamp: Date.now() / 1000,
+      });
+    }
+    next.apply(console, args);
+  };
+
+  window.addEventListener("error", (event) => captureError(event.error ?? event.message, "onerror"));
+  captureRejections();
+}
+
+/**
+ * Reports the unhandled promise rejections to the Rust process, with the
+ * stacktrace of their reason.
+ *
+ * The `onunhandledrejection` handler of the `GlobalHandlers` integration of
+ * the Sentry browser SDK must be disabled, or the rejections are reported twice.
+ */
+export function captureRejections(): void {
+  if (rejectionsCaptured) {
+    return;
+  }
+
+  rejectionsCaptured = true;
+  window.addEventListener("unhandledrejection", (event) =>
+    captureError(event.reason, "onunhandledrejection")
+  );
+}
+
+/**
+ * Parses the stack of the error with the stack parser of the Sentry browser SDK.
+ */
+function stacktraceFromError(error: unknown): Stacktrace | undefined {
+  const stack = (error as { stack?: unknown } | null | undefined)?.stack;
+  const stackParser = getClient()?.getOptions().stackParser;
+  if (typeof stack !== "string" || !stackParser) {
+    return undefined;
+  }
+
+  const frames = stackParser(stack);
+  return frames.length > 0 ? { frames } : undefined;
+}
+
+function captureError(error: unknown, mechanism: string): void {
+  const event: Event = {
+    level: "error",
+    exception: {
+      values: [
+        {
+          type: error instanceof Error ? error.name : "Error",
+          value: error instanceof Error ? error.message : String(error),
+          mechanism: { type: mechanism, handled: false },
+          stacktrace: stacktraceFromError(error),
+        },
+      ],
+    },
+    timestamp: Date.now() / 1000,
+  };
+  sendEventToRust(event).catch(() => undefined);
+}
+
+/**
+ * Reports to the Rust process that the Sentry browser SDK has been initialized.
+ */
+export async function reportReady(): Promise<void> {
+  const integrations = getClient()?.getOptions().integrations.map((integration) => integration.name) ?? [];
+  await invoke(`plugin:${pluginName}|sentry_js_ready`, { sdkVersion: SDK_VERSION, integrations });
+}
+
+/**
+ * Sends a heartbeat to the Rust process at the given interval, so that it can
+ * detect a crashed or unresponsive webview.
+ */
+export function startHeartbeat(intervalMs: number): void {
+  setInterval(() => invoke(`plugin:${pluginName}|heartbeat`), intervalMs);
+}
+
+/**
+ * Returns the `sentry-trace` header of the active span, to be passed to a
+ * Rust command continuing the trace with `continue_transaction`.
+ */
+export function traceHeader(): string | undefined {
+  const span = getActiveSpan();
+  return span ? spanToTraceHeader(span) : undefined;
+}
+
+/**
+ * Runs the callback in the trace bound to the scope of the Rust process, if
+ * any, so that the events captured by the webview are linked to it.
+ */
+export async function continueRustTrace<T>(callback: () => T): Promise<T> {
+  const sentryTrace = await invoke<string | null>(`plugin:${pluginName}|trace_header`);
+  return continueTrace({ sentryTrace: sentryTrace ?? undefined, baggage: undefined }, callback);
+}
+
 /**
  * Default options for the Sentry browser SDK to pass events and breadcrumbs to
  * the Rust SDK.
@@ -52,5 +287,6 @@ export const defaultOptions: BrowserOptions = {
   // We want to track app sessions rather than browser sessions
   autoSessionTracking: false,
   beforeSend: sendEventToRust,
+  beforeSendTransaction: sendTransactionToRust,
   beforeBreadcrumb: sendBreadcrumbToRust,
 };
@@ -0,0 +1,143 @@
+//! Limits on the attachments sent along an event.
+//!
+//! Sentry rejects a whole envelope whose attachments exceed its limits, losing
+//! the event along with them. The transport is wrapped so that the least
+//! important attachments are dropped instead, the event being tagged with
+//! `attachments_truncated`.
+
+#![warn(missing_docs)]
+
+use std::cmp::Reverse;
+use std::sync::Arc;
+use std::time::Duration;
+
+use sentry::protocol::{Attachment, AttachmentType, EnvelopeItem};
+use sentry::{ClientOptions, Envelope, Transport, TransportFactory};
+
+/// Limits on the attachments sent along an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttachmentLimits {
+    /// The maximum number of attachments of an event.
+    pub max_count: usize,
+    /// The maximum total size, in bytes, of the attachments of an event.
+    pub max_total_size: usize,
+}
+
+impl Default for AttachmentLimits {
+    fn default() -> Self {
+        Self {
+            max_count: 100,
+            max_total_size: 100 * 1024 * 1024,
+        }
+    }
+}
+
+/// Creates the inner transport, wrapped to enforce the attachment limits.
+pub(crate) struct AttachmentLimitsFactory {
+    inner: Arc<dyn TransportFactory>,
+    limits: AttachmentLimits,
+}
+
+impl AttachmentLimitsFactory {
+    pub(crate) fn new(inner: Arc<dyn TransportFactory>, limits: AttachmentLimits) -> Self {
+        Self { inner, limits }
+    }
+}
+
+impl TransportFactory for AttachmentLimitsFactory {
+    fn create_transport(&self, options: &ClientOptions) -> Arc<dyn Transport> {
+        Arc::new(AttachmentLimitsTransport {
+            inner: self.inner.create_transport(options),
+            limits: self.limits,
+        })
+    }
+}
+
+/// A transport dropping the attachments exceeding the limits.
+struct AttachmentLimitsTransport {
+    inner: Arc<dyn Transport>,
+    limits: AttachmentLimits,
+}
+
+impl Transport for AttachmentLimitsTransport {
+    fn send_envelope(&self, envelope: Envelope) {
+        self.inner.send_envelope(limit(envelope, &self.limits));
+    }
+
+    fn flush(&self, timeout: Duration) -> bool {
+        self.inner.flush(timeout)
+    }
+
+    fn shutdown(&self, timeout: Duration) -> bool {
+        self.inner.shutdown(timeout)
+    }
+}
+
+/// Whether the attachment is a crash report, dropped only as a last resort.
+fn is_crash_report(attachment: &Attachment) -> bool {
+    matches!(
+        attachment.ty,
+        Some(AttachmentType::Minidump | AttachmentType::AppleCrashReport)
+    ) || attachment.filename.ends_with(".mdmp")
+}
+
+/// Drops the attachments exceeding the limits, crash reports last and the
+/// other ones in the reverse order they were added.
+fn limit(envelope: Envelope, limits: &AttachmentLimits) -> Envelope {
+    let attachments: Vec<&Attachment> = envelope
+        .items()
+        .filter_map(|item| match item {
+            EnvelopeItem::Attachment(attachment) => Some(attachment),
+            _ => None,
+        })
+        .collect();
+    let total_size: usize = attachments
+        .iter()
+        .map(|attachment| attachment.buffer.len())
+        .sum();
+    if attachments.len() <= limits.max_count && total_size <= limits.max_total_size {
+        return envelope;
+    }
+
+    // The sort is stable, so attachments of the same importance keep their order.
+    let mut by_importance: Vec<usize> = (0..attachments.len()).collect();
+    by_importance.sort_by_key(|&index| Reverse(is_crash_report(attachments[index])));
+    let mut kept = vec![false; attachments.len()];
+    let (mut count, mut size) = (0, 0);
+    for index in by_importance {
+        let attachment_size = attachments[index].buffer.len();
+        if count < limits.max_count && size + attachment_size <= limits.max_total_size {
+            kept[index] = true;
+            count += 1;
+            size += attachment_size;
+        }
+    }
+
+    let mut limited = Envelope::new();
+    let mut kept = kept.into_iter();
+    for item in envelope.items() {
+        match item {
+            EnvelopeItem::Attachment(attachment) => {
+                if kept.next().unwrap_or(false) {
+                    limited.add_item(attachment.clone());
+                } else {
+                    log::warn!(
+                        "dropping the attachment {:?} ({} bytes), exceeding the attachment limits",
+                        attachment.filename,
+                        attachment.buffer.len()
+                    );
+                }
+            }
+            EnvelopeItem::Event(event) => {
+                let mut event = event.clone();
+                event
+                    .tags
+                    .insert("attachments_truncated".to_string(), "true".to_string());
+                limited.add_item(event);
+            }
+            item => limited.add_item(item.clone()),
+        }
+    }
+
+    limited
+}
@@ -0,0 +1,46 @@
+//! Helpers to record breadcrumbs from Rust as easily as from JavaScript.
+//!
+//! ```no_run
+//! use tauri_plugin_sentry::{breadcrumb, breadcrumb_with, sentry::Level};
+//!
+//! #[tauri::command]
+//! fn open_project(path: String) {
+//!     breadcrumb("Opening a project");
+//!     breadcrumb_with("project", Level::Info, [("path", path)]);
+//! }
+//! ```
+
+#![warn(missing_docs)]
+
+use sentry::protocol::Value;
+use sentry::{Breadcrumb, Level};
+
+/// Records a breadcrumb with the given message on the current hub.
+pub fn breadcrumb(message: impl Into<String>) {
+    sentry::add_breadcrumb(Breadcrumb {
+        message: Some(message.into()),
+        ..Default::default()
+    });
+}
+
+/// Records a breadcrumb of the given category, level and data on the current hub.
+///
+/// Without a message, the data of the breadcrumb is displayed in its place.
+pub fn breadcrumb_with<K, V>(
+    category: impl Into<String>,
+    level: Level,
+    data: impl IntoIterator<Item = (K, V)>,
+) where
+    K: Into<String>,
+    V: Into<Value>,
+{
+    sentry::add_breadcrumb(Breadcrumb {
+        category: Some(category.into()),
+        level,
+        data: data
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect(),
+        ..Default::default()
+    });
+}
@@ -0,0 +1,128 @@
+//! A chainable builder for the Sentry plugin.
+//!
+//! The builder produces the same `Options` accepted by `init`, so it is just
+//! a more convenient way to configure the plugin when only a few settings
+//! need to be changed.
+//!
+//! ```no_run
+//! let plugin = tauri_plugin_sentry::SentryPluginBuilder::<tauri::Wry>::new()
+//!     .with_dsn("https://key@sentry.io/42")
+//!     .inject_javascript(false)
+//!     .build();
+//! ```
+
+#![warn(missing_docs)]
+
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use sentry::{ClientOptions, IntoDsn};
+use tauri::{plugin::TauriPlugin, Runtime};
+
+use crate::Options;
+
+/// Builds the Sentry plugin step by step.
+pub struct SentryPluginBuilder<R: Runtime> {
+    options: Options,
+    _runtime: PhantomData<R>,
+}
+
+impl<R: Runtime> Default for SentryPluginBuilder<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: Runtime> SentryPluginBuilder<R> {
+    /// Creates a new builder with the default options.
+    pub fn new() -> Self {
+        Self::from_options(Options::default())
+    }
+
+    /// Creates a new builder starting from the given options.
+    pub fn from_options(options: Options) -> Self {
+        Self {
+            options,
+            _runtime: PhantomData,
+        }
+    }
+
+    /// Replaces the Sentry client options.
+    ///
+    /// This overwrites any DSN previously set with `with_dsn`.
+    #[must_use]
+    pub fn client_options(mut self, client: ClientOptions) -> Self {
+        self.options.client = client;
+        self
+    }
+
+    /// Sets the DSN events are sent to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given value is not a valid DSN.
+    #[must_use]
+    pub fn with_dsn(mut self, dsn: impl IntoDsn) -> Self {
+        self.options.client.dsn = dsn.into_dsn().expect("invalid value for DSN");
+        self
+    }
+
+    /// Sets the environment events are reported in.
+    #[must_use]
+    pub fn environment(mut self, environment: impl Into<Cow<'static, str>>) -> Self {
+        self.options.environment = Some(environment.into());
+        self
+    }
+
+    /// Sets the release events are reported for.
+    #[must_use]
+    pub fn release(mut self, release: impl Into<Cow<'static, str>>) -> Self {
+        self.options.release = Some(release.into());
+        self
+    }
+
+    /// Sets the distribution of the release events are reported for.
+    #[must_use]
+    pub fn dist(mut self, dist: impl Into<Cow<'static, str>>) -> Self {
+        self.options.dist = Some(dist.into());
+        self
+    }
+
+    /// Whether the Sentry browser SDK should be injected in the webviews.
+    #[must_use]
+    pub fn inject_javascript(mut self, inject: bool) -> Self {
+        self.options.javascript.inject = inject;
+        self
+    }
+
+    /// Enables or disables the debug mode of both the Rust client and the
+    /// injected JavaScript SDK, see `Options::debug`.
+    #[must_use]
+    pub fn debug(mut self, debug: bool) -> Self {
+        self.options.debug = debug;
+        self.options.client.debug = debug;
+        self.options.javascript.debug = debug;
+        self
+    }
+
+    /// Sets how long to wait for pending events when the application exits.
+    #[must_use]
+    pub fn flush_timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+        self.options.flush_timeout = timeout.into();
+        self
+    }
+
+    /// Sets the name the plugin is registered with, see `Options::plugin_name`
+    /// for the permissions it requires.
+    #[must_use]
+    pub fn plugin_name(mut self, name: impl Into<String>) -> Self {
+        self.options.plugin_name = name.into();
+        self
+    }
+
+    /// Builds the plugin, initializing the Sentry client.
+    pub fn build(self) -> TauriPlugin<R> {
+        crate::init(self.options)
+    }
+}
@@ -0,0 +1,733 @@
+//! The commands invoked by the injected script and the frontend.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use sentry::protocol::{Attachment, Context, Event, Map, Transaction, Value};
+use sentry::types::Uuid;
+use sentry::{Breadcrumb, Level, ScopeGuard, User};
+use serde::Deserialize;
+use tauri::{AppHandle, Manager, Runtime, State, Window};
+
+use crate::dedup::EventDeduplicator;
+use crate::handle::{JsStatus, JsStatuses};
+use crate::router::HubRouter;
+use crate::sampling::Sampler;
+use crate::session::{SessionInfo, SessionTracker};
+use crate::watchdog::WebviewWatchdog;
+use crate::{consent, feedback, PluginConfig};
+
+/// Checks that an event carries something to report.
+///
+/// Malformed levels are already rejected when the event is deserialized.
+fn validate_event(event: &Event<'_>) -> Result<(), String> {
+    if event.exception.is_empty() && event.message.is_none() && event.logentry.is_none() {
+        return Err("the event has neither an exception nor a message".to_string());
+    }
+
+    Ok(())
+}
+
+/// Checks that a breadcrumb carries something to record.
+///
+/// Malformed levels are already rejected when the breadcrumb is deserialized.
+fn validate_breadcrumb(breadcrumb: &Breadcrumb) -> Result<(), String> {
+    match &breadcrumb.message {
+        Some(message) if message.trim().is_empty() => {
+            Err("the breadcrumb message is empty".to_string())
+        }
+        None if breadcrumb.data.is_empty() => {
+            Err("the breadcrumb has neither a message nor data".to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Replaces the value with its serialization truncated to `max_size` bytes,
+/// if it is longer than that. Returns whether the value has been truncated.
+fn truncate_value(name: &str, value: &mut Value, max_size: usize) -> bool {
+    let serialized = value.to_string();
+    if serialized.len() <= max_size {
+        return false;
+    }
+
+    log::warn!(
+        "{} is {} bytes long, truncating it to {} bytes",
+        name,
+        serialized.len(),
+        max_size
+    );
+    let mut end = max_size;
+    while !serialized.is_char_boundary(end) {
+        end -= 1;
+    }
+    *value = Value::String(format!("{}...", &serialized[..end]));
+
+    true
+}
+
+/// Truncates the extra values and the values of the custom contexts of the
+/// event longer than `max_size` bytes, tagging the event if any is.
+fn truncate_event(event: &mut Event<'_>, max_size: usize) {
+    let mut truncated = false;
+    for (key, value) in event.extra.iter_mut() {
+        truncated |= truncate_value(&format!("the extra {:?}", key), value, max_size);
+    }
+    for (name, context) in event.contexts.iter_mut() {
+        if let Context::Other(values) = context {
+            for (key, value) in values.iter_mut() {
+                let name = format!("the context value {:?}.{:?}", name, key);
+                truncated |= truncate_value(&name, value, max_size);
+            }
+        }
+    }
+
+    if truncated {
+        event
+            .tags
+            .insert("payload_truncated".to_string(), "true".to_string());
+    }
+}
+
+/// Captures an event coming from the webview, tagging it with the label of
+/// the window it has been sent from.
+///
+/// Returns the id of the captured event, to be shown as a reference to the
+/// user or passed to `capture_feedback`, or `None` if the event has been
+/// dropped. Events are silently dropped when not sampled, or when they
+/// duplicate a recent event if deduplication is enabled. Returns an error if
+/// the event has nothing to report.
+#[tauri::command]
+pub(crate) fn event<R: Runtime>(
+    window: Window<R>,
+    config: State<'_, PluginConfig>,
+    router: State<'_, HubRouter>,
+    deduplicator: State<'_, EventDeduplicator>,
+    sampler: State<'_, Sampler>,
+    mut event: Event<'static>,
+) -> Result<Option<String>, String> {
+    validate_event(&event)?;
+    if !sampler.sample() || deduplicator.is_duplicate(&event) {
+        return Ok(None);
+    }
+
+    if config.force_js_platform || event.platform.is_empty() || event.platform == "other" {
+        event.platform = "javascript".into();
+    }
+    // The custom fingerprint set by the frontend is kept as is, but an empty
+    // one would prevent the scope fingerprint and the default grouping to apply.
+    if event.fingerprint.is_empty() {
+        event.fingerprint = Event::default().fingerprint;
+    }
+    event
+        .tags
+        .entry("window".to_string())
+        .or_insert_with(|| window.label().to_string());
+    if let Some(max_size) = config.js_max_value_size {
+        truncate_event(&mut event, max_size);
+    }
+
+    if let Some(before_send_js) = &config.before_send_js {
+        let Some(processed) = before_send_js(event) else {
+            return Ok(None);
+        };
+        event = processed;
+    }
+
+    // A nil id means the event has been dropped by the client (sampling, `before_send`...).
+    let event_id = router.window_hub(window.label()).capture_event(event);
+
+    Ok((!event_id.is_nil()).then(|| event_id.simple().to_string()))
+}
+
+/// Sends a transaction recorded by the tracing of the browser SDK.
+///
+/// The transaction has already been sampled by the browser SDK, with the rate
+/// of `Options::traces_sample_rate`: it is sent as is, with the release and
+/// environment of the client. Returns the id of the transaction, if sent.
+#[tauri::command]
+pub(crate) fn transaction<R: Runtime>(
+    window: Window<R>,
+    router: State<'_, HubRouter>,
+    mut transaction: Transaction<'static>,
+) -> Option<String> {
+    let client = router.javascript_hub().client()?;
+    if !crate::telemetry_enabled() || !client.is_enabled() {
+        return None;
+    }
+
+    let options = client.options();
+    transaction.release = transaction.release.or_else(|| options.release.clone());
+    transaction.environment = transaction
+        .environment
+        .or_else(|| options.environment.clone());
+    transaction.platform = "javascript".into();
+    transaction
+        .tags
+        .entry("window".to_string())
+        .or_insert_with(|| window.label().to_string());
+
+    let event_id = transaction.event_id;
+    client.send_envelope(transaction.into());
+
+    Some(event_id.simple().to_string())
+}
+
+/// Records a breadcrumb coming from the webview, unless it is rejected by the
+/// `breadcrumb_filter` option or reports a call to this plugin, after passing
+/// it through the `before_breadcrumb_js` option.
+fn record_breadcrumb(
+    config: &PluginConfig,
+    router: &HubRouter,
+    label: &str,
+    mut breadcrumb: Breadcrumb,
+) -> Result<(), String> {
+    validate_breadcrumb(&breadcrumb)?;
+
+    // Also matches the `breadcrumbs` command.
+    let ipc_url = format!("plugin%3A{}%7Cbreadcrumb", config.plugin_name);
+    if breadcrumb.category.as_ref().is_some_and(|s| s == "fetch")
+        && breadcrumb
+            .data
+            .get("url")
+            .is_some_and(|u| matches!(u, Value::String(x) if x.contains(&ipc_url)))
+    {
+        return Ok(());
+    }
+    if let Some(breadcrumb_filter) = &config.breadcrumb_filter {
+        if !breadcrumb_filter(&breadcrumb) {
+            return Ok(());
+        }
+    }
+    if let Some(before_breadcrumb_js) = &config.before_breadcrumb_js {
+        let Some(processed) = before_breadcrumb_js(breadcrumb) else {
+            return Ok(());
+        };
+        breadcrumb = processed;
+    }
+
+    // Goes through the client `before_breadcrumb` hook, like native breadcrumbs.
+    router.add_javascript_breadcrumb(label, breadcrumb);
+
+    Ok(())
+}
+
+/// Records a breadcrumb coming from the webview.
+///
+/// Breadcrumbs rejected by the `breadcrumb_filter` option are dropped.
+/// Returns an error if the breadcrumb has an empty message, or neither a
+/// message nor data.
+#[tauri::command]
+pub(crate) fn breadcrumb<R: Runtime>(
+    window: Window<R>,
+    config: State<'_, PluginConfig>,
+    router: State<'_, HubRouter>,
+    breadcrumb: Breadcrumb,
+) -> Result<(), String> {
+    record_breadcrumb(&config, &router, window.label(), breadcrumb)
+}
+
+/// Records a batch of breadcrumbs coming from the webview, in order.
+///
+/// Like `breadcrumb`, but in a single call. The invalid breadcrumbs are
+/// skipped, without failing the batch: the others are recorded in any case.
+#[tauri::command]
+pub(crate) fn breadcrumbs<R: Runtime>(
+    window: Window<R>,
+    config: State<'_, PluginConfig>,
+    router: State<'_, HubRouter>,
+    breadcrumbs: Vec<Breadcrumb>,
+) {
+    for breadcrumb in breadcrumbs {
+        if let Err(err) = record_breadcrumb(&config, &router, window.label(), breadcrumb) {
+            log::debug!("skipping a breadcrumb from the webview: {}", err);
+        }
+    }
+}
+
+/// Captures a simple message, at `info` level unless specified.
+#[tauri::command]
+pub(crate) fn capture_message<R: Runtime>(
+    window: Window<R>,
+    router: State<'_, HubRouter>,
+    message: String,
+    level: Option<Level>,
+) {
+    router
+        .window_hub(window.label())
+        .capture_message(&message, level.unwrap_or(Level::Info));
+}
+
+/// Sets the user on the current scope, or clears it when `None` is passed.
+#[tauri::command]
+pub(crate) fn set_user<R: Runtime>(
+    window: Window<R>,
+    router: State<'_, HubRouter>,
+    user: Option<User>,
+) {
+    router.configure_window_scope(window.label(), |scope| scope.set_user(user.clone()));
+}
+
+/// Sets a tag on the current scope.
+///
+/// The tag applies to every subsequent event, both from JavaScript and Rust,
+/// and persists for the process lifetime unless removed with `remove_tag`.
+#[tauri::command]
+pub(crate) fn set_tag<R: Runtime>(
+    window: Window<R>,
+    router: State<'_, HubRouter>,
+    key: String,
+    value: String,
+) {
+    router.configure_window_scope(window.label(), |scope| scope.set_tag(&key, &value));
+}
+
+/// Removes a tag from the current scope.
+#[tauri::command]
+pub(crate) fn remove_tag<R: Runtime>(window: Window<R>, router: State<'_, HubRouter>, key: String) {
+    router.configure_window_scope(window.label(), |scope| scope.remove_tag(&key));
+}
+
+/// The maximum size of an extra value, serialized as JSON, above which Sentry
+/// would trim it anyway.
+const MAX_EXTRA_SIZE: usize = 16 * 1024;
+
+/// Sets an extra value on the current scope, attached to every subsequent
+/// event (both from JavaScript and Rust).
+///
+/// The value keeps its JSON structure, unless it exceeds 16 KiB once
+/// serialized: it is then replaced with its truncated serialization.
+#[tauri::command]
+pub(crate) fn set_extra<R: Runtime>(
+    window: Window<R>,
+    router: State<'_, HubRouter>,
+    key: String,
+    value: Value,
+) {
+    let mut value = value;
+    truncate_value(&format!("the extra {:?}", key), &mut value, MAX_EXTRA_SIZE);
+
+    router.configure_window_scope(window.label(), |scope| scope.set_extra(&key, value.clone()));
+}
+
+/// Removes an extra value from the current scope.
+#[tauri::command]
+pub(crate) fn remove_extra<R: Runtime>(
+    window: Window<R>,
+    router: State<'_, HubRouter>,
+    key: String,
+) {
+    router.configure_window_scope(window.label(), |scope| scope.remove_extra(&key));
+}
+
+/// Sets a named context (e.g. `gpu`) on the current scope, attached to every
+/// subsequent event (both from JavaScript and Rust), or removes it when the
+/// value is empty.
+///
+/// Values exceeding 16 KiB once serialized are truncated, like extra values.
+#[tauri::command]
+pub(crate) fn set_context<R: Runtime>(
+    window: Window<R>,
+    router: State<'_, HubRouter>,
+    key: String,
+    value: Map<String, Value>,
+) {
+    if value.is_empty() {
+        router.configure_window_scope(window.label(), |scope| scope.remove_context(&key));
+        return;
+    }
+
+    let mut value = value;
+    for (name, value) in value.iter_mut() {
+        truncate_value(
+            &format!("the context value {:?}.{:?}", key, name),
+            value,
+            MAX_EXTRA_SIZE,
+        );
+    }
+
+    router.configure_window_scope(window.label(), |scope| {
+        scope.set_context(&key, Context::Other(value.clone()))
+    });
+}
+
+/// Removes a named context from the current scope.
+#[tauri::command]
+pub(crate) fn remove_context<R: Runtime>(
+    window: Window<R>,
+    router: State<'_, HubRouter>,
+    key: String,
+) {
+    router.configure_window_scope(window.label(), |scope| scope.remove_context(&key));
+}
+
+/// Removes the breadcrumbs recorded so far from the current scope, e.g. once
+/// a sensitive flow has been completed.
+#[tauri::command]
+pub(crate) fn clear_breadcrumbs<R: Runtime>(window: Window<R>, router: State<'_, HubRouter>) {
+    router.configure_window_scope(window.label(), |scope| scope.clear_breadcrumbs());
+}
+
+/// Resets the current scope, e.g. once a sensitive flow has been completed.
+///
+/// Everything set on the scope, from JavaScript or Rust, is cleared: the
+/// user, tags, extra values, contexts, breadcrumbs, attachments, fingerprint,
+/// level and span. The contexts detected when the app was set up and the
+/// `event_processor` option are then restored.
+///
+/// The release health session, which is attached to the scope, is ended and a
+/// new one is started. Only the scope pushed last is reset: the values of the
+/// outer scopes come back once it is popped.
+///
+/// When the window scopes are isolated, only the scope of the calling window
+/// is reset and the session is kept.
+#[tauri::command]
+pub(crate) fn reset_scope<R: Runtime>(
+    window: Window<R>,
+    router: State<'_, HubRouter>,
+    session: State<'_, SessionTracker>,
+) {
+    // The session is bound to the main hub, which is left untouched.
+    if router.isolates_windows() {
+        router.reset_window_scope(window.label());
+        return;
+    }
+
+    // Ended before the scope is cleared, as it would be lost with it.
+    let tracked = session.info().is_some();
+    if tracked {
+        session.end();
+    }
+    router.reset_scope();
+    if tracked {
+        session.start();
+    }
+}
+
+/// Sets the level on the current scope, overriding the level of every
+/// subsequent event, or restores the level of each event when `None` is passed.
+#[tauri::command]
+pub(crate) fn set_level<R: Runtime>(
+    window: Window<R>,
+    router: State<'_, HubRouter>,
+    level: Option<Level>,
+) {
+    router.configure_window_scope(window.label(), |scope| scope.set_level(level));
+}
+
+/// Sets the fingerprint on the current scope, grouping the subsequent events
+/// (both from JavaScript and Rust) together.
+///
+/// An empty fingerprint restores the default grouping. A fingerprint set on a
+/// JavaScript event takes precedence over the scope one.
+#[tauri::command]
+pub(crate) fn set_fingerprint<R: Runtime>(
+    window: Window<R>,
+    router: State<'_, HubRouter>,
+    fingerprint: Vec<String>,
+) {
+    let fingerprint: Vec<&str> = fingerprint.iter().map(String::as_str).collect();
+    router.configure_window_scope(window.label(), |scope| {
+        if fingerprint.is_empty() {
+            scope.set_fingerprint(None);
+        } else {
+            scope.set_fingerprint(Some(&fingerprint));
+        }
+    });
+}
+
+/// A set of changes applied to the current scope at once.
+///
+/// Fields left unset do not change the scope.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub(crate) struct ScopePatch {
+    user: Option<User>,
+    tags: BTreeMap<String, String>,
+    extras: Map<String, Value>,
+    level: Option<Level>,
+    fingerprint: Option<Vec<String>>,
+}
+
+/// Applies several changes to the current scope, sparing an IPC round-trip
+/// for each of them.
+#[tauri::command]
+pub(crate) fn configure_scope<R: Runtime>(
+    window: Window<R>,
+    router: State<'_, HubRouter>,
+    patch: ScopePatch,
+) {
+    let fingerprint: Option<Vec<&str>> = patch
+        .fingerprint
+        .as_ref()
+        .map(|fingerprint| fingerprint.iter().map(String::as_str).collect());
+
+    router.configure_window_scope(window.label(), |scope| {
+        if let Some(user) = &patch.user {
+            scope.set_user(Some(user.clone()));
+        }
+        for (key, value) in &patch.tags {
+            scope.set_tag(key, value);
+        }
+        for (key, value) in &patch.extras {
+            scope.set_extra(key, value.clone());
+        }
+        if patch.level.is_some() {
+            scope.set_level(patch.level);
+        }
+        if let Some(fingerprint) = &fingerprint {
+            scope.set_fingerprint(Some(fingerprint));
+        }
+    });
+}
+
+/// Attaches a file to the current scope, so it is sent with every subsequent event.
+///
+/// This command is not part of the default permission set, as it allows the
+/// webview to upload any file readable by the application.
+#[tauri::command]
+pub(crate) fn attach_file<R: Runtime>(
+    window: Window<R>,
+    config: State<'_, PluginConfig>,
+    router: State<'_, HubRouter>,
+    path: PathBuf,
+    filename: Option<String>,
+) -> Result<(), String> {
+    let size = std::fs::metadata(&path)
+        .map_err(|e| format!("cannot read {}: {}", path.display(), e))?
+        .len();
+    if size > config.max_attachment_size {
+        return Err(format!(
+            "{} is {} bytes, exceeding the maximum attachment size of {} bytes",
+            path.display(),
+            size,
+            config.max_attachment_size
+        ));
+    }
+
+    let buffer =
+        std::fs::read(&path).map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    let filename = filename.unwrap_or_else(|| {
+        path.file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "attachment".to_string())
+    });
+
+    router.configure_window_scope(window.label(), |scope| {
+        scope.add_attachment(Attachment {
+            buffer: buffer.clone(),
+            filename: filename.clone(),
+            ..Default::default()
+        })
+    });
+
+    Ok(())
+}
+
+/// Enables or disables the transmission of telemetry, e.g. when the user
+/// grants or revokes consent.
+#[tauri::command]
+pub(crate) fn set_telemetry_enabled<R: Runtime>(_app: AppHandle<R>, enabled: bool) {
+    consent::set_telemetry_enabled(enabled);
+}
+
+/// Sends a user feedback report, linked to the given event or to the last
+/// captured one if not specified.
+#[tauri::command]
+pub(crate) fn capture_feedback<R: Runtime>(
+    window: Window<R>,
+    router: State<'_, HubRouter>,
+    name: String,
+    email: String,
+    comments: String,
+    event_id: Option<String>,
+) -> Result<(), String> {
+    // The report is sent through the client the webview events are sent with.
+    let hub = router.window_hub(window.label());
+    let event_id = match event_id {
+        Some(event_id) => Uuid::parse_str(&event_id).map_err(|e| e.to_string())?,
+        None => hub
+            .last_event_id()
+            .ok_or("no event has been captured yet")?,
+    };
+
+    feedback::capture_feedback_on(&hub, event_id, &name, &email, &comments);
+
+    Ok(())
+}
+
+/// The scopes pushed by the frontend, popped in LIFO order.
+///
+/// The stacks are kept by window label if the window scopes are isolated, as
+/// each window then pushes on its own hub, and under `None` otherwise.
+#[derive(Default)]
+pub(crate) struct ScopeStack(Mutex<HashMap<Option<String>, Vec<Vec<ScopeGuard>>>>);
+
+impl ScopeStack {
+    /// The key of the stack the given window pushes on.
+    fn key(router: &HubRouter, label: &str) -> Option<String> {
+        router.isolates_windows().then(|| label.to_string())
+    }
+
+    /// Pops the scopes pushed by a destroyed window.
+    pub(crate) fn remove_window(&self, label: &str) {
+        let stack = self.0.lock().unwrap().remove(&Some(label.to_string()));
+        // The guards must be dropped in the reverse order they were pushed.
+        for guards in stack.into_iter().flatten().rev() {
+            drop(guards);
+        }
+    }
+}
+
+/// Pushes a new scope, isolating the changes made to it until `pop_scope`.
+///
+/// Scopes must be popped in the reverse order they have been pushed (LIFO):
+/// `pop_scope` always pops the most recently pushed one, by the calling window
+/// if the window scopes are isolated.
+#[tauri::command]
+pub(crate) fn push_scope<R: Runtime>(
+    window: Window<R>,
+    router: State<'_, HubRouter>,
+    scopes: State<'_, ScopeStack>,
+) {
+    let guards = router.push_window_scope(window.label());
+    let key = ScopeStack::key(&router, window.label());
+    scopes
+        .0
+        .lock()
+        .unwrap()
+        .entry(key)
+        .or_default()
+        .push(guards);
+}
+
+/// Pops the most recently pushed scope, discarding the changes made to it.
+///
+/// Returns an error, leaving the current scope untouched, if no scope has
+/// been pushed with `push_scope`.
+#[tauri::command]
+pub(crate) fn pop_scope<R: Runtime>(
+    window: Window<R>,
+    router: State<'_, HubRouter>,
+    scopes: State<'_, ScopeStack>,
+) -> Result<(), String> {
+    let key = ScopeStack::key(&router, window.label());
+    let guards = scopes.0.lock().unwrap().get_mut(&key).and_then(Vec::pop);
+    match guards {
+        Some(guards) => {
+            drop(guards);
+            Ok(())
+        }
+        None => Err("pop_scope called without a matching push_scope".to_string()),
+    }
+}
+
+/// Flushes the pending events, e.g. before an update or a logout.
+///
+/// Waits at most `timeout_ms` milliseconds, or the client `shutdown_timeout`
+/// (2 seconds by default) if not specified. A timeout of 1 to 2 seconds is
+/// usually enough on a working connection; pass a few hundred milliseconds
+/// when the operation must not be noticeably delayed. Resolves once the
+/// queues have been drained or the timeout has elapsed, without telling which.
+#[tauri::command]
+pub(crate) async fn flush<R: Runtime>(app: AppHandle<R>, timeout_ms: Option<u64>) {
+    let timeout = timeout_ms.map(Duration::from_millis);
+    let _ = tauri::async_runtime::spawn_blocking(move || {
+        if let Some(router) = app.try_state::<HubRouter>() {
+            router.flush(timeout);
+        }
+    })
+    .await;
+}
+
+/// Flushes the pending events, then stops sending, e.g. before a long idle
+/// period.
+///
+/// The events captured afterwards, from any thread, are dropped until
+/// `reinit_sentry` is called. Waits at most `timeout_ms` milliseconds, or the
+/// client `shutdown_timeout` if not specified, for the pending events.
+///
+/// This command is not part of the default permission set, as it allows the
+/// webview to silence crash reporting.
+#[tauri::command]
+pub(crate) async fn shutdown_sentry<R: Runtime>(app: AppHandle<R>, timeout_ms: Option<u64>) {
+    let timeout = timeout_ms.map(Duration::from_millis);
+    let _ = tauri::async_runtime::spawn_blocking(move || {
+        if let Some(router) = app.try_state::<HubRouter>() {
+            router.close(timeout);
+        }
+    })
+    .await;
+}
+
+/// Sends the events again after `shutdown_sentry`.
+///
+/// Does nothing if the clients have not been shut down. This command is not
+/// part of the default permission set, like `shutdown_sentry`.
+#[tauri::command]
+pub(crate) fn reinit_sentry<R: Runtime>(_app: AppHandle<R>, router: State<'_, HubRouter>) {
+    router.reopen();
+}
+
+/// Captures a warning with a minidump of the current process attached, without
+/// crashing, e.g. for a "report a hang" button. Returns the event identifier.
+///
+/// This requires the `minidump` feature and fails if the client is disabled or
+/// the minidump cannot be written.
+///
+/// This command is not part of the default permission set, as it allows the
+/// webview to upload the whole memory of the process.
+#[tauri::command]
+pub(crate) async fn capture_minidump<R: Runtime>(_app: AppHandle<R>) -> Result<String, String> {
+    #[cfg(feature = "minidump")]
+    let result = tauri::async_runtime::spawn_blocking(crate::minidump::capture_minidump)
+        .await
+        .map_err(|err| err.to_string())?
+        .map(|event_id| event_id.as_simple().to_string());
+    #[cfg(not(feature = "minidump"))]
+    let result = Err("minidumps require the `minidump` feature".to_string());
+
+    result
+}
+
+/// Returns the `sentry-trace` header of the span bound to the current scope,
+/// so that the webview can continue the trace.
+#[tauri::command]
+pub(crate) fn trace_header<R: Runtime>(_app: AppHandle<R>) -> Option<String> {
+    crate::current_trace_header()
+}
+
+/// Returns the state of the release health session, or `None` if
+/// `auto_session_tracking` is disabled.
+#[tauri::command]
+pub(crate) fn session_status<R: Runtime>(
+    _app: AppHandle<R>,
+    session: State<'_, SessionTracker>,
+) -> Option<SessionInfo> {
+    session.info()
+}
+
+/// Records that the Sentry browser SDK has been initialized in the window.
+#[tauri::command]
+pub(crate) fn sentry_js_ready<R: Runtime>(
+    window: Window<R>,
+    statuses: State<'_, JsStatuses>,
+    sdk_version: String,
+    integrations: Vec<String>,
+) {
+    let label = window.label().to_string();
+    let status = JsStatus {
+        window: label.clone(),
+        sdk_version,
+        integrations,
+    };
+    statuses.lock().unwrap().insert(label, status);
+}
+
+/// Records that the webview of the window is still responsive.
+#[tauri::command]
+pub(crate) fn heartbeat<R: Runtime>(window: Window<R>, watchdog: State<'_, WebviewWatchdog>) {
+    watchdog.heartbeat(window.label());
+}
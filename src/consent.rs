@@ -0,0 +1,56 @@
+//! Runtime switch gating the transmission of telemetry.
+//!
+//! When the plugin is configured to require consent, nothing is sent to
+//! Sentry until the user grants it. The switch is enforced through the client
+//! `before_send` and `before_breadcrumb` hooks, so it covers events coming
+//! from the webview as well as native events and panics, and by the transport
+//! gate for the envelopes which do not go through them, like the sessions and
+//! the transactions. The envelopes cached offline are not replayed either.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use sentry::protocol::Event;
+use sentry::{Breadcrumb, ClientOptions};
+
+static TELEMETRY_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Enables or disables the transmission of events and breadcrumbs.
+///
+/// While disabled, captured events, breadcrumbs, sessions and transactions
+/// are dropped.
+pub fn set_telemetry_enabled(enabled: bool) {
+    TELEMETRY_ENABLED.store(enabled, Ordering::SeqCst);
+}
+
+/// Whether events and breadcrumbs are currently being transmitted.
+pub fn telemetry_enabled() -> bool {
+    TELEMETRY_ENABLED.load(Ordering::SeqCst)
+}
+
+/// Wraps the client hooks so that they drop everything while telemetry is disabled.
+pub(crate) fn install(options: &mut ClientOptions) {
+    let before_send = options.before_send.take();
+    options.before_send = Some(Arc::new(move |event: Event<'static>| {
+        if !telemetry_enabled() {
+            return None;
+        }
+
+        match &before_send {
+            Some(before_send) => before_send(event),
+            None => Some(event),
+        }
+    }));
+
+    let before_breadcrumb = options.before_breadcrumb.take();
+    options.before_breadcrumb = Some(Arc::new(move |breadcrumb: Breadcrumb| {
+        if !telemetry_enabled() {
+            return None;
+        }
+
+        match &before_breadcrumb {
+            Some(before_breadcrumb) => before_breadcrumb(breadcrumb),
+            None => Some(breadcrumb),
+        }
+    }));
+}
@@ -0,0 +1,74 @@
+//! Native context detected from Tauri and the host platform.
+//!
+//! The contexts are set on the scope when the plugin is set up, so that every
+//! event, including the ones coming from the webview, carries the actual
+//! application and platform information instead of the browser-derived one.
+
+use std::time::SystemTime;
+
+use sentry::protocol::{AppContext, Context, Map};
+use tauri::{AppHandle, Runtime};
+
+use crate::router::HubRouter;
+
+/// Sets the `app`, `os`, `device` and `tauri` contexts on the current scopes.
+pub(crate) fn configure_scope<R: Runtime>(
+    app: &AppHandle<R>,
+    router: &HubRouter,
+    start_time: SystemTime,
+) {
+    let package_info = app.package_info();
+    let build_type = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    let app_context = AppContext {
+        app_start_time: Some(start_time),
+        build_type: Some(build_type.to_string()),
+        app_identifier: Some(app.config().identifier.clone()),
+        app_name: Some(package_info.name.clone()),
+        app_version: Some(package_info.version.to_string()),
+        ..Default::default()
+    };
+
+    let mut tauri_context = Map::new();
+    tauri_context.insert("version".to_string(), tauri::VERSION.into());
+
+    let os = os_context();
+    let device = device_context();
+    router.configure_base_scope(move |scope| {
+        scope.set_context("app", Context::App(Box::new(app_context.clone())));
+        if let Some(os) = &os {
+            scope.set_context("os", os.clone());
+        }
+        scope.set_context("device", device.clone());
+        scope.set_context("tauri", Context::Other(tauri_context.clone()));
+    });
+}
+
+#[cfg(feature = "contexts")]
+fn os_context() -> Option<Context> {
+    sentry::integrations::contexts::utils::os_context()
+}
+
+#[cfg(not(feature = "contexts"))]
+fn os_context() -> Option<Context> {
+    Some(Context::Os(Box::new(sentry::protocol::OsContext {
+        name: Some(std::env::consts::OS.to_string()),
+        ..Default::default()
+    })))
+}
+
+#[cfg(feature = "contexts")]
+fn device_context() -> Context {
+    sentry::integrations::contexts::utils::device_context()
+}
+
+#[cfg(not(feature = "contexts"))]
+fn device_context() -> Context {
+    Context::Device(Box::new(sentry::protocol::DeviceContext {
+        arch: Some(std::env::consts::ARCH.to_string()),
+        ..Default::default()
+    }))
+}
@@ -0,0 +1,76 @@
+//! Deduplication of the events sent by the webview.
+//!
+//! A runaway loop in the frontend can send the same error thousands of times.
+//! When enabled, an event identical to one captured less than the configured
+//! window ago is dropped, so that at most one of them is sent per window.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use sentry::protocol::Event;
+
+/// The maximum number of recent events remembered.
+const MAX_ENTRIES: usize = 64;
+
+/// Remembers the recently captured events to drop their duplicates.
+pub(crate) struct EventDeduplicator {
+    window: Option<Duration>,
+    recent: Mutex<VecDeque<(u64, Instant)>>,
+}
+
+impl EventDeduplicator {
+    /// Creates a deduplicator dropping duplicates within `window`, or none if `None`.
+    pub(crate) fn new(window: Option<Duration>) -> Self {
+        Self {
+            window,
+            recent: Mutex::new(VecDeque::with_capacity(MAX_ENTRIES)),
+        }
+    }
+
+    /// Whether the event duplicates one captured within the window, remembering it if not.
+    pub(crate) fn is_duplicate(&self, event: &Event<'_>) -> bool {
+        let Some(window) = self.window else {
+            return false;
+        };
+
+        let hash = event_hash(event);
+        let now = Instant::now();
+        let mut recent = self.recent.lock().unwrap();
+        while recent
+            .front()
+            .is_some_and(|(_, seen)| now.duration_since(*seen) > window)
+        {
+            recent.pop_front();
+        }
+
+        if recent.iter().any(|(recent_hash, _)| *recent_hash == hash) {
+            return true;
+        }
+
+        if recent.len() == MAX_ENTRIES {
+            recent.pop_front();
+        }
+        recent.push_back((hash, now));
+
+        false
+    }
+}
+
+/// Hashes what identifies an event: its fingerprint, message and exceptions.
+fn event_hash(event: &Event<'_>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    event.fingerprint.hash(&mut hasher);
+    event.message.hash(&mut hasher);
+    if let Some(logentry) = &event.logentry {
+        logentry.message.hash(&mut hasher);
+    }
+    for exception in event.exception.iter() {
+        exception.ty.hash(&mut hasher);
+        exception.value.hash(&mut hasher);
+    }
+
+    hasher.finish()
+}
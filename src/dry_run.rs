@@ -0,0 +1,74 @@
+//! A transport logging the envelopes instead of sending them.
+//!
+//! Used during development, to see what would be sent to Sentry without
+//! making any network request: each item of the envelopes is logged at the
+//! `info` level under `DRY_RUN_LOG_TARGET`, events and transactions as JSON.
+//! They are written to the standard error instead if no `log` logger accepts
+//! them, so that they are not silently lost.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use sentry::protocol::EnvelopeItem;
+use sentry::{ClientOptions, Envelope, Transport, TransportFactory};
+use serde::Serialize;
+
+/// The target the envelopes are logged under, to filter them out of the
+/// loggers forwarding the records to Sentry, like `SentryLogger`.
+pub const DRY_RUN_LOG_TARGET: &str = "tauri_plugin_sentry::dry_run";
+
+/// The DSN set when none is configured, as the client is disabled without one.
+pub(crate) const PLACEHOLDER_DSN: &str = "https://public@sentry.invalid/1";
+
+/// Creates a transport logging the envelopes.
+pub(crate) struct DryRunFactory;
+
+impl TransportFactory for DryRunFactory {
+    fn create_transport(&self, _options: &ClientOptions) -> Arc<dyn Transport> {
+        Arc::new(DryRunTransport)
+    }
+}
+
+/// A transport logging every envelope, synchronously so that nothing is left
+/// to flush.
+struct DryRunTransport;
+
+impl Transport for DryRunTransport {
+    fn send_envelope(&self, envelope: Envelope) {
+        let logged = log::log_enabled!(target: DRY_RUN_LOG_TARGET, log::Level::Info);
+        for item in envelope.items() {
+            if logged {
+                log::info!(target: DRY_RUN_LOG_TARGET, "Sentry dry run: {}", describe(item));
+            } else {
+                eprintln!("Sentry dry run: {}", describe(item));
+            }
+        }
+    }
+
+    fn flush(&self, _timeout: Duration) -> bool {
+        true
+    }
+}
+
+/// Describes an envelope item, without the content of the attachments.
+fn describe(item: &EnvelopeItem) -> String {
+    match item {
+        EnvelopeItem::Event(event) => format!("event {}", to_json(event)),
+        EnvelopeItem::Transaction(transaction) => {
+            format!("transaction {}", to_json(transaction))
+        }
+        EnvelopeItem::SessionUpdate(session) => format!("session {}", to_json(session)),
+        EnvelopeItem::SessionAggregates(sessions) => format!("sessions {}", to_json(sessions)),
+        EnvelopeItem::MonitorCheckIn(check_in) => format!("check-in {}", to_json(check_in)),
+        EnvelopeItem::Attachment(attachment) => format!(
+            "attachment {:?} ({} bytes)",
+            attachment.filename,
+            attachment.buffer.len()
+        ),
+        _ => "unsupported envelope item".to_string(),
+    }
+}
+
+fn to_json(value: &impl Serialize) -> String {
+    serde_json::to_string(value).unwrap_or_else(|e| format!("<not serializable: {}>", e))
+}
@@ -0,0 +1,41 @@
+//! Helpers to report the errors returned by commands.
+//!
+//! Errors are not panics, so they are not reported automatically. The helpers
+//! capture them with their whole chain of sources and the current stacktrace,
+//! and fit in a `map_err` so that the error is still returned to the frontend:
+//!
+//! ```no_run
+//! use tauri_plugin_sentry::capture_error;
+//!
+//! #[tauri::command]
+//! fn read_settings(path: String) -> Result<String, String> {
+//!     std::fs::read_to_string(path).map_err(|e| {
+//!         capture_error(&e);
+//!         e.to_string()
+//!     })
+//! }
+//! ```
+
+#![warn(missing_docs)]
+
+use std::error::Error;
+
+use sentry::types::Uuid;
+use sentry::Hub;
+use sentry_backtrace::current_stacktrace;
+
+#[cfg(feature = "anyhow")]
+pub use sentry::integrations::anyhow::capture_anyhow;
+
+/// Captures an error and its sources as a single event, returning its id.
+///
+/// The current stacktrace is attached to the outermost error, so the event
+/// points to where the error has been captured.
+pub fn capture_error<E: Error + ?Sized>(error: &E) -> Uuid {
+    let mut event = sentry::event_from_error(error);
+    if let Some(exception) = event.exception.last_mut() {
+        exception.stacktrace = current_stacktrace();
+    }
+
+    Hub::with_active(|hub| hub.capture_event(event))
+}
@@ -0,0 +1,40 @@
+//! Flushing of the pending events when the process exits.
+//!
+//! The plugin flushes the clients on `RunEvent::Exit`, which is emitted when
+//! the app exits through `AppHandle::exit`, `AppHandle::restart` or when the
+//! last window is closed. Exiting through `std::process::exit` skips it (and
+//! leaks the managed `ClientInitGuard`), so the clients are flushed again
+//! from an `atexit` hook. Events are still lost if the process is killed or
+//! aborted.
+
+use std::sync::{Arc, Mutex, Once};
+use std::time::Duration;
+
+use sentry::Hub;
+
+static EXIT_FLUSH: Mutex<Option<(Vec<Arc<Hub>>, Duration)>> = Mutex::new(None);
+static REGISTER: Once = Once::new();
+
+extern "C" fn flush_at_exit() {
+    let Ok(exit_flush) = EXIT_FLUSH.lock() else {
+        return;
+    };
+    if let Some((hubs, timeout)) = exit_flush.as_ref() {
+        for client in hubs.iter().filter_map(|hub| hub.client()) {
+            client.flush(Some(*timeout));
+        }
+    }
+}
+
+/// Flushes the clients bound to the given hubs when the process exits, waiting
+/// at most `timeout` (forever if `None`) for each of them.
+///
+/// Replaces the hubs registered by a previous initialization of the plugin.
+pub(crate) fn flush_on_exit(hubs: Vec<Arc<Hub>>, timeout: Option<Duration>) {
+    if let Ok(mut exit_flush) = EXIT_FLUSH.lock() {
+        *exit_flush = Some((hubs, timeout.unwrap_or(Duration::MAX)));
+    }
+    REGISTER.call_once(|| unsafe {
+        libc::atexit(flush_at_exit);
+    });
+}
@@ -0,0 +1,53 @@
+//! User feedback linked to a captured event.
+//!
+//! The Rust SDK has no dedicated envelope item for user reports, so the
+//! feedback is serialized by hand and sent as a raw envelope.
+
+#![warn(missing_docs)]
+
+use sentry::types::Uuid;
+use sentry::{Envelope, Hub};
+
+/// Sends a user feedback report linked to the given event, through the
+/// current hub.
+///
+/// The report is dropped when telemetry is disabled or there is no client.
+pub fn capture_feedback(event_id: Uuid, name: &str, email: &str, comments: &str) {
+    capture_feedback_on(&Hub::current(), event_id, name, email, comments);
+}
+
+/// Sends a user feedback report linked to the given event, through the client
+/// of `hub`, which should be the one the event has been captured on.
+///
+/// The report is dropped when telemetry is disabled or there is no client.
+pub fn capture_feedback_on(hub: &Hub, event_id: Uuid, name: &str, email: &str, comments: &str) {
+    if !crate::telemetry_enabled() {
+        return;
+    }
+
+    let Some(client) = hub.client() else {
+        return;
+    };
+
+    let event_id = event_id.as_simple().to_string();
+    let payload = serde_json::json!({
+        "event_id": event_id,
+        "name": name,
+        "email": email,
+        "comments": comments,
+    })
+    .to_string();
+
+    let mut bytes = serde_json::json!({ "event_id": event_id }).to_string();
+    bytes.push('\n');
+    bytes.push_str(
+        &serde_json::json!({ "type": "user_report", "length": payload.len() }).to_string(),
+    );
+    bytes.push('\n');
+    bytes.push_str(&payload);
+    bytes.push('\n');
+
+    if let Ok(envelope) = Envelope::from_bytes_raw(bytes.into_bytes()) {
+        client.send_envelope(envelope);
+    }
+}
@@ -0,0 +1,94 @@
+//! A transport dropping the envelopes while the clients are shut down, or
+//! while telemetry is disabled.
+//!
+//! The hubs of the threads other than the main one are created from the main
+//! hub on first use and keep its client: binding another client to the main
+//! hub does not reach them. The clients are therefore never replaced, their
+//! transport is wrapped with a gate instead, closed by `shutdown_sentry` and
+//! opened again by `reinit_sentry`, which every hub sharing the client sees.
+//!
+//! Sitting in front of every transport, the gate also holds back what the
+//! client hooks do not see, like the sessions and the transactions, until
+//! telemetry is enabled.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use sentry::{ClientOptions, Envelope, Transport, TransportFactory};
+
+/// The state of the gates of the transports, shared by the clients.
+#[derive(Clone)]
+pub(crate) struct Gate(Arc<AtomicBool>);
+
+impl Default for Gate {
+    fn default() -> Self {
+        Self(Arc::new(AtomicBool::new(true)))
+    }
+}
+
+impl Gate {
+    /// Wraps the transport of the client options with the gate.
+    pub(crate) fn install(&self, options: &mut ClientOptions) {
+        let inner = options
+            .transport
+            .take()
+            .unwrap_or_else(|| Arc::new(crate::transport::DefaultTransportFactory));
+        options.transport = Some(Arc::new(GateFactory {
+            inner,
+            gate: self.clone(),
+        }));
+    }
+
+    /// Drops the envelopes sent from now on.
+    pub(crate) fn close(&self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+
+    /// Sends the envelopes again.
+    pub(crate) fn open(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    fn is_open(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Creates the inner transport, wrapped by the gate.
+struct GateFactory {
+    inner: Arc<dyn TransportFactory>,
+    gate: Gate,
+}
+
+impl TransportFactory for GateFactory {
+    fn create_transport(&self, options: &ClientOptions) -> Arc<dyn Transport> {
+        Arc::new(GateTransport {
+            inner: self.inner.create_transport(options),
+            gate: self.gate.clone(),
+        })
+    }
+}
+
+/// A transport forwarding the envelopes only while the gate is open and
+/// telemetry is enabled.
+struct GateTransport {
+    inner: Arc<dyn Transport>,
+    gate: Gate,
+}
+
+impl Transport for GateTransport {
+    fn send_envelope(&self, envelope: Envelope) {
+        if self.gate.is_open() && crate::telemetry_enabled() {
+            self.inner.send_envelope(envelope);
+        }
+    }
+
+    fn flush(&self, timeout: Duration) -> bool {
+        self.inner.flush(timeout)
+    }
+
+    fn shutdown(&self, timeout: Duration) -> bool {
+        self.inner.shutdown(timeout)
+    }
+}
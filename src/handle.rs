@@ -0,0 +1,91 @@
+//! A handle to the Sentry client created by the plugin.
+//!
+//! The handle is returned by `init_with_handle` and lets advanced users manage
+//! the client lifecycle beyond the automatic flush on exit.
+
+#![warn(missing_docs)]
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use sentry::Client;
+
+/// The status reported by the Sentry browser SDK injected in a window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsStatus {
+    /// The label of the window the SDK runs in.
+    pub window: String,
+    /// The version of the Sentry browser SDK.
+    pub sdk_version: String,
+    /// The names of the integrations enabled in the SDK.
+    pub integrations: Vec<String>,
+}
+
+/// The statuses reported by the injected SDKs, by window label.
+pub(crate) type JsStatuses = Arc<Mutex<BTreeMap<String, JsStatus>>>;
+
+/// Gives access to the Sentry client initialized by the plugin.
+///
+/// The handle is cheap to clone and can be moved across threads.
+#[derive(Debug, Clone)]
+pub struct SentryHandle {
+    client: Option<Arc<Client>>,
+    js_statuses: JsStatuses,
+}
+
+impl SentryHandle {
+    pub(crate) fn new(client: Option<Arc<Client>>) -> Self {
+        Self {
+            client,
+            js_statuses: JsStatuses::default(),
+        }
+    }
+
+    /// The shared store the `sentry_js_ready` command records the statuses in.
+    pub(crate) fn js_statuses(&self) -> JsStatuses {
+        self.js_statuses.clone()
+    }
+
+    /// Returns the underlying client, if any.
+    pub fn client(&self) -> Option<&Arc<Client>> {
+        self.client.as_ref()
+    }
+
+    /// Whether the client is enabled and events are being sent.
+    pub fn is_enabled(&self) -> bool {
+        self.client
+            .as_ref()
+            .is_some_and(|client| client.is_enabled())
+    }
+
+    /// The status reported by the Sentry browser SDK of every window it has
+    /// been initialized in, ordered by window label.
+    ///
+    /// An empty list means that the SDK has not been initialized in any window
+    /// (yet): events will not be received from the webviews.
+    pub fn js_status(&self) -> Vec<JsStatus> {
+        self.js_statuses.lock().unwrap().values().cloned().collect()
+    }
+
+    /// Drains all pending events without shutting down the client, waiting at
+    /// most `timeout` (the client `shutdown_timeout` if `None`).
+    ///
+    /// Whether the queue has been drained in time is unknown: the result of
+    /// `Client::flush` is inverted by the transports of sentry 0.34.
+    pub fn flush(&self, timeout: Option<Duration>) {
+        if let Some(client) = &self.client {
+            client.flush(timeout);
+        }
+    }
+
+    /// Drains all pending events and shuts down the client transport.
+    ///
+    /// Events captured afterwards are dropped. Like `flush`, whether the queue
+    /// has been drained in time is unknown.
+    pub fn close(&self, timeout: Option<Duration>) {
+        if let Some(client) = &self.client {
+            client.close(timeout);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Tracking of the Tauri command being handled, reported with the panics.
+//!
+//! The commands of the application are not routed through the plugin, so the
+//! invoke handler of the application has to be wrapped with `track_commands`:
+//!
+//! ```no_run
+//! #[tauri::command]
+//! fn greet(name: String) -> String {
+//!     format!("Hello, {}!", name)
+//! }
+//!
+//! tauri::Builder::default()
+//!     .invoke_handler(tauri_plugin_sentry::track_commands(tauri::generate_handler![greet]));
+//! ```
+
+#![warn(missing_docs)]
+
+use std::cell::RefCell;
+
+use tauri::ipc::{Invoke, InvokeBody};
+use tauri::Runtime;
+
+thread_local! {
+    /// The name of the command being handled on this thread, and the hash of its arguments.
+    static CURRENT_COMMAND: RefCell<Option<(String, String)>> = const { RefCell::new(None) };
+}
+
+/// Clears the current command when dropped, even if the handler panics.
+struct CommandGuard;
+
+impl Drop for CommandGuard {
+    fn drop(&mut self) {
+        CURRENT_COMMAND.with(|command| command.borrow_mut().take());
+    }
+}
+
+/// Wraps an invoke handler so that the panics raised while handling a
+/// command are tagged with its name (`command`) and a hash of its arguments
+/// (`command.args_hash`), which are not reported as they may be private.
+///
+/// Only the synchronous commands are tracked: async commands are spawned on
+/// the async runtime and run after the handler returns.
+pub fn track_commands<R, F>(handler: F) -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static
+where
+    R: Runtime,
+    F: Fn(Invoke<R>) -> bool + Send + Sync + 'static,
+{
+    move |invoke| {
+        let args_hash = match invoke.message.payload() {
+            InvokeBody::Json(value) => crate::short_hash(value.to_string().as_bytes()),
+            InvokeBody::Raw(bytes) => crate::short_hash(bytes),
+        };
+        let name = invoke.message.command().to_string();
+        CURRENT_COMMAND.with(|command| *command.borrow_mut() = Some((name, args_hash)));
+        let _guard = CommandGuard;
+
+        handler(invoke)
+    }
+}
+
+/// The command being handled on the current thread, and the hash of its arguments.
+pub(crate) fn current_command() -> Option<(String, String)> {
+    CURRENT_COMMAND
+        .try_with(|command| {
+            command
+                .try_borrow()
+                .ok()
+                .and_then(|command| command.clone())
+        })
+        .ok()
+        .flatten()
+}
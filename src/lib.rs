@@ -1,27 +1,188 @@
+mod attachments;
+mod breadcrumb;
+mod builder;
+mod commands;
+mod consent;
+mod context;
+mod dedup;
+mod dry_run;
+mod error;
+mod exit;
+mod feedback;
+mod gate;
+mod handle;
+#[cfg(feature = "panic")]
+mod ipc;
+#[cfg(feature = "minidump")]
+mod minidump;
+mod offline;
+#[cfg(feature = "panic")]
+mod output;
 #[cfg(feature = "panic")]
 mod panic;
+mod router;
+mod sampling;
+mod session;
+mod spool;
+mod startup;
+#[cfg(feature = "test")]
+mod testing;
+mod transaction;
+mod transport;
+mod watchdog;
 
-use sentry::{add_breadcrumb, capture_event, protocol::Event, Breadcrumb, ClientInitGuard};
-use std::time::Duration;
+use sentry::{add_breadcrumb, protocol::Event, types::Dsn, Breadcrumb, Hub, TransportFactory};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 use tauri::{
     generate_handler,
     plugin::{Builder, TauriPlugin},
-    AppHandle, Manager, RunEvent, Runtime,
+    webview::PageLoadEvent,
+    AppHandle, Manager, RunEvent, Runtime, WindowEvent,
 };
 
 pub use sentry;
 pub use sentry::ClientOptions;
-use sentry::protocol::Value;
+use sentry::protocol::Map;
 pub use sentry_log;
 pub use sentry_log::SentryLogger;
 
+pub use attachments::AttachmentLimits;
+pub use breadcrumb::{breadcrumb, breadcrumb_with};
+pub use builder::SentryPluginBuilder;
+pub use consent::{set_telemetry_enabled, telemetry_enabled};
+pub use dry_run::DRY_RUN_LOG_TARGET;
+#[cfg(feature = "anyhow")]
+pub use error::capture_anyhow;
+pub use error::capture_error;
+pub use feedback::{capture_feedback, capture_feedback_on};
+pub use handle::{JsStatus, SentryHandle};
+#[cfg(feature = "panic")]
+pub use ipc::track_commands;
+#[cfg(feature = "minidump")]
+pub use minidump::capture_with_minidump;
 #[cfg(feature = "panic")]
-pub use panic::PanicIntegration;
+pub use output::{record_output, OutputTee};
+#[cfg(feature = "panic")]
+pub use panic::{PanicIntegration, PanicOptions};
+pub use session::SessionInfo;
+pub use spool::spooled_envelopes;
+#[cfg(feature = "test")]
+pub use testing::{install_test_transport, TestTransport};
+pub use transaction::{
+    continue_transaction, current_trace_header, start_transaction, TransactionGuard,
+};
+
+/// Whether the plugin has already been initialized in this process.
+static INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+/// The placeholders of the injected script, replaced when the plugin is initialized.
+const SCRIPT_PLACEHOLDERS: [&str; 7] = [
+    "__DEBUG__",
+    "__PLUGIN_NAME__",
+    "__COMMAND_BREADCRUMBS__",
+    "__HEARTBEAT_INTERVAL__",
+    "__CAPTURE_CONSOLE__",
+    "__TRACES_SAMPLE_RATE__",
+    "__INIT_OPTIONS__",
+];
+
+/// Where the script injected in the webviews is loaded from.
+#[derive(Debug, Clone, Default)]
+pub enum ScriptSource {
+    /// The minified script embedded in the plugin.
+    #[default]
+    Embedded,
+    /// A script read from the given file when the plugin is initialized, e.g.
+    /// an unminified build of the bridge to debug or hotfix it.
+    ///
+    /// The embedded script is used, with a warning, if the file cannot be read.
+    File(PathBuf),
+    /// The given script.
+    Inline(String),
+}
+
+impl ScriptSource {
+    /// Checked to contain each placeholder by the build script.
+    const EMBEDDED: &'static str = include_str!("../dist/inject.min.js");
+
+    /// Loads the script, falling back to the embedded one if it cannot be read
+    /// or does not contain each placeholder exactly once.
+    fn load(&self) -> String {
+        let script = match self {
+            Self::Embedded => return Self::EMBEDDED.to_string(),
+            Self::File(path) => match std::fs::read_to_string(path) {
+                Ok(script) => script,
+                Err(err) => {
+                    log::warn!(
+                        "cannot read the script to inject from {}, using the embedded one: {}",
+                        path.display(),
+                        err
+                    );
+                    return Self::EMBEDDED.to_string();
+                }
+            },
+            Self::Inline(script) => script.clone(),
+        };
+
+        for placeholder in SCRIPT_PLACEHOLDERS {
+            let count = script.matches(placeholder).count();
+            if count != 1 {
+                log::error!(
+                    "the script to inject must contain {} exactly once, found {} times: \
+                     using the embedded one",
+                    placeholder,
+                    count
+                );
+                return Self::EMBEDDED.to_string();
+            }
+        }
+
+        script
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct JavaScriptOptions {
     pub inject: bool,
     pub debug: bool,
+    /// Where the injected script is loaded from.
+    ///
+    /// The placeholders of the bundled script (`__DEBUG__`, `__PLUGIN_NAME__`...)
+    /// are replaced in any case: a script which does not contain each of them
+    /// exactly once is rejected with an error, and the embedded one is used.
+    pub script_source: ScriptSource,
+    /// Labels of the windows the script is injected into.
+    ///
+    /// An empty list means the script is injected in every window. When this
+    /// list or `deny_windows` is set, the script is no longer registered for
+    /// every webview: it is evaluated by the plugin in the selected windows
+    /// instead, each time a page starts loading in them.
+    pub allow_windows: Vec<String>,
+    /// Labels of the windows the script must never be injected into.
+    ///
+    /// See `allow_windows` for how the script is then injected.
+    pub deny_windows: Vec<String>,
+    /// Whether the errors of the webview are reported even when they are not
+    /// seen by the Sentry browser SDK.
+    ///
+    /// `console.error` calls are recorded as breadcrumbs and uncaught errors
+    /// are captured as events, in place of the `GlobalHandlers` integration of
+    /// the browser SDK.
+    ///
+    /// The unhandled promise rejections are always captured by the injected
+    /// script rather than by the browser SDK, with the stacktrace of their
+    /// reason, whether this is enabled or not.
+    pub capture_console: bool,
+    /// Tags set on the initial scope of the injected SDK.
+    ///
+    /// The release and environment of the Rust client are always passed to
+    /// the injected SDK, so that it does not need to be initialized again.
+    pub tags: BTreeMap<String, String>,
 }
 
 impl Default for JavaScriptOptions {
@@ -34,70 +195,1171 @@ impl Default for JavaScriptOptions {
         Self {
             inject: true,
             debug,
+            script_source: ScriptSource::default(),
+            allow_windows: vec![],
+            deny_windows: vec![],
+            capture_console: false,
+            tags: BTreeMap::new(),
         }
     }
 }
 
-#[derive(Debug, Clone, Default)]
+/// A callback that can modify or drop (by returning `None`) a value.
+pub type BeforeCallback<T> = Arc<dyn Fn(T) -> Option<T> + Send + Sync>;
+
+/// A callback deciding whether a value is kept (by returning `true`).
+pub type FilterCallback<T> = Arc<dyn Fn(&T) -> bool + Send + Sync>;
+
+/// A callback observing a value without modifying it.
+pub type ObserveCallback<T> = Arc<dyn Fn(&T) + Send + Sync>;
+
+#[derive(Clone)]
 pub struct Options {
     pub javascript: JavaScriptOptions,
+    /// The options of the Sentry client.
+    ///
+    /// If no DSN is set, it is read from the `SENTRY_DSN` environment variable,
+    /// as the native Sentry SDK does: an explicit DSN always wins. An empty or
+    /// invalid variable leaves the client disabled, discarding every event.
     pub client: ClientOptions,
+    /// How long to wait for pending events to be sent when the application exits.
+    ///
+    /// `None` blocks until every event has been flushed. Setting this too high
+    /// can noticeably delay the application shutdown on slow connections.
+    ///
+    /// Pending events are flushed when the app exits through `AppHandle::exit`,
+    /// `AppHandle::restart`, the last window being closed, or `std::process::exit`.
+    /// They are lost if the process is killed or aborted.
+    pub flush_timeout: Option<Duration>,
+    /// The name the plugin is registered with (`sentry` by default).
+    ///
+    /// Change it if another plugin already uses the `sentry` namespace: the
+    /// injected script will invoke its commands under the given name.
+    ///
+    /// The permissions shipped with the crate only apply to the `sentry` name,
+    /// every command is denied under another one. The application must then
+    /// generate them for its name, in its build script, from the commands and
+    /// default permissions the crate exposes to it:
+    ///
+    /// ```ignore
+    /// fn list(var: &str) -> Vec<String> {
+    ///     std::env::var(var).unwrap().split(',').map(String::from).collect()
+    /// }
+    ///
+    /// let commands: Vec<&'static str> = list("DEP_TAURI_PLUGIN_SENTRY_COMMANDS")
+    ///     .into_iter()
+    ///     .map(|command| &*Box::leak(command.into_boxed_str()))
+    ///     .collect();
+    /// tauri_build::try_build(tauri_build::Attributes::new().plugin(
+    ///     "crash-reporter",
+    ///     tauri_build::InlinedPlugin::new()
+    ///         .commands(commands.leak())
+    ///         .default_permission(tauri_build::DefaultPermissionRule::Allow(list(
+    ///             "DEP_TAURI_PLUGIN_SENTRY_DEFAULT_PERMISSIONS",
+    ///         ))),
+    /// ))
+    /// .unwrap();
+    /// ```
+    ///
+    /// The capabilities then refer to `crash-reporter:default` instead of
+    /// `sentry:default`.
+    pub plugin_name: String,
+    /// How panics are reported by the default `PanicIntegration`.
+    ///
+    /// The default integration is not installed if `client.integrations`
+    /// already contains a panic integration, in which case these are ignored.
+    #[cfg(feature = "panic")]
+    pub panic: PanicOptions,
+    /// Called on every event received from the webview before it is captured.
+    ///
+    /// Events sent through the `event` command get their `platform` set first
+    /// (see `force_js_platform`), then go through this callback and finally through
+    /// the client `before_send` hook, as any other captured event.
+    /// Returning `None` drops the event.
+    pub before_send_js: Option<BeforeCallback<Event<'static>>>,
+    /// Starts a release health session when the app is set up and ends it
+    /// when the app exits.
+    pub auto_session_tracking: bool,
+    /// Records a `ui.window` breadcrumb for every window event (focus, resize,
+    /// close...). Disabled by default as it can be noisy.
+    pub window_breadcrumbs: bool,
+    /// The maximum size, in bytes, of a file attached through the `attach_file` command.
+    pub max_attachment_size: u64,
+    /// Whether the user consent is required before sending any telemetry.
+    ///
+    /// When set, events and breadcrumbs (including panics) are dropped until
+    /// telemetry is enabled with the `set_telemetry_enabled` command or function.
+    pub require_consent: bool,
+    /// Whether events received from the webview always get their platform set
+    /// to `javascript`.
+    ///
+    /// When disabled, the platform is only set if the event does not specify one,
+    /// so that events relayed by the frontend (e.g. from a sidecar) keep theirs.
+    pub force_js_platform: bool,
+    /// The environment, overriding `ClientOptions::environment` when set.
+    pub environment: Option<Cow<'static, str>>,
+    /// The release, overriding `ClientOptions::release` when set.
+    pub release: Option<Cow<'static, str>>,
+    /// The distribution of the release, telling apart its builds (e.g. the
+    /// installers of each architecture).
+    ///
+    /// Set on the native events, and passed to the injected SDK so that the
+    /// events from the webview carry the same one.
+    pub dist: Option<Cow<'static, str>>,
+    /// The name of the machine, overriding `ClientOptions::server_name` when set.
+    pub server_name: Option<Cow<'static, str>>,
+    /// Reports a hash of the hostname as the server name, so that events from
+    /// the same machine can be correlated without sending its name.
+    ///
+    /// The hash is salted with the public key of the DSN. It is not meant to
+    /// resist someone who knows that key: a hostname guessed from a
+    /// dictionary can be checked against it.
+    ///
+    /// The hostname is only known with the `contexts` feature, otherwise no
+    /// server name is reported. Ignored if a server name is configured.
+    pub anonymize_server_name: bool,
+    /// Prefixes of the functions (e.g. `my_app::`) whose stack frames belong
+    /// to the application, added to `ClientOptions::in_app_include`.
+    ///
+    /// The other frames are collapsed in the Sentry UI. The classification
+    /// also applies to the stacktraces of panics.
+    pub in_app_include: Vec<&'static str>,
+    /// Prefixes of the functions whose stack frames never belong to the
+    /// application, added to `ClientOptions::in_app_exclude`.
+    pub in_app_exclude: Vec<&'static str>,
+    /// Rewrites the paths of the native stack frames starting with a prefix
+    /// (e.g. `/Users/alice`) with its replacement (e.g. `~`), so that the
+    /// build machine paths do not leak the names of its users.
+    ///
+    /// The first matching prefix is replaced in the frames file names and the
+    /// panic location, before the client `before_send` hook.
+    pub path_prefix_map: Vec<(String, String)>,
+    /// Sets the `app`, `os` and `device` contexts detected from Tauri and the
+    /// host platform on the scope when the app is set up.
+    pub auto_context: bool,
+    /// Options of a separate client the events from the webview are sent with.
+    ///
+    /// When set, the events, messages and breadcrumbs coming from the frontend
+    /// are sent to the project of this client, while native events and panics
+    /// keep using `client`. Scope changes made from the frontend apply to both.
+    ///
+    /// Each client owns its own transport (and its worker thread and queue)
+    /// and the webview hub keeps its own scope, so this roughly doubles the
+    /// memory and network resources used by the plugin. The integrations of
+    /// the main client, such as the panic one, are not installed on it.
+    pub javascript_client: Option<ClientOptions>,
+    /// Gives each window its own scope, so that the tags, user, breadcrumbs
+    /// and other values set by a window only apply to the events it captures.
+    ///
+    /// The window scopes start as a copy of the webview scope (see
+    /// `javascript_client`) when the window first calls the plugin. Native
+    /// events and panics keep using the main scope, which still records the
+    /// breadcrumbs of every window.
+    pub isolate_window_scopes: bool,
+    /// The transport used to send events, overriding `ClientOptions::transport`.
+    ///
+    /// Lets the application reuse its own HTTP client (connection pool, TLS
+    /// configuration...) instead of the one enabled by the `transport` feature,
+    /// which can then be disabled. It is also used by `javascript_client`,
+    /// unless the latter specifies its own transport.
+    pub transport: Option<Arc<dyn TransportFactory>>,
+    /// A directory where the envelopes are cached while Sentry is unreachable.
+    ///
+    /// When set, the Sentry host is probed before sending and, if offline, the
+    /// envelopes are written to this directory instead. They are sent when the
+    /// host becomes reachable again or at the next launch. Envelopes still
+    /// queued by the transport when the application exits are not cached.
+    pub offline_cache_dir: Option<PathBuf>,
+    /// A directory where the envelopes are written instead of being sent.
+    ///
+    /// When set, no network request is ever made to Sentry: `transport` and
+    /// `offline_cache_dir` are ignored, and the envelopes are kept in this
+    /// directory until uploaded by another tool (see `spooled_envelopes`).
+    /// A DSN is still required, as the client is disabled without one.
+    pub spool_dir: Option<PathBuf>,
+    /// Logs the envelopes at the `info` level, under `DRY_RUN_LOG_TARGET`,
+    /// instead of sending them, to see what would be sent during development.
+    ///
+    /// Without a `log` logger accepting them (e.g. `env_logger`), the
+    /// envelopes are written to the standard error instead. Filter the target
+    /// out of a `SentryLogger`, which would otherwise record each envelope as
+    /// a breadcrumb, embedded in the next one:
+    ///
+    /// ```
+    /// use tauri_plugin_sentry::sentry_log::{default_filter, LogFilter};
+    /// use tauri_plugin_sentry::{SentryLogger, DRY_RUN_LOG_TARGET};
+    ///
+    /// let logger = SentryLogger::new().filter(|metadata| match metadata.target() {
+    ///     DRY_RUN_LOG_TARGET => LogFilter::Ignore,
+    ///     _ => default_filter(metadata),
+    /// });
+    /// ```
+    ///
+    /// When set, no network request is ever made to Sentry: `transport`,
+    /// `offline_cache_dir` and `spool_dir` are ignored, and a placeholder DSN
+    /// is set if none is configured. The contents of the attachments are not
+    /// logged, only their names and sizes.
+    pub dry_run: bool,
+    /// Limits on the attachments of each event (minidump, output, files...).
+    ///
+    /// Sentry rejects an event whose attachments are too large altogether.
+    /// When set, the attachments exceeding the limits are dropped before
+    /// sending, the other ones first and crash reports last, and the event is
+    /// tagged with `attachments_truncated`. `None` sends every attachment.
+    pub attachment_limits: Option<AttachmentLimits>,
+    /// Enables the debug mode of both the Rust client and the injected JavaScript SDK.
+    ///
+    /// Unlike `JavaScriptOptions::debug`, this does not depend on `debug_assertions`,
+    /// so it can be turned on in release builds. When disabled, the debug flags
+    /// of `client`, `javascript_client` and `javascript` are left as they are.
+    pub debug: bool,
+    /// Records a `ui.action` breadcrumb for every Tauri command invoked by the
+    /// webview, to reconstruct what the user did before an error.
+    ///
+    /// The commands are recorded by the injected script, so this has no effect
+    /// if `javascript.inject` is disabled.
+    pub command_breadcrumbs: bool,
+    /// Drops the events from the webview identical to one captured less than
+    /// this duration ago, so that a runaway loop does not exhaust the quota.
+    ///
+    /// Events are compared by fingerprint, message and exceptions. `None`
+    /// disables the deduplication.
+    pub js_event_dedup_window: Option<Duration>,
+    /// The share of the events from the webview to keep, between 0 and 1.
+    ///
+    /// It is applied before capturing the event, so `ClientOptions::sample_rate`
+    /// still applies afterwards: webview events are kept at the product of both
+    /// rates, while native events and panics only depend on the client one.
+    /// `None` keeps every event.
+    pub js_sample_rate: Option<f32>,
+    /// The share of the traces to record, between 0 and 1, enabling the
+    /// performance monitoring on both the Rust and the webview sides.
+    ///
+    /// It overrides `ClientOptions::traces_sample_rate` (and the one of
+    /// `javascript_client`) and enables the `BrowserTracing` integration of the
+    /// injected script, whose transactions are sent through the Rust client.
+    /// `None` leaves the client options as they are and the webview tracing off.
+    pub traces_sample_rate: Option<f32>,
+    /// The maximum size, in bytes once serialized as JSON, of each extra value
+    /// and custom context value of the events from the webview, e.g. to keep
+    /// a serialized application state from exceeding the Sentry size limits.
+    ///
+    /// Longer values are replaced with their truncated serialization, and the
+    /// event is tagged with `payload_truncated`. `None` keeps them as they are.
+    pub js_max_value_size: Option<usize>,
+    /// Measures the time from the plugin initialization to the app being ready,
+    /// recorded as an `app.lifecycle` breadcrumb and an `app.start` transaction.
+    pub track_startup: bool,
+    /// Skips injecting the script in the webviews when the client is disabled
+    /// (e.g. no DSN is configured), since their events would be discarded anyway.
+    ///
+    /// A warning is logged in any case when the client is disabled.
+    pub skip_js_when_disabled: bool,
+    /// Called on every breadcrumb received from the webview: the breadcrumb is
+    /// dropped if it returns `false`, e.g. to drop some levels or categories.
+    ///
+    /// The kept breadcrumbs still go through the client `before_breadcrumb`
+    /// hook and are limited by `ClientOptions::max_breadcrumbs`.
+    pub breadcrumb_filter: Option<FilterCallback<Breadcrumb>>,
+    /// Called on every event, both from the webview and native, to modify it
+    /// (e.g. to scrub some data) or drop it by returning `None`.
+    ///
+    /// It is registered as an event processor on the scope of every hub when
+    /// the plugin is initialized, so it runs after `before_send_js` and before
+    /// the client `before_send` hook.
+    pub event_processor: Option<BeforeCallback<Event<'static>>>,
+    /// Called on every breadcrumb, both from the webview and native, to modify
+    /// it or drop it by returning `None`, before the client `before_breadcrumb` hook.
+    pub breadcrumb_processor: Option<BeforeCallback<Breadcrumb>>,
+    /// Called on every breadcrumb received from the webview and kept by
+    /// `breadcrumb_filter`, e.g. to prefix their categories with `js.`.
+    /// Returning `None` drops the breadcrumb.
+    ///
+    /// Unlike `breadcrumb_processor`, native breadcrumbs are not affected.
+    /// The breadcrumbs then go through `breadcrumb_processor` and the client
+    /// `before_breadcrumb` hook, as any other recorded breadcrumb.
+    pub before_breadcrumb_js: Option<BeforeCallback<Breadcrumb>>,
+    /// Called on every event about to be sent, both from the webview and
+    /// native, e.g. to count the captured events by level.
+    ///
+    /// It runs after the client `before_send` hook, so dropped events are not
+    /// observed. It is called on the capturing thread and must return quickly:
+    /// hand the event over to another thread for any expensive work.
+    pub on_capture: Option<ObserveCallback<Event<'static>>>,
+    /// Adds the milliseconds elapsed since the plugin was initialized to the
+    /// breadcrumbs (as their `uptime_ms` data) and events (as their
+    /// `uptime_ms` extra value), both from the webview and native, to
+    /// correlate them with the startup phases.
+    ///
+    /// The uptime is computed when they were recorded, from a monotonic clock,
+    /// and the values already set are kept.
+    pub record_uptime: bool,
+    /// Reports an error when a webview crashes or stays unresponsive for more
+    /// than 90 seconds, detected through a heartbeat sent by the injected script.
+    ///
+    /// This has no effect if `javascript.inject` is disabled.
+    pub report_webview_crashes: bool,
+    /// Installs a global `tracing` subscriber turning spans and events into
+    /// Sentry transactions, breadcrumbs and events.
+    ///
+    /// Tauri apps usually set up their own subscriber, in which case this
+    /// fails with a warning: leave it disabled and add the Sentry layer
+    /// to the existing subscriber instead:
+    ///
+    /// ```ignore
+    /// use tracing_subscriber::prelude::*;
+    ///
+    /// tracing_subscriber::registry()
+    ///     .with(tracing_subscriber::fmt::layer())
+    ///     .with(tauri_plugin_sentry::sentry::integrations::tracing::layer())
+    ///     .init();
+    /// ```
+    #[cfg(feature = "tracing")]
+    pub capture_tracing: bool,
+}
+
+impl std::fmt::Debug for Options {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        #[derive(Debug)]
+        struct BeforeSendJs;
+        let before_send_js = self.before_send_js.as_ref().map(|_| BeforeSendJs);
+        #[derive(Debug)]
+        struct Transport;
+        let transport = self.transport.as_ref().map(|_| Transport);
+        #[derive(Debug)]
+        struct BreadcrumbFilter;
+        let breadcrumb_filter = self.breadcrumb_filter.as_ref().map(|_| BreadcrumbFilter);
+        #[derive(Debug)]
+        struct EventProcessor;
+        let event_processor = self.event_processor.as_ref().map(|_| EventProcessor);
+        #[derive(Debug)]
+        struct BreadcrumbProcessor;
+        let breadcrumb_processor = self
+            .breadcrumb_processor
+            .as_ref()
+            .map(|_| BreadcrumbProcessor);
+        #[derive(Debug)]
+        struct BeforeBreadcrumbJs;
+        let before_breadcrumb_js = self
+            .before_breadcrumb_js
+            .as_ref()
+            .map(|_| BeforeBreadcrumbJs);
+        #[derive(Debug)]
+        struct OnCapture;
+        let on_capture = self.on_capture.as_ref().map(|_| OnCapture);
+
+        let mut debug = f.debug_struct("Options");
+        debug
+            .field("javascript", &self.javascript)
+            .field("client", &self.client)
+            .field("flush_timeout", &self.flush_timeout)
+            .field("plugin_name", &self.plugin_name);
+        #[cfg(feature = "panic")]
+        debug.field("panic", &self.panic);
+        debug
+            .field("before_send_js", &before_send_js)
+            .field("auto_session_tracking", &self.auto_session_tracking)
+            .field("window_breadcrumbs", &self.window_breadcrumbs)
+            .field("max_attachment_size", &self.max_attachment_size)
+            .field("require_consent", &self.require_consent)
+            .field("force_js_platform", &self.force_js_platform)
+            .field("environment", &self.environment)
+            .field("release", &self.release)
+            .field("dist", &self.dist)
+            .field("server_name", &self.server_name)
+            .field("anonymize_server_name", &self.anonymize_server_name)
+            .field("in_app_include", &self.in_app_include)
+            .field("in_app_exclude", &self.in_app_exclude)
+            .field("path_prefix_map", &self.path_prefix_map)
+            .field("auto_context", &self.auto_context)
+            .field("javascript_client", &self.javascript_client)
+            .field("isolate_window_scopes", &self.isolate_window_scopes)
+            .field("transport", &transport)
+            .field("offline_cache_dir", &self.offline_cache_dir)
+            .field("spool_dir", &self.spool_dir)
+            .field("dry_run", &self.dry_run)
+            .field("attachment_limits", &self.attachment_limits)
+            .field("debug", &self.debug)
+            .field("command_breadcrumbs", &self.command_breadcrumbs)
+            .field("js_event_dedup_window", &self.js_event_dedup_window)
+            .field("js_sample_rate", &self.js_sample_rate)
+            .field("traces_sample_rate", &self.traces_sample_rate)
+            .field("js_max_value_size", &self.js_max_value_size)
+            .field("track_startup", &self.track_startup)
+            .field("skip_js_when_disabled", &self.skip_js_when_disabled)
+            .field("breadcrumb_filter", &breadcrumb_filter)
+            .field("event_processor", &event_processor)
+            .field("breadcrumb_processor", &breadcrumb_processor)
+            .field("before_breadcrumb_js", &before_breadcrumb_js)
+            .field("on_capture", &on_capture)
+            .field("record_uptime", &self.record_uptime)
+            .field("report_webview_crashes", &self.report_webview_crashes);
+        #[cfg(feature = "tracing")]
+        debug.field("capture_tracing", &self.capture_tracing);
+        debug.finish()
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            javascript: JavaScriptOptions::default(),
+            client: ClientOptions::default(),
+            flush_timeout: Some(Duration::from_secs(5)),
+            plugin_name: "sentry".to_string(),
+            #[cfg(feature = "panic")]
+            panic: PanicOptions::default(),
+            before_send_js: None,
+            auto_session_tracking: true,
+            window_breadcrumbs: false,
+            max_attachment_size: 10 * 1024 * 1024,
+            require_consent: false,
+            force_js_platform: true,
+            environment: None,
+            release: None,
+            dist: None,
+            server_name: None,
+            anonymize_server_name: false,
+            in_app_include: vec![],
+            in_app_exclude: vec![],
+            path_prefix_map: vec![],
+            auto_context: true,
+            javascript_client: None,
+            isolate_window_scopes: false,
+            transport: None,
+            offline_cache_dir: None,
+            spool_dir: None,
+            dry_run: false,
+            attachment_limits: None,
+            debug: false,
+            command_breadcrumbs: false,
+            js_event_dedup_window: None,
+            js_sample_rate: None,
+            traces_sample_rate: None,
+            js_max_value_size: None,
+            track_startup: false,
+            skip_js_when_disabled: false,
+            breadcrumb_filter: None,
+            event_processor: None,
+            breadcrumb_processor: None,
+            before_breadcrumb_js: None,
+            on_capture: None,
+            record_uptime: false,
+            report_webview_crashes: false,
+            #[cfg(feature = "tracing")]
+            capture_tracing: false,
+        }
+    }
+}
+
+/// Plugin configuration needed by the commands, managed in the app state.
+pub(crate) struct PluginConfig {
+    pub(crate) plugin_name: String,
+    pub(crate) before_send_js: Option<BeforeCallback<Event<'static>>>,
+    pub(crate) max_attachment_size: u64,
+    pub(crate) force_js_platform: bool,
+    pub(crate) breadcrumb_filter: Option<FilterCallback<Breadcrumb>>,
+    pub(crate) before_breadcrumb_js: Option<BeforeCallback<Breadcrumb>>,
+    pub(crate) js_max_value_size: Option<usize>,
 }
 
-#[tauri::command]
-fn event<R: Runtime>(_app: AppHandle<R>, mut event: Event<'static>) {
-    event.platform = "javascript".into();
-    capture_event(event);
+/// The script evaluated in the windows selected by label, managed in the app
+/// state when `JavaScriptOptions::allow_windows` or `deny_windows` is set.
+struct WindowScript {
+    script: String,
+    allow_windows: Vec<String>,
+    deny_windows: Vec<String>,
 }
 
-#[tauri::command]
-fn breadcrumb<R: Runtime>(_app: AppHandle<R>, breadcrumb: Breadcrumb) {
-    if breadcrumb.category.as_ref().is_some_and(|s| s == "fetch") &&
-        breadcrumb.data.get("url").is_some_and(|u| {
-            matches!(u, Value::String(x) if x.contains("plugin%3Asentry%7Cbreadcrumb"))
-        }) {
-        return;
+impl WindowScript {
+    /// Whether the script is injected in the window with the given label.
+    fn allows(&self, label: &str) -> bool {
+        let listed = |labels: &[String]| labels.iter().any(|listed| listed == label);
+        (self.allow_windows.is_empty() || listed(&self.allow_windows))
+            && !listed(&self.deny_windows)
     }
+}
+
+/// Builds the breadcrumb recording a window event.
+fn window_event_breadcrumb(label: &str, event: &WindowEvent) -> Breadcrumb {
+    let name = match event {
+        WindowEvent::Resized(_) => "resized",
+        WindowEvent::Moved(_) => "moved",
+        WindowEvent::CloseRequested { .. } => "close-requested",
+        WindowEvent::Destroyed => "destroyed",
+        WindowEvent::Focused(true) => "focused",
+        WindowEvent::Focused(false) => "blurred",
+        WindowEvent::ScaleFactorChanged { .. } => "scale-factor-changed",
+        WindowEvent::DragDrop(_) => "drag-drop",
+        WindowEvent::ThemeChanged(_) => "theme-changed",
+        _ => "other",
+    };
+
+    let mut data = Map::new();
+    data.insert("label".to_string(), label.into());
+    data.insert("event".to_string(), name.into());
+
+    Breadcrumb {
+        category: Some("ui.window".to_string()),
+        message: Some(format!("Window {}: {}", label, name)),
+        data,
+        ..Default::default()
+    }
+}
+
+/// Reads the DSN from the `SENTRY_DSN` environment variable.
+///
+/// An empty or invalid value is ignored, with a warning if invalid.
+fn dsn_from_env() -> Option<Dsn> {
+    let dsn = std::env::var("SENTRY_DSN")
+        .ok()
+        .filter(|dsn| !dsn.is_empty())?;
+    match dsn.parse() {
+        Ok(dsn) => Some(dsn),
+        Err(err) => {
+            log::warn!("ignoring the invalid DSN in SENTRY_DSN: {}", err);
+            None
+        }
+    }
+}
+
+/// Sets a global `tracing` subscriber forwarding spans and events to Sentry.
+#[cfg(feature = "tracing")]
+fn install_tracing_layer() {
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+
+    let subscriber = tracing_subscriber::registry().with(sentry::integrations::tracing::layer());
+    if let Err(err) = subscriber.try_init() {
+        log::warn!(
+            "cannot install the Sentry tracing subscriber, add the layer to yours instead: {}",
+            err
+        );
+    }
+}
+
+/// Hashes the hostname, salted with `salt`, so that it is stable for the
+/// project.
+///
+/// The hash is truncated and salted, so it cannot be matched against the
+/// hashes of other projects. It can still be brute-forced by whoever knows the
+/// salt (the public key of the DSN, shipped with the application) and can
+/// guess the hostname, e.g. from a dictionary of common names.
+fn anonymized_server_name(salt: &str) -> Option<String> {
+    #[cfg(feature = "contexts")]
+    let hostname = sentry::integrations::contexts::utils::server_name();
+    #[cfg(not(feature = "contexts"))]
+    let hostname: Option<String> = None;
+
+    hostname.map(|hostname| short_hash(format!("{}:{}", salt, hostname).as_bytes()))
+}
+
+/// The first 8 bytes of the SHA-256 hash of the data, in hexadecimal.
+pub(crate) fn short_hash(data: &[u8]) -> String {
+    openssl::sha::sha256(data)[..8]
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Runs `processor` on the breadcrumbs before the `before_breadcrumb` hook of
+/// the client options.
+fn install_breadcrumb_processor(
+    client_options: &mut ClientOptions,
+    processor: BeforeCallback<Breadcrumb>,
+) {
+    let before_breadcrumb = client_options.before_breadcrumb.take();
+    client_options.before_breadcrumb = Some(Arc::new(move |breadcrumb| {
+        let breadcrumb = processor(breadcrumb)?;
+        match &before_breadcrumb {
+            Some(before_breadcrumb) => before_breadcrumb(breadcrumb),
+            None => Some(breadcrumb),
+        }
+    }));
+}
+
+/// Runs `observer` on the events kept by the `before_send` hook of the
+/// client options.
+fn install_capture_observer(
+    client_options: &mut ClientOptions,
+    observer: ObserveCallback<Event<'static>>,
+) {
+    let before_send = client_options.before_send.take();
+    client_options.before_send = Some(Arc::new(move |event| {
+        let event = match &before_send {
+            Some(before_send) => before_send(event)?,
+            None => event,
+        };
+        observer(&event);
+        Some(event)
+    }));
+}
+
+/// Rewrites the paths of the stack frames and of the panic location with the
+/// first matching prefix before the `before_send` hook of the client options.
+fn install_path_remapping(client_options: &mut ClientOptions, prefixes: Vec<(String, String)>) {
+    let remap = move |path: &mut String| {
+        if let Some((prefix, replacement)) = prefixes
+            .iter()
+            .find(|(prefix, _)| path.starts_with(prefix.as_str()))
+        {
+            path.replace_range(..prefix.len(), replacement);
+        }
+    };
+
+    let before_send = client_options.before_send.take();
+    client_options.before_send = Some(Arc::new(move |mut event: Event<'static>| {
+        let threads = event.threads.iter_mut();
+        let stacktraces = event
+            .exception
+            .iter_mut()
+            .filter_map(|exception| exception.stacktrace.as_mut())
+            .chain(threads.filter_map(|thread| thread.stacktrace.as_mut()))
+            .chain(event.stacktrace.as_mut());
+        for frame in stacktraces.flat_map(|stacktrace| stacktrace.frames.iter_mut()) {
+            frame.filename.iter_mut().for_each(&remap);
+            frame.abs_path.iter_mut().for_each(&remap);
+        }
+        if let Some(location) = event.tags.get_mut("panic.location") {
+            remap(location);
+        }
+
+        match &before_send {
+            Some(before_send) => before_send(event),
+            None => Some(event),
+        }
+    }));
+}
+
+/// Replaces the transport of the client options with one logging the envelopes,
+/// setting a placeholder DSN if needed to enable the client.
+fn install_dry_run(client_options: &mut ClientOptions) {
+    if client_options.dsn.is_none() {
+        client_options.dsn = dry_run::PLACEHOLDER_DSN.parse().ok();
+    }
+    client_options.transport = Some(Arc::new(dry_run::DryRunFactory));
+}
+
+/// Adds the uptime when the breadcrumbs and events were recorded before the
+/// `before_breadcrumb` and `before_send` hooks of the client options.
+fn install_uptime(client_options: &mut ClientOptions, start: Instant) {
+    let before_breadcrumb = client_options.before_breadcrumb.take();
+    client_options.before_breadcrumb = Some(Arc::new(move |mut breadcrumb: Breadcrumb| {
+        let uptime_ms = uptime_ms(start, breadcrumb.timestamp);
+        breadcrumb
+            .data
+            .entry("uptime_ms".to_string())
+            .or_insert_with(|| uptime_ms.into());
+        match &before_breadcrumb {
+            Some(before_breadcrumb) => before_breadcrumb(breadcrumb),
+            None => Some(breadcrumb),
+        }
+    }));
+
+    let before_send = client_options.before_send.take();
+    client_options.before_send = Some(Arc::new(move |mut event: Event<'static>| {
+        let uptime_ms = uptime_ms(start, event.timestamp);
+        event
+            .extra
+            .entry("uptime_ms".to_string())
+            .or_insert_with(|| uptime_ms.into());
+        match &before_send {
+            Some(before_send) => before_send(event),
+            None => Some(event),
+        }
+    }));
+}
 
-    add_breadcrumb(breadcrumb);
+/// The milliseconds elapsed between `start` and `timestamp`, measured on the
+/// monotonic clock from the age of the timestamp.
+fn uptime_ms(start: Instant, timestamp: SystemTime) -> u64 {
+    let age = SystemTime::now()
+        .duration_since(timestamp)
+        .unwrap_or_default();
+    start.elapsed().saturating_sub(age).as_millis() as u64
+}
+
+/// Wraps the transport of the client options with the offline cache.
+fn install_offline_cache(client_options: &mut ClientOptions, dir: PathBuf) {
+    let inner = client_options
+        .transport
+        .take()
+        .unwrap_or_else(|| Arc::new(transport::DefaultTransportFactory));
+    client_options.transport = Some(Arc::new(offline::OfflineCacheFactory::new(inner, dir)));
+}
+
+/// Serializes the options of the injected SDK matching the ones of the Rust
+/// client: its release, distribution and environment, and the initial tags.
+fn javascript_init_options(
+    client: &ClientOptions,
+    dist: Option<&str>,
+    tags: &BTreeMap<String, String>,
+) -> String {
+    let mut init_options = serde_json::Map::new();
+    if let Some(release) = &client.release {
+        init_options.insert("release".to_string(), release.as_ref().into());
+    }
+    if let Some(dist) = dist {
+        init_options.insert("dist".to_string(), dist.into());
+    }
+    if let Some(environment) = &client.environment {
+        init_options.insert("environment".to_string(), environment.as_ref().into());
+    }
+    if !tags.is_empty() {
+        init_options.insert(
+            "initialScope".to_string(),
+            serde_json::json!({ "tags": tags }),
+        );
+    }
+
+    serde_json::Value::Object(init_options).to_string()
+}
+
+/// Wraps the transport of the client options to enforce the attachment limits.
+fn install_attachment_limits(client_options: &mut ClientOptions, limits: AttachmentLimits) {
+    let inner = client_options
+        .transport
+        .take()
+        .unwrap_or_else(|| Arc::new(transport::DefaultTransportFactory));
+    client_options.transport = Some(Arc::new(attachments::AttachmentLimitsFactory::new(
+        inner, limits,
+    )));
+}
+
+/// Flushes the managed clients, waiting at most `timeout` (forever if `None`).
+fn flush_client<R: Runtime>(app: &AppHandle<R>, timeout: Option<Duration>) {
+    // `ClientInitGuard::flush` falls back to `shutdown_timeout` on `None`.
+    let timeout = timeout.unwrap_or(Duration::MAX);
+    // The state is missing if the setup failed: do not panic on top of it.
+    match app.try_state::<router::HubRouter>() {
+        Some(router) => router.flush(Some(timeout)),
+        None => log::warn!("the Sentry plugin has not been set up, pending events are not flushed"),
+    }
 }
 
 pub fn init<R>(options: Options) -> TauriPlugin<R>
 where
     R: Runtime,
 {
+    init_with_handle(options).0
+}
+
+/// Initializes the plugin with the client options built by `client`, which
+/// is given the release name.
+///
+/// This replaces the callback-based `sentry_tauri::init` of the older
+/// versions. The other settings keep their default: use `init` to change them.
+///
+/// ```no_run
+/// use tauri_plugin_sentry::{sentry, ClientOptions};
+///
+/// let plugin = tauri_plugin_sentry::init_with_client::<tauri::Wry, _>(
+///     sentry::release_name!(),
+///     |release| ClientOptions {
+///         dsn: "https://key@sentry.io/42".parse().ok(),
+///         debug: release.is_some_and(|release| release.contains("-dev")),
+///         ..Default::default()
+///     },
+/// );
+/// ```
+pub fn init_with_client<R, F>(release: Option<Cow<'static, str>>, client: F) -> TauriPlugin<R>
+where
+    R: Runtime,
+    F: FnOnce(Option<&str>) -> ClientOptions,
+{
+    let client = client(release.as_deref());
+    init(Options {
+        client,
+        release,
+        ..Default::default()
+    })
+}
+
+/// Initializes the plugin, also returning a handle to the Sentry client.
+///
+/// The handle can be used to flush or close the client at any time, in
+/// addition to the automatic flush performed when the app exits.
+///
+/// Initializing the plugin again (e.g. in tests) replaces the client of the
+/// current hub and clears its scope, dropping the state set up by the previous
+/// initialization. The clients bound by the previous one keep working until
+/// their guard is dropped, but are no longer flushed when the process exits.
+///
+/// The client, the panic hook and the exit flush are set up by this function,
+/// so panics are reported from then on, even before the plugin is registered
+/// or while the app is being set up. The state of the commands is managed
+/// first thing in the plugin setup: the commands invoked before it fail with
+/// an error, and a failed setup drops the client after reporting the panic.
+pub fn init_with_handle<R>(options: Options) -> (TauriPlugin<R>, SentryHandle)
+where
+    R: Runtime,
+{
+    if INITIALIZED.swap(true, Ordering::SeqCst) {
+        log::warn!("the Sentry plugin has already been initialized, replacing the previous client");
+        Hub::current().configure_scope(|scope| scope.clear());
+    }
+
+    let start_time = SystemTime::now();
+    let start_instant = Instant::now();
+    // Shared by the clients, so that `shutdown_sentry` reaches every hub.
+    let gate = gate::Gate::default();
     let sentry_client = {
-        #[allow(unused_mut)]
-        let mut options = options.client;
-        if options.default_integrations {
-            #[cfg(feature = "panic")]
-            options
+        let mut client_options = options.client;
+        if options.environment.is_some() {
+            client_options.environment = options.environment;
+        }
+        if options.release.is_some() {
+            client_options.release = options.release;
+        }
+        if let Some(rate) = options.traces_sample_rate {
+            client_options.traces_sample_rate = rate;
+        }
+        if options.server_name.is_some() {
+            client_options.server_name = options.server_name;
+        }
+        client_options
+            .in_app_include
+            .extend(&options.in_app_include);
+        client_options
+            .in_app_exclude
+            .extend(&options.in_app_exclude);
+        if options.transport.is_some() {
+            client_options.transport = options.transport.clone();
+        }
+        if options.dry_run {
+            log::info!("Sentry dry run: the envelopes are logged and never sent");
+            install_dry_run(&mut client_options);
+        } else if let Some(dir) = &options.spool_dir {
+            log::info!("Sentry envelopes are spooled to {:?} and never sent", dir);
+            client_options.transport = Some(Arc::new(spool::SpoolFactory::new(dir.clone())));
+        } else if let Some(dir) = &options.offline_cache_dir {
+            install_offline_cache(&mut client_options, dir.clone());
+        }
+        if let Some(limits) = options.attachment_limits {
+            install_attachment_limits(&mut client_options, limits);
+        }
+        if options.debug {
+            client_options.debug = true;
+        }
+        if client_options.dsn.is_none() {
+            client_options.dsn = dsn_from_env();
+        }
+        if options.anonymize_server_name && client_options.server_name.is_none() {
+            let salt = client_options
+                .dsn
+                .as_ref()
+                .map_or("", |dsn| dsn.public_key());
+            client_options.server_name = anonymized_server_name(salt).map(Cow::Owned);
+        }
+        let dsn = match client_options.dsn {
+            Some(_) => "set",
+            None => "not set, events are discarded",
+        };
+        log::info!(
+            "initializing Sentry plugin {:?}: DSN {}, environment {:?}, release {:?}, \
+             debug {} (JavaScript {}), JavaScript injection {}, consent required {}",
+            options.plugin_name,
+            dsn,
+            client_options.environment,
+            client_options.release,
+            client_options.debug,
+            options.javascript.debug || options.debug,
+            options.javascript.inject,
+            options.require_consent,
+        );
+        if let Some(processor) = &options.breadcrumb_processor {
+            install_breadcrumb_processor(&mut client_options, processor.clone());
+        }
+        if let Some(observer) = &options.on_capture {
+            install_capture_observer(&mut client_options, observer.clone());
+        }
+        if !options.path_prefix_map.is_empty() {
+            install_path_remapping(&mut client_options, options.path_prefix_map.clone());
+        }
+        if options.record_uptime {
+            install_uptime(&mut client_options, start_instant);
+        }
+        consent::set_telemetry_enabled(!options.require_consent);
+        consent::install(&mut client_options);
+        gate.install(&mut client_options);
+        // A panic integration registered by the application replaces the default one.
+        #[cfg(feature = "panic")]
+        if client_options.default_integrations
+            && !client_options
+                .integrations
+                .iter()
+                .any(|integration| integration.name() == "panic")
+        {
+            client_options
                 .integrations
-                .insert(0, std::sync::Arc::new(PanicIntegration::default()))
+                .insert(0, Arc::new(PanicIntegration::with_options(options.panic)))
         }
 
-        sentry::init(options)
+        sentry::init(client_options)
     };
+    if !sentry_client.is_enabled() {
+        log::warn!(
+            "the Sentry client is disabled and every event will be discarded: \
+             set a valid DSN in `ClientOptions::dsn` or the SENTRY_DSN environment variable"
+        );
+    }
+    let js_init_options = javascript_init_options(
+        sentry_client.options(),
+        options.dist.as_deref(),
+        &options.javascript.tags,
+    );
+    let javascript_client = options.javascript_client.map(|mut javascript_options| {
+        if javascript_options.transport.is_none() {
+            javascript_options.transport = options.transport;
+        }
+        if options.debug {
+            javascript_options.debug = true;
+        }
+        if let Some(rate) = options.traces_sample_rate {
+            javascript_options.traces_sample_rate = rate;
+        }
+        // The envelopes do not carry the DSN, keep them apart from the main ones.
+        if options.dry_run {
+            install_dry_run(&mut javascript_options);
+        } else if let Some(dir) = &options.spool_dir {
+            javascript_options.transport =
+                Some(Arc::new(spool::SpoolFactory::new(dir.join("javascript"))));
+        } else if let Some(dir) = &options.offline_cache_dir {
+            install_offline_cache(&mut javascript_options, dir.join("javascript"));
+        }
+        if let Some(limits) = options.attachment_limits {
+            install_attachment_limits(&mut javascript_options, limits);
+        }
+        if let Some(processor) = &options.breadcrumb_processor {
+            install_breadcrumb_processor(&mut javascript_options, processor.clone());
+        }
+        if let Some(observer) = &options.on_capture {
+            install_capture_observer(&mut javascript_options, observer.clone());
+        }
+        if options.record_uptime {
+            install_uptime(&mut javascript_options, start_instant);
+        }
+        javascript_options
+    });
+    let router = router::HubRouter::new(javascript_client, options.isolate_window_scopes, gate);
+    if let Some(dist) = options.dist {
+        router.configure_base_scope(move |scope| {
+            let dist = dist.clone();
+            scope.add_event_processor(move |mut event| {
+                event.dist.get_or_insert_with(|| dist.clone());
+                Some(event)
+            });
+        });
+    }
+    if let Some(processor) = options.event_processor {
+        router.configure_base_scope(move |scope| {
+            let processor = processor.clone();
+            scope.add_event_processor(move |event| processor(event));
+        });
+    }
+    #[cfg(feature = "tracing")]
+    if options.capture_tracing {
+        install_tracing_layer();
+    }
+    let handle = SentryHandle::new(Hub::main().client());
+    exit::flush_on_exit(router.hubs(), options.flush_timeout);
 
-    let mut plugin_builder = Builder::new("sentry")
-        .invoke_handler(generate_handler![event, breadcrumb])
-        .setup(|app, _api| {
+    let flush_timeout = options.flush_timeout;
+    let auto_session_tracking = options.auto_session_tracking;
+    let window_breadcrumbs = options.window_breadcrumbs;
+    let auto_context = options.auto_context;
+    let js_statuses = handle.js_statuses();
+    let report_webview_crashes = options.report_webview_crashes;
+    let watchdog = watchdog::WebviewWatchdog::default();
+    let setup_watchdog = watchdog.clone();
+    let session = session::SessionTracker::default();
+    let setup_session = session.clone();
+    let deduplicator = dedup::EventDeduplicator::new(options.js_event_dedup_window);
+    let sampler = sampling::Sampler::new(options.js_sample_rate);
+    let mut startup = options
+        .track_startup
+        .then(|| startup::StartupTracker::start(start_time));
+    // Registered for every webview, or evaluated in the selected windows only.
+    let mut init_script = None;
+    let mut window_script = None;
+    let inject =
+        options.javascript.inject && (handle.is_enabled() || !options.skip_js_when_disabled);
+    if inject {
+        // A zero interval disables the heartbeat.
+        let heartbeat_interval = if report_webview_crashes {
+            watchdog::HEARTBEAT_INTERVAL.as_millis()
+        } else {
+            0
+        };
+        let script = options
+            .javascript
+            .script_source
+            .load()
+            .replace(
+                "__DEBUG__",
+                &format!("{}", options.javascript.debug || options.debug),
+            )
+            .replace("__PLUGIN_NAME__", &format!("{:?}", options.plugin_name))
+            .replace(
+                "__COMMAND_BREADCRUMBS__",
+                &format!("{}", options.command_breadcrumbs),
+            )
+            .replace("__HEARTBEAT_INTERVAL__", &format!("{}", heartbeat_interval))
+            .replace(
+                "__CAPTURE_CONSOLE__",
+                &format!("{}", options.javascript.capture_console),
+            )
+            .replace(
+                "__TRACES_SAMPLE_RATE__",
+                &options
+                    .traces_sample_rate
+                    .map_or_else(|| "null".to_string(), |rate| rate.to_string()),
+            )
+            .replace("__INIT_OPTIONS__", &js_init_options);
+        let javascript = options.javascript;
+        if javascript.allow_windows.is_empty() && javascript.deny_windows.is_empty() {
+            init_script = Some(script);
+        } else {
+            window_script = Some(WindowScript {
+                script,
+                allow_windows: javascript.allow_windows,
+                deny_windows: javascript.deny_windows,
+            });
+        }
+    }
+
+    // The builder requires a static name: the plugin lives as long as the app.
+    let plugin_name: &'static str = Box::leak(options.plugin_name.into_boxed_str());
+    let config = PluginConfig {
+        plugin_name: plugin_name.to_string(),
+        before_send_js: options.before_send_js,
+        max_attachment_size: options.max_attachment_size,
+        force_js_platform: options.force_js_platform,
+        breadcrumb_filter: options.breadcrumb_filter,
+        before_breadcrumb_js: options.before_breadcrumb_js,
+        js_max_value_size: options.js_max_value_size,
+    };
+
+    let mut plugin_builder = Builder::new(plugin_name)
+        .invoke_handler(generate_handler![
+            commands::event,
+            commands::breadcrumb,
+            commands::breadcrumbs,
+            commands::capture_message,
+            commands::set_user,
+            commands::set_tag,
+            commands::remove_tag,
+            commands::attach_file,
+            commands::set_telemetry_enabled,
+            commands::capture_feedback,
+            commands::push_scope,
+            commands::pop_scope,
+            commands::flush,
+            commands::configure_scope,
+            commands::sentry_js_ready,
+            commands::set_fingerprint,
+            commands::set_level,
+            commands::heartbeat,
+            commands::set_extra,
+            commands::remove_extra,
+            commands::capture_minidump,
+            commands::trace_header,
+            commands::shutdown_sentry,
+            commands::reinit_sentry,
+            commands::session_status,
+            commands::set_context,
+            commands::remove_context,
+            commands::transaction,
+            commands::clear_breadcrumbs,
+            commands::reset_scope
+        ])
+        .setup(move |app, _api| {
+            // Everything is managed before the setup steps which could fail or
+            // panic, so that the commands and the exit flush find their state.
+            app.manage(setup_watchdog.clone());
+            app.manage(setup_session.clone());
             app.manage(sentry_client);
+            app.manage(config);
+            app.manage(commands::ScopeStack::default());
+            app.manage(deduplicator);
+            app.manage(sampler);
+            app.manage(js_statuses);
+            app.manage(router);
+            if let Some(window_script) = window_script {
+                app.manage(window_script);
+            }
+
+            if report_webview_crashes {
+                let app = app.clone();
+                setup_watchdog.spawn(move |label| {
+                    app.get_webview_window(label).map_or(true, |window| {
+                        window.is_visible().unwrap_or(true)
+                            && !window.is_minimized().unwrap_or(false)
+                    })
+                });
+            }
+            if auto_context {
+                context::configure_scope(app, &app.state(), start_time);
+            }
+            if auto_session_tracking {
+                setup_session.start();
+            }
+
             Ok(())
         })
-        .on_event(|app, event| {
-            if let RunEvent::Exit = event {
-                let client = app.state::<ClientInitGuard>();
-                client.flush(Some(Duration::from_secs(5)));
+        .on_event(move |app, event| match event {
+            // Start delivering pending events as soon as possible, without
+            // blocking: the process may be killed before `Exit` is emitted.
+            RunEvent::ExitRequested { .. } => flush_client(app, Some(Duration::ZERO)),
+            RunEvent::Ready => {
+                if let Some(startup) = &mut startup {
+                    startup.ready();
+                }
             }
-        });
+            RunEvent::WindowEvent { label, event, .. } => {
+                if let WindowEvent::Destroyed = event {
+                    watchdog.forget(label);
+                    if let Some(router) = app.try_state::<router::HubRouter>() {
+                        router.remove_window(label);
+                    }
+                    if let Some(scopes) = app.try_state::<commands::ScopeStack>() {
+                        scopes.remove_window(label);
+                    }
+                }
+                if window_breadcrumbs {
+                    add_breadcrumb(window_event_breadcrumb(label, event))
+                }
+            }
+            RunEvent::Exit => {
+                if auto_session_tracking {
+                    session.end();
+                }
 
-    if options.javascript.inject {
-        plugin_builder = plugin_builder.js_init_script(
-            include_str!("../dist/inject.min.js")
-                .replace("__DEBUG__", &format!("{}", options.javascript.debug)),
-        );
+                flush_client(app, flush_timeout)
+            }
+            _ => {}
+        })
+        .on_page_load(|webview, payload| {
+            // Emitted once the new document is committed, before its scripts run.
+            if !matches!(payload.event(), PageLoadEvent::Started) {
+                return;
+            }
+            let Some(window_script) = webview.try_state::<WindowScript>() else {
+                return;
+            };
+            if window_script.allows(webview.label()) {
+                if let Err(err) = webview.eval(&window_script.script) {
+                    log::error!(
+                        "cannot inject the Sentry script in window {:?}: {}",
+                        webview.label(),
+                        err
+                    );
+                }
+            }
+        });
+    if let Some(script) = init_script {
+        plugin_builder = plugin_builder.js_init_script(script);
     }
 
-    plugin_builder.build()
+    (plugin_builder.build(), handle)
 }
@@ -0,0 +1,335 @@
+//! Minidumps of the process, attached to the panic events.
+//!
+//! Enabled by the `minidump` feature, which pulls `minidump-writer`: without
+//! it, panics are still reported, just without a minidump attached.
+
+#[cfg(target_os = "windows")]
+use std::io::{Read, Seek};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use sentry::protocol::{Attachment, Event, Level};
+use sentry::types::Uuid;
+use sentry::{Hub, Scope};
+
+use crate::panic::{PanicIntegration, PanicOptions};
+
+pub(crate) fn get_dump_fn(options: &PanicOptions) -> PathBuf {
+    let pid = std::process::id();
+    let mut dump_fn = options.minidump_dir();
+    dump_fn.push(format!("{}{}.mdmp", options.minidump_prefix, pid));
+
+    dump_fn
+}
+
+/// Checks that the minidumps can be written to the given directory.
+fn check_minidump_dir(dir: &Path) -> std::io::Result<()> {
+    if !dir.is_dir() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "not an existing directory",
+        ));
+    }
+
+    let probe = dir.join(format!(".write_test_{}", std::process::id()));
+    std::fs::File::create(&probe)?;
+    std::fs::remove_file(probe)
+}
+
+/// Removes the minidumps left over by previous runs, older than `max_age`.
+fn remove_stale_minidumps(dir: PathBuf, prefix: String, max_age: Duration) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if !name.starts_with(&prefix) || !name.ends_with(".mdmp") {
+            continue;
+        }
+
+        let is_stale = entry
+            .metadata()
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age > max_age);
+        if is_stale {
+            let _ = std::fs::remove_file(entry.path());
+        }
+    }
+}
+
+/// The OS identifier of the thread that panicked or received a fatal signal.
+#[cfg(target_os = "linux")]
+pub(crate) type ThreadId = libc::pid_t;
+#[cfg(target_os = "macos")]
+pub(crate) type ThreadId = minidump_writer::mach2::mach_types::thread_t;
+#[cfg(target_os = "windows")]
+pub(crate) type ThreadId = u32;
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+pub(crate) type ThreadId = u32;
+
+/// Returns the identifier of the current thread.
+///
+/// This must be called from the thread that panicked, before doing anything
+/// else, so that the minidump blames the actual crashing thread.
+#[cfg(target_os = "linux")]
+pub(crate) fn current_thread_id() -> ThreadId {
+    unsafe { libc::syscall(libc::SYS_gettid) as ThreadId }
+}
+
+#[cfg(target_os = "macos")]
+pub(crate) fn current_thread_id() -> ThreadId {
+    unsafe { minidump_writer::mach2::mach_init::mach_thread_self() }
+}
+
+#[cfg(target_os = "windows")]
+pub(crate) fn current_thread_id() -> ThreadId {
+    unsafe { windows_sys::Win32::System::Threading::GetCurrentThreadId() }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+pub(crate) fn current_thread_id() -> ThreadId {
+    0
+}
+
+/// The path of a written minidump and its content, `None` if the minidump has
+/// been written but cannot be read back.
+type WrittenMinidump = (PathBuf, Option<Vec<u8>>);
+
+#[cfg(target_os = "linux")]
+pub(crate) fn write_minidump(
+    crashing_thread: ThreadId,
+    dump_fn: PathBuf,
+) -> Result<WrittenMinidump, Box<dyn std::error::Error>> {
+    let mut writer = minidump_writer::minidump_writer::MinidumpWriter::new(
+        std::process::id() as _,
+        crashing_thread,
+    );
+
+    writer.sanitize_stack();
+
+    let mut minidump_file = std::fs::File::create(&dump_fn)?;
+
+    Ok((dump_fn, Some(writer.dump(&mut minidump_file)?)))
+}
+
+#[cfg(target_os = "macos")]
+pub(crate) fn write_minidump(
+    crashing_thread: ThreadId,
+    dump_fn: PathBuf,
+) -> Result<WrittenMinidump, Box<dyn std::error::Error>> {
+    // Dumps the current process, the crashing thread being the one handling the panic.
+    let mut writer =
+        minidump_writer::minidump_writer::MinidumpWriter::new(None, Some(crashing_thread));
+
+    let mut minidump_file = std::fs::File::create(&dump_fn)?;
+
+    Ok((dump_fn, Some(writer.dump(&mut minidump_file)?)))
+}
+
+#[cfg(target_os = "windows")]
+pub(crate) fn write_minidump(
+    crashing_thread: ThreadId,
+    dump_fn: PathBuf,
+) -> Result<WrittenMinidump, Box<dyn std::error::Error>> {
+    // Opened for reading as well, as the minidump is read back once written.
+    let mut minidump_file = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(&dump_fn)?;
+
+    // Attempts to write the minidump
+    minidump_writer::minidump_writer::MinidumpWriter::dump_local_context(
+        // The exception code, presumably one of STATUS_*. Defaults to STATUS_NONCONTINUABLE_EXCEPTION if not specified
+        None,
+        // If not specified, uses the current thread as the "crashing" thread,
+        // but it could be any thread in the process
+        Some(crashing_thread),
+        None,
+        &mut minidump_file,
+    )?;
+
+    let mut buf = vec![];
+    let read = minidump_file
+        .seek(std::io::SeekFrom::Start(0))
+        .and_then(|_| minidump_file.read_to_end(&mut buf));
+    if let Err(err) = read {
+        log::warn!(
+            "the minidump has been written to {}, but cannot be read back: {}",
+            dump_fn.display(),
+            err
+        );
+        return Ok((dump_fn, None));
+    }
+
+    Ok((dump_fn, Some(buf)))
+}
+
+// Android and iOS are not supported yet by minidump-writer.
+// Other platforms are not supported, and probably never will: panics are
+// still reported, just without a minidump attached.
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+pub(crate) fn write_minidump(
+    _crashing_thread: ThreadId,
+    _dump_fn: PathBuf,
+) -> Result<WrittenMinidump, Box<dyn std::error::Error>> {
+    Err("minidumps are not supported on this platform".into())
+}
+
+/// Checks the minidump directory and starts the stale minidumps sweep, when
+/// the panic integration is set up.
+pub(crate) fn setup(options: &PanicOptions) {
+    let dir = options.minidump_dir();
+    if options.attach_minidump {
+        if let Err(err) = check_minidump_dir(&dir) {
+            log::warn!(
+                "minidumps cannot be written to {}, panics will be reported without them: {}",
+                dir.display(),
+                err
+            );
+        }
+    }
+
+    if let Some(max_age) = options.stale_minidump_max_age {
+        let prefix = options.minidump_prefix.clone();
+        std::thread::spawn(move || remove_stale_minidumps(dir, prefix, max_age));
+    }
+}
+
+/// Writes a minidump blaming the given thread and attaches it to the scope,
+/// returning its path.
+///
+/// The event is still captured, without the minidump, if it cannot be written.
+/// If it has been written but cannot be read back, it is kept on disk and its
+/// path is set as the `minidump_path` extra value, so that it can be collected.
+pub(crate) fn attach(
+    scope: &mut Scope,
+    crashing_thread: ThreadId,
+    options: &PanicOptions,
+) -> Option<PathBuf> {
+    let path = get_dump_fn(options);
+    let Ok((filename, buffer)) = write_minidump(crashing_thread, path.clone()) else {
+        let _ = std::fs::remove_file(path);
+        return None;
+    };
+    let Some(buffer) = buffer else {
+        scope.set_extra("minidump_path", filename.to_string_lossy().into());
+        return None;
+    };
+
+    scope.add_attachment(attachment(&filename, buffer, options));
+
+    Some(filename)
+}
+
+/// Flushes the client of the hub, then removes the minidump attached to the
+/// captured event, which has been read in memory.
+///
+/// The minidump is kept on disk if the transport reports that the flush did
+/// not complete: it is then removed by the stale minidumps sweep on a later run.
+pub(crate) fn flush_and_remove(hub: &Hub, path: Option<PathBuf>) {
+    let flushed = hub.client().is_some_and(|client| client.flush(None));
+    if let (true, Some(path)) = (flushed, path) {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// Creates the attachment of a minidump, of the type set in the options.
+fn attachment(filename: &Path, buffer: Vec<u8>, options: &PanicOptions) -> Attachment {
+    Attachment {
+        buffer,
+        filename: filename.to_string_lossy().to_string(),
+        content_type: options.minidump_content_type.clone(),
+        ty: Some(options.minidump_attachment_type),
+    }
+}
+
+/// Writes a minidump of the current process, for a capture not caused by a panic.
+///
+/// The minidump is written with the options of the panic integration, if
+/// installed.
+fn write_manual_minidump(hub: &Hub) -> Result<(PathBuf, Attachment), String> {
+    if !crate::telemetry_enabled() || !hub.client().is_some_and(|client| client.is_enabled()) {
+        return Err("the Sentry client is disabled".to_string());
+    }
+
+    let thread = current_thread_id();
+    let options =
+        hub.with_integration(|integration: &PanicIntegration| integration.options.clone());
+    // Not named after the process only, so that it cannot clash with a panic one.
+    let path = get_dump_fn(&options).with_extension("manual.mdmp");
+    let (filename, buffer) = write_minidump(thread, path.clone()).map_err(|err| {
+        let _ = std::fs::remove_file(&path);
+        err.to_string()
+    })?;
+    let buffer = buffer.ok_or_else(|| {
+        format!(
+            "the minidump has been kept at {}, as it cannot be read back",
+            filename.display()
+        )
+    })?;
+
+    let attachment = attachment(&filename, buffer, &options);
+
+    Ok((filename, attachment))
+}
+
+/// Captures the event with the given minidump attached, removing it once sent.
+fn capture_with_attachment(
+    hub: &Hub,
+    minidump: (PathBuf, Attachment),
+    event: Event<'static>,
+) -> Uuid {
+    let (filename, attachment) = minidump;
+    let event_id = hub.with_scope(
+        |scope| scope.add_attachment(attachment),
+        || hub.capture_event(event),
+    );
+    // Like for panics, kept on disk if it could not be sent.
+    flush_and_remove(hub, Some(filename));
+
+    event_id
+}
+
+/// Captures a non-fatal event with a minidump of the current process attached,
+/// e.g. to report a hang, returning the identifier of the event.
+///
+/// The minidump is removed once the event is sent.
+pub(crate) fn capture_minidump() -> Result<Uuid, String> {
+    let hub = Hub::current();
+    let minidump = write_manual_minidump(&hub)?;
+    let event = Event {
+        message: Some("Minidump captured on request".to_string()),
+        level: Level::Warning,
+        ..Default::default()
+    };
+
+    Ok(capture_with_attachment(&hub, minidump, event))
+}
+
+/// Captures the event with a minidump of the current process attached, to
+/// report serious errors which are not fatal (e.g. a lost GPU device).
+///
+/// Like for panics, the minidump is written with the options of the panic
+/// integration and removed once the event is sent. If it cannot be written,
+/// the event is captured without it.
+///
+/// This is expensive: every thread of the process is suspended while the
+/// minidump (usually a few megabytes) is written, and the calling thread is
+/// blocked until the event has been sent. Avoid calling it from the main
+/// thread or for frequent errors.
+pub fn capture_with_minidump(event: Event<'static>) -> Uuid {
+    let hub = Hub::current();
+    match write_manual_minidump(&hub) {
+        Ok(minidump) => capture_with_attachment(&hub, minidump, event),
+        Err(err) => {
+            log::warn!("Failed to write a minidump for the event: {}", err);
+            hub.capture_event(event)
+        }
+    }
+}
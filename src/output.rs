@@ -0,0 +1,83 @@
+//! The tail of the output of the application, attached to panic events.
+//!
+//! The standard streams cannot be reliably intercepted from within the
+//! process (the output still buffered in a pipe when it exits would be lost),
+//! so the application feeds the output it wants to keep, with [`record_output`]
+//! or by writing through an [`OutputTee`], e.g. as the target of its logger:
+//!
+//! ```ignore
+//! env_logger::Builder::from_default_env()
+//!     .target(env_logger::Target::Pipe(Box::new(
+//!         tauri_plugin_sentry::OutputTee::new(std::io::stderr()),
+//!     )))
+//!     .init();
+//! ```
+
+#![warn(missing_docs)]
+
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::sync::{Mutex, OnceLock};
+
+/// The maximum size and the last bytes of the output, once enabled by
+/// `PanicOptions::output_tail_size`.
+static OUTPUT: OnceLock<(usize, Mutex<VecDeque<u8>>)> = OnceLock::new();
+
+/// Starts keeping the last `size` bytes of the recorded output.
+pub(crate) fn enable(size: usize) {
+    let _ = OUTPUT.set((size, Mutex::new(VecDeque::with_capacity(size))));
+}
+
+/// Records some output of the application, attached to the panic events if
+/// `PanicOptions::output_tail_size` is set, and discarded otherwise.
+pub fn record_output(output: &[u8]) {
+    let Some((size, buffer)) = OUTPUT.get() else {
+        return;
+    };
+    let Ok(mut buffer) = buffer.lock() else {
+        return;
+    };
+
+    let output = &output[output.len().saturating_sub(*size)..];
+    let overflow = (buffer.len() + output.len()).saturating_sub(*size);
+    buffer.drain(..overflow);
+    buffer.extend(output);
+}
+
+/// Returns the recorded output, if any.
+///
+/// This is called from the panic hook: the buffer is skipped if it is locked
+/// by the panicking thread itself.
+pub(crate) fn tail() -> Option<Vec<u8>> {
+    let buffer = OUTPUT.get()?.1.try_lock().ok()?;
+    if buffer.is_empty() {
+        return None;
+    }
+
+    Some(buffer.iter().copied().collect())
+}
+
+/// A writer recording the output written to it before passing it on.
+#[derive(Debug)]
+pub struct OutputTee<W> {
+    inner: W,
+}
+
+impl<W: Write> OutputTee<W> {
+    /// Creates a writer passing the output on to `inner`.
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+}
+
+impl<W: Write> Write for OutputTee<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        record_output(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -13,76 +13,77 @@
 //! ```
 //! let integration = tauri_plugin_sentry::PanicIntegration::default().add_extractor(|info| None);
 //! ```
+//!
+//! Event processors can then modify or drop the event created from the panic:
+//!
+//! ```
+//! let integration = tauri_plugin_sentry::PanicIntegration::default()
+//!     .add_event_processor(|event| Some(event));
+//! ```
+//!
+//! Payloads raised with `std::panic::panic_any` can be described by a formatter:
+//!
+//! ```
+//! struct ExitCode(i32);
+//!
+//! let integration = tauri_plugin_sentry::PanicIntegration::default()
+//!     .add_payload_formatter(|code: &ExitCode| format!("exit code {}", code.0));
+//! ```
+//!
+//! Panics caught and recovered from, e.g. in a dedicated thread, can be
+//! reported as warnings instead, without a minidump:
+//!
+//! ```
+//! let integration = tauri_plugin_sentry::PanicIntegration::default().add_soft_panic_predicate(
+//!     |_info| std::thread::current().name() == Some("indexer"),
+//! );
+//! ```
 
 #![warn(missing_docs)]
 
-#[cfg(target_os = "windows")]
-use std::io::{Read, Seek};
+use std::any::Any;
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::error::Error;
 use std::panic::{self, PanicInfo};
 use std::path::PathBuf;
+#[cfg(unix)]
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
 use std::sync::Once;
+#[cfg(unix)]
+use std::sync::OnceLock;
+use std::time::Duration;
 
 use sentry::protocol::{Attachment, AttachmentType, Event, Exception, Level, Mechanism};
-use sentry::{ClientOptions, Integration};
+use sentry::{ClientOptions, Hub, Integration};
 use sentry_backtrace::current_stacktrace;
 
-fn get_dump_fn() -> PathBuf {
-    let pid = std::process::id();
-    let mut dump_fn = std::env::temp_dir();
-    dump_fn.push(format!("dump_{}.mdmp", pid));
-
-    dump_fn
-}
-
-#[cfg(target_os = "linux")]
-fn write_minidump() -> Result<(PathBuf, Vec<u8>), Box<dyn std::error::Error>> {
-    let mut writer =
-        minidump_writer::minidump_writer::MinidumpWriter::new(std::process::id() as _, unsafe {
-            libc::syscall(libc::SYS_gettid)
-        }
-            as i32);
-
-    writer.sanitize_stack();
-
-    let dump_fn = get_dump_fn();
-    let mut minidump_file = std::fs::File::create(&dump_fn)?;
-
-    Ok((dump_fn, writer.dump(&mut minidump_file)?))
+thread_local! {
+    static IN_PANIC_HANDLER: Cell<bool> = const { Cell::new(false) };
+    /// The fatal signal being reported as a panic on this thread, if any.
+    static RAISED_SIGNAL: Cell<Option<std::ffi::c_int>> = const { Cell::new(None) };
+    /// The thread which received the signal being reported on this thread.
+    #[cfg(feature = "minidump")]
+    static SIGNALED_THREAD: Cell<Option<crate::minidump::ThreadId>> = const { Cell::new(None) };
 }
 
-#[cfg(target_os = "macos")]
-fn write_minidump() -> Result<(PathBuf, Vec<u8>), Box<dyn std::error::Error>> {
-    // Defaults to dumping the current process and thread.
-    let mut writer = minidump_writer::minidump_writer::MinidumpWriter::new(None, None);
+/// Marks the current thread as reporting a panic until dropped.
+struct ReentrancyGuard;
 
-    let dump_fn = get_dump_fn();
-    let mut minidump_file = std::fs::File::create(&dump_fn)?;
-
-    Ok((dump_fn, writer.dump(&mut minidump_file)?))
+impl ReentrancyGuard {
+    fn enter() -> Option<Self> {
+        if IN_PANIC_HANDLER.with(|flag| flag.replace(true)) {
+            None
+        } else {
+            Some(Self)
+        }
+    }
 }
 
-#[cfg(target_os = "windows")]
-fn write_minidump() -> Result<(PathBuf, Vec<u8>), Box<dyn std::error::Error>> {
-    let dump_fn = get_dump_fn();
-    let mut minidump_file = std::fs::File::create(&dump_fn)?;
-
-    // Attempts to write the minidump
-    minidump_writer::minidump_writer::MinidumpWriter::dump_local_context(
-        // The exception code, presumably one of STATUS_*. Defaults to STATUS_NONCONTINUABLE_EXCEPTION if not specified
-        None,
-        // If not specified, uses the current thread as the "crashing" thread,
-        // so this is equivalent to passing `None`, but it could be any thread
-        // in the process
-        Some(unsafe { windows_sys::Win32::System::Threading::GetCurrentThreadId() }),
-        None,
-        &mut minidump_file,
-    )?;
-
-    let mut buf = vec![];
-    minidump_file.seek(std::io::SeekFrom::Start(0))?;
-    minidump_file.read_to_end(&mut buf)?;
-
-    Ok((dump_fn, buf))
+impl Drop for ReentrancyGuard {
+    fn drop(&mut self) {
+        IN_PANIC_HANDLER.with(|flag| flag.set(false));
+    }
 }
 
 /// A panic handler that sends to Sentry.
@@ -90,65 +91,481 @@ fn write_minidump() -> Result<(PathBuf, Vec<u8>), Box<dyn std::error::Error>> {
 /// This panic handler reports panics to Sentry. It also attempts to prevent
 /// double faults in some cases where it's known to be unsafe to invoke the
 /// Sentry panic handler.
+///
+/// The hook is installed once, but always reports through the panic
+/// integration of the client bound to the current hub, so that it follows the
+/// client when the plugin is initialized again.
 pub fn panic_handler(info: &PanicInfo<'_>) {
-    sentry::with_integration(|integration: &PanicIntegration, hub| {
-        // Android and iOS are not supported yet by minidump-writer.
-        // Other platforms are not supported, and probably never will.
-        #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
-        {
-            hub.with_scope(
-                |scope| {
-                    let Ok((filename, buffer)) = write_minidump() else {
-                        return;
-                    };
+    // A panic (or a fatal signal) raised while reporting another one would
+    // re-enter the handler: skip reporting and let the previous hook run.
+    let Some(_guard) = ReentrancyGuard::enter() else {
+        return;
+    };
+
+    // The hook runs on the thread that panicked, or on the signal reporter
+    // thread, which knows the thread that received the signal.
+    #[cfg(feature = "minidump")]
+    let crashing_thread = SIGNALED_THREAD
+        .with(|thread| thread.take())
+        .unwrap_or_else(crate::minidump::current_thread_id);
+    let signal = RAISED_SIGNAL.with(|signal| signal.take());
+    // With `panic = "abort"`, the panic is followed by `abort()`: the SIGABRT
+    // must not be reported as a second crash.
+    #[cfg(all(unix, panic = "abort"))]
+    if signal.is_none() {
+        SIGNAL_REPORTED.store(true, Ordering::SeqCst);
+    }
 
+    // Not through `sentry::with_integration`, which skips the disabled
+    // clients: the minidump may still have to be written without a DSN.
+    let hub = Hub::current();
+    hub.with_integration(|integration: &PanicIntegration| {
+        let soft = signal.is_none() && integration.is_soft_panic(info);
+        let can_send =
+            crate::telemetry_enabled() && hub.client().is_some_and(|client| client.is_enabled());
+        if !can_send {
+            // Kept on disk, to be collected manually.
+            #[cfg(feature = "minidump")]
+            if integration.options.always_write_minidump && !soft {
+                let path = crate::minidump::get_dump_fn(&integration.options);
+                let _ = crate::minidump::write_minidump(crashing_thread, path);
+            }
+
+            return;
+        }
+
+        // Built before writing the minidump, which is useless if the event is dropped.
+        let mut event = integration.event_from_panic_info(info);
+        annotate_event(&mut event, signal);
+        if soft {
+            soften_event(&mut event);
+        }
+        // The stacktraces are otherwise processed by the client integration.
+        #[cfg(not(feature = "backtrace"))]
+        if let Some(client) = hub.client() {
+            let stacktraces = event
+                .exception
+                .iter_mut()
+                .filter_map(|exception| exception.stacktrace.as_mut());
+            for stacktrace in stacktraces {
+                sentry_backtrace::process_event_stacktrace(stacktrace, client.options());
+            }
+        }
+        let Some(event) = integration.process_event(event) else {
+            return;
+        };
+
+        #[cfg(feature = "minidump")]
+        let mut dump_fn: Option<PathBuf> = None;
+        hub.with_scope(
+            |scope| {
+                if let Some(output) = crate::output::tail() {
                     scope.add_attachment(Attachment {
-                        buffer,
-                        filename: filename.to_string_lossy().to_string(),
-                        ty: Some(AttachmentType::Minidump),
+                        buffer: output,
+                        filename: "output.txt".to_string(),
+                        content_type: Some("text/plain".to_string()),
                         ..Default::default()
                     });
-                },
-                || {
-                    hub.capture_event(integration.event_from_panic_info(info));
-                },
-            );
-        }
-
+                }
+                #[cfg(feature = "minidump")]
+                if integration.options.attach_minidump && !soft {
+                    dump_fn = crate::minidump::attach(scope, crashing_thread, &integration.options);
+                }
+                for attachment in integration.attachments.iter().filter_map(|f| f()) {
+                    scope.add_attachment(attachment);
+                }
+            },
+            || {
+                hub.capture_event(event);
+            },
+        );
+
+        #[cfg(feature = "minidump")]
+        crate::minidump::flush_and_remove(&hub, dump_fn);
+        #[cfg(not(feature = "minidump"))]
         if let Some(client) = hub.client() {
             client.flush(None);
         }
     });
 }
 
+/// Adds the name of the crashing thread and the raised signal, if any, to the event.
+///
+/// The name of the thread which received a signal is unknown, as it cannot be
+/// retrieved safely from the signal handler. The panic location and the
+/// stacktrace are dropped as well, as they point to the reporter thread rather
+/// than to the crash: the minidump holds the stack of the crashing thread.
+fn annotate_event(event: &mut Event<'static>, signal: Option<std::ffi::c_int>) {
+    #[cfg(unix)]
+    if let Some(signum) = signal {
+        event.tags.remove("panic.location");
+        for exception in event.exception.iter_mut() {
+            exception.stacktrace = None;
+        }
+        event.tags.insert("signal".to_string(), signal_name(signum));
+        return;
+    }
+    #[cfg(not(unix))]
+    let _ = signal;
+
+    let thread = std::thread::current();
+    let thread_name = thread.name().unwrap_or("<unnamed>");
+    event
+        .tags
+        .insert("thread.name".to_string(), thread_name.to_string());
+    if let Some(exception) = event.exception.last_mut() {
+        if let Some(value) = &mut exception.value {
+            value.push_str(&format!(" (thread '{}')", thread_name));
+        }
+    }
+}
+
+/// Reports a soft panic as a handled warning.
+fn soften_event(event: &mut Event<'static>) {
+    event.level = Level::Warning;
+    for exception in event.exception.iter_mut() {
+        if let Some(mechanism) = &mut exception.mechanism {
+            mechanism.handled = Some(true);
+        }
+    }
+}
+
 type PanicExtractor = dyn Fn(&PanicInfo<'_>) -> Option<Event<'static>> + Send + Sync;
+type PanicEventProcessor = dyn Fn(Event<'static>) -> Option<Event<'static>> + Send + Sync;
+type PanicPayloadFormatter = dyn Fn(&(dyn Any + Send)) -> Option<String> + Send + Sync;
+type PanicAttachmentProvider = dyn Fn() -> Option<Attachment> + Send + Sync;
+type PanicPredicate = dyn Fn(&PanicInfo<'_>) -> bool + Send + Sync;
+
+/// Options controlling how panics are reported.
+///
+/// The minidump options have no effect without the `minidump` feature.
+#[derive(Debug, Clone)]
+pub struct PanicOptions {
+    /// Whether a minidump of the process should be attached to panic events.
+    ///
+    /// Disable it to avoid large uploads: the panic event is still captured.
+    pub attach_minidump: bool,
+    /// Minidumps left over by previous runs and older than this are removed
+    /// from the minidump directory on startup. `None` disables the sweep.
+    pub stale_minidump_max_age: Option<Duration>,
+    /// The directory minidumps are written to, the system temporary directory
+    /// if `None`.
+    ///
+    /// Set it on sandboxed systems where the temporary directory is read-only
+    /// or cleared. A warning is logged on startup if it is not writable.
+    pub minidump_dir: Option<PathBuf>,
+    /// The prefix of the minidump file names, followed by the process id.
+    ///
+    /// Only the files with this prefix are removed by the stale minidumps sweep.
+    pub minidump_prefix: String,
+    /// Whether fatal signals (`SIGSEGV`, `SIGABRT`...) are reported as panics
+    /// on Unix platforms.
+    ///
+    /// The previous handlers are chained after reporting. Disable it when
+    /// running under a debugger or along another crash reporter: panics are
+    /// still reported through the panic hook.
+    pub install_signal_handlers: bool,
+    /// Whether a minidump is written to the minidump directory even when it
+    /// cannot be sent, because the client is disabled (e.g. without a DSN) or
+    /// telemetry has not been allowed.
+    ///
+    /// The minidump is then left on disk to be collected manually. It is still
+    /// removed by the stale minidumps sweep once older than `stale_minidump_max_age`.
+    pub always_write_minidump: bool,
+    /// The number of bytes of the output recorded with `record_output` (or an
+    /// `OutputTee`) to attach to panic events as `output.txt`.
+    ///
+    /// `None` disables the recording. The attachment does not depend on
+    /// `attach_minidump`.
+    pub output_tail_size: Option<usize>,
+    /// The type of the minidump attachments, `event.minidump` by default.
+    ///
+    /// Change it when the minidumps are processed by a custom symbolication
+    /// pipeline instead of Sentry.
+    pub minidump_attachment_type: AttachmentType,
+    /// The content type of the minidump attachments, none by default.
+    pub minidump_content_type: Option<String>,
+}
+
+#[cfg(feature = "minidump")]
+impl PanicOptions {
+    pub(crate) fn minidump_dir(&self) -> PathBuf {
+        self.minidump_dir.clone().unwrap_or_else(std::env::temp_dir)
+    }
+}
+
+impl Default for PanicOptions {
+    fn default() -> Self {
+        Self {
+            attach_minidump: true,
+            stale_minidump_max_age: Some(Duration::from_secs(7 * 24 * 60 * 60)),
+            minidump_dir: None,
+            minidump_prefix: "dump_".to_string(),
+            install_signal_handlers: true,
+            always_write_minidump: false,
+            output_tail_size: None,
+            minidump_attachment_type: AttachmentType::Minidump,
+            minidump_content_type: None,
+        }
+    }
+}
 
 /// The Sentry Panic handler Integration.
 #[derive(Default)]
 pub struct PanicIntegration {
     extractors: Vec<Box<PanicExtractor>>,
+    processors: Vec<Box<PanicEventProcessor>>,
+    formatters: Vec<Box<PanicPayloadFormatter>>,
+    attachments: Vec<Box<PanicAttachmentProvider>>,
+    soft_predicates: Vec<Box<PanicPredicate>>,
+    pub(crate) options: PanicOptions,
 }
 
 impl std::fmt::Debug for PanicIntegration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("PanicIntegration")
             .field("extractors", &self.extractors.len())
+            .field("processors", &self.processors.len())
+            .field("formatters", &self.formatters.len())
+            .field("attachments", &self.attachments.len())
+            .field("soft_predicates", &self.soft_predicates.len())
+            .field("options", &self.options)
             .finish()
     }
 }
 
 static INIT: Once = Once::new();
 
+/// The fatal signals routed through the panic handler.
+#[cfg(unix)]
+const HANDLED_SIGNALS: [std::ffi::c_int; 5] = [
+    libc::SIGSEGV,
+    libc::SIGABRT,
+    libc::SIGBUS,
+    libc::SIGILL,
+    libc::SIGFPE,
+];
+
 #[cfg(unix)]
-unsafe extern "C" fn sigsegv_handler(signum: std::ffi::c_int) {
-    eprintln!("received signal {}", signum);
+fn signal_name(signum: std::ffi::c_int) -> String {
+    match signum {
+        libc::SIGSEGV => "SIGSEGV".to_string(),
+        libc::SIGABRT => "SIGABRT".to_string(),
+        libc::SIGBUS => "SIGBUS".to_string(),
+        libc::SIGILL => "SIGILL".to_string(),
+        libc::SIGFPE => "SIGFPE".to_string(),
+        _ => format!("signal {}", signum),
+    }
+}
+
+#[cfg(unix)]
+fn signal_description(signum: std::ffi::c_int) -> &'static str {
+    match signum {
+        libc::SIGSEGV => "Segmentation fault",
+        libc::SIGABRT => "Aborted",
+        libc::SIGBUS => "Bus error",
+        libc::SIGILL => "Illegal instruction",
+        libc::SIGFPE => "Floating point exception",
+        _ => "Fatal signal",
+    }
+}
+
+/// The dispositions of the handled signals before the handler was installed.
+#[cfg(unix)]
+static PREVIOUS_HANDLERS: OnceLock<Vec<(std::ffi::c_int, libc::sigaction)>> = OnceLock::new();
+
+/// How long the signal handler waits for the signal to be reported before
+/// chaining to the previous handler, as the reporter thread may be deadlocked
+/// by the state the signaled thread was left in.
+#[cfg(unix)]
+const SIGNAL_REPORT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The write end of the pipe the signals are sent to the reporter thread through.
+#[cfg(unix)]
+static SIGNAL_PIPE: AtomicI32 = AtomicI32::new(-1);
+/// The read end of the pipe the reporter thread acknowledges the reports through.
+#[cfg(unix)]
+static SIGNAL_ACK_PIPE: AtomicI32 = AtomicI32::new(-1);
+/// Whether a signal is being reported: only the first one is.
+#[cfg(unix)]
+static SIGNAL_REPORTED: AtomicBool = AtomicBool::new(false);
+
+/// A signal sent by the signal handler to the reporter thread.
+#[cfg(unix)]
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct SignalReport {
+    signum: std::ffi::c_int,
+    #[cfg(feature = "minidump")]
+    thread: crate::minidump::ThreadId,
+}
+
+/// Creates a pipe whose ends are not inherited by child processes.
+#[cfg(unix)]
+unsafe fn pipe() -> std::io::Result<[std::ffi::c_int; 2]> {
+    let mut fds = [-1; 2];
+    if libc::pipe(fds.as_mut_ptr()) != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    for fd in fds {
+        libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC);
+    }
+
+    Ok(fds)
+}
+
+/// Starts the thread reporting the signals received by the signal handler.
+///
+/// Nothing but async-signal-safe functions can be called from the signal
+/// handler: it sends the signal through a pipe to this thread, which reports
+/// it as a panic through the panic hook and acknowledges it.
+#[cfg(unix)]
+unsafe fn start_signal_reporter() -> std::io::Result<()> {
+    let [report_read, report_write] = pipe()?;
+    let [ack_read, ack_write] = pipe()?;
+
+    std::thread::Builder::new()
+        .name("sentry-signal-reporter".into())
+        .spawn(move || loop {
+            let mut report = std::mem::MaybeUninit::<SignalReport>::uninit();
+            let size = std::mem::size_of::<SignalReport>();
+            let read = unsafe { libc::read(report_read, report.as_mut_ptr().cast(), size) };
+            if read < 0 && std::io::Error::last_os_error().kind() == std::io::ErrorKind::Interrupted
+            {
+                continue;
+            }
+            if read != size as isize {
+                break;
+            }
+
+            let report = unsafe { report.assume_init() };
+            RAISED_SIGNAL.with(|signal| signal.set(Some(report.signum)));
+            #[cfg(feature = "minidump")]
+            SIGNALED_THREAD.with(|thread| thread.set(Some(report.thread)));
+            let _ = panic::catch_unwind(|| {
+                panic!(
+                    "{}! (signal {})",
+                    signal_description(report.signum),
+                    report.signum
+                );
+            });
+
+            unsafe { libc::write(ack_write, [1u8].as_ptr().cast(), 1) };
+        })?;
+
+    SIGNAL_ACK_PIPE.store(ack_read, Ordering::SeqCst);
+    SIGNAL_PIPE.store(report_write, Ordering::SeqCst);
+
+    Ok(())
+}
+
+/// Installs `signal_handler` for the handled signals, saving the previous ones.
+#[cfg(unix)]
+unsafe fn install_signal_handlers() {
+    if let Err(err) = start_signal_reporter() {
+        log::warn!("fatal signals will not be reported: {}", err);
+        return;
+    }
+
+    let mut action = std::mem::zeroed::<libc::sigaction>();
+    let handler = signal_handler as *const fn(std::ffi::c_int);
+    action.sa_sigaction = handler as libc::sighandler_t;
+    libc::sigemptyset(&mut action.sa_mask);
+
+    let mut previous_handlers = Vec::with_capacity(HANDLED_SIGNALS.len());
+    for signum in HANDLED_SIGNALS {
+        let mut previous = std::mem::zeroed::<libc::sigaction>();
+        if libc::sigaction(signum, &action, &mut previous) == 0 {
+            previous_handlers.push((signum, previous));
+        }
+    }
+
+    let _ = PREVIOUS_HANDLERS.set(previous_handlers);
+}
+
+/// Restores the disposition the signal had before the handler was installed.
+#[cfg(unix)]
+unsafe fn restore_previous_handler(signum: std::ffi::c_int) {
+    let previous = PREVIOUS_HANDLERS
+        .get()
+        .and_then(|handlers| handlers.iter().find(|(handled, _)| *handled == signum));
+    match previous {
+        Some((_, previous)) => {
+            libc::sigaction(signum, previous, std::ptr::null_mut());
+        }
+        None => {
+            libc::signal(signum, libc::SIG_DFL);
+        }
+    }
+}
+
+/// Writes `received signal <signum>` to the standard error, without allocating.
+#[cfg(unix)]
+unsafe fn write_signal_message(signum: std::ffi::c_int) {
+    const PREFIX: &[u8] = b"received signal ";
+    let mut message = [0u8; 32];
+    message[..PREFIX.len()].copy_from_slice(PREFIX);
+
+    let mut digits = [0u8; 10];
+    let mut count = 0;
+    let mut value = signum.unsigned_abs();
+    loop {
+        digits[count] = b'0' + (value % 10) as u8;
+        count += 1;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+
+    let mut len = PREFIX.len();
+    for digit in digits[..count].iter().rev() {
+        message[len] = *digit;
+        len += 1;
+    }
+    message[len] = b'\n';
+    len += 1;
+
+    libc::write(libc::STDERR_FILENO, message.as_ptr().cast(), len);
+}
+
+/// Handles the fatal signals, calling async-signal-safe functions only.
+#[cfg(unix)]
+unsafe extern "C" fn signal_handler(signum: std::ffi::c_int) {
+    write_signal_message(signum);
+
+    // Restore the previous disposition, so that a signal raised while
+    // reporting (e.g. `abort()` after the panic) is not handled again, and so
+    // that debuggers and other crash reporters get the signal afterwards.
+    restore_previous_handler(signum);
 
     let mut sigs = std::mem::zeroed::<libc::sigset_t>();
     libc::sigemptyset(&mut sigs);
     libc::sigaddset(&mut sigs, signum);
     libc::sigprocmask(libc::SIG_UNBLOCK, &sigs, std::ptr::null_mut());
 
-    panic!("Segmentation fault!");
+    // A signal received while reporting another one (possibly on the reporter
+    // thread itself) is not reported, so that it cannot wait for itself.
+    let pipe = SIGNAL_PIPE.load(Ordering::SeqCst);
+    if pipe >= 0 && !SIGNAL_REPORTED.swap(true, Ordering::SeqCst) {
+        let report = SignalReport {
+            signum,
+            #[cfg(feature = "minidump")]
+            thread: crate::minidump::current_thread_id(),
+        };
+        let size = std::mem::size_of::<SignalReport>();
+        if libc::write(pipe, (&report as *const SignalReport).cast(), size) == size as isize {
+            let mut ack = libc::pollfd {
+                fd: SIGNAL_ACK_PIPE.load(Ordering::SeqCst),
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            libc::poll(&mut ack, 1, SIGNAL_REPORT_TIMEOUT.as_millis() as _);
+        }
+    }
+
+    // Chain to the previous handler (or the default action).
+    libc::raise(signum);
 }
 
 impl Integration for PanicIntegration {
@@ -157,6 +574,15 @@ impl Integration for PanicIntegration {
     }
 
     fn setup(&self, _cfg: &mut ClientOptions) {
+        #[cfg(feature = "minidump")]
+        crate::minidump::setup(&self.options);
+
+        if let Some(size) = self.options.output_tail_size {
+            crate::output::enable(size);
+        }
+
+        #[cfg(unix)]
+        let handle_signals = self.options.install_signal_handlers;
         INIT.call_once(|| {
             let next = panic::take_hook();
             panic::set_hook(Box::new(move |info| {
@@ -165,15 +591,17 @@ impl Integration for PanicIntegration {
             }));
 
             #[cfg(unix)]
-            unsafe {
-                let handler = sigsegv_handler as *const fn(std::ffi::c_int);
-                libc::signal(libc::SIGSEGV, handler as libc::sighandler_t);
+            if handle_signals {
+                unsafe { install_signal_handlers() };
             }
         });
     }
 }
 
 /// Extract the message of a panic.
+///
+/// Only string payloads are returned, see `formatted_panic_message` for the
+/// other payloads.
 pub fn message_from_panic_info<'a>(info: &'a PanicInfo<'_>) -> &'a str {
     match info.payload().downcast_ref::<&'static str>() {
         Some(s) => s,
@@ -184,13 +612,56 @@ pub fn message_from_panic_info<'a>(info: &'a PanicInfo<'_>) -> &'a str {
     }
 }
 
+/// Extract the message of a panic, formatting the payloads which are not
+/// strings.
+///
+/// Besides strings, the payloads of `std::panic::panic_any` holding a boxed
+/// error, a number, a `bool` or a `char` are formatted. The type of any other
+/// payload cannot be known at runtime: register a formatter with
+/// `PanicIntegration::add_payload_formatter` to describe them.
+pub fn formatted_panic_message<'a>(info: &'a PanicInfo<'_>) -> Cow<'a, str> {
+    let payload = info.payload();
+    if payload.is::<&'static str>() || payload.is::<String>() {
+        return Cow::Borrowed(message_from_panic_info(info));
+    }
+    if let Some(err) = payload.downcast_ref::<Box<dyn Error + Send + Sync>>() {
+        return Cow::Owned(err.to_string());
+    }
+    if let Some(err) = payload.downcast_ref::<Box<dyn Error + Send>>() {
+        return Cow::Owned(err.to_string());
+    }
+
+    macro_rules! format_primitives {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = payload.downcast_ref::<$ty>() {
+                    return Cow::Owned(format!("{} ({})", value, stringify!($ty)));
+                }
+            )*
+        };
+    }
+    format_primitives!(
+        i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char
+    );
+
+    Cow::Borrowed("Box<dyn Any>")
+}
+
 impl PanicIntegration {
     /// Creates a new Panic Integration.
     pub fn new() -> Self {
         Self::default()
     }
 
-    /// Registers a new extractor.
+    /// Creates a new Panic Integration with the given options.
+    pub fn with_options(options: PanicOptions) -> Self {
+        Self {
+            options,
+            ..Self::default()
+        }
+    }
+
+    /// Registers a new extractor, after the ones already registered.
     #[must_use]
     pub fn add_extractor<F>(mut self, f: F) -> Self
     where
@@ -200,10 +671,129 @@ impl PanicIntegration {
         self
     }
 
+    /// Registers a new extractor, before the ones already registered so that
+    /// it takes precedence over them.
+    #[must_use]
+    pub fn prepend_extractor<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&PanicInfo<'_>) -> Option<Event<'static>> + Send + Sync + 'static,
+    {
+        self.extractors.insert(0, Box::new(f));
+        self
+    }
+
+    /// Removes all the registered extractors, so that panics are converted to
+    /// events by default.
+    #[must_use]
+    pub fn clear_extractors(mut self) -> Self {
+        self.extractors.clear();
+        self
+    }
+
+    /// Registers a new event processor.
+    ///
+    /// Processors run in order on the event built from the panic (by an
+    /// extractor or the default conversion) before it is captured, and can
+    /// modify it (e.g. add tags or scrub data) or drop it by returning `None`.
+    /// They run inside the panic hook, so they must be quick and must not block.
+    #[must_use]
+    pub fn add_event_processor<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Event<'static>) -> Option<Event<'static>> + Send + Sync + 'static,
+    {
+        self.processors.push(Box::new(f));
+        self
+    }
+
+    /// Registers a provider of an additional attachment for the panic events,
+    /// e.g. a JSON file describing the state of the application.
+    ///
+    /// Providers are called in the panic hook when the event is captured, in
+    /// the same scope as the minidump, and can skip the attachment by
+    /// returning `None`. Like event processors, they must be quick.
+    #[must_use]
+    pub fn add_attachment<F>(mut self, f: F) -> Self
+    where
+        F: Fn() -> Option<Attachment> + Send + Sync + 'static,
+    {
+        self.attachments.push(Box::new(f));
+        self
+    }
+
+    /// Registers a formatter for the panic payloads of type `T`, e.g. raised
+    /// with `std::panic::panic_any`, used as the message of the panic event.
+    ///
+    /// Formatters take precedence over the default formatting of
+    /// `formatted_panic_message`, in the order they are registered.
+    #[must_use]
+    pub fn add_payload_formatter<T, F>(mut self, f: F) -> Self
+    where
+        T: Any,
+        F: Fn(&T) -> String + Send + Sync + 'static,
+    {
+        self.formatters
+            .push(Box::new(move |payload| payload.downcast_ref::<T>().map(&f)));
+        self
+    }
+
+    /// Registers a predicate designating the soft panics, which are caught and
+    /// recovered from, e.g. with `std::panic::catch_unwind` in a subsystem.
+    ///
+    /// If any predicate returns `true`, the panic is reported as a handled
+    /// `Warning` instead of a `Fatal` error, and no minidump is written for
+    /// it. Predicates run in the panic hook, on the panicking thread, so
+    /// `std::thread::current` can be used to match the thread. Panics raised
+    /// for fatal signals are never soft.
+    #[must_use]
+    pub fn add_soft_panic_predicate<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&PanicInfo<'_>) -> bool + Send + Sync + 'static,
+    {
+        self.soft_predicates.push(Box::new(f));
+        self
+    }
+
+    /// Whether the panic is designated as soft by a registered predicate.
+    fn is_soft_panic(&self, info: &PanicInfo<'_>) -> bool {
+        self.soft_predicates.iter().any(|predicate| predicate(info))
+    }
+
+    /// Runs the registered event processors on the given event.
+    fn process_event(&self, event: Event<'static>) -> Option<Event<'static>> {
+        self.processors
+            .iter()
+            .try_fold(event, |event, processor| processor(event))
+    }
+
     /// Creates an event from the given panic info.
     ///
-    /// The stacktrace is calculated from the current frame.
+    /// The extractors are tried in order and the first one returning an event
+    /// wins: the following ones are not called. If none of them returns an
+    /// event, the panic is converted by default, with the stacktrace calculated
+    /// from the current frame.
+    ///
+    /// In both cases, the event is tagged with the location of the panic, as
+    /// `file:line:column`, and with the Tauri command being handled on the
+    /// thread, if tracked with `track_commands`.
     pub fn event_from_panic_info(&self, info: &PanicInfo<'_>) -> Event<'static> {
+        let mut event = self.build_event(info);
+        if let Some(location) = info.location() {
+            event
+                .tags
+                .insert("panic.location".to_string(), location.to_string());
+        }
+        if let Some((command, args_hash)) = crate::ipc::current_command() {
+            event.tags.insert("command".to_string(), command);
+            event
+                .tags
+                .insert("command.args_hash".to_string(), args_hash);
+        }
+
+        event
+    }
+
+    /// Creates an event with the first extractor returning one, or by default.
+    fn build_event(&self, info: &PanicInfo<'_>) -> Event<'static> {
         for extractor in &self.extractors {
             if let Some(event) = extractor(info) {
                 return event;
@@ -214,7 +804,11 @@ impl PanicIntegration {
         // and use `event_from_error`, but that way we won‘t get meaningful
         // backtraces yet.
 
-        let msg = message_from_panic_info(info);
+        let msg = self
+            .formatters
+            .iter()
+            .find_map(|formatter| formatter(info.payload()))
+            .map_or_else(|| formatted_panic_message(info), Cow::Owned);
         Event {
             exception: vec![Exception {
                 ty: "panic".into(),
@@ -223,7 +817,7 @@ impl PanicIntegration {
                     handled: Some(false),
                     ..Default::default()
                 }),
-                value: Some(msg.to_string()),
+                value: Some(msg.into_owned()),
                 stacktrace: current_stacktrace(),
                 ..Default::default()
             }]
@@ -0,0 +1,223 @@
+//! Selection of the hub events are captured on, depending on their source.
+//!
+//! By default every event goes through the main client. When a separate
+//! client is configured for the webview, the events, messages and breadcrumbs
+//! sent by the frontend are captured on a dedicated hub bound to it, while
+//! native events and panics keep using the main client.
+//!
+//! When the window scopes are isolated, each window gets its own hub, created
+//! from the webview one on first use: the changes made to the scope by a
+//! window then only apply to the events and messages it captures.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use sentry::{Breadcrumb, Client, ClientOptions, Hub, Scope, ScopeGuard};
+
+use crate::consent;
+use crate::gate::Gate;
+
+/// A configuration of the scope applied when the plugin is set up.
+type ScopeConfigurator = Arc<dyn Fn(&mut Scope) + Send + Sync>;
+
+/// Routes the events to the main hub or to the hub dedicated to the webview.
+pub(crate) struct HubRouter {
+    javascript: Option<Arc<Hub>>,
+    /// The gate of the transports, closed by `close`.
+    gate: Gate,
+    /// The configurations applied again when the scopes are reset.
+    base_scope: Mutex<Vec<ScopeConfigurator>>,
+    /// The hubs of the windows, by label, if their scopes are isolated.
+    windows: Option<Mutex<HashMap<String, Arc<Hub>>>>,
+}
+
+impl HubRouter {
+    /// Creates the router, binding a new client to the webview hub if
+    /// `javascript_client` is set, and giving each window its own hub if
+    /// `isolate_windows` is set.
+    ///
+    /// The transport of the main client must already be wrapped with `gate`.
+    pub(crate) fn new(
+        javascript_client: Option<ClientOptions>,
+        isolate_windows: bool,
+        gate: Gate,
+    ) -> Self {
+        let javascript = javascript_client.map(|mut options| {
+            consent::install(&mut options);
+            gate.install(&mut options);
+            let client = Client::from(sentry::apply_defaults(options));
+            Arc::new(Hub::new(Some(Arc::new(client)), Arc::new(Scope::default())))
+        });
+
+        Self {
+            javascript,
+            gate,
+            base_scope: Mutex::default(),
+            windows: isolate_windows.then(Mutex::default),
+        }
+    }
+
+    /// The hub events coming from the webview are captured on.
+    pub(crate) fn javascript_hub(&self) -> Arc<Hub> {
+        self.javascript.clone().unwrap_or_else(Hub::current)
+    }
+
+    /// Whether each window has its own hub.
+    pub(crate) fn isolates_windows(&self) -> bool {
+        self.windows.is_some()
+    }
+
+    /// The hub events coming from the given window are captured on.
+    pub(crate) fn window_hub(&self, label: &str) -> Arc<Hub> {
+        match &self.windows {
+            Some(windows) => windows
+                .lock()
+                .unwrap()
+                .entry(label.to_string())
+                .or_insert_with(|| Arc::new(Hub::new_from_top(self.javascript_hub())))
+                .clone(),
+            None => self.javascript_hub(),
+        }
+    }
+
+    /// Forgets the hub of a destroyed window.
+    pub(crate) fn remove_window(&self, label: &str) {
+        if let Some(windows) = &self.windows {
+            windows.lock().unwrap().remove(label);
+        }
+    }
+
+    /// The hubs of the windows, if their scopes are isolated.
+    fn window_hubs(&self) -> Vec<Arc<Hub>> {
+        self.windows
+            .iter()
+            .flat_map(|windows| {
+                windows
+                    .lock()
+                    .unwrap()
+                    .values()
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// The main hub and the webview hub, if any.
+    pub(crate) fn hubs(&self) -> Vec<Arc<Hub>> {
+        std::iter::once(Hub::main())
+            .chain(self.javascript.clone())
+            .collect()
+    }
+
+    /// Records a breadcrumb coming from the given window.
+    ///
+    /// The breadcrumb is recorded on the main hub too, so that native events
+    /// keep the trail of what happened in the frontend.
+    pub(crate) fn add_javascript_breadcrumb(&self, label: &str, breadcrumb: Breadcrumb) {
+        if self.isolates_windows() {
+            self.window_hub(label).add_breadcrumb(breadcrumb.clone());
+        } else if let Some(hub) = &self.javascript {
+            hub.add_breadcrumb(breadcrumb.clone());
+        }
+        Hub::current().add_breadcrumb(breadcrumb);
+    }
+
+    /// Applies `f` to the current scope of every hub.
+    pub(crate) fn configure_scope<F: Fn(&mut Scope)>(&self, f: F) {
+        Hub::current().configure_scope(&f);
+        if let Some(hub) = &self.javascript {
+            hub.configure_scope(&f);
+        }
+    }
+
+    /// Applies `f` to the current scope of the given window hub if the window
+    /// scopes are isolated, or of every hub otherwise.
+    pub(crate) fn configure_window_scope<F: Fn(&mut Scope)>(&self, label: &str, f: F) {
+        if self.isolates_windows() {
+            self.window_hub(label).configure_scope(f);
+        } else {
+            self.configure_scope(f);
+        }
+    }
+
+    /// Applies `f` to the current scope of every hub, including the window
+    /// ones, and again whenever the scopes are reset by `reset_scope`.
+    pub(crate) fn configure_base_scope<F>(&self, f: F)
+    where
+        F: Fn(&mut Scope) + Send + Sync + 'static,
+    {
+        self.configure_scope(&f);
+        for hub in self.window_hubs() {
+            hub.configure_scope(&f);
+        }
+        self.base_scope.lock().unwrap().push(Arc::new(f));
+    }
+
+    /// Clears the current scope of every hub, then applies the configurations
+    /// registered with `configure_base_scope` again.
+    pub(crate) fn reset_scope(&self) {
+        self.configure_scope(|scope| self.reset(scope));
+    }
+
+    /// Resets the current scope of the given window hub like `reset_scope`,
+    /// if the window scopes are isolated.
+    pub(crate) fn reset_window_scope(&self, label: &str) {
+        if self.isolates_windows() {
+            self.window_hub(label)
+                .configure_scope(|scope| self.reset(scope));
+        }
+    }
+
+    /// Clears the scope and applies the base configurations again.
+    fn reset(&self, scope: &mut Scope) {
+        scope.clear();
+        for configure in self.base_scope.lock().unwrap().iter() {
+            configure(scope);
+        }
+    }
+
+    /// Pushes a new scope on every hub, returning the guards popping them.
+    pub(crate) fn push_scope(&self) -> Vec<ScopeGuard> {
+        let mut guards = vec![Hub::current().push_scope()];
+        if let Some(hub) = &self.javascript {
+            guards.push(hub.push_scope());
+        }
+
+        guards
+    }
+
+    /// Pushes a new scope on the given window hub if the window scopes are
+    /// isolated, or on every hub otherwise.
+    pub(crate) fn push_window_scope(&self, label: &str) -> Vec<ScopeGuard> {
+        if self.isolates_windows() {
+            vec![self.window_hub(label).push_scope()]
+        } else {
+            self.push_scope()
+        }
+    }
+
+    /// Flushes the client of every hub, waiting at most `timeout` (the client
+    /// `shutdown_timeout` if `None`) for each of them.
+    ///
+    /// Whether the queues have been drained in time is unknown: the result of
+    /// `Client::flush` is inverted by the transports of sentry 0.34.
+    pub(crate) fn flush(&self, timeout: Option<Duration>) {
+        for client in self.hubs().iter().filter_map(|hub| hub.client()) {
+            client.flush(timeout);
+        }
+    }
+
+    /// Flushes the client of every hub, then closes the gate of their
+    /// transports, so that the events captured afterwards, on any thread, are
+    /// dropped until `reopen` is called.
+    pub(crate) fn close(&self, timeout: Option<Duration>) {
+        self.flush(timeout);
+        self.gate.close();
+    }
+
+    /// Opens the gate of the transports closed by `close`.
+    pub(crate) fn reopen(&self) {
+        self.gate.open();
+    }
+}
@@ -0,0 +1,56 @@
+//! Sampling of the events sent by the webview.
+//!
+//! The sampler runs in the `event` command, before the event is captured, so
+//! the client `sample_rate` still applies afterwards: the effective rate of
+//! the webview events is the product of both rates, while native events and
+//! panics are only sampled by the client.
+
+use std::process;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
+
+/// Keeps a random share of the events.
+pub(crate) struct Sampler {
+    rate: Option<f32>,
+    state: AtomicU64,
+}
+
+impl Sampler {
+    /// Creates a sampler keeping `rate` (between 0 and 1) of the events, or all of them if `None`.
+    ///
+    /// The random generator is seeded once per process.
+    pub(crate) fn new(rate: Option<f32>) -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos() as u64;
+
+        Self {
+            rate,
+            state: AtomicU64::new(nanos ^ u64::from(process::id()).rotate_left(32)),
+        }
+    }
+
+    /// Whether the next event should be kept.
+    pub(crate) fn sample(&self) -> bool {
+        match self.rate {
+            None => true,
+            Some(rate) if rate >= 1.0 => true,
+            Some(rate) if rate <= 0.0 => false,
+            Some(rate) => self.next_f64() < f64::from(rate),
+        }
+    }
+
+    /// Returns a random number in `[0, 1)`, using SplitMix64.
+    fn next_f64(&self) -> f64 {
+        let mut z = self
+            .state
+            .fetch_add(0x9e37_79b9_7f4a_7c15, Ordering::Relaxed)
+            .wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
@@ -0,0 +1,91 @@
+//! Tracking of the release health session started by the plugin.
+//!
+//! The Sentry SDK does not expose the session bound to the scope, so its
+//! state is mirrored here, applying the same rules to the captured events.
+
+#![warn(missing_docs)]
+
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use sentry::protocol::{Event, Level, SessionStatus};
+use serde::Serialize;
+
+/// The state of the current release health session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionInfo {
+    /// When the session started, in milliseconds since the Unix epoch.
+    pub started_ms: u64,
+    /// The status of the session, `crashed` once an unhandled error (such as
+    /// a panic) has been captured.
+    pub status: SessionStatus,
+    /// The number of errors captured during the session.
+    pub errors: u64,
+}
+
+/// Mirrors the session started with `sentry::start_session`.
+#[derive(Clone, Default)]
+pub(crate) struct SessionTracker(Arc<Mutex<Option<SessionInfo>>>);
+
+impl SessionTracker {
+    /// Starts the session on the current scope, tracking the events captured
+    /// through it.
+    pub(crate) fn start(&self) {
+        let started_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+        *self.0.lock().unwrap() = Some(SessionInfo {
+            started_ms,
+            status: SessionStatus::Ok,
+            errors: 0,
+        });
+
+        let tracker = self.clone();
+        sentry::configure_scope(|scope| {
+            scope.add_event_processor(move |event| {
+                tracker.update(&event);
+                Some(event)
+            })
+        });
+        sentry::start_session();
+    }
+
+    /// Ends the session on the current scope.
+    pub(crate) fn end(&self) {
+        if let Some(session) = self.0.lock().unwrap().as_mut() {
+            if session.status == SessionStatus::Ok {
+                session.status = SessionStatus::Exited;
+            }
+        }
+        sentry::end_session();
+    }
+
+    /// The state of the session, if one has been started.
+    pub(crate) fn info(&self) -> Option<SessionInfo> {
+        self.0.lock().unwrap().clone()
+    }
+
+    /// Counts the errors and detects the crashes, like the SDK does.
+    fn update(&self, event: &Event<'static>) {
+        let mut session = self.0.lock().unwrap();
+        let Some(session) = session.as_mut().filter(|s| s.status == SessionStatus::Ok) else {
+            return;
+        };
+
+        let has_error = event.level >= Level::Error || !event.exception.is_empty();
+        let is_crash = event.exception.iter().any(|exception| {
+            exception
+                .mechanism
+                .as_ref()
+                .is_some_and(|mechanism| mechanism.handled == Some(false))
+        });
+        if is_crash {
+            session.status = SessionStatus::Crashed;
+        }
+        if has_error {
+            session.errors += 1;
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! A transport writing the envelopes to disk instead of sending them.
+//!
+//! Used when telemetry must be reviewed before leaving the machine: the
+//! envelopes are spooled to a directory and never sent over the network, an
+//! external tool can then list them with [`spooled_envelopes`] and upload
+//! the approved ones.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use sentry::{ClientOptions, Envelope, Transport, TransportFactory};
+
+use crate::offline;
+
+/// Creates a transport spooling the envelopes to the given directory.
+pub(crate) struct SpoolFactory {
+    dir: PathBuf,
+}
+
+impl SpoolFactory {
+    pub(crate) fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+}
+
+impl TransportFactory for SpoolFactory {
+    fn create_transport(&self, _options: &ClientOptions) -> Arc<dyn Transport> {
+        Arc::new(SpoolTransport {
+            dir: self.dir.clone(),
+        })
+    }
+}
+
+/// A transport writing every envelope to a directory, synchronously so that
+/// nothing is left to flush.
+struct SpoolTransport {
+    dir: PathBuf,
+}
+
+impl Transport for SpoolTransport {
+    fn send_envelope(&self, envelope: Envelope) {
+        offline::store(&self.dir, &envelope);
+    }
+
+    fn flush(&self, _timeout: Duration) -> bool {
+        true
+    }
+}
+
+/// Lists the envelopes spooled to the given directory, oldest first.
+///
+/// The envelopes from the webview client, if `Options::javascript_client` is
+/// set, are spooled to the `javascript` subdirectory. Each file can be read
+/// with [`Envelope::from_path_raw`](sentry::Envelope::from_path_raw), and
+/// should be removed once uploaded.
+pub fn spooled_envelopes(dir: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+    offline::envelope_paths(dir.as_ref())
+}
@@ -0,0 +1,50 @@
+//! Measurement of the application startup time.
+//!
+//! The startup is measured from the plugin initialization to the
+//! `RunEvent::Ready` event, and recorded as a breadcrumb as well as an
+//! `app.start` transaction, sent if sampled by `traces_sample_rate`.
+
+use std::time::SystemTime;
+
+use sentry::protocol::Map;
+use sentry::{add_breadcrumb, Breadcrumb, Transaction, TransactionContext};
+
+/// Tracks the startup until the app is ready.
+pub(crate) struct StartupTracker {
+    start_time: SystemTime,
+    transaction: Option<Transaction>,
+}
+
+impl StartupTracker {
+    /// Starts tracking a startup begun at `start_time`.
+    pub(crate) fn start(start_time: SystemTime) -> Self {
+        let ctx = TransactionContext::new("app start", "app.start");
+        let transaction = sentry::start_transaction(ctx);
+
+        Self {
+            start_time,
+            transaction: Some(transaction),
+        }
+    }
+
+    /// Records the startup duration, once the app is ready.
+    pub(crate) fn ready(&mut self) {
+        let Some(transaction) = self.transaction.take() else {
+            return;
+        };
+
+        let elapsed = self.start_time.elapsed().unwrap_or_default();
+        let duration_ms = elapsed.as_millis() as u64;
+        let mut data = Map::new();
+        data.insert("duration_ms".to_string(), duration_ms.into());
+        add_breadcrumb(Breadcrumb {
+            category: Some("app.lifecycle".to_string()),
+            message: Some(format!("App ready in {} ms", duration_ms)),
+            data,
+            ..Default::default()
+        });
+
+        transaction.set_data("duration_ms", duration_ms.into());
+        transaction.finish();
+    }
+}
@@ -0,0 +1,52 @@
+//! Support for asserting on the events captured by the plugin in tests.
+//!
+//! [`install_test_transport`] replaces the transport with one keeping the
+//! envelopes in memory, which the test can then inspect:
+//!
+//! ```ignore
+//! let mut options = tauri_plugin_sentry::Options::default();
+//! let transport = tauri_plugin_sentry::install_test_transport(&mut options);
+//!
+//! let app = tauri::test::mock_builder()
+//!     .plugin(tauri_plugin_sentry::init(options))
+//!     .build(tauri::test::mock_context(tauri::test::noop_assets()))
+//!     .unwrap();
+//!
+//! // Run the action under test, e.g. a command through `tauri::test::get_ipc_response`.
+//!
+//! let events = transport.fetch_and_clear_events();
+//! assert_eq!(events.len(), 1);
+//! assert_eq!(events[0].message.as_deref(), Some("something went wrong"));
+//! ```
+//!
+//! As the plugin binds the client to the main hub, tests capturing events
+//! should not run concurrently in the same process.
+
+#![warn(missing_docs)]
+
+use std::sync::Arc;
+
+pub use sentry::test::TestTransport;
+
+use crate::Options;
+
+/// Makes the plugin keep every envelope in memory, returning the transport
+/// they can be fetched from.
+///
+/// A placeholder DSN is set if none is configured, as the client is disabled
+/// without one, and the options which would bypass or hold back the transport
+/// (dry run, spooling, offline cache and consent) are disabled. The transport is also
+/// used by `javascript_client`, unless the latter specifies its own.
+pub fn install_test_transport(options: &mut Options) -> Arc<TestTransport> {
+    let transport = TestTransport::new();
+    if options.client.dsn.is_none() {
+        options.client.dsn = crate::dry_run::PLACEHOLDER_DSN.parse().ok();
+    }
+    options.transport = Some(Arc::new(transport.clone()));
+    options.dry_run = false;
+    options.spool_dir = None;
+    options.offline_cache_dir = None;
+    options.require_consent = false;
+
+    transport
+}
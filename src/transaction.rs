@@ -0,0 +1,125 @@
+//! Helpers to trace the duration of Rust commands.
+//!
+//! `start_transaction` starts a Sentry performance transaction and returns a
+//! guard which finishes it when dropped, so the whole body of a command can
+//! be instrumented with a single line:
+//!
+//! ```no_run
+//! use tauri_plugin_sentry::{sentry::TransactionContext, start_transaction};
+//!
+//! #[tauri::command]
+//! fn heavy_computation() -> u64 {
+//!     let _transaction = start_transaction(TransactionContext::new(
+//!         "heavy_computation",
+//!         "tauri.command",
+//!     ));
+//!
+//!     (0..1_000_000).sum()
+//! }
+//! ```
+//!
+//! Traces are linked across the webview and the native code through the
+//! `sentry-trace` header: a command can continue the trace of the webview
+//! with `continue_transaction`, and the webview can continue the trace bound
+//! to the current scope, whose header is returned by `current_trace_header`.
+
+#![warn(missing_docs)]
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+use sentry::{Hub, Transaction, TransactionContext, TransactionOrSpan};
+
+/// A running transaction, finished when the guard is dropped.
+///
+/// The guard dereferences to the underlying `Transaction`, so child spans can
+/// be started and the status can be set as usual. The transaction is bound to
+/// the current scope until it is finished.
+#[derive(Debug)]
+pub struct TransactionGuard {
+    transaction: Option<Transaction>,
+    /// The hub the transaction is bound to the scope of.
+    hub: Arc<Hub>,
+    /// The span bound to the scope before the transaction.
+    previous_span: Option<TransactionOrSpan>,
+}
+
+impl TransactionGuard {
+    /// Finishes the transaction now, instead of waiting for the guard to be dropped.
+    pub fn finish(mut self) {
+        self.finish_transaction();
+    }
+
+    /// Unbinds the transaction from the scope, unless another span has been
+    /// bound since, and finishes it.
+    fn finish_transaction(&mut self) {
+        let Some(transaction) = self.transaction.take() else {
+            return;
+        };
+
+        let span_id = transaction.get_trace_context().span_id;
+        let previous_span = self.previous_span.take();
+        self.hub.configure_scope(|scope| {
+            let bound = scope
+                .get_span()
+                .is_some_and(|span| span.get_trace_context().span_id == span_id);
+            if bound {
+                scope.set_span(previous_span);
+            }
+        });
+        transaction.finish();
+    }
+}
+
+impl Deref for TransactionGuard {
+    type Target = Transaction;
+
+    fn deref(&self) -> &Self::Target {
+        self.transaction
+            .as_ref()
+            .expect("transaction is only taken when finished")
+    }
+}
+
+impl Drop for TransactionGuard {
+    fn drop(&mut self) {
+        self.finish_transaction();
+    }
+}
+
+/// Starts a new performance transaction, finished when the returned guard is dropped.
+///
+/// The transaction is bound to the current scope meanwhile, so that the
+/// events captured on this thread are linked to it, and `current_trace_header`
+/// returns its header.
+pub fn start_transaction(ctx: TransactionContext) -> TransactionGuard {
+    let hub = Hub::current();
+    let transaction = hub.start_transaction(ctx);
+    let mut previous_span = None;
+    hub.configure_scope(|scope| {
+        previous_span = scope.get_span();
+        scope.set_span(Some(transaction.clone().into()));
+    });
+
+    TransactionGuard {
+        transaction: Some(transaction),
+        hub,
+        previous_span,
+    }
+}
+
+/// Starts a new performance transaction continuing the trace of the given
+/// `sentry-trace` header, e.g. sent by the webview along with a command.
+///
+/// A new trace is started if the header is `None` or malformed.
+pub fn continue_transaction(name: &str, op: &str, sentry_trace: Option<&str>) -> TransactionGuard {
+    let headers = sentry_trace.map(|header| ("sentry-trace", header));
+    start_transaction(TransactionContext::continue_from_headers(name, op, headers))
+}
+
+/// Returns the `sentry-trace` header of the span bound to the current scope, if any.
+pub fn current_trace_header() -> Option<String> {
+    sentry::configure_scope(|scope| scope.get_span())?
+        .iter_headers()
+        .find_map(|(name, value)| (name == "sentry-trace").then_some(value))
+}
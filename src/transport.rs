@@ -0,0 +1,40 @@
+//! The default HTTP transport, reporting the result of its flush.
+//!
+//! The HTTP transports of sentry 0.34 return `false` from `flush` once their
+//! queue is drained and `true` when the timeout elapses first, the opposite of
+//! what `Transport::flush` documents. The default transport of the clients is
+//! wrapped to report it the right way round, so that the result can be relied
+//! upon, e.g. to remove a minidump once it has been sent.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use sentry::{ClientOptions, Envelope, Transport, TransportFactory};
+
+/// Creates the default transport of `sentry`, wrapped to fix its flush result.
+pub(crate) struct DefaultTransportFactory;
+
+impl TransportFactory for DefaultTransportFactory {
+    fn create_transport(&self, options: &ClientOptions) -> Arc<dyn Transport> {
+        Arc::new(DefaultTransport(
+            sentry::transports::DefaultTransportFactory.create_transport(options),
+        ))
+    }
+}
+
+/// A transport inverting the flush result of the default one.
+struct DefaultTransport(Arc<dyn Transport>);
+
+impl Transport for DefaultTransport {
+    fn send_envelope(&self, envelope: Envelope) {
+        self.0.send_envelope(envelope);
+    }
+
+    fn flush(&self, timeout: Duration) -> bool {
+        !self.0.flush(timeout)
+    }
+
+    fn shutdown(&self, timeout: Duration) -> bool {
+        !self.0.shutdown(timeout)
+    }
+}
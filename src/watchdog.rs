@@ -0,0 +1,135 @@
+//! Detection of crashed or unresponsive webviews.
+//!
+//! Tauri does not report the crashes of the webview render process on every
+//! platform, so the injected script sends a heartbeat at regular intervals.
+//! A window whose heartbeat stops for longer than `HEARTBEAT_TIMEOUT` is
+//! reported as crashed or unresponsive, once until it responds again.
+//!
+//! Hidden and minimized windows are not checked, as their timers may be
+//! suspended: their heartbeat is considered fresh until they are shown again.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use sentry::Level;
+
+/// How often the injected script sends a heartbeat.
+pub(crate) const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long a webview can stay silent before being reported.
+///
+/// Browsers throttle the timers of the pages in the background, e.g. covered
+/// by another window, down to once per minute: anything shorter would report
+/// them.
+const HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(90);
+
+struct Heartbeat {
+    last: Instant,
+    reported: bool,
+}
+
+/// Tracks the heartbeats of the webviews.
+#[derive(Clone, Default)]
+pub(crate) struct WebviewWatchdog {
+    heartbeats: Arc<Mutex<HashMap<String, Heartbeat>>>,
+}
+
+impl WebviewWatchdog {
+    /// Starts checking the heartbeats in a background thread, skipping the
+    /// windows for which `is_visible` returns `false`.
+    pub(crate) fn spawn<F>(&self, is_visible: F)
+    where
+        F: Fn(&str) -> bool + Send + 'static,
+    {
+        let watchdog = self.clone();
+        thread::Builder::new()
+            .name("sentry-webview-watchdog".into())
+            .spawn(move || loop {
+                thread::sleep(HEARTBEAT_INTERVAL);
+                watchdog.check(&is_visible);
+            })
+            .ok();
+    }
+
+    /// Records a heartbeat from the given window.
+    pub(crate) fn heartbeat(&self, label: &str) {
+        let mut heartbeats = self.heartbeats.lock().unwrap();
+        let heartbeat = heartbeats.entry(label.to_string()).or_insert(Heartbeat {
+            last: Instant::now(),
+            reported: false,
+        });
+        if heartbeat.reported {
+            sentry::add_breadcrumb(sentry::Breadcrumb {
+                category: Some("ui.window".to_string()),
+                message: Some(format!("Webview {} is responsive again", label)),
+                ..Default::default()
+            });
+        }
+
+        heartbeat.last = Instant::now();
+        heartbeat.reported = false;
+    }
+
+    /// Stops tracking a window, e.g. when it is destroyed.
+    pub(crate) fn forget(&self, label: &str) {
+        self.heartbeats.lock().unwrap().remove(label);
+    }
+
+    /// Reports the visible windows whose heartbeat has stopped.
+    fn check(&self, is_visible: &dyn Fn(&str) -> bool) {
+        for label in self.unresponsive(Instant::now(), is_visible) {
+            sentry::with_scope(
+                |scope| scope.set_tag("window", &label),
+                || {
+                    sentry::capture_message(
+                        &format!("Webview {} has crashed or is unresponsive", label),
+                        Level::Error,
+                    )
+                },
+            );
+        }
+    }
+
+    /// Marks the visible windows whose heartbeat has stopped as reported,
+    /// returning their labels.
+    fn unresponsive(&self, now: Instant, is_visible: &dyn Fn(&str) -> bool) -> Vec<String> {
+        let mut heartbeats = self.heartbeats.lock().unwrap();
+        let mut unresponsive = vec![];
+        for (label, heartbeat) in heartbeats.iter_mut() {
+            if !is_visible(label) {
+                heartbeat.last = now;
+                continue;
+            }
+            if heartbeat.reported || now.duration_since(heartbeat.last) < HEARTBEAT_TIMEOUT {
+                continue;
+            }
+
+            heartbeat.reported = true;
+            unresponsive.push(label.clone());
+        }
+
+        unresponsive
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hidden_windows_are_not_reported() {
+        let watchdog = WebviewWatchdog::default();
+        watchdog.heartbeat("visible");
+        watchdog.heartbeat("hidden");
+        let later = Instant::now() + HEARTBEAT_TIMEOUT + HEARTBEAT_INTERVAL;
+
+        let unresponsive = watchdog.unresponsive(later, &|label| label != "hidden");
+        assert_eq!(unresponsive, ["visible"]);
+
+        // Shown again, the window is given a full timeout to respond.
+        let unresponsive = watchdog.unresponsive(later + HEARTBEAT_INTERVAL, &|_| true);
+        assert!(unresponsive.is_empty());
+    }
+}
@@ -0,0 +1,324 @@
+//! Checks the commands invoked by the webview, through the IPC of a mock app.
+//!
+//! Each test runs in a child process running this same test binary, as the
+//! client is bound to the process hub by the first initialization only.
+
+use std::process::Command;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use sentry::protocol::Event;
+use sentry::{ClientOptions, Envelope, Transport};
+use serde_json::{json, Value};
+use tauri::ipc::{CallbackFn, InvokeBody, InvokeResponseBody};
+use tauri::test::{get_ipc_response, mock_builder, mock_context, noop_assets, MockRuntime};
+use tauri::utils::acl::ExecutionContext;
+use tauri::webview::InvokeRequest;
+use tauri::{App, Manager, RunEvent, WebviewWindow, WebviewWindowBuilder};
+use tauri_plugin_sentry::{install_test_transport, Options, SentryHandle, TestTransport};
+
+const CHILD_ENV: &str = "TAURI_PLUGIN_SENTRY_COMMANDS_CHILD";
+
+/// The commands allowed to the webview of the mock app.
+const COMMANDS: &[&str] = &[
+    "event",
+    "breadcrumb",
+    "capture_message",
+    "set_fingerprint",
+    "shutdown_sentry",
+    "reinit_sentry",
+];
+
+/// Runs the given test in a child process, checking that it succeeds.
+fn run_in_child(test: &str) {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", test, "--nocapture"])
+        .env(CHILD_ENV, "1")
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "the child process failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// A mock app with the plugin, sending the events to a test transport.
+struct TestApp {
+    app: App<MockRuntime>,
+    plugin_name: String,
+    window: WebviewWindow<MockRuntime>,
+    handle: SentryHandle,
+    transport: Arc<TestTransport>,
+}
+
+impl TestApp {
+    fn new(mut options: Options) -> Self {
+        let transport = install_test_transport(&mut options);
+        Self::with_transport(options, transport)
+    }
+
+    /// Builds the app with the options whose transport delivers the envelopes
+    /// to `transport`.
+    fn with_transport(options: Options, transport: Arc<TestTransport>) -> Self {
+        let plugin_name = options.plugin_name.clone();
+        let (plugin, handle) = tauri_plugin_sentry::init_with_handle::<MockRuntime>(options);
+
+        let mut context = mock_context(noop_assets());
+        for command in COMMANDS {
+            context.runtime_authority_mut().__allow_command(
+                format!("plugin:{}|{}", plugin_name, command),
+                ExecutionContext::Local,
+            );
+        }
+        let app = mock_builder().plugin(plugin).build(context).unwrap();
+        let window = WebviewWindowBuilder::new(&app, "main", Default::default())
+            .build()
+            .unwrap();
+
+        Self {
+            app,
+            plugin_name,
+            window,
+            handle,
+            transport,
+        }
+    }
+
+    /// Invokes a command of the plugin from the webview.
+    fn invoke(&self, command: &str, args: Value) -> Result<InvokeResponseBody, Value> {
+        let request = InvokeRequest {
+            cmd: format!("plugin:{}|{}", self.plugin_name, command),
+            callback: CallbackFn(0),
+            error: CallbackFn(1),
+            url: if cfg!(any(windows, target_os = "android")) {
+                "http://tauri.localhost"
+            } else {
+                "tauri://localhost"
+            }
+            .parse()
+            .unwrap(),
+            body: InvokeBody::Json(args),
+            headers: Default::default(),
+            invoke_key: tauri::test::INVOKE_KEY.to_string(),
+        };
+
+        get_ipc_response(&self.window, request)
+    }
+
+    /// The events sent so far.
+    fn sent_events(&self) -> Vec<Event<'static>> {
+        self.handle.flush(None);
+        self.transport.fetch_and_clear_events()
+    }
+
+    /// The messages of the events sent so far.
+    fn sent_messages(&self) -> Vec<String> {
+        self.sent_events()
+            .into_iter()
+            .filter_map(|event| event.message)
+            .collect()
+    }
+}
+
+#[test]
+fn shutdown_stops_every_thread() {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        return shutdown_and_reinit();
+    }
+
+    run_in_child("shutdown_stops_every_thread");
+}
+
+/// Captures messages from a thread whose hub has been created before the
+/// shutdown.
+fn shutdown_and_reinit() {
+    let app = TestApp::new(Options::default());
+    let (sender, receiver) = mpsc::channel::<&str>();
+    let (done_sender, done) = mpsc::channel();
+    let thread = std::thread::spawn(move || {
+        for message in receiver {
+            sentry::capture_message(message, sentry::Level::Info);
+            done_sender.send(()).unwrap();
+        }
+    });
+    let capture = |message| {
+        sender.send(message).unwrap();
+        done.recv().unwrap();
+    };
+
+    capture("before the shutdown");
+    app.invoke("shutdown_sentry", json!({})).unwrap();
+    capture("during the shutdown");
+    app.invoke("reinit_sentry", json!({})).unwrap();
+    capture("after the reinit");
+    drop(sender);
+    thread.join().unwrap();
+
+    assert_eq!(
+        app.sent_messages(),
+        ["before the shutdown", "after the reinit"]
+    );
+}
+
+#[test]
+fn commands_are_invoked_under_a_custom_name() {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        return invoke_under_custom_name();
+    }
+
+    run_in_child("commands_are_invoked_under_a_custom_name");
+}
+
+/// Captures a message through the plugin registered under another name.
+fn invoke_under_custom_name() {
+    let app = TestApp::new(Options {
+        plugin_name: "crash-reporter".to_string(),
+        ..Options::default()
+    });
+
+    app.invoke("capture_message", json!({ "message": "custom name" }))
+        .unwrap();
+
+    assert_eq!(app.sent_messages(), ["custom name"]);
+}
+
+#[test]
+fn fingerprints_survive_the_ipc() {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        return send_fingerprints();
+    }
+
+    run_in_child("fingerprints_survive_the_ipc");
+}
+
+/// Sets the fingerprints from the webview, on an event and on the scope.
+fn send_fingerprints() {
+    let app = TestApp::new(Options::default());
+
+    let event = json!({ "message": "event", "fingerprint": ["{{ default }}", "event"] });
+    app.invoke("event", json!({ "event": event })).unwrap();
+    app.invoke("set_fingerprint", json!({ "fingerprint": ["scope"] }))
+        .unwrap();
+    app.invoke("capture_message", json!({ "message": "message" }))
+        .unwrap();
+
+    let fingerprints: Vec<_> = app
+        .sent_events()
+        .into_iter()
+        .map(|event| event.fingerprint.into_owned())
+        .collect();
+    assert_eq!(
+        fingerprints,
+        [vec!["{{ default }}", "event"], vec!["scope"]]
+    );
+}
+
+/// A transport holding the envelopes back until flushed, like the HTTP ones.
+struct QueuedTransport {
+    queue: Mutex<Vec<Envelope>>,
+    inner: Arc<TestTransport>,
+}
+
+impl Transport for QueuedTransport {
+    fn send_envelope(&self, envelope: Envelope) {
+        self.queue.lock().unwrap().push(envelope);
+    }
+
+    fn flush(&self, _timeout: Duration) -> bool {
+        for envelope in self.queue.lock().unwrap().drain(..) {
+            self.inner.send_envelope(envelope);
+        }
+
+        true
+    }
+}
+
+#[test]
+fn exit_flushes_the_pending_events() {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        return exit_with_pending_events();
+    }
+
+    run_in_child("exit_flushes_the_pending_events");
+}
+
+/// Exits the app with an event still queued by the transport.
+///
+/// The mock runtime cannot request an exit, so the app exits by destroying its
+/// last window: like `AppHandle::exit`, it emits `ExitRequested` then `Exit`.
+fn exit_with_pending_events() {
+    let mut options = Options::default();
+    let transport = install_test_transport(&mut options);
+    let inner = transport.clone();
+    options.transport = Some(Arc::new(move |_: &ClientOptions| {
+        Arc::new(QueuedTransport {
+            queue: Mutex::default(),
+            inner: inner.clone(),
+        }) as Arc<dyn Transport>
+    }));
+    let test_app = TestApp::with_transport(options, transport);
+
+    test_app
+        .invoke("capture_message", json!({ "message": "before the exit" }))
+        .unwrap();
+    assert!(test_app.transport.fetch_and_clear_events().is_empty());
+
+    test_app.app.run(|app, event| {
+        if let RunEvent::Ready = event {
+            app.get_webview_window("main").unwrap().destroy().unwrap();
+        }
+    });
+
+    let messages: Vec<_> = test_app
+        .transport
+        .fetch_and_clear_events()
+        .into_iter()
+        .filter_map(|event| event.message)
+        .collect();
+    assert_eq!(messages, ["before the exit"]);
+}
+
+#[test]
+fn before_breadcrumb_applies_to_the_webview() {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        return record_webview_breadcrumbs();
+    }
+
+    run_in_child("before_breadcrumb_applies_to_the_webview");
+}
+
+/// Records breadcrumbs from the webview, with a client `before_breadcrumb`
+/// renaming or dropping them.
+fn record_webview_breadcrumbs() {
+    let mut options = Options::default();
+    options.client.before_breadcrumb = Some(Arc::new(|mut breadcrumb| {
+        if breadcrumb.category.as_deref() == Some("secret") {
+            return None;
+        }
+        breadcrumb.category = breadcrumb
+            .category
+            .map(|category| format!("js.{}", category));
+        Some(breadcrumb)
+    }));
+    let app = TestApp::new(options);
+
+    for category in ["ui.click", "secret"] {
+        let breadcrumb = json!({ "category": category, "message": "from the webview" });
+        app.invoke("breadcrumb", json!({ "breadcrumb": breadcrumb }))
+            .unwrap();
+    }
+    app.invoke("capture_message", json!({ "message": "message" }))
+        .unwrap();
+
+    let events = app.sent_events();
+    let categories: Vec<_> = events[0]
+        .breadcrumbs
+        .iter()
+        .filter(|breadcrumb| breadcrumb.message.as_deref() == Some("from the webview"))
+        .map(|breadcrumb| breadcrumb.category.as_deref())
+        .collect();
+    assert_eq!(categories, [Some("js.ui.click")]);
+}
@@ -0,0 +1,254 @@
+//! Checks that a native panic is reported as a fatal event with a minidump.
+//!
+//! The panics are raised in a child process running this same test binary, so
+//! that the panic hook installed by the integration cannot affect the runner.
+
+use std::path::Path;
+use std::process::Command;
+use std::sync::Arc;
+use std::time::Duration;
+
+use sentry::protocol::{AttachmentType, EnvelopeItem, Level};
+use sentry::{ClientOptions, Envelope, Transport};
+use tauri_plugin_sentry::{PanicIntegration, PanicOptions, TestTransport};
+
+const CHILD_ENV: &str = "TAURI_PLUGIN_SENTRY_PANIC_CHILD";
+
+/// Runs the given test in a child process, checking that it succeeds.
+fn run_in_child(test: &str) {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", test, "--nocapture"])
+        .env(CHILD_ENV, "1")
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "the child process failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// The number of minidumps in the given directory.
+fn minidumps_in(dir: &Path) -> usize {
+    std::fs::read_dir(dir)
+        .unwrap()
+        .flatten()
+        .filter(|entry| entry.file_name().to_string_lossy().ends_with(".mdmp"))
+        .count()
+}
+
+#[test]
+fn panic_is_reported_with_a_minidump() {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        return report_panic();
+    }
+
+    run_in_child("panic_is_reported_with_a_minidump");
+}
+
+/// Panics with the integration installed and checks the captured envelope.
+fn report_panic() {
+    let transport = TestTransport::new();
+    let _guard = sentry::init(ClientOptions {
+        dsn: "https://public@sentry.invalid/1".parse().ok(),
+        transport: Some(Arc::new(transport.clone())),
+        integrations: vec![Arc::new(PanicIntegration::new())],
+        ..ClientOptions::default()
+    });
+
+    let result = std::panic::catch_unwind(|| panic!("controlled panic"));
+    assert!(result.is_err());
+
+    let envelopes = transport.fetch_and_clear_envelopes();
+    let items: Vec<_> = envelopes
+        .iter()
+        .flat_map(|envelope| envelope.items())
+        .collect();
+
+    let event = items
+        .iter()
+        .find_map(|item| match item {
+            EnvelopeItem::Event(event) => Some(event),
+            _ => None,
+        })
+        .expect("no event captured");
+    assert_eq!(event.level, Level::Fatal);
+    assert!(event.exception[0]
+        .value
+        .as_deref()
+        .is_some_and(|value| value.starts_with("controlled panic")));
+
+    // minidump-writer supports these platforms only.
+    #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+    assert!(
+        items.iter().any(|item| matches!(
+            item,
+            EnvelopeItem::Attachment(attachment) if attachment.ty == Some(AttachmentType::Minidump)
+        )),
+        "no minidump attached"
+    );
+}
+
+#[test]
+fn thread_panic_is_reported_with_a_minidump() {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        return report_thread_panic();
+    }
+
+    run_in_child("thread_panic_is_reported_with_a_minidump");
+}
+
+/// Panics in a spawned thread and checks the captured envelope.
+fn report_thread_panic() {
+    let transport = TestTransport::new();
+    let _guard = sentry::init(ClientOptions {
+        dsn: "https://public@sentry.invalid/1".parse().ok(),
+        transport: Some(Arc::new(transport.clone())),
+        integrations: vec![Arc::new(PanicIntegration::new())],
+        ..ClientOptions::default()
+    });
+
+    let result = std::thread::Builder::new()
+        .name("worker".into())
+        .spawn(|| panic!("thread panic"))
+        .unwrap()
+        .join();
+    assert!(result.is_err());
+
+    let envelopes = transport.fetch_and_clear_envelopes();
+    let items: Vec<_> = envelopes
+        .iter()
+        .flat_map(|envelope| envelope.items())
+        .collect();
+
+    let event = items
+        .iter()
+        .find_map(|item| match item {
+            EnvelopeItem::Event(event) => Some(event),
+            _ => None,
+        })
+        .expect("no event captured");
+    assert_eq!(event.level, Level::Fatal);
+    assert_eq!(
+        event.exception[0].value.as_deref(),
+        Some("thread panic (thread 'worker')")
+    );
+    assert_eq!(
+        event.tags.get("thread.name").map(String::as_str),
+        Some("worker")
+    );
+
+    // minidump-writer supports these platforms only.
+    #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+    assert!(
+        items.iter().any(|item| matches!(
+            item,
+            EnvelopeItem::Attachment(attachment) if attachment.ty == Some(AttachmentType::Minidump)
+        )),
+        "no minidump attached"
+    );
+}
+
+/// A transport failing to upload anything, whose flush never completes.
+struct FailingTransport;
+
+impl Transport for FailingTransport {
+    fn send_envelope(&self, _envelope: Envelope) {}
+
+    fn flush(&self, _timeout: Duration) -> bool {
+        false
+    }
+}
+
+#[test]
+fn minidump_is_kept_after_a_failed_upload() {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        return keep_minidump();
+    }
+
+    run_in_child("minidump_is_kept_after_a_failed_upload");
+}
+
+/// Panics with an upload failing and checks the minidump left on disk, then
+/// with an upload succeeding and checks that it is removed.
+fn keep_minidump() {
+    let dir = std::env::temp_dir().join(format!("sentry-tauri-minidumps-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let options = PanicOptions {
+        minidump_dir: Some(dir.clone()),
+        ..PanicOptions::default()
+    };
+    let integration = Arc::new(PanicIntegration::with_options(options));
+    let failing = sentry::init(ClientOptions {
+        dsn: "https://public@sentry.invalid/1".parse().ok(),
+        transport: Some(Arc::new(|_: &ClientOptions| {
+            Arc::new(FailingTransport) as Arc<dyn Transport>
+        })),
+        integrations: vec![integration.clone()],
+        ..ClientOptions::default()
+    });
+
+    let result = std::panic::catch_unwind(|| panic!("failed upload"));
+    assert!(result.is_err());
+    let kept = minidumps_in(&dir);
+    drop(failing);
+
+    let transport = TestTransport::new();
+    let _guard = sentry::init(ClientOptions {
+        dsn: "https://public@sentry.invalid/1".parse().ok(),
+        transport: Some(Arc::new(transport.clone())),
+        integrations: vec![integration],
+        ..ClientOptions::default()
+    });
+
+    let result = std::panic::catch_unwind(|| panic!("successful upload"));
+    assert!(result.is_err());
+    let remaining = minidumps_in(&dir);
+    let _ = std::fs::remove_dir_all(&dir);
+
+    // minidump-writer supports these platforms only.
+    #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+    {
+        assert_eq!(kept, 1, "the minidump has not been kept");
+        assert_eq!(remaining, 0, "the minidump has not been removed");
+        assert!(!transport.fetch_and_clear_envelopes().is_empty());
+    }
+}
+
+#[test]
+fn minidump_is_written_without_a_dsn() {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        return write_minidump_without_dsn();
+    }
+
+    run_in_child("minidump_is_written_without_a_dsn");
+}
+
+/// Panics with a disabled client and checks the minidump left on disk.
+fn write_minidump_without_dsn() {
+    let dir = std::env::temp_dir().join(format!("sentry-tauri-no-dsn-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let options = PanicOptions {
+        minidump_dir: Some(dir.clone()),
+        always_write_minidump: true,
+        ..PanicOptions::default()
+    };
+    // Otherwise picked up by the client.
+    std::env::remove_var("SENTRY_DSN");
+    let guard = sentry::init(ClientOptions {
+        integrations: vec![Arc::new(PanicIntegration::with_options(options))],
+        ..ClientOptions::default()
+    });
+    assert!(!guard.is_enabled());
+
+    let result = std::panic::catch_unwind(|| panic!("controlled panic"));
+    assert!(result.is_err());
+
+    let minidumps = minidumps_in(&dir);
+    let _ = std::fs::remove_dir_all(&dir);
+
+    // minidump-writer supports these platforms only.
+    #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+    assert_eq!(minidumps, 1, "no minidump has been written");
+}
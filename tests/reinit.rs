@@ -0,0 +1,40 @@
+//! Checks that initializing the plugin twice replaces the client, the panic
+//! hook reporting through the integration of the latest one.
+
+use std::panic::catch_unwind;
+use std::sync::Arc;
+
+use sentry::protocol::Level;
+use tauri_plugin_sentry::{install_test_transport, Options, TestTransport};
+
+/// Options sending the events to a new test transport.
+fn options() -> (Options, Arc<TestTransport>) {
+    let mut options = Options::default();
+    let transport = install_test_transport(&mut options);
+
+    (options, transport)
+}
+
+#[test]
+fn init_twice_replaces_the_client() {
+    let (first_options, first) = options();
+    let (_first_plugin, first_handle) =
+        tauri_plugin_sentry::init_with_handle::<tauri::Wry>(first_options);
+    sentry::configure_scope(|scope| scope.set_tag("init", "first"));
+
+    let (second_options, second) = options();
+    let (_second_plugin, second_handle) =
+        tauri_plugin_sentry::init_with_handle::<tauri::Wry>(second_options);
+
+    let result = catch_unwind(|| panic!("panic after the second init"));
+    assert!(result.is_err());
+    first_handle.flush(None);
+    second_handle.flush(None);
+
+    assert!(first.fetch_and_clear_events().is_empty());
+    let events = second.fetch_and_clear_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].level, Level::Fatal);
+    // The scope set up by the first initialization has been cleared.
+    assert!(!events[0].tags.contains_key("init"));
+}
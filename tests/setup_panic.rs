@@ -0,0 +1,31 @@
+//! Checks that a panic raised while the app is being set up is reported, and
+//! does not take the test runner down with it.
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use sentry::protocol::Level;
+use tauri::test::{mock_builder, mock_context, noop_assets, MockRuntime};
+use tauri_plugin_sentry::{install_test_transport, Options};
+
+#[test]
+fn panic_during_setup_is_reported() {
+    let mut options = Options::default();
+    let transport = install_test_transport(&mut options);
+    let (plugin, handle) = tauri_plugin_sentry::init_with_handle::<MockRuntime>(options);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        mock_builder()
+            .plugin(plugin)
+            .setup(|_app| panic!("panic during setup"))
+            .build(mock_context(noop_assets()))
+    }));
+    assert!(result.is_err());
+    handle.flush(None);
+
+    let events = transport.fetch_and_clear_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].level, Level::Fatal);
+    // The name of the panicking thread is appended to the message.
+    let value = events[0].exception[0].value.as_deref().unwrap_or_default();
+    assert!(value.starts_with("panic during setup"));
+}
@@ -0,0 +1,28 @@
+//! Checks that the transactions started by `start_transaction` are bound to
+//! the scope while running.
+
+use std::sync::Arc;
+
+use sentry::{ClientOptions, TransactionContext};
+use tauri_plugin_sentry::{current_trace_header, start_transaction, TestTransport};
+
+#[test]
+fn transaction_is_bound_to_the_scope() {
+    let transport = TestTransport::new();
+    let _guard = sentry::init(ClientOptions {
+        dsn: "https://public@sentry.invalid/1".parse().ok(),
+        transport: Some(Arc::new(transport.clone())),
+        traces_sample_rate: 1.0,
+        ..ClientOptions::default()
+    });
+    assert_eq!(current_trace_header(), None);
+
+    let transaction = start_transaction(TransactionContext::new("command", "tauri.command"));
+    let trace = transaction.get_trace_context();
+    let header = current_trace_header().expect("no span bound to the scope");
+    assert!(header.starts_with(&format!("{}-{}", trace.trace_id, trace.span_id)));
+
+    transaction.finish();
+    assert_eq!(current_trace_header(), None);
+    assert_eq!(transport.fetch_and_clear_envelopes().len(), 1);
+}